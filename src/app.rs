@@ -13,32 +13,41 @@ use gpui_component::{
 use crate::ui::components::spinning_loader;
 
 use crate::keybindings::{
-  DeleteSelected, FocusSearch, GoToActivityMonitor, GoToCompose, GoToContainers, GoToDeployments, GoToImages,
-  GoToMachines, GoToNetworks, GoToPods, GoToServices, GoToSettings, GoToVolumes, InspectSelected, NewResource,
-  OpenCommandPalette, OpenTerminal, Refresh, RestartSelected, ShowKeyboardShortcuts, StartSelected, StopSelected,
-  ViewLogs,
+  DeleteSelected, FocusSearch, GoToActivityMonitor, GoToCompose, GoToContainers, GoToDeployments, GoToEvents,
+  GoToImages, GoToMachines, GoToNetworks, GoToPods, GoToServices, GoToSettings, GoToVolumes, InspectSelected,
+  NewResource, OpenCommandPalette, OpenTerminal, Refresh, RestartSelected, ShowKeyboardShortcuts, StartSelected,
+  StopSelected, ViewLogs,
 };
 
 use crate::assets::AppIcon;
+use crate::kubernetes::{ConfigKind, WorkloadKind};
 use crate::services::{DispatcherEvent, dispatcher, task_manager};
 use crate::state::{CurrentView, DockerState, Selection, StateChanged, docker_state};
 use crate::ui::activity::ActivityMonitorView;
 use crate::ui::command_palette::{CommandPalette, CommandPaletteEvent, PaletteAction};
 use crate::ui::compose::ComposeView;
+use crate::ui::configs::ConfigsView;
 use crate::ui::containers::ContainersView;
+use crate::ui::contexts::KubeContextsView;
 use crate::ui::deployments::DeploymentsView;
 use crate::ui::dialogs;
+use crate::ui::disk_usage::DiskUsageView;
+use crate::ui::events::EventsView;
 use crate::ui::global_search::{GlobalSearch, GlobalSearchEvent};
 use crate::ui::images::ImagesView;
+use crate::ui::ingresses::IngressesView;
 use crate::ui::machines::MachinesView;
 use crate::ui::networks::NetworksView;
+use crate::ui::nodes::NodesView;
 use crate::ui::pods::PodsView;
 use crate::ui::services::ServicesView;
 use crate::ui::settings::SettingsView;
 use crate::ui::setup_dialog::{
   SetupDialog, diagnose_k8s_quick, is_colima_installed, is_colima_running, is_docker_installed,
 };
+use crate::ui::storage::StorageView;
 use crate::ui::volumes::VolumesView;
+use crate::ui::workloads::WorkloadsView;
 
 /// Main application - only handles layout and view switching
 pub struct DocksideApp {
@@ -51,8 +60,20 @@ pub struct DocksideApp {
   networks_view: Entity<NetworksView>,
   pods_view: Entity<PodsView>,
   services_view: Entity<ServicesView>,
+  ingresses_view: Entity<IngressesView>,
   deployments_view: Entity<DeploymentsView>,
+  stateful_sets_view: Entity<WorkloadsView>,
+  daemon_sets_view: Entity<WorkloadsView>,
+  jobs_view: Entity<WorkloadsView>,
+  cron_jobs_view: Entity<WorkloadsView>,
+  config_maps_view: Entity<ConfigsView>,
+  secrets_view: Entity<ConfigsView>,
+  storage_view: Entity<StorageView>,
+  nodes_view: Entity<NodesView>,
   activity_view: Entity<ActivityMonitorView>,
+  events_view: Entity<EventsView>,
+  disk_usage_view: Entity<DiskUsageView>,
+  contexts_view: Entity<KubeContextsView>,
   settings_view: Entity<SettingsView>,
   // Centralized notification handling - prevents duplicate notifications on view switch
   pending_notifications: Vec<(NotificationType, String)>,
@@ -91,6 +112,18 @@ impl DocksideApp {
     })
     .detach();
 
+    // Deleting a protected resource from anywhere ends up here for typed confirmation
+    cx.subscribe_in(
+      &docker_state,
+      window,
+      |_this, _state, event: &StateChanged, window, cx| {
+        if let StateChanged::ProtectedDeleteRequest { kind, id, name } = event {
+          dialogs::open_protected_delete_dialog(*kind, id.clone(), name.clone(), window, cx);
+        }
+      },
+    )
+    .detach();
+
     // Observe theme changes to re-render when theme is switched
     cx.observe_global::<Theme>(|_this, cx| {
       cx.notify();
@@ -111,6 +144,11 @@ impl DocksideApp {
             .pending_notifications
             .push((NotificationType::Error, error.clone()));
         }
+        DispatcherEvent::Alert { message } => {
+          this
+            .pending_notifications
+            .push((NotificationType::Warning, message.clone()));
+        }
       }
       cx.notify();
     })
@@ -125,8 +163,20 @@ impl DocksideApp {
     let networks_view = cx.new(|cx| NetworksView::new(window, cx));
     let pods_view = cx.new(|cx| PodsView::new(window, cx));
     let services_view = cx.new(|cx| ServicesView::new(window, cx));
+    let ingresses_view = cx.new(|cx| IngressesView::new(window, cx));
     let deployments_view = cx.new(|cx| DeploymentsView::new(window, cx));
+    let stateful_sets_view = cx.new(|cx| WorkloadsView::new(WorkloadKind::StatefulSet, window, cx));
+    let daemon_sets_view = cx.new(|cx| WorkloadsView::new(WorkloadKind::DaemonSet, window, cx));
+    let jobs_view = cx.new(|cx| WorkloadsView::new(WorkloadKind::Job, window, cx));
+    let cron_jobs_view = cx.new(|cx| WorkloadsView::new(WorkloadKind::CronJob, window, cx));
+    let config_maps_view = cx.new(|cx| ConfigsView::new(ConfigKind::ConfigMap, window, cx));
+    let secrets_view = cx.new(|cx| ConfigsView::new(ConfigKind::Secret, window, cx));
+    let storage_view = cx.new(|cx| StorageView::new(window, cx));
+    let nodes_view = cx.new(|cx| NodesView::new(window, cx));
     let activity_view = cx.new(|cx| ActivityMonitorView::new(window, cx));
+    let events_view = cx.new(|cx| EventsView::new(window, cx));
+    let disk_usage_view = cx.new(|cx| DiskUsageView::new(window, cx));
+    let contexts_view = cx.new(|cx| KubeContextsView::new(window, cx));
     let settings_view = cx.new(SettingsView::new);
 
     // Run setup checks async - only show dialog if there are issues
@@ -179,8 +229,20 @@ impl DocksideApp {
       networks_view,
       pods_view,
       services_view,
+      ingresses_view,
       deployments_view,
+      stateful_sets_view,
+      daemon_sets_view,
+      jobs_view,
+      cron_jobs_view,
+      config_maps_view,
+      secrets_view,
+      storage_view,
+      nodes_view,
       activity_view,
+      events_view,
+      disk_usage_view,
+      contexts_view,
       settings_view,
       pending_notifications: Vec::new(),
       pending_setup_check: None,
@@ -564,6 +626,62 @@ impl DocksideApp {
                                     crate::services::set_view(CurrentView::Deployments, cx);
                                 })),
                         )
+                        .child(
+                            SidebarMenuItem::new("StatefulSets")
+                                .icon(AppIcon::Deployment)
+                                .active(current_view == CurrentView::StatefulSets)
+                                .on_click(cx.listener(|_this, _ev, _window, cx| {
+                                    crate::services::set_view(CurrentView::StatefulSets, cx);
+                                })),
+                        )
+                        .child(
+                            SidebarMenuItem::new("DaemonSets")
+                                .icon(AppIcon::Deployment)
+                                .active(current_view == CurrentView::DaemonSets)
+                                .on_click(cx.listener(|_this, _ev, _window, cx| {
+                                    crate::services::set_view(CurrentView::DaemonSets, cx);
+                                })),
+                        )
+                        .child(
+                            SidebarMenuItem::new("Jobs")
+                                .icon(AppIcon::Activity)
+                                .active(current_view == CurrentView::Jobs)
+                                .on_click(cx.listener(|_this, _ev, _window, cx| {
+                                    crate::services::set_view(CurrentView::Jobs, cx);
+                                })),
+                        )
+                        .child(
+                            SidebarMenuItem::new("CronJobs")
+                                .icon(AppIcon::Events)
+                                .active(current_view == CurrentView::CronJobs)
+                                .on_click(cx.listener(|_this, _ev, _window, cx| {
+                                    crate::services::set_view(CurrentView::CronJobs, cx);
+                                })),
+                        )
+                        .child(
+                            SidebarMenuItem::new("ConfigMaps")
+                                .icon(AppIcon::Settings)
+                                .active(current_view == CurrentView::ConfigMaps)
+                                .on_click(cx.listener(|_this, _ev, _window, cx| {
+                                    crate::services::set_view(CurrentView::ConfigMaps, cx);
+                                })),
+                        )
+                        .child(
+                            SidebarMenuItem::new("Secrets")
+                                .icon(AppIcon::Shield)
+                                .active(current_view == CurrentView::Secrets)
+                                .on_click(cx.listener(|_this, _ev, _window, cx| {
+                                    crate::services::set_view(CurrentView::Secrets, cx);
+                                })),
+                        )
+                        .child(
+                            SidebarMenuItem::new("Storage")
+                                .icon(AppIcon::Volume)
+                                .active(current_view == CurrentView::Storage)
+                                .on_click(cx.listener(|_this, _ev, _window, cx| {
+                                    crate::services::set_view(CurrentView::Storage, cx);
+                                })),
+                        )
                         .child(
                             SidebarMenuItem::new("Services")
                                 .icon(AppIcon::Service)
@@ -571,6 +689,30 @@ impl DocksideApp {
                                 .on_click(cx.listener(|_this, _ev, _window, cx| {
                                     crate::services::set_view(CurrentView::Services, cx);
                                 })),
+                        )
+                        .child(
+                            SidebarMenuItem::new("Ingresses")
+                                .icon(AppIcon::Network)
+                                .active(current_view == CurrentView::Ingresses)
+                                .on_click(cx.listener(|_this, _ev, _window, cx| {
+                                    crate::services::set_view(CurrentView::Ingresses, cx);
+                                })),
+                        )
+                        .child(
+                            SidebarMenuItem::new("Nodes")
+                                .icon(AppIcon::Machine)
+                                .active(current_view == CurrentView::Nodes)
+                                .on_click(cx.listener(|_this, _ev, _window, cx| {
+                                    crate::services::set_view(CurrentView::Nodes, cx);
+                                })),
+                        )
+                        .child(
+                            SidebarMenuItem::new("Contexts")
+                                .icon(AppIcon::Kubernetes)
+                                .active(current_view == CurrentView::Contexts)
+                                .on_click(cx.listener(|_this, _ev, _window, cx| {
+                                    crate::services::set_view(CurrentView::Contexts, cx);
+                                })),
                         ),
                 ),
             )
@@ -597,6 +739,22 @@ impl DocksideApp {
                                     crate::services::set_view(CurrentView::ActivityMonitor, cx);
                                 })),
                         )
+                        .child(
+                            SidebarMenuItem::new("Events")
+                                .icon(AppIcon::Events)
+                                .active(current_view == CurrentView::Events)
+                                .on_click(cx.listener(|_this, _ev, _window, cx| {
+                                    crate::services::set_view(CurrentView::Events, cx);
+                                })),
+                        )
+                        .child(
+                            SidebarMenuItem::new("Disk Usage")
+                                .icon(AppIcon::Volume)
+                                .active(current_view == CurrentView::DiskUsage)
+                                .on_click(cx.listener(|_this, _ev, _window, cx| {
+                                    crate::services::set_view(CurrentView::DiskUsage, cx);
+                                })),
+                        )
                         .child(
                             SidebarMenuItem::new("Prune")
                                 .icon(AppIcon::Trash)
@@ -628,8 +786,20 @@ impl DocksideApp {
       CurrentView::Networks => div().size_full().child(self.networks_view.clone()),
       CurrentView::Pods => div().size_full().child(self.pods_view.clone()),
       CurrentView::Services => div().size_full().child(self.services_view.clone()),
+      CurrentView::Ingresses => div().size_full().child(self.ingresses_view.clone()),
       CurrentView::Deployments => div().size_full().child(self.deployments_view.clone()),
+      CurrentView::StatefulSets => div().size_full().child(self.stateful_sets_view.clone()),
+      CurrentView::DaemonSets => div().size_full().child(self.daemon_sets_view.clone()),
+      CurrentView::Jobs => div().size_full().child(self.jobs_view.clone()),
+      CurrentView::CronJobs => div().size_full().child(self.cron_jobs_view.clone()),
+      CurrentView::ConfigMaps => div().size_full().child(self.config_maps_view.clone()),
+      CurrentView::Secrets => div().size_full().child(self.secrets_view.clone()),
+      CurrentView::Storage => div().size_full().child(self.storage_view.clone()),
+      CurrentView::Nodes => div().size_full().child(self.nodes_view.clone()),
+      CurrentView::Contexts => div().size_full().child(self.contexts_view.clone()),
       CurrentView::ActivityMonitor => div().size_full().child(self.activity_view.clone()),
+      CurrentView::Events => div().size_full().child(self.events_view.clone()),
+      CurrentView::DiskUsage => div().size_full().child(self.disk_usage_view.clone()),
       CurrentView::Settings => div().size_full().child(self.settings_view.clone()),
     }
   }
@@ -904,6 +1074,9 @@ impl Render for DocksideApp {
       .on_action(cx.listener(|_this, _: &GoToActivityMonitor, _window, cx| {
         crate::services::set_view(CurrentView::ActivityMonitor, cx);
       }))
+      .on_action(cx.listener(|_this, _: &GoToEvents, _window, cx| {
+        crate::services::set_view(CurrentView::Events, cx);
+      }))
       .on_action(cx.listener(|_this, _: &GoToSettings, _window, cx| {
         crate::services::set_view(CurrentView::Settings, cx);
       }))
@@ -950,6 +1123,12 @@ impl Render for DocksideApp {
           CurrentView::Services => {
             dialogs::open_create_service_dialog(window, cx);
           }
+          CurrentView::ConfigMaps => {
+            dialogs::open_create_config_dialog(ConfigKind::ConfigMap, window, cx);
+          }
+          CurrentView::Secrets => {
+            dialogs::open_create_config_dialog(ConfigKind::Secret, window, cx);
+          }
           _ => {
             window.push_notification(
               (NotificationType::Info, SharedString::from("No create action for this view.")),
@@ -1023,6 +1202,12 @@ impl Render for DocksideApp {
           Selection::Deployment { name, namespace } => {
             crate::services::restart_deployment(name, namespace, cx);
           }
+          Selection::Workload { kind, name, namespace } if kind.is_restartable() => {
+            crate::services::restart_workload(kind, name, namespace, cx);
+          }
+          Selection::Config { kind, name, namespace } => {
+            crate::services::restart_config_consumers(kind, name, namespace, cx);
+          }
           Selection::None => {
             window.push_notification(
               (NotificationType::Info, SharedString::from("Select a resource first.")),
@@ -1061,6 +1246,27 @@ impl Render for DocksideApp {
           Selection::Service { name, namespace } => {
             crate::services::delete_service(name, namespace, cx);
           }
+          Selection::Workload { kind, name, namespace } => {
+            crate::services::delete_workload(kind, name, namespace, cx);
+          }
+          Selection::Config { kind, name, namespace } => {
+            crate::services::delete_config(kind, name, namespace, cx);
+          }
+          Selection::Ingress { name, namespace } => {
+            crate::services::delete_ingress(name, namespace, cx);
+          }
+          Selection::VolumeClaim { name, namespace } => {
+            crate::services::delete_volume_claim(name, namespace, cx);
+          }
+          Selection::Node(_) => {
+            window.push_notification(
+              (
+                NotificationType::Info,
+                SharedString::from("Nodes can't be deleted here. Cordon or drain the node instead."),
+              ),
+              cx,
+            );
+          }
           Selection::Machine(name) => {
             crate::services::delete_machine(name, cx);
           }
@@ -66,6 +66,7 @@ pub enum AppIcon {
   Edit,
   Copy,
   Settings,
+  Shield,
   // Resources
   Container,
   Compose,
@@ -82,6 +83,7 @@ pub enum AppIcon {
   Files,
   Folder,
   Activity,
+  Events,
   ChevronRight,
   ChevronDown,
   // Platforms
@@ -104,6 +106,7 @@ impl IconNamed for AppIcon {
       Self::Edit => "icons/edit.svg",
       Self::Copy => "icons/copy.svg",
       Self::Settings => "icons/settings.svg",
+      Self::Shield => "icons/shield.svg",
       // Resources
       Self::Container => "icons/container.svg",
       Self::Compose => "icons/compose.svg",
@@ -120,6 +123,7 @@ impl IconNamed for AppIcon {
       Self::Files => "icons/files.svg",
       Self::Folder => "icons/folder.svg",
       Self::Activity => "icons/activity.svg",
+      Self::Events => "icons/events.svg",
       Self::ChevronRight => "icons/chevron-right.svg",
       Self::ChevronDown => "icons/chevron-down.svg",
       // Platforms
@@ -285,6 +285,35 @@ pub struct VmOsInfo {
   pub arch: String,
 }
 
+/// Root filesystem usage inside the VM, from `df -h /`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VmDiskUsage {
+  pub filesystem: String,
+  pub size: String,
+  pub used: String,
+  pub available: String,
+  pub use_percent: u8,
+}
+
+impl VmDiskUsage {
+  /// Parse `df -h` output, which may wrap a long filesystem name onto its own line
+  pub fn parse(output: &str) -> Option<Self> {
+    let mut lines = output.lines().skip_while(|l| !l.starts_with("Filesystem"));
+    lines.next()?;
+    let fields: Vec<&str> = lines.flat_map(str::split_whitespace).collect();
+    let [filesystem, size, used, available, percent, _mount] = fields.get(..6)? else {
+      return None;
+    };
+    Some(Self {
+      filesystem: (*filesystem).to_string(),
+      size: (*size).to_string(),
+      used: (*used).to_string(),
+      available: (*available).to_string(),
+      use_percent: percent.trim_end_matches('%').parse().ok()?,
+    })
+  }
+}
+
 /// A file entry in the VM filesystem
 #[derive(Debug, Clone)]
 pub struct VmFileEntry {
@@ -792,6 +821,25 @@ mod tests {
     assert!(config.env.is_empty());
   }
 
+  #[test]
+  fn test_vm_disk_usage_parse() {
+    let output = "Filesystem      Size  Used Avail Use% Mounted on\n/dev/vda1        98G   71G   23G  76% /\n";
+    let usage = VmDiskUsage::parse(output).unwrap();
+    assert_eq!(usage.filesystem, "/dev/vda1");
+    assert_eq!(usage.size, "98G");
+    assert_eq!(usage.used, "71G");
+    assert_eq!(usage.available, "23G");
+    assert_eq!(usage.use_percent, 76);
+
+    let wrapped = "Filesystem                Size      Used Available Use% Mounted on\n\
+                   /dev/disk/by-label/data-volume\n                          58.4G      5.0G     50.4G   9% /\n";
+    let usage = VmDiskUsage::parse(wrapped).unwrap();
+    assert_eq!(usage.filesystem, "/dev/disk/by-label/data-volume");
+    assert_eq!(usage.use_percent, 9);
+
+    assert_eq!(VmDiskUsage::parse("Unable to get disk usage"), None);
+  }
+
   #[test]
   fn test_colima_config_serialization() {
     let config = ColimaConfig::default();
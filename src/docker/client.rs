@@ -1,10 +1,16 @@
 use anyhow::{Result, anyhow};
 use bollard::Docker;
 use std::path::Path;
+use std::sync::Arc;
 
+use super::helper::HelperPool;
+
+#[derive(Clone)]
 pub struct DockerClient {
   inner: Option<Docker>,
   socket_path: String,
+  /// Volume helper containers, shared between clones
+  pub(super) helpers: Arc<HelperPool>,
 }
 
 impl DockerClient {
@@ -12,6 +18,7 @@ impl DockerClient {
     Self {
       inner: None,
       socket_path,
+      helpers: Arc::default(),
     }
   }
 
@@ -1,19 +1,69 @@
-use std::collections::HashMap;
+use anyhow::{Context as _, Result, anyhow};
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
 
 use super::{ContainerInfo, ContainerState};
 
 /// Docker Compose label keys
 pub const COMPOSE_PROJECT_LABEL: &str = "com.docker.compose.project";
 pub const COMPOSE_SERVICE_LABEL: &str = "com.docker.compose.service";
+pub const COMPOSE_WORKING_DIR_LABEL: &str = "com.docker.compose.project.working_dir";
+pub const COMPOSE_CONFIG_FILES_LABEL: &str = "com.docker.compose.project.config_files";
+
+/// File names `docker compose` looks for in a project directory, in order of preference
+pub const COMPOSE_FILE_NAMES: [&str; 4] = [
+  "compose.yaml",
+  "compose.yml",
+  "docker-compose.yaml",
+  "docker-compose.yml",
+];
 
 /// Represents a Docker Compose project with its services
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct ComposeProject {
   pub name: String,
   pub services: Vec<ComposeService>,
+  /// Project directory, from container labels or the registered compose file
+  pub working_dir: Option<String>,
+  /// Compose files backing the project
+  pub config_files: Vec<String>,
+  /// Services defined in the compose files (empty if the files could not be read)
+  pub definitions: Vec<ComposeServiceDefinition>,
+  /// Path the user registered this project with, if any
+  pub registered_path: Option<String>,
 }
 
 impl ComposeProject {
+  /// Services defined in the compose files that currently have no container. Services gated
+  /// behind a profile that is not in `profiles` are not expected to run and are left out.
+  pub fn missing_services<'a>(&'a self, profiles: &'a [String]) -> impl Iterator<Item = &'a ComposeServiceDefinition> {
+    self
+      .definitions
+      .iter()
+      .filter(|def| def.profiles.is_empty() || def.profiles.iter().any(|p| profiles.contains(p)))
+      .filter(|def| !self.services.iter().any(|s| s.name == def.name))
+  }
+
+  /// Profiles declared by any service, sorted and deduplicated
+  pub fn available_profiles(&self) -> Vec<String> {
+    let mut profiles: Vec<String> = self.definitions.iter().flat_map(|d| d.profiles.clone()).collect();
+    profiles.sort();
+    profiles.dedup();
+    profiles
+  }
+
+  /// Build a command target for this project
+  pub fn target(&self, env_file: Option<String>, profiles: Vec<String>) -> ComposeTarget {
+    ComposeTarget {
+      project: self.name.clone(),
+      working_dir: self.working_dir.clone(),
+      config_files: self.config_files.clone(),
+      env_file,
+      profiles,
+    }
+  }
+
   /// Total number of containers in this project
   pub fn container_count(&self) -> usize {
     self.services.len()
@@ -85,14 +135,20 @@ pub fn extract_compose_projects(containers: &[ContainerInfo]) -> Vec<ComposeProj
 
       let service = ComposeService::from_container(container, &service_name);
 
-      projects
-        .entry(project_name.clone())
-        .or_insert_with(|| ComposeProject {
-          name: project_name.clone(),
-          services: Vec::new(),
-        })
-        .services
-        .push(service);
+      let project = projects.entry(project_name.clone()).or_insert_with(|| ComposeProject {
+        name: project_name.clone(),
+        ..Default::default()
+      });
+      project.services.push(service);
+
+      if project.working_dir.is_none() {
+        project.working_dir = container.labels.get(COMPOSE_WORKING_DIR_LABEL).cloned();
+      }
+      if project.config_files.is_empty()
+        && let Some(files) = container.labels.get(COMPOSE_CONFIG_FILES_LABEL)
+      {
+        project.config_files = split_config_files(files);
+      }
     }
   }
 
@@ -108,6 +164,583 @@ pub fn extract_compose_projects(containers: &[ContainerInfo]) -> Vec<ComposeProj
   result
 }
 
+/// Merge compose file definitions into projects discovered from containers.
+/// Definitions without running containers become projects of their own.
+pub fn merge_compose_definitions(projects: &mut Vec<ComposeProject>, definitions: Vec<ComposeDefinition>) {
+  for def in definitions {
+    let project = match projects.iter().position(|p| p.name == def.project) {
+      Some(idx) => &mut projects[idx],
+      None => {
+        projects.push(ComposeProject {
+          name: def.project.clone(),
+          ..Default::default()
+        });
+        projects.last_mut().expect("just pushed")
+      }
+    };
+
+    project.working_dir = Some(def.working_dir);
+    project.config_files = def.config_files;
+    project.definitions = def.services;
+    if def.registered_path.is_some() {
+      project.registered_path = def.registered_path;
+    }
+  }
+
+  projects.sort_by(|a, b| a.name.cmp(&b.name));
+}
+
+/// A service as declared in a compose file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComposeServiceDefinition {
+  pub name: String,
+  pub image: Option<String>,
+  /// Whether the service has a `build` section
+  pub has_build: bool,
+  pub profiles: Vec<String>,
+  pub depends_on: Vec<String>,
+  pub networks: Vec<String>,
+  /// Named volumes (bind mounts are left out)
+  pub volumes: Vec<String>,
+}
+
+impl ComposeServiceDefinition {
+  fn from_yaml(name: String, value: &serde_yaml::Value) -> Self {
+    let volumes = value
+      .get("volumes")
+      .and_then(|v| v.as_sequence())
+      .map(|seq| seq.iter().filter_map(named_volume).collect())
+      .unwrap_or_default();
+
+    Self {
+      name,
+      image: value.get("image").and_then(|v| v.as_str()).map(String::from),
+      has_build: value.get("build").is_some(),
+      profiles: yaml_names(value.get("profiles")),
+      depends_on: yaml_names(value.get("depends_on")),
+      networks: yaml_names(value.get("networks")),
+      volumes,
+    }
+  }
+}
+
+/// Names from either a list (`[a, b]`) or a mapping (`{a: {...}, b: {...}}`)
+fn yaml_names(value: Option<&serde_yaml::Value>) -> Vec<String> {
+  match value {
+    Some(serde_yaml::Value::Sequence(seq)) => seq.iter().filter_map(|v| v.as_str().map(String::from)).collect(),
+    Some(serde_yaml::Value::Mapping(map)) => map.keys().filter_map(|k| k.as_str().map(String::from)).collect(),
+    _ => Vec::new(),
+  }
+}
+
+/// Volume name of a service volume entry, or `None` for bind mounts and anonymous volumes
+fn named_volume(entry: &serde_yaml::Value) -> Option<String> {
+  match entry {
+    serde_yaml::Value::String(spec) => {
+      let (source, _) = spec.split_once(':')?;
+      let is_path = source.starts_with(['.', '/', '~', '$']);
+      (!source.is_empty() && !is_path).then(|| source.to_string())
+    }
+    serde_yaml::Value::Mapping(_) => {
+      let is_volume = entry.get("type").and_then(|t| t.as_str()) == Some("volume");
+      entry
+        .get("source")
+        .and_then(|s| s.as_str())
+        .filter(|_| is_volume)
+        .map(String::from)
+    }
+    _ => None,
+  }
+}
+
+/// The parts of a compose file the app cares about
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComposeFile {
+  /// Top-level `name`, if set
+  pub name: Option<String>,
+  /// Services sorted by name
+  pub services: Vec<ComposeServiceDefinition>,
+}
+
+/// Parse services from compose YAML
+pub fn parse_compose_file(content: &str) -> Result<ComposeFile> {
+  let doc: serde_yaml::Value = serde_yaml::from_str(content).context("Invalid compose YAML")?;
+
+  let name = doc.get("name").and_then(|v| v.as_str()).map(String::from);
+  let mut services: Vec<ComposeServiceDefinition> = doc
+    .get("services")
+    .and_then(|v| v.as_mapping())
+    .map(|services| {
+      services
+        .iter()
+        .filter_map(|(key, value)| {
+          let name = key.as_str()?.to_string();
+          Some(ComposeServiceDefinition::from_yaml(name, value))
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+
+  services.sort_by(|a, b| a.name.cmp(&b.name));
+  Ok(ComposeFile { name, services })
+}
+
+/// A problem found while validating a compose file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComposeDiagnostic {
+  /// 1-based line the problem refers to, when known
+  pub line: Option<usize>,
+  pub message: String,
+  /// YAML syntax errors; the file cannot be parsed at all
+  pub is_syntax: bool,
+}
+
+impl ComposeDiagnostic {
+  fn model(content: &str, service: &str, message: String) -> Self {
+    Self {
+      line: service_line(content, service),
+      message,
+      is_syntax: false,
+    }
+  }
+}
+
+/// Line of a service's key, found by looking for `<name>:` indented under `services:`
+fn service_line(content: &str, service: &str) -> Option<usize> {
+  let key = format!("{service}:");
+  let mut in_services = false;
+  for (idx, line) in content.lines().enumerate() {
+    if !line.starts_with([' ', '\t']) && !line.trim().is_empty() {
+      in_services = line.trim_end() == "services:";
+      continue;
+    }
+    if in_services && line.trim_start().starts_with(&key) {
+      return Some(idx + 1);
+    }
+  }
+  None
+}
+
+/// Validate compose YAML: syntax first, then service references to other services, networks and volumes
+pub fn validate_compose_file(content: &str) -> Vec<ComposeDiagnostic> {
+  let doc: serde_yaml::Value = match serde_yaml::from_str(content) {
+    Ok(doc) => doc,
+    Err(e) => {
+      return vec![ComposeDiagnostic {
+        line: e.location().map(|l| l.line()),
+        message: e.to_string(),
+        is_syntax: true,
+      }];
+    }
+  };
+
+  let Some(services) = doc.get("services").and_then(|v| v.as_mapping()) else {
+    return vec![ComposeDiagnostic {
+      line: None,
+      message: "Missing top-level 'services' mapping".to_string(),
+      is_syntax: false,
+    }];
+  };
+
+  let service_names: Vec<&str> = services.keys().filter_map(|k| k.as_str()).collect();
+  let top_level = |key: &str| yaml_names(doc.get(key));
+  let declared_networks = top_level("networks");
+  let declared_volumes = top_level("volumes");
+  let mut diagnostics = Vec::new();
+
+  for (key, value) in services {
+    let Some(name) = key.as_str() else { continue };
+    if !value.is_mapping() {
+      diagnostics.push(ComposeDiagnostic::model(
+        content,
+        name,
+        format!("Service '{name}' must be a mapping"),
+      ));
+      continue;
+    }
+
+    let service = ComposeServiceDefinition::from_yaml(name.to_string(), value);
+    if service.image.is_none() && !service.has_build {
+      diagnostics.push(ComposeDiagnostic::model(
+        content,
+        name,
+        format!("Service '{name}' needs an 'image' or a 'build' section"),
+      ));
+    }
+    for dep in service
+      .depends_on
+      .iter()
+      .filter(|d| !service_names.contains(&d.as_str()))
+    {
+      diagnostics.push(ComposeDiagnostic::model(
+        content,
+        name,
+        format!("Service '{name}' depends on undefined service '{dep}'"),
+      ));
+    }
+    for network in service
+      .networks
+      .iter()
+      .filter(|n| n.as_str() != "default" && !declared_networks.contains(n))
+    {
+      diagnostics.push(ComposeDiagnostic::model(
+        content,
+        name,
+        format!("Service '{name}' uses undefined network '{network}'"),
+      ));
+    }
+    for volume in service.volumes.iter().filter(|v| !declared_volumes.contains(v)) {
+      diagnostics.push(ComposeDiagnostic::model(
+        content,
+        name,
+        format!("Service '{name}' uses undefined volume '{volume}'"),
+      ));
+    }
+  }
+
+  diagnostics
+}
+
+/// Group services into layers so every service comes after the services it depends on.
+/// Services caught in a dependency cycle end up together in the last layer.
+pub fn dependency_layers(services: &[ComposeServiceDefinition]) -> Vec<Vec<String>> {
+  let known: Vec<&str> = services.iter().map(|s| s.name.as_str()).collect();
+  let mut placed: Vec<String> = Vec::new();
+  let mut layers: Vec<Vec<String>> = Vec::new();
+  let mut remaining: Vec<&ComposeServiceDefinition> = services.iter().collect();
+
+  while !remaining.is_empty() {
+    let (ready, blocked): (Vec<_>, Vec<_>) = remaining.into_iter().partition(|s| {
+      s.depends_on
+        .iter()
+        .all(|d| placed.contains(d) || !known.contains(&d.as_str()))
+    });
+
+    if ready.is_empty() {
+      layers.push(blocked.iter().map(|s| s.name.clone()).collect());
+      break;
+    }
+
+    let layer: Vec<String> = ready.iter().map(|s| s.name.clone()).collect();
+    placed.extend(layer.iter().cloned());
+    layers.push(layer);
+    remaining = blocked;
+  }
+
+  layers
+}
+
+/// Services that were added or whose definition differs between two versions of a compose file
+pub fn changed_services(old: &str, new: &str) -> Vec<String> {
+  let services = |content: &str| -> Option<serde_yaml::Mapping> {
+    let doc: serde_yaml::Value = serde_yaml::from_str(content).ok()?;
+    doc.get("services")?.as_mapping().cloned()
+  };
+
+  let old_services = services(old).unwrap_or_default();
+  let mut changed: Vec<String> = services(new)
+    .unwrap_or_default()
+    .iter()
+    .filter(|(key, value)| old_services.get(*key) != Some(*value))
+    .filter_map(|(key, _)| key.as_str().map(String::from))
+    .collect();
+  changed.sort();
+  changed
+}
+
+/// Read and merge several compose files; later files override earlier ones per service
+pub fn load_compose_files(paths: &[String]) -> Result<ComposeFile> {
+  let mut merged = ComposeFile::default();
+
+  for path in paths {
+    let content = std::fs::read_to_string(path).with_context(|| format!("Failed to read {path}"))?;
+    let file = parse_compose_file(&content).with_context(|| format!("Failed to parse {path}"))?;
+
+    if merged.name.is_none() {
+      merged.name = file.name;
+    }
+    for service in file.services {
+      match merged.services.iter_mut().find(|s| s.name == service.name) {
+        Some(existing) => {
+          if service.image.is_some() {
+            existing.image = service.image;
+          }
+          existing.has_build |= service.has_build;
+          if !service.profiles.is_empty() {
+            existing.profiles = service.profiles;
+          }
+          extend_unique(&mut existing.depends_on, service.depends_on);
+          extend_unique(&mut existing.networks, service.networks);
+          extend_unique(&mut existing.volumes, service.volumes);
+        }
+        None => merged.services.push(service),
+      }
+    }
+  }
+
+  merged.services.sort_by(|a, b| a.name.cmp(&b.name));
+  Ok(merged)
+}
+
+/// Append the items not already in `list`, keeping the original order
+fn extend_unique(list: &mut Vec<String>, items: Vec<String>) {
+  for item in items {
+    if !list.contains(&item) {
+      list.push(item);
+    }
+  }
+}
+
+/// Resolve a registered path to a compose file. Directories are searched for the default file names.
+pub fn resolve_compose_path(path: &Path) -> Option<PathBuf> {
+  if path.is_dir() {
+    COMPOSE_FILE_NAMES
+      .iter()
+      .map(|name| path.join(name))
+      .find(|candidate| candidate.is_file())
+  } else if path.is_file() {
+    Some(path.to_path_buf())
+  } else {
+    None
+  }
+}
+
+/// Derive a project name the way `docker compose` does: lowercase, keeping `[a-z0-9_-]`
+pub fn normalize_project_name(name: &str) -> String {
+  name
+    .to_lowercase()
+    .chars()
+    .filter(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '-')
+    .collect::<String>()
+    .trim_start_matches(['_', '-'])
+    .to_string()
+}
+
+/// Split the comma-separated `config_files` label
+fn split_config_files(value: &str) -> Vec<String> {
+  value
+    .split(',')
+    .map(str::trim)
+    .filter(|s| !s.is_empty())
+    .map(String::from)
+    .collect()
+}
+
+/// Compose files loaded from disk for one project
+#[derive(Debug, Clone)]
+pub struct ComposeDefinition {
+  pub project: String,
+  pub working_dir: String,
+  pub config_files: Vec<String>,
+  pub services: Vec<ComposeServiceDefinition>,
+  pub registered_path: Option<String>,
+}
+
+impl ComposeDefinition {
+  /// Load the files a running project was started from
+  pub fn from_config_files(project: &str, working_dir: Option<&str>, config_files: &[String]) -> Result<Self> {
+    let file = load_compose_files(config_files)?;
+    let working_dir = working_dir
+      .map(String::from)
+      .or_else(|| parent_dir(config_files.first()?))
+      .unwrap_or_default();
+
+    Ok(Self {
+      project: project.to_string(),
+      working_dir,
+      config_files: config_files.to_vec(),
+      services: file.services,
+      registered_path: None,
+    })
+  }
+
+  /// Load a user-registered compose file or folder. `project_name` overrides the derived name when non-empty.
+  pub fn from_registered_path(path: &str, project_name: &str) -> Result<Self> {
+    let expanded = shellexpand_home(path);
+    let file_path = resolve_compose_path(&expanded).ok_or_else(|| anyhow!("No compose file found at {path}"))?;
+    let file_path_str = file_path.to_string_lossy().to_string();
+    let file = load_compose_files(std::slice::from_ref(&file_path_str))?;
+    let working_dir = parent_dir(&file_path_str).unwrap_or_default();
+
+    let project = if !project_name.is_empty() {
+      project_name.to_string()
+    } else if let Some(name) = file.name {
+      name
+    } else {
+      let dir_name = Path::new(&working_dir)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+      normalize_project_name(&dir_name)
+    };
+
+    Ok(Self {
+      project,
+      working_dir,
+      config_files: vec![file_path_str],
+      services: file.services,
+      registered_path: Some(path.to_string()),
+    })
+  }
+}
+
+fn parent_dir(path: &str) -> Option<String> {
+  Path::new(path).parent().map(|p| p.to_string_lossy().to_string())
+}
+
+/// Expand a leading `~` to the home directory
+fn shellexpand_home(path: &str) -> PathBuf {
+  match path.strip_prefix("~/") {
+    Some(rest) => dirs::home_dir().unwrap_or_else(|| PathBuf::from(".")).join(rest),
+    None => PathBuf::from(path),
+  }
+}
+
+/// Arguments identifying a compose project on the command line
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComposeTarget {
+  pub project: String,
+  pub working_dir: Option<String>,
+  pub config_files: Vec<String>,
+  pub env_file: Option<String>,
+  pub profiles: Vec<String>,
+}
+
+impl ComposeTarget {
+  /// Full `docker` arguments for running `subcommand` against this project
+  pub fn args(&self, subcommand: &[&str]) -> Vec<String> {
+    let mut args = vec!["compose".to_string(), "-p".to_string(), self.project.clone()];
+    for file in &self.config_files {
+      args.push("-f".to_string());
+      args.push(file.clone());
+    }
+    let working_dir = self.working_dir.as_ref().filter(|d| !d.is_empty());
+    if let Some(dir) = working_dir {
+      args.push("--project-directory".to_string());
+      args.push(dir.clone());
+    }
+    if let Some(env_file) = self.env_file.as_ref().filter(|f| !f.is_empty()) {
+      // docker compose resolves --env-file against its own cwd, not the project directory
+      let env_file = match working_dir {
+        Some(dir) if Path::new(env_file).is_relative() => Path::new(dir).join(env_file).to_string_lossy().into_owned(),
+        _ => env_file.clone(),
+      };
+      args.push("--env-file".to_string());
+      args.push(env_file);
+    }
+    for profile in &self.profiles {
+      args.push("--profile".to_string());
+      args.push(profile.clone());
+    }
+    args.extend(subcommand.iter().map(|s| (*s).to_string()));
+    args
+  }
+}
+
+/// Operations on a single service of a compose project
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComposeServiceAction {
+  /// Start the service, creating its container if needed
+  Start,
+  Stop,
+  Restart,
+  /// Recreate the container even if its configuration is unchanged
+  Recreate,
+  /// Rebuild the image and recreate the container
+  Rebuild,
+  Pull,
+  /// Run the given number of replicas
+  Scale(u32),
+}
+
+impl ComposeServiceAction {
+  /// Compose subcommand arguments for this action. Dependencies are left alone.
+  pub fn args(self, service: &str) -> Vec<String> {
+    let mut args: Vec<String> = match self {
+      ComposeServiceAction::Start => vec!["up", "-d", "--no-deps"],
+      ComposeServiceAction::Stop => vec!["stop"],
+      ComposeServiceAction::Restart => vec!["restart"],
+      ComposeServiceAction::Recreate => vec!["up", "-d", "--no-deps", "--force-recreate"],
+      ComposeServiceAction::Rebuild => vec!["up", "-d", "--no-deps", "--build"],
+      ComposeServiceAction::Pull => vec!["pull"],
+      ComposeServiceAction::Scale(_) => vec!["up", "-d", "--no-deps"],
+    }
+    .into_iter()
+    .map(String::from)
+    .collect();
+
+    if let ComposeServiceAction::Scale(replicas) = self {
+      args.push("--scale".to_string());
+      args.push(format!("{service}={replicas}"));
+    }
+    args.push(service.to_string());
+    args
+  }
+
+  /// In-progress, completed and failed verb forms for task messages
+  pub fn verbs(self) -> (&'static str, &'static str, &'static str) {
+    match self {
+      ComposeServiceAction::Start => ("Starting", "Started", "start"),
+      ComposeServiceAction::Stop => ("Stopping", "Stopped", "stop"),
+      ComposeServiceAction::Restart => ("Restarting", "Restarted", "restart"),
+      ComposeServiceAction::Recreate => ("Recreating", "Recreated", "recreate"),
+      ComposeServiceAction::Rebuild => ("Rebuilding", "Rebuilt", "rebuild"),
+      ComposeServiceAction::Pull => ("Pulling", "Pulled", "pull"),
+      ComposeServiceAction::Scale(_) => ("Scaling", "Scaled", "scale"),
+    }
+  }
+}
+
+/// One line of aggregated compose project logs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComposeLogLine {
+  /// Timestamp Docker attached to the line, if it could be parsed
+  pub timestamp: Option<DateTime<Utc>>,
+  pub service: String,
+  pub text: String,
+  pub is_stderr: bool,
+}
+
+impl ComposeLogLine {
+  /// Build from a raw line produced with `timestamps: true` (`<RFC 3339 time> <message>`)
+  pub fn from_raw(service: &str, raw: &str, is_stderr: bool) -> Self {
+    let (timestamp, text) = match raw.split_once(' ') {
+      Some((ts, rest)) => match DateTime::parse_from_rfc3339(ts) {
+        Ok(ts) => (Some(ts.with_timezone(&Utc)), rest),
+        Err(_) => (None, raw),
+      },
+      None => (None, raw),
+    };
+
+    Self {
+      timestamp,
+      service: service.to_string(),
+      text: text.to_string(),
+      is_stderr,
+    }
+  }
+}
+
+/// Insert a line keeping the buffer ordered by timestamp, dropping the oldest lines beyond `max_lines`.
+/// Lines without a timestamp are appended as they arrive.
+pub fn insert_log_line(buffer: &mut VecDeque<ComposeLogLine>, line: ComposeLogLine, max_lines: usize) {
+  let position = match line.timestamp {
+    Some(ts) => {
+      let mut idx = buffer.len();
+      while idx > 0 && buffer[idx - 1].timestamp.is_some_and(|prev| prev > ts) {
+        idx -= 1;
+      }
+      idx
+    }
+    None => buffer.len(),
+  };
+  buffer.insert(position, line);
+
+  while buffer.len() > max_lines {
+    buffer.pop_front();
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -156,6 +789,7 @@ mod tests {
           state: ContainerState::Running,
         },
       ],
+      ..Default::default()
     };
     assert_eq!(project.container_count(), 2);
   }
@@ -184,6 +818,7 @@ mod tests {
           state: ContainerState::Running,
         },
       ],
+      ..Default::default()
     };
     assert_eq!(project.running_count(), 2);
   }
@@ -207,6 +842,7 @@ mod tests {
           state: ContainerState::Running,
         },
       ],
+      ..Default::default()
     };
     assert!(all_running.is_all_running());
 
@@ -227,6 +863,7 @@ mod tests {
           state: ContainerState::Exited,
         },
       ],
+      ..Default::default()
     };
     assert!(!partial.is_all_running());
 
@@ -234,6 +871,7 @@ mod tests {
     let empty = ComposeProject {
       name: "empty".to_string(),
       services: vec![],
+      ..Default::default()
     };
     assert!(!empty.is_all_running());
   }
@@ -257,6 +895,7 @@ mod tests {
           state: ContainerState::Exited,
         },
       ],
+      ..Default::default()
     };
     assert!(all_stopped.is_all_stopped());
 
@@ -277,6 +916,7 @@ mod tests {
           state: ContainerState::Exited,
         },
       ],
+      ..Default::default()
     };
     assert!(!partial.is_all_stopped());
 
@@ -284,6 +924,7 @@ mod tests {
     let empty = ComposeProject {
       name: "empty".to_string(),
       services: vec![],
+      ..Default::default()
     };
     assert!(empty.is_all_stopped());
   }
@@ -307,6 +948,7 @@ mod tests {
           state: ContainerState::Running,
         },
       ],
+      ..Default::default()
     };
     assert_eq!(all_running.status_display(), "2/2 running");
 
@@ -327,6 +969,7 @@ mod tests {
           state: ContainerState::Exited,
         },
       ],
+      ..Default::default()
     };
     assert_eq!(all_stopped.status_display(), "0/2 stopped");
 
@@ -353,6 +996,7 @@ mod tests {
           state: ContainerState::Exited,
         },
       ],
+      ..Default::default()
     };
     assert_eq!(partial.status_display(), "1/3 running");
 
@@ -360,6 +1004,7 @@ mod tests {
     let empty = ComposeProject {
       name: "empty".to_string(),
       services: vec![],
+      ..Default::default()
     };
     assert_eq!(empty.status_display(), "0/0 stopped");
   }
@@ -587,6 +1232,7 @@ mod tests {
           state: ContainerState::Exited,
         },
       ],
+      ..Default::default()
     };
 
     // Only Running counts as running
@@ -596,4 +1242,320 @@ mod tests {
     assert!(!project.is_all_stopped());
     assert_eq!(project.status_display(), "1/4 running");
   }
+
+  // Compose file tests
+
+  const SAMPLE_COMPOSE: &str = r#"
+name: shop
+services:
+  web:
+    image: nginx:latest
+    depends_on: [api]
+  api:
+    build: ./api
+  debug:
+    image: busybox
+    profiles: ["debug", "tools"]
+"#;
+
+  #[test]
+  fn test_parse_compose_file() {
+    let file = parse_compose_file(SAMPLE_COMPOSE).expect("parse");
+    assert_eq!(file.name.as_deref(), Some("shop"));
+
+    let names: Vec<&str> = file.services.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["api", "debug", "web"]);
+    assert!(file.services[0].has_build);
+    assert_eq!(file.services[0].image, None);
+    assert_eq!(file.services[1].profiles, vec!["debug", "tools"]);
+    assert_eq!(file.services[2].image.as_deref(), Some("nginx:latest"));
+  }
+
+  #[test]
+  fn test_parse_compose_file_invalid() {
+    assert!(parse_compose_file("services: [unclosed").is_err());
+    assert!(parse_compose_file("version: '3'").expect("parse").services.is_empty());
+  }
+
+  #[test]
+  fn test_load_compose_files_override() {
+    let dir = std::env::temp_dir().join(format!("dockside-compose-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("create dir");
+    let base = dir.join("compose.yaml");
+    let overlay = dir.join("compose.override.yaml");
+    std::fs::write(&base, SAMPLE_COMPOSE).expect("write base");
+    std::fs::write(
+      &overlay,
+      "services:\n  web:\n    image: nginx:alpine\n    depends_on: [worker, api]\n  worker:\n    image: app\n",
+    )
+    .expect("write");
+
+    assert_eq!(resolve_compose_path(&dir), Some(base.clone()));
+
+    let paths = vec![
+      base.to_string_lossy().to_string(),
+      overlay.to_string_lossy().to_string(),
+    ];
+    let merged = load_compose_files(&paths).expect("load");
+    assert_eq!(merged.services.len(), 4);
+    let web = merged.services.iter().find(|s| s.name == "web").expect("web");
+    assert_eq!(web.image.as_deref(), Some("nginx:alpine"));
+    assert_eq!(web.depends_on, vec!["api", "worker"]);
+
+    let _ = std::fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn test_extract_compose_projects_reads_file_labels() {
+    let mut labels = make_compose_labels("shop", "web");
+    labels.insert(COMPOSE_WORKING_DIR_LABEL.to_string(), "/srv/shop".to_string());
+    labels.insert(
+      COMPOSE_CONFIG_FILES_LABEL.to_string(),
+      "/srv/shop/compose.yaml, /srv/shop/compose.prod.yaml".to_string(),
+    );
+    let containers = vec![make_container("shop-web-1", "nginx", ContainerState::Running, labels)];
+
+    let projects = extract_compose_projects(&containers);
+    assert_eq!(projects[0].working_dir.as_deref(), Some("/srv/shop"));
+    assert_eq!(
+      projects[0].config_files,
+      vec!["/srv/shop/compose.yaml", "/srv/shop/compose.prod.yaml"]
+    );
+  }
+
+  #[test]
+  fn test_merge_compose_definitions() {
+    let containers = vec![make_container(
+      "shop-web-1",
+      "nginx",
+      ContainerState::Running,
+      make_compose_labels("shop", "web"),
+    )];
+    let mut projects = extract_compose_projects(&containers);
+    let file = parse_compose_file(SAMPLE_COMPOSE).expect("parse");
+
+    merge_compose_definitions(
+      &mut projects,
+      vec![
+        ComposeDefinition {
+          project: "shop".to_string(),
+          working_dir: "/srv/shop".to_string(),
+          config_files: vec!["/srv/shop/compose.yaml".to_string()],
+          services: file.services.clone(),
+          registered_path: None,
+        },
+        ComposeDefinition {
+          project: "blog".to_string(),
+          working_dir: "/srv/blog".to_string(),
+          config_files: vec!["/srv/blog/compose.yaml".to_string()],
+          services: file.services,
+          registered_path: Some("/srv/blog".to_string()),
+        },
+      ],
+    );
+
+    assert_eq!(projects.len(), 2);
+    assert_eq!(projects[0].name, "blog");
+    assert_eq!(projects[0].missing_services(&[]).count(), 2);
+    assert_eq!(projects[0].missing_services(&["debug".to_string()]).count(), 3);
+    assert_eq!(projects[0].registered_path.as_deref(), Some("/srv/blog"));
+
+    let missing: Vec<&str> = projects[1].missing_services(&[]).map(|s| s.name.as_str()).collect();
+    assert_eq!(missing, vec!["api"]);
+    let missing: Vec<&str> = projects[1]
+      .missing_services(&["debug".to_string()])
+      .map(|s| s.name.as_str())
+      .collect();
+    assert_eq!(missing, vec!["api", "debug"]);
+    assert_eq!(projects[1].available_profiles(), vec!["debug", "tools"]);
+  }
+
+  #[test]
+  fn test_normalize_project_name() {
+    assert_eq!(normalize_project_name("My App"), "myapp");
+    assert_eq!(normalize_project_name("_web.site-2"), "website-2");
+  }
+
+  #[test]
+  fn test_compose_target_args() {
+    let target = ComposeTarget {
+      project: "shop".to_string(),
+      working_dir: Some("/srv/shop".to_string()),
+      config_files: vec!["/srv/shop/compose.yaml".to_string()],
+      env_file: Some(".env.prod".to_string()),
+      profiles: vec!["debug".to_string()],
+    };
+    assert_eq!(
+      target.args(&["up", "-d"]),
+      vec![
+        "compose",
+        "-p",
+        "shop",
+        "-f",
+        "/srv/shop/compose.yaml",
+        "--project-directory",
+        "/srv/shop",
+        "--env-file",
+        "/srv/shop/.env.prod",
+        "--profile",
+        "debug",
+        "up",
+        "-d"
+      ]
+    );
+
+    let bare = ComposeTarget {
+      project: "shop".to_string(),
+      env_file: Some(String::new()),
+      ..Default::default()
+    };
+    assert_eq!(bare.args(&["down"]), vec!["compose", "-p", "shop", "down"]);
+
+    let absolute_env = ComposeTarget {
+      project: "shop".to_string(),
+      working_dir: Some("/srv/shop".to_string()),
+      env_file: Some("/etc/shop.env".to_string()),
+      ..Default::default()
+    };
+    assert!(
+      absolute_env
+        .args(&["up"])
+        .windows(2)
+        .any(|w| w[0] == "--env-file" && w[1] == "/etc/shop.env")
+    );
+  }
+
+  #[test]
+  fn test_compose_service_action_args() {
+    assert_eq!(ComposeServiceAction::Stop.args("web"), vec!["stop", "web"]);
+    assert_eq!(
+      ComposeServiceAction::Recreate.args("web"),
+      vec!["up", "-d", "--no-deps", "--force-recreate", "web"]
+    );
+    assert_eq!(
+      ComposeServiceAction::Scale(3).args("worker"),
+      vec!["up", "-d", "--no-deps", "--scale", "worker=3", "worker"]
+    );
+  }
+
+  #[test]
+  fn test_compose_log_line_from_raw() {
+    let line = ComposeLogLine::from_raw("web", "2024-05-01T10:00:00.123456789Z GET / 200", false);
+    assert_eq!(line.text, "GET / 200");
+    assert_eq!(
+      line.timestamp.map(|t| t.to_rfc3339()),
+      Some("2024-05-01T10:00:00.123456789+00:00".to_string())
+    );
+
+    let untimed = ComposeLogLine::from_raw("web", "plain message", true);
+    assert_eq!(untimed.timestamp, None);
+    assert_eq!(untimed.text, "plain message");
+  }
+
+  #[test]
+  fn test_insert_log_line_merges_by_timestamp() {
+    let mut buffer = VecDeque::new();
+    insert_log_line(
+      &mut buffer,
+      ComposeLogLine::from_raw("web", "2024-05-01T10:00:02Z b", false),
+      10,
+    );
+    insert_log_line(
+      &mut buffer,
+      ComposeLogLine::from_raw("db", "2024-05-01T10:00:01Z a", false),
+      10,
+    );
+    insert_log_line(
+      &mut buffer,
+      ComposeLogLine::from_raw("db", "2024-05-01T10:00:03Z c", false),
+      10,
+    );
+    insert_log_line(&mut buffer, ComposeLogLine::from_raw("web", "no timestamp", false), 10);
+
+    let texts: Vec<&str> = buffer.iter().map(|l| l.text.as_str()).collect();
+    assert_eq!(texts, vec!["a", "b", "c", "no timestamp"]);
+
+    insert_log_line(
+      &mut buffer,
+      ComposeLogLine::from_raw("web", "2024-05-01T10:00:04Z d", false),
+      3,
+    );
+    let texts: Vec<&str> = buffer.iter().map(|l| l.text.as_str()).collect();
+    assert_eq!(texts, vec!["c", "no timestamp", "d"]);
+  }
+
+  #[test]
+  fn test_parse_compose_file_references() {
+    let file = parse_compose_file(
+      r#"
+services:
+  web:
+    image: nginx
+    depends_on:
+      api:
+        condition: service_healthy
+    networks: [front]
+    volumes:
+      - ./site:/usr/share/nginx/html
+      - static:/static
+      - type: volume
+        source: cache
+        target: /cache
+"#,
+    )
+    .expect("parse");
+
+    let web = &file.services[0];
+    assert_eq!(web.depends_on, vec!["api"]);
+    assert_eq!(web.networks, vec!["front"]);
+    assert_eq!(web.volumes, vec!["static", "cache"]);
+  }
+
+  #[test]
+  fn test_validate_compose_file() {
+    let content = "services:\n  web:\n    image: nginx\n    depends_on: [api]\n    networks: [front]\n  worker:\n    environment: {}\nnetworks:\n  back: {}\n";
+    let diagnostics = validate_compose_file(content);
+    let messages: Vec<&str> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+    assert_eq!(
+      messages,
+      vec![
+        "Service 'web' depends on undefined service 'api'",
+        "Service 'web' uses undefined network 'front'",
+        "Service 'worker' needs an 'image' or a 'build' section",
+      ]
+    );
+    assert_eq!(diagnostics[0].line, Some(2));
+    assert_eq!(diagnostics[2].line, Some(6));
+
+    let syntax = validate_compose_file("services:\n  web: [unclosed\n");
+    assert_eq!(syntax.len(), 1);
+    assert!(syntax[0].is_syntax);
+    assert!(syntax[0].line.is_some());
+
+    assert!(validate_compose_file(SAMPLE_COMPOSE).is_empty());
+  }
+
+  #[test]
+  fn test_dependency_layers() {
+    let file = parse_compose_file(
+      "services:\n  web: {image: a, depends_on: [api]}\n  api: {image: b, depends_on: [db, cache]}\n  db: {image: c}\n  cache: {image: d}\n",
+    )
+    .expect("parse");
+    assert_eq!(
+      dependency_layers(&file.services),
+      vec![vec!["cache", "db"], vec!["api"], vec!["web"]]
+    );
+
+    let cyclic = parse_compose_file("services:\n  a: {image: x, depends_on: [b]}\n  b: {image: x, depends_on: [a]}\n")
+      .expect("parse");
+    assert_eq!(dependency_layers(&cyclic.services), vec![vec!["a", "b"]]);
+  }
+
+  #[test]
+  fn test_changed_services() {
+    let old = "services:\n  web: {image: nginx:1}\n  db: {image: postgres}\n";
+    let new = "services:\n  web: {image: nginx:2}\n  db: {image: postgres}\n  cache: {image: redis}\n";
+    assert_eq!(changed_services(old, new), vec!["cache", "web"]);
+    assert!(changed_services(new, new).is_empty());
+  }
 }
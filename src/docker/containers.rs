@@ -1,6 +1,7 @@
 use anyhow::Result;
+use bollard::container::LogOutput;
 use bollard::exec::{CreateExecOptions, StartExecResults};
-use bollard::models::{ContainerCreateBody, HostConfig};
+use bollard::models::{ContainerCreateBody, ContainerSummary, HostConfig};
 use bollard::query_parameters::{
   CommitContainerOptions, CreateContainerOptions, KillContainerOptions, ListContainersOptions, LogsOptions,
   RemoveContainerOptions, RenameContainerOptions, RestartContainerOptions, StartContainerOptions, StopContainerOptions,
@@ -9,8 +10,9 @@ use chrono::{DateTime, Utc};
 use futures::stream::StreamExt;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::time::Instant;
 
-use super::DockerClient;
+use super::{DockerClient, HELPER_LABEL};
 
 /// Build Docker's exposed ports map from a set of port keys.
 /// Docker API requires empty objects as values for exposed ports.
@@ -156,6 +158,117 @@ impl ContainerFileEntry {
   }
 }
 
+/// Options for running a command inside a container
+#[derive(Debug, Clone, Default)]
+pub struct ExecOptions {
+  pub cmd: Vec<String>,
+  /// User to run as (`user`, `user:group`, `uid` or `uid:gid`)
+  pub user: Option<String>,
+  pub working_dir: Option<String>,
+  pub env: Vec<(String, String)>,
+  /// Data written to the command's stdin before it is closed
+  pub stdin: Option<String>,
+}
+
+impl ExecOptions {
+  /// Run a command line through `/bin/sh -c`
+  pub fn shell(command: &str) -> Self {
+    Self {
+      cmd: vec!["/bin/sh".to_string(), "-c".to_string(), command.to_string()],
+      ..Default::default()
+    }
+  }
+}
+
+/// Structured result of a command executed inside a container
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecResult {
+  pub stdout: String,
+  pub stderr: String,
+  /// Exit code reported by Docker (None if the daemon did not report one)
+  pub exit_code: Option<i64>,
+  pub duration_ms: u64,
+}
+
+impl ExecResult {
+  pub fn is_success(&self) -> bool {
+    self.exit_code == Some(0)
+  }
+
+  pub fn display_exit_code(&self) -> String {
+    self.exit_code.map_or_else(|| "?".to_string(), |code| code.to_string())
+  }
+
+  #[allow(clippy::cast_precision_loss)]
+  pub fn display_duration(&self) -> String {
+    if self.duration_ms < 1000 {
+      format!("{}ms", self.duration_ms)
+    } else {
+      format!("{:.1}s", self.duration_ms as f64 / 1000.0)
+    }
+  }
+}
+
+/// Parse environment variables, one `KEY=VALUE` per line. Values are taken verbatim,
+/// so they may contain spaces, commas and further `=` signs.
+pub fn parse_env_pairs(input: &str) -> Vec<(String, String)> {
+  input
+    .lines()
+    .filter_map(|line| {
+      let (key, value) = line.trim_end_matches('\r').split_once('=')?;
+      let key = key.trim();
+      if key.is_empty() {
+        None
+      } else {
+        Some((key.to_string(), value.to_string()))
+      }
+    })
+    .collect()
+}
+
+/// Convert a container summary into a `ContainerInfo`, skipping the pooled helper
+/// containers Dockside runs for its own probe, volume and network operations.
+fn container_info(container: ContainerSummary) -> Option<ContainerInfo> {
+  if container.labels.as_ref().is_some_and(|l| l.contains_key(HELPER_LABEL)) {
+    return None;
+  }
+
+  let id = container.id.unwrap_or_default();
+  let names = container.names.unwrap_or_default();
+  let name = names
+    .first()
+    .map_or_else(|| id.clone(), |n| n.trim_start_matches('/').to_string());
+
+  let ports = container
+    .ports
+    .unwrap_or_default()
+    .into_iter()
+    .map(|p| PortMapping {
+      private_port: p.private_port,
+      public_port: p.public_port,
+      protocol: p.typ.map_or_else(|| "tcp".to_string(), |t| t.to_string()),
+      ip: p.ip,
+    })
+    .collect();
+
+  let created = container.created.and_then(|ts| DateTime::from_timestamp(ts, 0));
+
+  Some(ContainerInfo {
+    id,
+    name,
+    image: container.image.unwrap_or_default(),
+    image_id: container.image_id.unwrap_or_default(),
+    state: ContainerState::from_str(&container.state.map(|s| format!("{s:?}")).unwrap_or_default()),
+    status: container.status.unwrap_or_default(),
+    created,
+    ports,
+    labels: container.labels.unwrap_or_default(),
+    command: container.command,
+    size_rw: container.size_rw,
+    size_root_fs: container.size_root_fs,
+  })
+}
+
 impl DockerClient {
   pub async fn list_containers(&self, all: bool) -> Result<Vec<ContainerInfo>> {
     let docker = self.client()?;
@@ -167,44 +280,7 @@ impl DockerClient {
 
     let containers = docker.list_containers(Some(options)).await?;
 
-    let mut result = Vec::new();
-    for container in containers {
-      let id = container.id.unwrap_or_default();
-      let names = container.names.unwrap_or_default();
-      let name = names
-        .first()
-        .map_or_else(|| id.clone(), |n| n.trim_start_matches('/').to_string());
-
-      let ports = container
-        .ports
-        .unwrap_or_default()
-        .into_iter()
-        .map(|p| PortMapping {
-          private_port: p.private_port,
-          public_port: p.public_port,
-          protocol: p.typ.map_or_else(|| "tcp".to_string(), |t| t.to_string()),
-          ip: p.ip,
-        })
-        .collect();
-
-      let created = container.created.and_then(|ts| DateTime::from_timestamp(ts, 0));
-
-      result.push(ContainerInfo {
-        id,
-        name,
-        image: container.image.unwrap_or_default(),
-        image_id: container.image_id.unwrap_or_default(),
-        state: ContainerState::from_str(&container.state.map(|s| format!("{s:?}")).unwrap_or_default()),
-        status: container.status.unwrap_or_default(),
-        created,
-        ports,
-        labels: container.labels.unwrap_or_default(),
-        command: container.command,
-        size_rw: container.size_rw,
-        size_root_fs: container.size_root_fs,
-      });
-    }
-
+    let mut result: Vec<ContainerInfo> = containers.into_iter().filter_map(container_info).collect();
     result.sort_by(|a, b| a.name.cmp(&b.name));
     Ok(result)
   }
@@ -471,6 +547,43 @@ impl DockerClient {
     Ok(logs)
   }
 
+  /// Follow a container's logs with timestamps until it stops, calling `on_line(line, is_stderr)` for each line.
+  /// Starts `tail` lines back, or at `since` (unix seconds) when given.
+  pub async fn follow_logs(
+    &self,
+    id: &str,
+    tail: usize,
+    since: Option<i64>,
+    mut on_line: impl FnMut(&str, bool),
+  ) -> Result<()> {
+    let docker = self.client()?;
+
+    let options = LogsOptions {
+      follow: true,
+      stdout: true,
+      stderr: true,
+      timestamps: true,
+      since: since.and_then(|s| i32::try_from(s).ok()).unwrap_or_default(),
+      tail: if since.is_some() {
+        "all".to_string()
+      } else {
+        tail.to_string()
+      },
+      ..Default::default()
+    };
+
+    let mut stream = docker.logs(id, Some(options));
+    while let Some(result) = stream.next().await {
+      let output = result.map_err(|e| anyhow::anyhow!("Failed to follow logs: {e}"))?;
+      let is_stderr = matches!(output, LogOutput::StdErr { .. });
+      for line in output.to_string().lines() {
+        on_line(line, is_stderr);
+      }
+    }
+
+    Ok(())
+  }
+
   /// Inspect a container and return JSON
   pub async fn inspect_container(&self, id: &str) -> Result<String> {
     use bollard::query_parameters::InspectContainerOptions;
@@ -508,6 +621,76 @@ impl DockerClient {
     Ok(result)
   }
 
+  /// Execute a command in a container, keeping stdout, stderr and the exit code apart
+  pub async fn exec(&self, id: &str, options: ExecOptions) -> Result<ExecResult> {
+    use tokio::io::AsyncWriteExt;
+
+    let docker = self.client()?;
+    let started = Instant::now();
+
+    let env: Option<Vec<String>> = if options.env.is_empty() {
+      None
+    } else {
+      Some(options.env.iter().map(|(k, v)| format!("{k}={v}")).collect())
+    };
+
+    let exec = docker
+      .create_exec(
+        id,
+        CreateExecOptions {
+          attach_stdin: Some(options.stdin.is_some()),
+          attach_stdout: Some(true),
+          attach_stderr: Some(true),
+          cmd: Some(options.cmd),
+          env,
+          user: options.user.filter(|u| !u.is_empty()),
+          working_dir: options.working_dir.filter(|w| !w.is_empty()),
+          ..Default::default()
+        },
+      )
+      .await?;
+
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+
+    if let StartExecResults::Attached { mut output, mut input } = docker.start_exec(&exec.id, None).await? {
+      // Feed stdin while output is read, otherwise a command that writes a lot
+      // before consuming its input blocks once the output buffer fills
+      let writer = options.stdin.map(|data| {
+        tokio::spawn(async move {
+          input.write_all(data.as_bytes()).await?;
+          input.shutdown().await
+        })
+      });
+
+      while let Some(msg) = output.next().await {
+        match msg? {
+          LogOutput::StdErr { message } => stderr.push_str(&String::from_utf8_lossy(&message)),
+          LogOutput::StdOut { message } | LogOutput::Console { message } => {
+            stdout.push_str(&String::from_utf8_lossy(&message));
+          }
+          LogOutput::StdIn { .. } => {}
+        }
+      }
+
+      // The command may exit without reading all of its input; that is not an exec failure
+      if let Some(writer) = writer
+        && let Ok(Err(e)) = writer.await
+      {
+        tracing::debug!("Exec stdin closed early: {e}");
+      }
+    }
+
+    let inspect = docker.inspect_exec(&exec.id).await?;
+
+    Ok(ExecResult {
+      stdout,
+      stderr,
+      exit_code: inspect.exit_code,
+      duration_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+    })
+  }
+
   /// List files in a container directory
   pub async fn list_container_files(&self, id: &str, path: &str) -> Result<Vec<ContainerFileEntry>> {
     // Use ls -la with specific format for parsing
@@ -865,6 +1048,63 @@ mod tests {
     assert_eq!(empty_file.display_size(), "0 B");
   }
 
+  #[test]
+  fn test_exec_options_shell() {
+    let options = ExecOptions::shell("php artisan migrate && php artisan cache:clear");
+    assert_eq!(
+      options.cmd,
+      vec!["/bin/sh", "-c", "php artisan migrate && php artisan cache:clear"]
+    );
+    assert!(options.user.is_none());
+    assert!(options.working_dir.is_none());
+    assert!(options.env.is_empty());
+    assert!(options.stdin.is_none());
+  }
+
+  #[test]
+  fn test_exec_result_status_and_display() {
+    let ok = ExecResult {
+      stdout: "done\n".to_string(),
+      stderr: String::new(),
+      exit_code: Some(0),
+      duration_ms: 250,
+    };
+    assert!(ok.is_success());
+    assert_eq!(ok.display_exit_code(), "0");
+    assert_eq!(ok.display_duration(), "250ms");
+
+    let failed = ExecResult {
+      exit_code: Some(127),
+      duration_ms: 1500,
+      ..ok.clone()
+    };
+    assert!(!failed.is_success());
+    assert_eq!(failed.display_exit_code(), "127");
+    assert_eq!(failed.display_duration(), "1.5s");
+
+    let unknown = ExecResult { exit_code: None, ..ok };
+    assert!(!unknown.is_success());
+    assert_eq!(unknown.display_exit_code(), "?");
+  }
+
+  #[test]
+  fn test_parse_env_pairs() {
+    let env =
+      parse_env_pairs("APP_ENV=test\r\n  DEBUG=1\nEMPTY=\n=skipped\nnovalue\nGREETING=x y\nLIST=a,b\nQUERY=a=b");
+    assert_eq!(
+      env,
+      vec![
+        ("APP_ENV".to_string(), "test".to_string()),
+        ("DEBUG".to_string(), "1".to_string()),
+        ("EMPTY".to_string(), String::new()),
+        ("GREETING".to_string(), "x y".to_string()),
+        ("LIST".to_string(), "a,b".to_string()),
+        ("QUERY".to_string(), "a=b".to_string()),
+      ]
+    );
+    assert!(parse_env_pairs("").is_empty());
+  }
+
   #[test]
   fn test_container_file_entry_symlink_shows_size() {
     // Symlinks are files, should show size not "-"
@@ -878,4 +1118,38 @@ mod tests {
     };
     assert_eq!(symlink.display_size(), "42 B");
   }
+
+  #[test]
+  fn test_container_info_skips_helper_containers() {
+    let app = ContainerSummary {
+      id: Some("abc123".to_string()),
+      names: Some(vec!["/web".to_string()]),
+      image: Some("nginx:latest".to_string()),
+      labels: Some(HashMap::from([(
+        "com.docker.compose.project".to_string(),
+        "shop".to_string(),
+      )])),
+      ..Default::default()
+    };
+    let info = container_info(app).expect("regular container is listed");
+    assert_eq!(info.name, "web");
+    assert_eq!(info.image, "nginx:latest");
+
+    let helper = ContainerSummary {
+      id: Some("def456".to_string()),
+      names: Some(vec!["/dockside-helper-1".to_string()]),
+      labels: Some(HashMap::from([(HELPER_LABEL.to_string(), "volume".to_string())])),
+      ..Default::default()
+    };
+    assert!(container_info(helper).is_none());
+
+    let unlabeled = ContainerSummary {
+      id: Some("0123456789abcdef".to_string()),
+      ..Default::default()
+    };
+    assert_eq!(
+      container_info(unlabeled).map(|c| c.name),
+      Some("0123456789abcdef".to_string())
+    );
+  }
 }
@@ -0,0 +1,657 @@
+//! Network diagnostics run from a container's point of view
+//!
+//! Probes run in a temporary helper that joins the source container's network
+//! namespace, so they see the same DNS, routes and addresses even when the
+//! container's own image has no networking tools.
+
+use anyhow::{Result, bail};
+
+use super::{DockerClient, ExecOptions, ExecResult};
+
+/// Seconds each connection attempt may take
+const PROBE_TIMEOUT_SECS: u32 = 5;
+/// Echo requests sent by the ping probe
+const PING_COUNT: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticProbe {
+  Dns,
+  Tcp,
+  Http,
+  Ping,
+}
+
+impl DiagnosticProbe {
+  pub const ALL: [DiagnosticProbe; 4] = [
+    DiagnosticProbe::Dns,
+    DiagnosticProbe::Tcp,
+    DiagnosticProbe::Http,
+    DiagnosticProbe::Ping,
+  ];
+
+  pub fn label(self) -> &'static str {
+    match self {
+      DiagnosticProbe::Dns => "DNS",
+      DiagnosticProbe::Tcp => "TCP",
+      DiagnosticProbe::Http => "HTTP",
+      DiagnosticProbe::Ping => "Ping",
+    }
+  }
+
+  fn tool(self) -> &'static str {
+    match self {
+      DiagnosticProbe::Dns => "nslookup",
+      DiagnosticProbe::Tcp => "nc",
+      DiagnosticProbe::Http => "wget",
+      DiagnosticProbe::Ping => "ping",
+    }
+  }
+
+  /// Command line for the probe, passed to exec without a shell
+  fn command(self, target: &DiagnosticTarget) -> Result<Vec<String>> {
+    let timeout = PROBE_TIMEOUT_SECS.to_string();
+    let args: Vec<String> = match self {
+      DiagnosticProbe::Dns => vec![target.host.clone()],
+      DiagnosticProbe::Tcp => {
+        let Some(port) = target.port else {
+          bail!("TCP needs a port, e.g. {}:5432", target.host);
+        };
+        vec![
+          "-z".into(),
+          "-v".into(),
+          "-w".into(),
+          timeout,
+          target.host.clone(),
+          port.to_string(),
+        ]
+      }
+      DiagnosticProbe::Http => vec![
+        "-S".into(),
+        "-O".into(),
+        "/dev/null".into(),
+        "-T".into(),
+        timeout,
+        target.url(),
+      ],
+      DiagnosticProbe::Ping => vec![
+        "-c".into(),
+        PING_COUNT.to_string(),
+        "-W".into(),
+        "2".into(),
+        target.host.clone(),
+      ],
+    };
+    Ok(std::iter::once(self.tool().to_string()).chain(args).collect())
+  }
+}
+
+/// Host to probe, with the optional port, path and scheme used by the TCP and HTTP probes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticTarget {
+  pub host: String,
+  pub port: Option<u16>,
+  /// Request path for HTTP, starting with `/`
+  pub path: String,
+  pub https: bool,
+}
+
+impl DiagnosticTarget {
+  /// Parse `db`, `db:5432`, `[fd00::2]:80` or `http://api:8080/health`
+  pub fn parse(input: &str) -> Result<Self> {
+    let input = input.trim();
+    let (https, rest) = if let Some(rest) = input.strip_prefix("https://") {
+      (true, rest)
+    } else {
+      (false, input.strip_prefix("http://").unwrap_or(input))
+    };
+    let (authority, path) = match rest.find('/') {
+      Some(i) => (&rest[..i], &rest[i..]),
+      None => (rest, "/"),
+    };
+
+    let (host, port) = if let Some(bracketed) = authority.strip_prefix('[') {
+      let Some((host, after)) = bracketed.split_once(']') else {
+        bail!("Missing ] in {authority}");
+      };
+      let port = match after.strip_prefix(':') {
+        Some(port) => Some(parse_port(port)?),
+        None if after.is_empty() => None,
+        None => bail!("Unexpected {after} after {host}"),
+      };
+      (host, port)
+    } else {
+      match authority.split_once(':') {
+        // A bare IPv6 address has several colons and no port
+        Some((host, port)) if !port.contains(':') => (host, Some(parse_port(port)?)),
+        _ => (authority, None),
+      }
+    };
+
+    if host.is_empty() {
+      bail!("Enter a host name or address");
+    }
+    // Arguments are passed without a shell, but a leading dash would still read as an option
+    if host.starts_with('-') || host.chars().any(char::is_whitespace) {
+      bail!("Invalid host: {host}");
+    }
+
+    Ok(Self {
+      host: host.to_string(),
+      port,
+      path: path.to_string(),
+      https,
+    })
+  }
+
+  /// URL for the HTTP probe, defaulting to plain HTTP on the target port
+  pub fn url(&self) -> String {
+    let scheme = if self.https { "https" } else { "http" };
+    let host = if self.host.contains(':') {
+      format!("[{}]", self.host)
+    } else {
+      self.host.clone()
+    };
+    match self.port {
+      Some(port) => format!("{scheme}://{host}:{port}{}", self.path),
+      None => format!("{scheme}://{host}{}", self.path),
+    }
+  }
+}
+
+fn parse_port(port: &str) -> Result<u16> {
+  match port.parse::<u16>() {
+    Ok(port) if port > 0 => Ok(port),
+    _ => bail!("Invalid port: {port}"),
+  }
+}
+
+/// Outcome of one probe, with a plain-language reading of the raw output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticResult {
+  pub probe: DiagnosticProbe,
+  /// Command as run, empty if the probe could not start
+  pub command: String,
+  pub success: bool,
+  /// One-line outcome, e.g. `Resolves to 172.20.0.3`
+  pub summary: String,
+  /// What the outcome means and what to check next
+  pub explanation: String,
+  /// Raw stdout and stderr
+  pub output: String,
+  pub duration_ms: u64,
+}
+
+impl DiagnosticResult {
+  fn failed(probe: DiagnosticProbe, summary: impl Into<String>, explanation: impl Into<String>) -> Self {
+    Self {
+      probe,
+      command: String::new(),
+      success: false,
+      summary: summary.into(),
+      explanation: explanation.into(),
+      output: String::new(),
+      duration_ms: 0,
+    }
+  }
+
+  /// Read the exec result of a probe
+  pub fn interpret(probe: DiagnosticProbe, target: &DiagnosticTarget, result: &ExecResult) -> Self {
+    let output = [result.stdout.trim_end(), result.stderr.trim_end()]
+      .into_iter()
+      .filter(|s| !s.is_empty())
+      .collect::<Vec<_>>()
+      .join("\n");
+    let lower = output.to_lowercase();
+
+    let (success, summary, explanation) = if result.exit_code == Some(127)
+      || lower.contains(&format!("{}: not found", probe.tool()))
+      || lower.contains("executable file not found")
+    {
+      (
+        false,
+        format!("{} is not available", probe.tool()),
+        format!(
+          "The helper image has no {}. Set a helper image that includes networking tools, such as alpine, in Settings.",
+          probe.tool()
+        ),
+      )
+    } else {
+      match probe {
+        DiagnosticProbe::Dns => interpret_dns(target, &output, result.is_success()),
+        DiagnosticProbe::Tcp => interpret_tcp(target, &lower, result.is_success()),
+        DiagnosticProbe::Http => interpret_http(target, &output, result.is_success()),
+        DiagnosticProbe::Ping => interpret_ping(target, &output),
+      }
+    };
+
+    Self {
+      probe,
+      command: probe.command(target).map(|c| c.join(" ")).unwrap_or_default(),
+      success,
+      summary,
+      explanation,
+      output,
+      duration_ms: result.duration_ms,
+    }
+  }
+}
+
+type Reading = (bool, String, String);
+
+fn interpret_dns(target: &DiagnosticTarget, output: &str, exited_ok: bool) -> Reading {
+  let (server, addresses) = parse_nslookup(output);
+  let lower = output.to_lowercase();
+  let host = &target.host;
+
+  if !addresses.is_empty() {
+    let via = match server.as_deref() {
+      Some("127.0.0.11") => " through Docker's embedded DNS server".to_string(),
+      Some(server) => format!(" through {server}"),
+      None => String::new(),
+    };
+    return (
+      true,
+      format!("Resolves to {}", addresses.join(", ")),
+      format!("{host} resolves{via}. If the connection still fails, try the TCP probe on the service port."),
+    );
+  }
+  if lower.contains("timed out") || lower.contains("no servers could be reached") {
+    return (
+      false,
+      "DNS server did not answer".to_string(),
+      "The container can't reach its DNS server. Check the daemon's DNS settings, and whether the network is internal \
+       while the name is external."
+        .to_string(),
+    );
+  }
+  if lower.contains("nxdomain") || lower.contains("can't find") || lower.contains("can't resolve") || !exited_ok {
+    return (
+      false,
+      format!("{host} does not resolve"),
+      "Containers resolve each other by name or alias only on a shared user-defined network; the default bridge \
+       has no name resolution. Check that both containers are connected to the same network and the name is spelled \
+       right."
+        .to_string(),
+    );
+  }
+  (
+    false,
+    "No addresses returned".to_string(),
+    format!("The DNS server knows {host} but returned no address records for it."),
+  )
+}
+
+/// DNS server and resolved addresses from `nslookup` output (busybox and bind formats)
+fn parse_nslookup(output: &str) -> (Option<String>, Vec<String>) {
+  let mut server = None;
+  let mut addresses: Vec<String> = Vec::new();
+  let mut in_answer = false;
+
+  for line in output.lines().map(str::trim) {
+    if let Some(value) = line.strip_prefix("Server:") {
+      server = Some(value.trim().to_string());
+    } else if line.starts_with("Name:") {
+      in_answer = true;
+    } else if in_answer
+      && line.starts_with("Address")
+      && let Some((_, value)) = line.split_once(':')
+      && let Some(address) = value.split_whitespace().next()
+      && !addresses.iter().any(|a| a == address)
+    {
+      addresses.push(address.to_string());
+    }
+  }
+  (server, addresses)
+}
+
+/// Common reasons a connection attempt fails, from the tool's lowercased output
+fn connection_failure(target: &DiagnosticTarget, lower: &str) -> Reading {
+  let host = &target.host;
+  let port = target.port.unwrap_or(if target.https { 443 } else { 80 });
+
+  if lower.contains("bad address") || lower.contains("unable to resolve") || lower.contains("name or service not known")
+  {
+    return (
+      false,
+      format!("{host} does not resolve"),
+      "The name could not be resolved, so no connection was attempted. Run the DNS probe for details.".to_string(),
+    );
+  }
+  if lower.contains("refused") {
+    return (
+      false,
+      "Connection refused".to_string(),
+      format!(
+        "{host} is reachable but nothing accepts connections on port {port}. The service may still be starting, \
+         listen on another port, or be bound to 127.0.0.1 instead of 0.0.0.0 inside its container."
+      ),
+    );
+  }
+  if lower.contains("no route") || lower.contains("unreachable") {
+    return (
+      false,
+      "No route to host".to_string(),
+      format!("This container has no route to {host}. Connect both containers to a shared network."),
+    );
+  }
+  (
+    false,
+    "No answer".to_string(),
+    format!(
+      "The connection to {host}:{port} timed out. Packets are being dropped, usually by a firewall, or the address \
+       belongs to a network this container isn't connected to."
+    ),
+  )
+}
+
+fn interpret_tcp(target: &DiagnosticTarget, lower: &str, exited_ok: bool) -> Reading {
+  if exited_ok {
+    let port = target.port.unwrap_or_default();
+    return (
+      true,
+      format!("Port {port} is open"),
+      format!(
+        "A TCP connection to {}:{port} succeeded, so the network path is fine. If the application still fails, check \
+         its protocol settings and logs.",
+        target.host
+      ),
+    );
+  }
+  connection_failure(target, lower)
+}
+
+fn interpret_http(target: &DiagnosticTarget, output: &str, exited_ok: bool) -> Reading {
+  let Some((code, status)) = parse_http_status(output) else {
+    let lower = output.to_lowercase();
+    if lower.contains("ssl") || lower.contains("tls") {
+      return (
+        false,
+        "TLS handshake failed".to_string(),
+        "The port answered but the TLS handshake failed. Check whether the service expects plain HTTP, or whether \
+         its certificate is valid."
+          .to_string(),
+      );
+    }
+    if exited_ok {
+      return (
+        true,
+        "Request succeeded".to_string(),
+        "The server answered but reported no status line.".to_string(),
+      );
+    }
+    return connection_failure(target, &lower);
+  };
+
+  let explanation = match code {
+    200..=399 => format!(
+      "{} answered {status}, so both the network and the server are working.",
+      target.url()
+    ),
+    401 | 403 => {
+      "The server answered but refused the request. Networking works; the request needs credentials.".to_string()
+    }
+    404 => format!(
+      "The server answered but has nothing at {}. Networking works; check the path.",
+      target.path
+    ),
+    400..=499 => "The server answered but rejected the request. Networking works; check the request.".to_string(),
+    _ => "The server answered with an error. Networking works; check the target's logs.".to_string(),
+  };
+  ((200..400).contains(&code), status, explanation)
+}
+
+/// Last HTTP status in `wget -S` output, e.g. `(404, "HTTP 404 Not Found")`. Redirects print several.
+fn parse_http_status(output: &str) -> Option<(u16, String)> {
+  output.lines().rev().find_map(|line| {
+    let start = line.find("HTTP/")?;
+    let mut parts = line[start..].split_whitespace().skip(1);
+    let code: u16 = parts.next()?.parse().ok()?;
+    let reason = parts.collect::<Vec<_>>().join(" ");
+    let status = if reason.is_empty() {
+      format!("HTTP {code}")
+    } else {
+      format!("HTTP {code} {reason}")
+    };
+    Some((code, status))
+  })
+}
+
+fn interpret_ping(target: &DiagnosticTarget, output: &str) -> Reading {
+  let lower = output.to_lowercase();
+  let host = &target.host;
+  let Some(loss) = parse_ping_loss(output) else {
+    return connection_failure(target, &lower);
+  };
+
+  let average = parse_ping_average(output)
+    .map(|avg| format!(", avg {avg} ms"))
+    .unwrap_or_default();
+  if loss == 0 {
+    (
+      true,
+      format!("0% loss{average}"),
+      format!("{host} answers ping, so it is reachable at the IP level."),
+    )
+  } else if loss < 100 {
+    (
+      true,
+      format!("{loss}% loss{average}"),
+      format!("{host} is reachable but some packets were lost; the link may be congested or flaky."),
+    )
+  } else {
+    (
+      false,
+      "No replies".to_string(),
+      format!(
+        "{host} did not answer ping. Many hosts and firewalls drop ICMP, so try the TCP probe before concluding it \
+         is down."
+      ),
+    )
+  }
+}
+
+/// Packet loss percentage from the ping summary line
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn parse_ping_loss(output: &str) -> Option<u32> {
+  output.lines().find_map(|line| {
+    let end = line.find("% packet loss")?;
+    let start = line[..end]
+      .rfind(|c: char| !c.is_ascii_digit() && c != '.')
+      .map_or(0, |i| i + 1);
+    line[start..end].parse::<f64>().ok().map(|loss| loss.round() as u32)
+  })
+}
+
+/// Average round trip from `round-trip min/avg/max = 0.1/0.2/0.3 ms` (or the iputils `rtt` line)
+fn parse_ping_average(output: &str) -> Option<String> {
+  output.lines().find_map(|line| {
+    if !line.contains("min/avg/max") {
+      return None;
+    }
+    let (_, values) = line.split_once('=')?;
+    values.trim().split('/').nth(1).map(|avg| avg.trim().to_string())
+  })
+}
+
+impl DockerClient {
+  /// Run probes against a target from inside a running container's network namespace
+  pub async fn run_diagnostics(
+    &self,
+    container: &str,
+    target: &DiagnosticTarget,
+    probes: &[DiagnosticProbe],
+  ) -> Result<Vec<DiagnosticResult>> {
+    let helper = self.network_helper(container).await?;
+
+    let mut results = Vec::with_capacity(probes.len());
+    for &probe in probes {
+      let cmd = match probe.command(target) {
+        Ok(cmd) => cmd,
+        Err(e) => {
+          results.push(DiagnosticResult::failed(probe, "Not run", e.to_string()));
+          continue;
+        }
+      };
+      let options = ExecOptions {
+        cmd,
+        ..Default::default()
+      };
+      results.push(match self.exec(&helper, options).await {
+        Ok(result) => DiagnosticResult::interpret(probe, target, &result),
+        Err(e) => DiagnosticResult::failed(probe, "Probe failed to run", e.to_string()),
+      });
+    }
+
+    self.remove_helper(&helper).await;
+    Ok(results)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn exec_result(exit_code: i64, stdout: &str, stderr: &str) -> ExecResult {
+    ExecResult {
+      stdout: stdout.to_string(),
+      stderr: stderr.to_string(),
+      exit_code: Some(exit_code),
+      duration_ms: 12,
+    }
+  }
+
+  #[test]
+  fn test_diagnostic_target_parse() {
+    let target = DiagnosticTarget::parse("db").unwrap();
+    assert_eq!(target.host, "db");
+    assert_eq!(target.port, None);
+    assert_eq!(target.url(), "http://db/");
+
+    let target = DiagnosticTarget::parse("db:5432").unwrap();
+    assert_eq!(target.port, Some(5432));
+
+    let target = DiagnosticTarget::parse("https://api:8443/health?full=1").unwrap();
+    assert!(target.https);
+    assert_eq!(target.host, "api");
+    assert_eq!(target.path, "/health?full=1");
+    assert_eq!(target.url(), "https://api:8443/health?full=1");
+
+    let target = DiagnosticTarget::parse("[fd00::2]:80").unwrap();
+    assert_eq!(target.host, "fd00::2");
+    assert_eq!(target.url(), "http://[fd00::2]:80/");
+    assert_eq!(DiagnosticTarget::parse("fd00::2").unwrap().port, None);
+
+    assert!(DiagnosticTarget::parse("").is_err());
+    assert!(DiagnosticTarget::parse("db:0").is_err());
+    assert!(DiagnosticTarget::parse("db:http").is_err());
+    assert!(DiagnosticTarget::parse("-v").is_err());
+  }
+
+  #[test]
+  fn test_probe_commands() {
+    let target = DiagnosticTarget::parse("db").unwrap();
+    assert!(DiagnosticProbe::Tcp.command(&target).is_err());
+    assert_eq!(DiagnosticProbe::Dns.command(&target).unwrap(), vec!["nslookup", "db"]);
+
+    let target = DiagnosticTarget::parse("db:5432").unwrap();
+    assert_eq!(
+      DiagnosticProbe::Tcp.command(&target).unwrap(),
+      vec!["nc", "-z", "-v", "-w", "5", "db", "5432"]
+    );
+  }
+
+  #[test]
+  fn test_interpret_dns() {
+    let target = DiagnosticTarget::parse("db").unwrap();
+    let output = "Server:\t\t127.0.0.11\nAddress:\t127.0.0.11:53\n\nNon-authoritative answer:\nName:\tdb\n\
+                  Address: 172.20.0.3\n\nNon-authoritative answer:\n*** Can't find db: No answer\n";
+    let result = DiagnosticResult::interpret(DiagnosticProbe::Dns, &target, &exec_result(0, output, ""));
+    assert!(result.success);
+    assert_eq!(result.summary, "Resolves to 172.20.0.3");
+    assert!(result.explanation.contains("embedded DNS"));
+
+    let output = "Server:\t\t127.0.0.11\nAddress:\t127.0.0.11:53\n\n** server can't find db: NXDOMAIN\n";
+    let result = DiagnosticResult::interpret(DiagnosticProbe::Dns, &target, &exec_result(1, output, ""));
+    assert!(!result.success);
+    assert_eq!(result.summary, "db does not resolve");
+
+    let result = DiagnosticResult::interpret(
+      DiagnosticProbe::Dns,
+      &target,
+      &exec_result(127, "", "sh: nslookup: not found"),
+    );
+    assert_eq!(result.summary, "nslookup is not available");
+  }
+
+  #[test]
+  fn test_interpret_tcp() {
+    let target = DiagnosticTarget::parse("db:5432").unwrap();
+    let result = DiagnosticResult::interpret(
+      DiagnosticProbe::Tcp,
+      &target,
+      &exec_result(0, "", "db (172.20.0.3:5432) open"),
+    );
+    assert!(result.success);
+    assert_eq!(result.summary, "Port 5432 is open");
+    assert_eq!(result.command, "nc -z -v -w 5 db 5432");
+
+    let refused = "nc: db (172.20.0.3:5432): Connection refused";
+    let result = DiagnosticResult::interpret(DiagnosticProbe::Tcp, &target, &exec_result(1, "", refused));
+    assert_eq!(result.summary, "Connection refused");
+    assert!(result.explanation.contains("0.0.0.0"));
+
+    let result = DiagnosticResult::interpret(
+      DiagnosticProbe::Tcp,
+      &target,
+      &exec_result(1, "", "nc: bad address 'db'"),
+    );
+    assert_eq!(result.summary, "db does not resolve");
+
+    let result = DiagnosticResult::interpret(DiagnosticProbe::Tcp, &target, &exec_result(1, "", ""));
+    assert_eq!(result.summary, "No answer");
+  }
+
+  #[test]
+  fn test_interpret_http() {
+    let target = DiagnosticTarget::parse("api:8080/health").unwrap();
+    let ok = "Connecting to api:8080 (172.20.0.4:8080)\n  HTTP/1.1 301 Moved Permanently\n  Location: /health/\n  \
+              HTTP/1.1 200 OK\n  Content-Type: text/plain\n";
+    let result = DiagnosticResult::interpret(DiagnosticProbe::Http, &target, &exec_result(0, "", ok));
+    assert!(result.success);
+    assert_eq!(result.summary, "HTTP 200 OK");
+
+    let missing = "Connecting to api:8080 (172.20.0.4:8080)\nwget: server returned error: HTTP/1.1 404 Not Found";
+    let result = DiagnosticResult::interpret(DiagnosticProbe::Http, &target, &exec_result(1, "", missing));
+    assert!(!result.success);
+    assert_eq!(result.summary, "HTTP 404 Not Found");
+    assert!(result.explanation.contains("/health"));
+
+    let refused = "Connecting to api:8080 (172.20.0.4:8080)\nwget: can't connect to remote host (172.20.0.4): \
+                   Connection refused";
+    let result = DiagnosticResult::interpret(DiagnosticProbe::Http, &target, &exec_result(1, "", refused));
+    assert_eq!(result.summary, "Connection refused");
+    assert!(result.explanation.contains("port 8080"));
+  }
+
+  #[test]
+  fn test_interpret_ping() {
+    let target = DiagnosticTarget::parse("db").unwrap();
+    let ok = "PING db (172.20.0.3): 56 data bytes\n64 bytes from 172.20.0.3: seq=0 ttl=64 time=0.101 ms\n\n\
+              --- db ping statistics ---\n3 packets transmitted, 3 packets received, 0% packet loss\n\
+              round-trip min/avg/max = 0.081/0.101/0.123 ms\n";
+    let result = DiagnosticResult::interpret(DiagnosticProbe::Ping, &target, &exec_result(0, ok, ""));
+    assert!(result.success);
+    assert_eq!(result.summary, "0% loss, avg 0.101 ms");
+
+    let lost = "--- db ping statistics ---\n3 packets transmitted, 0 packets received, 100% packet loss\n";
+    let result = DiagnosticResult::interpret(DiagnosticProbe::Ping, &target, &exec_result(1, lost, ""));
+    assert!(!result.success);
+    assert_eq!(result.summary, "No replies");
+
+    let partial = "3 packets transmitted, 2 received, 33.3333% packet loss, time 2003ms\n\
+                   rtt min/avg/max/mdev = 0.050/0.060/0.070/0.010 ms\n";
+    let result = DiagnosticResult::interpret(DiagnosticProbe::Ping, &target, &exec_result(1, partial, ""));
+    assert!(result.success);
+    assert_eq!(result.summary, "33% loss, avg 0.060 ms");
+  }
+}
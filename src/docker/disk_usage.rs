@@ -0,0 +1,386 @@
+//! Disk usage breakdown from `/system/df`
+
+use anyhow::Result;
+use bollard::models::SystemDataUsageResponse;
+
+use super::{ContainerState, DockerClient, HELPER_LABEL, PruneKind};
+
+/// Kind of data Docker keeps on disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiskUsageCategory {
+  Images,
+  Containers,
+  Volumes,
+  BuildCache,
+}
+
+impl DiskUsageCategory {
+  pub const ALL: [DiskUsageCategory; 4] = [
+    DiskUsageCategory::Images,
+    DiskUsageCategory::Containers,
+    DiskUsageCategory::Volumes,
+    DiskUsageCategory::BuildCache,
+  ];
+
+  pub fn label(self) -> &'static str {
+    match self {
+      DiskUsageCategory::Images => "Images",
+      DiskUsageCategory::Containers => "Containers",
+      DiskUsageCategory::Volumes => "Local Volumes",
+      DiskUsageCategory::BuildCache => "Build Cache",
+    }
+  }
+}
+
+/// A single image, container, volume or cache record and its size
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskUsageItem {
+  /// Image or container id, volume name, or cache record id
+  pub id: String,
+  pub name: String,
+  pub size: u64,
+  /// Unused, so a prune would remove it
+  pub reclaimable: bool,
+  /// Short context, e.g. the image a container runs or a cache record's type
+  pub detail: String,
+}
+
+/// Usage of one category, with items largest first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskUsageSummary {
+  pub category: DiskUsageCategory,
+  pub total: u64,
+  pub reclaimable: u64,
+  /// Items in use by a container or build
+  pub active: usize,
+  pub items: Vec<DiskUsageItem>,
+}
+
+impl DiskUsageSummary {
+  fn new(category: DiskUsageCategory, mut items: Vec<DiskUsageItem>) -> Self {
+    items.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.name.cmp(&b.name)));
+    Self {
+      category,
+      total: items.iter().map(|i| i.size).sum(),
+      reclaimable: items.iter().filter(|i| i.reclaimable).map(|i| i.size).sum(),
+      active: items.iter().filter(|i| !i.reclaimable).count(),
+      items,
+    }
+  }
+
+  /// The `n` largest items
+  pub fn top(&self, n: usize) -> &[DiskUsageItem] {
+    &self.items[..n.min(self.items.len())]
+  }
+
+  pub fn display_total(&self) -> String {
+    bytesize::ByteSize(self.total).to_string()
+  }
+
+  pub fn display_reclaimable(&self) -> String {
+    bytesize::ByteSize(self.reclaimable).to_string()
+  }
+
+  #[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+  )]
+  pub fn reclaimable_percent(&self) -> u8 {
+    if self.total == 0 {
+      0
+    } else {
+      ((self.reclaimable as f64 / self.total as f64) * 100.0).round() as u8
+    }
+  }
+}
+
+/// Where Docker's disk space goes, by category
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemDiskUsage {
+  pub images: DiskUsageSummary,
+  pub containers: DiskUsageSummary,
+  pub volumes: DiskUsageSummary,
+  pub build_cache: DiskUsageSummary,
+}
+
+impl SystemDiskUsage {
+  pub fn categories(&self) -> [&DiskUsageSummary; 4] {
+    [&self.images, &self.containers, &self.volumes, &self.build_cache]
+  }
+
+  pub fn total(&self) -> u64 {
+    self.categories().iter().map(|c| c.total).sum()
+  }
+
+  pub fn reclaimable(&self) -> u64 {
+    self.categories().iter().map(|c| c.reclaimable).sum()
+  }
+
+  /// Space taken by what a prune of `kind` works on; networks take none
+  pub fn size_of(&self, kind: PruneKind) -> u64 {
+    match kind {
+      PruneKind::Containers => self.containers.total,
+      PruneKind::Images => self.images.total,
+      PruneKind::Volumes => self.volumes.total,
+      PruneKind::Networks => 0,
+      PruneKind::BuildCache => self.build_cache.total,
+    }
+  }
+
+  fn from_df(df: SystemDataUsageResponse) -> Self {
+    let size = |size: i64| u64::try_from(size).unwrap_or(0);
+    let containers = df.containers.unwrap_or_default();
+
+    let images = df.images.unwrap_or_default();
+    // Image sizes include layers shared with other images. Like `docker system df`, count
+    // shared layers once and only reclaim what in-use images don't need.
+    let used_by_images: u64 = images
+      .iter()
+      .filter(|i| i.containers > 0 && i.shared_size >= 0)
+      .map(|i| size(i.size - i.shared_size))
+      .sum();
+    let mut images = DiskUsageSummary::new(
+      DiskUsageCategory::Images,
+      images
+        .into_iter()
+        .map(|image| DiskUsageItem {
+          name: image
+            .repo_tags
+            .iter()
+            .find(|t| !t.starts_with("<none>"))
+            .cloned()
+            .unwrap_or_else(|| short_id(&image.id)),
+          detail: match image.containers {
+            n if n > 0 => format!("{n} container{}", if n == 1 { "" } else { "s" }),
+            _ => "Unused".to_string(),
+          },
+          reclaimable: image.containers <= 0,
+          size: size(image.size),
+          id: image.id,
+        })
+        .collect(),
+    );
+    if let Some(layers) = df.layers_size.map(size).filter(|l| *l > 0) {
+      images.total = layers;
+      images.reclaimable = layers.saturating_sub(used_by_images);
+    }
+
+    let container_items = containers
+      .iter()
+      .filter(|c| c.labels.as_ref().is_none_or(|l| !l.contains_key(HELPER_LABEL)))
+      .map(|c| {
+        let state = ContainerState::from_str(&c.state.map(|s| s.to_string()).unwrap_or_default());
+        DiskUsageItem {
+          id: c.id.clone().unwrap_or_default(),
+          name: c
+            .names
+            .as_ref()
+            .and_then(|n| n.first())
+            .map(|n| n.trim_start_matches('/').to_string())
+            .unwrap_or_else(|| short_id(c.id.as_deref().unwrap_or_default())),
+          size: size(c.size_rw.unwrap_or(0)),
+          reclaimable: !matches!(
+            state,
+            ContainerState::Running | ContainerState::Paused | ContainerState::Restarting
+          ),
+          detail: c.image.clone().unwrap_or_default(),
+        }
+      })
+      .collect();
+
+    let (_, helper_refs) = super::volumes::volume_mounts(&containers);
+    let volume_items = df
+      .volumes
+      .unwrap_or_default()
+      .into_iter()
+      .map(|v| {
+        let (bytes, refs) = v.usage_data.map_or((0, 0), |u| (u.size, u.ref_count));
+        let refs = (refs - helper_refs.get(&v.name).copied().unwrap_or(0)).max(0);
+        DiskUsageItem {
+          id: v.name.clone(),
+          name: v.name,
+          size: size(bytes),
+          reclaimable: refs == 0,
+          detail: match refs {
+            0 => "Unused".to_string(),
+            1 => "1 container".to_string(),
+            n => format!("{n} containers"),
+          },
+        }
+      })
+      .collect();
+
+    // Shared records are counted by the builds that share them
+    let cache_items = df
+      .build_cache
+      .unwrap_or_default()
+      .into_iter()
+      .filter(|c| !c.shared.unwrap_or(false))
+      .map(|c| {
+        let id = c.id.unwrap_or_default();
+        DiskUsageItem {
+          name: c.description.filter(|d| !d.is_empty()).unwrap_or_else(|| short_id(&id)),
+          id,
+          size: size(c.size.unwrap_or(0)),
+          reclaimable: !c.in_use.unwrap_or(false),
+          detail: c.typ.map(|t| t.to_string()).unwrap_or_default(),
+        }
+      })
+      .collect();
+
+    Self {
+      images,
+      containers: DiskUsageSummary::new(DiskUsageCategory::Containers, container_items),
+      volumes: DiskUsageSummary::new(DiskUsageCategory::Volumes, volume_items),
+      build_cache: DiskUsageSummary::new(DiskUsageCategory::BuildCache, cache_items),
+    }
+  }
+}
+
+/// Id without the `sha256:` prefix, cut to 12 characters
+fn short_id(id: &str) -> String {
+  id.trim_start_matches("sha256:").chars().take(12).collect()
+}
+
+impl DockerClient {
+  /// Disk usage of images, container writable layers, local volumes and build cache
+  pub async fn system_df(&self) -> Result<SystemDiskUsage> {
+    let docker = self.client()?;
+    let df = docker.df(None).await?;
+    Ok(SystemDiskUsage::from_df(df))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use bollard::models::{
+    BuildCache, ContainerSummary, ContainerSummaryStateEnum, ImageSummary, Volume, VolumeUsageData,
+  };
+
+  use super::*;
+
+  fn image(id: &str, tag: &str, size: i64, shared: i64, containers: i64) -> ImageSummary {
+    ImageSummary {
+      id: id.to_string(),
+      repo_tags: vec![tag.to_string()],
+      size,
+      shared_size: shared,
+      containers,
+      ..Default::default()
+    }
+  }
+
+  fn container(name: &str, state: ContainerSummaryStateEnum, size_rw: i64) -> ContainerSummary {
+    ContainerSummary {
+      id: Some(format!("{name}-id")),
+      names: Some(vec![format!("/{name}")]),
+      image: Some("nginx".to_string()),
+      state: Some(state),
+      size_rw: Some(size_rw),
+      ..Default::default()
+    }
+  }
+
+  fn volume(name: &str, size: i64, ref_count: i64) -> Volume {
+    Volume {
+      name: name.to_string(),
+      usage_data: Some(VolumeUsageData { size, ref_count }),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn test_system_disk_usage_from_df() {
+    let mut helper = container("helper", ContainerSummaryStateEnum::RUNNING, 10);
+    helper.labels = Some(HashMap::from([(HELPER_LABEL.to_string(), "volume".to_string())]));
+    helper.mounts = Some(vec![bollard::models::MountPoint {
+      typ: Some(bollard::models::MountPointTypeEnum::VOLUME),
+      name: Some("cache".to_string()),
+      ..Default::default()
+    }]);
+
+    let df = SystemDataUsageResponse {
+      layers_size: Some(900),
+      images: Some(vec![
+        image("sha256:aaa", "nginx:latest", 500, 100, 2),
+        image("sha256:bbbbbbbbbbbbbbbb", "<none>:<none>", 300, 100, 0),
+        image("sha256:ccc", "redis:7", 200, 0, 0),
+      ]),
+      containers: Some(vec![
+        container("web", ContainerSummaryStateEnum::RUNNING, 40),
+        container("old", ContainerSummaryStateEnum::EXITED, 60),
+        helper,
+      ]),
+      volumes: Some(vec![
+        volume("data", 1000, 1),
+        volume("cache", 50, 1),
+        volume("unknown", -1, 0),
+      ]),
+      build_cache: Some(vec![
+        BuildCache {
+          id: Some("c1".to_string()),
+          description: Some("RUN cargo build".to_string()),
+          in_use: Some(false),
+          shared: Some(false),
+          size: Some(700),
+          ..Default::default()
+        },
+        BuildCache {
+          id: Some("c2".to_string()),
+          in_use: Some(true),
+          shared: Some(false),
+          size: Some(100),
+          ..Default::default()
+        },
+        BuildCache {
+          id: Some("c3".to_string()),
+          shared: Some(true),
+          size: Some(999),
+          ..Default::default()
+        },
+      ]),
+    };
+
+    let usage = SystemDiskUsage::from_df(df);
+
+    assert_eq!(usage.images.total, 900);
+    assert_eq!(usage.images.reclaimable, 500);
+    assert_eq!(usage.images.active, 1);
+    let names: Vec<&str> = usage.images.items.iter().map(|i| i.name.as_str()).collect();
+    assert_eq!(names, vec!["nginx:latest", "bbbbbbbbbbbb", "redis:7"]);
+
+    assert_eq!(usage.containers.items.len(), 2);
+    assert_eq!(usage.containers.total, 100);
+    assert_eq!(usage.containers.reclaimable, 60);
+    assert_eq!(usage.containers.items[0].name, "old");
+
+    assert_eq!(usage.volumes.total, 1050);
+    assert_eq!(usage.volumes.reclaimable, 50);
+    assert_eq!(usage.volumes.top(1)[0].name, "data");
+    assert_eq!(usage.volumes.top(10).len(), 3);
+
+    assert_eq!(usage.build_cache.total, 800);
+    assert_eq!(usage.build_cache.reclaimable, 700);
+    assert_eq!(usage.build_cache.items[0].name, "RUN cargo build");
+    assert_eq!(usage.build_cache.items[1].name, "c2");
+
+    assert_eq!(usage.total(), 900 + 100 + 1050 + 800);
+    assert_eq!(usage.reclaimable(), 500 + 60 + 50 + 700);
+  }
+
+  #[test]
+  fn test_disk_usage_reclaimable_percent() {
+    let summary = DiskUsageSummary::new(DiskUsageCategory::Volumes, Vec::new());
+    assert_eq!(summary.reclaimable_percent(), 0);
+
+    let summary = DiskUsageSummary {
+      total: 300,
+      reclaimable: 100,
+      ..summary
+    };
+    assert_eq!(summary.reclaimable_percent(), 33);
+  }
+}
@@ -0,0 +1,490 @@
+//! Pooled helper containers for volume file operations
+//!
+//! Docker has no API for browsing a volume, so file operations run inside a container
+//! that mounts it. Instead of one short-lived container per call, each volume gets a
+//! long-lived helper that commands are exec'd into. Helpers are removed once idle,
+//! and every helper carries [`HELPER_LABEL`] so leftovers from a crash can be found.
+//! Network diagnostics use short-lived, unpooled helpers that share a container's network.
+
+use anyhow::{Result, anyhow, bail};
+use bollard::models::ContainerCreateBody;
+use bollard::query_parameters::{CreateContainerOptions, ListContainersOptions, RemoveContainerOptions};
+use futures::StreamExt;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::{DockerClient, ExecOptions, ExecResult, ImageInfo};
+
+/// Label set on every helper and archive container created by the app
+pub const HELPER_LABEL: &str = "dockside.helper";
+/// Label holding the volume a helper mounts
+const HELPER_VOLUME_LABEL: &str = "dockside.helper.volume";
+
+/// Image pulled when nothing suitable exists locally
+const DEFAULT_HELPER_IMAGE: &str = "alpine:latest";
+/// Small images with both the file and the networking tools, preferred when picking a local helper image
+const PREFERRED_IMAGES: &[&str] = &["alpine", "busybox"];
+/// Local images probed before giving up when the default image can't be pulled
+const MAX_PROBED_IMAGES: usize = 5;
+/// Tools the file explorer relies on
+const REQUIRED_TOOLS: &str = "ls cat stat readlink dirname mkdir mv rm touch chown chmod";
+/// Tools the network diagnostics rely on
+const DIAGNOSTIC_TOOLS: &str = "nslookup nc wget ping";
+
+/// Keeps the helper alive without a busy process; exits promptly on stop
+const HELPER_SCRIPT: &str = "trap 'exit 0' TERM INT; while :; do sleep 3600 & wait $!; done";
+
+/// Helper image and lifetime settings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelperConfig {
+  /// Image for helper containers (empty to pick one automatically)
+  pub image: String,
+  /// Helpers unused for this long are removed
+  pub idle_timeout: Duration,
+}
+
+impl Default for HelperConfig {
+  fn default() -> Self {
+    Self {
+      image: String::new(),
+      idle_timeout: Duration::from_secs(300),
+    }
+  }
+}
+
+struct Helper {
+  id: String,
+  last_used: Instant,
+}
+
+/// Helpers shared by all clones of a [`DockerClient`]
+#[derive(Default)]
+pub(super) struct HelperPool {
+  config: Mutex<HelperConfig>,
+  /// Configured image and the image it resolved to
+  image: tokio::sync::Mutex<Option<(String, String)>>,
+  /// Running helper per volume
+  running: tokio::sync::Mutex<HashMap<String, Helper>>,
+}
+
+impl DockerClient {
+  /// Apply helper settings. Running helpers keep their image until they are recycled.
+  pub fn configure_helpers(&self, config: HelperConfig) {
+    if let Ok(mut current) = self.helpers.config.lock() {
+      *current = config;
+    }
+  }
+
+  fn helper_config(&self) -> HelperConfig {
+    self.helpers.config.lock().map(|c| c.clone()).unwrap_or_default()
+  }
+
+  /// Image used for helper containers: the configured one, a suitable local image,
+  /// the pulled default, or (offline) any local image that has the required tools
+  pub(super) async fn helper_image(&self) -> Result<String> {
+    let configured = self.helper_config().image.trim().to_string();
+    let mut resolved = self.helpers.image.lock().await;
+    if let Some((for_config, image)) = resolved.as_ref()
+      && *for_config == configured
+    {
+      return Ok(image.clone());
+    }
+
+    let docker = self.client()?;
+    let image = if configured.is_empty() {
+      let local = self.list_images(false).await.unwrap_or_default();
+      let candidates = helper_image_candidates(&local);
+      if let Some(image) = candidates.iter().find(|c| is_preferred_image(c)) {
+        image.clone()
+      } else if self.pull_image(DEFAULT_HELPER_IMAGE, None).await.is_ok() {
+        DEFAULT_HELPER_IMAGE.to_string()
+      } else {
+        // Offline: use whatever local image has a shell and the tools we need
+        let mut found = None;
+        for candidate in candidates.into_iter().take(MAX_PROBED_IMAGES) {
+          if self.probe_helper_image(&candidate).await {
+            found = Some(candidate);
+            break;
+          }
+        }
+        found.ok_or_else(|| {
+          anyhow!(
+            "No helper image available: {DEFAULT_HELPER_IMAGE} could not be pulled and no local image has the required tools"
+          )
+        })?
+      }
+    } else {
+      if docker.inspect_image(&configured).await.is_err() {
+        self
+          .pull_image(&configured, None)
+          .await
+          .map_err(|e| anyhow!("Helper image {configured} is not available locally: {e}"))?;
+      }
+      configured.clone()
+    };
+
+    *resolved = Some((configured, image.clone()));
+    Ok(image)
+  }
+
+  /// Whether an image can run the shell commands the file explorer and the diagnostics use
+  async fn probe_helper_image(&self, image: &str) -> bool {
+    let Ok(docker) = self.client() else {
+      return false;
+    };
+    let name = format!("dockside-helper-probe-{}", unique_suffix());
+    let config = ContainerCreateBody {
+      image: Some(image.to_string()),
+      entrypoint: Some(vec!["sh".to_string(), "-c".to_string()]),
+      cmd: Some(vec![format!(
+        "command -v {REQUIRED_TOOLS} {DIAGNOSTIC_TOOLS} >/dev/null"
+      )]),
+      labels: Some(HashMap::from([(HELPER_LABEL.to_string(), "probe".to_string())])),
+      network_disabled: Some(true),
+      ..Default::default()
+    };
+    let options = CreateContainerOptions {
+      name: Some(name.clone()),
+      ..Default::default()
+    };
+    if docker.create_container(Some(options), config).await.is_err() {
+      return false;
+    }
+
+    let mut ok = docker
+      .start_container(&name, None::<bollard::query_parameters::StartContainerOptions>)
+      .await
+      .is_ok();
+    if ok {
+      let mut wait_stream = docker.wait_container(&name, None::<bollard::query_parameters::WaitContainerOptions>);
+      while let Some(result) = wait_stream.next().await {
+        ok = matches!(result, Ok(response) if response.status_code == 0);
+      }
+    }
+    remove_container(docker, &name).await;
+    ok
+  }
+
+  /// Running helper for a volume, started on first use
+  async fn volume_helper(&self, volume: &str) -> Result<String> {
+    if let Some(helper) = self.helpers.running.lock().await.get_mut(volume) {
+      helper.last_used = Instant::now();
+      return Ok(helper.id.clone());
+    }
+
+    // Resolving the image may pull it; don't hold up other volumes meanwhile
+    let docker = self.client()?;
+    let image = self.helper_image().await?;
+    let mut helpers = self.helpers.running.lock().await;
+    if let Some(helper) = helpers.get_mut(volume) {
+      helper.last_used = Instant::now();
+      return Ok(helper.id.clone());
+    }
+
+    let name = format!("dockside-helper-{}", unique_suffix());
+    let host_config = bollard::models::HostConfig {
+      binds: Some(vec![format!("{volume}:/data")]),
+      network_mode: Some("none".to_string()),
+      ..Default::default()
+    };
+    let config = ContainerCreateBody {
+      image: Some(image),
+      entrypoint: Some(vec!["sh".to_string(), "-c".to_string()]),
+      cmd: Some(vec![HELPER_SCRIPT.to_string()]),
+      labels: Some(HashMap::from([
+        (HELPER_LABEL.to_string(), "volume".to_string()),
+        (HELPER_VOLUME_LABEL.to_string(), volume.to_string()),
+      ])),
+      host_config: Some(host_config),
+      ..Default::default()
+    };
+    let options = CreateContainerOptions {
+      name: Some(name.clone()),
+      ..Default::default()
+    };
+
+    docker.create_container(Some(options), config).await?;
+    if let Err(e) = docker
+      .start_container(&name, None::<bollard::query_parameters::StartContainerOptions>)
+      .await
+    {
+      remove_container(docker, &name).await;
+      return Err(e.into());
+    }
+
+    helpers.insert(
+      volume.to_string(),
+      Helper {
+        id: name.clone(),
+        last_used: Instant::now(),
+      },
+    );
+    Ok(name)
+  }
+
+  /// Temporary helper joined to a running container's network namespace, for probes that
+  /// must see its DNS and routes. Remove it with [`Self::remove_helper`] when done.
+  pub(super) async fn network_helper(&self, container: &str) -> Result<String> {
+    let docker = self.client()?;
+    let inspect = docker.inspect_container(container, None).await?;
+    if !inspect.state.and_then(|s| s.running).unwrap_or(false) {
+      bail!("Container must be running to share its network");
+    }
+
+    let image = self.helper_image().await?;
+    let name = format!("dockside-helper-{}", unique_suffix());
+    let host_config = bollard::models::HostConfig {
+      network_mode: Some(format!("container:{}", inspect.id.as_deref().unwrap_or(container))),
+      ..Default::default()
+    };
+    let config = ContainerCreateBody {
+      image: Some(image),
+      entrypoint: Some(vec!["sh".to_string(), "-c".to_string()]),
+      cmd: Some(vec![HELPER_SCRIPT.to_string()]),
+      labels: Some(HashMap::from([(HELPER_LABEL.to_string(), "network".to_string())])),
+      host_config: Some(host_config),
+      ..Default::default()
+    };
+    let options = CreateContainerOptions {
+      name: Some(name.clone()),
+      ..Default::default()
+    };
+
+    docker.create_container(Some(options), config).await?;
+    if let Err(e) = docker
+      .start_container(&name, None::<bollard::query_parameters::StartContainerOptions>)
+      .await
+    {
+      remove_container(docker, &name).await;
+      return Err(e.into());
+    }
+    Ok(name)
+  }
+
+  /// Remove a helper that is not tracked by the pool
+  pub(super) async fn remove_helper(&self, name: &str) {
+    if let Ok(docker) = self.client() {
+      remove_container(docker, name).await;
+    }
+  }
+
+  /// Run a command in the volume's helper, with the volume mounted at `/data`.
+  /// A helper that was removed behind our back is replaced once.
+  pub(super) async fn exec_in_volume(&self, volume: &str, cmd: Vec<String>) -> Result<ExecResult> {
+    let helper = self.volume_helper(volume).await?;
+    let options = ExecOptions {
+      cmd: cmd.clone(),
+      ..Default::default()
+    };
+    match self.exec(&helper, options.clone()).await {
+      Err(e) if is_gone(&e) => {
+        self.helpers.running.lock().await.remove(volume);
+        let helper = self.volume_helper(volume).await?;
+        self.exec(&helper, options).await
+      }
+      result => result,
+    }
+  }
+
+  /// Like [`Self::exec_in_volume`], returning stdout and failing with the output on a non-zero exit
+  pub(super) async fn run_in_volume(&self, volume: &str, cmd: Vec<String>) -> Result<String> {
+    let result = self.exec_in_volume(volume, cmd).await?;
+    if !result.is_success() {
+      let message = if result.stderr.trim().is_empty() {
+        result.stdout
+      } else {
+        result.stderr
+      };
+      bail!("{}", message.trim().replace("/data/", "/"));
+    }
+    Ok(result.stdout)
+  }
+
+  /// Running helper for a volume, for archive uploads and downloads
+  pub(super) async fn volume_archive_target(&self, volume: &str) -> Result<String> {
+    // Make sure the helper is still there before handing out its id
+    let helper = self.volume_helper(volume).await?;
+    if self.client()?.inspect_container(&helper, None).await.is_ok() {
+      return Ok(helper);
+    }
+    self.helpers.running.lock().await.remove(volume);
+    self.volume_helper(volume).await
+  }
+
+  /// Remove the helper for a volume so the volume can be deleted
+  pub async fn release_volume_helper(&self, volume: &str) {
+    let helper = self.helpers.running.lock().await.remove(volume);
+    if let (Some(helper), Ok(docker)) = (helper, self.client()) {
+      remove_container(docker, &helper.id).await;
+    }
+  }
+
+  /// Remove helpers that have been idle longer than the configured timeout
+  pub async fn reap_idle_helpers(&self) -> usize {
+    let timeout = self.helper_config().idle_timeout;
+    let idle: Vec<Helper> = {
+      let mut helpers = self.helpers.running.lock().await;
+      let volumes: Vec<String> = helpers
+        .iter()
+        .filter(|(_, h)| h.last_used.elapsed() >= timeout)
+        .map(|(v, _)| v.clone())
+        .collect();
+      volumes.iter().filter_map(|v| helpers.remove(v)).collect()
+    };
+
+    if let Ok(docker) = self.client() {
+      for helper in &idle {
+        remove_container(docker, &helper.id).await;
+      }
+    }
+    idle.len()
+  }
+
+  /// Remove every container carrying the helper label, including ones left by a previous run
+  pub async fn cleanup_helpers(&self) -> Result<usize> {
+    let docker = self.client()?;
+    self.helpers.running.lock().await.clear();
+
+    let options = ListContainersOptions {
+      all: true,
+      filters: Some(HashMap::from([("label".to_string(), vec![HELPER_LABEL.to_string()])])),
+      ..Default::default()
+    };
+    let containers = docker.list_containers(Some(options)).await?;
+    let mut removed = 0;
+    for id in containers.into_iter().filter_map(|c| c.id) {
+      remove_container(docker, &id).await;
+      removed += 1;
+    }
+    Ok(removed)
+  }
+}
+
+/// Local images worth trying as a helper, preferred small images first, then newest first
+fn helper_image_candidates(images: &[ImageInfo]) -> Vec<String> {
+  let mut images: Vec<&ImageInfo> = images.iter().collect();
+  images.sort_by(|a, b| b.created.cmp(&a.created));
+
+  let tags = images
+    .iter()
+    .flat_map(|i| i.repo_tags.iter())
+    .filter(|t| !t.starts_with("<none>"))
+    .cloned();
+  let (mut preferred, others): (Vec<String>, Vec<String>) = tags.partition(|t| is_preferred_image(t));
+  preferred.sort_by_key(|t| PREFERRED_IMAGES.iter().position(|p| image_repo(t) == *p));
+  preferred.extend(others);
+  preferred
+}
+
+fn is_preferred_image(tag: &str) -> bool {
+  PREFERRED_IMAGES.contains(&image_repo(tag))
+}
+
+/// Repository of an image reference, without Docker Hub's default registry and namespace
+/// (`docker.io/library/alpine:3.20` -> `alpine`, `foo/alpine:3` -> `foo/alpine`)
+fn image_repo(tag: &str) -> &str {
+  let name = tag.split('@').next().unwrap_or(tag);
+  // A ':' after the last '/' starts the tag; earlier ones belong to a registry port
+  let name = match name.rfind(':') {
+    Some(i) if !name[i..].contains('/') => &name[..i],
+    _ => name,
+  };
+  let name = name
+    .strip_prefix("docker.io/")
+    .or_else(|| name.strip_prefix("index.docker.io/"))
+    .unwrap_or(name);
+  name.strip_prefix("library/").unwrap_or(name)
+}
+
+/// Whether an exec failed because the helper container no longer exists or stopped
+fn is_gone(error: &anyhow::Error) -> bool {
+  let message = error.to_string();
+  message.contains("No such container") || message.contains("is not running")
+}
+
+fn unique_suffix() -> u128 {
+  std::time::SystemTime::now()
+    .duration_since(std::time::UNIX_EPOCH)
+    .unwrap_or_default()
+    .as_nanos()
+}
+
+async fn remove_container(docker: &bollard::Docker, name: &str) {
+  let _ = docker
+    .remove_container(
+      name,
+      Some(RemoveContainerOptions {
+        force: true,
+        ..Default::default()
+      }),
+    )
+    .await;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::{TimeZone, Utc};
+
+  fn image(tag: &str, created: i64) -> ImageInfo {
+    ImageInfo {
+      id: format!("sha256:{tag}"),
+      repo_tags: vec![tag.to_string()],
+      repo_digests: vec![],
+      created: Utc.timestamp_opt(created, 0).single(),
+      size: 0,
+      virtual_size: None,
+      labels: HashMap::new(),
+      architecture: None,
+      os: None,
+    }
+  }
+
+  #[test]
+  fn test_image_repo() {
+    assert_eq!(image_repo("alpine:latest"), "alpine");
+    assert_eq!(image_repo("docker.io/library/busybox:1.36"), "busybox");
+    assert_eq!(image_repo("library/alpine:3.20"), "alpine");
+    assert_eq!(image_repo("localhost:5000/team/alpine"), "localhost:5000/team/alpine");
+    assert_eq!(image_repo("foo/alpine:3"), "foo/alpine");
+    assert_eq!(image_repo("ubuntu@sha256:abc"), "ubuntu");
+    assert!(is_preferred_image("docker.io/library/alpine:3.20"));
+    assert!(!is_preferred_image("foo/alpine:3"));
+    assert!(!is_preferred_image("localhost:5000/alpine"));
+    assert!(!is_preferred_image("alpine-node:20"));
+    assert!(!is_preferred_image("debian:bookworm"));
+  }
+
+  #[test]
+  fn test_helper_image_candidates() {
+    let images = vec![
+      image("postgres:16", 300),
+      image("<none>:<none>", 400),
+      image("debian:bookworm", 100),
+      image("node:20", 200),
+      image("busybox:latest", 50),
+      image("foo/alpine:3", 250),
+      image("alpine:3.20", 20),
+    ];
+    assert_eq!(
+      helper_image_candidates(&images),
+      [
+        "alpine:3.20",
+        "busybox:latest",
+        "postgres:16",
+        "foo/alpine:3",
+        "node:20",
+        "debian:bookworm"
+      ]
+    );
+    assert!(helper_image_candidates(&[]).is_empty());
+  }
+
+  #[test]
+  fn test_helper_config_default() {
+    let config = HelperConfig::default();
+    assert!(config.image.is_empty());
+    assert_eq!(config.idle_timeout, Duration::from_secs(300));
+  }
+}
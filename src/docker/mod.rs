@@ -1,17 +1,29 @@
 mod client;
 mod compose;
 mod containers;
+mod diagnostics;
+mod disk_usage;
+mod helper;
 mod images;
 mod networks;
+mod protect;
 mod prune;
 mod stats;
+mod topology;
+mod volume_backup;
 mod volumes;
 
 pub use client::*;
 pub use compose::*;
 pub use containers::*;
+pub use diagnostics::*;
+pub use disk_usage::*;
+pub use helper::*;
 pub use images::*;
 pub use networks::*;
+pub use protect::*;
 pub use prune::*;
 pub use stats::*;
+pub use topology::*;
+pub use volume_backup::*;
 pub use volumes::*;
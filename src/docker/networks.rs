@@ -1,8 +1,12 @@
-use anyhow::Result;
-use bollard::query_parameters::ListNetworksOptions;
+use anyhow::{Result, anyhow, bail};
+use bollard::models::{
+  ContainerSummary, EndpointIpamConfig, EndpointSettings, NetworkConnectRequest, NetworkDisconnectRequest,
+};
+use bollard::query_parameters::{ListContainersOptions, ListNetworksOptions};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::net::IpAddr;
 
 use super::DockerClient;
 
@@ -41,6 +45,178 @@ pub struct NetworkContainer {
   pub mac_address: Option<String>,
   pub ipv4_address: Option<String>,
   pub ipv6_address: Option<String>,
+  #[serde(default)]
+  pub aliases: Vec<String>,
+}
+
+/// An IPAM address pool for a new network
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IpamPool {
+  pub subnet: String,
+  pub gateway: Option<String>,
+  pub ip_range: Option<String>,
+  /// Addresses reserved for hosts outside Docker, keyed by host name
+  pub aux_addresses: HashMap<String, String>,
+}
+
+/// Everything needed to create a network
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkCreateOptions {
+  pub name: String,
+  pub driver: String,
+  pub driver_options: HashMap<String, String>,
+  pub pools: Vec<IpamPool>,
+  pub enable_ipv6: bool,
+  pub internal: bool,
+  pub attachable: bool,
+  pub labels: HashMap<String, String>,
+}
+
+impl Default for NetworkCreateOptions {
+  fn default() -> Self {
+    Self {
+      name: String::new(),
+      driver: "bridge".to_string(),
+      driver_options: HashMap::new(),
+      pools: Vec::new(),
+      enable_ipv6: false,
+      internal: false,
+      attachable: false,
+      labels: HashMap::new(),
+    }
+  }
+}
+
+impl NetworkCreateOptions {
+  /// Check the pools are well formed and don't overlap each other or any `existing` network
+  pub fn validate(&self, existing: &[NetworkInfo]) -> Result<()> {
+    if self.name.trim().is_empty() {
+      bail!("Network name is required");
+    }
+
+    let mut seen: Vec<(Cidr, &str)> = Vec::new();
+    for pool in &self.pools {
+      let subnet = Cidr::parse(&pool.subnet).ok_or_else(|| anyhow!("Invalid subnet \"{}\"", pool.subnet))?;
+
+      if let Some(gateway) = &pool.gateway {
+        let ip: IpAddr = gateway.parse().map_err(|_| anyhow!("Invalid gateway \"{gateway}\""))?;
+        if !subnet.contains(ip) {
+          bail!("Gateway {gateway} is outside subnet {}", pool.subnet);
+        }
+      }
+      if let Some(range) = &pool.ip_range {
+        let range_cidr = Cidr::parse(range).ok_or_else(|| anyhow!("Invalid IP range \"{range}\""))?;
+        if range_cidr.prefix < subnet.prefix || !subnet.contains(range_cidr.addr) {
+          bail!("IP range {range} is outside subnet {}", pool.subnet);
+        }
+      }
+      for (host, address) in &pool.aux_addresses {
+        let ip: IpAddr = address
+          .parse()
+          .map_err(|_| anyhow!("Invalid auxiliary address \"{address}\" for {host}"))?;
+        if !subnet.contains(ip) {
+          bail!(
+            "Auxiliary address {address} for {host} is outside subnet {}",
+            pool.subnet
+          );
+        }
+      }
+
+      if let Some((_, other)) = seen.iter().find(|(cidr, _)| cidr.overlaps(&subnet)) {
+        bail!("Subnet {} overlaps {other}", pool.subnet);
+      }
+      for network in existing {
+        let used = network
+          .ipam
+          .iter()
+          .flat_map(|ipam| &ipam.config)
+          .filter_map(|config| config.subnet.as_deref());
+        for other in used {
+          if Cidr::parse(other).is_some_and(|cidr| cidr.overlaps(&subnet)) {
+            bail!(
+              "Subnet {} overlaps {other} used by network {}",
+              pool.subnet,
+              network.name
+            );
+          }
+        }
+      }
+      seen.push((subnet, &pool.subnet));
+    }
+
+    Ok(())
+  }
+}
+
+/// An IPv4 or IPv6 prefix such as `172.20.0.0/16`
+#[derive(Debug, Clone, Copy)]
+struct Cidr {
+  addr: IpAddr,
+  prefix: u8,
+}
+
+impl Cidr {
+  fn parse(s: &str) -> Option<Self> {
+    let (addr, prefix) = s.trim().split_once('/')?;
+    let addr: IpAddr = addr.parse().ok()?;
+    let prefix: u8 = prefix.parse().ok()?;
+    (prefix <= Self::width(addr)).then_some(Self { addr, prefix })
+  }
+
+  fn width(addr: IpAddr) -> u8 {
+    if addr.is_ipv4() { 32 } else { 128 }
+  }
+
+  /// The leading `prefix` bits of `addr`
+  fn network_bits(addr: IpAddr, prefix: u8) -> u128 {
+    let bits = match addr {
+      IpAddr::V4(v4) => u128::from(u32::from(v4)),
+      IpAddr::V6(v6) => u128::from(v6),
+    };
+    if prefix == 0 {
+      0
+    } else {
+      bits >> (Self::width(addr) - prefix)
+    }
+  }
+
+  fn contains(&self, addr: IpAddr) -> bool {
+    self.addr.is_ipv4() == addr.is_ipv4()
+      && Self::network_bits(self.addr, self.prefix) == Self::network_bits(addr, self.prefix)
+  }
+
+  fn overlaps(&self, other: &Cidr) -> bool {
+    let prefix = self.prefix.min(other.prefix);
+    self.addr.is_ipv4() == other.addr.is_ipv4()
+      && Self::network_bits(self.addr, prefix) == Self::network_bits(other.addr, prefix)
+  }
+}
+
+/// Endpoint settings used when connecting a container to a network
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetworkConnectOptions {
+  pub aliases: Vec<String>,
+  pub ipv4_address: Option<String>,
+  pub ipv6_address: Option<String>,
+  /// Legacy links in `container[:alias]` form
+  pub links: Vec<String>,
+}
+
+impl NetworkConnectOptions {
+  fn endpoint_settings(&self) -> EndpointSettings {
+    let ipam_config = (self.ipv4_address.is_some() || self.ipv6_address.is_some()).then(|| EndpointIpamConfig {
+      ipv4_address: self.ipv4_address.clone(),
+      ipv6_address: self.ipv6_address.clone(),
+      ..Default::default()
+    });
+
+    EndpointSettings {
+      ipam_config,
+      aliases: (!self.aliases.is_empty()).then(|| self.aliases.clone()),
+      links: (!self.links.is_empty()).then(|| self.links.clone()),
+      ..Default::default()
+    }
+  }
 }
 
 impl NetworkInfo {
@@ -57,13 +233,79 @@ impl NetworkInfo {
   }
 }
 
+/// Endpoints from container summaries, keyed by network id and then container id
+fn attached_containers(containers: &[ContainerSummary]) -> HashMap<String, HashMap<String, NetworkContainer>> {
+  let mut attached: HashMap<String, HashMap<String, NetworkContainer>> = HashMap::new();
+
+  for container in containers {
+    let Some(id) = container.id.clone() else {
+      continue;
+    };
+    let name = container
+      .names
+      .as_ref()
+      .and_then(|names| names.first())
+      .map(|n| n.trim_start_matches('/').to_string());
+    let endpoints = container
+      .network_settings
+      .as_ref()
+      .and_then(|settings| settings.networks.as_ref());
+
+    for endpoint in endpoints.into_iter().flat_map(HashMap::values) {
+      let Some(network_id) = endpoint.network_id.clone().filter(|n| !n.is_empty()) else {
+        continue;
+      };
+      let with_prefix = |address: &Option<String>, prefix: Option<i64>| {
+        address.as_ref().filter(|a| !a.is_empty()).map(|a| match prefix {
+          Some(len) if len > 0 => format!("{a}/{len}"),
+          _ => a.clone(),
+        })
+      };
+      // DNS names also carry the container name and short id, which aren't aliases
+      let short_id = &id[..12.min(id.len())];
+      let aliases = endpoint
+        .aliases
+        .clone()
+        .or_else(|| endpoint.dns_names.clone())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|alias| Some(alias) != name.as_ref() && alias != short_id)
+        .collect();
+
+      attached.entry(network_id).or_default().insert(
+        id.clone(),
+        NetworkContainer {
+          name: name.clone(),
+          endpoint_id: endpoint.endpoint_id.clone(),
+          mac_address: endpoint.mac_address.clone().filter(|m| !m.is_empty()),
+          ipv4_address: with_prefix(&endpoint.ip_address, endpoint.ip_prefix_len),
+          ipv6_address: with_prefix(&endpoint.global_ipv6_address, endpoint.global_ipv6_prefix_len),
+          aliases,
+        },
+      );
+    }
+  }
+
+  attached
+}
+
 impl DockerClient {
   pub async fn list_networks(&self) -> Result<Vec<NetworkInfo>> {
     let docker = self.client()?;
 
     let options = ListNetworksOptions { ..Default::default() };
+    let containers_options = ListContainersOptions {
+      all: true,
+      ..Default::default()
+    };
 
-    let networks = docker.list_networks(Some(options)).await?;
+    // The list endpoint leaves out attached containers, so take them from the container list
+    let (networks, containers) = tokio::join!(
+      docker.list_networks(Some(options)),
+      docker.list_containers(Some(containers_options)),
+    );
+    let networks = networks?;
+    let mut attached = attached_containers(&containers.unwrap_or_default());
 
     let mut result = Vec::new();
     for network in networks {
@@ -87,7 +329,8 @@ impl DockerClient {
           .collect(),
       });
 
-      let containers = network
+      let id = network.id.unwrap_or_default();
+      let mut containers: HashMap<String, NetworkContainer> = network
         .containers
         .unwrap_or_default()
         .into_iter()
@@ -100,13 +343,22 @@ impl DockerClient {
               mac_address: container.mac_address,
               ipv4_address: container.ipv4_address,
               ipv6_address: container.ipv6_address,
+              aliases: Vec::new(),
             },
           )
         })
         .collect();
+      for (container_id, endpoint) in attached.remove(&id).unwrap_or_default() {
+        match containers.get_mut(&container_id) {
+          Some(existing) => existing.aliases = endpoint.aliases,
+          None => {
+            containers.insert(container_id, endpoint);
+          }
+        }
+      }
 
       result.push(NetworkInfo {
-        id: network.id.unwrap_or_default(),
+        id,
         name: network.name.unwrap_or_default(),
         driver: network.driver.unwrap_or_default(),
         scope: network.scope.unwrap_or_default(),
@@ -130,23 +382,64 @@ impl DockerClient {
     Ok(())
   }
 
-  pub async fn create_network(&self, name: &str, enable_ipv6: bool, subnet: Option<&str>) -> Result<NetworkInfo> {
+  /// Attach a running or stopped container to a network
+  pub async fn connect_network(&self, network: &str, container: &str, options: &NetworkConnectOptions) -> Result<()> {
+    let docker = self.client()?;
+
+    let request = NetworkConnectRequest {
+      container: Some(container.to_string()),
+      endpoint_config: Some(options.endpoint_settings()),
+    };
+
+    docker.connect_network(network, request).await?;
+    Ok(())
+  }
+
+  pub async fn disconnect_network(&self, network: &str, container: &str, force: bool) -> Result<()> {
     let docker = self.client()?;
 
-    let ipam = subnet.map(|subnet| bollard::models::Ipam {
+    let request = NetworkDisconnectRequest {
+      container: Some(container.to_string()),
+      force: Some(force),
+    };
+
+    docker.disconnect_network(network, request).await?;
+    Ok(())
+  }
+
+  pub async fn create_network(&self, options: &NetworkCreateOptions) -> Result<NetworkInfo> {
+    // The engine only rejects overlaps with networks on the same driver, so check them all up front
+    let existing = self.list_networks().await?;
+    options.validate(&existing)?;
+
+    let docker = self.client()?;
+
+    let ipam = (!options.pools.is_empty()).then(|| bollard::models::Ipam {
       driver: Some("default".to_string()),
-      config: Some(vec![bollard::models::IpamConfig {
-        subnet: Some(subnet.to_string()),
-        ..Default::default()
-      }]),
+      config: Some(
+        options
+          .pools
+          .iter()
+          .map(|pool| bollard::models::IpamConfig {
+            subnet: Some(pool.subnet.clone()),
+            gateway: pool.gateway.clone(),
+            ip_range: pool.ip_range.clone(),
+            auxiliary_addresses: (!pool.aux_addresses.is_empty()).then(|| pool.aux_addresses.clone()),
+          })
+          .collect(),
+      ),
       options: None,
     });
 
     let config = bollard::models::NetworkCreateRequest {
-      name: name.to_string(),
-      driver: Some("bridge".to_string()),
-      enable_ipv6: Some(enable_ipv6),
+      name: options.name.clone(),
+      driver: Some(options.driver.clone()),
+      internal: Some(options.internal),
+      attachable: Some(options.attachable),
+      enable_ipv6: Some(options.enable_ipv6),
       ipam,
+      options: (!options.driver_options.is_empty()).then(|| options.driver_options.clone()),
+      labels: (!options.labels.is_empty()).then(|| options.labels.clone()),
       ..Default::default()
     };
 
@@ -155,21 +448,25 @@ impl DockerClient {
 
     Ok(NetworkInfo {
       id,
-      name: name.to_string(),
-      driver: "bridge".to_string(),
+      name: options.name.clone(),
+      driver: options.driver.clone(),
       scope: "local".to_string(),
-      internal: false,
-      enable_ipv6,
+      internal: options.internal,
+      enable_ipv6: options.enable_ipv6,
       created: Some(Utc::now()),
-      labels: HashMap::new(),
-      options: HashMap::new(),
-      ipam: subnet.map(|s| IpamInfo {
+      labels: options.labels.clone(),
+      options: options.driver_options.clone(),
+      ipam: (!options.pools.is_empty()).then(|| IpamInfo {
         driver: Some("default".to_string()),
-        config: vec![IpamConfig {
-          subnet: Some(s.to_string()),
-          gateway: None,
-          ip_range: None,
-        }],
+        config: options
+          .pools
+          .iter()
+          .map(|pool| IpamConfig {
+            subnet: Some(pool.subnet.clone()),
+            gateway: pool.gateway.clone(),
+            ip_range: pool.ip_range.clone(),
+          })
+          .collect(),
       }),
       containers: HashMap::new(),
     })
@@ -231,6 +528,7 @@ mod tests {
             mac_address: None,
             ipv4_address: Some("172.17.0.2".to_string()),
             ipv6_address: None,
+            aliases: Vec::new(),
           },
         ),
         (
@@ -241,6 +539,7 @@ mod tests {
             mac_address: None,
             ipv4_address: Some("172.17.0.3".to_string()),
             ipv6_address: None,
+            aliases: Vec::new(),
           },
         ),
       ]),
@@ -306,6 +605,211 @@ mod tests {
     assert_eq!(ipam.config[0].gateway, Some("172.18.0.1".to_string()));
   }
 
+  #[test]
+  fn test_network_connect_options_endpoint_settings() {
+    let empty = NetworkConnectOptions::default().endpoint_settings();
+    assert!(empty.ipam_config.is_none());
+    assert!(empty.aliases.is_none());
+    assert!(empty.links.is_none());
+
+    let options = NetworkConnectOptions {
+      aliases: vec!["db".to_string(), "postgres".to_string()],
+      ipv4_address: Some("172.20.0.10".to_string()),
+      ipv6_address: None,
+      links: vec!["cache:redis".to_string()],
+    };
+    let settings = options.endpoint_settings();
+    let ipam = settings.ipam_config.unwrap();
+    assert_eq!(ipam.ipv4_address, Some("172.20.0.10".to_string()));
+    assert!(ipam.ipv6_address.is_none());
+    assert_eq!(settings.aliases, Some(vec!["db".to_string(), "postgres".to_string()]));
+    assert_eq!(settings.links, Some(vec!["cache:redis".to_string()]));
+  }
+
+  #[test]
+  fn test_cidr_overlap() {
+    let a = Cidr::parse("172.20.0.0/16").unwrap();
+    assert!(a.overlaps(&Cidr::parse("172.20.5.0/24").unwrap()));
+    assert!(Cidr::parse("172.20.5.0/24").unwrap().overlaps(&a));
+    assert!(!a.overlaps(&Cidr::parse("172.21.0.0/16").unwrap()));
+    assert!(!a.overlaps(&Cidr::parse("fd00::/64").unwrap()));
+    assert!(Cidr::parse("0.0.0.0/0").unwrap().overlaps(&a));
+    assert!(
+      Cidr::parse("fd00:1::/48")
+        .unwrap()
+        .overlaps(&Cidr::parse("fd00:1:0:5::/64").unwrap())
+    );
+
+    assert!(a.contains("172.20.255.1".parse().unwrap()));
+    assert!(!a.contains("172.21.0.1".parse().unwrap()));
+
+    assert!(Cidr::parse("172.20.0.0").is_none());
+    assert!(Cidr::parse("172.20.0.0/33").is_none());
+    assert!(Cidr::parse("not-an-ip/16").is_none());
+  }
+
+  #[test]
+  fn test_network_create_options_validate() {
+    let existing = vec![NetworkInfo {
+      id: "net1".to_string(),
+      name: "backend".to_string(),
+      driver: "bridge".to_string(),
+      scope: "local".to_string(),
+      internal: false,
+      enable_ipv6: false,
+      created: None,
+      labels: HashMap::new(),
+      options: HashMap::new(),
+      ipam: Some(IpamInfo {
+        driver: Some("default".to_string()),
+        config: vec![IpamConfig {
+          subnet: Some("172.18.0.0/16".to_string()),
+          gateway: None,
+          ip_range: None,
+        }],
+      }),
+      containers: HashMap::new(),
+    }];
+
+    let pool = IpamPool {
+      subnet: "172.30.0.0/24".to_string(),
+      gateway: Some("172.30.0.1".to_string()),
+      ip_range: Some("172.30.0.128/25".to_string()),
+      aux_addresses: HashMap::from([("router".to_string(), "172.30.0.2".to_string())]),
+    };
+    let options = NetworkCreateOptions {
+      name: "frontend".to_string(),
+      pools: vec![pool.clone()],
+      ..Default::default()
+    };
+    assert!(options.validate(&existing).is_ok());
+
+    let unnamed = NetworkCreateOptions {
+      name: " ".to_string(),
+      ..options.clone()
+    };
+    assert!(unnamed.validate(&existing).is_err());
+
+    let overlapping = NetworkCreateOptions {
+      pools: vec![IpamPool {
+        subnet: "172.18.4.0/24".to_string(),
+        ..Default::default()
+      }],
+      ..options.clone()
+    };
+    let err = overlapping.validate(&existing).unwrap_err().to_string();
+    assert!(err.contains("backend"), "{err}");
+
+    let duplicate = NetworkCreateOptions {
+      pools: vec![pool.clone(), pool.clone()],
+      ..options.clone()
+    };
+    assert!(duplicate.validate(&existing).is_err());
+
+    let bad_gateway = NetworkCreateOptions {
+      pools: vec![IpamPool {
+        gateway: Some("10.0.0.1".to_string()),
+        ..pool.clone()
+      }],
+      ..options.clone()
+    };
+    assert!(bad_gateway.validate(&existing).is_err());
+
+    let bad_range = NetworkCreateOptions {
+      pools: vec![IpamPool {
+        ip_range: Some("172.30.0.0/16".to_string()),
+        ..pool.clone()
+      }],
+      ..options.clone()
+    };
+    assert!(bad_range.validate(&existing).is_err());
+
+    let bad_aux = NetworkCreateOptions {
+      pools: vec![IpamPool {
+        aux_addresses: HashMap::from([("router".to_string(), "10.1.1.1".to_string())]),
+        ..pool
+      }],
+      ..options
+    };
+    assert!(bad_aux.validate(&existing).is_err());
+  }
+
+  #[test]
+  fn test_attached_containers() {
+    use bollard::models::ContainerSummaryNetworkSettings;
+
+    let endpoint = EndpointSettings {
+      network_id: Some("net1".to_string()),
+      endpoint_id: Some("ep1".to_string()),
+      ip_address: Some("172.18.0.2".to_string()),
+      ip_prefix_len: Some(16),
+      global_ipv6_address: Some(String::new()),
+      dns_names: Some(vec![
+        "web".to_string(),
+        "abc123def456".to_string(),
+        "frontend".to_string(),
+      ]),
+      ..Default::default()
+    };
+    let containers = vec![
+      ContainerSummary {
+        id: Some("abc123def4567890".to_string()),
+        names: Some(vec!["/web".to_string()]),
+        network_settings: Some(ContainerSummaryNetworkSettings {
+          networks: Some(HashMap::from([("backend".to_string(), endpoint)])),
+        }),
+        ..Default::default()
+      },
+      // Host-networked containers have no network id
+      ContainerSummary {
+        id: Some("fff".to_string()),
+        network_settings: Some(ContainerSummaryNetworkSettings {
+          networks: Some(HashMap::from([("host".to_string(), EndpointSettings::default())])),
+        }),
+        ..Default::default()
+      },
+    ];
+
+    let attached = attached_containers(&containers);
+    assert_eq!(attached.len(), 1);
+    let web = &attached["net1"]["abc123def4567890"];
+    assert_eq!(web.name.as_deref(), Some("web"));
+    assert_eq!(web.ipv4_address.as_deref(), Some("172.18.0.2/16"));
+    assert_eq!(web.ipv6_address, None);
+    assert_eq!(web.aliases, vec!["frontend".to_string()]);
+  }
+
+  #[test]
+  fn test_attached_containers_keeps_hex_looking_aliases() {
+    use bollard::models::ContainerSummaryNetworkSettings;
+
+    // "cafe" and "db" are real aliases even though "cafe" is a prefix of the id
+    let endpoint = EndpointSettings {
+      network_id: Some("net1".to_string()),
+      dns_names: Some(vec![
+        "cache".to_string(),
+        "cafe12345678".to_string(),
+        "cafe".to_string(),
+        "db".to_string(),
+      ]),
+      ..Default::default()
+    };
+    let containers = vec![ContainerSummary {
+      id: Some("cafe1234567890ab".to_string()),
+      names: Some(vec!["/cache".to_string()]),
+      network_settings: Some(ContainerSummaryNetworkSettings {
+        networks: Some(HashMap::from([("backend".to_string(), endpoint)])),
+      }),
+      ..Default::default()
+    }];
+
+    let attached = attached_containers(&containers);
+    assert_eq!(
+      attached["net1"]["cafe1234567890ab"].aliases,
+      vec!["cafe".to_string(), "db".to_string()]
+    );
+  }
+
   #[test]
   fn test_network_container() {
     let container = NetworkContainer {
@@ -314,6 +818,7 @@ mod tests {
       mac_address: Some("02:42:ac:11:00:02".to_string()),
       ipv4_address: Some("172.17.0.2/16".to_string()),
       ipv6_address: Some("fd00::2/64".to_string()),
+      aliases: vec!["web".to_string()],
     };
     assert_eq!(container.name, Some("nginx".to_string()));
     assert_eq!(container.ipv4_address, Some("172.17.0.2/16".to_string()));
@@ -0,0 +1,104 @@
+//! Deletion protection
+//!
+//! Resources labelled [`PROTECT_LABEL`]`=true` or pinned in settings are left out of every prune,
+//! and deleting them takes typed confirmation. Kubernetes objects use the same key as an annotation.
+
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+use serde::{Deserialize, Serialize};
+
+use super::PruneKind;
+
+/// Label (or Kubernetes annotation) that protects a resource when set to exactly `true`
+pub const PROTECT_LABEL: &str = "dockside.protect";
+
+/// Whether labels or annotations mark a resource as protected. The value is compared exactly,
+/// like the `label!` filter prunes send to the daemon, so both agree on what is protected.
+pub fn has_protect_label<S: BuildHasher>(labels: &HashMap<String, String, S>) -> bool {
+  labels.get(PROTECT_LABEL).is_some_and(|value| value == "true")
+}
+
+/// A resource pinned in settings. Containers, volumes and networks are pinned by name so the
+/// pin outlives a recreate; images by id.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PinnedResource {
+  pub kind: PruneKind,
+  pub name: String,
+}
+
+/// What prunes and deletes must leave alone
+#[derive(Debug, Clone, Default)]
+pub struct Protection {
+  pinned: Vec<PinnedResource>,
+}
+
+impl Protection {
+  pub fn new(pinned: Vec<PinnedResource>) -> Self {
+    Self { pinned }
+  }
+
+  pub fn has_pins(&self, kind: PruneKind) -> bool {
+    self.pinned.iter().any(|p| p.kind == kind)
+  }
+
+  /// Pinned under its id or any of its names
+  pub fn is_pinned(&self, kind: PruneKind, id: &str, names: &[&str]) -> bool {
+    self
+      .pinned
+      .iter()
+      .any(|p| p.kind == kind && (p.name == id || names.contains(&p.name.as_str())))
+  }
+
+  pub fn is_protected(
+    &self,
+    kind: PruneKind,
+    id: &str,
+    names: &[&str],
+    labels: Option<&HashMap<String, String>>,
+  ) -> bool {
+    labels.is_some_and(has_protect_label) || self.is_pinned(kind, id, names)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_has_protect_label() {
+    let labels = |value: &str| HashMap::from([(PROTECT_LABEL.to_string(), value.to_string())]);
+    assert!(has_protect_label(&labels("true")));
+    // The daemon's label filter is exact, so other spellings don't count
+    assert!(!has_protect_label(&labels("TRUE")));
+    assert!(!has_protect_label(&labels("True")));
+    assert!(!has_protect_label(&labels("false")));
+    assert!(!has_protect_label(&HashMap::new()));
+  }
+
+  #[test]
+  fn test_protection() {
+    let protection = Protection::new(vec![
+      PinnedResource {
+        kind: PruneKind::Containers,
+        name: "db".to_string(),
+      },
+      PinnedResource {
+        kind: PruneKind::Images,
+        name: "sha256:abc".to_string(),
+      },
+    ]);
+
+    assert!(protection.has_pins(PruneKind::Containers));
+    assert!(!protection.has_pins(PruneKind::Volumes));
+
+    assert!(protection.is_pinned(PruneKind::Containers, "f00", &["db"]));
+    assert!(protection.is_pinned(PruneKind::Images, "sha256:abc", &["app:latest"]));
+    // Pins are per kind
+    assert!(!protection.is_pinned(PruneKind::Volumes, "db", &["db"]));
+
+    let labelled = HashMap::from([(PROTECT_LABEL.to_string(), "true".to_string())]);
+    assert!(protection.is_protected(PruneKind::Volumes, "data", &["data"], Some(&labelled)));
+    assert!(!protection.is_protected(PruneKind::Volumes, "data", &["data"], None));
+  }
+}
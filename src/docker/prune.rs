@@ -1,10 +1,378 @@
-use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use anyhow::{Result, anyhow, bail};
+use bollard::models::{ContainerSummaryStateEnum, MountPointTypeEnum, SystemDataUsageResponse};
 use bollard::query_parameters::{
-  PruneContainersOptions, PruneImagesOptions, PruneNetworksOptions, PruneVolumesOptions,
+  PruneBuildOptions, PruneContainersOptions, PruneImagesOptions, PruneNetworksOptions, PruneVolumesOptions,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use super::DockerClient;
+use super::{DockerClient, NetworkInfo, PROTECT_LABEL, Protection};
+
+/// Label the daemon puts on volumes created without a name; only these are pruned
+pub(super) const ANONYMOUS_VOLUME_LABEL: &str = "com.docker.volume.anonymous";
+
+/// Kind of Docker resource a prune removes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum PruneKind {
+  Containers,
+  Images,
+  Volumes,
+  Networks,
+  BuildCache,
+}
+
+impl PruneKind {
+  pub const ALL: [PruneKind; 5] = [
+    PruneKind::Containers,
+    PruneKind::Images,
+    PruneKind::Volumes,
+    PruneKind::Networks,
+    PruneKind::BuildCache,
+  ];
+
+  pub fn label(self) -> &'static str {
+    match self {
+      PruneKind::Containers => "Containers",
+      PruneKind::Images => "Images",
+      PruneKind::Volumes => "Volumes",
+      PruneKind::Networks => "Networks",
+      PruneKind::BuildCache => "Build Cache",
+    }
+  }
+
+  /// Volume prune rejects `until`
+  fn supports_until(self) -> bool {
+    self != PruneKind::Volumes
+  }
+
+  /// Build cache records carry no labels
+  fn supports_labels(self) -> bool {
+    self != PruneKind::BuildCache
+  }
+}
+
+/// Prune filters in `docker ... prune --filter` syntax
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PruneFilters {
+  /// Go duration (`24h`, `1h30m`), Unix timestamp or RFC 3339 time; only older items are pruned
+  pub until: Option<String>,
+  /// `key` or `key=value`; items must carry all of them
+  pub labels: Vec<String>,
+  /// `key` or `key=value`; items carrying any of them are kept
+  pub exclude_labels: Vec<String>,
+}
+
+/// Whether the prune endpoint can be trusted to skip protected items. It only sees labels, and
+/// it only spares items matching every `label!` filter, so the protect label must be the only one.
+fn daemon_skips_protected(kind: PruneKind, filters: &PruneFilters, protection: &Protection) -> bool {
+  !kind.supports_labels() || (filters.exclude_labels.is_empty() && !protection.has_pins(kind))
+}
+
+impl PruneFilters {
+  /// Parse space separated filters, e.g. `until=24h label=env=dev label!=keep`
+  pub fn parse(input: &str) -> Result<Self> {
+    let mut filters = Self::default();
+    for token in input.split_whitespace() {
+      let (key, value) = token
+        .split_once('=')
+        .filter(|(_, value)| !value.is_empty())
+        .ok_or_else(|| anyhow!("Filter '{token}' should look like key=value"))?;
+      match key {
+        "until" => {
+          until_cutoff(value, 0)?;
+          filters.until = Some(value.to_string());
+        }
+        "label" => filters.labels.push(value.to_string()),
+        "label!" => filters.exclude_labels.push(value.to_string()),
+        _ => bail!("Unsupported filter '{key}', use until, label or label!"),
+      }
+    }
+    Ok(filters)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.until.is_none() && self.labels.is_empty() && self.exclude_labels.is_empty()
+  }
+
+  /// Filters for the prune endpoint of `kind`, leaving out the ones it rejects. Without other
+  /// exclusions, protected items are excluded by label.
+  fn to_api(&self, kind: PruneKind) -> HashMap<String, Vec<String>> {
+    let mut map = HashMap::new();
+    if kind.supports_until()
+      && let Some(until) = &self.until
+    {
+      map.insert("until".to_string(), vec![until.clone()]);
+    }
+    if kind.supports_labels() {
+      if !self.labels.is_empty() {
+        map.insert("label".to_string(), self.labels.clone());
+      }
+      if self.exclude_labels.is_empty() {
+        map.insert("label!".to_string(), vec![format!("{PROTECT_LABEL}=true")]);
+      } else {
+        map.insert("label!".to_string(), self.exclude_labels.clone());
+      }
+    }
+    map
+  }
+
+  /// Whether an item passes the filters the way the daemon would judge it
+  fn matches(&self, kind: PruneKind, labels: Option<&HashMap<String, String>>, created: Option<i64>, now: i64) -> bool {
+    if kind.supports_until()
+      && let Some(cutoff) = self.until.as_deref().and_then(|u| until_cutoff(u, now).ok())
+      && created.is_none_or(|created| created >= cutoff)
+    {
+      return false;
+    }
+    if !kind.supports_labels() {
+      return true;
+    }
+    let has = |filter: &String| {
+      let labels = labels.into_iter().flatten();
+      match filter.split_once('=') {
+        Some((key, value)) => labels.into_iter().any(|(k, v)| k == key && v == value),
+        None => labels.into_iter().any(|(k, _)| k == filter),
+      }
+    };
+    self.labels.iter().all(&has) && !self.exclude_labels.iter().any(&has)
+  }
+}
+
+impl fmt::Display for PruneFilters {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut parts = Vec::new();
+    if let Some(until) = &self.until {
+      parts.push(format!("until={until}"));
+    }
+    parts.extend(self.labels.iter().map(|l| format!("label={l}")));
+    parts.extend(self.exclude_labels.iter().map(|l| format!("label!={l}")));
+    write!(f, "{}", parts.join(" "))
+  }
+}
+
+/// Unix time before which items count as old enough for an `until` filter
+fn until_cutoff(value: &str, now: i64) -> Result<i64> {
+  if let Ok(timestamp) = value.parse::<i64>() {
+    return Ok(timestamp);
+  }
+  if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+    return Ok(time.timestamp());
+  }
+
+  let invalid = || anyhow!("'{value}' is not a duration like 24h or 1h30m, or a timestamp");
+  let mut seconds = 0i64;
+  let mut number = String::new();
+  for ch in value.chars() {
+    if ch.is_ascii_digit() {
+      number.push(ch);
+      continue;
+    }
+    let n: i64 = number.parse().map_err(|_| invalid())?;
+    number.clear();
+    let unit = match ch {
+      'h' => 3600,
+      'm' => 60,
+      's' => 1,
+      _ => return Err(invalid()),
+    };
+    seconds = n
+      .checked_mul(unit)
+      .and_then(|s| s.checked_add(seconds))
+      .ok_or_else(invalid)?;
+  }
+  if !number.is_empty() {
+    return Err(invalid());
+  }
+  Ok(now - seconds)
+}
+
+/// Something a prune would remove
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PruneCandidate {
+  pub kind: PruneKind,
+  /// Container, image, network or cache record id, or volume name
+  pub id: String,
+  pub name: String,
+  /// Bytes freed; zero for networks
+  pub size: u64,
+  /// Short context, e.g. a container's image or a cache record's type
+  pub detail: String,
+}
+
+impl PruneCandidate {
+  pub fn display_size(&self) -> String {
+    bytesize::ByteSize(self.size).to_string()
+  }
+}
+
+/// Work out what a prune of `kinds` would remove, in the order the prune runs, leaving out
+/// protected items. Images, volumes and networks only held by containers the same prune removes
+/// count as unused.
+fn prune_candidates(
+  kinds: &[PruneKind],
+  dangling_only: bool,
+  filters: &PruneFilters,
+  protection: &Protection,
+  now: i64,
+  df: SystemDataUsageResponse,
+  networks: Vec<NetworkInfo>,
+) -> Vec<PruneCandidate> {
+  let size = |size: i64| u64::try_from(size).unwrap_or(0);
+  let time = |s: Option<&String>| {
+    s.and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+      .map(|t| t.timestamp())
+  };
+  let mut candidates = Vec::new();
+
+  let containers = df.containers.unwrap_or_default();
+  if kinds.contains(&PruneKind::Containers) {
+    for c in &containers {
+      let stopped = matches!(
+        c.state,
+        Some(ContainerSummaryStateEnum::CREATED | ContainerSummaryStateEnum::EXITED | ContainerSummaryStateEnum::DEAD)
+      );
+      let id = c.id.clone().unwrap_or_default();
+      let names: Vec<&str> = c.names.iter().flatten().map(|n| n.trim_start_matches('/')).collect();
+      if !stopped
+        || !filters.matches(PruneKind::Containers, c.labels.as_ref(), c.created, now)
+        || protection.is_protected(PruneKind::Containers, &id, &names, c.labels.as_ref())
+      {
+        continue;
+      }
+      candidates.push(PruneCandidate {
+        kind: PruneKind::Containers,
+        name: c
+          .names
+          .as_ref()
+          .and_then(|n| n.first())
+          .map_or_else(|| short_id(&id), |n| n.trim_start_matches('/').to_string()),
+        id,
+        size: size(c.size_rw.unwrap_or(0)),
+        detail: c.image.clone().unwrap_or_default(),
+      });
+    }
+  }
+  let removed: HashSet<String> = candidates.iter().map(|c| c.id.clone()).collect();
+  let remaining: Vec<_> = containers
+    .iter()
+    .filter(|c| c.id.as_ref().is_none_or(|id| !removed.contains(id)))
+    .collect();
+  let remaining_ids: HashSet<&str> = remaining.iter().filter_map(|c| c.id.as_deref()).collect();
+
+  if kinds.contains(&PruneKind::Images) {
+    let used: HashSet<&str> = remaining.iter().filter_map(|c| c.image_id.as_deref()).collect();
+    for image in df.images.unwrap_or_default() {
+      let tags: Vec<&String> = image.repo_tags.iter().filter(|t| !t.starts_with("<none>")).collect();
+      if used.contains(image.id.as_str())
+        || (dangling_only && !tags.is_empty())
+        || !filters.matches(PruneKind::Images, Some(&image.labels), Some(image.created), now)
+        || protection.is_protected(
+          PruneKind::Images,
+          &image.id,
+          &tags.iter().map(|t| t.as_str()).collect::<Vec<_>>(),
+          Some(&image.labels),
+        )
+      {
+        continue;
+      }
+      candidates.push(PruneCandidate {
+        kind: PruneKind::Images,
+        name: tags.first().map_or_else(|| short_id(&image.id), |t| (*t).clone()),
+        detail: match tags.len() {
+          0 => "Dangling".to_string(),
+          1 => String::new(),
+          n => format!("{n} tags"),
+        },
+        size: size(image.size),
+        id: image.id,
+      });
+    }
+  }
+
+  if kinds.contains(&PruneKind::Volumes) {
+    let used: HashSet<&str> = remaining
+      .iter()
+      .flat_map(|c| c.mounts.iter().flatten())
+      .filter(|m| m.typ == Some(MountPointTypeEnum::VOLUME))
+      .filter_map(|m| m.name.as_deref())
+      .collect();
+    for volume in df.volumes.unwrap_or_default() {
+      if !volume.labels.contains_key(ANONYMOUS_VOLUME_LABEL)
+        || used.contains(volume.name.as_str())
+        || !filters.matches(PruneKind::Volumes, Some(&volume.labels), None, now)
+        || protection.is_protected(PruneKind::Volumes, &volume.name, &[], Some(&volume.labels))
+      {
+        continue;
+      }
+      candidates.push(PruneCandidate {
+        kind: PruneKind::Volumes,
+        id: volume.name.clone(),
+        name: volume.name,
+        size: size(volume.usage_data.map_or(0, |u| u.size)),
+        detail: volume.driver,
+      });
+    }
+  }
+
+  if kinds.contains(&PruneKind::Networks) {
+    for network in networks {
+      let predefined = matches!(network.name.as_str(), "bridge" | "host" | "none") || network.scope == "swarm";
+      let used = network.containers.keys().any(|id| remaining_ids.contains(id.as_str()));
+      let created = network.created.map(|t| t.timestamp());
+      if predefined
+        || used
+        || !filters.matches(PruneKind::Networks, Some(&network.labels), created, now)
+        || protection.is_protected(
+          PruneKind::Networks,
+          &network.id,
+          &[&network.name],
+          Some(&network.labels),
+        )
+      {
+        continue;
+      }
+      candidates.push(PruneCandidate {
+        kind: PruneKind::Networks,
+        id: network.id,
+        name: network.name,
+        size: 0,
+        detail: network.driver,
+      });
+    }
+  }
+
+  if kinds.contains(&PruneKind::BuildCache) {
+    for record in df.build_cache.unwrap_or_default() {
+      // BuildKit ages records by when a build last used them
+      let used_at = time(record.last_used_at.as_ref()).or_else(|| time(record.created_at.as_ref()));
+      if record.in_use.unwrap_or(false) || !filters.matches(PruneKind::BuildCache, None, used_at, now) {
+        continue;
+      }
+      let id = record.id.unwrap_or_default();
+      candidates.push(PruneCandidate {
+        kind: PruneKind::BuildCache,
+        name: record
+          .description
+          .filter(|d| !d.is_empty())
+          .unwrap_or_else(|| short_id(&id)),
+        id,
+        size: size(record.size.unwrap_or(0)),
+        detail: record.typ.map(|t| t.to_string()).unwrap_or_default(),
+      });
+    }
+  }
+
+  candidates.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| b.size.cmp(&a.size)));
+  candidates
+}
+
+/// Id without the `sha256:` prefix, cut to 12 characters
+fn short_id(id: &str) -> String {
+  id.trim_start_matches("sha256:").chars().take(12).collect()
+}
 
 /// Result of a prune operation
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -14,6 +382,7 @@ pub struct PruneResult {
   pub images_deleted: Vec<String>,
   pub volumes_deleted: Vec<String>,
   pub networks_deleted: Vec<String>,
+  pub build_cache_deleted: Vec<String>,
   pub space_reclaimed: u64,
   // Kubernetes
   pub pods_deleted: Vec<String>,
@@ -31,6 +400,7 @@ impl PruneResult {
       + self.images_deleted.len()
       + self.volumes_deleted.len()
       + self.networks_deleted.len()
+      + self.build_cache_deleted.len()
       + self.pods_deleted.len()
       + self.deployments_deleted.len()
       + self.services_deleted.len()
@@ -39,14 +409,29 @@ impl PruneResult {
   pub fn is_empty(&self) -> bool {
     self.total_items_deleted() == 0
   }
+
+  /// Add the outcome of another prune to this one
+  pub fn merge(&mut self, other: PruneResult) {
+    self.containers_deleted.extend(other.containers_deleted);
+    self.images_deleted.extend(other.images_deleted);
+    self.volumes_deleted.extend(other.volumes_deleted);
+    self.networks_deleted.extend(other.networks_deleted);
+    self.build_cache_deleted.extend(other.build_cache_deleted);
+    self.pods_deleted.extend(other.pods_deleted);
+    self.deployments_deleted.extend(other.deployments_deleted);
+    self.services_deleted.extend(other.services_deleted);
+    self.space_reclaimed += other.space_reclaimed;
+  }
 }
 
 impl DockerClient {
   /// Prune stopped containers
-  pub async fn prune_containers(&self) -> Result<PruneResult> {
+  async fn prune_containers(&self, filters: &PruneFilters) -> Result<PruneResult> {
     let docker = self.client()?;
 
-    let options = PruneContainersOptions::default();
+    let options = PruneContainersOptions {
+      filters: Some(filters.to_api(PruneKind::Containers)),
+    };
     let response = docker.prune_containers(Some(options)).await?;
 
     Ok(PruneResult {
@@ -57,14 +442,13 @@ impl DockerClient {
   }
 
   /// Prune unused images
-  pub async fn prune_images(&self, dangling_only: bool) -> Result<PruneResult> {
-    use std::collections::HashMap;
+  async fn prune_images(&self, dangling_only: bool, filters: &PruneFilters) -> Result<PruneResult> {
     let docker = self.client()?;
 
     // Docker API: dangling=true removes only untagged images
     //             dangling=false removes ALL unused images (like docker image prune -a)
     //             No filter defaults to dangling=true
-    let mut filters = HashMap::new();
+    let mut filters = filters.to_api(PruneKind::Images);
     filters.insert(
       "dangling".to_string(),
       vec![if dangling_only { "true" } else { "false" }.to_string()],
@@ -88,11 +472,13 @@ impl DockerClient {
     })
   }
 
-  /// Prune unused volumes
-  pub async fn prune_volumes(&self) -> Result<PruneResult> {
+  /// Prune unused anonymous volumes; named volumes are left alone like `docker volume prune`
+  async fn prune_volumes(&self, filters: &PruneFilters) -> Result<PruneResult> {
     let docker = self.client()?;
 
-    let options = PruneVolumesOptions::default();
+    let options = PruneVolumesOptions {
+      filters: Some(filters.to_api(PruneKind::Volumes)),
+    };
     let response = docker.prune_volumes(Some(options)).await?;
 
     Ok(PruneResult {
@@ -103,10 +489,12 @@ impl DockerClient {
   }
 
   /// Prune unused networks
-  pub async fn prune_networks(&self) -> Result<PruneResult> {
+  async fn prune_networks(&self, filters: &PruneFilters) -> Result<PruneResult> {
     let docker = self.client()?;
 
-    let options = PruneNetworksOptions::default();
+    let options = PruneNetworksOptions {
+      filters: Some(filters.to_api(PruneKind::Networks)),
+    };
     let response = docker.prune_networks(Some(options)).await?;
 
     Ok(PruneResult {
@@ -114,11 +502,140 @@ impl DockerClient {
       ..Default::default()
     })
   }
+
+  /// Prune build cache that no running build uses, not just dangling records
+  async fn prune_build_cache(&self, filters: &PruneFilters) -> Result<PruneResult> {
+    let docker = self.client()?;
+
+    let options = PruneBuildOptions {
+      all: Some(true),
+      filters: Some(filters.to_api(PruneKind::BuildCache)),
+      ..Default::default()
+    };
+    let response = docker.prune_build(Some(options)).await?;
+
+    Ok(PruneResult {
+      build_cache_deleted: response.caches_deleted.unwrap_or_default(),
+      space_reclaimed: u64::try_from(response.space_reclaimed.unwrap_or(0)).unwrap_or(0),
+      ..Default::default()
+    })
+  }
+
+  /// Prune one kind of resource, leaving protected items alone; `dangling_only` applies to images
+  pub async fn prune(
+    &self,
+    kind: PruneKind,
+    dangling_only: bool,
+    filters: &PruneFilters,
+    protection: &Protection,
+  ) -> Result<PruneResult> {
+    if !daemon_skips_protected(kind, filters, protection) {
+      let candidates = self.preview_prune(&[kind], dangling_only, filters, protection).await?;
+      return self.remove_prune_candidates(&candidates).await;
+    }
+    match kind {
+      PruneKind::Containers => self.prune_containers(filters).await,
+      PruneKind::Images => self.prune_images(dangling_only, filters).await,
+      PruneKind::Volumes => self.prune_volumes(filters).await,
+      PruneKind::Networks => self.prune_networks(filters).await,
+      PruneKind::BuildCache => self.prune_build_cache(filters).await,
+    }
+  }
+
+  /// List what pruning `kinds` with `filters` would remove, without removing anything
+  pub async fn preview_prune(
+    &self,
+    kinds: &[PruneKind],
+    dangling_only: bool,
+    filters: &PruneFilters,
+    protection: &Protection,
+  ) -> Result<Vec<PruneCandidate>> {
+    let docker = self.client()?;
+    let df = docker.df(None).await?;
+    let networks = if kinds.contains(&PruneKind::Networks) {
+      self.list_networks().await?
+    } else {
+      Vec::new()
+    };
+    Ok(prune_candidates(
+      kinds,
+      dangling_only,
+      filters,
+      protection,
+      Utc::now().timestamp(),
+      df,
+      networks,
+    ))
+  }
+
+  /// Remove items picked from a preview one by one, skipping any that are gone or now in use
+  pub async fn remove_prune_candidates(&self, candidates: &[PruneCandidate]) -> Result<PruneResult> {
+    let docker = self.client()?;
+    let mut result = PruneResult::default();
+
+    let (cache, mut others): (Vec<&PruneCandidate>, Vec<&PruneCandidate>) =
+      candidates.iter().partition(|c| c.kind == PruneKind::BuildCache);
+    // Containers go first so the images, volumes and networks they held are free
+    others.sort_by_key(|c| c.kind);
+
+    for candidate in others {
+      let (removed, deleted) = match candidate.kind {
+        PruneKind::Containers => (
+          self.remove_container(&candidate.id, false).await,
+          &mut result.containers_deleted,
+        ),
+        PruneKind::Images => (
+          self.remove_unused_image(&candidate.id).await,
+          &mut result.images_deleted,
+        ),
+        PruneKind::Volumes => (
+          self.remove_volume(&candidate.id, false).await,
+          &mut result.volumes_deleted,
+        ),
+        PruneKind::Networks => (self.remove_network(&candidate.id).await, &mut result.networks_deleted),
+        PruneKind::BuildCache => continue,
+      };
+      if removed.is_ok() {
+        deleted.push(candidate.id.clone());
+        result.space_reclaimed += candidate.size;
+      }
+    }
+
+    // Cache records can only be removed through a prune, narrowed down by id
+    if !cache.is_empty() {
+      let ids = cache.iter().map(|c| c.id.clone()).collect();
+      let options = PruneBuildOptions {
+        all: Some(true),
+        filters: Some(HashMap::from([("id".to_string(), ids)])),
+        ..Default::default()
+      };
+      if let Ok(response) = docker.prune_build(Some(options)).await {
+        result.build_cache_deleted = response.caches_deleted.unwrap_or_default();
+        result.space_reclaimed += u64::try_from(response.space_reclaimed.unwrap_or(0)).unwrap_or(0);
+      }
+    }
+
+    Ok(result)
+  }
+
+  /// Remove an image under all its tags, which is what a prune does, without forcing it
+  /// out from under a container
+  async fn remove_unused_image(&self, id: &str) -> Result<()> {
+    let docker = self.client()?;
+    let tags = docker.inspect_image(id).await?.repo_tags.unwrap_or_default();
+    for tag in tags.iter().skip(1) {
+      self.remove_image(tag, false).await?;
+    }
+    self.remove_image(id, false).await
+  }
 }
 
 #[cfg(test)]
 mod tests {
+  use bollard::models::{BuildCache, ContainerSummary, ImageSummary, MountPoint, Volume, VolumeUsageData};
+
   use super::*;
+  use crate::docker::{NetworkContainer, PinnedResource};
 
   #[test]
   fn test_prune_result_default() {
@@ -127,6 +644,7 @@ mod tests {
     assert!(result.images_deleted.is_empty());
     assert!(result.volumes_deleted.is_empty());
     assert!(result.networks_deleted.is_empty());
+    assert!(result.build_cache_deleted.is_empty());
     assert!(result.pods_deleted.is_empty());
     assert!(result.deployments_deleted.is_empty());
     assert!(result.services_deleted.is_empty());
@@ -158,12 +676,31 @@ mod tests {
       images_deleted: vec!["i1".to_string()],
       volumes_deleted: vec!["v1".to_string(), "v2".to_string(), "v3".to_string()],
       networks_deleted: vec!["n1".to_string()],
+      build_cache_deleted: vec!["b1".to_string()],
       pods_deleted: vec!["p1".to_string()],
       deployments_deleted: vec![],
       services_deleted: vec!["s1".to_string(), "s2".to_string()],
       space_reclaimed: 0,
     };
-    assert_eq!(result.total_items_deleted(), 10);
+    assert_eq!(result.total_items_deleted(), 11);
+  }
+
+  #[test]
+  fn test_prune_result_merge() {
+    let mut result = PruneResult {
+      containers_deleted: vec!["c1".to_string()],
+      space_reclaimed: 100,
+      ..Default::default()
+    };
+    result.merge(PruneResult {
+      containers_deleted: vec!["c2".to_string()],
+      build_cache_deleted: vec!["b1".to_string()],
+      space_reclaimed: 50,
+      ..Default::default()
+    });
+    assert_eq!(result.containers_deleted, vec!["c1", "c2"]);
+    assert_eq!(result.build_cache_deleted, vec!["b1"]);
+    assert_eq!(result.space_reclaimed, 150);
   }
 
   #[test]
@@ -189,4 +726,328 @@ mod tests {
     };
     assert!(!with_k8s.is_empty());
   }
+
+  #[test]
+  fn test_prune_filters_parse() {
+    let filters = PruneFilters::parse("until=24h label=env=dev  label!=keep").unwrap();
+    assert_eq!(filters.until.as_deref(), Some("24h"));
+    assert_eq!(filters.labels, vec!["env=dev"]);
+    assert_eq!(filters.exclude_labels, vec!["keep"]);
+    assert_eq!(filters.to_string(), "until=24h label=env=dev label!=keep");
+
+    assert!(PruneFilters::parse("").unwrap().is_empty());
+    assert!(PruneFilters::parse("until=1h30m").is_ok());
+    assert!(PruneFilters::parse("until=2024-01-01T00:00:00Z").is_ok());
+    assert!(PruneFilters::parse("until=3d").is_err());
+    assert!(PruneFilters::parse("until=").is_err());
+    assert!(PruneFilters::parse("dangling=true").is_err());
+    assert!(PruneFilters::parse("keep").is_err());
+  }
+
+  #[test]
+  fn test_until_cutoff() {
+    assert_eq!(until_cutoff("24h", 100_000).unwrap(), 100_000 - 86_400);
+    assert_eq!(until_cutoff("1h30m", 10_000).unwrap(), 10_000 - 5_400);
+    assert_eq!(until_cutoff("1700000000", 0).unwrap(), 1_700_000_000);
+    assert!(until_cutoff("h", 0).is_err());
+    assert!(until_cutoff("10", 0).is_ok());
+    assert!(until_cutoff("10x", 0).is_err());
+  }
+
+  #[test]
+  fn test_prune_filters_to_api() {
+    let filters = PruneFilters::parse("until=24h label=env label!=keep").unwrap();
+
+    let containers = filters.to_api(PruneKind::Containers);
+    assert_eq!(containers["until"], vec!["24h"]);
+    assert_eq!(containers["label"], vec!["env"]);
+    assert_eq!(containers["label!"], vec!["keep"]);
+
+    // Protected items are excluded unless other exclusions take the slot
+    let networks = PruneFilters::default().to_api(PruneKind::Networks);
+    assert_eq!(networks["label!"], vec!["dockside.protect=true"]);
+
+    let volumes = filters.to_api(PruneKind::Volumes);
+    assert!(!volumes.contains_key("until"));
+    assert!(volumes.contains_key("label"));
+
+    let cache = filters.to_api(PruneKind::BuildCache);
+    assert_eq!(cache.len(), 1);
+    assert!(cache.contains_key("until"));
+  }
+
+  #[test]
+  fn test_prune_filters_matches() {
+    let filters = PruneFilters::parse("until=1h label=env=dev label!=keep").unwrap();
+    let now = 10_000;
+    let labels = |pairs: &[(&str, &str)]| -> HashMap<String, String> {
+      pairs
+        .iter()
+        .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+        .collect()
+    };
+
+    let dev = labels(&[("env", "dev")]);
+    assert!(filters.matches(PruneKind::Containers, Some(&dev), Some(1_000), now));
+    // Too new
+    assert!(!filters.matches(PruneKind::Containers, Some(&dev), Some(9_000), now));
+    // Unknown age is never old enough
+    assert!(!filters.matches(PruneKind::Containers, Some(&dev), None, now));
+    // Volumes ignore until
+    assert!(filters.matches(PruneKind::Volumes, Some(&dev), None, now));
+
+    let prod = labels(&[("env", "prod")]);
+    assert!(!filters.matches(PruneKind::Containers, Some(&prod), Some(1_000), now));
+    let kept = labels(&[("env", "dev"), ("keep", "")]);
+    assert!(!filters.matches(PruneKind::Containers, Some(&kept), Some(1_000), now));
+    assert!(!filters.matches(PruneKind::Containers, None, Some(1_000), now));
+
+    // Build cache ignores labels
+    assert!(filters.matches(PruneKind::BuildCache, None, Some(1_000), now));
+  }
+
+  fn container(id: &str, state: ContainerSummaryStateEnum, image_id: &str, volume: Option<&str>) -> ContainerSummary {
+    ContainerSummary {
+      id: Some(id.to_string()),
+      names: Some(vec![format!("/{id}")]),
+      image: Some("app".to_string()),
+      image_id: Some(image_id.to_string()),
+      state: Some(state),
+      size_rw: Some(10),
+      created: Some(0),
+      mounts: volume.map(|name| {
+        vec![MountPoint {
+          typ: Some(MountPointTypeEnum::VOLUME),
+          name: Some(name.to_string()),
+          ..Default::default()
+        }]
+      }),
+      ..Default::default()
+    }
+  }
+
+  fn image(id: &str, tags: &[&str], size: i64) -> ImageSummary {
+    ImageSummary {
+      id: id.to_string(),
+      repo_tags: tags.iter().map(ToString::to_string).collect(),
+      size,
+      ..Default::default()
+    }
+  }
+
+  fn network(id: &str, container: Option<&str>) -> NetworkInfo {
+    NetworkInfo {
+      id: id.to_string(),
+      name: id.to_string(),
+      driver: "bridge".to_string(),
+      scope: "local".to_string(),
+      internal: false,
+      enable_ipv6: false,
+      created: None,
+      labels: HashMap::new(),
+      options: HashMap::new(),
+      ipam: None,
+      containers: container
+        .map(|c| {
+          HashMap::from([(
+            c.to_string(),
+            NetworkContainer {
+              name: None,
+              endpoint_id: None,
+              mac_address: None,
+              ipv4_address: None,
+              ipv6_address: None,
+              aliases: Vec::new(),
+            },
+          )])
+        })
+        .unwrap_or_default(),
+    }
+  }
+
+  #[test]
+  fn test_prune_candidates() {
+    let anonymous = HashMap::from([(ANONYMOUS_VOLUME_LABEL.to_string(), String::new())]);
+    let df = SystemDataUsageResponse {
+      containers: Some(vec![
+        container("web", ContainerSummaryStateEnum::RUNNING, "sha256:web", Some("data")),
+        container("old", ContainerSummaryStateEnum::EXITED, "sha256:old", Some("scratch")),
+      ]),
+      images: Some(vec![
+        image("sha256:web", &["web:latest"], 300),
+        image("sha256:old", &["old:1", "old:2"], 200),
+        image("sha256:dangling", &["<none>:<none>"], 100),
+      ]),
+      volumes: Some(vec![
+        Volume {
+          name: "data".to_string(),
+          usage_data: Some(VolumeUsageData { size: 50, ref_count: 1 }),
+          labels: anonymous.clone(),
+          ..Default::default()
+        },
+        Volume {
+          name: "scratch".to_string(),
+          usage_data: Some(VolumeUsageData { size: 70, ref_count: 1 }),
+          labels: anonymous,
+          ..Default::default()
+        },
+        // Named volumes are never pruned, even unused
+        Volume {
+          name: "cache".to_string(),
+          usage_data: Some(VolumeUsageData { size: 90, ref_count: 0 }),
+          ..Default::default()
+        },
+      ]),
+      build_cache: Some(vec![
+        BuildCache {
+          id: Some("c1".to_string()),
+          in_use: Some(false),
+          size: Some(400),
+          ..Default::default()
+        },
+        BuildCache {
+          id: Some("c2".to_string()),
+          in_use: Some(true),
+          size: Some(500),
+          ..Default::default()
+        },
+      ]),
+      ..Default::default()
+    };
+    let networks = vec![
+      network("bridge", None),
+      network("front", Some("web")),
+      network("back", Some("old")),
+      network("idle", None),
+    ];
+    let all = [
+      PruneKind::Containers,
+      PruneKind::Images,
+      PruneKind::Volumes,
+      PruneKind::Networks,
+      PruneKind::BuildCache,
+    ];
+
+    let candidates = prune_candidates(
+      &all,
+      false,
+      &PruneFilters::default(),
+      &Protection::default(),
+      0,
+      df.clone(),
+      networks.clone(),
+    );
+    let ids: Vec<(PruneKind, &str)> = candidates.iter().map(|c| (c.kind, c.id.as_str())).collect();
+    // What the stopped container held goes with it
+    assert_eq!(
+      ids,
+      vec![
+        (PruneKind::Containers, "old"),
+        (PruneKind::Images, "sha256:old"),
+        (PruneKind::Images, "sha256:dangling"),
+        (PruneKind::Volumes, "scratch"),
+        (PruneKind::Networks, "back"),
+        (PruneKind::Networks, "idle"),
+        (PruneKind::BuildCache, "c1"),
+      ]
+    );
+    assert_eq!(candidates[1].name, "old:1");
+    assert_eq!(candidates[1].detail, "2 tags");
+    assert_eq!(candidates[2].detail, "Dangling");
+
+    // Without the container prune, the stopped container keeps its image, volume and network
+    let kinds = [PruneKind::Images, PruneKind::Volumes, PruneKind::Networks];
+    let candidates = prune_candidates(
+      &kinds,
+      false,
+      &PruneFilters::default(),
+      &Protection::default(),
+      0,
+      df.clone(),
+      networks.clone(),
+    );
+    let ids: Vec<&str> = candidates.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, vec!["sha256:dangling", "idle"]);
+
+    let candidates = prune_candidates(
+      &[PruneKind::Images],
+      true,
+      &PruneFilters::default(),
+      &Protection::default(),
+      0,
+      df.clone(),
+      Vec::new(),
+    );
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates[0].id, "sha256:dangling");
+
+    // A pinned container stays, and so does everything it holds
+    let protection = Protection::new(vec![PinnedResource {
+      kind: PruneKind::Containers,
+      name: "old".to_string(),
+    }]);
+    let candidates = prune_candidates(&all, false, &PruneFilters::default(), &protection, 0, df, networks);
+    let ids: Vec<&str> = candidates.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, vec!["sha256:dangling", "idle", "c1"]);
+  }
+
+  #[test]
+  fn test_prune_candidates_skip_protect_label() {
+    let labels = HashMap::from([(PROTECT_LABEL.to_string(), "true".to_string())]);
+    let df = SystemDataUsageResponse {
+      images: Some(vec![ImageSummary {
+        labels,
+        ..image("sha256:kept", &["<none>:<none>"], 100)
+      }]),
+      volumes: Some(vec![Volume {
+        name: "kept".to_string(),
+        labels: HashMap::from([
+          (PROTECT_LABEL.to_string(), "true".to_string()),
+          (ANONYMOUS_VOLUME_LABEL.to_string(), String::new()),
+        ]),
+        ..Default::default()
+      }]),
+      ..Default::default()
+    };
+    let kinds = [PruneKind::Images, PruneKind::Volumes];
+    let candidates = prune_candidates(
+      &kinds,
+      false,
+      &PruneFilters::default(),
+      &Protection::default(),
+      0,
+      df,
+      Vec::new(),
+    );
+    assert!(candidates.is_empty());
+  }
+
+  #[test]
+  fn test_daemon_skips_protected() {
+    let none = Protection::default();
+    let filters = PruneFilters::parse("label!=keep").unwrap();
+    assert!(daemon_skips_protected(
+      PruneKind::Images,
+      &PruneFilters::default(),
+      &none
+    ));
+    assert!(!daemon_skips_protected(PruneKind::Images, &filters, &none));
+    // Build cache carries no labels to protect
+    assert!(daemon_skips_protected(PruneKind::BuildCache, &filters, &none));
+
+    let pinned = Protection::new(vec![PinnedResource {
+      kind: PruneKind::Volumes,
+      name: "data".to_string(),
+    }]);
+    assert!(!daemon_skips_protected(
+      PruneKind::Volumes,
+      &PruneFilters::default(),
+      &pinned
+    ));
+    assert!(daemon_skips_protected(
+      PruneKind::Networks,
+      &PruneFilters::default(),
+      &pinned
+    ));
+  }
 }
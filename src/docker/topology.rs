@@ -0,0 +1,266 @@
+//! Network topology derived from networks and containers
+
+use super::{COMPOSE_PROJECT_LABEL, ContainerInfo, NetworkInfo};
+
+/// A container attached to a network hub
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopologyNode {
+  pub container_id: String,
+  pub name: String,
+  pub running: bool,
+  /// Compose project the container belongs to
+  pub project: Option<String>,
+  pub address: Option<String>,
+  pub aliases: Vec<String>,
+}
+
+/// A network and the containers attached to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopologyHub {
+  pub network_id: String,
+  pub name: String,
+  pub driver: String,
+  pub subnet: Option<String>,
+  pub internal: bool,
+  pub nodes: Vec<TopologyNode>,
+}
+
+/// A host port published by a container, drawn as an edge leaving the graph
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishedPort {
+  pub node: TopologyNode,
+  /// `8080` or `127.0.0.1:8080`
+  pub host: String,
+  /// `80/tcp`
+  pub target: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetworkTopology {
+  pub hubs: Vec<TopologyHub>,
+  pub published: Vec<PublishedPort>,
+}
+
+impl NetworkTopology {
+  pub fn build(networks: &[NetworkInfo], containers: &[ContainerInfo]) -> Self {
+    let node = |container: &ContainerInfo| TopologyNode {
+      container_id: container.id.clone(),
+      name: container.name.clone(),
+      running: container.state.is_running(),
+      project: container.labels.get(COMPOSE_PROJECT_LABEL).cloned(),
+      address: None,
+      aliases: Vec::new(),
+    };
+
+    let mut hubs: Vec<TopologyHub> = networks
+      .iter()
+      .map(|network| {
+        let mut nodes: Vec<TopologyNode> = network
+          .containers
+          .iter()
+          .map(|(id, endpoint)| {
+            let base = containers.iter().find(|c| c.id == *id).map_or_else(
+              // Attached but missing from the container list, e.g. not refreshed yet
+              || TopologyNode {
+                container_id: id.clone(),
+                name: endpoint.name.clone().unwrap_or_else(|| id.chars().take(12).collect()),
+                running: false,
+                project: None,
+                address: None,
+                aliases: Vec::new(),
+              },
+              node,
+            );
+            TopologyNode {
+              address: endpoint.ipv4_address.clone().or_else(|| endpoint.ipv6_address.clone()),
+              aliases: endpoint.aliases.clone(),
+              ..base
+            }
+          })
+          .collect();
+        nodes.sort_by(|a, b| a.name.cmp(&b.name));
+
+        TopologyHub {
+          network_id: network.id.clone(),
+          name: network.name.clone(),
+          driver: network.driver.clone(),
+          subnet: network
+            .ipam
+            .as_ref()
+            .and_then(|ipam| ipam.config.first())
+            .and_then(|config| config.subnet.clone()),
+          internal: network.internal,
+          nodes,
+        }
+      })
+      .collect();
+    // Busy networks first
+    hubs.sort_by(|a, b| {
+      a.nodes
+        .is_empty()
+        .cmp(&b.nodes.is_empty())
+        .then_with(|| a.name.cmp(&b.name))
+    });
+
+    let mut published: Vec<PublishedPort> = Vec::new();
+    for container in containers {
+      for port in &container.ports {
+        let Some(public) = port.public_port else {
+          continue;
+        };
+        let host = match port.ip.as_deref() {
+          None | Some("" | "0.0.0.0" | "::") => public.to_string(),
+          Some(ip) => format!("{ip}:{public}"),
+        };
+        let target = format!("{}/{}", port.private_port, port.protocol);
+        // IPv4 and IPv6 bindings of the same port show up twice
+        let duplicate = published
+          .iter()
+          .any(|p| p.node.container_id == container.id && p.host == host && p.target == target);
+        if !duplicate {
+          published.push(PublishedPort {
+            node: node(container),
+            host,
+            target,
+          });
+        }
+      }
+    }
+
+    Self { hubs, published }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use super::*;
+  use crate::docker::{ContainerState, IpamConfig, IpamInfo, NetworkContainer, PortMapping};
+
+  fn container(id: &str, name: &str, state: ContainerState, ports: Vec<PortMapping>) -> ContainerInfo {
+    ContainerInfo {
+      id: id.to_string(),
+      name: name.to_string(),
+      image: "nginx".to_string(),
+      image_id: String::new(),
+      state,
+      status: String::new(),
+      created: None,
+      ports,
+      labels: HashMap::from([(COMPOSE_PROJECT_LABEL.to_string(), "shop".to_string())]),
+      command: None,
+      size_rw: None,
+      size_root_fs: None,
+    }
+  }
+
+  fn network(id: &str, name: &str, containers: Vec<(&str, &str)>) -> NetworkInfo {
+    NetworkInfo {
+      id: id.to_string(),
+      name: name.to_string(),
+      driver: "bridge".to_string(),
+      scope: "local".to_string(),
+      internal: false,
+      enable_ipv6: false,
+      created: None,
+      labels: HashMap::new(),
+      options: HashMap::new(),
+      ipam: Some(IpamInfo {
+        driver: None,
+        config: vec![IpamConfig {
+          subnet: Some("172.20.0.0/16".to_string()),
+          gateway: None,
+          ip_range: None,
+        }],
+      }),
+      containers: containers
+        .into_iter()
+        .map(|(id, ip)| {
+          (
+            id.to_string(),
+            NetworkContainer {
+              name: None,
+              endpoint_id: None,
+              mac_address: None,
+              ipv4_address: Some(ip.to_string()),
+              ipv6_address: None,
+              aliases: vec!["api".to_string()],
+            },
+          )
+        })
+        .collect(),
+    }
+  }
+
+  #[test]
+  fn test_topology_hubs() {
+    let containers = vec![
+      container("c1", "web", ContainerState::Running, Vec::new()),
+      container("c2", "db", ContainerState::Exited, Vec::new()),
+    ];
+    let networks = vec![
+      network("n0", "idle", Vec::new()),
+      network(
+        "n1",
+        "shop_default",
+        vec![
+          ("c1", "172.20.0.2/16"),
+          ("c2", "172.20.0.3/16"),
+          ("gone", "172.20.0.9/16"),
+        ],
+      ),
+    ];
+
+    let topology = NetworkTopology::build(&networks, &containers);
+    assert_eq!(topology.hubs.len(), 2);
+
+    let hub = &topology.hubs[0];
+    assert_eq!(hub.name, "shop_default");
+    assert_eq!(hub.subnet.as_deref(), Some("172.20.0.0/16"));
+    let names: Vec<&str> = hub.nodes.iter().map(|n| n.name.as_str()).collect();
+    assert_eq!(names, vec!["db", "gone", "web"]);
+
+    let web = &hub.nodes[2];
+    assert!(web.running);
+    assert_eq!(web.project.as_deref(), Some("shop"));
+    assert_eq!(web.address.as_deref(), Some("172.20.0.2/16"));
+    assert_eq!(web.aliases, vec!["api".to_string()]);
+
+    let gone = &hub.nodes[1];
+    assert!(!gone.running);
+    assert_eq!(gone.project, None);
+
+    assert!(topology.hubs[1].nodes.is_empty());
+  }
+
+  #[test]
+  fn test_topology_published_ports() {
+    let port = |ip: Option<&str>, public: Option<u16>| PortMapping {
+      private_port: 80,
+      public_port: public,
+      protocol: "tcp".to_string(),
+      ip: ip.map(ToString::to_string),
+    };
+    let containers = vec![container(
+      "c1",
+      "web",
+      ContainerState::Running,
+      vec![
+        port(Some("0.0.0.0"), Some(8080)),
+        port(Some("::"), Some(8080)),
+        port(Some("127.0.0.1"), Some(9090)),
+        port(None, None),
+      ],
+    )];
+
+    let topology = NetworkTopology::build(&[], &containers);
+    let edges: Vec<(&str, &str)> = topology
+      .published
+      .iter()
+      .map(|p| (p.host.as_str(), p.target.as_str()))
+      .collect();
+    assert_eq!(edges, vec![("8080", "80/tcp"), ("127.0.0.1:9090", "80/tcp")]);
+    assert_eq!(topology.published[0].node.name, "web");
+  }
+}
@@ -0,0 +1,606 @@
+//! Volume backup, restore and clone
+//!
+//! Archives are streamed through the Docker API from a stopped helper container that
+//! mounts the volume at `/data`, so this works the same for local and VM-backed engines.
+//! Backups are gzip-compressed on the host and get a `sha256sum`-compatible checksum
+//! file next to them.
+
+use anyhow::{Result, bail};
+use bollard::Docker;
+use bollard::models::ContainerCreateBody;
+use bollard::query_parameters::{
+  CreateContainerOptions, DownloadFromContainerOptions, RemoveContainerOptions, UploadToContainerOptions,
+};
+use bytes::Bytes;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use futures::StreamExt;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+use super::prune::ANONYMOUS_VOLUME_LABEL;
+use super::{DockerClient, HELPER_LABEL};
+
+const BACKUP_EXTENSION: &str = ".tar.gz";
+const CHECKSUM_EXTENSION: &str = ".sha256";
+const TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+/// Archive transfers of large volumes take far longer than regular API calls
+const ARCHIVE_TIMEOUT: Duration = Duration::from_secs(6 * 60 * 60);
+/// Minimum number of bytes between progress reports
+const PROGRESS_STEP: u64 = 4 * 1024 * 1024;
+const CHUNK_SIZE: usize = 256 * 1024;
+
+/// A finished backup
+#[derive(Debug, Clone)]
+pub struct VolumeBackup {
+  pub path: PathBuf,
+  /// Compressed size in bytes
+  pub size: u64,
+  pub sha256: String,
+}
+
+/// A backup archive found on disk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VolumeBackupFile {
+  pub path: PathBuf,
+  pub created: DateTime<Utc>,
+  pub size: u64,
+  pub has_checksum: bool,
+}
+
+impl VolumeBackupFile {
+  pub fn file_name(&self) -> String {
+    self
+      .path
+      .file_name()
+      .map(|n| n.to_string_lossy().to_string())
+      .unwrap_or_default()
+  }
+
+  pub fn display_size(&self) -> String {
+    bytesize::ByteSize(self.size).to_string()
+  }
+}
+
+/// File name for a backup of `volume` taken at `at`
+pub fn backup_file_name(volume: &str, at: DateTime<Utc>) -> String {
+  format!("{volume}-{}{BACKUP_EXTENSION}", at.format(TIMESTAMP_FORMAT))
+}
+
+/// Time a backup was taken, if `file_name` is a backup of `volume`
+pub fn backup_timestamp(volume: &str, file_name: &str) -> Option<DateTime<Utc>> {
+  let stamp = file_name
+    .strip_prefix(volume)?
+    .strip_prefix('-')?
+    .strip_suffix(BACKUP_EXTENSION)?;
+  NaiveDateTime::parse_from_str(stamp, TIMESTAMP_FORMAT)
+    .ok()
+    .map(|t| t.and_utc())
+}
+
+/// Checksum file stored next to an archive
+pub fn checksum_path(archive: &Path) -> PathBuf {
+  let mut name = archive.as_os_str().to_owned();
+  name.push(CHECKSUM_EXTENSION);
+  PathBuf::from(name)
+}
+
+/// Labels for a clone of a volume. Compose and anonymous-volume markers are dropped so
+/// the copy isn't adopted by the source's project or swept up by an anonymous volume prune.
+fn cloned_volume_labels(labels: HashMap<String, String>) -> Vec<(String, String)> {
+  labels
+    .into_iter()
+    .filter(|(key, _)| !key.starts_with("com.docker.compose.") && key != ANONYMOUS_VOLUME_LABEL)
+    .collect()
+}
+
+/// Hash from a `sha256sum` style line (`<hex>  <file name>`)
+pub fn parse_checksum(content: &str) -> Option<String> {
+  let hash = content.split_whitespace().next()?;
+  (hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit())).then(|| hash.to_lowercase())
+}
+
+/// Backups of a volume in `dir`, newest first
+pub fn list_volume_backups(dir: &Path, volume: &str) -> Vec<VolumeBackupFile> {
+  let Ok(entries) = fs::read_dir(dir) else {
+    return Vec::new();
+  };
+
+  let mut backups: Vec<VolumeBackupFile> = entries
+    .filter_map(Result::ok)
+    .filter_map(|entry| {
+      let path = entry.path();
+      let created = backup_timestamp(volume, &entry.file_name().to_string_lossy())?;
+      let size = entry.metadata().ok().filter(fs::Metadata::is_file)?.len();
+      let has_checksum = checksum_path(&path).exists();
+      Some(VolumeBackupFile {
+        path,
+        created,
+        size,
+        has_checksum,
+      })
+    })
+    .collect();
+
+  backups.sort_by(|a, b| b.created.cmp(&a.created));
+  backups
+}
+
+/// Delete all but the newest `keep` backups of a volume, returning how many were removed
+pub fn prune_volume_backups(dir: &Path, volume: &str, keep: usize) -> Result<usize> {
+  let expired: Vec<_> = list_volume_backups(dir, volume).into_iter().skip(keep).collect();
+  for backup in &expired {
+    fs::remove_file(&backup.path)?;
+    let _ = fs::remove_file(checksum_path(&backup.path));
+  }
+  Ok(expired.len())
+}
+
+/// Check an archive against its checksum file.
+/// Returns `false` when there is no checksum to compare with and fails on a mismatch.
+pub fn verify_backup(archive: &Path) -> Result<bool> {
+  let Ok(content) = fs::read_to_string(checksum_path(archive)) else {
+    return Ok(false);
+  };
+  let Some(expected) = parse_checksum(&content) else {
+    bail!("Checksum file for {} is malformed", archive.display());
+  };
+
+  let mut reader = BufReader::new(File::open(archive)?);
+  let mut hasher = Sha256::new();
+  io::copy(&mut reader, &mut hasher)?;
+  let actual = hex::encode(hasher.finalize());
+  if actual != expected {
+    bail!("Checksum mismatch for {}: the archive is corrupted", archive.display());
+  }
+  Ok(true)
+}
+
+/// Writer that hashes and counts everything written through it
+struct HashingWriter<W> {
+  inner: W,
+  hasher: Sha256,
+  written: u64,
+}
+
+impl<W: Write> HashingWriter<W> {
+  fn new(inner: W) -> Self {
+    Self {
+      inner,
+      hasher: Sha256::new(),
+      written: 0,
+    }
+  }
+
+  /// Flush and return the number of bytes written and their SHA-256
+  fn finish(mut self) -> io::Result<(u64, String)> {
+    self.inner.flush()?;
+    Ok((self.written, hex::encode(self.hasher.finalize())))
+  }
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    let n = self.inner.write(buf)?;
+    self.hasher.update(&buf[..n]);
+    self.written += n as u64;
+    Ok(n)
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    self.inner.flush()
+  }
+}
+
+/// Reader that counts the bytes read through it
+struct CountingReader<R> {
+  inner: R,
+  count: Arc<AtomicU64>,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+  fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    let n = self.inner.read(buf)?;
+    self.count.fetch_add(n as u64, Ordering::Relaxed);
+    Ok(n)
+  }
+}
+
+impl DockerClient {
+  /// Back up a volume to a compressed archive in `dir`.
+  /// `on_progress` receives the number of uncompressed bytes read from the volume so far.
+  pub async fn backup_volume(
+    &self,
+    volume: &str,
+    dir: &Path,
+    mut on_progress: impl FnMut(u64),
+  ) -> Result<VolumeBackup> {
+    fs::create_dir_all(dir)?;
+    let file_name = backup_file_name(volume, Utc::now());
+    let path = dir.join(&file_name);
+    // Written under a temporary name so failed backups never look like real ones
+    let partial = dir.join(format!("{file_name}.partial"));
+
+    let docker = self.archive_client()?;
+    let holder = self.archive_holder(&docker, volume, true).await?;
+
+    let result = async {
+      let (tx, mut rx) = mpsc::channel::<Bytes>(16);
+      let partial_path = partial.clone();
+      let writer = tokio::task::spawn_blocking(move || -> io::Result<(u64, String)> {
+        let file = BufWriter::new(File::create(&partial_path)?);
+        let mut encoder = GzEncoder::new(HashingWriter::new(file), Compression::default());
+        while let Some(chunk) = rx.blocking_recv() {
+          encoder.write_all(&chunk)?;
+        }
+        encoder.finish()?.finish()
+      });
+
+      let mut stream = docker.download_from_container(
+        &holder,
+        Some(DownloadFromContainerOptions {
+          path: "/data".to_string(),
+        }),
+      );
+      let mut received = 0;
+      let mut reported = 0;
+      let mut failure = None;
+      while let Some(chunk) = stream.next().await {
+        let chunk = match chunk {
+          Ok(chunk) => chunk,
+          Err(e) => {
+            failure = Some(e);
+            break;
+          }
+        };
+        received += chunk.len() as u64;
+        // The writer only hangs up when it failed; its error is returned below
+        if tx.send(chunk).await.is_err() {
+          break;
+        }
+        if received - reported >= PROGRESS_STEP {
+          on_progress(received);
+          reported = received;
+        }
+      }
+      drop(tx);
+
+      // Wait for the writer even after a failed download so the partial file can be removed
+      let written = writer.await?;
+      if let Some(e) = failure {
+        return Err(e.into());
+      }
+      Ok::<_, anyhow::Error>(written?)
+    }
+    .await;
+
+    remove_archive_holder(&docker, &holder).await;
+
+    let (size, sha256) = match result {
+      Ok(written) => written,
+      Err(e) => {
+        let _ = fs::remove_file(&partial);
+        return Err(e);
+      }
+    };
+
+    fs::rename(&partial, &path)?;
+    fs::write(checksum_path(&path), format!("{sha256}  {file_name}\n"))?;
+
+    Ok(VolumeBackup { path, size, sha256 })
+  }
+
+  /// Restore an archive into a volume, creating the volume if it does not exist.
+  /// Files in the archive replace existing ones with the same path; other files are kept.
+  /// `on_progress` receives the number of compressed bytes read from the archive so far.
+  /// Returns whether the archive was verified against its checksum file.
+  pub async fn restore_volume(
+    &self,
+    archive: &Path,
+    volume: &str,
+    mut on_progress: impl FnMut(u64) + Send + 'static,
+  ) -> Result<bool> {
+    let archive = archive.to_path_buf();
+    let verify_path = archive.clone();
+    let verified = tokio::task::spawn_blocking(move || verify_backup(&verify_path)).await??;
+
+    let docker = self.archive_client()?;
+    if docker.inspect_volume(volume).await.is_err() {
+      self.create_volume_with_opts(volume, "local", Vec::new()).await?;
+    }
+    let holder = self.archive_holder(&docker, volume, false).await?;
+
+    let (tx, rx) = mpsc::channel::<Bytes>(16);
+    let read_bytes = Arc::new(AtomicU64::new(0));
+    let counter = read_bytes.clone();
+    let reader = tokio::task::spawn_blocking(move || -> io::Result<()> {
+      let file = CountingReader {
+        inner: File::open(&archive)?,
+        count: counter,
+      };
+      let mut decoder = GzDecoder::new(BufReader::new(file));
+      let mut buf = vec![0; CHUNK_SIZE];
+      loop {
+        let n = decoder.read(&mut buf)?;
+        // Stop when done or when the upload hung up after an error
+        if n == 0 || tx.blocking_send(Bytes::copy_from_slice(&buf[..n])).is_err() {
+          return Ok(());
+        }
+      }
+    });
+
+    let mut reported = 0;
+    let body =
+      futures::stream::unfold(rx, |mut rx| async move { rx.recv().await.map(|chunk| (chunk, rx)) }).map(move |chunk| {
+        let read = read_bytes.load(Ordering::Relaxed);
+        if read - reported >= PROGRESS_STEP {
+          on_progress(read);
+          reported = read;
+        }
+        chunk
+      });
+
+    // Archives hold a top-level `data/` directory, which lands on the volume mount
+    let upload = docker
+      .upload_to_container_streaming(
+        &holder,
+        Some(UploadToContainerOptions {
+          path: "/".to_string(),
+          ..Default::default()
+        }),
+        body,
+      )
+      .await;
+    let read = reader.await;
+
+    remove_archive_holder(&docker, &holder).await;
+
+    read??;
+    upload?;
+    Ok(verified)
+  }
+
+  /// Copy the contents of a volume into a new volume with the same driver and user labels.
+  /// `on_progress` receives the number of bytes copied so far.
+  pub async fn clone_volume(
+    &self,
+    source: &str,
+    target: &str,
+    mut on_progress: impl FnMut(u64) + Send + 'static,
+  ) -> Result<()> {
+    let docker = self.archive_client()?;
+    if docker.inspect_volume(target).await.is_ok() {
+      bail!("Volume '{target}' already exists");
+    }
+    let source_info = docker.inspect_volume(source).await?;
+    self
+      .create_volume_with_opts(target, &source_info.driver, cloned_volume_labels(source_info.labels))
+      .await?;
+
+    let result = async {
+      let source_holder = self.archive_holder(&docker, source, true).await?;
+      let target_holder = match self.archive_holder(&docker, target, false).await {
+        Ok(holder) => holder,
+        Err(e) => {
+          remove_archive_holder(&docker, &source_holder).await;
+          return Err(e);
+        }
+      };
+
+      let mut copied = 0;
+      let mut reported = 0;
+      let body = docker
+        .download_from_container(
+          &source_holder,
+          Some(DownloadFromContainerOptions {
+            path: "/data".to_string(),
+          }),
+        )
+        .map(move |chunk| {
+          if let Ok(chunk) = &chunk {
+            copied += chunk.len() as u64;
+            if copied - reported >= PROGRESS_STEP {
+              on_progress(copied);
+              reported = copied;
+            }
+          }
+          chunk.map_err(io::Error::other)
+        });
+
+      let upload = docker
+        .upload_to_container(
+          &target_holder,
+          Some(UploadToContainerOptions {
+            path: "/".to_string(),
+            ..Default::default()
+          }),
+          bollard::body_try_stream(body),
+        )
+        .await;
+
+      remove_archive_holder(&docker, &source_holder).await;
+      remove_archive_holder(&docker, &target_holder).await;
+      upload.map_err(anyhow::Error::from)
+    }
+    .await;
+
+    // Don't leave a half-copied volume behind
+    if result.is_err() {
+      let _ = self.remove_volume(target, true).await;
+    }
+    result
+  }
+
+  /// API client with a timeout long enough for whole-volume archive transfers
+  pub(super) fn archive_client(&self) -> Result<Docker> {
+    let mut docker = self.client()?.clone();
+    docker.set_timeout(ARCHIVE_TIMEOUT);
+    Ok(docker)
+  }
+
+  /// Create a stopped container with the volume mounted at `/data`.
+  /// Archive endpoints work on stopped containers, so it never needs to run.
+  async fn archive_holder(&self, docker: &Docker, volume: &str, read_only: bool) -> Result<String> {
+    let timestamp = std::time::SystemTime::now()
+      .duration_since(std::time::UNIX_EPOCH)
+      .unwrap_or_default()
+      .as_nanos();
+    let container_name = format!("docker-ui-vol-archive-{timestamp}");
+
+    let mode = if read_only { ":ro" } else { "" };
+    let host_config = bollard::models::HostConfig {
+      binds: Some(vec![format!("{volume}:/data{mode}")]),
+      ..Default::default()
+    };
+
+    let config = ContainerCreateBody {
+      image: Some(self.helper_image().await?),
+      cmd: Some(vec!["true".to_string()]),
+      labels: Some([(HELPER_LABEL.to_string(), "archive".to_string())].into()),
+      host_config: Some(host_config),
+      ..Default::default()
+    };
+
+    let options = CreateContainerOptions {
+      name: Some(container_name.clone()),
+      ..Default::default()
+    };
+
+    docker.create_container(Some(options), config).await?;
+    Ok(container_name)
+  }
+}
+
+async fn remove_archive_holder(docker: &Docker, name: &str) {
+  let _ = docker
+    .remove_container(
+      name,
+      Some(RemoveContainerOptions {
+        force: true,
+        ..Default::default()
+      }),
+    )
+    .await;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::TimeZone;
+
+  fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("dockside-backup-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+  }
+
+  #[test]
+  fn test_backup_file_name_round_trip() {
+    let at = Utc.with_ymd_and_hms(2024, 3, 9, 14, 5, 7).unwrap();
+    let name = backup_file_name("pg-data", at);
+    assert_eq!(name, "pg-data-20240309-140507.tar.gz");
+    assert_eq!(backup_timestamp("pg-data", &name), Some(at));
+
+    // Backups of other volumes sharing a prefix don't match
+    assert_eq!(backup_timestamp("pg", &name), None);
+    assert_eq!(
+      backup_timestamp("pg-data", "pg-data-20240309-140507.tar.gz.partial"),
+      None
+    );
+    assert_eq!(backup_timestamp("pg-data", "pg-data-latest.tar.gz"), None);
+  }
+
+  #[test]
+  fn test_parse_checksum() {
+    let hash = "a".repeat(64);
+    assert_eq!(parse_checksum(&format!("{hash}  vol.tar.gz\n")), Some(hash));
+    assert_eq!(parse_checksum(&"A".repeat(64)), Some("a".repeat(64)));
+    assert_eq!(parse_checksum("abc123  vol.tar.gz"), None);
+    assert_eq!(parse_checksum(""), None);
+  }
+
+  #[test]
+  fn test_cloned_volume_labels() {
+    let labels = HashMap::from([
+      ("com.docker.compose.project".to_string(), "shop".to_string()),
+      ("com.docker.compose.volume".to_string(), "db".to_string()),
+      ("com.docker.volume.anonymous".to_string(), String::new()),
+      ("com.example.team".to_string(), "payments".to_string()),
+      ("keep".to_string(), "true".to_string()),
+    ]);
+    let mut cloned = cloned_volume_labels(labels);
+    cloned.sort();
+    assert_eq!(
+      cloned,
+      vec![
+        ("com.example.team".to_string(), "payments".to_string()),
+        ("keep".to_string(), "true".to_string()),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_list_and_prune_backups() {
+    let dir = temp_dir("prune");
+    for day in 1..=4 {
+      let at = Utc.with_ymd_and_hms(2024, 1, day, 0, 0, 0).unwrap();
+      let path = dir.join(backup_file_name("db", at));
+      fs::write(&path, b"archive").unwrap();
+      fs::write(checksum_path(&path), "checksum").unwrap();
+    }
+    fs::write(dir.join("db-cache-20240105-000000.tar.gz"), b"other").unwrap();
+    fs::write(dir.join("notes.txt"), b"unrelated").unwrap();
+
+    let backups = list_volume_backups(&dir, "db");
+    assert_eq!(backups.len(), 4);
+    assert_eq!(backups[0].file_name(), "db-20240104-000000.tar.gz");
+    assert!(backups.iter().all(|b| b.has_checksum && b.size == 7));
+
+    assert_eq!(prune_volume_backups(&dir, "db", 2).unwrap(), 2);
+    let remaining: Vec<_> = list_volume_backups(&dir, "db")
+      .iter()
+      .map(VolumeBackupFile::file_name)
+      .collect();
+    assert_eq!(remaining, ["db-20240104-000000.tar.gz", "db-20240103-000000.tar.gz"]);
+    assert!(!checksum_path(&dir.join("db-20240101-000000.tar.gz")).exists());
+    assert!(dir.join("db-cache-20240105-000000.tar.gz").exists());
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn test_verify_backup() {
+    let dir = temp_dir("verify");
+    let archive = dir.join("vol-20240101-000000.tar.gz");
+
+    let mut writer = HashingWriter::new(File::create(&archive).unwrap());
+    writer.write_all(b"backup contents").unwrap();
+    let (size, sha256) = writer.finish().unwrap();
+    assert_eq!(size, 15);
+
+    // No checksum file: nothing to verify against
+    assert!(!verify_backup(&archive).unwrap());
+
+    fs::write(
+      checksum_path(&archive),
+      format!("{sha256}  vol-20240101-000000.tar.gz\n"),
+    )
+    .unwrap();
+    assert!(verify_backup(&archive).unwrap());
+
+    fs::write(&archive, b"tampered contents").unwrap();
+    assert!(verify_backup(&archive).is_err());
+
+    let _ = fs::remove_dir_all(&dir);
+  }
+}
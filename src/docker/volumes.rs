@@ -1,14 +1,21 @@
-use anyhow::Result;
-use bollard::models::ContainerCreateBody;
+use anyhow::{Result, anyhow, bail};
+use bollard::models::ContainerSummary;
 use bollard::query_parameters::{
-  CreateContainerOptions, ListVolumesOptions, LogsOptions, RemoveContainerOptions, RemoveVolumeOptions,
+  DownloadFromContainerOptions, ListContainersOptions, ListVolumesOptions, RemoveVolumeOptions,
+  UploadToContainerOptions,
 };
 use chrono::{DateTime, Utc};
 use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Component, Path, PathBuf};
 
-use super::DockerClient;
+use super::{DockerClient, HELPER_LABEL};
+
+/// Mode given to files created in a volume
+const NEW_FILE_MODE: u32 = 0o644;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VolumeInfo {
@@ -20,20 +27,59 @@ pub struct VolumeInfo {
   pub scope: String,
   pub status: Option<HashMap<String, String>>,
   pub usage_data: Option<VolumeUsage>,
+  /// Containers that mount this volume
+  #[serde(default)]
+  pub containers: Vec<VolumeContainer>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VolumeUsage {
+  /// Size in bytes, or -1 when the driver can't report it
   pub size: i64,
   pub ref_count: i64,
 }
 
+/// A container mounting a volume
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VolumeContainer {
+  pub container_id: String,
+  pub container_name: String,
+  pub running: bool,
+  /// Path inside the container
+  pub destination: String,
+  pub read_write: bool,
+  /// Extra mount options such as `z` or `nocopy`
+  pub mode: String,
+}
+
+impl VolumeContainer {
+  /// `rw`/`ro` plus any extra mount options, e.g. `ro,z`
+  pub fn display_mode(&self) -> String {
+    let access = if self.read_write { "rw" } else { "ro" };
+    let extra: Vec<&str> = self
+      .mode
+      .split(',')
+      .map(str::trim)
+      .filter(|m| !m.is_empty() && *m != "rw" && *m != "ro")
+      .collect();
+    if extra.is_empty() {
+      access.to_string()
+    } else {
+      format!("{access},{}", extra.join(","))
+    }
+  }
+}
+
 impl VolumeInfo {
   pub fn display_size(&self) -> String {
-    self.usage_data.as_ref().map_or_else(
-      || "Unknown".to_string(),
-      |u| bytesize::ByteSize(u64::try_from(u.size).unwrap_or(0)).to_string(),
-    )
+    self
+      .size()
+      .map_or_else(|| "Unknown".to_string(), |size| bytesize::ByteSize(size).to_string())
+  }
+
+  /// Size in bytes when the engine reported one
+  pub fn size(&self) -> Option<u64> {
+    self.usage_data.as_ref().and_then(|u| u64::try_from(u.size).ok())
   }
 
   pub fn is_in_use(&self) -> bool {
@@ -62,12 +108,41 @@ impl VolumeFileEntry {
 }
 
 impl DockerClient {
+  /// List volumes with disk usage from `/system/df` and the containers mounting each one
   pub async fn list_volumes(&self) -> Result<Vec<VolumeInfo>> {
     let docker = self.client()?;
 
     let options = ListVolumesOptions { ..Default::default() };
+    let containers_options = ListContainersOptions {
+      all: true,
+      ..Default::default()
+    };
 
-    let response = docker.list_volumes(Some(options)).await?;
+    // Usage is best effort: df can be slow or unsupported on some engines
+    let (response, df, containers) = tokio::join!(
+      docker.list_volumes(Some(options)),
+      docker.df(None),
+      docker.list_containers(Some(containers_options)),
+    );
+    let response = response?;
+
+    let usage: HashMap<String, VolumeUsage> = df
+      .ok()
+      .and_then(|df| df.volumes)
+      .unwrap_or_default()
+      .into_iter()
+      .filter_map(|v| {
+        let usage = v.usage_data?;
+        Some((
+          v.name,
+          VolumeUsage {
+            size: usage.size,
+            ref_count: usage.ref_count,
+          },
+        ))
+      })
+      .collect();
+    let (mut mounts, helper_refs) = volume_mounts(&containers.unwrap_or_default());
 
     let volumes = response.volumes.unwrap_or_default();
     let mut result = Vec::new();
@@ -79,12 +154,18 @@ impl DockerClient {
         .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
         .map(|dt| dt.with_timezone(&Utc));
 
-      let usage_data = volume.usage_data.map(|u| VolumeUsage {
-        size: u.size,
-        ref_count: u.ref_count,
-      });
+      // Our own helper containers don't count as users of the volume
+      let usage_data = usage
+        .get(&volume.name)
+        .map(|u| (u.size, u.ref_count))
+        .or_else(|| volume.usage_data.as_ref().map(|u| (u.size, u.ref_count)))
+        .map(|(size, ref_count)| VolumeUsage {
+          size,
+          ref_count: (ref_count - helper_refs.get(&volume.name).copied().unwrap_or(0)).max(0),
+        });
 
       result.push(VolumeInfo {
+        containers: mounts.remove(&volume.name).unwrap_or_default(),
         name: volume.name,
         driver: volume.driver,
         mountpoint: volume.mountpoint,
@@ -102,6 +183,8 @@ impl DockerClient {
 
   pub async fn remove_volume(&self, name: &str, force: bool) -> Result<()> {
     let docker = self.client()?;
+    // Our own helper would otherwise keep the volume in use
+    self.release_volume_helper(name).await;
     docker.remove_volume(name, Some(RemoveVolumeOptions { force })).await?;
     Ok(())
   }
@@ -138,90 +221,20 @@ impl DockerClient {
       scope: volume.scope.map(|s| format!("{s:?}")).unwrap_or_default(),
       status: None,
       usage_data: None,
+      containers: Vec::new(),
     })
   }
 
-  /// List files in a volume by running a temporary container with ls command
-  /// Docker API doesn't expose direct volume file browsing, so we create a
-  /// lightweight container that runs ls and exits immediately.
+  /// List files in a volume by running `ls` in the volume's helper container.
+  /// Docker API doesn't expose direct volume file browsing.
   pub async fn list_volume_files(&self, volume_name: &str, path: &str) -> Result<Vec<VolumeFileEntry>> {
-    let docker = self.client()?;
-
-    // Normalize path - volume is mounted at /data
-    let normalized_path = if path.is_empty() || path == "/" {
-      "/data".to_string()
-    } else {
-      format!("/data{}", path.trim_end_matches('/'))
-    };
-
-    // Create a temporary container that runs ls command and exits
-    // Use timestamp for unique name
-    let timestamp = std::time::SystemTime::now()
-      .duration_since(std::time::UNIX_EPOCH)
-      .unwrap_or_default()
-      .as_nanos();
-    let container_name = format!("docker-ui-vol-{timestamp}");
-
-    let host_config = bollard::models::HostConfig {
-      binds: Some(vec![format!("{}:/data:ro", volume_name)]),
-      ..Default::default()
-    };
-
-    let config = ContainerCreateBody {
-      image: Some("alpine:latest".to_string()),
-      cmd: Some(vec!["ls".to_string(), "-la".to_string(), normalized_path.clone()]),
-      host_config: Some(host_config),
-      tty: Some(false),
-      attach_stdout: Some(true),
-      attach_stderr: Some(true),
-      ..Default::default()
-    };
-
-    let options = CreateContainerOptions {
-      name: Some(container_name.clone()),
-      ..Default::default()
-    };
-
-    // Create and start container (it will run ls and exit immediately)
-    docker.create_container(Some(options), config).await?;
-    docker
-      .start_container(
-        &container_name,
-        None::<bollard::query_parameters::StartContainerOptions>,
+    let output = self
+      .run_in_volume(
+        volume_name,
+        vec!["ls".to_string(), "-la".to_string(), volume_path(path)],
       )
       .await?;
 
-    // Wait for container to finish
-    let mut wait_stream =
-      docker.wait_container(&container_name, None::<bollard::query_parameters::WaitContainerOptions>);
-    while (wait_stream.next().await).is_some() {}
-
-    // Get the logs (output of ls command)
-    let log_options = LogsOptions {
-      stdout: true,
-      stderr: true,
-      ..Default::default()
-    };
-
-    let mut logs_stream = docker.logs(&container_name, Some(log_options));
-    let mut output = String::new();
-    while let Some(chunk) = logs_stream.next().await {
-      if let Ok(log) = chunk {
-        output.push_str(&log.to_string());
-      }
-    }
-
-    // Remove the container
-    let _ = docker
-      .remove_container(
-        &container_name,
-        Some(RemoveContainerOptions {
-          force: true,
-          ..Default::default()
-        }),
-      )
-      .await;
-
     // Parse the output
     let base_path = if path.is_empty() || path == "/" {
       "/".to_string()
@@ -279,232 +292,361 @@ impl DockerClient {
     Ok(entries)
   }
 
-  /// Read file content from a volume using a temporary container
+  /// Read file content from a volume
   pub async fn read_volume_file(&self, volume_name: &str, path: &str) -> Result<String> {
-    let docker = self.client()?;
-
-    // Normalize path - volume is mounted at /data
-    let normalized_path = format!("/data{}", path.trim_start_matches('/'));
-
-    // Create a temporary container that runs cat command and exits
-    let timestamp = std::time::SystemTime::now()
-      .duration_since(std::time::UNIX_EPOCH)
-      .unwrap_or_default()
-      .as_nanos();
-    let container_name = format!("docker-ui-vol-read-{timestamp}");
+    self
+      .run_in_volume(volume_name, vec!["cat".to_string(), volume_path(path)])
+      .await
+  }
 
-    let host_config = bollard::models::HostConfig {
-      binds: Some(vec![format!("{}:/data:ro", volume_name)]),
-      ..Default::default()
-    };
+  /// Resolve a symlink in a volume to a volume-relative path
+  pub async fn resolve_volume_symlink(&self, volume_name: &str, path: &str) -> Result<String> {
+    let output = self
+      .run_in_volume(
+        volume_name,
+        vec!["readlink".to_string(), "-f".to_string(), volume_path(path)],
+      )
+      .await?;
 
-    let config = ContainerCreateBody {
-      image: Some("alpine:latest".to_string()),
-      cmd: Some(vec!["cat".to_string(), normalized_path]),
-      host_config: Some(host_config),
-      tty: Some(false),
-      attach_stdout: Some(true),
-      attach_stderr: Some(true),
-      ..Default::default()
-    };
+    // Convert /data path back to volume-relative path
+    let output = output.trim();
+    let result = output.strip_prefix("/data").unwrap_or(output).to_string();
+    let result = if result.is_empty() { "/".to_string() } else { result };
 
-    let options = CreateContainerOptions {
-      name: Some(container_name.clone()),
-      ..Default::default()
-    };
+    Ok(result)
+  }
 
-    // Create and start container
-    docker.create_container(Some(options), config).await?;
-    docker
-      .start_container(
-        &container_name,
-        None::<bollard::query_parameters::StartContainerOptions>,
+  /// Check if a path in a volume is a directory
+  pub async fn is_volume_directory(&self, volume_name: &str, path: &str) -> Result<bool> {
+    let result = self
+      .exec_in_volume(
+        volume_name,
+        vec!["test".to_string(), "-d".to_string(), volume_path(path)],
       )
       .await?;
+    Ok(result.is_success())
+  }
 
-    // Wait for container to finish
-    let mut wait_stream =
-      docker.wait_container(&container_name, None::<bollard::query_parameters::WaitContainerOptions>);
-    while (wait_stream.next().await).is_some() {}
-
-    // Get the logs (output of cat command)
-    let log_options = LogsOptions {
-      stdout: true,
-      stderr: true,
-      ..Default::default()
-    };
+  /// Run a shell script in the volume's helper container.
+  /// `args` are passed as positional parameters so paths never need quoting.
+  /// Fails with the script output when it exits non-zero.
+  async fn run_volume_script(&self, volume_name: &str, script: &str, args: &[String]) -> Result<String> {
+    let mut cmd = vec!["sh".to_string(), "-c".to_string(), script.to_string(), "sh".to_string()];
+    cmd.extend(args.iter().cloned());
+    self.run_in_volume(volume_name, cmd).await
+  }
 
-    let mut logs_stream = docker.logs(&container_name, Some(log_options));
-    let mut output = String::new();
-    while let Some(chunk) = logs_stream.next().await {
-      if let Ok(log) = chunk {
-        output.push_str(&log.to_string());
-      }
-    }
+  /// Owner and mode of a path, or of its parent directory when the path doesn't exist yet.
+  /// The flag is `true` when the path exists.
+  async fn volume_entry_owner(&self, volume_name: &str, path: &str) -> Result<(FileOwner, bool)> {
+    let script =
+      r#"if [ -e "$1" ]; then stat -c '%u %g %a' "$1"; else stat -c '%u %g %a' "$(dirname "$1")" && echo new; fi"#;
+    let output = self
+      .run_volume_script(volume_name, script, &[volume_path(path)])
+      .await?;
+    let owner = FileOwner::parse(&output).ok_or_else(|| anyhow!("Unexpected stat output: {}", output.trim()))?;
+    Ok((owner, !output.lines().any(|l| l.trim() == "new")))
+  }
 
-    // Remove the container
-    let _ = docker
-      .remove_container(
-        &container_name,
-        Some(RemoveContainerOptions {
-          force: true,
+  /// Upload an archive and extract it into a directory of the volume
+  async fn upload_volume_archive(&self, volume_name: &str, dir: &str, archive: Vec<u8>) -> Result<()> {
+    let docker = self.archive_client()?;
+    let helper = self.volume_archive_target(volume_name).await?;
+    docker
+      .upload_to_container(
+        &helper,
+        Some(UploadToContainerOptions {
+          path: volume_path(dir),
           ..Default::default()
         }),
+        bollard::body_full(archive.into()),
       )
-      .await;
+      .await?;
+    Ok(())
+  }
 
-    Ok(output)
+  /// Write a text file in a volume. Existing files keep their owner and mode;
+  /// new files get the owner of their directory.
+  pub async fn write_volume_file(&self, volume_name: &str, path: &str, content: &str) -> Result<()> {
+    let (dir, name) = split_volume_path(path).ok_or_else(|| anyhow!("Invalid file path: {path}"))?;
+    let (owner, exists) = self.volume_entry_owner(volume_name, path).await?;
+    let mode = if exists { owner.mode } else { NEW_FILE_MODE };
+
+    let mut header = tar::Header::new_gnu();
+    header.set_size(content.len() as u64);
+    header.set_mode(mode);
+    header.set_uid(owner.uid);
+    header.set_gid(owner.gid);
+    header.set_mtime(u64::try_from(Utc::now().timestamp()).unwrap_or_default());
+    let mut builder = tar::Builder::new(Vec::new());
+    builder.append_data(&mut header, name, content.as_bytes())?;
+
+    self
+      .upload_volume_archive(volume_name, dir, builder.into_inner()?)
+      .await
   }
 
-  /// Resolve a symlink in a volume using a temporary container
-  pub async fn resolve_volume_symlink(&self, volume_name: &str, path: &str) -> Result<String> {
-    let docker = self.client()?;
+  /// Create an empty file owned by the owner of its directory. Fails if the path exists.
+  pub async fn create_volume_file(&self, volume_name: &str, path: &str) -> Result<()> {
+    let script = r#"[ ! -e "$1" ] || { echo "$1 already exists"; exit 1; }
+touch "$1" && chmod 644 "$1" && chown "$(stat -c %u:%g "$(dirname "$1")")" "$1""#;
+    self
+      .run_volume_script(volume_name, script, &[volume_path(path)])
+      .await?;
+    Ok(())
+  }
 
-    let normalized_path = format!("/data{}", path.trim_start_matches('/'));
+  /// Create a directory owned by the owner of its parent. Fails if the path exists.
+  pub async fn create_volume_directory(&self, volume_name: &str, path: &str) -> Result<()> {
+    let script = r#"mkdir -- "$1" && chown "$(stat -c %u:%g "$(dirname "$1")")" "$1""#;
+    self
+      .run_volume_script(volume_name, script, &[volume_path(path)])
+      .await?;
+    Ok(())
+  }
 
-    let timestamp = std::time::SystemTime::now()
-      .duration_since(std::time::UNIX_EPOCH)
-      .unwrap_or_default()
-      .as_nanos();
-    let container_name = format!("docker-ui-vol-link-{timestamp}");
+  /// Rename or move an entry within a volume. Fails if the destination exists.
+  pub async fn rename_volume_entry(&self, volume_name: &str, from: &str, to: &str) -> Result<()> {
+    let script = r#"[ ! -e "$2" ] || { echo "$2 already exists"; exit 1; }
+mv -- "$1" "$2""#;
+    self
+      .run_volume_script(volume_name, script, &[volume_path(from), volume_path(to)])
+      .await?;
+    Ok(())
+  }
 
-    let host_config = bollard::models::HostConfig {
-      binds: Some(vec![format!("{}:/data:ro", volume_name)]),
-      ..Default::default()
-    };
+  /// Delete a file or directory (recursively) from a volume
+  pub async fn delete_volume_entry(&self, volume_name: &str, path: &str) -> Result<()> {
+    if path.trim_matches('/').is_empty() {
+      bail!("Refusing to delete the volume root");
+    }
+    self
+      .run_volume_script(volume_name, r#"rm -rf -- "$1""#, &[volume_path(path)])
+      .await?;
+    Ok(())
+  }
 
-    let config = ContainerCreateBody {
-      image: Some("alpine:latest".to_string()),
-      cmd: Some(vec!["readlink".to_string(), "-f".to_string(), normalized_path]),
-      host_config: Some(host_config),
-      tty: Some(false),
-      attach_stdout: Some(true),
-      attach_stderr: Some(true),
-      ..Default::default()
-    };
+  /// Copy a host file or directory into a directory of the volume.
+  /// Permissions come from the host; ownership from the destination directory.
+  pub async fn upload_to_volume(&self, volume_name: &str, source: &Path, dest_dir: &str) -> Result<()> {
+    let (owner, exists) = self.volume_entry_owner(volume_name, dest_dir).await?;
+    if !exists {
+      bail!("Directory {dest_dir} does not exist");
+    }
 
-    let options = CreateContainerOptions {
-      name: Some(container_name.clone()),
-      ..Default::default()
-    };
+    let source = source.to_path_buf();
+    let archive = tokio::task::spawn_blocking(move || build_upload_archive(&source, owner.uid, owner.gid)).await??;
+    self.upload_volume_archive(volume_name, dest_dir, archive).await
+  }
 
-    docker.create_container(Some(options), config).await?;
-    docker
-      .start_container(
-        &container_name,
-        None::<bollard::query_parameters::StartContainerOptions>,
-      )
-      .await?;
+  /// Download a file or directory from a volume into `dest_dir` on the host, keeping permissions.
+  /// Returns the path written, which gets a numeric suffix when the name is already taken.
+  pub async fn download_from_volume(&self, volume_name: &str, path: &str, dest_dir: &Path) -> Result<PathBuf> {
+    let docker = self.archive_client()?;
+    let helper = self.volume_archive_target(volume_name).await?;
 
-    let mut wait_stream =
-      docker.wait_container(&container_name, None::<bollard::query_parameters::WaitContainerOptions>);
-    while (wait_stream.next().await).is_some() {}
+    let mut stream = docker.download_from_container(
+      &helper,
+      Some(DownloadFromContainerOptions {
+        path: volume_path(path),
+      }),
+    );
+    let mut archive = Vec::new();
+    while let Some(chunk) = stream.next().await {
+      archive.extend_from_slice(&chunk?);
+    }
 
-    let log_options = LogsOptions {
-      stdout: true,
-      stderr: true,
-      ..Default::default()
-    };
+    let dest_dir = dest_dir.to_path_buf();
+    tokio::task::spawn_blocking(move || unpack_download(&archive, &dest_dir)).await?
+  }
+}
+
+/// Volume mounts per volume name, plus how many of each volume's references are our helpers
+pub(super) fn volume_mounts(
+  containers: &[ContainerSummary],
+) -> (HashMap<String, Vec<VolumeContainer>>, HashMap<String, i64>) {
+  let mut mounts: HashMap<String, Vec<VolumeContainer>> = HashMap::new();
+  let mut helper_refs: HashMap<String, i64> = HashMap::new();
 
-    let mut logs_stream = docker.logs(&container_name, Some(log_options));
-    let mut output = String::new();
-    while let Some(chunk) = logs_stream.next().await {
-      if let Ok(log) = chunk {
-        output.push_str(&log.to_string());
+  for container in containers {
+    let is_helper = container
+      .labels
+      .as_ref()
+      .is_some_and(|labels| labels.contains_key(HELPER_LABEL));
+    let name = container
+      .names
+      .as_ref()
+      .and_then(|names| names.first())
+      .map(|n| n.trim_start_matches('/').to_string())
+      .unwrap_or_default();
+    let running = container
+      .state
+      .is_some_and(|s| s == bollard::models::ContainerSummaryStateEnum::RUNNING);
+
+    let volume_mounts = container
+      .mounts
+      .iter()
+      .flatten()
+      .filter(|m| m.typ == Some(bollard::models::MountPointTypeEnum::VOLUME));
+    for mount in volume_mounts {
+      let Some(volume) = mount.name.clone() else {
+        continue;
+      };
+      if is_helper {
+        *helper_refs.entry(volume).or_default() += 1;
+        continue;
       }
+      mounts.entry(volume).or_default().push(VolumeContainer {
+        container_id: container.id.clone().unwrap_or_default(),
+        container_name: name.clone(),
+        running,
+        destination: mount.destination.clone().unwrap_or_default(),
+        read_write: mount.rw.unwrap_or(true),
+        mode: mount.mode.clone().unwrap_or_default(),
+      });
     }
+  }
 
-    let _ = docker
-      .remove_container(
-        &container_name,
-        Some(RemoveContainerOptions {
-          force: true,
-          ..Default::default()
-        }),
-      )
-      .await;
-
-    // Convert /data path back to volume-relative path
-    let result = output.trim().replace("/data", "");
-    let result = if result.is_empty() { "/".to_string() } else { result };
+  for list in mounts.values_mut() {
+    list.sort_by(|a, b| {
+      b.running
+        .cmp(&a.running)
+        .then_with(|| a.container_name.cmp(&b.container_name))
+    });
+  }
+  (mounts, helper_refs)
+}
 
-    Ok(result)
+/// Map a volume-relative path to where helper containers mount the volume
+fn volume_path(path: &str) -> String {
+  let path = path.trim_matches('/');
+  if path.is_empty() {
+    "/data".to_string()
+  } else {
+    format!("/data/{path}")
   }
+}
 
-  /// Check if a path in a volume is a directory using a temporary container
-  pub async fn is_volume_directory(&self, volume_name: &str, path: &str) -> Result<bool> {
-    let docker = self.client()?;
+/// Split a volume-relative path into its parent directory and file name
+fn split_volume_path(path: &str) -> Option<(&str, &str)> {
+  let path = path.trim_end_matches('/');
+  let (dir, name) = path.rsplit_once('/').unwrap_or(("", path));
+  (!name.is_empty() && name != "." && name != "..").then_some((if dir.is_empty() { "/" } else { dir }, name))
+}
 
-    let normalized_path = format!("/data{}", path.trim_start_matches('/'));
+/// Numeric owner and permission bits of a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileOwner {
+  uid: u64,
+  gid: u64,
+  mode: u32,
+}
 
-    let timestamp = std::time::SystemTime::now()
-      .duration_since(std::time::UNIX_EPOCH)
-      .unwrap_or_default()
-      .as_nanos();
-    let container_name = format!("docker-ui-vol-dir-{timestamp}");
+impl FileOwner {
+  /// Parse `stat -c '%u %g %a'` output (mode in octal)
+  fn parse(output: &str) -> Option<Self> {
+    let mut parts = output.lines().next()?.split_whitespace();
+    Some(Self {
+      uid: parts.next()?.parse().ok()?,
+      gid: parts.next()?.parse().ok()?,
+      mode: u32::from_str_radix(parts.next()?, 8).ok()?,
+    })
+  }
+}
 
-    let host_config = bollard::models::HostConfig {
-      binds: Some(vec![format!("{}:/data:ro", volume_name)]),
-      ..Default::default()
-    };
+/// Tar a host file or directory, giving every entry the given owner
+fn build_upload_archive(source: &Path, uid: u64, gid: u64) -> io::Result<Vec<u8>> {
+  let name = source
+    .file_name()
+    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Cannot upload a path without a name"))?;
+  let mut builder = tar::Builder::new(Vec::new());
+  append_with_owner(&mut builder, source, Path::new(name), uid, gid)?;
+  builder.into_inner()
+}
 
-    let config = ContainerCreateBody {
-      image: Some("alpine:latest".to_string()),
-      cmd: Some(vec![
-        "sh".to_string(),
-        "-c".to_string(),
-        format!("test -d '{}' && echo dir", normalized_path),
-      ]),
-      host_config: Some(host_config),
-      tty: Some(false),
-      attach_stdout: Some(true),
-      attach_stderr: Some(true),
-      ..Default::default()
-    };
+fn append_with_owner(
+  builder: &mut tar::Builder<Vec<u8>>,
+  source: &Path,
+  name: &Path,
+  uid: u64,
+  gid: u64,
+) -> io::Result<()> {
+  let meta = fs::symlink_metadata(source)?;
+  let mut header = tar::Header::new_gnu();
+  header.set_metadata_in_mode(&meta, tar::HeaderMode::Complete);
+  header.set_uid(uid);
+  header.set_gid(gid);
+
+  if meta.file_type().is_symlink() {
+    builder.append_link(&mut header, name, fs::read_link(source)?)?;
+  } else if meta.is_dir() {
+    builder.append_data(&mut header, name, io::empty())?;
+    let mut children = fs::read_dir(source)?.collect::<io::Result<Vec<_>>>()?;
+    children.sort_by_key(fs::DirEntry::file_name);
+    for child in children {
+      append_with_owner(builder, &child.path(), &name.join(child.file_name()), uid, gid)?;
+    }
+  } else if meta.is_file() {
+    builder.append_data(&mut header, name, File::open(source)?)?;
+  }
+  // Sockets, fifos and devices are skipped
+  Ok(())
+}
 
-    let options = CreateContainerOptions {
-      name: Some(container_name.clone()),
-      ..Default::default()
+/// Extract a downloaded archive into `dest_dir`, renaming its top-level entry if that name is taken
+fn unpack_download(archive: &[u8], dest_dir: &Path) -> Result<PathBuf> {
+  fs::create_dir_all(dest_dir)?;
+  let mut archive = tar::Archive::new(archive);
+  archive.set_preserve_permissions(true);
+  archive.set_preserve_mtime(true);
+
+  // Top-level name in the archive and where it lands on the host
+  let mut root: Option<(PathBuf, PathBuf)> = None;
+  for entry in archive.entries()? {
+    let mut entry = entry?;
+    let path = entry.path()?.into_owned();
+    if !path.components().all(|c| matches!(c, Component::Normal(_))) {
+      bail!("Unsafe path in archive: {}", path.display());
+    }
+    let Some(Component::Normal(top)) = path.components().next() else {
+      continue;
     };
 
-    docker.create_container(Some(options), config).await?;
-    docker
-      .start_container(
-        &container_name,
-        None::<bollard::query_parameters::StartContainerOptions>,
-      )
-      .await?;
-
-    let mut wait_stream =
-      docker.wait_container(&container_name, None::<bollard::query_parameters::WaitContainerOptions>);
-    while (wait_stream.next().await).is_some() {}
-
-    let log_options = LogsOptions {
-      stdout: true,
-      stderr: true,
-      ..Default::default()
+    let (archive_root, host_root) = root.get_or_insert_with(|| (PathBuf::from(top), unique_path(dest_dir, top)));
+    let relative = path.strip_prefix(&*archive_root)?;
+    let target = if relative.as_os_str().is_empty() {
+      host_root.clone()
+    } else {
+      host_root.join(relative)
     };
-
-    let mut logs_stream = docker.logs(&container_name, Some(log_options));
-    let mut output = String::new();
-    while let Some(chunk) = logs_stream.next().await {
-      if let Ok(log) = chunk {
-        output.push_str(&log.to_string());
-      }
+    if let Some(parent) = target.parent() {
+      fs::create_dir_all(parent)?;
     }
+    entry.unpack(&target)?;
+  }
 
-    let _ = docker
-      .remove_container(
-        &container_name,
-        Some(RemoveContainerOptions {
-          force: true,
-          ..Default::default()
-        }),
-      )
-      .await;
+  root
+    .map(|(_, host_root)| host_root)
+    .ok_or_else(|| anyhow!("Nothing to download"))
+}
 
-    Ok(output.contains("dir"))
+/// `dir/name`, or `dir/name (n).ext` for the first free `n` when that exists
+fn unique_path(dir: &Path, name: &std::ffi::OsStr) -> PathBuf {
+  let candidate = dir.join(name);
+  if !candidate.exists() {
+    return candidate;
   }
+
+  let name = Path::new(name);
+  let stem = name.file_stem().unwrap_or_default().to_string_lossy();
+  let extension = name
+    .extension()
+    .map(|e| format!(".{}", e.to_string_lossy()))
+    .unwrap_or_default();
+  (1..=u32::MAX)
+    .map(|n| dir.join(format!("{stem} ({n}){extension}")))
+    .find(|p| !p.exists())
+    .unwrap_or(candidate)
 }
 
 #[cfg(test)]
@@ -526,6 +668,7 @@ mod tests {
         size: 1024 * 1024 * 100, // 100 MiB
         ref_count: 1,
       }),
+      containers: Vec::new(),
     };
     assert_eq!(volume.display_size(), "100.0 MiB");
 
@@ -552,6 +695,7 @@ mod tests {
         size: 1000,
         ref_count: 2,
       }),
+      containers: Vec::new(),
     };
     assert!(in_use.is_in_use());
 
@@ -573,6 +717,82 @@ mod tests {
     assert!(!no_usage.is_in_use());
   }
 
+  #[test]
+  fn test_volume_size_unreported() {
+    let volume = VolumeInfo {
+      name: "nfs".to_string(),
+      driver: "local".to_string(),
+      mountpoint: String::new(),
+      created: None,
+      labels: HashMap::new(),
+      scope: "local".to_string(),
+      status: None,
+      usage_data: Some(VolumeUsage { size: -1, ref_count: 1 }),
+      containers: Vec::new(),
+    };
+    assert_eq!(volume.size(), None);
+    assert_eq!(volume.display_size(), "Unknown");
+    assert!(volume.is_in_use());
+  }
+
+  #[test]
+  fn test_volume_mounts() {
+    use bollard::models::{ContainerSummaryStateEnum, MountPoint, MountPointTypeEnum};
+
+    let mount = |name: &str, destination: &str, rw: bool| MountPoint {
+      typ: Some(MountPointTypeEnum::VOLUME),
+      name: Some(name.to_string()),
+      destination: Some(destination.to_string()),
+      rw: Some(rw),
+      mode: Some("z".to_string()),
+      ..Default::default()
+    };
+    let containers = vec![
+      ContainerSummary {
+        id: Some("b1".to_string()),
+        names: Some(vec!["/web".to_string()]),
+        state: Some(ContainerSummaryStateEnum::EXITED),
+        mounts: Some(vec![
+          mount("data", "/srv", false),
+          MountPoint {
+            typ: Some(MountPointTypeEnum::BIND),
+            source: Some("/host".to_string()),
+            destination: Some("/host".to_string()),
+            ..Default::default()
+          },
+        ]),
+        ..Default::default()
+      },
+      ContainerSummary {
+        id: Some("a1".to_string()),
+        names: Some(vec!["/db".to_string()]),
+        state: Some(ContainerSummaryStateEnum::RUNNING),
+        mounts: Some(vec![mount("data", "/var/lib/data", true)]),
+        ..Default::default()
+      },
+      ContainerSummary {
+        id: Some("h1".to_string()),
+        labels: Some(HashMap::from([(HELPER_LABEL.to_string(), "data".to_string())])),
+        mounts: Some(vec![mount("data", "/data", true)]),
+        ..Default::default()
+      },
+    ];
+
+    let (mounts, helper_refs) = volume_mounts(&containers);
+    assert_eq!(mounts.len(), 1);
+    assert_eq!(helper_refs.get("data"), Some(&1));
+
+    let data = &mounts["data"];
+    assert_eq!(data.len(), 2);
+    // Running containers come first
+    assert_eq!(data[0].container_name, "db");
+    assert!(data[0].running);
+    assert_eq!(data[0].display_mode(), "rw,z");
+    assert_eq!(data[1].container_name, "web");
+    assert_eq!(data[1].destination, "/srv");
+    assert_eq!(data[1].display_mode(), "ro,z");
+  }
+
   #[test]
   fn test_volume_usage() {
     let usage = VolumeUsage {
@@ -623,4 +843,90 @@ mod tests {
     };
     assert_eq!(symlink.display_size(), "20 B");
   }
+
+  #[test]
+  fn test_volume_path() {
+    assert_eq!(volume_path("/"), "/data");
+    assert_eq!(volume_path(""), "/data");
+    assert_eq!(volume_path("/etc/app.conf"), "/data/etc/app.conf");
+    assert_eq!(volume_path("logs/"), "/data/logs");
+  }
+
+  #[test]
+  fn test_split_volume_path() {
+    assert_eq!(split_volume_path("/app.conf"), Some(("/", "app.conf")));
+    assert_eq!(split_volume_path("/etc/app/"), Some(("/etc", "app")));
+    assert_eq!(split_volume_path("/"), None);
+    assert_eq!(split_volume_path("/etc/.."), None);
+  }
+
+  #[test]
+  fn test_file_owner_parse() {
+    assert_eq!(
+      FileOwner::parse("1000 100 644\n"),
+      Some(FileOwner {
+        uid: 1000,
+        gid: 100,
+        mode: 0o644
+      })
+    );
+    assert_eq!(FileOwner::parse("0 0 2775\nnew\n").map(|o| o.mode), Some(0o2775));
+    assert_eq!(FileOwner::parse("stat: can't stat"), None);
+    assert_eq!(FileOwner::parse(""), None);
+  }
+
+  #[test]
+  fn test_upload_archive_roundtrip() {
+    let root = std::env::temp_dir().join(format!("dockside-volume-upload-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    let source = root.join("fixtures");
+    fs::create_dir_all(source.join("nested")).unwrap();
+    fs::write(source.join("seed.sql"), "select 1;").unwrap();
+    fs::write(source.join("nested/data.json"), "{}").unwrap();
+
+    let archive = build_upload_archive(&source, 999, 998).unwrap();
+    let mut entries = tar::Archive::new(archive.as_slice())
+      .entries()
+      .unwrap()
+      .map(|e| {
+        let e = e.unwrap();
+        let header = e.header();
+        assert_eq!(header.uid().unwrap(), 999);
+        assert_eq!(header.gid().unwrap(), 998);
+        e.path().unwrap().display().to_string()
+      })
+      .collect::<Vec<_>>();
+    entries.sort();
+    assert_eq!(
+      entries,
+      [
+        "fixtures",
+        "fixtures/nested",
+        "fixtures/nested/data.json",
+        "fixtures/seed.sql"
+      ]
+    );
+
+    // Unpacking next to an existing copy picks a free name
+    let dest = root.join("downloads");
+    let first = unpack_download(&archive, &dest).unwrap();
+    let second = unpack_download(&archive, &dest).unwrap();
+    assert_eq!(first, dest.join("fixtures"));
+    assert_eq!(second, dest.join("fixtures (1)"));
+    assert_eq!(fs::read_to_string(second.join("nested/data.json")).unwrap(), "{}");
+
+    fs::remove_dir_all(&root).unwrap();
+  }
+
+  #[test]
+  fn test_unique_path_keeps_extension() {
+    let dir = std::env::temp_dir().join(format!("dockside-volume-unique-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    assert_eq!(unique_path(&dir, "app.conf".as_ref()), dir.join("app.conf"));
+    fs::write(dir.join("app.conf"), "").unwrap();
+    fs::write(dir.join("app (1).conf"), "").unwrap();
+    assert_eq!(unique_path(&dir, "app.conf".as_ref()), dir.join("app (2).conf"));
+    fs::remove_dir_all(&dir).unwrap();
+  }
 }
@@ -23,6 +23,7 @@ actions!(
     GoToServices,
     GoToMachines,
     GoToActivityMonitor,
+    GoToEvents,
     GoToSettings,
     // Common actions
     Refresh,
@@ -59,6 +60,7 @@ pub fn register_keybindings(cx: &mut App) {
     // Other views (Cmd+9, Cmd+0)
     KeyBinding::new("cmd-9", GoToMachines, None),
     KeyBinding::new("cmd-0", GoToActivityMonitor, None),
+    KeyBinding::new("cmd-shift-e", GoToEvents, None),
     // Settings (Cmd+,)
     KeyBinding::new("cmd-,", GoToSettings, None),
     // Common actions
@@ -141,6 +143,11 @@ pub fn get_all_shortcuts() -> Vec<KeyboardShortcut> {
       description: "Go to Activity Monitor",
       category: "Navigation",
     },
+    KeyboardShortcut {
+      keys: "Cmd+Shift+E",
+      description: "Go to Events",
+      category: "Navigation",
+    },
     KeyboardShortcut {
       keys: "Cmd+,",
       description: "Go to Settings",
@@ -1,18 +1,30 @@
 use anyhow::{Context, Result};
 use chrono::Utc;
-use k8s_openapi::api::apps::v1::{Deployment, ReplicaSet};
-use k8s_openapi::api::core::v1::{Namespace, Pod};
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{ConfigMap, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod, Secret};
+use k8s_openapi::api::networking::v1::Ingress;
+use k8s_openapi::api::storage::v1::StorageClass;
+use k8s_openapi::{ByteString, NamespaceResourceScope};
 use kube::{
-  Api, Client, Config,
-  api::{DeleteParams, ListParams, LogParams, Patch, PatchParams, PostParams},
+  Api, Client, Config, Resource,
+  api::{DeleteParams, EvictParams, ListParams, LogParams, Patch, PatchParams, PostParams},
+  core::ErrorResponse,
 };
+use serde::de::DeserializeOwned;
 use serde_json::json;
+use std::collections::HashSet;
 use std::fmt::Write as _;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
 use k8s_openapi::api::core::v1::Service;
 
-use super::types::{DeploymentInfo, NamespaceInfo, PodInfo, ServiceInfo};
+use super::kubeconfig::{client_config, kubeconfig_selection};
+use super::types::{
+  ConfigInfo, ConfigKind, DeploymentInfo, DrainAction, IngressInfo, NamespaceInfo, NodeInfo, PersistentVolumeInfo,
+  PodInfo, ServiceInfo, StorageClassInfo, VolumeClaimInfo, WorkloadInfo, WorkloadKind,
+};
 
 /// Kubernetes client wrapper
 pub struct KubeClient {
@@ -20,12 +32,12 @@ pub struct KubeClient {
 }
 
 impl KubeClient {
-  /// Create a new `KubeClient` from default kubeconfig
+  /// Create a new `KubeClient` for the selected kubeconfig files and context
   /// Includes VPN-aware fallback: if the server URL uses a VM IP that's unreachable,
   /// automatically tries localhost with the same port
   pub async fn new() -> Result<Self> {
     // First, try to load the config to inspect the server URL
-    let config = Config::infer().await.context("Failed to load kubeconfig")?;
+    let config = client_config(&kubeconfig_selection()).await?;
 
     // Check if the server URL uses a non-localhost IP (likely VM IP)
     let server_url = config.cluster_url.to_string();
@@ -61,6 +73,11 @@ impl KubeClient {
     }
   }
 
+  /// Underlying client, e.g. for the resource watchers
+  pub fn into_client(self) -> Client {
+    self.client
+  }
+
   /// Try to connect with a timeout
   async fn try_connect_with_timeout(config: Config, timeout: Duration) -> Result<Client> {
     let client = Client::try_from(config)?;
@@ -218,9 +235,17 @@ impl KubeClient {
 
   /// Trigger a rollout restart on a deployment
   async fn rollout_restart_deployment(&self, name: &str, namespace: &str) -> Result<String> {
-    let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+    self.rollout_restart::<Deployment>(name, namespace, "Deployment").await
+  }
+
+  /// Trigger a rollout restart on any controller with a pod template
+  async fn rollout_restart<K>(&self, name: &str, namespace: &str, kind: &str) -> Result<String>
+  where
+    K: Resource<Scope = NamespaceResourceScope, DynamicType = ()> + Clone + DeserializeOwned + std::fmt::Debug,
+  {
+    let api: Api<K> = Api::namespaced(self.client.clone(), namespace);
 
-    // Patch the deployment with a restart annotation (same as kubectl rollout restart)
+    // Patch the controller with a restart annotation (same as kubectl rollout restart)
     let patch = json!({
         "spec": {
             "template": {
@@ -236,9 +261,9 @@ impl KubeClient {
     api
       .patch(name, &PatchParams::default(), &Patch::Merge(&patch))
       .await
-      .context(format!("Failed to restart deployment {name}"))?;
+      .context(format!("Failed to restart {} {name}", kind.to_lowercase()))?;
 
-    Ok(format!("Deployment {name} rollout restart triggered"))
+    Ok(format!("{kind} {name} rollout restart triggered"))
   }
 
   /// Get pod describe output (formatted pod details)
@@ -686,6 +711,654 @@ impl KubeClient {
 
     Ok(format!("Service {} created in namespace {}", options.name, namespace))
   }
+
+  // ========================================================================
+  // Workload Methods (StatefulSets, DaemonSets, Jobs, CronJobs)
+  // ========================================================================
+
+  /// List objects of one kind in a namespace (or all namespaces if None)
+  async fn list_namespaced<K>(&self, namespace: Option<&str>, plural: &str) -> Result<Vec<K>>
+  where
+    K: Resource<Scope = NamespaceResourceScope, DynamicType = ()> + Clone + DeserializeOwned + std::fmt::Debug,
+  {
+    let api: Api<K> = match namespace {
+      Some(ns) => Api::namespaced(self.client.clone(), ns),
+      None => Api::all(self.client.clone()),
+    };
+    let list = api.list(&ListParams::default()).await.context(format!(
+      "Failed to list {} in {}",
+      plural.to_lowercase(),
+      namespace.map_or_else(|| "all namespaces".to_string(), |ns| format!("namespace {ns}"))
+    ))?;
+    Ok(list.items)
+  }
+
+  /// List workloads of a kind in a namespace (or all namespaces if None)
+  pub async fn list_workloads(&self, kind: WorkloadKind, namespace: Option<&str>) -> Result<Vec<WorkloadInfo>> {
+    Ok(match kind {
+      WorkloadKind::StatefulSet => self
+        .list_namespaced::<StatefulSet>(namespace, kind.plural())
+        .await?
+        .iter()
+        .map(WorkloadInfo::from_stateful_set)
+        .collect(),
+      WorkloadKind::DaemonSet => self
+        .list_namespaced::<DaemonSet>(namespace, kind.plural())
+        .await?
+        .iter()
+        .map(WorkloadInfo::from_daemon_set)
+        .collect(),
+      WorkloadKind::Job => self
+        .list_namespaced::<Job>(namespace, kind.plural())
+        .await?
+        .iter()
+        .map(WorkloadInfo::from_job)
+        .collect(),
+      WorkloadKind::CronJob => self
+        .list_namespaced::<CronJob>(namespace, kind.plural())
+        .await?
+        .iter()
+        .map(WorkloadInfo::from_cron_job)
+        .collect(),
+    })
+  }
+
+  /// Delete a workload. Pods and jobs it owns are removed in the background, like kubectl does.
+  pub async fn delete_workload(&self, kind: WorkloadKind, name: &str, namespace: &str) -> Result<()> {
+    let dp = DeleteParams::background();
+    let result = match kind {
+      WorkloadKind::StatefulSet => Api::<StatefulSet>::namespaced(self.client.clone(), namespace)
+        .delete(name, &dp)
+        .await
+        .map(|_| ()),
+      WorkloadKind::DaemonSet => Api::<DaemonSet>::namespaced(self.client.clone(), namespace)
+        .delete(name, &dp)
+        .await
+        .map(|_| ()),
+      WorkloadKind::Job => Api::<Job>::namespaced(self.client.clone(), namespace)
+        .delete(name, &dp)
+        .await
+        .map(|_| ()),
+      WorkloadKind::CronJob => Api::<CronJob>::namespaced(self.client.clone(), namespace)
+        .delete(name, &dp)
+        .await
+        .map(|_| ()),
+    };
+    result.context(format!(
+      "Failed to delete {} {name} in namespace {namespace}",
+      kind.label().to_lowercase()
+    ))
+  }
+
+  /// Get workload YAML
+  pub async fn get_workload_yaml(&self, kind: WorkloadKind, name: &str, namespace: &str) -> Result<String> {
+    let context = format!("Failed to get {} {name}", kind.label().to_lowercase());
+    let yaml = match kind {
+      WorkloadKind::StatefulSet => {
+        let obj = Api::<StatefulSet>::namespaced(self.client.clone(), namespace)
+          .get(name)
+          .await
+          .context(context)?;
+        serde_yaml::to_string(&obj)
+      }
+      WorkloadKind::DaemonSet => {
+        let obj = Api::<DaemonSet>::namespaced(self.client.clone(), namespace)
+          .get(name)
+          .await
+          .context(context)?;
+        serde_yaml::to_string(&obj)
+      }
+      WorkloadKind::Job => {
+        let obj = Api::<Job>::namespaced(self.client.clone(), namespace)
+          .get(name)
+          .await
+          .context(context)?;
+        serde_yaml::to_string(&obj)
+      }
+      WorkloadKind::CronJob => {
+        let obj = Api::<CronJob>::namespaced(self.client.clone(), namespace)
+          .get(name)
+          .await
+          .context(context)?;
+        serde_yaml::to_string(&obj)
+      }
+    };
+    yaml.context("Failed to serialize workload to YAML")
+  }
+
+  /// Scale a StatefulSet
+  pub async fn scale_stateful_set(&self, name: &str, namespace: &str, replicas: i32) -> Result<String> {
+    let api: Api<StatefulSet> = Api::namespaced(self.client.clone(), namespace);
+    let patch = json!({ "spec": { "replicas": replicas } });
+
+    api
+      .patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+      .await
+      .context(format!("Failed to scale statefulset {name}"))?;
+
+    Ok(format!("StatefulSet {name} scaled to {replicas} replicas"))
+  }
+
+  /// Rollout restart a StatefulSet or DaemonSet
+  pub async fn restart_workload(&self, kind: WorkloadKind, name: &str, namespace: &str) -> Result<String> {
+    match kind {
+      WorkloadKind::StatefulSet => self.rollout_restart::<StatefulSet>(name, namespace, kind.label()).await,
+      WorkloadKind::DaemonSet => self.rollout_restart::<DaemonSet>(name, namespace, kind.label()).await,
+      WorkloadKind::Job | WorkloadKind::CronJob => Err(anyhow::anyhow!("{} cannot be restarted", kind.plural())),
+    }
+  }
+
+  /// Run a CronJob now by creating a Job from its template (same as `kubectl create job --from=cronjob/...`)
+  pub async fn trigger_cron_job(&self, name: &str, namespace: &str) -> Result<String> {
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
+
+    let cron_api: Api<CronJob> = Api::namespaced(self.client.clone(), namespace);
+    let cron_job = cron_api
+      .get(name)
+      .await
+      .context(format!("Failed to get cronjob {name}"))?;
+    let template = cron_job
+      .spec
+      .as_ref()
+      .map(|s| s.job_template.clone())
+      .ok_or_else(|| anyhow::anyhow!("CronJob {name} has no job template"))?;
+
+    // Object names are capped at 63 characters for the generated pod labels
+    let suffix = format!("-manual-{}", Utc::now().timestamp());
+    let base: String = name.chars().take(63 - suffix.len()).collect();
+    let job_name = format!("{}{suffix}", base.trim_end_matches('-'));
+
+    let template_meta = template.metadata.unwrap_or_default();
+    let mut annotations = template_meta.annotations.unwrap_or_default();
+    annotations.insert("cronjob.kubernetes.io/instantiate".to_string(), "manual".to_string());
+
+    let job = Job {
+      metadata: ObjectMeta {
+        name: Some(job_name.clone()),
+        namespace: Some(namespace.to_string()),
+        labels: template_meta.labels,
+        annotations: Some(annotations),
+        owner_references: cron_job.metadata.uid.clone().map(|uid| {
+          vec![OwnerReference {
+            api_version: "batch/v1".to_string(),
+            kind: "CronJob".to_string(),
+            name: name.to_string(),
+            uid,
+            controller: Some(true),
+            block_owner_deletion: Some(true),
+          }]
+        }),
+        ..Default::default()
+      },
+      spec: template.spec,
+      ..Default::default()
+    };
+
+    let job_api: Api<Job> = Api::namespaced(self.client.clone(), namespace);
+    job_api
+      .create(&PostParams::default(), &job)
+      .await
+      .context(format!("Failed to create job from cronjob {name}"))?;
+
+    Ok(format!("Job {job_name} created from CronJob {name}"))
+  }
+
+  /// Suspend or resume a CronJob
+  pub async fn set_cron_job_suspended(&self, name: &str, namespace: &str, suspend: bool) -> Result<String> {
+    let api: Api<CronJob> = Api::namespaced(self.client.clone(), namespace);
+    let patch = json!({ "spec": { "suspend": suspend } });
+
+    api
+      .patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+      .await
+      .context(format!("Failed to update cronjob {name}"))?;
+
+    let action = if suspend { "suspended" } else { "resumed" };
+    Ok(format!("CronJob {name} {action}"))
+  }
+
+  // ========================================================================
+  // Config Methods (ConfigMaps, Secrets)
+  // ========================================================================
+
+  /// List ConfigMaps or Secrets in a namespace (or all namespaces if None)
+  pub async fn list_configs(&self, kind: ConfigKind, namespace: Option<&str>) -> Result<Vec<ConfigInfo>> {
+    Ok(match kind {
+      ConfigKind::ConfigMap => self
+        .list_namespaced::<ConfigMap>(namespace, kind.plural())
+        .await?
+        .iter()
+        .map(ConfigInfo::from_config_map)
+        .collect(),
+      ConfigKind::Secret => self
+        .list_namespaced::<Secret>(namespace, kind.plural())
+        .await?
+        .iter()
+        .map(ConfigInfo::from_secret)
+        .collect(),
+    })
+  }
+
+  /// Delete a ConfigMap or Secret
+  pub async fn delete_config(&self, kind: ConfigKind, name: &str, namespace: &str) -> Result<()> {
+    let dp = DeleteParams::default();
+    let result = match kind {
+      ConfigKind::ConfigMap => Api::<ConfigMap>::namespaced(self.client.clone(), namespace)
+        .delete(name, &dp)
+        .await
+        .map(|_| ()),
+      ConfigKind::Secret => Api::<Secret>::namespaced(self.client.clone(), namespace)
+        .delete(name, &dp)
+        .await
+        .map(|_| ()),
+    };
+    result.context(format!(
+      "Failed to delete {} {name} in namespace {namespace}",
+      kind.label().to_lowercase()
+    ))
+  }
+
+  /// Get ConfigMap or Secret YAML
+  pub async fn get_config_yaml(&self, kind: ConfigKind, name: &str, namespace: &str) -> Result<String> {
+    let context = format!("Failed to get {} {name}", kind.label().to_lowercase());
+    let yaml = match kind {
+      ConfigKind::ConfigMap => {
+        let obj = Api::<ConfigMap>::namespaced(self.client.clone(), namespace)
+          .get(name)
+          .await
+          .context(context)?;
+        serde_yaml::to_string(&obj)
+      }
+      ConfigKind::Secret => {
+        let obj = Api::<Secret>::namespaced(self.client.clone(), namespace)
+          .get(name)
+          .await
+          .context(context)?;
+        serde_yaml::to_string(&obj)
+      }
+    };
+    yaml.context("Failed to serialize object to YAML")
+  }
+
+  /// Merge patch `data` (and `binaryData` for ConfigMaps) of a ConfigMap or Secret
+  async fn patch_config_data(
+    &self,
+    kind: ConfigKind,
+    name: &str,
+    namespace: &str,
+    patch: &serde_json::Value,
+  ) -> Result<()> {
+    let pp = PatchParams::default();
+    let result = match kind {
+      ConfigKind::ConfigMap => Api::<ConfigMap>::namespaced(self.client.clone(), namespace)
+        .patch(name, &pp, &Patch::Merge(patch))
+        .await
+        .map(|_| ()),
+      ConfigKind::Secret => Api::<Secret>::namespaced(self.client.clone(), namespace)
+        .patch(name, &pp, &Patch::Merge(patch))
+        .await
+        .map(|_| ()),
+    };
+    result.context(format!("Failed to update {} {name}", kind.label().to_lowercase()))
+  }
+
+  /// Add or replace one key. Secret values and non-UTF-8 ConfigMap values are base64-encoded.
+  pub async fn set_config_entry(
+    &self,
+    kind: ConfigKind,
+    name: &str,
+    namespace: &str,
+    key: &str,
+    value: Vec<u8>,
+  ) -> Result<String> {
+    let patch = match (kind, String::from_utf8(value)) {
+      (ConfigKind::ConfigMap, Ok(text)) => json!({ "data": { key: text }, "binaryData": { key: null } }),
+      (ConfigKind::ConfigMap, Err(e)) => {
+        json!({ "data": { key: null }, "binaryData": { key: ByteString(e.into_bytes()) } })
+      }
+      (ConfigKind::Secret, value) => {
+        let bytes = value.map_or_else(|e| e.into_bytes(), String::into_bytes);
+        json!({ "data": { key: ByteString(bytes) } })
+      }
+    };
+    self.patch_config_data(kind, name, namespace, &patch).await?;
+
+    Ok(format!("Key {key} of {} {name} saved", kind.label()))
+  }
+
+  /// Remove one key
+  pub async fn remove_config_entry(&self, kind: ConfigKind, name: &str, namespace: &str, key: &str) -> Result<String> {
+    let patch = match kind {
+      ConfigKind::ConfigMap => json!({ "data": { key: null }, "binaryData": { key: null } }),
+      ConfigKind::Secret => json!({ "data": { key: null } }),
+    };
+    self.patch_config_data(kind, name, namespace, &patch).await?;
+
+    Ok(format!("Key {key} removed from {} {name}", kind.label()))
+  }
+
+  /// Create a ConfigMap or an Opaque Secret from key/value pairs
+  pub async fn create_config(
+    &self,
+    kind: ConfigKind,
+    name: &str,
+    namespace: &str,
+    entries: Vec<(String, Vec<u8>)>,
+  ) -> Result<String> {
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+    use std::collections::BTreeMap;
+
+    let metadata = ObjectMeta {
+      name: Some(name.to_string()),
+      namespace: Some(namespace.to_string()),
+      ..Default::default()
+    };
+    let context = format!("Failed to create {} {name}", kind.label().to_lowercase());
+
+    match kind {
+      ConfigKind::ConfigMap => {
+        let mut data = BTreeMap::new();
+        let mut binary_data = BTreeMap::new();
+        for (key, value) in entries {
+          match String::from_utf8(value) {
+            Ok(text) => {
+              data.insert(key, text);
+            }
+            Err(e) => {
+              binary_data.insert(key, ByteString(e.into_bytes()));
+            }
+          }
+        }
+        let cm = ConfigMap {
+          metadata,
+          data: (!data.is_empty()).then_some(data),
+          binary_data: (!binary_data.is_empty()).then_some(binary_data),
+          ..Default::default()
+        };
+        Api::<ConfigMap>::namespaced(self.client.clone(), namespace)
+          .create(&PostParams::default(), &cm)
+          .await
+          .context(context)?;
+      }
+      ConfigKind::Secret => {
+        let secret = Secret {
+          metadata,
+          type_: Some("Opaque".to_string()),
+          data: Some(entries.into_iter().map(|(k, v)| (k, ByteString(v))).collect()),
+          ..Default::default()
+        };
+        Api::<Secret>::namespaced(self.client.clone(), namespace)
+          .create(&PostParams::default(), &secret)
+          .await
+          .context(context)?;
+      }
+    }
+
+    Ok(format!("{} {name} created in namespace {namespace}", kind.label()))
+  }
+
+  /// Rollout restart the given deployments, returning how many were restarted
+  pub async fn restart_deployments(&self, deployments: &[(String, String)]) -> Result<usize> {
+    for (name, namespace) in deployments {
+      self.rollout_restart_deployment(name, namespace).await?;
+    }
+    Ok(deployments.len())
+  }
+
+  // ========================================================================
+  // Ingress Methods
+  // ========================================================================
+
+  /// List ingresses in a namespace (or all namespaces if None)
+  pub async fn list_ingresses(&self, namespace: Option<&str>) -> Result<Vec<IngressInfo>> {
+    let ingresses = self.list_namespaced::<Ingress>(namespace, "Ingresses").await?;
+    Ok(ingresses.iter().map(IngressInfo::from_ingress).collect())
+  }
+
+  /// Delete an ingress
+  pub async fn delete_ingress(&self, name: &str, namespace: &str) -> Result<()> {
+    let api: Api<Ingress> = Api::namespaced(self.client.clone(), namespace);
+    api
+      .delete(name, &DeleteParams::default())
+      .await
+      .context(format!("Failed to delete ingress {name} in namespace {namespace}"))?;
+    Ok(())
+  }
+
+  /// Get ingress YAML
+  pub async fn get_ingress_yaml(&self, name: &str, namespace: &str) -> Result<String> {
+    let api: Api<Ingress> = Api::namespaced(self.client.clone(), namespace);
+    let ingress = api.get(name).await.context(format!("Failed to get ingress {name}"))?;
+
+    serde_yaml::to_string(&ingress).context("Failed to serialize ingress to YAML")
+  }
+
+  // ========================================================================
+  // Storage Methods (PersistentVolumeClaims, PersistentVolumes, StorageClasses)
+  // ========================================================================
+
+  /// List PersistentVolumeClaims in a namespace (or all namespaces if None)
+  pub async fn list_volume_claims(&self, namespace: Option<&str>) -> Result<Vec<VolumeClaimInfo>> {
+    let claims = self
+      .list_namespaced::<PersistentVolumeClaim>(namespace, "PersistentVolumeClaims")
+      .await?;
+    Ok(claims.iter().map(VolumeClaimInfo::from_pvc).collect())
+  }
+
+  /// List PersistentVolumes (cluster-scoped)
+  pub async fn list_persistent_volumes(&self) -> Result<Vec<PersistentVolumeInfo>> {
+    let api: Api<PersistentVolume> = Api::all(self.client.clone());
+    let volumes = api
+      .list(&ListParams::default())
+      .await
+      .context("Failed to list persistent volumes")?;
+    Ok(volumes.items.iter().map(PersistentVolumeInfo::from_pv).collect())
+  }
+
+  /// List StorageClasses (cluster-scoped)
+  pub async fn list_storage_classes(&self) -> Result<Vec<StorageClassInfo>> {
+    let api: Api<StorageClass> = Api::all(self.client.clone());
+    let classes = api
+      .list(&ListParams::default())
+      .await
+      .context("Failed to list storage classes")?;
+    Ok(classes.items.iter().map(StorageClassInfo::from_storage_class).collect())
+  }
+
+  /// Delete a PersistentVolumeClaim
+  pub async fn delete_volume_claim(&self, name: &str, namespace: &str) -> Result<()> {
+    let api: Api<PersistentVolumeClaim> = Api::namespaced(self.client.clone(), namespace);
+    api.delete(name, &DeleteParams::default()).await.context(format!(
+      "Failed to delete persistent volume claim {name} in namespace {namespace}"
+    ))?;
+    Ok(())
+  }
+
+  /// Get PersistentVolumeClaim YAML
+  pub async fn get_volume_claim_yaml(&self, name: &str, namespace: &str) -> Result<String> {
+    let api: Api<PersistentVolumeClaim> = Api::namespaced(self.client.clone(), namespace);
+    let pvc = api
+      .get(name)
+      .await
+      .context(format!("Failed to get persistent volume claim {name}"))?;
+
+    serde_yaml::to_string(&pvc).context("Failed to serialize persistent volume claim to YAML")
+  }
+
+  // ========================================================================
+  // Node Methods
+  // ========================================================================
+
+  /// List nodes with the pods scheduled on each
+  pub async fn list_nodes(&self) -> Result<Vec<NodeInfo>> {
+    let api: Api<Node> = Api::all(self.client.clone());
+    let nodes = api.list(&ListParams::default()).await.context("Failed to list nodes")?;
+
+    let pods_api: Api<Pod> = Api::all(self.client.clone());
+    let pods = pods_api
+      .list(&ListParams::default())
+      .await
+      .context("Failed to list pods in all namespaces")?;
+
+    let mut infos: Vec<NodeInfo> = nodes.items.iter().map(NodeInfo::from_node).collect();
+    for pod in &pods.items {
+      let node_name = pod.spec.as_ref().and_then(|s| s.node_name.as_deref());
+      if let Some(info) = infos.iter_mut().find(|n| Some(n.name.as_str()) == node_name) {
+        info.add_pod(pod);
+      }
+    }
+    Ok(infos)
+  }
+
+  /// Cordon or uncordon a node
+  pub async fn set_node_unschedulable(&self, name: &str, unschedulable: bool) -> Result<String> {
+    let api: Api<Node> = Api::all(self.client.clone());
+
+    let patch = json!({
+        "spec": {
+            "unschedulable": unschedulable
+        }
+    });
+
+    api
+      .patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+      .await
+      .context(format!("Failed to update node {name}"))?;
+
+    if unschedulable {
+      Ok(format!("Node {name} cordoned"))
+    } else {
+      Ok(format!("Node {name} uncordoned"))
+    }
+  }
+
+  /// Drain a node like `kubectl drain --ignore-daemonsets`: cordon it, then evict its pods
+  /// through the eviction API so PodDisruptionBudgets are respected, and wait for them to go.
+  /// Pods no controller recreates, and pods with emptyDir data, make the drain fail unless
+  /// `force` is set (kubectl's `--force --delete-emptydir-data`). Pods are evicted together,
+  /// and evictions a budget blocks are retried until `DRAIN_TIMEOUT`.
+  pub async fn drain_node(
+    &self,
+    name: &str,
+    force: bool,
+    on_progress: impl Fn(String) + Send + Sync,
+  ) -> Result<String> {
+    const DRAIN_TIMEOUT: Duration = Duration::from_secs(300);
+    const RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+    let all: Api<Pod> = Api::all(self.client.clone());
+    let params = ListParams::default().fields(&format!("spec.nodeName={name}"));
+    let list_pods = async || {
+      all
+        .list(&params)
+        .await
+        .map(|pods| pods.items)
+        .context(format!("Failed to list pods on node {name}"))
+    };
+    // Pods to evict as (namespace, name, uid), or an error naming the ones that need force
+    let plan = |pods: &[Pod]| -> Result<Vec<(String, String, String)>> {
+      let mut evictable = Vec::new();
+      let mut blocked = Vec::new();
+      for pod in pods {
+        let action = DrainAction::for_pod(pod);
+        let (Some(namespace), Some(pod_name), Some(uid)) = (
+          pod.metadata.namespace.clone(),
+          pod.metadata.name.clone(),
+          pod.metadata.uid.clone(),
+        ) else {
+          continue;
+        };
+        match action.blocker() {
+          Some(reason) if !force => blocked.push(format!("{namespace}/{pod_name} ({reason})")),
+          _ if action != DrainAction::Skip => evictable.push((namespace, pod_name, uid)),
+          _ => {}
+        }
+      }
+      if blocked.is_empty() {
+        Ok(evictable)
+      } else {
+        Err(anyhow::anyhow!(
+          "Cannot drain {name} without force: {}",
+          blocked.join(", ")
+        ))
+      }
+    };
+
+    // Refuse before cordoning so a refused drain leaves the node as it was
+    plan(&list_pods().await?)?;
+    self.set_node_unschedulable(name, true).await?;
+    on_progress(format!("Cordoned {name}"));
+
+    let evictable = plan(&list_pods().await?)?;
+    let total = evictable.len();
+    let evicted = AtomicUsize::new(0);
+    let started = tokio::time::Instant::now();
+
+    let evictions = evictable.iter().map(|(namespace, pod, _)| {
+      let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+      let (evicted, on_progress) = (&evicted, &on_progress);
+      async move {
+        loop {
+          match api.evict(pod, &EvictParams::default()).await {
+            // Already gone
+            Ok(_) | Err(kube::Error::Api(ErrorResponse { code: 404, .. })) => {
+              let done = evicted.fetch_add(1, Ordering::Relaxed) + 1;
+              on_progress(format!("Evicted {namespace}/{pod} ({done}/{total})"));
+              return Ok(());
+            }
+            // Blocked by a PodDisruptionBudget
+            Err(kube::Error::Api(ErrorResponse { code: 429, .. })) => {
+              if started.elapsed() > DRAIN_TIMEOUT {
+                return Err(anyhow::anyhow!(
+                  "Timed out waiting for a disruption budget to allow evicting {namespace}/{pod}"
+                ));
+              }
+              on_progress(format!("Waiting for disruption budget on {namespace}/{pod}"));
+              tokio::time::sleep(RETRY_INTERVAL).await;
+            }
+            Err(e) => {
+              return Err(e).context(format!("Failed to evict pod {pod} in namespace {namespace}"));
+            }
+          }
+        }
+      }
+    });
+    // Every eviction runs to the end before the first failure is reported
+    futures::future::join_all(evictions)
+      .await
+      .into_iter()
+      .collect::<Result<Vec<()>>>()?;
+
+    // A pod recreated under the same name is a new pod, so match by uid
+    let uids: HashSet<&str> = evictable.iter().map(|(_, _, uid)| uid.as_str()).collect();
+    loop {
+      let remaining = list_pods()
+        .await?
+        .iter()
+        .filter(|p| p.metadata.uid.as_deref().is_some_and(|uid| uids.contains(uid)))
+        .count();
+      if remaining == 0 {
+        break;
+      }
+      if started.elapsed() > DRAIN_TIMEOUT {
+        return Err(anyhow::anyhow!(
+          "Timed out waiting for {remaining} pod(s) to leave node {name}"
+        ));
+      }
+      on_progress(format!("Waiting for {remaining} pod(s) to terminate"));
+      tokio::time::sleep(Duration::from_secs(2)).await;
+    }
+
+    Ok(format!("Node {name} drained, {total} pod(s) evicted"))
+  }
+
+  /// Get node YAML
+  pub async fn get_node_yaml(&self, name: &str) -> Result<String> {
+    let api: Api<Node> = Api::all(self.client.clone());
+    let node = api.get(name).await.context(format!("Failed to get node {name}"))?;
+
+    serde_yaml::to_string(&node).context("Failed to serialize node to YAML")
+  }
 }
 
 /// Options for creating a deployment
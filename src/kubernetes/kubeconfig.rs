@@ -0,0 +1,520 @@
+//! Kubeconfig loading, merging and context selection
+//!
+//! Merges the files listed in `KUBECONFIG` (or `~/.kube/config`) with extra files the
+//! user added in settings, following kubectl's rules: the first file to define a cluster,
+//! user or context wins. The context the app talks to can be switched in-app without
+//! touching `current-context` in any file.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context as _, Result, anyhow};
+use kube::Config;
+use kube::config::{AuthInfo, KubeConfigOptions, Kubeconfig};
+use parking_lot::RwLock;
+
+/// Which kubeconfig files and context the app uses
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KubeconfigSelection {
+  /// Files merged after the `KUBECONFIG` ones
+  pub extra_files: Vec<PathBuf>,
+  /// Context used in-app (`None` follows the kubeconfig's `current-context`)
+  pub context: Option<String>,
+}
+
+impl KubeconfigSelection {
+  /// Whether this is plain kubectl behaviour, so `Config::infer` can be used as-is
+  pub fn is_default(&self) -> bool {
+    self.extra_files.is_empty() && self.context.is_none()
+  }
+}
+
+static SELECTION: RwLock<KubeconfigSelection> = RwLock::new(KubeconfigSelection {
+  extra_files: Vec::new(),
+  context: None,
+});
+
+/// Selection every new `KubeClient` is built from
+pub fn kubeconfig_selection() -> KubeconfigSelection {
+  SELECTION.read().clone()
+}
+
+/// Replace the selection; clients created afterwards use it
+pub fn set_kubeconfig_selection(selection: KubeconfigSelection) {
+  *SELECTION.write() = selection;
+}
+
+/// A kubeconfig file taking part in the merge
+#[derive(Debug, Clone)]
+pub struct KubeconfigFile {
+  pub path: PathBuf,
+  /// Added in settings rather than coming from `KUBECONFIG`
+  pub extra: bool,
+  pub exists: bool,
+  /// Read or parse failure
+  pub error: Option<String>,
+}
+
+/// A context from the merged kubeconfig
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KubeContextInfo {
+  pub name: String,
+  pub cluster: String,
+  pub user: Option<String>,
+  pub namespace: Option<String>,
+  /// File the context was taken from
+  pub source: PathBuf,
+}
+
+/// A cluster from the merged kubeconfig
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KubeClusterInfo {
+  pub name: String,
+  pub server: Option<String>,
+  pub insecure: bool,
+}
+
+/// A user (auth info) from the merged kubeconfig
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KubeUserInfo {
+  pub name: String,
+  /// How the user authenticates, e.g. `exec (aws)` or `client certificate`
+  pub auth: String,
+}
+
+/// Everything the context manager shows
+#[derive(Debug, Clone, Default)]
+pub struct KubeconfigOverview {
+  pub files: Vec<KubeconfigFile>,
+  pub contexts: Vec<KubeContextInfo>,
+  pub clusters: Vec<KubeClusterInfo>,
+  pub users: Vec<KubeUserInfo>,
+  /// `current-context` from the merged files
+  pub current_context: Option<String>,
+  /// Context the app actually uses (the in-app override or `current_context`)
+  pub active_context: Option<String>,
+}
+
+impl KubeconfigOverview {
+  pub fn context(&self, name: &str) -> Option<&KubeContextInfo> {
+    self.contexts.iter().find(|c| c.name == name)
+  }
+
+  pub fn active(&self) -> Option<&KubeContextInfo> {
+    self.active_context.as_deref().and_then(|name| self.context(name))
+  }
+}
+
+/// Files from `KUBECONFIG` (or `~/.kube/config`) followed by the extra files, without duplicates
+pub fn kubeconfig_files(extra: &[PathBuf]) -> Vec<(PathBuf, bool)> {
+  let env_paths: Vec<PathBuf> = std::env::var_os("KUBECONFIG")
+    .map(|value| {
+      std::env::split_paths(&value)
+        .filter(|p| !p.as_os_str().is_empty())
+        .collect()
+    })
+    .unwrap_or_default();
+
+  let base = if env_paths.is_empty() {
+    dirs::home_dir()
+      .map(|home| vec![home.join(".kube").join("config")])
+      .unwrap_or_default()
+  } else {
+    env_paths
+  };
+
+  with_extra_files(base, extra)
+}
+
+/// `base` files followed by the extra ones, without duplicates
+fn with_extra_files(base: Vec<PathBuf>, extra: &[PathBuf]) -> Vec<(PathBuf, bool)> {
+  let mut seen = HashSet::new();
+  base
+    .into_iter()
+    .map(|p| (p, false))
+    .chain(extra.iter().map(|p| (expand_home(p), true)))
+    .filter(|(p, _)| seen.insert(p.clone()))
+    .collect()
+}
+
+/// Expand a leading `~/`
+pub fn expand_home(path: &Path) -> PathBuf {
+  match (path.strip_prefix("~"), dirs::home_dir()) {
+    (Ok(rest), Some(home)) => home.join(rest),
+    _ => path.to_path_buf(),
+  }
+}
+
+/// Load and merge the kubeconfig files for a selection
+///
+/// Missing files are skipped like kubectl does; files that fail to parse are reported in
+/// the overview and left out of the merge.
+pub fn load_kubeconfig(selection: &KubeconfigSelection) -> (Option<Kubeconfig>, KubeconfigOverview) {
+  merge_kubeconfig_files(kubeconfig_files(&selection.extra_files), selection.context.as_deref())
+}
+
+/// Merge `files` in order and pick the active context, `context` if some file defines it
+fn merge_kubeconfig_files(
+  files: Vec<(PathBuf, bool)>,
+  context: Option<&str>,
+) -> (Option<Kubeconfig>, KubeconfigOverview) {
+  let mut overview = KubeconfigOverview::default();
+  let mut merged: Option<Kubeconfig> = None;
+
+  for (path, extra) in files {
+    let exists = path.exists();
+    let mut file = KubeconfigFile {
+      path: path.clone(),
+      extra,
+      exists,
+      error: None,
+    };
+
+    if exists {
+      match Kubeconfig::read_from(&path) {
+        Ok(config) => {
+          let known: HashSet<String> = overview.contexts.iter().map(|c| c.name.clone()).collect();
+          for named in &config.contexts {
+            if known.contains(&named.name) {
+              continue;
+            }
+            let context = named.context.as_ref();
+            overview.contexts.push(KubeContextInfo {
+              name: named.name.clone(),
+              cluster: context.map(|c| c.cluster.clone()).unwrap_or_default(),
+              user: context.and_then(|c| c.user.clone()),
+              namespace: context.and_then(|c| c.namespace.clone()),
+              source: path.clone(),
+            });
+          }
+
+          merged = match merged {
+            None => Some(config),
+            Some(base) => match base.clone().merge(config) {
+              Ok(next) => Some(next),
+              Err(e) => {
+                file.error = Some(e.to_string());
+                Some(base)
+              }
+            },
+          };
+        }
+        Err(e) => file.error = Some(e.to_string()),
+      }
+    }
+
+    overview.files.push(file);
+  }
+
+  if let Some(config) = &merged {
+    overview.clusters = config
+      .clusters
+      .iter()
+      .map(|c| KubeClusterInfo {
+        name: c.name.clone(),
+        server: c.cluster.as_ref().and_then(|c| c.server.clone()),
+        insecure: c
+          .cluster
+          .as_ref()
+          .and_then(|c| c.insecure_skip_tls_verify)
+          .unwrap_or(false),
+      })
+      .collect();
+    overview.users = config
+      .auth_infos
+      .iter()
+      .map(|u| KubeUserInfo {
+        name: u.name.clone(),
+        auth: describe_auth(u.auth_info.as_ref()),
+      })
+      .collect();
+    overview.current_context.clone_from(&config.current_context);
+  }
+
+  overview.active_context = context
+    .filter(|name| overview.context(name).is_some())
+    .map(String::from)
+    .or_else(|| overview.current_context.clone());
+
+  (merged, overview)
+}
+
+/// Short description of how a user authenticates
+fn describe_auth(auth: Option<&AuthInfo>) -> String {
+  let Some(auth) = auth else {
+    return "none".to_string();
+  };
+
+  if let Some(exec) = &auth.exec {
+    let command = exec
+      .command
+      .as_deref()
+      .and_then(|c| Path::new(c).file_name())
+      .map(|c| c.to_string_lossy().to_string())
+      .unwrap_or_default();
+    return if command.is_empty() {
+      "exec".to_string()
+    } else {
+      format!("exec ({command})")
+    };
+  }
+  if let Some(provider) = &auth.auth_provider {
+    return format!("auth provider ({})", provider.name);
+  }
+  if auth.client_certificate.is_some() || auth.client_certificate_data.is_some() {
+    return "client certificate".to_string();
+  }
+  if auth.token.is_some() || auth.token_file.is_some() {
+    return "token".to_string();
+  }
+  if auth.username.is_some() {
+    return "basic".to_string();
+  }
+  "none".to_string()
+}
+
+/// Client config for the current selection
+///
+/// The default selection goes through `Config::infer` so in-cluster and plain `KUBECONFIG`
+/// setups behave exactly like kubectl.
+pub async fn client_config(selection: &KubeconfigSelection) -> Result<Config> {
+  if selection.is_default() {
+    return Config::infer().await.context("Failed to load kubeconfig");
+  }
+
+  let (merged, overview) = load_kubeconfig(selection);
+  let kubeconfig = merged.ok_or_else(|| anyhow!("No readable kubeconfig files"))?;
+  // A context that was removed from the files falls back to `current-context`, like the overview
+  let options = KubeConfigOptions {
+    context: selection
+      .context
+      .clone()
+      .filter(|name| overview.context(name).is_some()),
+    ..KubeConfigOptions::default()
+  };
+
+  Config::from_custom_kubeconfig(kubeconfig, &options)
+    .await
+    .context("Failed to load kubeconfig")
+}
+
+/// Set `current-context` on disk, like `kubectl config use-context`
+///
+/// Writes to the first file that already sets `current-context`, otherwise the first
+/// existing file. Only the `current-context:` line is rewritten (or appended when there is
+/// none), so comments and formatting elsewhere in the file are kept.
+pub fn write_current_context(selection: &KubeconfigSelection, context: &str) -> Result<PathBuf> {
+  let files: Vec<PathBuf> = kubeconfig_files(&selection.extra_files)
+    .into_iter()
+    .map(|(p, _)| p)
+    .filter(|p| p.exists())
+    .collect();
+
+  let mut target = None;
+  for path in &files {
+    let content = fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let value: serde_yaml::Value = serde_yaml::from_str(&content).unwrap_or(serde_yaml::Value::Null);
+    let has_current = value
+      .get("current-context")
+      .and_then(serde_yaml::Value::as_str)
+      .is_some_and(|c| !c.is_empty());
+    if has_current {
+      target = Some((path.clone(), content, value));
+      break;
+    }
+    if target.is_none() {
+      target = Some((path.clone(), content, value));
+    }
+  }
+
+  let (path, content, value) = target.ok_or_else(|| anyhow!("No kubeconfig file to write to"))?;
+  if !value.is_mapping() {
+    return Err(anyhow!("{} is not a kubeconfig", path.display()));
+  }
+  let content = set_current_context_line(&content, context).ok_or_else(|| {
+    anyhow!(
+      "Can't find the current-context line in {}; set it with kubectl config use-context",
+      path.display()
+    )
+  })?;
+
+  fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+  Ok(path)
+}
+
+/// `content` with its top-level `current-context:` line set to `context`, appended when missing.
+/// `None` when the key is there but not on a line of its own, as in a JSON kubeconfig.
+fn set_current_context_line(content: &str, context: &str) -> Option<String> {
+  let value = serde_yaml::to_string(context).ok()?;
+  let line = format!("current-context: {}", value.trim_end());
+  let is_key = |l: &str| {
+    ["current-context:", "\"current-context\":", "'current-context':"]
+      .iter()
+      .any(|key| l.starts_with(key))
+  };
+
+  let mut found = false;
+  let mut updated = String::with_capacity(content.len() + line.len());
+  for l in content.split_inclusive('\n') {
+    if found || !is_key(l) {
+      updated.push_str(l);
+      continue;
+    }
+    found = true;
+    updated.push_str(&line);
+    updated.push_str(if l.ends_with("\r\n") {
+      "\r\n"
+    } else if l.ends_with('\n') {
+      "\n"
+    } else {
+      ""
+    });
+  }
+  if found {
+    return Some(updated);
+  }
+
+  let parsed: serde_yaml::Value = serde_yaml::from_str(content).unwrap_or(serde_yaml::Value::Null);
+  if parsed.get("current-context").is_some() {
+    return None;
+  }
+  let separator = if content.is_empty() || content.ends_with('\n') {
+    ""
+  } else {
+    "\n"
+  };
+  Some(format!("{content}{separator}{line}\n"))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const CONFIG: &str = r"
+apiVersion: v1
+kind: Config
+current-context: colima
+clusters:
+- name: colima
+  cluster:
+    server: https://127.0.0.1:6443
+- name: staging
+  cluster:
+    server: https://staging.example.com
+    insecure-skip-tls-verify: true
+contexts:
+- name: colima
+  context:
+    cluster: colima
+    user: colima
+- name: staging
+  context:
+    cluster: staging
+    user: deployer
+    namespace: web
+users:
+- name: colima
+  user:
+    client-certificate-data: AAAA
+- name: deployer
+  user:
+    exec:
+      apiVersion: client.authentication.k8s.io/v1beta1
+      command: /usr/local/bin/aws
+";
+
+  #[test]
+  fn test_selection_default() {
+    assert!(KubeconfigSelection::default().is_default());
+    let selection = KubeconfigSelection {
+      context: Some("staging".to_string()),
+      ..Default::default()
+    };
+    assert!(!selection.is_default());
+  }
+
+  #[test]
+  fn test_describe_auth() {
+    let config = Kubeconfig::from_yaml(CONFIG).unwrap();
+    let auth: Vec<String> = config
+      .auth_infos
+      .iter()
+      .map(|u| describe_auth(u.auth_info.as_ref()))
+      .collect();
+    assert_eq!(auth, vec!["client certificate", "exec (aws)"]);
+    assert_eq!(describe_auth(None), "none");
+  }
+
+  #[test]
+  fn test_load_extra_file() {
+    let dir = std::env::temp_dir().join(format!("dockside-kubeconfig-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("config");
+    fs::write(&path, CONFIG).unwrap();
+
+    // Leave the developer's own KUBECONFIG out of it
+    let (merged, overview) = merge_kubeconfig_files(with_extra_files(Vec::new(), &[path.clone()]), Some("staging"));
+    assert!(merged.is_some());
+
+    let file = overview.files.iter().find(|f| f.path == path).unwrap();
+    assert!(file.extra && file.exists && file.error.is_none());
+
+    let staging = overview.context("staging").unwrap();
+    assert_eq!(staging.namespace.as_deref(), Some("web"));
+    assert_eq!(staging.user.as_deref(), Some("deployer"));
+    assert_eq!(overview.active_context.as_deref(), Some("staging"));
+    assert!(overview.clusters.iter().any(|c| c.name == "staging" && c.insecure));
+
+    fs::remove_dir_all(&dir).ok();
+  }
+
+  #[test]
+  fn test_unknown_context_falls_back_to_current() {
+    let dir = std::env::temp_dir().join(format!("dockside-kubeconfig-fallback-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("config");
+    fs::write(&path, CONFIG).unwrap();
+
+    let (_, overview) = merge_kubeconfig_files(with_extra_files(Vec::new(), &[path]), Some("gone"));
+    assert_eq!(overview.active_context.as_deref(), Some("colima"));
+
+    fs::remove_dir_all(&dir).ok();
+  }
+
+  #[test]
+  fn test_files_are_deduplicated() {
+    let base = PathBuf::from("/tmp/dockside-base.yaml");
+    let extra = PathBuf::from("/tmp/dockside-a.yaml");
+    let files = with_extra_files(vec![base.clone()], &[extra.clone(), extra.clone(), base.clone()]);
+    assert_eq!(files, vec![(base, false), (extra, true)]);
+  }
+
+  #[test]
+  fn test_set_current_context_line() {
+    let content = "# Managed by hand\napiVersion: v1\ncurrent-context: colima # local\nkind: Config\n";
+    let updated = set_current_context_line(content, "staging").unwrap();
+    assert_eq!(
+      updated,
+      "# Managed by hand\napiVersion: v1\ncurrent-context: staging\nkind: Config\n"
+    );
+
+    // Added at the end when the file has none
+    let updated = set_current_context_line("apiVersion: v1\nkind: Config", "staging").unwrap();
+    assert_eq!(updated, "apiVersion: v1\nkind: Config\ncurrent-context: staging\n");
+
+    // Names that need quoting stay valid YAML
+    let updated = set_current_context_line("current-context: colima\r\n", "dev: eu").unwrap();
+    assert!(updated.ends_with("\r\n"));
+    let value: serde_yaml::Value = serde_yaml::from_str(&updated).unwrap();
+    assert_eq!(value["current-context"].as_str(), Some("dev: eu"));
+
+    // Nested keys are left alone
+    let nested = "contexts:\n- name: a\n  context:\n    current-context: x\n";
+    let updated = set_current_context_line(nested, "b").unwrap();
+    assert!(updated.starts_with(nested));
+    let value: serde_yaml::Value = serde_yaml::from_str(&updated).unwrap();
+    assert_eq!(value["current-context"].as_str(), Some("b"));
+    assert!(set_current_context_line("{\"kind\": \"Config\", \"current-context\": \"a\"}", "b").is_none());
+  }
+}
@@ -1,5 +1,15 @@
 mod client;
+mod kubeconfig;
 mod types;
 
 pub use client::{ContainerPortConfig, CreateDeploymentOptions, CreateServiceOptions, KubeClient, ServicePortConfig};
-pub use types::{DeploymentInfo, PodInfo, PodPhase, ServiceInfo};
+pub use kubeconfig::{
+  KubeClusterInfo, KubeContextInfo, KubeUserInfo, KubeconfigFile, KubeconfigOverview, KubeconfigSelection, expand_home,
+  kubeconfig_selection, load_kubeconfig, set_kubeconfig_selection, write_current_context,
+};
+pub use types::{
+  ConfigEntry, ConfigInfo, ConfigKind, ConfigRef, ConfigUsage, DeploymentInfo, IngressInfo, IngressRoute, JobState,
+  NodeAllocation, NodeCondition, NodeInfo, NodePod, NodeResources, PersistentVolumeInfo, PodInfo, PodPhase,
+  ServiceInfo, StorageClassInfo, VolumeClaimInfo, WorkloadHealth, WorkloadInfo, WorkloadKind, WorkloadStatus,
+  access_modes_display, format_cpu_millis, format_memory, is_valid_config_key, parse_file_sources, parse_literal_pairs,
+};
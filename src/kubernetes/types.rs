@@ -79,6 +79,11 @@ pub struct PodInfo {
   pub ip: Option<String>,
   pub containers: Vec<PodContainer>,
   pub labels: HashMap<String, String>,
+  pub annotations: HashMap<String, String>,
+  /// ConfigMaps and Secrets the pod consumes
+  pub config_refs: Vec<ConfigRef>,
+  /// PersistentVolumeClaims the pod mounts
+  pub volume_claims: Vec<String>,
 }
 
 impl PodInfo {
@@ -90,6 +95,7 @@ impl PodInfo {
     let name = metadata.name.clone().unwrap_or_default();
     let namespace = metadata.namespace.clone().unwrap_or_else(|| "default".to_string());
     let labels: HashMap<String, String> = metadata.labels.clone().unwrap_or_default().into_iter().collect();
+    let annotations: HashMap<String, String> = metadata.annotations.clone().unwrap_or_default().into_iter().collect();
     let creation_timestamp = metadata.creation_timestamp.as_ref().map(|t| t.0);
 
     let phase = status
@@ -99,6 +105,8 @@ impl PodInfo {
 
     let node = spec.and_then(|s| s.node_name.clone());
     let ip = status.and_then(|s| s.pod_ip.clone());
+    let config_refs = spec.map(config_refs).unwrap_or_default();
+    let volume_claims = spec.map(volume_claims).unwrap_or_default();
 
     // Get container statuses
     let container_statuses: Vec<PodContainer> = status
@@ -128,6 +136,9 @@ impl PodInfo {
       ip,
       containers: container_statuses,
       labels,
+      annotations,
+      config_refs,
+      volume_claims,
     }
   }
 }
@@ -180,6 +191,7 @@ pub struct ServiceInfo {
   pub selector: HashMap<String, String>,
   pub age: String,
   pub labels: HashMap<String, String>,
+  pub annotations: HashMap<String, String>,
 }
 
 impl ServiceInfo {
@@ -190,6 +202,7 @@ impl ServiceInfo {
     let name = metadata.name.clone().unwrap_or_default();
     let namespace = metadata.namespace.clone().unwrap_or_else(|| "default".to_string());
     let labels: HashMap<String, String> = metadata.labels.clone().unwrap_or_default().into_iter().collect();
+    let annotations: HashMap<String, String> = metadata.annotations.clone().unwrap_or_default().into_iter().collect();
     let creation_timestamp = metadata.creation_timestamp.as_ref().map(|t| t.0);
 
     let service_type = spec
@@ -240,6 +253,7 @@ impl ServiceInfo {
       selector,
       age,
       labels,
+      annotations,
     }
   }
 
@@ -275,7 +289,10 @@ pub struct DeploymentInfo {
   pub available_replicas: i32,
   pub age: String,
   pub labels: HashMap<String, String>,
+  pub annotations: HashMap<String, String>,
   pub images: Vec<String>,
+  /// ConfigMaps and Secrets the pod template consumes
+  pub config_refs: Vec<ConfigRef>,
 }
 
 impl DeploymentInfo {
@@ -287,6 +304,7 @@ impl DeploymentInfo {
     let name = metadata.name.clone().unwrap_or_default();
     let namespace = metadata.namespace.clone().unwrap_or_else(|| "default".to_string());
     let labels: HashMap<String, String> = metadata.labels.clone().unwrap_or_default().into_iter().collect();
+    let annotations: HashMap<String, String> = metadata.annotations.clone().unwrap_or_default().into_iter().collect();
     let creation_timestamp = metadata.creation_timestamp.as_ref().map(|t| t.0);
 
     let replicas = spec.and_then(|s| s.replicas).unwrap_or(0);
@@ -299,25 +317,1531 @@ impl DeploymentInfo {
       .and_then(|s| s.template.spec.as_ref())
       .map(|pod_spec| pod_spec.containers.iter().filter_map(|c| c.image.clone()).collect())
       .unwrap_or_default();
+    let config_refs = spec
+      .and_then(|s| s.template.spec.as_ref())
+      .map(config_refs)
+      .unwrap_or_default();
+
+    let age = creation_timestamp.map_or_else(|| "Unknown".to_string(), format_age);
+
+    Self {
+      name,
+      namespace,
+      replicas,
+      ready_replicas,
+      updated_replicas,
+      available_replicas,
+      age,
+      labels,
+      annotations,
+      images,
+      config_refs,
+    }
+  }
+
+  /// Get ready status string (e.g., "2/3")
+  pub fn ready_display(&self) -> String {
+    format!("{}/{}", self.ready_replicas, self.replicas)
+  }
+}
+
+// ============================================================================
+// Workload Types (StatefulSets, DaemonSets, Jobs, CronJobs)
+// ============================================================================
+
+/// Workload controllers listed alongside deployments
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WorkloadKind {
+  StatefulSet,
+  DaemonSet,
+  Job,
+  CronJob,
+}
+
+impl WorkloadKind {
+  pub const ALL: [WorkloadKind; 4] = [
+    WorkloadKind::StatefulSet,
+    WorkloadKind::DaemonSet,
+    WorkloadKind::Job,
+    WorkloadKind::CronJob,
+  ];
+
+  /// Kubernetes kind, as used in owner references and events
+  pub fn label(self) -> &'static str {
+    match self {
+      WorkloadKind::StatefulSet => "StatefulSet",
+      WorkloadKind::DaemonSet => "DaemonSet",
+      WorkloadKind::Job => "Job",
+      WorkloadKind::CronJob => "CronJob",
+    }
+  }
+
+  pub fn plural(self) -> &'static str {
+    match self {
+      WorkloadKind::StatefulSet => "StatefulSets",
+      WorkloadKind::DaemonSet => "DaemonSets",
+      WorkloadKind::Job => "Jobs",
+      WorkloadKind::CronJob => "CronJobs",
+    }
+  }
+
+  pub fn from_label(kind: &str) -> Option<Self> {
+    Self::ALL.into_iter().find(|k| k.label() == kind)
+  }
+
+  /// Only StatefulSets have a replica count to scale
+  pub fn is_scalable(self) -> bool {
+    matches!(self, WorkloadKind::StatefulSet)
+  }
+
+  /// StatefulSets and DaemonSets support a rollout restart
+  pub fn is_restartable(self) -> bool {
+    matches!(self, WorkloadKind::StatefulSet | WorkloadKind::DaemonSet)
+  }
+}
+
+/// Where a job stands, from its conditions and counters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobState {
+  Running,
+  Complete,
+  Failed,
+  Suspended,
+}
+
+impl std::fmt::Display for JobState {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      JobState::Running => write!(f, "Running"),
+      JobState::Complete => write!(f, "Complete"),
+      JobState::Failed => write!(f, "Failed"),
+      JobState::Suspended => write!(f, "Suspended"),
+    }
+  }
+}
+
+/// Kind-specific status of a workload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkloadStatus {
+  StatefulSet {
+    replicas: i32,
+    ready: i32,
+    current: i32,
+    updated: i32,
+  },
+  DaemonSet {
+    desired: i32,
+    current: i32,
+    ready: i32,
+    updated: i32,
+    available: i32,
+  },
+  Job {
+    completions: i32,
+    succeeded: i32,
+    failed: i32,
+    active: i32,
+    state: JobState,
+    /// How long the job ran (or has been running)
+    duration: Option<String>,
+  },
+  CronJob {
+    schedule: String,
+    suspended: bool,
+    active: usize,
+    last_schedule: Option<String>,
+    last_successful: Option<String>,
+  },
+}
+
+/// Overall health used for list badges
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkloadHealth {
+  Healthy,
+  Progressing,
+  Failing,
+  Idle,
+}
+
+/// StatefulSet, DaemonSet, Job or CronJob information
+#[derive(Debug, Clone)]
+pub struct WorkloadInfo {
+  pub kind: WorkloadKind,
+  pub name: String,
+  pub namespace: String,
+  pub age: String,
+  pub labels: HashMap<String, String>,
+  pub annotations: HashMap<String, String>,
+  /// Pod selector (`matchLabels`); empty for CronJobs, whose pods belong to their jobs
+  pub selector: HashMap<String, String>,
+  pub images: Vec<String>,
+  /// Controlling CronJob of a Job
+  pub owner: Option<String>,
+  pub status: WorkloadStatus,
+}
+
+impl WorkloadInfo {
+  fn base(
+    kind: WorkloadKind,
+    metadata: &k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta,
+    selector: Option<&k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector>,
+    pod_spec: Option<&k8s_openapi::api::core::v1::PodSpec>,
+    status: WorkloadStatus,
+  ) -> Self {
+    let owner = metadata
+      .owner_references
+      .as_ref()
+      .and_then(|refs| refs.iter().find(|r| r.kind == "CronJob"))
+      .map(|r| r.name.clone());
+
+    Self {
+      kind,
+      name: metadata.name.clone().unwrap_or_default(),
+      namespace: metadata.namespace.clone().unwrap_or_else(|| "default".to_string()),
+      age: metadata
+        .creation_timestamp
+        .as_ref()
+        .map_or_else(|| "Unknown".to_string(), |t| format_age(t.0)),
+      labels: metadata.labels.clone().unwrap_or_default().into_iter().collect(),
+      annotations: metadata.annotations.clone().unwrap_or_default().into_iter().collect(),
+      selector: selector
+        .and_then(|s| s.match_labels.clone())
+        .unwrap_or_default()
+        .into_iter()
+        .collect(),
+      images: pod_spec
+        .map(|spec| spec.containers.iter().filter_map(|c| c.image.clone()).collect())
+        .unwrap_or_default(),
+      owner,
+      status,
+    }
+  }
+
+  pub fn from_stateful_set(sts: &k8s_openapi::api::apps::v1::StatefulSet) -> Self {
+    let spec = sts.spec.as_ref();
+    let status = sts.status.as_ref();
+
+    Self::base(
+      WorkloadKind::StatefulSet,
+      &sts.metadata,
+      spec.map(|s| &s.selector),
+      spec.and_then(|s| s.template.spec.as_ref()),
+      WorkloadStatus::StatefulSet {
+        replicas: spec.and_then(|s| s.replicas).unwrap_or(1),
+        ready: status.and_then(|s| s.ready_replicas).unwrap_or(0),
+        current: status.and_then(|s| s.current_replicas).unwrap_or(0),
+        updated: status.and_then(|s| s.updated_replicas).unwrap_or(0),
+      },
+    )
+  }
+
+  pub fn from_daemon_set(ds: &k8s_openapi::api::apps::v1::DaemonSet) -> Self {
+    let spec = ds.spec.as_ref();
+    let status = ds.status.as_ref();
+
+    Self::base(
+      WorkloadKind::DaemonSet,
+      &ds.metadata,
+      spec.map(|s| &s.selector),
+      spec.and_then(|s| s.template.spec.as_ref()),
+      WorkloadStatus::DaemonSet {
+        desired: status.map_or(0, |s| s.desired_number_scheduled),
+        current: status.map_or(0, |s| s.current_number_scheduled),
+        ready: status.map_or(0, |s| s.number_ready),
+        updated: status.and_then(|s| s.updated_number_scheduled).unwrap_or(0),
+        available: status.and_then(|s| s.number_available).unwrap_or(0),
+      },
+    )
+  }
+
+  pub fn from_job(job: &k8s_openapi::api::batch::v1::Job) -> Self {
+    let spec = job.spec.as_ref();
+    let status = job.status.as_ref();
+
+    let has_condition = |type_: &str| {
+      status
+        .and_then(|s| s.conditions.as_ref())
+        .is_some_and(|conds| conds.iter().any(|c| c.type_ == type_ && c.status == "True"))
+    };
+    let state = if has_condition("Complete") {
+      JobState::Complete
+    } else if has_condition("Failed") {
+      JobState::Failed
+    } else if spec.and_then(|s| s.suspend).unwrap_or(false) {
+      JobState::Suspended
+    } else {
+      JobState::Running
+    };
+
+    let start = status.and_then(|s| s.start_time.as_ref()).map(|t| t.0);
+    let end = status.and_then(|s| s.completion_time.as_ref()).map(|t| t.0);
+    let duration = start.map(|start| format_duration(end.unwrap_or_else(Utc::now) - start));
+
+    Self::base(
+      WorkloadKind::Job,
+      &job.metadata,
+      spec.and_then(|s| s.selector.as_ref()),
+      spec.and_then(|s| s.template.spec.as_ref()),
+      WorkloadStatus::Job {
+        completions: spec.and_then(|s| s.completions).unwrap_or(1),
+        succeeded: status.and_then(|s| s.succeeded).unwrap_or(0),
+        failed: status.and_then(|s| s.failed).unwrap_or(0),
+        active: status.and_then(|s| s.active).unwrap_or(0),
+        state,
+        duration,
+      },
+    )
+  }
+
+  pub fn from_cron_job(cj: &k8s_openapi::api::batch::v1::CronJob) -> Self {
+    let spec = cj.spec.as_ref();
+    let status = cj.status.as_ref();
+    let pod_spec = spec
+      .and_then(|s| s.job_template.spec.as_ref())
+      .and_then(|s| s.template.spec.as_ref());
+
+    Self::base(
+      WorkloadKind::CronJob,
+      &cj.metadata,
+      None,
+      pod_spec,
+      WorkloadStatus::CronJob {
+        schedule: spec.map(|s| s.schedule.clone()).unwrap_or_default(),
+        suspended: spec.and_then(|s| s.suspend).unwrap_or(false),
+        active: status.and_then(|s| s.active.as_ref()).map_or(0, Vec::len),
+        last_schedule: status
+          .and_then(|s| s.last_schedule_time.as_ref())
+          .map(|t| format_age(t.0)),
+        last_successful: status
+          .and_then(|s| s.last_successful_time.as_ref())
+          .map(|t| format_age(t.0)),
+      },
+    )
+  }
+
+  /// Short status for list badges, e.g. "2/3", "1/1 done" or the schedule
+  pub fn status_display(&self) -> String {
+    match &self.status {
+      WorkloadStatus::StatefulSet { replicas, ready, .. } => format!("{ready}/{replicas}"),
+      WorkloadStatus::DaemonSet { desired, ready, .. } => format!("{ready}/{desired}"),
+      WorkloadStatus::Job {
+        completions,
+        succeeded,
+        state,
+        ..
+      } => match state {
+        JobState::Complete => format!("{succeeded}/{completions} done"),
+        JobState::Failed | JobState::Suspended => state.to_string(),
+        JobState::Running => format!("{succeeded}/{completions}"),
+      },
+      WorkloadStatus::CronJob {
+        schedule, suspended, ..
+      } => {
+        if *suspended {
+          "Suspended".to_string()
+        } else {
+          schedule.clone()
+        }
+      }
+    }
+  }
+
+  pub fn health(&self) -> WorkloadHealth {
+    let replica_health = |ready: i32, desired: i32| {
+      if desired == 0 {
+        WorkloadHealth::Idle
+      } else if ready >= desired {
+        WorkloadHealth::Healthy
+      } else if ready > 0 {
+        WorkloadHealth::Progressing
+      } else {
+        WorkloadHealth::Failing
+      }
+    };
+
+    match &self.status {
+      WorkloadStatus::StatefulSet { replicas, ready, .. } => replica_health(*ready, *replicas),
+      WorkloadStatus::DaemonSet { desired, ready, .. } => replica_health(*ready, *desired),
+      WorkloadStatus::Job { state, .. } => match state {
+        JobState::Complete => WorkloadHealth::Healthy,
+        JobState::Running => WorkloadHealth::Progressing,
+        JobState::Failed => WorkloadHealth::Failing,
+        JobState::Suspended => WorkloadHealth::Idle,
+      },
+      WorkloadStatus::CronJob { suspended, .. } => {
+        if *suspended {
+          WorkloadHealth::Idle
+        } else {
+          WorkloadHealth::Healthy
+        }
+      }
+    }
+  }
+
+  /// Desired replicas of a StatefulSet
+  pub fn replicas(&self) -> Option<i32> {
+    match self.status {
+      WorkloadStatus::StatefulSet { replicas, .. } => Some(replicas),
+      _ => None,
+    }
+  }
+
+  pub fn is_suspended(&self) -> bool {
+    matches!(
+      self.status,
+      WorkloadStatus::CronJob { suspended: true, .. }
+        | WorkloadStatus::Job {
+          state: JobState::Suspended,
+          ..
+        }
+    )
+  }
+
+  /// Whether a pod is one of this workload's, by the selector labels
+  pub fn selects(&self, pod: &PodInfo) -> bool {
+    pod.namespace == self.namespace
+      && !self.selector.is_empty()
+      && self.selector.iter().all(|(k, v)| pod.labels.get(k) == Some(v))
+  }
+}
+
+/// Format a duration as e.g. "45s", "3m12s" or "2h5m"
+fn format_duration(duration: chrono::Duration) -> String {
+  let secs = duration.num_seconds().max(0);
+  if secs >= 3600 {
+    format!("{}h{}m", secs / 3600, (secs % 3600) / 60)
+  } else if secs >= 60 {
+    format!("{}m{}s", secs / 60, secs % 60)
+  } else {
+    format!("{secs}s")
+  }
+}
+
+// ============================================================================
+// Config Types (ConfigMaps, Secrets)
+// ============================================================================
+
+/// ConfigMap or Secret
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfigKind {
+  ConfigMap,
+  Secret,
+}
+
+impl ConfigKind {
+  pub const ALL: [ConfigKind; 2] = [ConfigKind::ConfigMap, ConfigKind::Secret];
+
+  pub fn label(self) -> &'static str {
+    match self {
+      ConfigKind::ConfigMap => "ConfigMap",
+      ConfigKind::Secret => "Secret",
+    }
+  }
+
+  pub fn plural(self) -> &'static str {
+    match self {
+      ConfigKind::ConfigMap => "ConfigMaps",
+      ConfigKind::Secret => "Secrets",
+    }
+  }
+}
+
+/// How a pod consumes a ConfigMap or Secret
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConfigUsage {
+  Env,
+  Volume,
+}
+
+impl std::fmt::Display for ConfigUsage {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ConfigUsage::Env => write!(f, "env"),
+      ConfigUsage::Volume => write!(f, "volume"),
+    }
+  }
+}
+
+/// A ConfigMap or Secret referenced from a pod spec
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigRef {
+  pub kind: ConfigKind,
+  pub name: String,
+  pub usage: ConfigUsage,
+}
+
+/// ConfigMaps and Secrets a pod spec references through env vars, `envFrom` or volumes
+pub fn config_refs(spec: &k8s_openapi::api::core::v1::PodSpec) -> Vec<ConfigRef> {
+  let mut refs = Vec::new();
+  let mut push = |kind, name: &str, usage| {
+    let r = ConfigRef {
+      kind,
+      name: name.to_string(),
+      usage,
+    };
+    if !name.is_empty() && !refs.contains(&r) {
+      refs.push(r);
+    }
+  };
+
+  let containers = spec.containers.iter().chain(spec.init_containers.iter().flatten());
+  for container in containers {
+    for env in container.env.iter().flatten() {
+      let Some(source) = &env.value_from else { continue };
+      if let Some(key_ref) = &source.config_map_key_ref {
+        push(ConfigKind::ConfigMap, &key_ref.name, ConfigUsage::Env);
+      }
+      if let Some(key_ref) = &source.secret_key_ref {
+        push(ConfigKind::Secret, &key_ref.name, ConfigUsage::Env);
+      }
+    }
+    for env_from in container.env_from.iter().flatten() {
+      if let Some(cm) = &env_from.config_map_ref {
+        push(ConfigKind::ConfigMap, &cm.name, ConfigUsage::Env);
+      }
+      if let Some(secret) = &env_from.secret_ref {
+        push(ConfigKind::Secret, &secret.name, ConfigUsage::Env);
+      }
+    }
+  }
+
+  for volume in spec.volumes.iter().flatten() {
+    if let Some(cm) = &volume.config_map {
+      push(ConfigKind::ConfigMap, &cm.name, ConfigUsage::Volume);
+    }
+    if let Some(name) = volume.secret.as_ref().and_then(|s| s.secret_name.as_deref()) {
+      push(ConfigKind::Secret, name, ConfigUsage::Volume);
+    }
+    for source in volume.projected.iter().flat_map(|p| p.sources.iter().flatten()) {
+      if let Some(cm) = &source.config_map {
+        push(ConfigKind::ConfigMap, &cm.name, ConfigUsage::Volume);
+      }
+      if let Some(secret) = &source.secret {
+        push(ConfigKind::Secret, &secret.name, ConfigUsage::Volume);
+      }
+    }
+  }
+
+  refs
+}
+
+/// One key of a ConfigMap or Secret
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigEntry {
+  pub key: String,
+  /// Decoded value; base64 for binary data
+  pub value: String,
+  /// Value is not valid UTF-8 and is shown base64-encoded
+  pub binary: bool,
+}
+
+impl ConfigEntry {
+  fn from_bytes(key: String, bytes: Vec<u8>) -> Self {
+    match String::from_utf8(bytes) {
+      Ok(value) => Self {
+        key,
+        value,
+        binary: false,
+      },
+      Err(e) => Self {
+        key,
+        value: serde_json::to_value(k8s_openapi::ByteString(e.into_bytes()))
+          .ok()
+          .and_then(|v| v.as_str().map(str::to_string))
+          .unwrap_or_default(),
+        binary: true,
+      },
+    }
+  }
+}
+
+/// ConfigMap or Secret information with decoded data
+#[derive(Debug, Clone)]
+pub struct ConfigInfo {
+  pub kind: ConfigKind,
+  pub name: String,
+  pub namespace: String,
+  pub age: String,
+  /// Secret type, e.g. `Opaque` or `kubernetes.io/tls`; `None` for ConfigMaps
+  pub secret_type: Option<String>,
+  pub immutable: bool,
+  pub labels: HashMap<String, String>,
+  pub annotations: HashMap<String, String>,
+  /// Entries sorted by key
+  pub entries: Vec<ConfigEntry>,
+}
+
+impl ConfigInfo {
+  fn base(
+    kind: ConfigKind,
+    metadata: &k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta,
+    immutable: Option<bool>,
+    mut entries: Vec<ConfigEntry>,
+  ) -> Self {
+    entries.sort_by(|a, b| a.key.cmp(&b.key));
+
+    Self {
+      kind,
+      name: metadata.name.clone().unwrap_or_default(),
+      namespace: metadata.namespace.clone().unwrap_or_else(|| "default".to_string()),
+      age: metadata
+        .creation_timestamp
+        .as_ref()
+        .map_or_else(|| "Unknown".to_string(), |t| format_age(t.0)),
+      secret_type: None,
+      immutable: immutable.unwrap_or(false),
+      labels: metadata.labels.clone().unwrap_or_default().into_iter().collect(),
+      annotations: metadata.annotations.clone().unwrap_or_default().into_iter().collect(),
+      entries,
+    }
+  }
+
+  pub fn from_config_map(cm: &k8s_openapi::api::core::v1::ConfigMap) -> Self {
+    let text = cm
+      .data
+      .clone()
+      .unwrap_or_default()
+      .into_iter()
+      .map(|(key, value)| ConfigEntry {
+        key,
+        value,
+        binary: false,
+      });
+    let binary = cm
+      .binary_data
+      .clone()
+      .unwrap_or_default()
+      .into_iter()
+      .map(|(key, bytes)| ConfigEntry::from_bytes(key, bytes.0));
+
+    Self::base(
+      ConfigKind::ConfigMap,
+      &cm.metadata,
+      cm.immutable,
+      text.chain(binary).collect(),
+    )
+  }
+
+  pub fn from_secret(secret: &k8s_openapi::api::core::v1::Secret) -> Self {
+    // The API returns base64; `ByteString` has already decoded it
+    let entries = secret
+      .data
+      .clone()
+      .unwrap_or_default()
+      .into_iter()
+      .map(|(key, bytes)| ConfigEntry::from_bytes(key, bytes.0))
+      .collect();
+
+    let mut info = Self::base(ConfigKind::Secret, &secret.metadata, secret.immutable, entries);
+    info.secret_type = Some(secret.type_.clone().unwrap_or_else(|| "Opaque".to_string()));
+    info
+  }
+
+  pub fn entry(&self, key: &str) -> Option<&ConfigEntry> {
+    self.entries.iter().find(|e| e.key == key)
+  }
+
+  /// How a pod spec with these references consumes this object; empty if it doesn't
+  pub fn usages(&self, refs: &[ConfigRef]) -> Vec<ConfigUsage> {
+    let mut usages: Vec<ConfigUsage> = refs
+      .iter()
+      .filter(|r| r.kind == self.kind && r.name == self.name)
+      .map(|r| r.usage)
+      .collect();
+    usages.sort();
+    usages.dedup();
+    usages
+  }
+
+  /// Whether a pod in the same namespace consumes this object
+  pub fn is_used_by(&self, pod: &PodInfo) -> bool {
+    pod.namespace == self.namespace && !self.usages(&pod.config_refs).is_empty()
+  }
+}
+
+/// Config keys must be alphanumerics, `-`, `_` or `.`
+pub fn is_valid_config_key(key: &str) -> bool {
+  !key.is_empty()
+    && key.len() <= 253
+    && key
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// Parse `key=value` lines (like `kubectl create --from-literal`); blank lines and `#` comments are skipped
+pub fn parse_literal_pairs(text: &str) -> Result<Vec<(String, String)>, String> {
+  let mut pairs: Vec<(String, String)> = Vec::new();
+  for line in text.lines() {
+    let line = line.trim_start();
+    if line.trim().is_empty() || line.starts_with('#') {
+      continue;
+    }
+    let Some((key, value)) = line.split_once('=') else {
+      return Err(format!("Expected key=value, got '{}'", line.trim()));
+    };
+    let key = key.trim();
+    if !is_valid_config_key(key) {
+      return Err(format!("Invalid key '{key}'"));
+    }
+    if pairs.iter().any(|(k, _)| k == key) {
+      return Err(format!("Duplicate key '{key}'"));
+    }
+    pairs.push((key.to_string(), value.to_string()));
+  }
+  Ok(pairs)
+}
+
+/// Parse file sources, one per line: `path` (key is the file name) or `key=path`
+/// (like `kubectl create --from-file`)
+pub fn parse_file_sources(text: &str) -> Result<Vec<(String, std::path::PathBuf)>, String> {
+  let mut sources: Vec<(String, std::path::PathBuf)> = Vec::new();
+  for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+    let (key, path) = match line.split_once('=') {
+      Some((key, path)) => (key.trim().to_string(), std::path::PathBuf::from(path.trim())),
+      None => {
+        let path = std::path::PathBuf::from(line);
+        let key = path
+          .file_name()
+          .map(|n| n.to_string_lossy().to_string())
+          .unwrap_or_default();
+        (key, path)
+      }
+    };
+    if !is_valid_config_key(&key) {
+      return Err(format!("Invalid key '{key}' for {}", path.display()));
+    }
+    if sources.iter().any(|(k, _)| *k == key) {
+      return Err(format!("Duplicate key '{key}'"));
+    }
+    sources.push((key, path));
+  }
+  Ok(sources)
+}
+
+// ============================================================================
+// Ingress Types
+// ============================================================================
+
+/// One host/path rule of an Ingress, resolved to its backend
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IngressRoute {
+  /// `None` matches any host
+  pub host: Option<String>,
+  pub path: String,
+  pub path_type: String,
+  /// Backend service name; `None` for resource backends
+  pub service: Option<String>,
+  /// Service port number or name
+  pub port: Option<String>,
+  /// Backend as shown, e.g. `web:80` or `StorageBucket/static`
+  pub backend: String,
+  /// Host is listed in the Ingress TLS section
+  pub tls: bool,
+}
+
+impl IngressRoute {
+  fn from_backend(
+    host: Option<String>,
+    path: String,
+    path_type: String,
+    backend: &k8s_openapi::api::networking::v1::IngressBackend,
+    tls_hosts: &[String],
+  ) -> Self {
+    let service = backend.service.as_ref().map(|s| s.name.clone());
+    let port = backend
+      .service
+      .as_ref()
+      .and_then(|s| s.port.as_ref())
+      .and_then(|p| p.number.map(|n| n.to_string()).or_else(|| p.name.clone()));
+    let backend_display = match (&service, &port, &backend.resource) {
+      (Some(svc), Some(port), _) => format!("{svc}:{port}"),
+      (Some(svc), None, _) => svc.clone(),
+      (None, _, Some(resource)) => format!("{}/{}", resource.kind, resource.name),
+      (None, _, None) => "-".to_string(),
+    };
+    let tls = host.as_ref().is_some_and(|h| tls_hosts.contains(h));
+
+    Self {
+      host,
+      path,
+      path_type,
+      service,
+      port,
+      backend: backend_display,
+      tls,
+    }
+  }
+
+  /// URL to open in a browser; rules without a host use the first load balancer address
+  pub fn url(&self, addresses: &[String]) -> Option<String> {
+    let host = self.host.as_ref().or_else(|| addresses.first())?;
+    // Wildcard hosts can't be opened
+    if host.contains('*') {
+      return None;
+    }
+    let scheme = if self.tls { "https" } else { "http" };
+    let path = if self.path.starts_with('/') {
+      self.path.clone()
+    } else {
+      format!("/{}", self.path)
+    };
+    Some(format!("{scheme}://{host}{path}"))
+  }
+}
+
+/// Ingress information
+#[derive(Debug, Clone)]
+pub struct IngressInfo {
+  pub name: String,
+  pub namespace: String,
+  pub age: String,
+  pub class: Option<String>,
+  /// Load balancer IPs and hostnames
+  pub addresses: Vec<String>,
+  pub routes: Vec<IngressRoute>,
+  pub default_backend: Option<String>,
+  pub tls_hosts: Vec<String>,
+  pub labels: HashMap<String, String>,
+  pub annotations: HashMap<String, String>,
+}
+
+impl IngressInfo {
+  pub fn from_ingress(ingress: &k8s_openapi::api::networking::v1::Ingress) -> Self {
+    let metadata = &ingress.metadata;
+    let spec = ingress.spec.as_ref();
+    let annotations: HashMap<String, String> = metadata.annotations.clone().unwrap_or_default().into_iter().collect();
+
+    let tls_hosts: Vec<String> = spec
+      .and_then(|s| s.tls.as_ref())
+      .map(|tls| tls.iter().flat_map(|t| t.hosts.clone().unwrap_or_default()).collect())
+      .unwrap_or_default();
+
+    let mut routes = Vec::new();
+    for rule in spec.and_then(|s| s.rules.as_ref()).into_iter().flatten() {
+      for path in rule.http.iter().flat_map(|http| &http.paths) {
+        routes.push(IngressRoute::from_backend(
+          rule.host.clone(),
+          path.path.clone().unwrap_or_else(|| "/".to_string()),
+          path.path_type.clone(),
+          &path.backend,
+          &tls_hosts,
+        ));
+      }
+    }
+
+    let default_backend = spec
+      .and_then(|s| s.default_backend.as_ref())
+      .map(|b| IngressRoute::from_backend(None, "/".to_string(), String::new(), b, &tls_hosts).backend);
+
+    let addresses = ingress
+      .status
+      .as_ref()
+      .and_then(|s| s.load_balancer.as_ref())
+      .and_then(|lb| lb.ingress.as_ref())
+      .map(|lbs| {
+        lbs
+          .iter()
+          .filter_map(|lb| lb.ip.clone().or_else(|| lb.hostname.clone()))
+          .collect()
+      })
+      .unwrap_or_default();
+
+    // Older clusters still set the class through an annotation
+    let class = spec
+      .and_then(|s| s.ingress_class_name.clone())
+      .or_else(|| annotations.get("kubernetes.io/ingress.class").cloned());
+
+    Self {
+      name: metadata.name.clone().unwrap_or_default(),
+      namespace: metadata.namespace.clone().unwrap_or_else(|| "default".to_string()),
+      age: metadata
+        .creation_timestamp
+        .as_ref()
+        .map_or_else(|| "Unknown".to_string(), |t| format_age(t.0)),
+      class,
+      addresses,
+      routes,
+      default_backend,
+      tls_hosts,
+      labels: metadata.labels.clone().unwrap_or_default().into_iter().collect(),
+      annotations,
+    }
+  }
+
+  /// Distinct backend services, in rule order
+  pub fn backend_services(&self) -> Vec<String> {
+    let mut services: Vec<String> = Vec::new();
+    for service in self.routes.iter().filter_map(|r| r.service.as_ref()) {
+      if !services.contains(service) {
+        services.push(service.clone());
+      }
+    }
+    services
+  }
+
+  /// Hosts shown in lists, e.g. "app.local, api.local" or "*"
+  pub fn hosts_display(&self) -> String {
+    let mut hosts: Vec<&str> = Vec::new();
+    for route in &self.routes {
+      let host = route.host.as_deref().unwrap_or("*");
+      if !hosts.contains(&host) {
+        hosts.push(host);
+      }
+    }
+    if hosts.is_empty() {
+      "*".to_string()
+    } else {
+      hosts.join(", ")
+    }
+  }
+}
+
+// ============================================================================
+// Storage Types (PersistentVolumeClaims, PersistentVolumes, StorageClasses)
+// ============================================================================
+
+/// Short form of an access mode, as kubectl prints it
+pub fn short_access_mode(mode: &str) -> &str {
+  match mode {
+    "ReadWriteOnce" => "RWO",
+    "ReadOnlyMany" => "ROX",
+    "ReadWriteMany" => "RWX",
+    "ReadWriteOncePod" => "RWOP",
+    other => other,
+  }
+}
+
+/// Access modes joined for display, e.g. "RWO,RWX"
+pub fn access_modes_display(modes: &[String]) -> String {
+  if modes.is_empty() {
+    return "-".to_string();
+  }
+  modes.iter().map(|m| short_access_mode(m)).collect::<Vec<_>>().join(",")
+}
+
+/// `storage` from a resource map such as capacity or requests
+fn storage_quantity(
+  map: Option<&std::collections::BTreeMap<String, k8s_openapi::apimachinery::pkg::api::resource::Quantity>>,
+) -> Option<String> {
+  map.and_then(|m| m.get("storage")).map(|q| q.0.clone())
+}
+
+/// PersistentVolumeClaims a pod spec mounts
+pub fn volume_claims(spec: &k8s_openapi::api::core::v1::PodSpec) -> Vec<String> {
+  let mut claims: Vec<String> = Vec::new();
+  for volume in spec.volumes.iter().flatten() {
+    if let Some(pvc) = &volume.persistent_volume_claim
+      && !claims.contains(&pvc.claim_name)
+    {
+      claims.push(pvc.claim_name.clone());
+    }
+  }
+  claims
+}
+
+/// PersistentVolumeClaim information
+#[derive(Debug, Clone)]
+pub struct VolumeClaimInfo {
+  pub name: String,
+  pub namespace: String,
+  pub age: String,
+  /// Pending, Bound or Lost
+  pub phase: String,
+  /// Bound PersistentVolume
+  pub volume: Option<String>,
+  pub requested: Option<String>,
+  /// Actual size once bound
+  pub capacity: Option<String>,
+  pub access_modes: Vec<String>,
+  pub storage_class: Option<String>,
+  pub volume_mode: String,
+  pub labels: HashMap<String, String>,
+}
+
+impl VolumeClaimInfo {
+  pub fn from_pvc(pvc: &k8s_openapi::api::core::v1::PersistentVolumeClaim) -> Self {
+    let metadata = &pvc.metadata;
+    let spec = pvc.spec.as_ref();
+    let status = pvc.status.as_ref();
+
+    let access_modes = status
+      .and_then(|s| s.access_modes.clone())
+      .or_else(|| spec.and_then(|s| s.access_modes.clone()))
+      .unwrap_or_default();
+
+    Self {
+      name: metadata.name.clone().unwrap_or_default(),
+      namespace: metadata.namespace.clone().unwrap_or_else(|| "default".to_string()),
+      age: metadata
+        .creation_timestamp
+        .as_ref()
+        .map_or_else(|| "Unknown".to_string(), |t| format_age(t.0)),
+      phase: status
+        .and_then(|s| s.phase.clone())
+        .unwrap_or_else(|| "Pending".to_string()),
+      volume: spec.and_then(|s| s.volume_name.clone()).filter(|v| !v.is_empty()),
+      requested: storage_quantity(
+        spec
+          .and_then(|s| s.resources.as_ref())
+          .and_then(|r| r.requests.as_ref()),
+      ),
+      capacity: storage_quantity(status.and_then(|s| s.capacity.as_ref())),
+      access_modes,
+      storage_class: spec.and_then(|s| s.storage_class_name.clone()),
+      volume_mode: spec
+        .and_then(|s| s.volume_mode.clone())
+        .unwrap_or_else(|| "Filesystem".to_string()),
+      labels: metadata.labels.clone().unwrap_or_default().into_iter().collect(),
+    }
+  }
+
+  pub fn is_bound(&self) -> bool {
+    self.phase == "Bound"
+  }
+
+  /// Capacity once bound, otherwise the requested size
+  pub fn size_display(&self) -> String {
+    self
+      .capacity
+      .clone()
+      .or_else(|| self.requested.clone())
+      .unwrap_or_else(|| "-".to_string())
+  }
+
+  /// Whether a pod mounts this claim
+  pub fn is_used_by(&self, pod: &PodInfo) -> bool {
+    pod.namespace == self.namespace && pod.volume_claims.contains(&self.name)
+  }
+}
+
+/// PersistentVolume information
+#[derive(Debug, Clone)]
+pub struct PersistentVolumeInfo {
+  pub name: String,
+  pub age: String,
+  /// Available, Bound, Released or Failed
+  pub phase: String,
+  pub capacity: Option<String>,
+  pub access_modes: Vec<String>,
+  pub reclaim_policy: String,
+  pub storage_class: Option<String>,
+  /// Claim as `(namespace, name)`
+  pub claim: Option<(String, String)>,
+  /// Where the data lives, e.g. "hostPath /var/lib/data" or "csi driver.example.com"
+  pub source: String,
+}
 
-    let age = creation_timestamp.map_or_else(|| "Unknown".to_string(), format_age);
+impl PersistentVolumeInfo {
+  pub fn from_pv(pv: &k8s_openapi::api::core::v1::PersistentVolume) -> Self {
+    let metadata = &pv.metadata;
+    let spec = pv.spec.as_ref();
+
+    let claim = spec
+      .and_then(|s| s.claim_ref.as_ref())
+      .and_then(|c| Some((c.namespace.clone()?, c.name.clone()?)));
+
+    let source = spec
+      .and_then(|s| {
+        s.host_path
+          .as_ref()
+          .map(|h| format!("hostPath {}", h.path))
+          .or_else(|| s.local.as_ref().map(|l| format!("local {}", l.path)))
+          .or_else(|| s.nfs.as_ref().map(|n| format!("nfs {}:{}", n.server, n.path)))
+          .or_else(|| s.csi.as_ref().map(|c| format!("csi {}", c.driver)))
+      })
+      .unwrap_or_else(|| "-".to_string());
 
     Self {
-      name,
-      namespace,
-      replicas,
-      ready_replicas,
-      updated_replicas,
-      available_replicas,
-      age,
+      name: metadata.name.clone().unwrap_or_default(),
+      age: metadata
+        .creation_timestamp
+        .as_ref()
+        .map_or_else(|| "Unknown".to_string(), |t| format_age(t.0)),
+      phase: pv
+        .status
+        .as_ref()
+        .and_then(|s| s.phase.clone())
+        .unwrap_or_else(|| "Pending".to_string()),
+      capacity: storage_quantity(spec.and_then(|s| s.capacity.as_ref())),
+      access_modes: spec.and_then(|s| s.access_modes.clone()).unwrap_or_default(),
+      reclaim_policy: spec
+        .and_then(|s| s.persistent_volume_reclaim_policy.clone())
+        .unwrap_or_else(|| "Retain".to_string()),
+      storage_class: spec.and_then(|s| s.storage_class_name.clone()),
+      claim,
+      source,
+    }
+  }
+
+  /// Claim as `namespace/name`
+  pub fn claim_display(&self) -> String {
+    self
+      .claim
+      .as_ref()
+      .map_or_else(|| "-".to_string(), |(ns, name)| format!("{ns}/{name}"))
+  }
+}
+
+/// StorageClass information
+#[derive(Debug, Clone)]
+pub struct StorageClassInfo {
+  pub name: String,
+  pub age: String,
+  pub provisioner: String,
+  pub reclaim_policy: String,
+  pub binding_mode: String,
+  pub allow_expansion: bool,
+  pub is_default: bool,
+}
+
+impl StorageClassInfo {
+  pub fn from_storage_class(sc: &k8s_openapi::api::storage::v1::StorageClass) -> Self {
+    let metadata = &sc.metadata;
+    let is_default = metadata.annotations.as_ref().is_some_and(|a| {
+      a.get("storageclass.kubernetes.io/is-default-class")
+        .is_some_and(|v| v == "true")
+    });
+
+    Self {
+      name: metadata.name.clone().unwrap_or_default(),
+      age: metadata
+        .creation_timestamp
+        .as_ref()
+        .map_or_else(|| "Unknown".to_string(), |t| format_age(t.0)),
+      provisioner: sc.provisioner.clone(),
+      reclaim_policy: sc.reclaim_policy.clone().unwrap_or_else(|| "Delete".to_string()),
+      binding_mode: sc
+        .volume_binding_mode
+        .clone()
+        .unwrap_or_else(|| "Immediate".to_string()),
+      allow_expansion: sc.allow_volume_expansion.unwrap_or(false),
+      is_default,
+    }
+  }
+}
+
+// ============================================================================
+// Node Types
+// ============================================================================
+
+/// CPU quantity in millicores, e.g. "250m" is 250 and "2" is 2000
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn parse_cpu_millis(quantity: &str) -> Option<u64> {
+  let quantity = quantity.trim();
+  let (number, scale) = if let Some(n) = quantity.strip_suffix('n') {
+    (n, 1e-6)
+  } else if let Some(u) = quantity.strip_suffix('u') {
+    (u, 1e-3)
+  } else if let Some(m) = quantity.strip_suffix('m') {
+    (m, 1.0)
+  } else {
+    (quantity, 1000.0)
+  };
+  let value: f64 = number.parse().ok()?;
+  (value >= 0.0).then(|| (value * scale).round() as u64)
+}
+
+/// Memory quantity in bytes, e.g. "512Mi" or "1G"
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn parse_memory_bytes(quantity: &str) -> Option<u64> {
+  const SUFFIXES: [(&str, f64); 13] = [
+    ("Ki", 1024.0),
+    ("Mi", 1_048_576.0),
+    ("Gi", 1_073_741_824.0),
+    ("Ti", 1_099_511_627_776.0),
+    ("Pi", 1_125_899_906_842_624.0),
+    ("Ei", 1_152_921_504_606_846_976.0),
+    ("k", 1e3),
+    ("M", 1e6),
+    ("G", 1e9),
+    ("T", 1e12),
+    ("P", 1e15),
+    ("E", 1e18),
+    ("m", 1e-3),
+  ];
+
+  let quantity = quantity.trim();
+  let (number, scale) = SUFFIXES
+    .iter()
+    .find_map(|(suffix, scale)| quantity.strip_suffix(suffix).map(|n| (n, *scale)))
+    .unwrap_or((quantity, 1.0));
+  let value: f64 = number.parse().ok()?;
+  (value >= 0.0).then(|| (value * scale).round() as u64)
+}
+
+/// Millicores for display, whole cores when exact ("2" or "250m")
+pub fn format_cpu_millis(millis: u64) -> String {
+  if millis % 1000 == 0 {
+    format!("{}", millis / 1000)
+  } else {
+    format!("{millis}m")
+  }
+}
+
+/// Bytes in binary units, as kubectl prints memory ("512Mi", "3.8Gi")
+#[allow(clippy::cast_precision_loss)]
+pub fn format_memory(bytes: u64) -> String {
+  const KI: u64 = 1024;
+  const MI: u64 = KI * 1024;
+  const GI: u64 = MI * 1024;
+
+  if bytes >= GI {
+    format!("{:.1}Gi", bytes as f64 / GI as f64)
+  } else if bytes >= MI {
+    format!("{}Mi", bytes / MI)
+  } else if bytes >= KI {
+    format!("{}Ki", bytes / KI)
+  } else {
+    bytes.to_string()
+  }
+}
+
+type ResourceMap = std::collections::BTreeMap<String, k8s_openapi::apimachinery::pkg::api::resource::Quantity>;
+
+/// CPU, memory and pod counts from a node's capacity or allocatable
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NodeResources {
+  pub cpu_millis: u64,
+  pub memory_bytes: u64,
+  pub pods: u64,
+}
+
+impl NodeResources {
+  pub fn from_map(map: Option<&ResourceMap>) -> Self {
+    let get = |key: &str| map.and_then(|m| m.get(key)).map(|q| q.0.as_str());
+
+    Self {
+      cpu_millis: get("cpu").and_then(parse_cpu_millis).unwrap_or(0),
+      memory_bytes: get("memory").and_then(parse_memory_bytes).unwrap_or(0),
+      pods: get("pods").and_then(|p| p.parse().ok()).unwrap_or(0),
+    }
+  }
+}
+
+/// Requests and limits, for one pod or summed over a node
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NodeAllocation {
+  pub cpu_requests: u64,
+  pub cpu_limits: u64,
+  pub memory_requests: u64,
+  pub memory_limits: u64,
+}
+
+impl NodeAllocation {
+  /// What the scheduler reserves for a pod: the larger of the summed app
+  /// containers and the biggest init container, plus pod overhead
+  pub fn from_pod_spec(spec: &k8s_openapi::api::core::v1::PodSpec) -> Self {
+    let container = |resources: Option<&k8s_openapi::api::core::v1::ResourceRequirements>| {
+      let requests = resources.and_then(|r| r.requests.as_ref());
+      let limits = resources.and_then(|r| r.limits.as_ref());
+      let cpu = |map: Option<&ResourceMap>| {
+        map
+          .and_then(|m| m.get("cpu"))
+          .and_then(|q| parse_cpu_millis(&q.0))
+          .unwrap_or(0)
+      };
+      let memory = |map: Option<&ResourceMap>| {
+        map
+          .and_then(|m| m.get("memory"))
+          .and_then(|q| parse_memory_bytes(&q.0))
+          .unwrap_or(0)
+      };
+      Self {
+        cpu_requests: cpu(requests),
+        cpu_limits: cpu(limits),
+        memory_requests: memory(requests),
+        memory_limits: memory(limits),
+      }
+    };
+
+    let mut total = Self::default();
+    for c in &spec.containers {
+      total.add(container(c.resources.as_ref()));
+    }
+    for c in spec.init_containers.iter().flatten() {
+      let init = container(c.resources.as_ref());
+      total.cpu_requests = total.cpu_requests.max(init.cpu_requests);
+      total.cpu_limits = total.cpu_limits.max(init.cpu_limits);
+      total.memory_requests = total.memory_requests.max(init.memory_requests);
+      total.memory_limits = total.memory_limits.max(init.memory_limits);
+    }
+    if let Some(overhead) = spec.overhead.as_ref() {
+      let overhead = NodeResources::from_map(Some(overhead));
+      total.cpu_requests += overhead.cpu_millis;
+      total.memory_requests += overhead.memory_bytes;
+    }
+    total
+  }
+
+  pub fn add(&mut self, other: Self) {
+    self.cpu_requests += other.cpu_requests;
+    self.cpu_limits += other.cpu_limits;
+    self.memory_requests += other.memory_requests;
+    self.memory_limits += other.memory_limits;
+  }
+}
+
+/// Node condition, e.g. Ready or MemoryPressure
+#[derive(Debug, Clone)]
+pub struct NodeCondition {
+  pub condition_type: String,
+  pub status: String,
+  pub reason: Option<String>,
+  pub message: Option<String>,
+  /// Time since the last transition
+  pub since: String,
+}
+
+impl NodeCondition {
+  /// Ready should be True; the pressure conditions should be False
+  pub fn is_healthy(&self) -> bool {
+    if self.condition_type == "Ready" {
+      self.status == "True"
+    } else {
+      self.status == "False"
+    }
+  }
+}
+
+/// How draining a node treats one of its pods, following `kubectl drain --ignore-daemonsets`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrainAction {
+  /// DaemonSet pods, static mirror pods and finished pods stay
+  Skip,
+  /// Evicted; its controller recreates it elsewhere
+  Evict,
+  /// No controller recreates it once evicted (kubectl's `--force`)
+  Unmanaged,
+  /// Evicting it deletes its emptyDir data (kubectl's `--delete-emptydir-data`)
+  LocalData,
+}
+
+impl DrainAction {
+  pub fn for_pod(pod: &Pod) -> Self {
+    let controller = pod
+      .metadata
+      .owner_references
+      .iter()
+      .flatten()
+      .find(|o| o.controller == Some(true));
+    let mirror = pod
+      .metadata
+      .annotations
+      .as_ref()
+      .is_some_and(|a| a.contains_key("kubernetes.io/config.mirror"));
+    let finished = pod
+      .status
+      .as_ref()
+      .and_then(|s| s.phase.as_deref())
+      .is_some_and(|p| p == "Succeeded" || p == "Failed");
+    let local_data = pod
+      .spec
+      .as_ref()
+      .and_then(|s| s.volumes.as_ref())
+      .is_some_and(|volumes| volumes.iter().any(|v| v.empty_dir.is_some()));
+
+    if mirror || finished || controller.is_some_and(|c| c.kind == "DaemonSet") {
+      DrainAction::Skip
+    } else if controller.is_none() {
+      DrainAction::Unmanaged
+    } else if local_data {
+      DrainAction::LocalData
+    } else {
+      DrainAction::Evict
+    }
+  }
+
+  /// Why a drain without force refuses to evict the pod
+  pub fn blocker(self) -> Option<&'static str> {
+    match self {
+      DrainAction::Unmanaged => Some("no controller"),
+      DrainAction::LocalData => Some("emptyDir data"),
+      DrainAction::Skip | DrainAction::Evict => None,
+    }
+  }
+}
+
+/// Pod scheduled on a node
+#[derive(Debug, Clone)]
+pub struct NodePod {
+  pub name: String,
+  pub namespace: String,
+  pub phase: PodPhase,
+  pub age: String,
+  pub resources: NodeAllocation,
+  pub drain: DrainAction,
+}
+
+/// Node information
+#[derive(Debug, Clone)]
+pub struct NodeInfo {
+  pub name: String,
+  pub age: String,
+  pub roles: Vec<String>,
+  pub ready: bool,
+  /// Cordoned
+  pub unschedulable: bool,
+  pub conditions: Vec<NodeCondition>,
+  pub capacity: NodeResources,
+  pub allocatable: NodeResources,
+  /// Requests and limits of the pods running on the node
+  pub allocated: NodeAllocation,
+  /// Taints as kubectl prints them, e.g. `node-role.kubernetes.io/control-plane:NoSchedule`
+  pub taints: Vec<String>,
+  pub labels: HashMap<String, String>,
+  pub internal_ip: Option<String>,
+  pub kubelet_version: String,
+  pub os_image: String,
+  pub kernel_version: String,
+  pub container_runtime: String,
+  pub architecture: String,
+  pub pods: Vec<NodePod>,
+}
+
+impl NodeInfo {
+  pub fn from_node(node: &k8s_openapi::api::core::v1::Node) -> Self {
+    let metadata = &node.metadata;
+    let spec = node.spec.as_ref();
+    let status = node.status.as_ref();
+    let labels: HashMap<String, String> = metadata.labels.clone().unwrap_or_default().into_iter().collect();
+
+    let mut roles: Vec<String> = labels
+      .keys()
+      .filter_map(|k| k.strip_prefix("node-role.kubernetes.io/"))
+      .filter(|r| !r.is_empty())
+      .map(ToString::to_string)
+      .chain(labels.get("kubernetes.io/role").cloned())
+      .collect();
+    roles.sort();
+    roles.dedup();
+
+    let conditions: Vec<NodeCondition> = status
+      .and_then(|s| s.conditions.as_ref())
+      .map(|conditions| {
+        conditions
+          .iter()
+          .map(|c| NodeCondition {
+            condition_type: c.type_.clone(),
+            status: c.status.clone(),
+            reason: c.reason.clone(),
+            message: c.message.clone(),
+            since: c
+              .last_transition_time
+              .as_ref()
+              .map_or_else(|| "-".to_string(), |t| format_age(t.0)),
+          })
+          .collect()
+      })
+      .unwrap_or_default();
+    let ready = conditions
+      .iter()
+      .any(|c| c.condition_type == "Ready" && c.status == "True");
+
+    let taints = spec
+      .and_then(|s| s.taints.as_ref())
+      .map(|taints| {
+        taints
+          .iter()
+          .map(|t| match &t.value {
+            Some(value) if !value.is_empty() => format!("{}={value}:{}", t.key, t.effect),
+            _ => format!("{}:{}", t.key, t.effect),
+          })
+          .collect()
+      })
+      .unwrap_or_default();
+
+    let internal_ip = status.and_then(|s| s.addresses.as_ref()).and_then(|addresses| {
+      addresses
+        .iter()
+        .find(|a| a.type_ == "InternalIP")
+        .map(|a| a.address.clone())
+    });
+
+    let info = status.and_then(|s| s.node_info.as_ref());
+    let field = |f: fn(&k8s_openapi::api::core::v1::NodeSystemInfo) -> &String| {
+      info.map_or_else(|| "-".to_string(), |i| f(i).clone())
+    };
+
+    Self {
+      name: metadata.name.clone().unwrap_or_default(),
+      age: metadata
+        .creation_timestamp
+        .as_ref()
+        .map_or_else(|| "Unknown".to_string(), |t| format_age(t.0)),
+      roles,
+      ready,
+      unschedulable: spec.and_then(|s| s.unschedulable).unwrap_or(false),
+      conditions,
+      capacity: NodeResources::from_map(status.and_then(|s| s.capacity.as_ref())),
+      allocatable: NodeResources::from_map(status.and_then(|s| s.allocatable.as_ref())),
+      allocated: NodeAllocation::default(),
+      taints,
       labels,
-      images,
+      internal_ip,
+      kubelet_version: field(|i| &i.kubelet_version),
+      os_image: field(|i| &i.os_image),
+      kernel_version: field(|i| &i.kernel_version),
+      container_runtime: field(|i| &i.container_runtime_version),
+      architecture: field(|i| &i.architecture),
+      pods: Vec::new(),
     }
   }
 
-  /// Get ready status string (e.g., "2/3")
-  pub fn ready_display(&self) -> String {
-    format!("{}/{}", self.ready_replicas, self.replicas)
+  /// Attach a pod scheduled on this node; finished pods don't count toward allocations
+  pub fn add_pod(&mut self, pod: &Pod) {
+    let info = PodInfo::from_pod(pod);
+    let resources = pod.spec.as_ref().map(NodeAllocation::from_pod_spec).unwrap_or_default();
+
+    if !matches!(info.phase, PodPhase::Succeeded | PodPhase::Failed) {
+      self.allocated.add(resources);
+    }
+    self.pods.push(NodePod {
+      name: info.name,
+      namespace: info.namespace,
+      phase: info.phase,
+      age: info.age,
+      resources,
+      drain: DrainAction::for_pod(pod),
+    });
+  }
+
+  /// Status column as kubectl prints it, e.g. "Ready,SchedulingDisabled"
+  pub fn status_display(&self) -> String {
+    let status = if self.ready { "Ready" } else { "NotReady" };
+    if self.unschedulable {
+      format!("{status},SchedulingDisabled")
+    } else {
+      status.to_string()
+    }
+  }
+
+  pub fn roles_display(&self) -> String {
+    if self.roles.is_empty() {
+      "<none>".to_string()
+    } else {
+      self.roles.join(",")
+    }
+  }
+
+  /// Pods that aren't finished
+  pub fn active_pod_count(&self) -> usize {
+    self
+      .pods
+      .iter()
+      .filter(|p| !matches!(p.phase, PodPhase::Succeeded | PodPhase::Failed))
+      .count()
+  }
+
+  /// Pods a drain only evicts when forced, as `namespace/name (reason)`
+  pub fn drain_blockers(&self) -> Vec<String> {
+    self
+      .pods
+      .iter()
+      .filter_map(|p| {
+        let reason = p.drain.blocker()?;
+        Some(format!("{}/{} ({reason})", p.namespace, p.name))
+      })
+      .collect()
   }
 }
 
@@ -398,6 +1922,9 @@ mod tests {
         restart_count: 0,
       }],
       labels: HashMap::from([("app".to_string(), "nginx".to_string())]),
+      annotations: HashMap::new(),
+      config_refs: vec![],
+      volume_claims: vec![],
     };
     assert_eq!(pod.name, "my-pod");
     assert_eq!(pod.namespace, "default");
@@ -457,6 +1984,7 @@ mod tests {
       selector: HashMap::from([("app".to_string(), "nginx".to_string())]),
       age: "1h".to_string(),
       labels: HashMap::new(),
+      annotations: HashMap::new(),
     };
     assert_eq!(svc.ports_display(), "80:30080/TCP, 443:8443/TCP");
   }
@@ -479,6 +2007,7 @@ mod tests {
       selector: HashMap::new(),
       age: "2h".to_string(),
       labels: HashMap::new(),
+      annotations: HashMap::new(),
     };
     assert_eq!(svc.ports_display(), "8080:http/TCP");
   }
@@ -494,7 +2023,9 @@ mod tests {
       available_replicas: 2,
       age: "1d".to_string(),
       labels: HashMap::new(),
+      annotations: HashMap::new(),
       images: vec!["nginx:latest".to_string()],
+      config_refs: vec![],
     };
     assert_eq!(dep.ready_display(), "2/3");
   }
@@ -510,7 +2041,9 @@ mod tests {
       available_replicas: 5,
       age: "2d".to_string(),
       labels: HashMap::from([("app".to_string(), "web".to_string())]),
+      annotations: HashMap::new(),
       images: vec!["app:v1".to_string(), "sidecar:v1".to_string()],
+      config_refs: vec![],
     };
     assert_eq!(dep.ready_display(), "5/5");
     assert_eq!(dep.images.len(), 2);
@@ -539,6 +2072,7 @@ mod tests {
       selector: HashMap::new(),
       age: "1h".to_string(),
       labels: HashMap::new(),
+      annotations: HashMap::new(),
     };
     assert_eq!(svc.ports_display(), "");
   }
@@ -555,7 +2089,9 @@ mod tests {
       available_replicas: 0,
       age: "1h".to_string(),
       labels: HashMap::new(),
+      annotations: HashMap::new(),
       images: vec!["app:v1".to_string()],
+      config_refs: vec![],
     };
     assert_eq!(dep.ready_display(), "0/0");
   }
@@ -606,9 +2142,600 @@ mod tests {
         },
       ],
       labels: HashMap::new(),
+      annotations: HashMap::new(),
+      config_refs: vec![],
+      volume_claims: vec![],
     };
     assert_eq!(pod.containers.len(), 3);
     assert_eq!(pod.restarts, 5);
     assert!(pod.phase.is_running());
   }
+
+  fn workload(kind: WorkloadKind, status: WorkloadStatus) -> WorkloadInfo {
+    WorkloadInfo {
+      kind,
+      name: "web".to_string(),
+      namespace: "default".to_string(),
+      age: "1d".to_string(),
+      labels: HashMap::new(),
+      annotations: HashMap::new(),
+      selector: HashMap::from([("app".to_string(), "web".to_string())]),
+      images: vec![],
+      owner: None,
+      status,
+    }
+  }
+
+  #[test]
+  fn test_workload_kind_labels() {
+    for kind in WorkloadKind::ALL {
+      assert_eq!(WorkloadKind::from_label(kind.label()), Some(kind));
+    }
+    assert_eq!(WorkloadKind::from_label("Deployment"), None);
+    assert!(WorkloadKind::StatefulSet.is_scalable());
+    assert!(!WorkloadKind::DaemonSet.is_scalable());
+    assert!(WorkloadKind::DaemonSet.is_restartable());
+    assert!(!WorkloadKind::CronJob.is_restartable());
+  }
+
+  #[test]
+  fn test_job_completion_status() {
+    use k8s_openapi::api::batch::v1::{Job, JobCondition, JobSpec, JobStatus};
+
+    let job = Job {
+      metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+        name: Some("migrate".to_string()),
+        ..Default::default()
+      },
+      spec: Some(JobSpec {
+        completions: Some(3),
+        ..Default::default()
+      }),
+      status: Some(JobStatus {
+        succeeded: Some(3),
+        conditions: Some(vec![JobCondition {
+          type_: "Complete".to_string(),
+          status: "True".to_string(),
+          ..Default::default()
+        }]),
+        ..Default::default()
+      }),
+    };
+    let info = WorkloadInfo::from_job(&job);
+    assert!(matches!(
+      info.status,
+      WorkloadStatus::Job {
+        state: JobState::Complete,
+        ..
+      }
+    ));
+    assert_eq!(info.status_display(), "3/3 done");
+    assert_eq!(info.health(), WorkloadHealth::Healthy);
+  }
+
+  #[test]
+  fn test_cron_job_suspended() {
+    let info = workload(
+      WorkloadKind::CronJob,
+      WorkloadStatus::CronJob {
+        schedule: "*/5 * * * *".to_string(),
+        suspended: true,
+        active: 0,
+        last_schedule: None,
+        last_successful: None,
+      },
+    );
+    assert!(info.is_suspended());
+    assert_eq!(info.status_display(), "Suspended");
+    assert_eq!(info.health(), WorkloadHealth::Idle);
+    assert_eq!(info.replicas(), None);
+  }
+
+  #[test]
+  fn test_stateful_set_health_and_selector() {
+    let info = workload(
+      WorkloadKind::StatefulSet,
+      WorkloadStatus::StatefulSet {
+        replicas: 3,
+        ready: 1,
+        current: 3,
+        updated: 3,
+      },
+    );
+    assert_eq!(info.status_display(), "1/3");
+    assert_eq!(info.health(), WorkloadHealth::Progressing);
+    assert_eq!(info.replicas(), Some(3));
+
+    let mut pod = PodInfo {
+      name: "web-0".to_string(),
+      namespace: "default".to_string(),
+      phase: PodPhase::Running,
+      ready: "1/1".to_string(),
+      restarts: 0,
+      age: "1d".to_string(),
+      node: None,
+      ip: None,
+      containers: vec![],
+      labels: HashMap::from([("app".to_string(), "web".to_string())]),
+      annotations: HashMap::new(),
+      config_refs: vec![],
+      volume_claims: vec![],
+    };
+    assert!(info.selects(&pod));
+    pod.namespace = "other".to_string();
+    assert!(!info.selects(&pod));
+  }
+
+  #[test]
+  fn test_format_duration() {
+    assert_eq!(format_duration(chrono::Duration::seconds(45)), "45s");
+    assert_eq!(format_duration(chrono::Duration::seconds(192)), "3m12s");
+    assert_eq!(format_duration(chrono::Duration::seconds(7500)), "2h5m");
+  }
+
+  #[test]
+  fn test_config_refs_from_pod_spec() {
+    use k8s_openapi::api::core::v1::{
+      ConfigMapEnvSource, ConfigMapVolumeSource, Container, EnvFromSource, EnvVar, EnvVarSource, PodSpec,
+      ProjectedVolumeSource, SecretKeySelector, SecretProjection, Volume, VolumeProjection,
+    };
+
+    let spec = PodSpec {
+      containers: vec![Container {
+        name: "app".to_string(),
+        env: Some(vec![EnvVar {
+          name: "DB_PASSWORD".to_string(),
+          value_from: Some(EnvVarSource {
+            secret_key_ref: Some(SecretKeySelector {
+              name: "db".to_string(),
+              key: "password".to_string(),
+              ..Default::default()
+            }),
+            ..Default::default()
+          }),
+          ..Default::default()
+        }]),
+        env_from: Some(vec![EnvFromSource {
+          config_map_ref: Some(ConfigMapEnvSource {
+            name: "app-config".to_string(),
+            ..Default::default()
+          }),
+          ..Default::default()
+        }]),
+        ..Default::default()
+      }],
+      volumes: Some(vec![
+        Volume {
+          name: "config".to_string(),
+          config_map: Some(ConfigMapVolumeSource {
+            name: "app-config".to_string(),
+            ..Default::default()
+          }),
+          ..Default::default()
+        },
+        Volume {
+          name: "certs".to_string(),
+          projected: Some(ProjectedVolumeSource {
+            sources: Some(vec![VolumeProjection {
+              secret: Some(SecretProjection {
+                name: "tls".to_string(),
+                ..Default::default()
+              }),
+              ..Default::default()
+            }]),
+            ..Default::default()
+          }),
+          ..Default::default()
+        },
+      ]),
+      ..Default::default()
+    };
+
+    let refs = config_refs(&spec);
+    assert_eq!(refs.len(), 4);
+    assert!(refs.contains(&ConfigRef {
+      kind: ConfigKind::Secret,
+      name: "db".to_string(),
+      usage: ConfigUsage::Env,
+    }));
+    assert!(refs.contains(&ConfigRef {
+      kind: ConfigKind::Secret,
+      name: "tls".to_string(),
+      usage: ConfigUsage::Volume,
+    }));
+
+    let cm = ConfigInfo::from_config_map(&k8s_openapi::api::core::v1::ConfigMap {
+      metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+        name: Some("app-config".to_string()),
+        ..Default::default()
+      },
+      ..Default::default()
+    });
+    assert_eq!(cm.usages(&refs), vec![ConfigUsage::Env, ConfigUsage::Volume]);
+  }
+
+  #[test]
+  fn test_secret_entries_decoded() {
+    use std::collections::BTreeMap;
+
+    let secret = k8s_openapi::api::core::v1::Secret {
+      metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+        name: Some("db".to_string()),
+        namespace: Some("prod".to_string()),
+        ..Default::default()
+      },
+      data: Some(BTreeMap::from([
+        ("password".to_string(), k8s_openapi::ByteString(b"hunter2".to_vec())),
+        ("keystore".to_string(), k8s_openapi::ByteString(vec![0xff, 0xfe])),
+      ])),
+      ..Default::default()
+    };
+
+    let info = ConfigInfo::from_secret(&secret);
+    assert_eq!(info.secret_type.as_deref(), Some("Opaque"));
+    assert_eq!(info.entries[0].key, "keystore");
+    assert!(info.entries[0].binary);
+    assert_eq!(info.entries[0].value, "//4=");
+    assert_eq!(info.entry("password").map(|e| e.value.as_str()), Some("hunter2"));
+  }
+
+  #[test]
+  fn test_parse_literal_pairs() {
+    let pairs = parse_literal_pairs("# comment\nuser=admin\n\nurl=http://x?a=b\n").unwrap();
+    assert_eq!(
+      pairs,
+      vec![
+        ("user".to_string(), "admin".to_string()),
+        ("url".to_string(), "http://x?a=b".to_string()),
+      ]
+    );
+    assert!(parse_literal_pairs("novalue").is_err());
+    assert!(parse_literal_pairs("a=1\na=2").is_err());
+    assert!(parse_literal_pairs("bad key=1").is_err());
+  }
+
+  #[test]
+  fn test_parse_file_sources() {
+    let sources = parse_file_sources("/etc/app/config.yaml\nnginx.conf = ./conf/default.conf\n").unwrap();
+    assert_eq!(sources[0].0, "config.yaml");
+    assert_eq!(sources[1].0, "nginx.conf");
+    assert_eq!(sources[1].1, std::path::PathBuf::from("./conf/default.conf"));
+    assert!(parse_file_sources("a=/x\na=/y").is_err());
+  }
+
+  #[test]
+  fn test_ingress_routes_and_urls() {
+    use k8s_openapi::api::networking::v1::{
+      HTTPIngressPath, HTTPIngressRuleValue, Ingress, IngressBackend, IngressRule, IngressServiceBackend, IngressSpec,
+      IngressTLS, ServiceBackendPort,
+    };
+
+    let backend = |name: &str, port: i32| IngressBackend {
+      service: Some(IngressServiceBackend {
+        name: name.to_string(),
+        port: Some(ServiceBackendPort {
+          number: Some(port),
+          name: None,
+        }),
+      }),
+      resource: None,
+    };
+    let path = |p: &str, svc: &str, port: i32| HTTPIngressPath {
+      path: Some(p.to_string()),
+      path_type: "Prefix".to_string(),
+      backend: backend(svc, port),
+    };
+
+    let ingress = Ingress {
+      metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+        name: Some("web".to_string()),
+        namespace: Some("prod".to_string()),
+        ..Default::default()
+      },
+      spec: Some(IngressSpec {
+        ingress_class_name: Some("nginx".to_string()),
+        rules: Some(vec![
+          IngressRule {
+            host: Some("app.example.com".to_string()),
+            http: Some(HTTPIngressRuleValue {
+              paths: vec![path("/", "frontend", 80), path("/api", "api", 8080)],
+            }),
+          },
+          IngressRule {
+            host: None,
+            http: Some(HTTPIngressRuleValue {
+              paths: vec![path("/health", "api", 8080)],
+            }),
+          },
+        ]),
+        tls: Some(vec![IngressTLS {
+          hosts: Some(vec!["app.example.com".to_string()]),
+          secret_name: Some("web-tls".to_string()),
+        }]),
+        ..Default::default()
+      }),
+      status: None,
+    };
+
+    let info = IngressInfo::from_ingress(&ingress);
+    assert_eq!(info.class.as_deref(), Some("nginx"));
+    assert_eq!(info.routes.len(), 3);
+    assert_eq!(info.routes[1].backend, "api:8080");
+    assert_eq!(info.backend_services(), vec!["frontend".to_string(), "api".to_string()]);
+    assert_eq!(info.hosts_display(), "app.example.com, *");
+    assert_eq!(info.routes[1].url(&[]).as_deref(), Some("https://app.example.com/api"));
+    // No host and no load balancer address yet
+    assert_eq!(info.routes[2].url(&[]), None);
+    assert_eq!(
+      info.routes[2].url(&["10.0.0.5".to_string()]).as_deref(),
+      Some("http://10.0.0.5/health")
+    );
+  }
+
+  #[test]
+  fn test_volume_claim_info() {
+    use std::collections::BTreeMap;
+
+    use k8s_openapi::api::core::v1::{
+      PersistentVolumeClaim, PersistentVolumeClaimSpec, PersistentVolumeClaimStatus, VolumeResourceRequirements,
+    };
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+    let pvc = PersistentVolumeClaim {
+      metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+        name: Some("data".to_string()),
+        namespace: Some("prod".to_string()),
+        ..Default::default()
+      },
+      spec: Some(PersistentVolumeClaimSpec {
+        access_modes: Some(vec!["ReadWriteOnce".to_string()]),
+        resources: Some(VolumeResourceRequirements {
+          requests: Some(BTreeMap::from([("storage".to_string(), Quantity("1Gi".to_string()))])),
+          ..Default::default()
+        }),
+        storage_class_name: Some("standard".to_string()),
+        volume_name: Some("pvc-1234".to_string()),
+        ..Default::default()
+      }),
+      status: Some(PersistentVolumeClaimStatus {
+        phase: Some("Bound".to_string()),
+        capacity: Some(BTreeMap::from([("storage".to_string(), Quantity("2Gi".to_string()))])),
+        ..Default::default()
+      }),
+    };
+
+    let info = VolumeClaimInfo::from_pvc(&pvc);
+    assert!(info.is_bound());
+    assert_eq!(info.volume.as_deref(), Some("pvc-1234"));
+    assert_eq!(info.size_display(), "2Gi");
+    assert_eq!(access_modes_display(&info.access_modes), "RWO");
+
+    let mut pod = PodInfo {
+      name: "db-0".to_string(),
+      namespace: "prod".to_string(),
+      phase: PodPhase::Running,
+      ready: "1/1".to_string(),
+      restarts: 0,
+      age: "1d".to_string(),
+      node: None,
+      ip: None,
+      containers: vec![],
+      labels: HashMap::new(),
+      annotations: HashMap::new(),
+      config_refs: vec![],
+      volume_claims: vec!["data".to_string()],
+    };
+    assert!(info.is_used_by(&pod));
+    pod.namespace = "staging".to_string();
+    assert!(!info.is_used_by(&pod));
+  }
+
+  #[test]
+  fn test_storage_class_default() {
+    use std::collections::BTreeMap;
+
+    let sc = k8s_openapi::api::storage::v1::StorageClass {
+      metadata: k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta {
+        name: Some("standard".to_string()),
+        annotations: Some(BTreeMap::from([(
+          "storageclass.kubernetes.io/is-default-class".to_string(),
+          "true".to_string(),
+        )])),
+        ..Default::default()
+      },
+      provisioner: "rancher.io/local-path".to_string(),
+      ..Default::default()
+    };
+
+    let info = StorageClassInfo::from_storage_class(&sc);
+    assert!(info.is_default);
+    assert_eq!(info.reclaim_policy, "Delete");
+    assert_eq!(info.binding_mode, "Immediate");
+    assert!(!info.allow_expansion);
+  }
+
+  #[test]
+  fn test_parse_quantities() {
+    assert_eq!(parse_cpu_millis("250m"), Some(250));
+    assert_eq!(parse_cpu_millis("2"), Some(2000));
+    assert_eq!(parse_cpu_millis("0.5"), Some(500));
+    assert_eq!(parse_cpu_millis("abc"), None);
+    assert_eq!(parse_memory_bytes("512Mi"), Some(512 * 1024 * 1024));
+    assert_eq!(parse_memory_bytes("1G"), Some(1_000_000_000));
+    assert_eq!(parse_memory_bytes("129e6"), Some(129_000_000));
+    assert_eq!(parse_memory_bytes("1024"), Some(1024));
+    assert_eq!(format_cpu_millis(2000), "2");
+    assert_eq!(format_cpu_millis(250), "250m");
+    assert_eq!(format_memory(512 * 1024 * 1024), "512Mi");
+    assert_eq!(format_memory(3 * 1024 * 1024 * 1024 / 2), "1.5Gi");
+  }
+
+  #[test]
+  fn test_node_info_allocations() {
+    use std::collections::BTreeMap;
+
+    use k8s_openapi::api::core::v1::{
+      Container, Node, NodeCondition as K8sNodeCondition, NodeSpec, NodeStatus, PodSpec, PodStatus,
+      ResourceRequirements, Taint,
+    };
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    let quantities = |cpu: &str, memory: &str| {
+      BTreeMap::from([
+        ("cpu".to_string(), Quantity(cpu.to_string())),
+        ("memory".to_string(), Quantity(memory.to_string())),
+      ])
+    };
+
+    let node = Node {
+      metadata: ObjectMeta {
+        name: Some("colima".to_string()),
+        labels: Some(BTreeMap::from([(
+          "node-role.kubernetes.io/control-plane".to_string(),
+          "true".to_string(),
+        )])),
+        ..Default::default()
+      },
+      spec: Some(NodeSpec {
+        unschedulable: Some(true),
+        taints: Some(vec![Taint {
+          key: "dedicated".to_string(),
+          value: Some("db".to_string()),
+          effect: "NoSchedule".to_string(),
+          ..Default::default()
+        }]),
+        ..Default::default()
+      }),
+      status: Some(NodeStatus {
+        capacity: Some(quantities("4", "8Gi")),
+        allocatable: Some(quantities("3800m", "7Gi")),
+        conditions: Some(vec![K8sNodeCondition {
+          type_: "Ready".to_string(),
+          status: "True".to_string(),
+          ..Default::default()
+        }]),
+        ..Default::default()
+      }),
+    };
+
+    let mut info = NodeInfo::from_node(&node);
+    assert_eq!(info.roles_display(), "control-plane");
+    assert_eq!(info.status_display(), "Ready,SchedulingDisabled");
+    assert_eq!(info.taints, vec!["dedicated=db:NoSchedule".to_string()]);
+    assert_eq!(info.allocatable.cpu_millis, 3800);
+
+    let pod = |name: &str, phase: &str| Pod {
+      metadata: ObjectMeta {
+        name: Some(name.to_string()),
+        namespace: Some("default".to_string()),
+        ..Default::default()
+      },
+      spec: Some(PodSpec {
+        containers: vec![
+          Container {
+            resources: Some(ResourceRequirements {
+              requests: Some(quantities("250m", "256Mi")),
+              limits: Some(quantities("500m", "512Mi")),
+              ..Default::default()
+            }),
+            ..Default::default()
+          },
+          Container {
+            resources: Some(ResourceRequirements {
+              requests: Some(quantities("100m", "64Mi")),
+              ..Default::default()
+            }),
+            ..Default::default()
+          },
+        ],
+        init_containers: Some(vec![Container {
+          resources: Some(ResourceRequirements {
+            requests: Some(quantities("1", "64Mi")),
+            ..Default::default()
+          }),
+          ..Default::default()
+        }]),
+        ..Default::default()
+      }),
+      status: Some(PodStatus {
+        phase: Some(phase.to_string()),
+        ..Default::default()
+      }),
+    };
+
+    info.add_pod(&pod("web", "Running"));
+    info.add_pod(&pod("migrate", "Succeeded"));
+    assert_eq!(info.pods.len(), 2);
+    assert_eq!(info.active_pod_count(), 1);
+    // The init container needs more CPU than the app containers together
+    assert_eq!(info.allocated.cpu_requests, 1000);
+    assert_eq!(info.allocated.cpu_limits, 500);
+    assert_eq!(info.allocated.memory_requests, 320 * 1024 * 1024);
+    // Test pods have no controller
+    assert_eq!(info.drain_blockers(), vec!["default/web (no controller)".to_string()]);
+  }
+
+  #[test]
+  fn test_drain_action() {
+    use k8s_openapi::api::core::v1::{EmptyDirVolumeSource, PodSpec, PodStatus, Volume};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
+
+    let pod = |owner: Option<&str>, empty_dir: bool, phase: &str| Pod {
+      metadata: ObjectMeta {
+        name: Some("web-0".to_string()),
+        namespace: Some("default".to_string()),
+        owner_references: owner.map(|kind| {
+          vec![OwnerReference {
+            kind: kind.to_string(),
+            name: "web".to_string(),
+            controller: Some(true),
+            ..Default::default()
+          }]
+        }),
+        ..Default::default()
+      },
+      spec: Some(PodSpec {
+        volumes: empty_dir.then(|| {
+          vec![Volume {
+            name: "cache".to_string(),
+            empty_dir: Some(EmptyDirVolumeSource::default()),
+            ..Default::default()
+          }]
+        }),
+        ..Default::default()
+      }),
+      status: Some(PodStatus {
+        phase: Some(phase.to_string()),
+        ..Default::default()
+      }),
+    };
+
+    assert_eq!(
+      DrainAction::for_pod(&pod(Some("ReplicaSet"), false, "Running")),
+      DrainAction::Evict
+    );
+    assert_eq!(
+      DrainAction::for_pod(&pod(Some("DaemonSet"), true, "Running")),
+      DrainAction::Skip
+    );
+    assert_eq!(DrainAction::for_pod(&pod(None, false, "Succeeded")), DrainAction::Skip);
+    assert_eq!(
+      DrainAction::for_pod(&pod(None, true, "Running")),
+      DrainAction::Unmanaged
+    );
+    assert_eq!(
+      DrainAction::for_pod(&pod(Some("StatefulSet"), true, "Running")),
+      DrainAction::LocalData
+    );
+    assert_eq!(DrainAction::LocalData.blocker(), Some("emptyDir data"));
+    assert_eq!(DrainAction::Evict.blocker(), None);
+
+    let mut mirror = pod(None, false, "Running");
+    mirror.metadata.annotations = Some(std::collections::BTreeMap::from([(
+      "kubernetes.io/config.mirror".to_string(),
+      "abc".to_string(),
+    )]));
+    assert_eq!(DrainAction::for_pod(&mirror), DrainAction::Skip);
+  }
 }
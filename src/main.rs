@@ -186,6 +186,15 @@ fn main() {
     // Start real-time resource watchers for automatic UI updates
     services::start_watchers(cx);
 
+    // Run scheduled volume backups
+    services::start_backup_scheduler(cx);
+
+    // Run cleanup rules on their schedules and thresholds
+    services::start_cleanup_scheduler(cx);
+
+    // Remove volume helper containers once they go idle
+    services::start_helper_reaper(cx);
+
     // Open the main window
     open_main_window(cx);
 
@@ -5,7 +5,7 @@
 use gpui::{Menu, MenuItem, actions};
 
 use crate::keybindings::{
-  FocusSearch, GoToActivityMonitor, GoToCompose, GoToContainers, GoToDeployments, GoToImages, GoToMachines,
+  FocusSearch, GoToActivityMonitor, GoToCompose, GoToContainers, GoToDeployments, GoToEvents, GoToImages, GoToMachines,
   GoToNetworks, GoToPods, GoToServices, GoToSettings, GoToVolumes, NewResource, OpenCommandPalette, Refresh,
   ShowKeyboardShortcuts,
 };
@@ -68,6 +68,7 @@ pub fn app_menus() -> Vec<Menu> {
         MenuItem::separator(),
         MenuItem::action("Machines", GoToMachines),
         MenuItem::action("Activity Monitor", GoToActivityMonitor),
+        MenuItem::action("Events", GoToEvents),
         MenuItem::separator(),
         MenuItem::action("Keyboard Shortcuts", ShowKeyboardShortcuts),
       ],
@@ -0,0 +1,537 @@
+//! Crash, OOM and restart-loop detection
+//!
+//! Inspects timeline events as they arrive and raises alerts for containers
+//! that exit with a non-zero code, get OOM killed or keep restarting, and for
+//! pods stuck in `CrashLoopBackOff`. Alerts are shown in-app and sent as
+//! desktop notifications with a "View logs" action where the platform allows it.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use gpui::App;
+
+use super::core::{DispatcherEvent, dispatcher};
+use crate::docker::HELPER_LABEL;
+use crate::state::{CurrentView, EventSource, NotificationSettings, TimelineEvent};
+
+/// Events older than this are replays (e.g. the initial Kubernetes list) and never alert
+const MAX_EVENT_AGE_SECS: i64 = 120;
+
+/// A `die` this soon after a `kill` was requested by the user (stop/restart)
+const USER_KILL_GRACE_SECS: i64 = 30;
+
+/// A `die` this soon after an `oom` is the same incident
+const OOM_DIE_GRACE_SECS: i64 = 10;
+
+/// Desktop notifications waiting for a click at once; further alerts are shown in-app only
+const MAX_PENDING_NOTIFICATIONS: usize = 3;
+
+/// How long a desktop notification stays up before it expires
+#[cfg(target_os = "linux")]
+const NOTIFICATION_EXPIRE_MS: u32 = 15_000;
+
+static PENDING_NOTIFICATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// What went wrong
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CrashKind {
+  NonZeroExit { exit_code: String },
+  OomKilled,
+  RestartLoop { exits: usize, minutes: u64 },
+  CrashLoopBackOff,
+}
+
+/// A detected crash for a single resource
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrashAlert {
+  pub kind: CrashKind,
+  pub source: EventSource,
+  pub resource_id: String,
+  pub resource_name: String,
+  pub namespace: Option<String>,
+}
+
+impl CrashAlert {
+  fn display_name(&self) -> String {
+    match &self.namespace {
+      Some(ns) => format!("{ns}/{}", self.resource_name),
+      None => self.resource_name.clone(),
+    }
+  }
+
+  pub fn title(&self) -> String {
+    let what = match self.source {
+      EventSource::Docker => "Container",
+      EventSource::Kubernetes => "Pod",
+    };
+    match &self.kind {
+      CrashKind::NonZeroExit { .. } => format!("{what} exited with an error"),
+      CrashKind::OomKilled => format!("{what} killed (out of memory)"),
+      CrashKind::RestartLoop { .. } => format!("{what} is restarting repeatedly"),
+      CrashKind::CrashLoopBackOff => format!("{what} in CrashLoopBackOff"),
+    }
+  }
+
+  pub fn body(&self) -> String {
+    let name = self.display_name();
+    match &self.kind {
+      CrashKind::NonZeroExit { exit_code } => format!("{name} exited with code {exit_code}"),
+      CrashKind::OomKilled => format!("{name} ran out of memory and was killed"),
+      CrashKind::RestartLoop { exits, minutes } => format!("{name} exited {exits} times in the last {minutes} minutes"),
+      CrashKind::CrashLoopBackOff => format!("{name} keeps failing and is backing off restarts"),
+    }
+  }
+
+  /// Navigate to the logs of the affected resource
+  pub fn open_logs(&self, cx: &mut App) {
+    match self.source {
+      EventSource::Docker => {
+        super::set_view(CurrentView::Containers, cx);
+        super::open_container_logs(self.resource_id.clone(), cx);
+      }
+      EventSource::Kubernetes => {
+        super::set_view(CurrentView::Pods, cx);
+        super::open_pod_logs(
+          self.resource_name.clone(),
+          self.namespace.clone().unwrap_or_default(),
+          cx,
+        );
+      }
+    }
+  }
+}
+
+/// Tracks recent container exits to detect crashes and restart loops
+#[derive(Debug, Default)]
+pub struct CrashDetector {
+  exits: HashMap<String, VecDeque<DateTime<Utc>>>,
+  last_kill: HashMap<String, DateTime<Utc>>,
+  last_oom: HashMap<String, DateTime<Utc>>,
+  /// When a repeating alert (restart loop, back-off) was last raised per resource
+  last_repeat_alert: HashMap<String, DateTime<Utc>>,
+}
+
+impl CrashDetector {
+  /// Feed one timeline event and return an alert if it indicates a crash
+  pub fn process(
+    &mut self,
+    event: &TimelineEvent,
+    settings: &NotificationSettings,
+    now: DateTime<Utc>,
+  ) -> Option<CrashAlert> {
+    if !settings.enabled || now - event.timestamp > Duration::seconds(MAX_EVENT_AGE_SECS) {
+      return None;
+    }
+    if settings.is_muted(&event.resource_name, event.namespace.as_deref()) {
+      return None;
+    }
+    // Diagnostic probes and volume helpers exit non-zero by design
+    if event.attributes.contains_key(HELPER_LABEL) {
+      return None;
+    }
+
+    let minutes = settings.restart_loop_window_minutes.clamp(1, 24 * 60);
+    let window = Duration::minutes(i64::try_from(minutes).unwrap_or(1));
+    let kind = match (event.source, event.kind.as_str()) {
+      (EventSource::Docker, "container") => self.process_container(event, settings.restart_loop_threshold, minutes)?,
+      (EventSource::Kubernetes, "Pod") => self.process_pod(event, window)?,
+      _ => return None,
+    };
+
+    Some(CrashAlert {
+      kind,
+      source: event.source,
+      resource_id: event.resource_id.clone(),
+      resource_name: event.resource_name.clone(),
+      namespace: event.namespace.clone(),
+    })
+  }
+
+  fn process_container(&mut self, event: &TimelineEvent, threshold: usize, minutes: u64) -> Option<CrashKind> {
+    let window = Duration::minutes(i64::try_from(minutes).unwrap_or(1));
+    let id = &event.resource_id;
+    let at = event.timestamp;
+
+    match event.action.as_str() {
+      "kill" => {
+        self.last_kill.insert(id.clone(), at);
+        None
+      }
+      "oom" => {
+        self.last_oom.insert(id.clone(), at);
+        Some(CrashKind::OomKilled)
+      }
+      "die" => {
+        let user_stopped = self
+          .last_kill
+          .remove(id)
+          .is_some_and(|t| at - t <= Duration::seconds(USER_KILL_GRACE_SECS));
+        if user_stopped {
+          return None;
+        }
+
+        let exits = self.exits.entry(id.clone()).or_default();
+        exits.push_back(at);
+        while exits.front().is_some_and(|t| at - *t > window) {
+          exits.pop_front();
+        }
+        let exit_count = exits.len();
+
+        let recently_alerted = self.last_repeat_alert.get(id).is_some_and(|t| at - *t <= window);
+
+        if exit_count >= threshold.max(2) {
+          if recently_alerted {
+            return None;
+          }
+          self.last_repeat_alert.insert(id.clone(), at);
+          return Some(CrashKind::RestartLoop {
+            exits: exit_count,
+            minutes,
+          });
+        }
+
+        let exit_code = event.attributes.get("exitCode").cloned().unwrap_or_default();
+        let after_oom = self
+          .last_oom
+          .get(id)
+          .is_some_and(|t| at - *t <= Duration::seconds(OOM_DIE_GRACE_SECS));
+        if exit_code.is_empty() || exit_code == "0" || after_oom || recently_alerted {
+          return None;
+        }
+        Some(CrashKind::NonZeroExit { exit_code })
+      }
+      "destroy" => {
+        self.exits.remove(id);
+        self.last_kill.remove(id);
+        self.last_oom.remove(id);
+        self.last_repeat_alert.remove(id);
+        None
+      }
+      _ => None,
+    }
+  }
+
+  fn process_pod(&mut self, event: &TimelineEvent, window: Duration) -> Option<CrashKind> {
+    let is_backoff = event.action == "BackOff"
+      && event
+        .message
+        .as_deref()
+        .is_some_and(|m| m.contains("restarting failed container"));
+    if !is_backoff {
+      return None;
+    }
+
+    let key = format!(
+      "{}/{}",
+      event.namespace.as_deref().unwrap_or_default(),
+      event.resource_name
+    );
+    if self
+      .last_repeat_alert
+      .get(&key)
+      .is_some_and(|t| event.timestamp - *t <= window)
+    {
+      return None;
+    }
+    self.last_repeat_alert.insert(key, event.timestamp);
+    Some(CrashKind::CrashLoopBackOff)
+  }
+}
+
+/// Show an alert in-app and as a desktop notification
+pub fn notify_crash(alert: CrashAlert, cx: &mut App) {
+  let disp = dispatcher(cx);
+  disp.update(cx, |_, cx| {
+    cx.emit(DispatcherEvent::Alert {
+      message: format!("{}: {}", alert.title(), alert.body()),
+    });
+  });
+
+  // Waiting for a click can take as long as the notification stays up, so each one gets its
+  // own thread instead of a shared executor thread, and a crash loop can't pile them up
+  if PENDING_NOTIFICATIONS.fetch_add(1, Ordering::SeqCst) >= MAX_PENDING_NOTIFICATIONS {
+    PENDING_NOTIFICATIONS.fetch_sub(1, Ordering::SeqCst);
+    return;
+  }
+
+  let (tx, rx) = futures::channel::oneshot::channel();
+  let title = alert.title();
+  let body = alert.body();
+  let spawned = std::thread::Builder::new()
+    .name("desktop-notification".to_string())
+    .spawn(move || {
+      let _ = tx.send(send_desktop_notification(&title, &body));
+      PENDING_NOTIFICATIONS.fetch_sub(1, Ordering::SeqCst);
+    });
+  if let Err(e) = spawned {
+    PENDING_NOTIFICATIONS.fetch_sub(1, Ordering::SeqCst);
+    tracing::debug!("Failed to start desktop notification thread: {e}");
+    return;
+  }
+
+  cx.spawn(async move |cx| {
+    let clicked = rx.await.unwrap_or(Ok(false));
+
+    match clicked {
+      Ok(true) => {
+        let _ = cx.update(|cx| {
+          cx.activate(true);
+          alert.open_logs(cx);
+        });
+      }
+      Ok(false) => {}
+      Err(e) => tracing::debug!("Desktop notification failed: {e}"),
+    }
+  })
+  .detach();
+}
+
+/// Send a notification through the desktop notification service (D-Bus via `notify-send`).
+/// Blocks until the notification is closed or expires and returns whether "View logs" was clicked.
+#[cfg(target_os = "linux")]
+fn send_desktop_notification(title: &str, body: &str) -> Result<bool> {
+  use std::process::Command;
+
+  let expire = format!("--expire-time={NOTIFICATION_EXPIRE_MS}");
+  let output = Command::new("notify-send")
+    .args([
+      "--app-name=Dockside",
+      "--urgency=normal",
+      expire.as_str(),
+      "--action=logs=View logs",
+      "--wait",
+    ])
+    .arg(title)
+    .arg(body)
+    .output()?;
+
+  if output.status.success() {
+    return Ok(String::from_utf8_lossy(&output.stdout).trim() == "logs");
+  }
+
+  // Older libnotify releases don't support actions; fall back to a plain notification
+  let status = Command::new("notify-send")
+    .args(["--app-name=Dockside", "--urgency=normal", expire.as_str()])
+    .arg(title)
+    .arg(body)
+    .status()?;
+  if !status.success() {
+    anyhow::bail!("notify-send exited with {status}");
+  }
+  Ok(false)
+}
+
+/// Send a notification through Notification Center (no action support)
+#[cfg(target_os = "macos")]
+fn send_desktop_notification(title: &str, body: &str) -> Result<bool> {
+  let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+  let script = format!(
+    "display notification \"{}\" with title \"Dockside\" subtitle \"{}\"",
+    escape(body),
+    escape(title)
+  );
+  let status = std::process::Command::new("osascript").args(["-e", &script]).status()?;
+  if !status.success() {
+    anyhow::bail!("osascript exited with {status}");
+  }
+  Ok(false)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn send_desktop_notification(_title: &str, _body: &str) -> Result<bool> {
+  Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::BTreeMap;
+
+  fn container_event(action: &str, exit_code: Option<&str>, at: DateTime<Utc>) -> TimelineEvent {
+    let mut attributes = BTreeMap::from([("name".to_string(), "web".to_string())]);
+    if let Some(code) = exit_code {
+      attributes.insert("exitCode".to_string(), code.to_string());
+    }
+    TimelineEvent {
+      id: format!("{action}-{}", at.timestamp_nanos_opt().unwrap_or_default()),
+      timestamp: at,
+      source: EventSource::Docker,
+      kind: "container".to_string(),
+      action: action.to_string(),
+      resource_id: "abc123".to_string(),
+      resource_name: "web".to_string(),
+      namespace: None,
+      attributes,
+      message: None,
+      is_warning: false,
+    }
+  }
+
+  fn pod_event(reason: &str, message: &str, at: DateTime<Utc>) -> TimelineEvent {
+    TimelineEvent {
+      id: format!("pod-{}", at.timestamp_nanos_opt().unwrap_or_default()),
+      timestamp: at,
+      source: EventSource::Kubernetes,
+      kind: "Pod".to_string(),
+      action: reason.to_string(),
+      resource_id: "uid-1".to_string(),
+      resource_name: "api-7c9d".to_string(),
+      namespace: Some("default".to_string()),
+      attributes: BTreeMap::new(),
+      message: Some(message.to_string()),
+      is_warning: true,
+    }
+  }
+
+  #[test]
+  fn test_non_zero_exit_alerts() {
+    let mut detector = CrashDetector::default();
+    let settings = NotificationSettings::default();
+    let now = Utc::now();
+
+    let alert = detector
+      .process(&container_event("die", Some("1"), now), &settings, now)
+      .expect("alert");
+    assert_eq!(
+      alert.kind,
+      CrashKind::NonZeroExit {
+        exit_code: "1".to_string()
+      }
+    );
+    assert_eq!(alert.body(), "web exited with code 1");
+
+    assert!(
+      detector
+        .process(&container_event("die", Some("0"), now), &settings, now)
+        .is_none()
+    );
+  }
+
+  #[test]
+  fn test_user_stop_does_not_alert() {
+    let mut detector = CrashDetector::default();
+    let settings = NotificationSettings::default();
+    let now = Utc::now();
+
+    assert!(
+      detector
+        .process(&container_event("kill", None, now), &settings, now)
+        .is_none()
+    );
+    assert!(
+      detector
+        .process(&container_event("die", Some("143"), now), &settings, now)
+        .is_none()
+    );
+  }
+
+  #[test]
+  fn test_oom_alerts_once() {
+    let mut detector = CrashDetector::default();
+    let settings = NotificationSettings::default();
+    let now = Utc::now();
+
+    let alert = detector.process(&container_event("oom", None, now), &settings, now);
+    assert_eq!(alert.map(|a| a.kind), Some(CrashKind::OomKilled));
+    assert!(
+      detector
+        .process(&container_event("die", Some("137"), now), &settings, now)
+        .is_none()
+    );
+  }
+
+  #[test]
+  fn test_restart_loop_detection() {
+    let mut detector = CrashDetector::default();
+    let settings = NotificationSettings::default();
+    let start = Utc::now() - Duration::seconds(60);
+
+    let kinds: Vec<_> = (0..4)
+      .map(|i| {
+        let at = start + Duration::seconds(i * 10);
+        detector
+          .process(&container_event("die", Some("1"), at), &settings, at)
+          .map(|a| a.kind)
+      })
+      .collect();
+
+    assert!(matches!(kinds[0], Some(CrashKind::NonZeroExit { .. })));
+    assert!(matches!(kinds[1], Some(CrashKind::NonZeroExit { .. })));
+    assert_eq!(kinds[2], Some(CrashKind::RestartLoop { exits: 3, minutes: 5 }));
+    // Further exits within the window stay quiet
+    assert_eq!(kinds[3], None);
+  }
+
+  #[test]
+  fn test_restart_loop_reports_clamped_window() {
+    let mut detector = CrashDetector::default();
+    let settings = NotificationSettings {
+      restart_loop_threshold: 2,
+      restart_loop_window_minutes: 0,
+      ..NotificationSettings::default()
+    };
+    let now = Utc::now();
+
+    detector.process(&container_event("die", Some("1"), now), &settings, now);
+    let alert = detector
+      .process(&container_event("die", Some("1"), now), &settings, now)
+      .expect("alert");
+    assert_eq!(alert.kind, CrashKind::RestartLoop { exits: 2, minutes: 1 });
+    assert!(alert.body().ends_with("in the last 1 minutes"));
+  }
+
+  #[test]
+  fn test_crash_loop_back_off() {
+    let mut detector = CrashDetector::default();
+    let settings = NotificationSettings::default();
+    let now = Utc::now();
+
+    let event = pod_event(
+      "BackOff",
+      "Back-off restarting failed container api in pod api-7c9d",
+      now,
+    );
+    let alert = detector.process(&event, &settings, now).expect("alert");
+    assert_eq!(alert.kind, CrashKind::CrashLoopBackOff);
+    assert_eq!(alert.title(), "Pod in CrashLoopBackOff");
+    assert!(detector.process(&event, &settings, now).is_none());
+
+    let pull = pod_event("BackOff", "Back-off pulling image \"missing:latest\"", now);
+    assert!(CrashDetector::default().process(&pull, &settings, now).is_none());
+  }
+
+  #[test]
+  fn test_muted_disabled_and_stale_events_are_ignored() {
+    let now = Utc::now();
+    let event = container_event("die", Some("1"), now);
+
+    let muted = NotificationSettings {
+      muted_resources: vec!["web".to_string()],
+      ..NotificationSettings::default()
+    };
+    assert!(CrashDetector::default().process(&event, &muted, now).is_none());
+
+    let disabled = NotificationSettings {
+      enabled: false,
+      ..NotificationSettings::default()
+    };
+    assert!(CrashDetector::default().process(&event, &disabled, now).is_none());
+
+    let mut helper = container_event("die", Some("1"), now);
+    helper.attributes.insert(HELPER_LABEL.to_string(), "probe".to_string());
+    assert!(
+      CrashDetector::default()
+        .process(&helper, &NotificationSettings::default(), now)
+        .is_none()
+    );
+
+    let later = now + Duration::minutes(10);
+    assert!(
+      CrashDetector::default()
+        .process(&event, &NotificationSettings::default(), later)
+        .is_none()
+    );
+  }
+}
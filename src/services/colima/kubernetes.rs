@@ -5,56 +5,13 @@ use gpui::App;
 use crate::colima::ColimaClient;
 use crate::services::{TaskStage, advance_stage, complete_task, fail_task, start_staged_task, start_task};
 use crate::state::{StateChanged, docker_state};
-use crate::utils::{colima_cmd, kubectl_cmd};
+use crate::utils::colima_cmd;
 
 use super::super::core::{DispatcherEvent, dispatcher};
-use super::super::kubernetes::{refresh_deployments, refresh_namespaces, refresh_pods, refresh_services};
-
-/// Switch kubectl context (async, non-blocking)
-pub fn switch_kubectl_context(context: String, cx: &mut App) {
-  let task_id = start_task(cx, format!("Switching to '{context}'..."));
-  let disp = dispatcher(cx);
-  let ctx = context.clone();
-
-  cx.spawn(async move |cx| {
-    let result = cx
-      .background_executor()
-      .spawn(async move {
-        let output = kubectl_cmd().args(["config", "use-context", &context]).output();
-        match output {
-          Ok(o) if o.status.success() => Ok(()),
-          Ok(o) => Err(String::from_utf8_lossy(&o.stderr).to_string()),
-          Err(e) => Err(e.to_string()),
-        }
-      })
-      .await;
-
-    cx.update(|cx| match result {
-      Ok(()) => {
-        complete_task(cx, task_id);
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskCompleted {
-            message: format!("Switched to context '{ctx}'"),
-          });
-        });
-        // Refresh K8s data with new context
-        refresh_pods(cx);
-        refresh_namespaces(cx);
-        refresh_services(cx);
-        refresh_deployments(cx);
-      }
-      Err(e) => {
-        fail_task(cx, task_id, e.clone());
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed {
-            error: format!("Failed to switch context: {e}"),
-          });
-        });
-      }
-    })
-  })
-  .detach();
-}
+use super::super::kubernetes::{
+  refresh_deployments, refresh_namespaces, refresh_pods, refresh_services, switch_kube_context,
+};
+use super::machines::colima_kube_context;
 
 /// Reset Kubernetes on Colima (async, non-blocking)
 pub fn reset_colima_kubernetes(cx: &mut App) {
@@ -325,24 +282,9 @@ pub fn enable_kubernetes(name: String, cx: &mut App) {
     cx.update(|cx| advance_stage(cx, task_id)).ok();
 
     // Refresh machine list
-    let name_for_context = name.clone();
     let vms = cx
       .background_executor()
-      .spawn(async move {
-        let vms = ColimaClient::list().unwrap_or_default();
-
-        // Switch kubectl context to the colima context for this machine
-        let kubectl_context = if name_for_context == "default" {
-          "colima".to_string()
-        } else {
-          format!("colima-{name_for_context}")
-        };
-
-        // Try to switch kubectl context (don't fail if it doesn't work)
-        let _ = kubectl_cmd().args(["config", "use-context", &kubectl_context]).output();
-
-        vms
-      })
+      .spawn(async move { ColimaClient::list().unwrap_or_default() })
       .await;
 
     cx.update(|cx| {
@@ -356,11 +298,8 @@ pub fn enable_kubernetes(name: String, cx: &mut App) {
           message: format!("Kubernetes enabled on '{name_clone}'"),
         });
       });
-      // Refresh K8s data
-      refresh_pods(cx);
-      refresh_namespaces(cx);
-      refresh_services(cx);
-      refresh_deployments(cx);
+      // Switch to the machine's cluster, which also reloads the K8s data
+      switch_kube_context(colima_kube_context(&name_clone), true, cx);
     })
     .ok();
   })
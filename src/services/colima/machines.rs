@@ -5,11 +5,21 @@ use gpui::App;
 use crate::colima::{ColimaClient, ColimaConfig};
 use crate::services::{TaskStage, advance_stage, complete_task, fail_task, start_staged_task, start_task};
 use crate::state::{StateChanged, docker_state};
-use crate::utils::{docker_cmd, kubectl_cmd};
+use crate::utils::docker_cmd;
 
 use super::super::core::{DispatcherEvent, dispatcher};
 use super::super::docker::refresh_containers;
-use super::super::kubernetes::{refresh_deployments, refresh_namespaces, refresh_pods, refresh_services};
+use super::super::kubernetes::switch_kube_context;
+
+/// Docker and kube context name Colima registers for a profile: "colima" for the
+/// default profile, "colima-<profile>" for others.
+pub(super) fn colima_kube_context(profile: &str) -> String {
+  if profile == "default" {
+    "colima".to_string()
+  } else {
+    format!("colima-{profile}")
+  }
+}
 
 /// Create a new machine using the config file approach
 pub fn create_machine(profile: String, config: ColimaConfig, cx: &mut App) {
@@ -44,18 +54,6 @@ pub fn create_machine(profile: String, config: ColimaConfig, cx: &mut App) {
         match ColimaClient::start_with_config(&profile, &config) {
           Ok(()) => {
             let vms = ColimaClient::list().unwrap_or_default();
-
-            // If kubernetes is enabled, switch kubectl context
-            if has_kubernetes {
-              let kubectl_context = if profile == "default" {
-                "colima".to_string()
-              } else {
-                format!("colima-{profile}")
-              };
-              // Try to switch kubectl context (don't fail if it doesn't work)
-              let _ = kubectl_cmd().args(["config", "use-context", &kubectl_context]).output();
-            }
-
             Ok(vms)
           }
           Err(e) => Err(e.to_string()),
@@ -75,12 +73,9 @@ pub fn create_machine(profile: String, config: ColimaConfig, cx: &mut App) {
             message: format!("Machine '{profile_clone}' created"),
           });
         });
-        // Refresh K8s data if kubernetes was enabled
+        // Switch to the machine's cluster if kubernetes was enabled
         if has_kubernetes {
-          refresh_pods(cx);
-          refresh_namespaces(cx);
-          refresh_services(cx);
-          refresh_deployments(cx);
+          switch_kube_context(colima_kube_context(&profile_clone), true, cx);
         }
       }
       Err(e) => {
@@ -181,25 +176,9 @@ pub fn edit_machine(profile: String, config: ColimaConfig, cx: &mut App) {
     // Stage 3: Verify and refresh list
     cx.update(|cx| advance_stage(cx, task_id)).ok();
 
-    let profile_for_k8s = profile.clone();
     let vms = cx
       .background_executor()
-      .spawn(async move {
-        let vms = ColimaClient::list().unwrap_or_default();
-
-        // If kubernetes was enabled, switch kubectl context
-        if has_kubernetes {
-          let kubectl_context = if profile_for_k8s == "default" {
-            "colima".to_string()
-          } else {
-            format!("colima-{profile_for_k8s}")
-          };
-          // Try to switch kubectl context (don't fail if it doesn't work)
-          let _ = kubectl_cmd().args(["config", "use-context", &kubectl_context]).output();
-        }
-
-        vms
-      })
+      .spawn(async move { ColimaClient::list().unwrap_or_default() })
       .await;
 
     cx.update(|cx| {
@@ -213,12 +192,9 @@ pub fn edit_machine(profile: String, config: ColimaConfig, cx: &mut App) {
           message: format!("Machine '{profile_for_context}' updated successfully"),
         });
       });
-      // Refresh K8s data if kubernetes was enabled
+      // Switch to the machine's cluster if kubernetes was enabled
       if has_kubernetes {
-        refresh_pods(cx);
-        refresh_namespaces(cx);
-        refresh_services(cx);
-        refresh_deployments(cx);
+        switch_kube_context(colima_kube_context(&profile_for_context), true, cx);
       }
     })
     .ok();
@@ -246,17 +222,6 @@ pub fn start_machine(name: String, cx: &mut App) {
             // Check if the started machine has kubernetes enabled
             let has_k8s = vms.iter().any(|vm| vm.name == name && vm.kubernetes);
 
-            // If kubernetes is enabled, switch kubectl context
-            if has_k8s {
-              let kubectl_context = if name == "default" {
-                "colima".to_string()
-              } else {
-                format!("colima-{name}")
-              };
-              // Try to switch kubectl context (don't fail if it doesn't work)
-              let _ = kubectl_cmd().args(["config", "use-context", &kubectl_context]).output();
-            }
-
             Ok((vms, has_k8s))
           }
           Err(e) => Err(e.to_string()),
@@ -276,12 +241,9 @@ pub fn start_machine(name: String, cx: &mut App) {
             message: format!("Machine '{name_clone}' started"),
           });
         });
-        // Refresh K8s data if kubernetes is enabled
+        // Switch to the machine's cluster if kubernetes is enabled
         if has_k8s {
-          refresh_pods(cx);
-          refresh_namespaces(cx);
-          refresh_services(cx);
-          refresh_deployments(cx);
+          switch_kube_context(colima_kube_context(&name_clone), true, cx);
         }
       }
       Err(e) => {
@@ -361,17 +323,6 @@ pub fn restart_machine(name: String, cx: &mut App) {
             // Check if the restarted machine has kubernetes enabled
             let has_k8s = vms.iter().any(|vm| vm.name == name && vm.kubernetes);
 
-            // If kubernetes is enabled, switch kubectl context
-            if has_k8s {
-              let kubectl_context = if name == "default" {
-                "colima".to_string()
-              } else {
-                format!("colima-{name}")
-              };
-              // Try to switch kubectl context (don't fail if it doesn't work)
-              let _ = kubectl_cmd().args(["config", "use-context", &kubectl_context]).output();
-            }
-
             Ok((vms, has_k8s))
           }
           Err(e) => Err(e.to_string()),
@@ -391,12 +342,9 @@ pub fn restart_machine(name: String, cx: &mut App) {
             message: format!("Machine '{name_clone}' restarted"),
           });
         });
-        // Refresh K8s data if kubernetes is enabled
+        // Switch to the machine's cluster if kubernetes is enabled
         if has_k8s {
-          refresh_pods(cx);
-          refresh_namespaces(cx);
-          refresh_services(cx);
-          refresh_deployments(cx);
+          switch_kube_context(colima_kube_context(&name_clone), true, cx);
         }
       }
       Err(e) => {
@@ -506,12 +454,7 @@ pub fn set_default_machine(name: String, has_kubernetes: bool, cx: &mut App) {
     let result = cx
       .background_executor()
       .spawn(async move {
-        // Docker context name for colima is "colima" for default or "colima-<profile>" for others
-        let context_name = if name == "default" {
-          "colima".to_string()
-        } else {
-          format!("colima-{name}")
-        };
+        let context_name = colima_kube_context(&name);
 
         // Switch docker context
         let docker_output = docker_cmd().args(["context", "use", &context_name]).output();
@@ -527,15 +470,6 @@ pub fn set_default_machine(name: String, has_kubernetes: bool, cx: &mut App) {
           Ok(_) => {}
         }
 
-        // If machine has kubernetes, switch kubectl context
-        if has_kubernetes {
-          // kubectl context for colima is "colima" for default or "colima-<profile>" for others
-          let kubectl_context = context_name.clone();
-
-          // k8s context switch is optional - don't fail if kubectl isn't available
-          let _ = kubectl_cmd().args(["config", "use-context", &kubectl_context]).output();
-        }
-
         Ok((context_name, has_kubernetes))
       })
       .await;
@@ -554,13 +488,10 @@ pub fn set_default_machine(name: String, has_kubernetes: bool, cx: &mut App) {
           cx.emit(DispatcherEvent::TaskCompleted { message: msg });
         });
 
-        // Refresh data to reflect new context
+        // Refresh data to reflect new context; the kube context switch restarts the watchers
         refresh_containers(cx);
         if switched_k8s {
-          refresh_pods(cx);
-          refresh_namespaces(cx);
-          refresh_services(cx);
-          refresh_deployments(cx);
+          switch_kube_context(context_name.clone(), true, cx);
         }
 
         // Notify that default machine changed
@@ -17,8 +17,16 @@ pub fn docker_client() -> Arc<RwLock<Option<DockerClient>>> {
 /// Event emitted when a task completes (for UI to show notifications)
 #[derive(Clone, Debug)]
 pub enum DispatcherEvent {
-  TaskCompleted { message: String },
-  TaskFailed { error: String },
+  TaskCompleted {
+    message: String,
+  },
+  TaskFailed {
+    error: String,
+  },
+  /// Something needs attention (e.g. a container crashed)
+  Alert {
+    message: String,
+  },
 }
 
 /// Central action dispatcher - handles all async operations
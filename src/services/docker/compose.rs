@@ -1,81 +1,104 @@
 //! Docker Compose operations
 
+use futures::StreamExt;
+use futures::channel::mpsc::{UnboundedSender, unbounded};
 use gpui::App;
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader};
+use std::process::Stdio;
 
-use crate::services::{complete_task, fail_task, start_task};
+use crate::docker::{ComposeServiceAction, ComposeTarget};
+use crate::services::{complete_task, fail_task, set_task_status, start_task};
 use crate::utils::docker_cmd;
 
 use super::super::core::{DispatcherEvent, dispatcher};
 use super::containers::refresh_containers;
 
-pub fn compose_up(project_name: String, cx: &mut App) {
-  let task_id = start_task(cx, format!("Starting '{project_name}'..."));
-  let disp = dispatcher(cx);
+/// Number of trailing output lines kept for error messages
+const ERROR_TAIL_LINES: usize = 20;
 
-  cx.spawn(async move |cx| {
-    let project = project_name.clone();
-    let result = cx
-      .background_executor()
-      .spawn(async move {
-        let output = docker_cmd().args(["compose", "-p", &project, "up", "-d"]).output();
-
-        match output {
-          Ok(out) if out.status.success() => Ok(()),
-          Ok(out) => Err(String::from_utf8_lossy(&out.stderr).to_string()),
-          Err(e) => Err(e.to_string()),
-        }
-      })
-      .await;
+pub fn compose_up(target: ComposeTarget, cx: &mut App) {
+  let subject = format!("'{}'", target.project);
+  run_compose(
+    target.args(&["up", "-d"]),
+    subject,
+    ("Starting", "Started", "start"),
+    cx,
+  );
+}
 
-    cx.update(|cx| match result {
-      Ok(()) => {
-        complete_task(cx, task_id);
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskCompleted {
-            message: format!("Started '{project_name}'"),
-          });
-        });
-        refresh_containers(cx);
-      }
-      Err(e) => {
-        fail_task(cx, task_id, e.clone());
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed {
-            error: format!("Failed to start '{project_name}': {e}"),
-          });
-        });
-      }
-    })
-  })
-  .detach();
+pub fn compose_down(target: ComposeTarget, cx: &mut App) {
+  let subject = format!("'{}'", target.project);
+  run_compose(target.args(&["down"]), subject, ("Stopping", "Stopped", "stop"), cx);
+}
+
+pub fn compose_restart(target: ComposeTarget, cx: &mut App) {
+  let subject = format!("'{}'", target.project);
+  run_compose(
+    target.args(&["restart"]),
+    subject,
+    ("Restarting", "Restarted", "restart"),
+    cx,
+  );
+}
+
+pub fn compose_pull(target: ComposeTarget, cx: &mut App) {
+  let subject = format!("images for '{}'", target.project);
+  run_compose(target.args(&["pull"]), subject, ("Pulling", "Pulled", "pull"), cx);
+}
+
+pub fn compose_build(target: ComposeTarget, cx: &mut App) {
+  let subject = format!("'{}'", target.project);
+  run_compose(target.args(&["build"]), subject, ("Building", "Built", "build"), cx);
 }
 
-pub fn compose_down(project_name: String, cx: &mut App) {
-  let task_id = start_task(cx, format!("Stopping '{project_name}'..."));
+/// Run an action against one service without touching the rest of the project
+pub fn compose_service_action(target: ComposeTarget, service: String, action: ComposeServiceAction, cx: &mut App) {
+  let subject = match action {
+    ComposeServiceAction::Scale(replicas) => format!("'{service}' to {replicas}"),
+    _ => format!("'{service}'"),
+  };
+  let service_args = action.args(&service);
+  let service_args: Vec<&str> = service_args.iter().map(String::as_str).collect();
+  run_compose(target.args(&service_args), subject, action.verbs(), cx);
+}
+
+/// Apply compose file changes by running `up -d` for just the given services
+pub fn compose_up_services(target: ComposeTarget, services: Vec<String>, cx: &mut App) {
+  let subject = services.iter().map(|s| format!("'{s}'")).collect::<Vec<_>>().join(", ");
+  let mut subcommand = vec!["up", "-d"];
+  subcommand.extend(services.iter().map(String::as_str));
+  run_compose(
+    target.args(&subcommand),
+    subject,
+    ("Applying changes to", "Applied changes to", "apply changes to"),
+    cx,
+  );
+}
+
+/// Run `docker` with the given arguments as a tracked task, streaming output lines into the task status.
+/// `verbs` are the in-progress, completed and failed forms used in messages.
+fn run_compose(args: Vec<String>, subject: String, verbs: (&str, &str, &str), cx: &mut App) {
+  let (pending, done, failed) = verbs;
+  let task_id = start_task(cx, format!("{pending} {subject}..."));
   let disp = dispatcher(cx);
+  let done = format!("{done} {subject}");
+  let failed = format!("Failed to {failed} {subject}");
 
   cx.spawn(async move |cx| {
-    let project = project_name.clone();
-    let result = cx
-      .background_executor()
-      .spawn(async move {
-        let output = docker_cmd().args(["compose", "-p", &project, "down"]).output();
-
-        match output {
-          Ok(out) if out.status.success() => Ok(()),
-          Ok(out) => Err(String::from_utf8_lossy(&out.stderr).to_string()),
-          Err(e) => Err(e.to_string()),
-        }
-      })
-      .await;
+    let (tx, mut rx) = unbounded::<String>();
+    let run = cx.background_executor().spawn(async move { run_streaming(&args, &tx) });
+
+    while let Some(line) = rx.next().await {
+      let _ = cx.update(|cx| set_task_status(cx, task_id, line));
+    }
+    let result = run.await;
 
     cx.update(|cx| match result {
       Ok(()) => {
         complete_task(cx, task_id);
         disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskCompleted {
-            message: format!("Stopped '{project_name}'"),
-          });
+          cx.emit(DispatcherEvent::TaskCompleted { message: done });
         });
         refresh_containers(cx);
       }
@@ -83,53 +106,59 @@ pub fn compose_down(project_name: String, cx: &mut App) {
         fail_task(cx, task_id, e.clone());
         disp.update(cx, |_, cx| {
           cx.emit(DispatcherEvent::TaskFailed {
-            error: format!("Failed to stop '{project_name}': {e}"),
+            error: format!("{failed}: {e}"),
           });
         });
+        refresh_containers(cx);
       }
     })
   })
   .detach();
 }
 
-pub fn compose_restart(project_name: String, cx: &mut App) {
-  let task_id = start_task(cx, format!("Restarting '{project_name}'..."));
-  let disp = dispatcher(cx);
+/// Run a docker command, sending each non-empty output line to `lines`.
+/// On failure the error holds the last lines of output.
+fn run_streaming(args: &[String], lines: &UnboundedSender<String>) -> Result<(), String> {
+  let mut child = docker_cmd()
+    .args(args)
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .map_err(|e| e.to_string())?;
 
-  cx.spawn(async move |cx| {
-    let project = project_name.clone();
-    let result = cx
-      .background_executor()
-      .spawn(async move {
-        let output = docker_cmd().args(["compose", "-p", &project, "restart"]).output();
-
-        match output {
-          Ok(out) if out.status.success() => Ok(()),
-          Ok(out) => Err(String::from_utf8_lossy(&out.stderr).to_string()),
-          Err(e) => Err(e.to_string()),
+  // Compose reports progress on stderr; drain stdout on its own thread so neither pipe can fill up
+  let stdout = child.stdout.take();
+  let stdout_lines = lines.clone();
+  let stdout_reader = std::thread::spawn(move || {
+    if let Some(stdout) = stdout {
+      for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+        if !line.trim().is_empty() {
+          let _ = stdout_lines.unbounded_send(line.trim().to_string());
         }
-      })
-      .await;
+      }
+    }
+  });
 
-    cx.update(|cx| match result {
-      Ok(()) => {
-        complete_task(cx, task_id);
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskCompleted {
-            message: format!("Restarted '{project_name}'"),
-          });
-        });
-        refresh_containers(cx);
+  let mut tail = VecDeque::with_capacity(ERROR_TAIL_LINES);
+  if let Some(stderr) = child.stderr.take() {
+    for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+      let line = line.trim().to_string();
+      if line.is_empty() {
+        continue;
       }
-      Err(e) => {
-        fail_task(cx, task_id, e.clone());
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed {
-            error: format!("Failed to restart '{project_name}': {e}"),
-          });
-        });
+      if tail.len() == ERROR_TAIL_LINES {
+        tail.pop_front();
       }
-    })
-  })
-  .detach();
+      tail.push_back(line.clone());
+      let _ = lines.unbounded_send(line);
+    }
+  }
+
+  let _ = stdout_reader.join();
+  let status = child.wait().map_err(|e| e.to_string())?;
+  if status.success() {
+    Ok(())
+  } else {
+    Err(Vec::from(tail).join("\n"))
+  }
 }
@@ -2,8 +2,8 @@
 
 use gpui::App;
 
-use crate::docker::{ContainerCreateConfig, ContainerFlags};
-use crate::services::{Tokio, complete_task, fail_task, start_task};
+use crate::docker::{ContainerCreateConfig, ContainerFlags, PruneKind};
+use crate::services::{Tokio, complete_task, confirm_protected_delete, fail_task, start_task};
 use crate::state::{StateChanged, docker_state};
 
 use super::super::core::{DispatcherEvent, dispatcher, docker_client};
@@ -146,7 +146,15 @@ pub fn restart_container(id: String, cx: &mut App) {
   .detach();
 }
 
+/// Delete a container, asking for its name to be typed first when it is protected
 pub fn delete_container(id: String, cx: &mut App) {
+  if confirm_protected_delete(PruneKind::Containers, &id, cx) {
+    return;
+  }
+  delete_container_confirmed(id, cx);
+}
+
+pub(crate) fn delete_container_confirmed(id: String, cx: &mut App) {
   let task_id = start_task(cx, "Deleting container...".to_string());
   let disp = dispatcher(cx);
   let client = docker_client();
@@ -2,7 +2,8 @@
 
 use gpui::App;
 
-use crate::services::{Tokio, complete_task, fail_task, start_task};
+use crate::docker::PruneKind;
+use crate::services::{Tokio, complete_task, confirm_protected_delete, fail_task, start_task};
 use crate::state::{ImageInspectData, StateChanged, docker_state};
 
 use super::super::core::{DispatcherEvent, dispatcher, docker_client};
@@ -32,7 +33,15 @@ pub fn refresh_images(cx: &mut App) {
   .detach();
 }
 
+/// Delete a image, asking for its name to be typed first when it is protected
 pub fn delete_image(id: String, cx: &mut App) {
+  if confirm_protected_delete(PruneKind::Images, &id, cx) {
+    return;
+  }
+  delete_image_confirmed(id, cx);
+}
+
+pub(crate) fn delete_image_confirmed(id: String, cx: &mut App) {
   let task_id = start_task(cx, "Deleting image...".to_string());
   let disp = dispatcher(cx);
   let client = docker_client();
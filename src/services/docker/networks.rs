@@ -2,7 +2,8 @@
 
 use gpui::App;
 
-use crate::services::{Tokio, complete_task, fail_task, start_task};
+use crate::docker::{NetworkConnectOptions, NetworkCreateOptions, PruneKind};
+use crate::services::{Tokio, complete_task, confirm_protected_delete, fail_task, start_task};
 use crate::state::{StateChanged, docker_state};
 
 use super::super::core::{DispatcherEvent, dispatcher, docker_client};
@@ -33,8 +34,8 @@ pub fn refresh_networks(cx: &mut App) {
   .detach();
 }
 
-pub fn create_network(name: String, enable_ipv6: bool, subnet: Option<String>, cx: &mut App) {
-  let task_id = start_task(cx, format!("Creating network {name}..."));
+pub fn create_network(options: NetworkCreateOptions, cx: &mut App) {
+  let task_id = start_task(cx, format!("Creating network {}...", options.name));
   let disp = dispatcher(cx);
   let client = docker_client();
 
@@ -43,7 +44,7 @@ pub fn create_network(name: String, enable_ipv6: bool, subnet: Option<String>, c
     let docker = guard
       .as_ref()
       .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
-    docker.create_network(&name, enable_ipv6, subnet.as_deref()).await
+    docker.create_network(&options).await
   });
 
   cx.spawn(async move |cx| {
@@ -75,7 +76,15 @@ pub fn create_network(name: String, enable_ipv6: bool, subnet: Option<String>, c
   .detach();
 }
 
+/// Delete a network, asking for its name to be typed first when it is protected
 pub fn delete_network(id: String, cx: &mut App) {
+  if confirm_protected_delete(PruneKind::Networks, &id, cx) {
+    return;
+  }
+  delete_network_confirmed(id, cx);
+}
+
+pub(crate) fn delete_network_confirmed(id: String, cx: &mut App) {
   let task_id = start_task(cx, "Deleting network...".to_string());
   let disp = dispatcher(cx);
   let client = docker_client();
@@ -116,3 +125,87 @@ pub fn delete_network(id: String, cx: &mut App) {
   })
   .detach();
 }
+
+pub fn connect_network(network: String, container: String, options: NetworkConnectOptions, cx: &mut App) {
+  let task_id = start_task(cx, "Connecting container to network...".to_string());
+  let disp = dispatcher(cx);
+  let client = docker_client();
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let guard = client.read().await;
+    let docker = guard
+      .as_ref()
+      .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+    docker.connect_network(&network, &container, &options).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await;
+    cx.update(|cx| match result {
+      Ok(Ok(())) => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: "Container connected".to_string(),
+          });
+        });
+        refresh_networks(cx);
+      }
+      Ok(Err(e)) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed { error: e.to_string() });
+        });
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed { error: e.to_string() });
+        });
+      }
+    })
+  })
+  .detach();
+}
+
+pub fn disconnect_network(network: String, container: String, cx: &mut App) {
+  let task_id = start_task(cx, "Disconnecting container from network...".to_string());
+  let disp = dispatcher(cx);
+  let client = docker_client();
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let guard = client.read().await;
+    let docker = guard
+      .as_ref()
+      .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+    docker.disconnect_network(&network, &container, false).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await;
+    cx.update(|cx| match result {
+      Ok(Ok(())) => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: "Container disconnected".to_string(),
+          });
+        });
+        refresh_networks(cx);
+      }
+      Ok(Err(e)) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed { error: e.to_string() });
+        });
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed { error: e.to_string() });
+        });
+      }
+    })
+  })
+  .detach();
+}
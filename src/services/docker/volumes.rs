@@ -1,12 +1,25 @@
 //! Volume operations
 
-use gpui::App;
+use chrono::Utc;
+use futures::StreamExt;
+use futures::channel::mpsc::{UnboundedReceiver, unbounded};
+use gpui::{App, AsyncApp, Task};
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::time::Duration;
 
-use crate::services::{Tokio, complete_task, fail_task, start_task};
-use crate::state::{StateChanged, docker_state};
+use crate::docker::{DockerClient, PruneKind, list_volume_backups, prune_volume_backups};
+use crate::services::{Tokio, complete_task, confirm_protected_delete, fail_task, set_task_status, start_task};
+use crate::state::{StateChanged, docker_state, settings_state};
 
 use super::super::core::{DispatcherEvent, dispatcher, docker_client};
 
+/// How often the backup schedule is checked
+const BACKUP_SCHEDULE_CHECK: Duration = Duration::from_secs(60);
+/// How often idle volume helpers are looked for
+const HELPER_REAP_INTERVAL: Duration = Duration::from_secs(30);
+
 pub fn create_volume(name: String, driver: String, labels: Vec<(String, String)>, cx: &mut App) {
   let task_id = start_task(cx, format!("Creating volume {name}..."));
   let disp = dispatcher(cx);
@@ -49,7 +62,15 @@ pub fn create_volume(name: String, driver: String, labels: Vec<(String, String)>
   .detach();
 }
 
+/// Delete a volume, asking for its name to be typed first when it is protected
 pub fn delete_volume(name: String, cx: &mut App) {
+  if confirm_protected_delete(PruneKind::Volumes, &name, cx) {
+    return;
+  }
+  delete_volume_confirmed(name, cx);
+}
+
+pub(crate) fn delete_volume_confirmed(name: String, cx: &mut App) {
   let task_id = start_task(cx, "Deleting volume...".to_string());
   let disp = dispatcher(cx);
   let client = docker_client();
@@ -151,3 +172,459 @@ pub fn list_volume_files(volume_name: String, path: String, cx: &mut App) {
   })
   .detach();
 }
+
+/// Run a write operation on a volume's files as a task, then reload `refresh_dir` in the file explorer.
+/// `op` resolves to the message shown on success.
+fn run_volume_file_task<F, Fut>(description: String, volume: String, refresh_dir: String, cx: &mut App, op: F)
+where
+  F: FnOnce(DockerClient) -> Fut + Send + 'static,
+  Fut: Future<Output = anyhow::Result<String>> + Send + 'static,
+{
+  let task_id = start_task(cx, format!("{description}..."));
+  let disp = dispatcher(cx);
+  let client = docker_client();
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let docker = client
+      .read()
+      .await
+      .clone()
+      .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+    op(docker).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = match tokio_task.await {
+      Ok(result) => result,
+      Err(e) => Err(e.into()),
+    };
+
+    cx.update(|cx| {
+      match result {
+        Ok(message) => {
+          complete_task(cx, task_id);
+          disp.update(cx, |_, cx| {
+            cx.emit(DispatcherEvent::TaskCompleted { message });
+          });
+        }
+        Err(e) => {
+          fail_task(cx, task_id, e.to_string());
+          disp.update(cx, |_, cx| {
+            cx.emit(DispatcherEvent::TaskFailed {
+              error: format!("{description} failed: {e}"),
+            });
+          });
+        }
+      }
+      list_volume_files(volume, refresh_dir, cx);
+    })
+  })
+  .detach();
+}
+
+/// Parent directory of a volume path, as used by the file explorer
+fn parent_dir(path: &str) -> String {
+  match path.trim_end_matches('/').rsplit_once('/') {
+    Some((parent, _)) if !parent.is_empty() => parent.to_string(),
+    _ => "/".to_string(),
+  }
+}
+
+/// Copy a host file or directory into a directory of the volume
+pub fn upload_to_volume(volume: String, source: PathBuf, dest_dir: String, cx: &mut App) {
+  let name = source
+    .file_name()
+    .map(|n| n.to_string_lossy().to_string())
+    .unwrap_or_default();
+  let (vol, dir) = (volume.clone(), dest_dir.clone());
+  run_volume_file_task(
+    format!("Uploading {name} to {volume}:{dest_dir}"),
+    volume,
+    dest_dir,
+    cx,
+    move |docker| async move {
+      docker.upload_to_volume(&vol, &source, &dir).await?;
+      Ok(format!("Uploaded {name} to {vol}:{dir}"))
+    },
+  );
+}
+
+/// Download a file or directory from a volume into the user's downloads directory
+pub fn download_from_volume(volume: String, path: String, cx: &mut App) {
+  let dest = dirs::download_dir()
+    .or_else(dirs::home_dir)
+    .unwrap_or_else(std::env::temp_dir);
+  let (vol, entry) = (volume.clone(), path.clone());
+  run_volume_file_task(
+    format!("Downloading {volume}:{path}"),
+    volume,
+    parent_dir(&path),
+    cx,
+    move |docker| async move {
+      let saved = docker.download_from_volume(&vol, &entry, &dest).await?;
+      Ok(format!("Downloaded {vol}:{entry} to {}", saved.display()))
+    },
+  );
+}
+
+/// Overwrite (or create) a text file in a volume
+pub fn save_volume_file(volume: String, path: String, content: String, cx: &mut App) {
+  let (vol, file) = (volume.clone(), path.clone());
+  run_volume_file_task(
+    format!("Saving {volume}:{path}"),
+    volume,
+    parent_dir(&path),
+    cx,
+    move |docker| async move {
+      docker.write_volume_file(&vol, &file, &content).await?;
+      Ok(format!("Saved {vol}:{file}"))
+    },
+  );
+}
+
+pub fn create_volume_directory(volume: String, path: String, cx: &mut App) {
+  let (vol, dir) = (volume.clone(), path.clone());
+  run_volume_file_task(
+    format!("Creating {volume}:{path}"),
+    volume,
+    parent_dir(&path),
+    cx,
+    move |docker| async move {
+      docker.create_volume_directory(&vol, &dir).await?;
+      Ok(format!("Created folder {vol}:{dir}"))
+    },
+  );
+}
+
+pub fn create_volume_file(volume: String, path: String, cx: &mut App) {
+  let (vol, file) = (volume.clone(), path.clone());
+  run_volume_file_task(
+    format!("Creating {volume}:{path}"),
+    volume,
+    parent_dir(&path),
+    cx,
+    move |docker| async move {
+      docker.create_volume_file(&vol, &file).await?;
+      Ok(format!("Created file {vol}:{file}"))
+    },
+  );
+}
+
+pub fn rename_volume_entry(volume: String, from: String, to: String, cx: &mut App) {
+  let vol = volume.clone();
+  let description = format!("Renaming {volume}:{from} to {to}");
+  run_volume_file_task(description, volume, parent_dir(&from), cx, move |docker| async move {
+    docker.rename_volume_entry(&vol, &from, &to).await?;
+    Ok(format!("Renamed {vol}:{from} to {to}"))
+  });
+}
+
+pub fn delete_volume_entry(volume: String, path: String, cx: &mut App) {
+  let (vol, entry) = (volume.clone(), path.clone());
+  run_volume_file_task(
+    format!("Deleting {volume}:{path}"),
+    volume,
+    parent_dir(&path),
+    cx,
+    move |docker| async move {
+      docker.delete_volume_entry(&vol, &entry).await?;
+      Ok(format!("Deleted {vol}:{entry}"))
+    },
+  );
+}
+
+/// Back up a volume to the configured backup directory
+pub fn backup_volume(name: String, cx: &mut App) {
+  let dir = settings_state(cx).read(cx).settings.backups.backup_dir();
+  run_backup(name, dir, None, cx).detach();
+}
+
+/// Back up a volume and, for scheduled backups, delete those beyond `keep`.
+/// The returned task resolves once the backup has finished.
+fn run_backup(name: String, dir: PathBuf, keep: Option<usize>, cx: &mut App) -> Task<()> {
+  let task_id = start_task(cx, format!("Backing up volume {name}..."));
+  let disp = dispatcher(cx);
+  let state = docker_state(cx);
+  let client = docker_client();
+  let (tx, rx) = unbounded();
+
+  let volume = name.clone();
+  let tokio_task = Tokio::spawn(cx, async move {
+    // Clone the client so a long backup doesn't hold the lock
+    let docker = client
+      .read()
+      .await
+      .clone()
+      .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+    let backup = docker
+      .backup_volume(&volume, &dir, |bytes| {
+        let _ = tx.unbounded_send(bytes);
+      })
+      .await?;
+    if let Some(keep) = keep {
+      prune_volume_backups(&dir, &volume, keep)?;
+    }
+    anyhow::Ok(backup)
+  });
+
+  cx.spawn(async move |cx| {
+    forward_progress(task_id, rx, None, cx).await;
+    let result = match tokio_task.await {
+      Ok(result) => result,
+      Err(e) => Err(e.into()),
+    };
+
+    let _ = cx.update(|cx| {
+      match result {
+        Ok(backup) => {
+          complete_task(cx, task_id);
+          disp.update(cx, |_, cx| {
+            cx.emit(DispatcherEvent::TaskCompleted {
+              message: format!(
+                "Backed up {name} to {} ({}, sha256 {})",
+                backup.path.display(),
+                bytesize::ByteSize(backup.size),
+                &backup.sha256[..12]
+              ),
+            });
+          });
+        }
+        Err(e) => {
+          fail_task(cx, task_id, e.to_string());
+          disp.update(cx, |_, cx| {
+            cx.emit(DispatcherEvent::TaskFailed {
+              error: format!("Failed to back up {name}: {e}"),
+            });
+          });
+        }
+      }
+      state.update(cx, |_, cx| {
+        cx.emit(StateChanged::VolumeBackupsChanged { volume_name: name });
+      });
+    });
+  })
+}
+
+/// Restore a backup archive into a volume, creating the volume if needed
+pub fn restore_volume(archive: PathBuf, volume: String, cx: &mut App) {
+  let file_name = archive
+    .file_name()
+    .map(|n| n.to_string_lossy().to_string())
+    .unwrap_or_default();
+  let task_id = start_task(cx, format!("Restoring {file_name} into {volume}..."));
+  let disp = dispatcher(cx);
+  let client = docker_client();
+  let total = std::fs::metadata(&archive).ok().map(|m| m.len());
+  let (tx, rx) = unbounded();
+
+  let target = volume.clone();
+  let tokio_task = Tokio::spawn(cx, async move {
+    let docker = client
+      .read()
+      .await
+      .clone()
+      .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+    docker
+      .restore_volume(&archive, &target, move |bytes| {
+        let _ = tx.unbounded_send(bytes);
+      })
+      .await
+  });
+
+  cx.spawn(async move |cx| {
+    forward_progress(task_id, rx, total, cx).await;
+    let result = match tokio_task.await {
+      Ok(result) => result,
+      Err(e) => Err(e.into()),
+    };
+
+    cx.update(|cx| match result {
+      Ok(verified) => {
+        complete_task(cx, task_id);
+        let checksum = if verified {
+          "checksum verified"
+        } else {
+          "no checksum to verify"
+        };
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: format!("Restored {file_name} into {volume} ({checksum})"),
+          });
+        });
+        refresh_volumes(cx);
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to restore {file_name} into {volume}: {e}"),
+          });
+        });
+        refresh_volumes(cx);
+      }
+    })
+  })
+  .detach();
+}
+
+/// Copy a volume into a new volume
+pub fn clone_volume(source: String, target: String, cx: &mut App) {
+  let task_id = start_task(cx, format!("Cloning volume {source} to {target}..."));
+  let disp = dispatcher(cx);
+  let client = docker_client();
+  let (tx, rx) = unbounded();
+
+  let (from, to) = (source.clone(), target.clone());
+  let tokio_task = Tokio::spawn(cx, async move {
+    let docker = client
+      .read()
+      .await
+      .clone()
+      .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+    docker
+      .clone_volume(&from, &to, move |bytes| {
+        let _ = tx.unbounded_send(bytes);
+      })
+      .await
+  });
+
+  cx.spawn(async move |cx| {
+    forward_progress(task_id, rx, None, cx).await;
+    let result = match tokio_task.await {
+      Ok(result) => result,
+      Err(e) => Err(e.into()),
+    };
+
+    cx.update(|cx| match result {
+      Ok(()) => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: format!("Cloned volume {source} to {target}"),
+          });
+        });
+        refresh_volumes(cx);
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to clone volume {source}: {e}"),
+          });
+        });
+      }
+    })
+  })
+  .detach();
+}
+
+/// Show transferred bytes (or a percentage when the total is known) in the task status
+/// until the sender is dropped
+async fn forward_progress(task_id: u64, mut rx: UnboundedReceiver<u64>, total: Option<u64>, cx: &mut AsyncApp) {
+  while let Some(bytes) = rx.next().await {
+    let status = match total {
+      Some(total) if total > 0 => format!("{}%", bytes.saturating_mul(100) / total),
+      _ => format!("{} transferred", bytesize::ByteSize(bytes)),
+    };
+    let _ = cx.update(|cx| set_task_status(cx, task_id, status));
+  }
+}
+
+/// Periodically apply helper settings and remove volume helpers that have gone idle
+pub fn start_helper_reaper(cx: &mut App) {
+  let client = docker_client();
+
+  cx.spawn(async move |cx| {
+    loop {
+      cx.background_executor().timer(HELPER_REAP_INTERVAL).await;
+
+      let Ok(config) = cx.update(|cx| settings_state(cx).read(cx).settings.volume_helper.helper_config()) else {
+        return;
+      };
+
+      let client = client.clone();
+      let Ok(task) = cx.update(|cx| {
+        Tokio::spawn(cx, async move {
+          let docker = client.read().await.clone()?;
+          docker.configure_helpers(config);
+          Some(docker.reap_idle_helpers().await)
+        })
+      }) else {
+        return;
+      };
+      if let Ok(Some(removed)) = task.await
+        && removed > 0
+      {
+        tracing::debug!("Removed {removed} idle volume helpers");
+      }
+    }
+  })
+  .detach();
+}
+
+/// Back up scheduled volumes whenever their newest backup is older than the configured interval
+pub fn start_backup_scheduler(cx: &mut App) {
+  cx.spawn(async move |cx| {
+    // Last attempt per volume, so a failing backup isn't retried every check
+    let mut attempts = HashMap::new();
+
+    loop {
+      cx.background_executor().timer(BACKUP_SCHEDULE_CHECK).await;
+
+      let Ok((settings, existing)) = cx.update(|cx| {
+        let existing: Vec<String> = docker_state(cx)
+          .read(cx)
+          .volumes
+          .iter()
+          .map(|v| v.name.clone())
+          .collect();
+        (settings_state(cx).read(cx).settings.backups.clone(), existing)
+      }) else {
+        return;
+      };
+
+      let volumes: Vec<String> = settings
+        .scheduled_volumes
+        .iter()
+        .filter(|v| existing.contains(v))
+        .cloned()
+        .collect();
+      if volumes.is_empty() {
+        continue;
+      }
+
+      let dir = settings.backup_dir();
+      let interval = chrono::Duration::hours(i64::try_from(settings.interval_hours.clamp(1, 24 * 365)).unwrap_or(24));
+      let scan_dir = dir.clone();
+      let last_backups: Vec<(String, Option<chrono::DateTime<Utc>>)> = cx
+        .background_executor()
+        .spawn(async move {
+          volumes
+            .into_iter()
+            .map(|v| {
+              let last = list_volume_backups(&scan_dir, &v).first().map(|b| b.created);
+              (v, last)
+            })
+            .collect()
+        })
+        .await;
+
+      let now = Utc::now();
+      for (volume, last_backup) in last_backups {
+        let last = last_backup.max(attempts.get(&volume).copied());
+        if last.is_some_and(|t| now - t < interval) {
+          continue;
+        }
+        attempts.insert(volume.clone(), now);
+
+        // One backup at a time keeps the load on the engine predictable
+        let keep = Some(settings.keep_last.max(1));
+        let Ok(task) = cx.update(|cx| run_backup(volume, dir.clone(), keep, cx)) else {
+          return;
+        };
+        task.await;
+      }
+    }
+  })
+  .detach();
+}
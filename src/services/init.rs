@@ -17,6 +17,7 @@ pub fn load_initial_data(cx: &mut App) {
   let settings = settings_state(cx).read(cx).settings.clone();
   let custom_socket = settings.docker_socket.clone();
   let colima_profile = settings.default_colima_profile.clone();
+  let helper_config = settings.volume_helper.helper_config();
 
   // First, get colima VMs and socket path (sync operation)
   let colima_task = cx.background_executor().spawn(async move {
@@ -43,6 +44,7 @@ pub fn load_initial_data(cx: &mut App) {
 
     // Initialize the shared Docker client
     let mut new_client = DockerClient::new(socket_path);
+    new_client.configure_helpers(helper_config);
     let docker_connected = new_client.connect().await.is_ok();
 
     // Store in the global if connected
@@ -55,6 +57,13 @@ pub fn load_initial_data(cx: &mut App) {
       let guard = client_handle.read().await;
       let docker = guard.as_ref().unwrap();
 
+      // Helpers left behind by a previous run (e.g. after a crash)
+      if let Ok(removed) = docker.cleanup_helpers().await
+        && removed > 0
+      {
+        tracing::info!("Removed {removed} leftover helper containers");
+      }
+
       let containers = docker.list_containers(true).await.unwrap_or_default();
       let images = docker.list_images(false).await.unwrap_or_default();
       let volumes = docker.list_volumes().await.unwrap_or_default();
@@ -0,0 +1,243 @@
+//! Kubernetes ConfigMap and Secret operations
+
+use std::path::PathBuf;
+
+use gpui::App;
+
+use crate::kubernetes::{ConfigKind, KubeClient};
+use crate::services::{Tokio, complete_task, fail_task, start_task};
+use crate::state::{LoadState, StateChanged, docker_state};
+
+use super::super::core::{DispatcherEvent, dispatcher};
+use super::deployments::refresh_deployments;
+use super::pods::refresh_pods;
+
+/// Refresh the list of ConfigMaps or Secrets
+pub fn refresh_configs(kind: ConfigKind, cx: &mut App) {
+  let state = docker_state(cx);
+
+  // Only show loading state on initial load, not on background refreshes
+  if state.read(cx).configs_state(kind) == LoadState::NotLoaded {
+    state.update(cx, |state, _cx| {
+      state.set_configs_loading(kind);
+    });
+  }
+
+  let selected_ns = state.read(cx).selected_namespace.clone();
+  let namespace = if selected_ns == "all" { None } else { Some(selected_ns) };
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = KubeClient::new().await?;
+    client.list_configs(kind, namespace.as_deref()).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+
+    cx.update(|cx| {
+      state.update(cx, |state, cx| {
+        match result {
+          Ok(configs) => state.set_configs(kind, configs),
+          Err(e) => state.set_configs_error(kind, e.to_string()),
+        }
+        cx.emit(StateChanged::ConfigsUpdated { kind });
+      });
+    })
+  })
+  .detach();
+}
+
+/// Refresh ConfigMaps and Secrets
+pub fn refresh_all_configs(cx: &mut App) {
+  for kind in ConfigKind::ALL {
+    refresh_configs(kind, cx);
+  }
+}
+
+/// Run a ConfigMap or Secret operation as a task, then refresh the list
+fn run_config_task<F, Fut>(kind: ConfigKind, name: String, running: String, failed: &'static str, cx: &mut App, f: F)
+where
+  F: FnOnce(KubeClient) -> Fut + Send + 'static,
+  Fut: std::future::Future<Output = anyhow::Result<String>> + Send + 'static,
+{
+  let task_id = start_task(cx, running);
+  let disp = dispatcher(cx);
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = KubeClient::new().await?;
+    f(client).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+
+    cx.update(|cx| match result {
+      Ok(msg) => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted { message: msg });
+        });
+        refresh_configs(kind, cx);
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to {failed} '{name}': {e}"),
+          });
+        });
+      }
+    })
+  })
+  .detach();
+}
+
+/// Delete a ConfigMap or Secret
+pub fn delete_config(kind: ConfigKind, name: String, namespace: String, cx: &mut App) {
+  let running = format!("Deleting {} '{name}'...", kind.label().to_lowercase());
+  let n = name.clone();
+  run_config_task(kind, name, running, "delete", cx, move |client| async move {
+    client.delete_config(kind, &n, &namespace).await?;
+    Ok(format!("{} '{n}' deleted", kind.label()))
+  });
+}
+
+/// Add or replace one key of a ConfigMap or Secret
+pub fn set_config_entry(kind: ConfigKind, name: String, namespace: String, key: String, value: String, cx: &mut App) {
+  let running = format!("Saving '{key}' in '{name}'...");
+  let n = name.clone();
+  run_config_task(kind, name, running, "update", cx, move |client| async move {
+    client
+      .set_config_entry(kind, &n, &namespace, &key, value.into_bytes())
+      .await
+  });
+}
+
+/// Remove one key of a ConfigMap or Secret
+pub fn remove_config_entry(kind: ConfigKind, name: String, namespace: String, key: String, cx: &mut App) {
+  let running = format!("Removing '{key}' from '{name}'...");
+  let n = name.clone();
+  run_config_task(kind, name, running, "update", cx, move |client| async move {
+    client.remove_config_entry(kind, &n, &namespace, &key).await
+  });
+}
+
+/// Create a ConfigMap or Secret from literal pairs and files, read as raw bytes
+pub fn create_config(
+  kind: ConfigKind,
+  name: String,
+  namespace: String,
+  literals: Vec<(String, String)>,
+  files: Vec<(String, PathBuf)>,
+  cx: &mut App,
+) {
+  let running = format!("Creating {} '{name}'...", kind.label().to_lowercase());
+  let n = name.clone();
+  run_config_task(kind, name, running, "create", cx, move |client| async move {
+    let mut entries: Vec<(String, Vec<u8>)> = literals.into_iter().map(|(k, v)| (k, v.into_bytes())).collect();
+    for (key, path) in files {
+      if entries.iter().any(|(k, _)| *k == key) {
+        anyhow::bail!("Duplicate key '{key}'");
+      }
+      let path = crate::kubernetes::expand_home(&path);
+      let bytes = tokio::fs::read(&path)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {e}", path.display()))?;
+      entries.push((key, bytes));
+    }
+    client.create_config(kind, &n, &namespace, entries).await
+  });
+}
+
+/// Rollout restart every deployment whose pod template consumes a ConfigMap or Secret
+pub fn restart_config_consumers(kind: ConfigKind, name: String, namespace: String, cx: &mut App) {
+  let state = docker_state(cx);
+  let disp = dispatcher(cx);
+
+  let deployments: Vec<(String, String)> = {
+    let state = state.read(cx);
+    let Some(config) = state.get_config(kind, &name, &namespace) else {
+      return;
+    };
+    state
+      .deployments
+      .iter()
+      .filter(|d| d.namespace == namespace && !config.usages(&d.config_refs).is_empty())
+      .map(|d| (d.name.clone(), d.namespace.clone()))
+      .collect()
+  };
+
+  if deployments.is_empty() {
+    disp.update(cx, |_, cx| {
+      cx.emit(DispatcherEvent::TaskFailed {
+        error: format!("No deployments consume {} '{name}'", kind.label().to_lowercase()),
+      });
+    });
+    return;
+  }
+
+  let task_id = start_task(cx, format!("Restarting consumers of '{name}'..."));
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = KubeClient::new().await?;
+    client.restart_deployments(&deployments).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+
+    cx.update(|cx| match result {
+      Ok(count) => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: format!("Restarted {count} deployment(s) using '{name}'"),
+          });
+        });
+        refresh_deployments(cx);
+        refresh_pods(cx);
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to restart consumers of '{name}': {e}"),
+          });
+        });
+      }
+    })
+  })
+  .detach();
+}
+
+/// Get ConfigMap or Secret YAML
+pub fn get_config_yaml(kind: ConfigKind, name: String, namespace: String, cx: &mut App) {
+  let state = docker_state(cx);
+  let name_clone = name.clone();
+  let namespace_clone = namespace.clone();
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = KubeClient::new().await?;
+    client.get_config_yaml(kind, &name, &namespace).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+    let yaml = match result {
+      Ok(y) => y,
+      Err(e) => format!("Error: {e}"),
+    };
+
+    cx.update(|cx| {
+      state.update(cx, |_state, cx| {
+        cx.emit(StateChanged::ConfigYamlLoaded {
+          kind,
+          name: name_clone,
+          namespace: namespace_clone,
+          yaml,
+        });
+      });
+    })
+  })
+  .detach();
+}
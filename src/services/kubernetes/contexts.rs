@@ -0,0 +1,179 @@
+//! Kubeconfig files and context switching
+
+use std::path::{Path, PathBuf};
+
+use gpui::App;
+
+use crate::kubernetes::{expand_home, load_kubeconfig, set_kubeconfig_selection, write_current_context};
+use crate::services::{complete_task, fail_task, restart_kubernetes_watchers, start_task};
+use crate::state::{SettingsChanged, StateChanged, docker_state, settings_state};
+
+use super::super::core::{DispatcherEvent, dispatcher};
+use super::{
+  refresh_all_configs, refresh_all_workloads, refresh_deployments, refresh_ingresses, refresh_namespaces,
+  refresh_nodes, refresh_pods, refresh_services, refresh_storage,
+};
+
+/// Apply the saved kubeconfig files and context so new clients use them
+pub fn init_kubeconfig(cx: &App) {
+  let selection = settings_state(cx).read(cx).settings.kubernetes.selection();
+  set_kubeconfig_selection(selection);
+}
+
+/// Reload the merged kubeconfig shown in the context manager
+pub fn refresh_kubeconfig(cx: &mut App) {
+  let state = docker_state(cx);
+  let selection = settings_state(cx).read(cx).settings.kubernetes.selection();
+
+  cx.spawn(async move |cx| {
+    let (_, overview) = cx
+      .background_executor()
+      .spawn(async move { load_kubeconfig(&selection) })
+      .await;
+
+    cx.update(|cx| {
+      state.update(cx, |state, cx| {
+        state.set_kubeconfig(overview);
+        cx.emit(StateChanged::KubeconfigUpdated);
+      });
+    })
+  })
+  .detach();
+}
+
+/// Switch the Kubernetes context
+///
+/// With `write_kubeconfig` the context also becomes `current-context` on disk (what
+/// `kubectl config use-context` does). Otherwise only the app talks to it.
+pub fn switch_kube_context(context: String, write_kubeconfig: bool, cx: &mut App) {
+  let task_id = start_task(cx, format!("Switching to '{context}'..."));
+  let disp = dispatcher(cx);
+  let selection = settings_state(cx).read(cx).settings.kubernetes.selection();
+
+  cx.spawn(async move |cx| {
+    let ctx = context.clone();
+    let result = cx
+      .background_executor()
+      .spawn(async move {
+        let (_, overview) = load_kubeconfig(&selection);
+        let Some(info) = overview.context(&ctx).cloned() else {
+          return Err(anyhow::anyhow!("Context '{ctx}' not found in kubeconfig"));
+        };
+        if write_kubeconfig {
+          write_current_context(&selection, &ctx)?;
+        }
+        Ok(info.namespace)
+      })
+      .await;
+
+    cx.update(|cx| match result {
+      Ok(namespace) => {
+        // A context written to the kubeconfig is followed like kubectl would, so drop the override
+        let in_app = if write_kubeconfig {
+          String::new()
+        } else {
+          context.clone()
+        };
+        settings_state(cx).update(cx, |state, cx| {
+          state.settings.kubernetes.context = in_app;
+          let _ = state.settings.save();
+          cx.emit(SettingsChanged::SettingsUpdated);
+        });
+        docker_state(cx).update(cx, |state, _cx| {
+          state.set_selected_namespace(namespace.unwrap_or_else(|| "default".to_string()));
+        });
+
+        reload_kubernetes(cx);
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: format!("Switched to context '{context}'"),
+          });
+        });
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to switch context: {e}"),
+          });
+        });
+      }
+    })
+  })
+  .detach();
+}
+
+/// Stop overriding the context in-app and follow the kubeconfig's `current-context` again
+pub fn follow_current_context(cx: &mut App) {
+  settings_state(cx).update(cx, |state, cx| {
+    state.settings.kubernetes.context.clear();
+    let _ = state.settings.save();
+    cx.emit(SettingsChanged::SettingsUpdated);
+  });
+  reload_kubernetes(cx);
+}
+
+/// Add a kubeconfig file to merge after the `KUBECONFIG` ones
+pub fn add_kubeconfig_file(path: String, cx: &mut App) {
+  let path = path.trim().to_string();
+  if path.is_empty() {
+    return;
+  }
+
+  let added = settings_state(cx).update(cx, |state, cx| {
+    let files = &mut state.settings.kubernetes.kubeconfig_files;
+    if files.contains(&path) {
+      return false;
+    }
+    files.push(path);
+    let _ = state.settings.save();
+    cx.emit(SettingsChanged::SettingsUpdated);
+    true
+  });
+  if added {
+    reload_kubernetes(cx);
+  }
+}
+
+/// Stop merging a kubeconfig file; an in-app context that came from it is dropped too
+pub fn remove_kubeconfig_file(path: PathBuf, cx: &mut App) {
+  let context_source = {
+    let state = docker_state(cx);
+    let state = state.read(cx);
+    state.kubeconfig.active().map(|c| c.source.clone())
+  };
+
+  settings_state(cx).update(cx, |state, cx| {
+    let kubernetes = &mut state.settings.kubernetes;
+    // Settings keep paths as typed, so compare them expanded
+    kubernetes
+      .kubeconfig_files
+      .retain(|f| expand_home(Path::new(f)) != path);
+    if context_source.is_some_and(|source| source == path) {
+      kubernetes.context.clear();
+    }
+    let _ = state.settings.save();
+    cx.emit(SettingsChanged::SettingsUpdated);
+  });
+  reload_kubernetes(cx);
+}
+
+/// Rebuild clients and watchers against the current selection and reload all Kubernetes data
+fn reload_kubernetes(cx: &mut App) {
+  init_kubeconfig(cx);
+  restart_kubernetes_watchers(cx);
+  refresh_kubeconfig(cx);
+  docker_state(cx).update(cx, |_state, cx| {
+    cx.emit(StateChanged::NamespacesUpdated);
+  });
+  refresh_namespaces(cx);
+  refresh_pods(cx);
+  refresh_services(cx);
+  refresh_deployments(cx);
+  refresh_all_workloads(cx);
+  refresh_all_configs(cx);
+  refresh_ingresses(cx);
+  refresh_storage(cx);
+  refresh_nodes(cx);
+}
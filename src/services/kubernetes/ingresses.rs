@@ -0,0 +1,112 @@
+//! Kubernetes ingress operations
+
+use gpui::App;
+
+use crate::kubernetes::KubeClient;
+use crate::services::{Tokio, complete_task, fail_task, start_task};
+use crate::state::{LoadState, StateChanged, docker_state};
+
+use super::super::core::{DispatcherEvent, dispatcher};
+
+/// Refresh ingresses list
+pub fn refresh_ingresses(cx: &mut App) {
+  let state = docker_state(cx);
+
+  // Only show loading state on initial load, not on background refreshes
+  if state.read(cx).ingresses_state == LoadState::NotLoaded {
+    state.update(cx, |state, _cx| {
+      state.set_ingresses_loading();
+    });
+  }
+
+  let selected_ns = state.read(cx).selected_namespace.clone();
+  let namespace = if selected_ns == "all" { None } else { Some(selected_ns) };
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = KubeClient::new().await?;
+    client.list_ingresses(namespace.as_deref()).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+
+    cx.update(|cx| {
+      state.update(cx, |state, cx| {
+        match result {
+          Ok(ingresses) => state.set_ingresses(ingresses),
+          Err(e) => state.set_ingresses_error(e.to_string()),
+        }
+        cx.emit(StateChanged::IngressesUpdated);
+      });
+    })
+  })
+  .detach();
+}
+
+/// Delete an ingress
+pub fn delete_ingress(name: String, namespace: String, cx: &mut App) {
+  let task_id = start_task(cx, format!("Deleting ingress '{name}'..."));
+  let name_clone = name.clone();
+  let disp = dispatcher(cx);
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = KubeClient::new().await?;
+    client.delete_ingress(&name, &namespace).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+
+    cx.update(|cx| match result {
+      Ok(()) => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: format!("Ingress '{name_clone}' deleted"),
+          });
+        });
+        refresh_ingresses(cx);
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to delete ingress '{name_clone}': {e}"),
+          });
+        });
+      }
+    })
+  })
+  .detach();
+}
+
+/// Get ingress YAML
+pub fn get_ingress_yaml(name: String, namespace: String, cx: &mut App) {
+  let state = docker_state(cx);
+  let name_clone = name.clone();
+  let namespace_clone = namespace.clone();
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = KubeClient::new().await?;
+    client.get_ingress_yaml(&name, &namespace).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+    let yaml = match result {
+      Ok(y) => y,
+      Err(e) => format!("Error: {e}"),
+    };
+
+    cx.update(|cx| {
+      state.update(cx, |_state, cx| {
+        cx.emit(StateChanged::IngressYamlLoaded {
+          name: name_clone,
+          namespace: namespace_clone,
+          yaml,
+        });
+      });
+    })
+  })
+  .detach();
+}
@@ -1,9 +1,22 @@
-//! Kubernetes resource operations (pods, services, deployments)
+//! Kubernetes resource operations (pods, services, deployments, workloads, configs, ingresses, storage, nodes) and
+//! kubeconfig contexts
 
+pub mod configs;
+pub mod contexts;
 pub mod deployments;
+pub mod ingresses;
+pub mod nodes;
 pub mod pods;
 pub mod services;
+pub mod storage;
+pub mod workloads;
 
+pub use configs::*;
+pub use contexts::*;
 pub use deployments::*;
+pub use ingresses::*;
+pub use nodes::*;
 pub use pods::*;
 pub use services::*;
+pub use storage::*;
+pub use workloads::*;
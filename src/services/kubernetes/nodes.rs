@@ -0,0 +1,166 @@
+//! Kubernetes node operations
+
+use futures::StreamExt;
+use futures::channel::mpsc::unbounded;
+use gpui::App;
+
+use crate::kubernetes::KubeClient;
+use crate::services::{Tokio, complete_task, fail_task, set_task_status, start_task};
+use crate::state::{LoadState, StateChanged, docker_state};
+
+use super::super::core::{DispatcherEvent, dispatcher};
+
+/// Refresh nodes along with the pods scheduled on them
+pub fn refresh_nodes(cx: &mut App) {
+  let state = docker_state(cx);
+
+  // Only show loading state on initial load, not on background refreshes
+  if state.read(cx).nodes_state == LoadState::NotLoaded {
+    state.update(cx, |state, _cx| {
+      state.set_nodes_loading();
+    });
+  }
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = KubeClient::new().await?;
+    client.list_nodes().await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+
+    cx.update(|cx| {
+      state.update(cx, |state, cx| {
+        match result {
+          Ok(nodes) => state.set_nodes(nodes),
+          Err(e) => state.set_nodes_error(e.to_string()),
+        }
+        cx.emit(StateChanged::NodesUpdated);
+      });
+    })
+  })
+  .detach();
+}
+
+/// Mark a node unschedulable so no new pods land on it
+pub fn cordon_node(name: String, cx: &mut App) {
+  set_node_unschedulable(name, true, cx);
+}
+
+/// Allow pods to be scheduled on a node again
+pub fn uncordon_node(name: String, cx: &mut App) {
+  set_node_unschedulable(name, false, cx);
+}
+
+fn set_node_unschedulable(name: String, unschedulable: bool, cx: &mut App) {
+  let action = if unschedulable { "Cordoning" } else { "Uncordoning" };
+  let task_id = start_task(cx, format!("{action} node '{name}'..."));
+  let name_clone = name.clone();
+  let disp = dispatcher(cx);
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = KubeClient::new().await?;
+    client.set_node_unschedulable(&name, unschedulable).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+
+    cx.update(|cx| match result {
+      Ok(message) => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted { message });
+        });
+        refresh_nodes(cx);
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        let verb = if unschedulable { "cordon" } else { "uncordon" };
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to {verb} node '{name_clone}': {e}"),
+          });
+        });
+      }
+    })
+  })
+  .detach();
+}
+
+/// Cordon a node and evict its pods, respecting PodDisruptionBudgets. Without `force`, pods
+/// no controller recreates or with emptyDir data fail the drain. Each eviction is reported in
+/// the task status.
+pub fn drain_node(name: String, force: bool, cx: &mut App) {
+  let task_id = start_task(cx, format!("Draining node '{name}'..."));
+  let name_clone = name.clone();
+  let disp = dispatcher(cx);
+  let (tx, mut rx) = unbounded();
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = KubeClient::new().await?;
+    client
+      .drain_node(&name, force, move |status| {
+        let _ = tx.unbounded_send(status);
+      })
+      .await
+  });
+
+  cx.spawn(async move |cx| {
+    while let Some(status) = rx.next().await {
+      let _ = cx.update(|cx| {
+        set_task_status(cx, task_id, status);
+        // Pods leave the node as they are evicted
+        refresh_nodes(cx);
+      });
+    }
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+
+    cx.update(|cx| match result {
+      Ok(message) => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted { message });
+        });
+        refresh_nodes(cx);
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to drain node '{name_clone}': {e}"),
+          });
+        });
+        // The node stays cordoned after a partial drain
+        refresh_nodes(cx);
+      }
+    })
+  })
+  .detach();
+}
+
+/// Get node YAML
+pub fn get_node_yaml(name: String, cx: &mut App) {
+  let state = docker_state(cx);
+  let name_clone = name.clone();
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = KubeClient::new().await?;
+    client.get_node_yaml(&name).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+    let yaml = match result {
+      Ok(y) => y,
+      Err(e) => format!("Error: {e}"),
+    };
+
+    cx.update(|cx| {
+      state.update(cx, |_state, cx| {
+        cx.emit(StateChanged::NodeYamlLoaded { name: name_clone, yaml });
+      });
+    })
+  })
+  .detach();
+}
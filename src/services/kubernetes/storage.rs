@@ -0,0 +1,87 @@
+//! Kubernetes PersistentVolumeClaim, PersistentVolume and StorageClass operations
+
+use gpui::App;
+
+use crate::kubernetes::KubeClient;
+use crate::services::{Tokio, complete_task, fail_task, start_task};
+use crate::state::{LoadState, StateChanged, docker_state};
+
+use super::super::core::{DispatcherEvent, dispatcher};
+
+/// Refresh claims in the selected namespace along with the cluster's volumes and storage classes
+pub fn refresh_storage(cx: &mut App) {
+  let state = docker_state(cx);
+
+  // Only show loading state on initial load, not on background refreshes
+  if state.read(cx).storage_state == LoadState::NotLoaded {
+    state.update(cx, |state, _cx| {
+      state.set_storage_loading();
+    });
+  }
+
+  let selected_ns = state.read(cx).selected_namespace.clone();
+  let namespace = if selected_ns == "all" { None } else { Some(selected_ns) };
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = KubeClient::new().await?;
+    let (claims, volumes, classes) = tokio::join!(
+      client.list_volume_claims(namespace.as_deref()),
+      client.list_persistent_volumes(),
+      client.list_storage_classes(),
+    );
+    // Listing cluster-scoped objects may be forbidden for namespace-bound users
+    Ok::<_, anyhow::Error>((claims?, volumes.unwrap_or_default(), classes.unwrap_or_default()))
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+
+    cx.update(|cx| {
+      state.update(cx, |state, cx| {
+        match result {
+          Ok((claims, volumes, classes)) => state.set_storage(claims, volumes, classes),
+          Err(e) => state.set_storage_error(e.to_string()),
+        }
+        cx.emit(StateChanged::StorageUpdated);
+      });
+    })
+  })
+  .detach();
+}
+
+/// Delete a PersistentVolumeClaim
+pub fn delete_volume_claim(name: String, namespace: String, cx: &mut App) {
+  let task_id = start_task(cx, format!("Deleting volume claim '{name}'..."));
+  let name_clone = name.clone();
+  let disp = dispatcher(cx);
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = KubeClient::new().await?;
+    client.delete_volume_claim(&name, &namespace).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+
+    cx.update(|cx| match result {
+      Ok(()) => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: format!("Volume claim '{name_clone}' deleted"),
+          });
+        });
+        refresh_storage(cx);
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to delete volume claim '{name_clone}': {e}"),
+          });
+        });
+      }
+    })
+  })
+  .detach();
+}
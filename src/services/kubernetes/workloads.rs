@@ -0,0 +1,200 @@
+//! Kubernetes StatefulSet, DaemonSet, Job and CronJob operations
+
+use gpui::App;
+
+use crate::kubernetes::{KubeClient, WorkloadKind};
+use crate::services::{Tokio, complete_task, fail_task, start_task};
+use crate::state::{LoadState, StateChanged, docker_state};
+
+use super::super::core::{DispatcherEvent, dispatcher};
+use super::pods::refresh_pods;
+
+/// Refresh the list of one workload kind
+pub fn refresh_workloads(kind: WorkloadKind, cx: &mut App) {
+  let state = docker_state(cx);
+
+  // Only show loading state on initial load, not on background refreshes
+  if state.read(cx).workloads_state(kind) == LoadState::NotLoaded {
+    state.update(cx, |state, _cx| {
+      state.set_workloads_loading(kind);
+    });
+  }
+
+  let selected_ns = state.read(cx).selected_namespace.clone();
+  let namespace = if selected_ns == "all" { None } else { Some(selected_ns) };
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = KubeClient::new().await?;
+    client.list_workloads(kind, namespace.as_deref()).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+
+    cx.update(|cx| {
+      state.update(cx, |state, cx| match result {
+        Ok(workloads) => {
+          state.set_workloads(kind, workloads);
+          cx.emit(StateChanged::WorkloadsUpdated { kind });
+        }
+        Err(e) => {
+          state.set_workloads_error(kind, e.to_string());
+          cx.emit(StateChanged::WorkloadsUpdated { kind });
+        }
+      });
+    })
+  })
+  .detach();
+}
+
+/// Refresh StatefulSets, DaemonSets, Jobs and CronJobs
+pub fn refresh_all_workloads(cx: &mut App) {
+  for kind in WorkloadKind::ALL {
+    refresh_workloads(kind, cx);
+  }
+}
+
+/// Run a workload operation as a task, then refresh what it changed
+fn run_workload_task<F, Fut>(
+  kind: WorkloadKind,
+  name: String,
+  running: String,
+  failed: &'static str,
+  cx: &mut App,
+  f: F,
+) where
+  F: FnOnce(KubeClient) -> Fut + Send + 'static,
+  Fut: std::future::Future<Output = anyhow::Result<String>> + Send + 'static,
+{
+  let task_id = start_task(cx, running);
+  let disp = dispatcher(cx);
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = KubeClient::new().await?;
+    f(client).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+
+    cx.update(|cx| match result {
+      Ok(msg) => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted { message: msg });
+        });
+        refresh_workloads(kind, cx);
+        // Triggering a CronJob creates a Job
+        if kind == WorkloadKind::CronJob {
+          refresh_workloads(WorkloadKind::Job, cx);
+        }
+        refresh_pods(cx);
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to {failed} '{name}': {e}"),
+          });
+        });
+      }
+    })
+  })
+  .detach();
+}
+
+/// Delete a workload
+pub fn delete_workload(kind: WorkloadKind, name: String, namespace: String, cx: &mut App) {
+  let running = format!("Deleting {} '{name}'...", kind.label().to_lowercase());
+  let n = name.clone();
+  run_workload_task(kind, name, running, "delete", cx, move |client| async move {
+    client.delete_workload(kind, &n, &namespace).await?;
+    Ok(format!("{} '{n}' deleted", kind.label()))
+  });
+}
+
+/// Scale a StatefulSet
+pub fn scale_workload(kind: WorkloadKind, name: String, namespace: String, replicas: i32, cx: &mut App) {
+  let running = format!("Scaling '{name}' to {replicas} replicas...");
+  let n = name.clone();
+  run_workload_task(kind, name, running, "scale", cx, move |client| async move {
+    match kind {
+      WorkloadKind::StatefulSet => client.scale_stateful_set(&n, &namespace, replicas).await,
+      _ => Err(anyhow::anyhow!("{} cannot be scaled", kind.plural())),
+    }
+  });
+}
+
+/// Rollout restart a StatefulSet or DaemonSet
+pub fn restart_workload(kind: WorkloadKind, name: String, namespace: String, cx: &mut App) {
+  let running = format!("Restarting '{name}'...");
+  let n = name.clone();
+  run_workload_task(kind, name, running, "restart", cx, move |client| async move {
+    client.restart_workload(kind, &n, &namespace).await
+  });
+}
+
+/// Create a Job from a CronJob right away
+pub fn trigger_cron_job(name: String, namespace: String, cx: &mut App) {
+  let running = format!("Triggering '{name}'...");
+  let n = name.clone();
+  run_workload_task(
+    WorkloadKind::CronJob,
+    name,
+    running,
+    "trigger",
+    cx,
+    move |client| async move { client.trigger_cron_job(&n, &namespace).await },
+  );
+}
+
+/// Suspend or resume a CronJob
+pub fn set_cron_job_suspended(name: String, namespace: String, suspend: bool, cx: &mut App) {
+  let running = if suspend {
+    format!("Suspending '{name}'...")
+  } else {
+    format!("Resuming '{name}'...")
+  };
+  let failed = if suspend { "suspend" } else { "resume" };
+  let n = name.clone();
+  run_workload_task(
+    WorkloadKind::CronJob,
+    name,
+    running,
+    failed,
+    cx,
+    move |client| async move { client.set_cron_job_suspended(&n, &namespace, suspend).await },
+  );
+}
+
+/// Get workload YAML
+pub fn get_workload_yaml(kind: WorkloadKind, name: String, namespace: String, cx: &mut App) {
+  let state = docker_state(cx);
+  let name_clone = name.clone();
+  let namespace_clone = namespace.clone();
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = KubeClient::new().await?;
+    client.get_workload_yaml(kind, &name, &namespace).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+    let yaml = match result {
+      Ok(y) => y,
+      Err(e) => format!("Error: {e}"),
+    };
+
+    cx.update(|cx| {
+      state.update(cx, |_state, cx| {
+        cx.emit(StateChanged::WorkloadYamlLoaded {
+          kind,
+          name: name_clone,
+          namespace: namespace_clone,
+          yaml,
+        });
+      });
+    })
+  })
+  .detach();
+}
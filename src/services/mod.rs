@@ -5,13 +5,17 @@
 //!
 //! - `core` - Dispatcher types and Docker client management
 //! - `docker` - Docker resource operations (containers, images, volumes, networks, compose)
+//! - `alerts` - Crash, OOM and restart-loop detection and notifications
 //! - `colima` - Colima machine and Kubernetes control operations
-//! - `kubernetes` - Kubernetes resource operations (pods, services, deployments)
+//! - `kubernetes` - Kubernetes resource operations (pods, services, deployments, workloads, configs, ingresses,
+//!   storage, nodes) and kubeconfig contexts
 //! - `navigation` - View and tab navigation functions
-//! - `prune` - Docker prune operations
+//! - `prune` - Docker prune operations and scheduled cleanup rules
+//! - `protection` - Pinned and labelled resources kept from prunes and accidental deletes
 //! - `init` - Initial data loading
 //! - `watchers` - Real-time resource watchers for Docker and Kubernetes
 
+mod alerts;
 mod colima;
 mod core;
 mod docker;
@@ -19,11 +23,13 @@ mod gpui_tokio;
 mod init;
 mod kubernetes;
 mod navigation;
+mod protection;
 mod prune;
 mod task_manager;
 mod watchers;
 
 // Re-export everything for backward compatibility
+pub use alerts::*;
 pub use colima::*;
 pub use core::*;
 pub use docker::*;
@@ -31,13 +37,14 @@ pub use gpui_tokio::Tokio;
 pub use init::*;
 pub use kubernetes::*;
 pub use navigation::*;
+pub use protection::*;
 pub use prune::*;
 pub use task_manager::*;
-pub use watchers::stop_watchers;
+pub use watchers::{restart_kubernetes_watchers, stop_watchers};
 
 use gpui::App;
 
-use crate::state::{init_docker_state, init_settings};
+use crate::state::{init_docker_state, init_events_state, init_settings};
 
 /// Initialize all global services
 pub fn init_services(cx: &mut App) {
@@ -47,6 +54,10 @@ pub fn init_services(cx: &mut App) {
   // Initialize state
   init_docker_state(cx);
   init_settings(cx);
+  init_events_state(cx);
+
+  // Kubernetes clients use the kubeconfig files and context saved in settings
+  init_kubeconfig(cx);
 
   // Initialize services
   init_task_manager(cx);
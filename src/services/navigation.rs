@@ -2,8 +2,10 @@
 
 use gpui::App;
 
+use crate::kubernetes::{ConfigKind, WorkloadKind};
 use crate::state::{
-  ContainerDetailTab, CurrentView, DeploymentDetailTab, MachineDetailTab, PodDetailTab, ServiceDetailTab, StateChanged,
+  ConfigDetailTab, ContainerDetailTab, CurrentView, DeploymentDetailTab, IngressDetailTab, InvolvedObject,
+  MachineDetailTab, NodeDetailTab, PodDetailTab, Selection, ServiceDetailTab, StateChanged, WorkloadDetailTab,
   docker_state,
 };
 
@@ -16,6 +18,24 @@ pub fn set_view(view: CurrentView, cx: &mut App) {
   });
 }
 
+/// Switch to a view and select a resource in it
+pub fn select_resource(view: CurrentView, selection: Selection, cx: &mut App) {
+  set_view(view, cx);
+  let state = docker_state(cx);
+  state.update(cx, |state, cx| {
+    // Containers go through the tab request so the view loads logs and inspect data
+    if let Selection::Container(container) = &selection {
+      cx.emit(StateChanged::ContainerTabRequest {
+        container_id: container.id.clone(),
+        tab: ContainerDetailTab::Info,
+      });
+    } else {
+      state.set_selection(selection);
+      cx.emit(StateChanged::SelectionChanged);
+    }
+  });
+}
+
 // ==================== Container Tab Navigation ====================
 
 /// Open a container's terminal tab
@@ -152,6 +172,20 @@ pub fn open_pod_yaml(name: String, namespace: String, cx: &mut App) {
 
 // ==================== Service Tab Navigation ====================
 
+/// Open a service in the services view
+pub fn open_service(name: String, namespace: String, cx: &mut App) {
+  let state = docker_state(cx);
+  state.update(cx, |state, cx| {
+    state.set_view(CurrentView::Services);
+    cx.emit(StateChanged::ViewChanged);
+    cx.emit(StateChanged::ServiceTabRequest {
+      service_name: name,
+      namespace,
+      tab: ServiceDetailTab::Info,
+    });
+  });
+}
+
 /// Open service with YAML tab selected
 pub fn open_service_yaml(name: String, namespace: String, cx: &mut App) {
   let state = docker_state(cx);
@@ -193,3 +227,141 @@ pub fn request_scale_dialog(name: String, namespace: String, current_replicas: i
     });
   });
 }
+
+// ==================== Workload Tab Navigation ====================
+
+/// Open a StatefulSet, DaemonSet, Job or CronJob in its view
+pub fn open_workload(kind: WorkloadKind, name: String, namespace: String, cx: &mut App) {
+  let state = docker_state(cx);
+  state.update(cx, |state, cx| {
+    state.set_view(CurrentView::for_workload(kind));
+    cx.emit(StateChanged::ViewChanged);
+    cx.emit(StateChanged::WorkloadTabRequest {
+      kind,
+      name,
+      namespace,
+      tab: WorkloadDetailTab::Info,
+    });
+  });
+}
+
+/// Open workload with YAML tab selected
+pub fn open_workload_yaml(kind: WorkloadKind, name: String, namespace: String, cx: &mut App) {
+  let state = docker_state(cx);
+  state.update(cx, |_state, cx| {
+    cx.emit(StateChanged::WorkloadTabRequest {
+      kind,
+      name: name.clone(),
+      namespace: namespace.clone(),
+      tab: WorkloadDetailTab::Yaml,
+    });
+  });
+  // Also trigger the YAML fetch
+  super::kubernetes::get_workload_yaml(kind, name, namespace, cx);
+}
+
+/// Request to open scale dialog for a StatefulSet
+pub fn request_workload_scale_dialog(
+  kind: WorkloadKind,
+  name: String,
+  namespace: String,
+  current_replicas: i32,
+  cx: &mut App,
+) {
+  let state = docker_state(cx);
+  state.update(cx, |_state, cx| {
+    cx.emit(StateChanged::WorkloadScaleRequest {
+      kind,
+      name,
+      namespace,
+      current_replicas,
+    });
+  });
+}
+
+/// Navigate to a ConfigMap or Secret
+pub fn open_config(kind: ConfigKind, name: String, namespace: String, cx: &mut App) {
+  open_config_tab(kind, name, namespace, ConfigDetailTab::Data, cx);
+}
+
+/// Navigate to a ConfigMap or Secret with a specific tab selected
+pub fn open_config_tab(kind: ConfigKind, name: String, namespace: String, tab: ConfigDetailTab, cx: &mut App) {
+  let state = docker_state(cx);
+  state.update(cx, |state, cx| {
+    state.set_view(CurrentView::for_config(kind));
+    cx.emit(StateChanged::ViewChanged);
+    cx.emit(StateChanged::ConfigTabRequest {
+      kind,
+      name: name.clone(),
+      namespace: namespace.clone(),
+      tab,
+    });
+  });
+  if tab == ConfigDetailTab::Yaml {
+    super::kubernetes::get_config_yaml(kind, name, namespace, cx);
+  }
+}
+
+// ==================== Ingress and Storage Navigation ====================
+
+/// Navigate to an ingress with a specific tab selected
+pub fn open_ingress_tab(name: String, namespace: String, tab: IngressDetailTab, cx: &mut App) {
+  let state = docker_state(cx);
+  state.update(cx, |state, cx| {
+    state.set_view(CurrentView::Ingresses);
+    cx.emit(StateChanged::ViewChanged);
+    cx.emit(StateChanged::IngressTabRequest {
+      name: name.clone(),
+      namespace: namespace.clone(),
+      tab,
+    });
+  });
+  if tab == IngressDetailTab::Yaml {
+    super::kubernetes::get_ingress_yaml(name, namespace, cx);
+  }
+}
+
+/// Navigate to a PersistentVolumeClaim in the storage view
+pub fn open_volume_claim(name: String, namespace: String, cx: &mut App) {
+  let state = docker_state(cx);
+  state.update(cx, |state, cx| {
+    state.set_view(CurrentView::Storage);
+    cx.emit(StateChanged::ViewChanged);
+    cx.emit(StateChanged::VolumeClaimRequest { name, namespace });
+  });
+}
+
+// ==================== Node Navigation ====================
+
+/// Navigate to a node's overview
+pub fn open_node(name: String, cx: &mut App) {
+  open_node_tab(name, NodeDetailTab::Overview, cx);
+}
+
+/// Navigate to a node with a specific tab selected
+pub fn open_node_tab(name: String, tab: NodeDetailTab, cx: &mut App) {
+  let state = docker_state(cx);
+  state.update(cx, |state, cx| {
+    state.set_view(CurrentView::Nodes);
+    cx.emit(StateChanged::ViewChanged);
+    cx.emit(StateChanged::NodeTabRequest {
+      name: name.clone(),
+      tab,
+    });
+  });
+  if tab == NodeDetailTab::Yaml {
+    super::kubernetes::get_node_yaml(name, cx);
+  }
+}
+
+// ==================== Events Navigation ====================
+
+/// Open the events view filtered to a single Kubernetes object
+pub fn open_object_events(object: InvolvedObject, cx: &mut App) {
+  let state = docker_state(cx);
+  state.update(cx, |state, cx| {
+    state.set_view(CurrentView::Events);
+    cx.emit(StateChanged::ViewChanged);
+    cx.emit(StateChanged::EventsFilterRequest { object });
+  });
+}
@@ -0,0 +1,93 @@
+//! Protected resources: pins, and the typed confirmation deleting them takes
+
+use gpui::App;
+
+use crate::docker::PruneKind;
+use crate::state::{SettingsChanged, StateChanged, docker_state, settings_state};
+
+use super::docker::{
+  delete_container_confirmed, delete_image_confirmed, delete_network_confirmed, delete_volume_confirmed,
+};
+
+/// Name to confirm with and whether a listed resource is protected; `None` when it isn't listed
+fn lookup(kind: PruneKind, id: &str, cx: &App) -> Option<(String, bool)> {
+  let state = docker_state(cx).read(cx);
+  let protection = settings_state(cx).read(cx).settings.protection();
+
+  match kind {
+    PruneKind::Containers => state.containers.iter().find(|c| c.id == id).map(|c| {
+      let protected = protection.is_protected(kind, &c.id, &[&c.name], Some(&c.labels));
+      (c.name.clone(), protected)
+    }),
+    PruneKind::Images => state.images.iter().find(|i| i.id == id).map(|i| {
+      let tags: Vec<&str> = i.repo_tags.iter().map(String::as_str).collect();
+      let protected = protection.is_protected(kind, &i.id, &tags, Some(&i.labels));
+      (i.display_name(), protected)
+    }),
+    PruneKind::Volumes => state.volumes.iter().find(|v| v.name == id).map(|v| {
+      let protected = protection.is_protected(kind, &v.name, &[], Some(&v.labels));
+      (v.name.clone(), protected)
+    }),
+    PruneKind::Networks => state.networks.iter().find(|n| n.id == id).map(|n| {
+      let protected = protection.is_protected(kind, &n.id, &[&n.name], Some(&n.labels));
+      (n.name.clone(), protected)
+    }),
+    PruneKind::BuildCache => None,
+  }
+}
+
+/// Whether a container, image, network (by id) or volume (by name) is labelled or pinned
+pub fn is_protected(kind: PruneKind, id: &str, cx: &App) -> bool {
+  lookup(kind, id, cx).is_some_and(|(_, protected)| protected)
+}
+
+/// Ask for typed confirmation instead of deleting a protected resource. Returns whether it asked.
+pub(crate) fn confirm_protected_delete(kind: PruneKind, id: &str, cx: &mut App) -> bool {
+  let Some((name, true)) = lookup(kind, id, cx) else {
+    return false;
+  };
+  docker_state(cx).update(cx, |_state, cx| {
+    cx.emit(StateChanged::ProtectedDeleteRequest {
+      kind,
+      id: id.to_string(),
+      name,
+    });
+  });
+  true
+}
+
+/// Delete a protected resource once its name has been typed
+pub fn delete_protected(kind: PruneKind, id: String, cx: &mut App) {
+  match kind {
+    PruneKind::Containers => delete_container_confirmed(id, cx),
+    PruneKind::Images => delete_image_confirmed(id, cx),
+    PruneKind::Volumes => delete_volume_confirmed(id, cx),
+    PruneKind::Networks => delete_network_confirmed(id, cx),
+    PruneKind::BuildCache => {}
+  }
+}
+
+/// Pin or unpin a resource. Containers, volumes and networks are pinned by name, images by id.
+pub fn set_pinned(kind: PruneKind, name: String, pinned: bool, cx: &mut App) {
+  let changed = settings_state(cx).update(cx, |state, cx| {
+    let changed = state.settings.set_pinned(kind, &name, pinned);
+    if changed {
+      let _ = state.settings.save();
+      cx.emit(SettingsChanged::SettingsUpdated);
+    }
+    changed
+  });
+  if !changed {
+    return;
+  }
+
+  // Lists show a protected marker, so have them redraw
+  let event = match kind {
+    PruneKind::Containers => StateChanged::ContainersUpdated,
+    PruneKind::Images => StateChanged::ImagesUpdated,
+    PruneKind::Volumes => StateChanged::VolumesUpdated,
+    PruneKind::Networks => StateChanged::NetworksUpdated,
+    PruneKind::BuildCache => return,
+  };
+  docker_state(cx).update(cx, |_state, cx| cx.emit(event));
+}
@@ -1,15 +1,26 @@
 //! Docker prune operations
 
-use gpui::{App, AppContext, Entity};
+use chrono::Utc;
+use gpui::{App, AppContext, Entity, Task};
+use std::collections::BTreeMap;
+use std::time::Duration;
 
-use crate::docker::PruneResult;
+use crate::colima::{ColimaClient, VmDiskUsage};
+use crate::docker::{PruneFilters, PruneKind, PruneResult, has_protect_label};
 use crate::services::{Tokio, complete_task, fail_task, start_task};
+use crate::state::{
+  CleanupRule, CleanupTrigger, EventSource, EventsChanged, SettingsChanged, TimelineEvent, docker_state, events_state,
+  settings_state,
+};
 use crate::ui::PruneDialog;
 
 use super::core::{DispatcherEvent, dispatcher, docker_client};
 use super::docker::{refresh_containers, refresh_images, refresh_networks, refresh_volumes};
 use super::kubernetes::{refresh_deployments, refresh_pods, refresh_services};
 
+/// How often cleanup rules are checked
+const CLEANUP_CHECK: Duration = Duration::from_secs(5 * 60);
+
 pub fn prune_docker(options: &crate::ui::PruneOptions, cx: &mut App) -> Entity<PruneDialog> {
   let task_id = start_task(cx, "Pruning Docker resources...".to_string());
   let disp = dispatcher(cx);
@@ -23,11 +34,11 @@ pub fn prune_docker(options: &crate::ui::PruneOptions, cx: &mut App) -> Entity<P
   });
   let prune_dialog_clone = prune_dialog.clone();
 
-  let prune_containers = options.prune_containers;
-  let prune_images = options.prune_images;
-  let prune_volumes = options.prune_volumes;
-  let prune_networks = options.prune_networks;
+  let kinds = options.docker_kinds();
+  let protection = settings_state(cx).read(cx).settings.protection();
   let images_dangling_only = options.images_dangling_only;
+  let filters = options.filters.clone();
+  let selected = options.selected.clone();
   let prune_k8s_pods = options.prune_k8s_pods;
   let prune_k8s_pods_all = options.prune_k8s_pods_all;
   let prune_k8s_deployments = options.prune_k8s_deployments;
@@ -41,23 +52,17 @@ pub fn prune_docker(options: &crate::ui::PruneOptions, cx: &mut App) -> Entity<P
 
     let mut result = PruneResult::default();
 
-    if prune_containers && let Ok(r) = docker.prune_containers().await {
-      result.containers_deleted = r.containers_deleted;
-      result.space_reclaimed += r.space_reclaimed;
-    }
-
-    if prune_images && let Ok(r) = docker.prune_images(images_dangling_only).await {
-      result.images_deleted = r.images_deleted;
-      result.space_reclaimed += r.space_reclaimed;
-    }
-
-    if prune_volumes && let Ok(r) = docker.prune_volumes().await {
-      result.volumes_deleted = r.volumes_deleted;
-      result.space_reclaimed += r.space_reclaimed;
-    }
-
-    if prune_networks && let Ok(r) = docker.prune_networks().await {
-      result.networks_deleted = r.networks_deleted;
+    if let Some(items) = &selected {
+      // Picked from a preview: remove exactly those
+      if let Ok(r) = docker.remove_prune_candidates(items).await {
+        result = r;
+      }
+    } else {
+      for kind in kinds {
+        if let Ok(r) = docker.prune(kind, images_dangling_only, &filters, &protection).await {
+          result.merge(r);
+        }
+      }
     }
 
     // Kubernetes pruning
@@ -73,8 +78,8 @@ pub fn prune_docker(options: &crate::ui::PruneOptions, cx: &mut App) -> Entity<P
       // Prune deployments first (this will cascade delete their pods)
       if prune_k8s_deployments && let Ok(deployments) = kube_client.list_deployments(None).await {
         for deployment in deployments {
-          // Skip system namespaces
-          if is_system_namespace(&deployment.namespace) {
+          // Skip system namespaces and protected deployments
+          if is_system_namespace(&deployment.namespace) || has_protect_label(&deployment.annotations) {
             continue;
           }
           if kube_client
@@ -92,8 +97,8 @@ pub fn prune_docker(options: &crate::ui::PruneOptions, cx: &mut App) -> Entity<P
       // Prune services
       if prune_k8s_services && let Ok(services) = kube_client.list_services(None).await {
         for service in services {
-          // Skip system namespaces and default kubernetes service
-          if is_system_resource(&service.namespace, &service.name) {
+          // Skip system namespaces, the default kubernetes service and protected services
+          if is_system_resource(&service.namespace, &service.name) || has_protect_label(&service.annotations) {
             continue;
           }
           if kube_client
@@ -111,8 +116,8 @@ pub fn prune_docker(options: &crate::ui::PruneOptions, cx: &mut App) -> Entity<P
       // Prune pods (only orphans if deployments were pruned, or based on status)
       if prune_k8s_pods && let Ok(pods) = kube_client.list_pods(None).await {
         for pod in pods {
-          // Skip system namespaces
-          if is_system_namespace(&pod.namespace) {
+          // Skip system namespaces and protected pods
+          if is_system_namespace(&pod.namespace) || has_protect_label(&pod.annotations) {
             continue;
           }
 
@@ -157,6 +162,12 @@ pub fn prune_docker(options: &crate::ui::PruneOptions, cx: &mut App) -> Entity<P
           if !prune_result.networks_deleted.is_empty() {
             parts.push(format!("{} networks", prune_result.networks_deleted.len()));
           }
+          if !prune_result.build_cache_deleted.is_empty() {
+            parts.push(format!(
+              "{} build cache records",
+              prune_result.build_cache_deleted.len()
+            ));
+          }
           if !prune_result.pods_deleted.is_empty() {
             parts.push(format!("{} pods", prune_result.pods_deleted.len()));
           }
@@ -223,3 +234,202 @@ pub fn prune_docker(options: &crate::ui::PruneOptions, cx: &mut App) -> Entity<P
 
   prune_dialog
 }
+
+/// Run cleanup rules in the background: scheduled ones when they are due, threshold ones when
+/// the Colima disk or the space their resources take is over the limit
+pub fn start_cleanup_scheduler(cx: &mut App) {
+  cx.spawn(async move |cx| {
+    loop {
+      cx.background_executor().timer(CLEANUP_CHECK).await;
+
+      let Ok((rules, machines)) = cx.update(|cx| {
+        let now = Utc::now();
+        let rules: Vec<CleanupRule> = settings_state(cx)
+          .read(cx)
+          .settings
+          .cleanup_rules
+          .iter()
+          .filter(|r| r.is_due(now))
+          .cloned()
+          .collect();
+        let machines: Vec<String> = docker_state(cx)
+          .read(cx)
+          .colima_vms
+          .iter()
+          .filter(|vm| vm.status.is_running())
+          .map(|vm| vm.name.clone())
+          .collect();
+        (rules, machines)
+      }) else {
+        return;
+      };
+      if rules.is_empty() {
+        continue;
+      }
+
+      // Only measure what the due rules look at
+      let disk_percent = if rules
+        .iter()
+        .any(|r| matches!(r.trigger, CleanupTrigger::DiskAbove { .. }))
+      {
+        cx.background_executor()
+          .spawn(async move {
+            machines
+              .iter()
+              .filter_map(|name| {
+                let profile = (name != "default").then_some(name.as_str());
+                let output = ColimaClient::get_disk_usage(profile).ok()?;
+                VmDiskUsage::parse(&output).map(|u| u.use_percent)
+              })
+              .max()
+          })
+          .await
+      } else {
+        None
+      };
+
+      let usage = if rules
+        .iter()
+        .any(|r| matches!(r.trigger, CleanupTrigger::SizeAbove { .. }))
+      {
+        let client = docker_client();
+        let Ok(task) = cx.update(|cx| {
+          Tokio::spawn(cx, async move {
+            let docker = client.read().await.clone()?;
+            docker.system_df().await.ok()
+          })
+        }) else {
+          return;
+        };
+        task.await.ok().flatten()
+      } else {
+        None
+      };
+
+      for rule in rules {
+        let over = match rule.trigger {
+          CleanupTrigger::Every { .. } => true,
+          CleanupTrigger::DiskAbove { percent } => disk_percent.is_some_and(|p| p >= percent),
+          CleanupTrigger::SizeAbove { gigabytes } => usage
+            .as_ref()
+            .is_some_and(|u| u.size_of(rule.kind) > gigabytes.saturating_mul(1_000_000_000)),
+        };
+        if !over {
+          continue;
+        }
+
+        // One rule at a time so runs don't race over the same resources
+        let Ok(task) = cx.update(|cx| run_cleanup_rule(rule, cx)) else {
+          return;
+        };
+        task.await;
+      }
+    }
+  })
+  .detach();
+}
+
+/// Run a cleanup rule now, record it in the events timeline and stamp its last run
+pub fn run_cleanup_rule(rule: CleanupRule, cx: &mut App) -> Task<()> {
+  let task_id = start_task(cx, format!("Running cleanup rule {}...", rule.name));
+  let disp = dispatcher(cx);
+  let client = docker_client();
+
+  let kind = rule.kind;
+  let dangling_only = rule.dangling_only;
+  let filters = rule.filters.clone();
+  let protection = settings_state(cx).read(cx).settings.protection();
+  let tokio_task = Tokio::spawn(cx, async move {
+    let filters = PruneFilters::parse(&filters)?;
+    let docker = client
+      .read()
+      .await
+      .clone()
+      .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+    docker.prune(kind, dangling_only, &filters, &protection).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = match tokio_task.await {
+      Ok(result) => result,
+      Err(e) => Err(e.into()),
+    };
+
+    let _ = cx.update(|cx| {
+      let now = Utc::now();
+
+      // Failed runs count too, so a broken rule doesn't retry on every check
+      settings_state(cx).update(cx, |state, cx| {
+        if let Some(r) = state.settings.cleanup_rules.iter_mut().find(|r| r.name == rule.name) {
+          r.last_run = Some(now);
+          let _ = state.settings.save();
+          cx.emit(SettingsChanged::SettingsUpdated);
+        }
+      });
+
+      let mut attributes = BTreeMap::from([
+        ("rule".to_string(), rule.name.clone()),
+        ("trigger".to_string(), rule.trigger.describe()),
+      ]);
+      if !rule.filters.trim().is_empty() {
+        attributes.insert("filters".to_string(), rule.filters.trim().to_string());
+      }
+
+      let message = match &result {
+        Ok(pruned) => {
+          complete_task(cx, task_id);
+          attributes.insert("reclaimed".to_string(), pruned.space_reclaimed.to_string());
+          let message = format!(
+            "Cleanup {} ({}): removed {}, reclaimed {}",
+            rule.name,
+            rule.kind.label(),
+            pruned.total_items_deleted(),
+            pruned.display_space_reclaimed()
+          );
+          disp.update(cx, |_, cx| {
+            cx.emit(DispatcherEvent::TaskCompleted {
+              message: message.clone(),
+            });
+          });
+          message
+        }
+        Err(e) => {
+          fail_task(cx, task_id, e.to_string());
+          disp.update(cx, |_, cx| {
+            cx.emit(DispatcherEvent::TaskFailed {
+              error: format!("Cleanup {} failed: {e}", rule.name),
+            });
+          });
+          format!("Cleanup {} failed: {e}", rule.name)
+        }
+      };
+
+      let event = TimelineEvent {
+        id: format!("cleanup-{}-{}", rule.name, now.timestamp_millis()),
+        timestamp: now,
+        source: EventSource::Docker,
+        kind: "cleanup".to_string(),
+        action: "prune".to_string(),
+        resource_id: String::new(),
+        resource_name: rule.name.clone(),
+        namespace: None,
+        attributes,
+        message: Some(message),
+        is_warning: result.is_err(),
+      };
+      events_state(cx).update(cx, |state, cx| {
+        if !state.push_events(vec![event]).is_empty() {
+          cx.emit(EventsChanged::Appended);
+        }
+      });
+
+      match rule.kind {
+        PruneKind::Containers => refresh_containers(cx),
+        PruneKind::Images => refresh_images(cx),
+        PruneKind::Volumes => refresh_volumes(cx),
+        PruneKind::Networks => refresh_networks(cx),
+        PruneKind::BuildCache => {}
+      }
+    });
+  })
+}
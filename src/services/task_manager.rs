@@ -132,6 +132,13 @@ impl TaskManager {
     }
   }
 
+  /// Replace the status line shown under the task description
+  pub fn set_stage_status(&mut self, task_id: u64, status: impl Into<String>) {
+    if let Some(task) = self.tasks.get_mut(&task_id) {
+      task.stage_status = Some(status.into());
+    }
+  }
+
   /// Mark task as completed
   pub fn complete_task(&mut self, task_id: u64) {
     if let Some(task) = self.tasks.get_mut(&task_id) {
@@ -203,6 +210,15 @@ pub fn advance_stage(cx: &mut App, task_id: u64) {
   });
 }
 
+/// Helper to update a task's status line from any context
+pub fn set_task_status(cx: &mut App, task_id: u64, status: impl Into<String>) {
+  let manager = task_manager(cx);
+  manager.update(cx, |m, cx| {
+    m.set_stage_status(task_id, status);
+    cx.notify();
+  });
+}
+
 /// Helper to complete a task from any context
 pub fn complete_task(cx: &mut App, task_id: u64) {
   let manager = task_manager(cx);
@@ -438,6 +454,19 @@ mod tests {
     assert_eq!(task.display_status(), "Custom status");
   }
 
+  #[test]
+  fn test_task_manager_set_stage_status() {
+    let mut manager = TaskManager::new();
+    let task_id = manager.start_task("Streaming task");
+
+    manager.set_stage_status(task_id, "Container web-1  Started");
+    assert_eq!(manager.running_tasks()[0].display_status(), "Container web-1  Started");
+
+    // Unknown tasks are ignored
+    manager.set_stage_status(99999, "ignored");
+    assert_eq!(manager.running_tasks().len(), 1);
+  }
+
   #[test]
   fn test_task_ids_are_unique() {
     let task1 = Task::new("Task 1");
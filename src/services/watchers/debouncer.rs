@@ -21,6 +21,17 @@ pub enum ResourceType {
   Pod,
   Deployment,
   Service,
+  StatefulSet,
+  DaemonSet,
+  Job,
+  CronJob,
+  ConfigMap,
+  Secret,
+  Ingress,
+  PersistentVolumeClaim,
+  PersistentVolume,
+  StorageClass,
+  Node,
   Machine,
 }
 
@@ -8,17 +8,21 @@
 //! - Automatic reconnection with health checks
 //! - Graceful degradation when Docker is unavailable
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::Duration;
 
 use bollard::Docker;
 use bollard::query_parameters::EventsOptions;
 use bollard::secret::{EventMessage, EventMessageTypeEnum};
+use chrono::{DateTime, Utc};
 use futures::StreamExt;
 use tokio::sync::RwLock;
+use tokio::sync::mpsc::UnboundedSender;
 
 use super::{WatcherControl, debouncer::ResourceType};
 use crate::docker::DockerClient;
+use crate::state::{EventSource, TimelineEvent};
 
 /// Backoff configuration for reconnection
 struct Backoff {
@@ -74,11 +78,18 @@ impl DockerResourceEvent {
 /// Docker events watcher
 pub struct DockerEventWatcher {
   client: Arc<RwLock<Option<DockerClient>>>,
+  timeline: Option<UnboundedSender<TimelineEvent>>,
 }
 
 impl DockerEventWatcher {
   pub fn new(client: Arc<RwLock<Option<DockerClient>>>) -> Self {
-    Self { client }
+    Self { client, timeline: None }
+  }
+
+  /// Also forward every raw event to the events timeline
+  pub fn with_timeline(mut self, tx: UnboundedSender<TimelineEvent>) -> Self {
+    self.timeline = Some(tx);
+    self
   }
 
   /// Start watching Docker events
@@ -155,6 +166,11 @@ impl DockerEventWatcher {
         event = stream.next() => {
           match event {
             Some(Ok(event)) => {
+              if let Some(tx) = &self.timeline
+                && let Some(timeline_event) = Self::timeline_event(&event)
+              {
+                let _ = tx.send(timeline_event);
+              }
               if let Some(resource_event) = Self::parse_event(&event) {
                 on_event(resource_event);
               }
@@ -204,4 +220,45 @@ impl DockerEventWatcher {
       _ => None,
     }
   }
+
+  /// Convert a raw Docker event into a timeline entry
+  fn timeline_event(event: &EventMessage) -> Option<TimelineEvent> {
+    let kind = event.typ.as_ref()?.to_string();
+    let action = event.action.clone()?;
+    let actor = event.actor.as_ref()?;
+    let resource_id = actor.id.clone().unwrap_or_default();
+    let attributes: BTreeMap<String, String> = actor
+      .attributes
+      .as_ref()
+      .map(|attrs| attrs.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+      .unwrap_or_default();
+    let resource_name = attributes.get("name").cloned().unwrap_or_else(|| resource_id.clone());
+
+    let timestamp = event
+      .time_nano
+      .map(DateTime::from_timestamp_nanos)
+      .or_else(|| event.time.and_then(|secs| DateTime::from_timestamp(secs, 0)))
+      .unwrap_or_else(Utc::now);
+
+    let is_warning = action == "oom"
+      || action.starts_with("health_status: unhealthy")
+      || (action == "die" && attributes.get("exitCode").is_some_and(|code| code != "0"));
+
+    Some(TimelineEvent {
+      id: format!(
+        "docker-{}-{resource_id}-{action}",
+        timestamp.timestamp_nanos_opt().unwrap_or_default()
+      ),
+      timestamp,
+      source: EventSource::Docker,
+      kind,
+      action,
+      resource_id,
+      resource_name,
+      namespace: None,
+      attributes,
+      message: None,
+      is_warning,
+    })
+  }
 }
@@ -8,18 +8,27 @@
 //! - Generic implementation for all K8s resource types
 //! - Automatic reconnection with cluster availability checks
 
+use std::collections::BTreeMap;
 use std::time::Duration;
 
 use anyhow::Result;
+use chrono::Utc;
 use futures::StreamExt;
-use k8s_openapi::NamespaceResourceScope;
-use k8s_openapi::api::apps::v1::Deployment;
-use k8s_openapi::api::core::v1::{Pod, Service};
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{
+  ConfigMap, Event, Node, PersistentVolume, PersistentVolumeClaim, Pod, Secret, Service,
+};
+use k8s_openapi::api::networking::v1::Ingress;
+use k8s_openapi::api::storage::v1::StorageClass;
 use kube::runtime::watcher::{self, Event as WatchEvent};
 use kube::{Api, Client, Resource};
+use tokio::sync::mpsc::UnboundedSender;
 
 use super::WatcherControl;
 use super::debouncer::ResourceType;
+use crate::kubernetes::KubeClient;
+use crate::state::{EventSource, TimelineEvent};
 
 /// Backoff configuration for reconnection
 struct Backoff {
@@ -49,12 +58,20 @@ impl Backoff {
 /// Kubernetes resource watcher using generics to avoid duplication
 pub struct KubernetesWatcher {
   client: Option<Client>,
+  timeline: Option<UnboundedSender<TimelineEvent>>,
 }
 
 impl KubernetesWatcher {
+  /// Connect with the selected kubeconfig context, like every other `KubeClient`
   pub async fn new() -> Self {
-    let client = Client::try_default().await.ok();
-    Self { client }
+    let client = KubeClient::new().await.ok().map(KubeClient::into_client);
+    Self { client, timeline: None }
+  }
+
+  /// Also watch `Event` objects and forward them to the events timeline
+  pub fn with_timeline(mut self, tx: UnboundedSender<TimelineEvent>) -> Self {
+    self.timeline = Some(tx);
+    self
   }
 
   pub fn is_available(&self) -> bool {
@@ -73,12 +90,41 @@ impl KubernetesWatcher {
     let (tx, mut rx) = tokio::sync::mpsc::channel::<ResourceType>(256);
 
     // Spawn generic watchers for each resource type
-    let handles = [
+    let mut handles = vec![
       Self::spawn_watcher::<Pod>(client.clone(), control.clone(), tx.clone(), ResourceType::Pod),
       Self::spawn_watcher::<Deployment>(client.clone(), control.clone(), tx.clone(), ResourceType::Deployment),
-      Self::spawn_watcher::<Service>(client.clone(), control, tx, ResourceType::Service),
+      Self::spawn_watcher::<Service>(client.clone(), control.clone(), tx.clone(), ResourceType::Service),
+      Self::spawn_watcher::<StatefulSet>(client.clone(), control.clone(), tx.clone(), ResourceType::StatefulSet),
+      Self::spawn_watcher::<DaemonSet>(client.clone(), control.clone(), tx.clone(), ResourceType::DaemonSet),
+      Self::spawn_watcher::<Job>(client.clone(), control.clone(), tx.clone(), ResourceType::Job),
+      Self::spawn_watcher::<CronJob>(client.clone(), control.clone(), tx.clone(), ResourceType::CronJob),
+      Self::spawn_watcher::<ConfigMap>(client.clone(), control.clone(), tx.clone(), ResourceType::ConfigMap),
+      Self::spawn_watcher::<Secret>(client.clone(), control.clone(), tx.clone(), ResourceType::Secret),
+      Self::spawn_watcher::<Ingress>(client.clone(), control.clone(), tx.clone(), ResourceType::Ingress),
+      Self::spawn_watcher::<PersistentVolumeClaim>(
+        client.clone(),
+        control.clone(),
+        tx.clone(),
+        ResourceType::PersistentVolumeClaim,
+      ),
+      // Cluster-scoped
+      Self::spawn_watcher::<PersistentVolume>(
+        client.clone(),
+        control.clone(),
+        tx.clone(),
+        ResourceType::PersistentVolume,
+      ),
+      Self::spawn_watcher::<StorageClass>(client.clone(), control.clone(), tx.clone(), ResourceType::StorageClass),
+      Self::spawn_watcher::<Node>(client.clone(), control.clone(), tx, ResourceType::Node),
     ];
 
+    if let Some(timeline) = self.timeline.clone() {
+      let client = client.clone();
+      handles.push(tokio::spawn(async move {
+        Self::watch_events(client, control, timeline).await;
+      }));
+    }
+
     // Forward events to callback
     while let Some(resource_type) = rx.recv().await {
       on_change(resource_type);
@@ -98,13 +144,7 @@ impl KubernetesWatcher {
     resource_type: ResourceType,
   ) -> tokio::task::JoinHandle<()>
   where
-    K: Resource<Scope = NamespaceResourceScope, DynamicType = ()>
-      + Clone
-      + std::fmt::Debug
-      + Send
-      + Sync
-      + serde::de::DeserializeOwned
-      + 'static,
+    K: Resource<DynamicType = ()> + Clone + std::fmt::Debug + Send + Sync + serde::de::DeserializeOwned + 'static,
     K::DynamicType: Default,
   {
     tokio::spawn(async move {
@@ -120,13 +160,7 @@ impl KubernetesWatcher {
     tx: tokio::sync::mpsc::Sender<ResourceType>,
     resource_type: ResourceType,
   ) where
-    K: Resource<Scope = NamespaceResourceScope, DynamicType = ()>
-      + Clone
-      + std::fmt::Debug
-      + Send
-      + Sync
-      + serde::de::DeserializeOwned
-      + 'static,
+    K: Resource<DynamicType = ()> + Clone + std::fmt::Debug + Send + Sync + serde::de::DeserializeOwned + 'static,
     K::DynamicType: Default,
   {
     let api: Api<K> = Api::all(client);
@@ -199,4 +233,88 @@ impl KubernetesWatcher {
   {
     matches!(event, WatchEvent::Apply(_) | WatchEvent::Delete(_))
   }
+
+  /// Watch core/v1 `Event` objects across all namespaces for the timeline
+  async fn watch_events(client: Client, control: WatcherControl, timeline: UnboundedSender<TimelineEvent>) {
+    let api: Api<Event> = Api::all(client);
+    let mut backoff = Backoff::new();
+
+    while control.is_running() {
+      let watcher = watcher::watcher(api.clone(), watcher::Config::default());
+      futures::pin_mut!(watcher);
+
+      let result: Result<()> = loop {
+        if !control.is_running() {
+          break Ok(());
+        }
+        tokio::select! {
+          event = watcher.next() => {
+            match event {
+              Some(Ok(WatchEvent::Apply(ev) | WatchEvent::InitApply(ev))) => {
+                backoff.reset();
+                if let Some(timeline_event) = Self::timeline_event(&ev) {
+                  let _ = timeline.send(timeline_event);
+                }
+              }
+              Some(Ok(_)) => {}
+              Some(Err(e)) => break Err(anyhow::anyhow!("Watch error: {e}")),
+              None => break Ok(()),
+            }
+          }
+          () = tokio::time::sleep(Duration::from_secs(60)) => {
+            // Periodic keepalive
+          }
+        }
+      };
+
+      if let Err(e) = result {
+        let delay = backoff.next_delay();
+        tracing::warn!("Event watcher error: {e}, retrying in {delay:?}");
+        tokio::time::sleep(delay).await;
+      }
+    }
+  }
+
+  /// Convert a Kubernetes `Event` into a timeline entry
+  fn timeline_event(event: &Event) -> Option<TimelineEvent> {
+    let uid = event.metadata.uid.clone()?;
+    let object = &event.involved_object;
+    let count = event.count.unwrap_or(1);
+
+    let timestamp = event
+      .last_timestamp
+      .as_ref()
+      .map(|t| t.0)
+      .or_else(|| event.event_time.as_ref().map(|t| t.0))
+      .or_else(|| event.metadata.creation_timestamp.as_ref().map(|t| t.0))
+      .unwrap_or_else(Utc::now);
+
+    let mut attributes = BTreeMap::new();
+    if let Some(component) = event.source.as_ref().and_then(|s| s.component.clone()) {
+      attributes.insert("component".to_string(), component);
+    }
+    if let Some(host) = event.source.as_ref().and_then(|s| s.host.clone()) {
+      attributes.insert("host".to_string(), host);
+    }
+    if count > 1 {
+      attributes.insert("count".to_string(), count.to_string());
+    }
+    if let Some(field_path) = object.field_path.clone() {
+      attributes.insert("fieldPath".to_string(), field_path);
+    }
+
+    Some(TimelineEvent {
+      id: format!("k8s-{uid}-{count}"),
+      timestamp,
+      source: EventSource::Kubernetes,
+      kind: object.kind.clone().unwrap_or_default(),
+      action: event.reason.clone().unwrap_or_default(),
+      resource_id: object.uid.clone().unwrap_or_default(),
+      resource_name: object.name.clone().unwrap_or_default(),
+      namespace: object.namespace.clone(),
+      attributes,
+      message: event.message.clone(),
+      is_warning: event.type_.as_deref() == Some("Warning"),
+    })
+  }
 }
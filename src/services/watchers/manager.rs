@@ -8,19 +8,66 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use gpui::{App, Global};
+use parking_lot::Mutex;
 use tokio::sync::RwLock;
+use tokio::sync::mpsc::UnboundedSender;
 
 use super::WatcherControl;
-use super::debouncer::{EventDebouncer, ResourceType};
+use super::debouncer::{DebounceSender, EventDebouncer, ResourceType};
 use super::docker_events::DockerEventWatcher;
 use super::kubernetes::KubernetesWatcher;
 use super::machines::MachineWatcher;
 use crate::docker::DockerClient;
+use crate::kubernetes::{ConfigKind, WorkloadKind};
+use crate::services::{CrashDetector, notify_crash};
+use crate::state::{EventsChanged, TimelineEvent, events_state, settings_state};
 
 /// Manages all resource watchers
 pub struct WatcherManager {
   docker_client: Arc<RwLock<Option<DockerClient>>>,
   control: WatcherControl,
+  kubernetes: Arc<KubernetesWatchers>,
+}
+
+/// Kubernetes watchers have their own control so they can be restarted on context switches
+#[derive(Default)]
+struct KubernetesWatchers {
+  control: Mutex<WatcherControl>,
+  /// Where the watchers send changes and events, set once the manager has started
+  senders: Mutex<Option<(DebounceSender, UnboundedSender<TimelineEvent>)>>,
+}
+
+impl KubernetesWatchers {
+  /// Stop the running watchers and start new ones against the selected context
+  fn restart(&self, cx: &mut App) {
+    let Some((k8s_tx, timeline_tx)) = self.senders.lock().clone() else {
+      return;
+    };
+
+    let control = WatcherControl::new();
+    let previous = std::mem::replace(&mut *self.control.lock(), control.clone());
+    previous.stop();
+
+    crate::services::Tokio::spawn(cx, async move {
+      let watcher = KubernetesWatcher::new().await.with_timeline(timeline_tx);
+
+      if watcher.is_available() {
+        watcher
+          .watch_all(control, |resource_type| {
+            tracing::debug!("Kubernetes change: {resource_type:?}");
+            k8s_tx.send(resource_type);
+          })
+          .await;
+      }
+
+      Ok::<(), anyhow::Error>(())
+    })
+    .detach();
+  }
+
+  fn stop(&self) {
+    self.control.lock().stop();
+  }
 }
 
 impl WatcherManager {
@@ -28,6 +75,7 @@ impl WatcherManager {
     Self {
       docker_client,
       control: WatcherControl::new(),
+      kubernetes: Arc::new(KubernetesWatchers::default()),
     }
   }
 
@@ -53,11 +101,44 @@ impl WatcherManager {
     })
     .detach();
 
+    // Raw Docker and Kubernetes events go to the events timeline
+    let (timeline_tx, mut timeline_rx) = tokio::sync::mpsc::unbounded_channel::<TimelineEvent>();
+
+    cx.spawn(async move |cx| {
+      let mut detector = CrashDetector::default();
+      while let Some(first) = timeline_rx.recv().await {
+        // Drain whatever else arrived so bursts become a single journal write
+        let mut batch = vec![first];
+        while let Ok(event) = timeline_rx.try_recv() {
+          batch.push(event);
+        }
+        let _ = cx.update(|cx| {
+          let added = events_state(cx).update(cx, |state, cx| {
+            let added = state.push_events(batch);
+            if !added.is_empty() {
+              cx.emit(EventsChanged::Appended);
+            }
+            added
+          });
+
+          let notifications = settings_state(cx).read(cx).settings.notifications.clone();
+          let now = chrono::Utc::now();
+          for event in &added {
+            if let Some(alert) = detector.process(event, &notifications, now) {
+              notify_crash(alert, cx);
+            }
+          }
+        });
+      }
+    })
+    .detach();
+
     // Spawn Docker events watcher
     let docker_tx = debounce_tx.clone();
     let docker_control = control.clone();
+    let docker_timeline_tx = timeline_tx.clone();
     crate::services::Tokio::spawn(cx, async move {
-      let watcher = DockerEventWatcher::new(docker_client);
+      let watcher = DockerEventWatcher::new(docker_client).with_timeline(docker_timeline_tx);
 
       watcher
         .watch(docker_control, |event| {
@@ -70,24 +151,9 @@ impl WatcherManager {
     })
     .detach();
 
-    // Spawn Kubernetes watcher
-    let k8s_tx = debounce_tx.clone();
-    let k8s_control = control.clone();
-    crate::services::Tokio::spawn(cx, async move {
-      let watcher = KubernetesWatcher::new().await;
-
-      if watcher.is_available() {
-        watcher
-          .watch_all(k8s_control, |resource_type| {
-            tracing::debug!("Kubernetes change: {resource_type:?}");
-            k8s_tx.send(resource_type);
-          })
-          .await;
-      }
-
-      Ok::<(), anyhow::Error>(())
-    })
-    .detach();
+    // Spawn Kubernetes watchers
+    *self.kubernetes.senders.lock() = Some((debounce_tx.clone(), timeline_tx));
+    self.kubernetes.restart(cx);
 
     // Spawn Colima machine watcher (polls every 10 seconds - conservative to avoid overhead)
     let machine_tx = debounce_tx;
@@ -110,6 +176,7 @@ impl WatcherManager {
   /// Stop all watchers gracefully
   pub fn stop(&self) {
     self.control.stop();
+    self.kubernetes.stop();
   }
 }
 
@@ -138,6 +205,33 @@ fn refresh_resources(resources: &HashSet<ResourceType>, cx: &mut App) {
       ResourceType::Service => {
         crate::services::refresh_services(cx);
       }
+      ResourceType::StatefulSet => {
+        crate::services::refresh_workloads(WorkloadKind::StatefulSet, cx);
+      }
+      ResourceType::DaemonSet => {
+        crate::services::refresh_workloads(WorkloadKind::DaemonSet, cx);
+      }
+      ResourceType::Job => {
+        crate::services::refresh_workloads(WorkloadKind::Job, cx);
+      }
+      ResourceType::CronJob => {
+        crate::services::refresh_workloads(WorkloadKind::CronJob, cx);
+      }
+      ResourceType::ConfigMap => {
+        crate::services::refresh_configs(ConfigKind::ConfigMap, cx);
+      }
+      ResourceType::Secret => {
+        crate::services::refresh_configs(ConfigKind::Secret, cx);
+      }
+      ResourceType::Ingress => {
+        crate::services::refresh_ingresses(cx);
+      }
+      ResourceType::PersistentVolumeClaim | ResourceType::PersistentVolume | ResourceType::StorageClass => {
+        crate::services::refresh_storage(cx);
+      }
+      ResourceType::Node => {
+        crate::services::refresh_nodes(cx);
+      }
       ResourceType::Machine => {
         crate::services::refresh_machines(cx);
       }
@@ -157,6 +251,16 @@ pub fn start_watchers(docker_client: Arc<RwLock<Option<DockerClient>>>, cx: &mut
   cx.set_global(GlobalWatcherManager(manager));
 }
 
+/// Reconnect the Kubernetes watchers, e.g. after switching context
+pub fn restart_kubernetes_watchers(cx: &mut App) {
+  let kubernetes = cx
+    .try_global::<GlobalWatcherManager>()
+    .map(|global| global.0.kubernetes.clone());
+  if let Some(kubernetes) = kubernetes {
+    kubernetes.restart(cx);
+  }
+}
+
 /// Stop all watchers gracefully (call on app shutdown)
 pub fn stop_watchers(cx: &App) {
   if let Some(global) = cx.try_global::<GlobalWatcherManager>() {
@@ -1,4 +1,5 @@
 use crate::colima::{ColimaConfig, VmFileEntry, VmOsInfo};
+use crate::kubernetes::{ConfigKind, WorkloadKind};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum CurrentView {
@@ -10,12 +11,44 @@ pub enum CurrentView {
   Networks,
   Pods,
   Services,
+  Ingresses,
   Deployments,
+  StatefulSets,
+  DaemonSets,
+  Jobs,
+  CronJobs,
+  ConfigMaps,
+  Secrets,
+  Storage,
+  Nodes,
+  Contexts,
   Machines,
   ActivityMonitor,
+  Events,
+  DiskUsage,
   Settings,
 }
 
+impl CurrentView {
+  /// View listing a workload kind
+  pub fn for_workload(kind: WorkloadKind) -> Self {
+    match kind {
+      WorkloadKind::StatefulSet => CurrentView::StatefulSets,
+      WorkloadKind::DaemonSet => CurrentView::DaemonSets,
+      WorkloadKind::Job => CurrentView::Jobs,
+      WorkloadKind::CronJob => CurrentView::CronJobs,
+    }
+  }
+
+  /// View listing ConfigMaps or Secrets
+  pub fn for_config(kind: ConfigKind) -> Self {
+    match kind {
+      ConfigKind::ConfigMap => CurrentView::ConfigMaps,
+      ConfigKind::Secret => CurrentView::Secrets,
+    }
+  }
+}
+
 /// Type of logs to display for a machine
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum MachineLogType {
@@ -90,12 +123,34 @@ mod tests {
       CurrentView::Networks,
       CurrentView::Pods,
       CurrentView::Services,
+      CurrentView::Ingresses,
       CurrentView::Deployments,
+      CurrentView::StatefulSets,
+      CurrentView::DaemonSets,
+      CurrentView::Jobs,
+      CurrentView::CronJobs,
+      CurrentView::ConfigMaps,
+      CurrentView::Secrets,
+      CurrentView::Storage,
+      CurrentView::Nodes,
+      CurrentView::Contexts,
       CurrentView::Machines,
       CurrentView::ActivityMonitor,
+      CurrentView::Events,
+      CurrentView::DiskUsage,
       CurrentView::Settings,
     ];
-    assert_eq!(views.len(), 11);
+    assert_eq!(views.len(), 23);
+  }
+
+  #[test]
+  fn test_current_view_for_workload() {
+    assert_eq!(
+      CurrentView::for_workload(WorkloadKind::StatefulSet),
+      CurrentView::StatefulSets
+    );
+    assert_eq!(CurrentView::for_workload(WorkloadKind::CronJob), CurrentView::CronJobs);
+    assert_eq!(CurrentView::for_config(ConfigKind::Secret), CurrentView::Secrets);
   }
 
   #[test]
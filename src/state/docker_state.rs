@@ -1,10 +1,16 @@
+use std::collections::HashMap;
+
 use gpui::{App, AppContext, Entity, EventEmitter, Global};
 
 use crate::colima::ColimaVm;
-use crate::docker::{ContainerInfo, ImageInfo, NetworkInfo, VolumeInfo};
-use crate::kubernetes::{DeploymentInfo, PodInfo, ServiceInfo};
+use crate::docker::{ContainerInfo, ImageInfo, NetworkInfo, PruneKind, VolumeInfo};
+use crate::kubernetes::{
+  ConfigInfo, ConfigKind, DeploymentInfo, IngressInfo, KubeconfigOverview, NodeInfo, PersistentVolumeInfo, PodInfo,
+  ServiceInfo, StorageClassInfo, VolumeClaimInfo, WorkloadInfo, WorkloadKind,
+};
 
 use super::app_state::CurrentView;
+use super::events::InvolvedObject;
 
 use crate::docker::VolumeFileEntry;
 
@@ -55,17 +61,21 @@ pub enum ContainerDetailTab {
   Logs = 1,
   Processes = 2,
   Terminal = 3,
-  Files = 4,
-  Inspect = 5,
+  Run = 4,
+  Files = 5,
+  Diagnostics = 6,
+  Inspect = 7,
 }
 
 impl ContainerDetailTab {
-  pub const ALL: [ContainerDetailTab; 6] = [
+  pub const ALL: [ContainerDetailTab; 8] = [
     ContainerDetailTab::Info,
     ContainerDetailTab::Logs,
     ContainerDetailTab::Processes,
     ContainerDetailTab::Terminal,
+    ContainerDetailTab::Run,
     ContainerDetailTab::Files,
+    ContainerDetailTab::Diagnostics,
     ContainerDetailTab::Inspect,
   ];
 
@@ -75,7 +85,9 @@ impl ContainerDetailTab {
       ContainerDetailTab::Logs => "Logs",
       ContainerDetailTab::Processes => "Processes",
       ContainerDetailTab::Terminal => "Terminal",
+      ContainerDetailTab::Run => "Run",
       ContainerDetailTab::Files => "Files",
+      ContainerDetailTab::Diagnostics => "Diagnostics",
       ContainerDetailTab::Inspect => "Inspect",
     }
   }
@@ -90,15 +102,17 @@ pub enum PodDetailTab {
   Logs = 1,
   Terminal = 2,
   Describe = 3,
-  Yaml = 4,
+  Events = 4,
+  Yaml = 5,
 }
 
 impl PodDetailTab {
-  pub const ALL: [PodDetailTab; 5] = [
+  pub const ALL: [PodDetailTab; 6] = [
     PodDetailTab::Info,
     PodDetailTab::Logs,
     PodDetailTab::Terminal,
     PodDetailTab::Describe,
+    PodDetailTab::Events,
     PodDetailTab::Yaml,
   ];
 
@@ -108,6 +122,7 @@ impl PodDetailTab {
       PodDetailTab::Logs => "Logs",
       PodDetailTab::Terminal => "Terminal",
       PodDetailTab::Describe => "Describe",
+      PodDetailTab::Events => "Events",
       PodDetailTab::Yaml => "YAML",
     }
   }
@@ -121,7 +136,8 @@ pub enum ServiceDetailTab {
   Info = 0,
   Ports = 1,
   Endpoints = 2,
-  Yaml = 3,
+  Events = 3,
+  Yaml = 4,
 }
 
 /// Tab indices for deployment detail view
@@ -131,6 +147,48 @@ pub enum DeploymentDetailTab {
   #[default]
   Info = 0,
   Pods = 1,
+  Events = 2,
+  Yaml = 3,
+}
+
+/// Tab indices for StatefulSet, DaemonSet, Job and CronJob detail views
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(usize)]
+pub enum WorkloadDetailTab {
+  #[default]
+  Info = 0,
+  /// Pods, or the jobs a CronJob created
+  Pods = 1,
+  Yaml = 2,
+}
+
+/// Tab indices for ConfigMap and Secret detail views
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(usize)]
+pub enum ConfigDetailTab {
+  #[default]
+  Data = 0,
+  /// Pods consuming the object
+  Consumers = 1,
+  Yaml = 2,
+}
+
+/// Tab indices for ingress detail views
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(usize)]
+pub enum IngressDetailTab {
+  #[default]
+  Rules = 0,
+  Yaml = 1,
+}
+
+/// Tab indices for node detail views
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(usize)]
+pub enum NodeDetailTab {
+  #[default]
+  Overview = 0,
+  Pods = 1,
   Yaml = 2,
 }
 
@@ -156,6 +214,29 @@ pub enum Selection {
     name: String,
     namespace: String,
   },
+  /// StatefulSet, DaemonSet, Job or CronJob
+  Workload {
+    kind: WorkloadKind,
+    name: String,
+    namespace: String,
+  },
+  /// ConfigMap or Secret
+  Config {
+    kind: ConfigKind,
+    name: String,
+    namespace: String,
+  },
+  Ingress {
+    name: String,
+    namespace: String,
+  },
+  /// PersistentVolumeClaim
+  VolumeClaim {
+    name: String,
+    namespace: String,
+  },
+  /// Kubernetes node (cluster-scoped)
+  Node(String),
   Machine(String), // Machine name
 }
 
@@ -180,6 +261,8 @@ pub enum StateChanged {
   NetworksUpdated,
   PodsUpdated,
   NamespacesUpdated,
+  /// Kubeconfig files or contexts were reloaded
+  KubeconfigUpdated,
   ViewChanged,
   SelectionChanged,
   Loading,
@@ -191,6 +274,10 @@ pub enum StateChanged {
   VolumeFilesError {
     volume_name: String,
   },
+  /// A backup of the volume was written or removed
+  VolumeBackupsChanged {
+    volume_name: String,
+  },
   ImageInspectLoaded {
     image_id: String,
     data: ImageInspectData,
@@ -239,6 +326,13 @@ pub enum StateChanged {
     container_id: String,
     container_name: String,
   },
+  /// A protected resource is about to be deleted; its name has to be typed to go ahead
+  ProtectedDeleteRequest {
+    kind: PruneKind,
+    /// Container, image or network id, or volume name
+    id: String,
+    name: String,
+  },
   /// Request to open a pod with a specific tab
   PodTabRequest {
     pod_name: String,
@@ -277,6 +371,83 @@ pub enum StateChanged {
     namespace: String,
     current_replicas: i32,
   },
+  // StatefulSets, DaemonSets, Jobs and CronJobs
+  WorkloadsUpdated {
+    kind: WorkloadKind,
+  },
+  WorkloadYamlLoaded {
+    kind: WorkloadKind,
+    name: String,
+    namespace: String,
+    yaml: String,
+  },
+  /// Request to open a workload with a specific tab
+  WorkloadTabRequest {
+    kind: WorkloadKind,
+    name: String,
+    namespace: String,
+    tab: WorkloadDetailTab,
+  },
+  /// Request to open scale dialog for a StatefulSet
+  WorkloadScaleRequest {
+    kind: WorkloadKind,
+    name: String,
+    namespace: String,
+    current_replicas: i32,
+  },
+  // ConfigMaps and Secrets
+  ConfigsUpdated {
+    kind: ConfigKind,
+  },
+  ConfigYamlLoaded {
+    kind: ConfigKind,
+    name: String,
+    namespace: String,
+    yaml: String,
+  },
+  /// Request to open a ConfigMap or Secret with a specific tab
+  ConfigTabRequest {
+    kind: ConfigKind,
+    name: String,
+    namespace: String,
+    tab: ConfigDetailTab,
+  },
+  // Ingresses
+  IngressesUpdated,
+  IngressYamlLoaded {
+    name: String,
+    namespace: String,
+    yaml: String,
+  },
+  /// Request to open an ingress with a specific tab
+  IngressTabRequest {
+    name: String,
+    namespace: String,
+    tab: IngressDetailTab,
+  },
+  // PersistentVolumeClaims, PersistentVolumes and StorageClasses
+  StorageUpdated,
+  /// Request to open a PersistentVolumeClaim in the storage view
+  VolumeClaimRequest {
+    name: String,
+    namespace: String,
+  },
+  // Nodes
+  NodesUpdated,
+  NodeYamlLoaded {
+    name: String,
+    yaml: String,
+  },
+  /// Request to open a node with a specific tab
+  NodeTabRequest {
+    name: String,
+    tab: NodeDetailTab,
+  },
+  // Events
+  /// Request to show the events view filtered to one object
+  EventsFilterRequest {
+    object: InvolvedObject,
+  },
 }
 
 /// Represents the load state of a resource
@@ -302,11 +473,25 @@ pub struct DockerState {
   pub pods: Vec<PodInfo>,
   pub services: Vec<ServiceInfo>,
   pub deployments: Vec<DeploymentInfo>,
+  /// StatefulSets, DaemonSets, Jobs and CronJobs by kind
+  pub workloads: HashMap<WorkloadKind, Vec<WorkloadInfo>>,
+  /// ConfigMaps and Secrets by kind
+  pub configs: HashMap<ConfigKind, Vec<ConfigInfo>>,
+  pub ingresses: Vec<IngressInfo>,
+  pub volume_claims: Vec<VolumeClaimInfo>,
+  /// Cluster-scoped, not filtered by namespace
+  pub persistent_volumes: Vec<PersistentVolumeInfo>,
+  /// Cluster-scoped, not filtered by namespace
+  pub storage_classes: Vec<StorageClassInfo>,
+  /// Cluster-scoped, with the pods of every namespace attached
+  pub nodes: Vec<NodeInfo>,
   pub namespaces: Vec<String>,
   pub selected_namespace: String,
   pub k8s_available: bool,
   /// Error message for K8s connectivity issues
   pub k8s_error: Option<String>,
+  /// Merged kubeconfig files, contexts, clusters and users
+  pub kubeconfig: KubeconfigOverview,
 
   // UI state
   pub current_view: CurrentView,
@@ -325,6 +510,12 @@ pub struct DockerState {
   pub pods_state: LoadState,
   pub services_state: LoadState,
   pub deployments_state: LoadState,
+  pub workloads_state: HashMap<WorkloadKind, LoadState>,
+  pub configs_state: HashMap<ConfigKind, LoadState>,
+  pub ingresses_state: LoadState,
+  /// Claims, volumes and classes load together
+  pub storage_state: LoadState,
+  pub nodes_state: LoadState,
   pub machines_state: LoadState,
 }
 
@@ -339,10 +530,18 @@ impl DockerState {
       pods: Vec::new(),
       services: Vec::new(),
       deployments: Vec::new(),
+      workloads: HashMap::new(),
+      configs: HashMap::new(),
+      ingresses: Vec::new(),
+      volume_claims: Vec::new(),
+      persistent_volumes: Vec::new(),
+      storage_classes: Vec::new(),
+      nodes: Vec::new(),
       namespaces: vec!["default".to_string()],
       selected_namespace: "default".to_string(),
       k8s_available: false,
       k8s_error: None,
+      kubeconfig: KubeconfigOverview::default(),
       current_view: CurrentView::default(),
       active_detail_tab: 0,
       selection: Selection::None,
@@ -355,6 +554,11 @@ impl DockerState {
       pods_state: LoadState::NotLoaded,
       services_state: LoadState::NotLoaded,
       deployments_state: LoadState::NotLoaded,
+      workloads_state: HashMap::new(),
+      configs_state: HashMap::new(),
+      ingresses_state: LoadState::NotLoaded,
+      storage_state: LoadState::NotLoaded,
+      nodes_state: LoadState::NotLoaded,
       machines_state: LoadState::NotLoaded,
     }
   }
@@ -434,6 +638,10 @@ impl DockerState {
     }
   }
 
+  pub fn set_kubeconfig(&mut self, kubeconfig: KubeconfigOverview) {
+    self.kubeconfig = kubeconfig;
+  }
+
   // Services (Kubernetes)
   pub fn set_services(&mut self, services: Vec<ServiceInfo>) {
     self.services = services;
@@ -476,6 +684,131 @@ impl DockerState {
       .find(|d| d.name == name && d.namespace == namespace)
   }
 
+  // Workloads (Kubernetes)
+  pub fn workloads(&self, kind: WorkloadKind) -> &[WorkloadInfo] {
+    self.workloads.get(&kind).map_or(&[], Vec::as_slice)
+  }
+
+  pub fn workloads_state(&self, kind: WorkloadKind) -> LoadState {
+    self.workloads_state.get(&kind).cloned().unwrap_or_default()
+  }
+
+  pub fn set_workloads(&mut self, kind: WorkloadKind, workloads: Vec<WorkloadInfo>) {
+    self.workloads.insert(kind, workloads);
+    self.workloads_state.insert(kind, LoadState::Loaded);
+  }
+
+  pub fn set_workloads_loading(&mut self, kind: WorkloadKind) {
+    self.workloads_state.insert(kind, LoadState::Loading);
+  }
+
+  pub fn set_workloads_error(&mut self, kind: WorkloadKind, error: String) {
+    self.workloads_state.insert(kind, LoadState::Error(error));
+  }
+
+  pub fn get_workload(&self, kind: WorkloadKind, name: &str, namespace: &str) -> Option<&WorkloadInfo> {
+    self
+      .workloads(kind)
+      .iter()
+      .find(|w| w.name == name && w.namespace == namespace)
+  }
+
+  // ConfigMaps and Secrets (Kubernetes)
+  pub fn configs(&self, kind: ConfigKind) -> &[ConfigInfo] {
+    self.configs.get(&kind).map_or(&[], Vec::as_slice)
+  }
+
+  pub fn configs_state(&self, kind: ConfigKind) -> LoadState {
+    self.configs_state.get(&kind).cloned().unwrap_or_default()
+  }
+
+  pub fn set_configs(&mut self, kind: ConfigKind, configs: Vec<ConfigInfo>) {
+    self.configs.insert(kind, configs);
+    self.configs_state.insert(kind, LoadState::Loaded);
+  }
+
+  pub fn set_configs_loading(&mut self, kind: ConfigKind) {
+    self.configs_state.insert(kind, LoadState::Loading);
+  }
+
+  pub fn set_configs_error(&mut self, kind: ConfigKind, error: String) {
+    self.configs_state.insert(kind, LoadState::Error(error));
+  }
+
+  pub fn get_config(&self, kind: ConfigKind, name: &str, namespace: &str) -> Option<&ConfigInfo> {
+    self
+      .configs(kind)
+      .iter()
+      .find(|c| c.name == name && c.namespace == namespace)
+  }
+
+  // Ingresses (Kubernetes)
+  pub fn set_ingresses(&mut self, ingresses: Vec<IngressInfo>) {
+    self.ingresses = ingresses;
+    self.ingresses_state = LoadState::Loaded;
+  }
+
+  pub fn set_ingresses_loading(&mut self) {
+    self.ingresses_state = LoadState::Loading;
+  }
+
+  pub fn set_ingresses_error(&mut self, error: String) {
+    self.ingresses_state = LoadState::Error(error);
+  }
+
+  pub fn get_ingress(&self, name: &str, namespace: &str) -> Option<&IngressInfo> {
+    self
+      .ingresses
+      .iter()
+      .find(|i| i.name == name && i.namespace == namespace)
+  }
+
+  // Storage (Kubernetes)
+  pub fn set_storage(
+    &mut self,
+    volume_claims: Vec<VolumeClaimInfo>,
+    persistent_volumes: Vec<PersistentVolumeInfo>,
+    storage_classes: Vec<StorageClassInfo>,
+  ) {
+    self.volume_claims = volume_claims;
+    self.persistent_volumes = persistent_volumes;
+    self.storage_classes = storage_classes;
+    self.storage_state = LoadState::Loaded;
+  }
+
+  pub fn set_storage_loading(&mut self) {
+    self.storage_state = LoadState::Loading;
+  }
+
+  pub fn set_storage_error(&mut self, error: String) {
+    self.storage_state = LoadState::Error(error);
+  }
+
+  pub fn get_volume_claim(&self, name: &str, namespace: &str) -> Option<&VolumeClaimInfo> {
+    self
+      .volume_claims
+      .iter()
+      .find(|c| c.name == name && c.namespace == namespace)
+  }
+
+  // Nodes (Kubernetes)
+  pub fn set_nodes(&mut self, nodes: Vec<NodeInfo>) {
+    self.nodes = nodes;
+    self.nodes_state = LoadState::Loaded;
+  }
+
+  pub fn set_nodes_loading(&mut self) {
+    self.nodes_state = LoadState::Loading;
+  }
+
+  pub fn set_nodes_error(&mut self, error: String) {
+    self.nodes_state = LoadState::Error(error);
+  }
+
+  pub fn get_node(&self, name: &str) -> Option<&NodeInfo> {
+    self.nodes.iter().find(|n| n.name == name)
+  }
+
   // Navigation
   pub fn set_view(&mut self, view: CurrentView) {
     self.current_view = view;
@@ -513,6 +846,15 @@ pub fn docker_state(cx: &App) -> Entity<DockerState> {
 mod tests {
   use super::super::app_state::CurrentView;
   use super::*;
+  use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+  fn metadata(name: &str, namespace: &str) -> ObjectMeta {
+    ObjectMeta {
+      name: Some(name.to_string()),
+      namespace: Some(namespace.to_string()),
+      ..Default::default()
+    }
+  }
 
   #[test]
   fn test_docker_state_initialization() {
@@ -601,6 +943,112 @@ mod tests {
     assert!(state.k8s_error.is_none());
   }
 
+  #[test]
+  fn test_docker_state_workloads() {
+    let mut state = DockerState::new();
+    assert_eq!(state.workloads_state(WorkloadKind::Job), LoadState::NotLoaded);
+    assert!(state.workloads(WorkloadKind::Job).is_empty());
+
+    state.set_workloads_loading(WorkloadKind::Job);
+    assert_eq!(state.workloads_state(WorkloadKind::Job), LoadState::Loading);
+    assert_eq!(state.workloads_state(WorkloadKind::CronJob), LoadState::NotLoaded);
+
+    state.set_workloads_error(WorkloadKind::Job, "forbidden".to_string());
+    assert_eq!(
+      state.workloads_state(WorkloadKind::Job),
+      LoadState::Error("forbidden".to_string())
+    );
+
+    let job = WorkloadInfo::from_job(&k8s_openapi::api::batch::v1::Job {
+      metadata: metadata("migrate", "web"),
+      ..Default::default()
+    });
+    state.set_workloads(WorkloadKind::Job, vec![job]);
+    assert_eq!(state.workloads_state(WorkloadKind::Job), LoadState::Loaded);
+    let found = state.get_workload(WorkloadKind::Job, "migrate", "web").unwrap();
+    assert_eq!(found.kind, WorkloadKind::Job);
+    assert!(state.get_workload(WorkloadKind::Job, "migrate", "default").is_none());
+    assert!(state.get_workload(WorkloadKind::Job, "seed", "web").is_none());
+    // Each kind has its own list
+    assert!(state.get_workload(WorkloadKind::CronJob, "migrate", "web").is_none());
+  }
+
+  #[test]
+  fn test_docker_state_configs() {
+    let mut state = DockerState::new();
+    assert_eq!(state.configs_state(ConfigKind::Secret), LoadState::NotLoaded);
+
+    state.set_configs_loading(ConfigKind::Secret);
+    assert_eq!(state.configs_state(ConfigKind::Secret), LoadState::Loading);
+    assert_eq!(state.configs_state(ConfigKind::ConfigMap), LoadState::NotLoaded);
+
+    let secret = ConfigInfo::from_secret(&k8s_openapi::api::core::v1::Secret {
+      metadata: metadata("db", "web"),
+      ..Default::default()
+    });
+    state.set_configs(ConfigKind::Secret, vec![secret]);
+    assert_eq!(state.configs_state(ConfigKind::Secret), LoadState::Loaded);
+    assert!(state.configs(ConfigKind::ConfigMap).is_empty());
+    let found = state.get_config(ConfigKind::Secret, "db", "web").unwrap();
+    assert_eq!(found.kind, ConfigKind::Secret);
+    assert!(state.get_config(ConfigKind::Secret, "db", "default").is_none());
+    assert!(state.get_config(ConfigKind::Secret, "cache", "web").is_none());
+    assert!(state.get_config(ConfigKind::ConfigMap, "db", "web").is_none());
+  }
+
+  #[test]
+  fn test_docker_state_storage() {
+    let mut state = DockerState::new();
+    assert_eq!(state.storage_state, LoadState::NotLoaded);
+    assert_eq!(state.ingresses_state, LoadState::NotLoaded);
+
+    state.set_storage_error("forbidden".to_string());
+    assert_eq!(state.storage_state, LoadState::Error("forbidden".to_string()));
+
+    let claim = VolumeClaimInfo::from_pvc(&k8s_openapi::api::core::v1::PersistentVolumeClaim {
+      metadata: metadata("data", "web"),
+      ..Default::default()
+    });
+    state.set_storage(vec![claim], Vec::new(), Vec::new());
+    assert_eq!(state.storage_state, LoadState::Loaded);
+    assert_eq!(state.get_volume_claim("data", "web").unwrap().name, "data");
+    assert!(state.get_volume_claim("data", "default").is_none());
+    assert!(state.get_volume_claim("logs", "web").is_none());
+
+    let ingress = IngressInfo::from_ingress(&k8s_openapi::api::networking::v1::Ingress {
+      metadata: metadata("site", "web"),
+      ..Default::default()
+    });
+    state.set_ingresses(vec![ingress]);
+    assert_eq!(state.ingresses_state, LoadState::Loaded);
+    assert_eq!(state.get_ingress("site", "web").unwrap().namespace, "web");
+    assert!(state.get_ingress("site", "default").is_none());
+    assert!(state.get_ingress("api", "web").is_none());
+  }
+
+  #[test]
+  fn test_docker_state_nodes() {
+    let mut state = DockerState::new();
+    assert_eq!(state.nodes_state, LoadState::NotLoaded);
+
+    state.set_nodes_loading();
+    assert_eq!(state.nodes_state, LoadState::Loading);
+
+    // Nodes are cluster-scoped, so the name alone finds them
+    let node = NodeInfo::from_node(&k8s_openapi::api::core::v1::Node {
+      metadata: ObjectMeta {
+        name: Some("colima".to_string()),
+        ..Default::default()
+      },
+      ..Default::default()
+    });
+    state.set_nodes(vec![node]);
+    assert_eq!(state.nodes_state, LoadState::Loaded);
+    let found = state.get_node("colima").unwrap();
+    assert!(!found.unschedulable);
+    assert!(state.get_node("worker-1").is_none());
+  }
+
   #[test]
   fn test_docker_state_namespaces() {
     let mut state = DockerState::new();
@@ -652,11 +1100,12 @@ mod tests {
 
   #[test]
   fn test_container_detail_tab() {
-    assert_eq!(ContainerDetailTab::ALL.len(), 6);
+    assert_eq!(ContainerDetailTab::ALL.len(), 7);
     assert_eq!(ContainerDetailTab::Info.label(), "Info");
     assert_eq!(ContainerDetailTab::Logs.label(), "Logs");
     assert_eq!(ContainerDetailTab::Processes.label(), "Processes");
     assert_eq!(ContainerDetailTab::Terminal.label(), "Terminal");
+    assert_eq!(ContainerDetailTab::Run.label(), "Run");
     assert_eq!(ContainerDetailTab::Files.label(), "Files");
     assert_eq!(ContainerDetailTab::Inspect.label(), "Inspect");
   }
@@ -675,11 +1124,12 @@ mod tests {
 
   #[test]
   fn test_pod_detail_tab() {
-    assert_eq!(PodDetailTab::ALL.len(), 5);
+    assert_eq!(PodDetailTab::ALL.len(), 6);
     assert_eq!(PodDetailTab::Info.label(), "Info");
     assert_eq!(PodDetailTab::Logs.label(), "Logs");
     assert_eq!(PodDetailTab::Terminal.label(), "Terminal");
     assert_eq!(PodDetailTab::Describe.label(), "Describe");
+    assert_eq!(PodDetailTab::Events.label(), "Events");
     assert_eq!(PodDetailTab::Yaml.label(), "YAML");
   }
 }
@@ -0,0 +1,602 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use futures::channel::mpsc::{UnboundedSender, unbounded};
+use gpui::{App, AppContext, Entity, EventEmitter, Global};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::kubernetes::PodInfo;
+
+/// Label linking a Deployment's pods to their ReplicaSet
+const POD_TEMPLATE_HASH_LABEL: &str = "pod-template-hash";
+
+/// Maximum number of events kept in memory and on disk
+pub const MAX_TIMELINE_EVENTS: usize = 5000;
+
+/// Where an event came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventSource {
+  Docker,
+  Kubernetes,
+}
+
+/// A single entry in the events timeline
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimelineEvent {
+  /// Stable identifier used to drop duplicates (e.g. replayed Kubernetes events)
+  pub id: String,
+  pub timestamp: DateTime<Utc>,
+  pub source: EventSource,
+  /// Resource kind (`container`, `image`, `Pod`, `Deployment`, ...)
+  pub kind: String,
+  /// Docker action or Kubernetes reason
+  pub action: String,
+  pub resource_id: String,
+  pub resource_name: String,
+  pub namespace: Option<String>,
+  /// Docker actor attributes or Kubernetes event source details
+  pub attributes: BTreeMap<String, String>,
+  pub message: Option<String>,
+  /// Kubernetes `Warning` events (and Docker `die`/`oom` actions)
+  pub is_warning: bool,
+}
+
+impl TimelineEvent {
+  /// Case-insensitive match against kind, action, name, namespace, message and attributes
+  pub fn matches_query(&self, query: &str) -> bool {
+    if query.is_empty() {
+      return true;
+    }
+    let query = query.to_lowercase();
+    let contains = |s: &str| s.to_lowercase().contains(&query);
+
+    contains(&self.kind)
+      || contains(&self.action)
+      || contains(&self.resource_name)
+      || contains(&self.resource_id)
+      || self.namespace.as_deref().is_some_and(contains)
+      || self.message.as_deref().is_some_and(contains)
+      || self.attributes.iter().any(|(k, v)| contains(k) || contains(v))
+  }
+
+  /// Attributes rendered as `key=value` pairs
+  pub fn display_attributes(&self) -> String {
+    self
+      .attributes
+      .iter()
+      .map(|(k, v)| format!("{k}={v}"))
+      .collect::<Vec<_>>()
+      .join(" ")
+  }
+
+  /// Identifier shared by every occurrence of the same Kubernetes `Event` object
+  pub fn series_id(&self) -> &str {
+    match self.source {
+      EventSource::Kubernetes => self.id.rsplit_once('-').map_or(self.id.as_str(), |(series, _)| series),
+      EventSource::Docker => &self.id,
+    }
+  }
+
+  /// How many times a Kubernetes event has been seen (`count` attribute)
+  pub fn count(&self) -> u32 {
+    self.attributes.get("count").and_then(|c| c.parse().ok()).unwrap_or(1)
+  }
+}
+
+/// Kubernetes object events are shown for, like `kubectl events --for kind/name`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvolvedObject {
+  pub kind: String,
+  pub name: String,
+  pub namespace: String,
+  /// ReplicaSets a Deployment owns; their events and their pods' events count as its own
+  pub replica_sets: Vec<String>,
+}
+
+impl InvolvedObject {
+  pub fn new(kind: impl Into<String>, name: impl Into<String>, namespace: impl Into<String>) -> Self {
+    Self {
+      kind: kind.into(),
+      name: name.into(),
+      namespace: namespace.into(),
+      replica_sets: Vec::new(),
+    }
+  }
+
+  /// A Deployment with the ReplicaSets its pods come from. A pod belongs to ReplicaSet
+  /// `name-<pod-template-hash>` when its name starts with that, which keeps out pods of
+  /// StatefulSets or Jobs that merely share the name prefix.
+  pub fn deployment(name: &str, namespace: &str, pods: &[PodInfo]) -> Self {
+    let mut replica_sets: Vec<String> = pods
+      .iter()
+      .filter(|p| p.namespace == namespace)
+      .filter_map(|p| {
+        let replica_set = format!("{name}-{}", p.labels.get(POD_TEMPLATE_HASH_LABEL)?);
+        p.name.starts_with(&format!("{replica_set}-")).then_some(replica_set)
+      })
+      .collect();
+    replica_sets.sort();
+    replica_sets.dedup();
+
+    Self {
+      replica_sets,
+      ..Self::new("Deployment", name, namespace)
+    }
+  }
+
+  /// Whether an event is about this object. Deployments also include events of the
+  /// ReplicaSets they own and of those ReplicaSets' pods, since a stalled rollout
+  /// usually surfaces there.
+  pub fn matches(&self, event: &TimelineEvent) -> bool {
+    if event.source != EventSource::Kubernetes || event.namespace.as_deref() != Some(self.namespace.as_str()) {
+      return false;
+    }
+    if event.kind == self.kind && event.resource_name == self.name {
+      return true;
+    }
+
+    self.replica_sets.iter().any(|replica_set| match event.kind.as_str() {
+      "ReplicaSet" => event.resource_name == *replica_set,
+      // Pods that are gone keep their events, so match `replica-set-suffix` by name
+      "Pod" => event
+        .resource_name
+        .strip_prefix(replica_set.as_str())
+        .and_then(|r| r.strip_prefix('-'))
+        .is_some_and(|suffix| !suffix.is_empty() && !suffix.contains('-')),
+      _ => false,
+    })
+  }
+}
+
+impl std::fmt::Display for InvolvedObject {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}/{} ({})", self.kind, self.name, self.namespace)
+  }
+}
+
+/// A journal write, planned on the UI thread and applied in order off it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JournalWrite {
+  Append(Vec<TimelineEvent>),
+  Rewrite(Vec<TimelineEvent>),
+}
+
+/// Append-only JSON lines file holding the most recent timeline events
+pub struct EventJournal {
+  path: PathBuf,
+  max_events: usize,
+  lines_on_disk: usize,
+}
+
+impl EventJournal {
+  pub fn new(path: PathBuf, max_events: usize) -> Self {
+    Self {
+      path,
+      max_events,
+      lines_on_disk: 0,
+    }
+  }
+
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  pub fn default_path() -> PathBuf {
+    let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+    home.join(".config").join("docker-ui").join("events.jsonl")
+  }
+
+  /// Load the newest `max_events` entries, skipping lines that fail to parse
+  pub fn load(&mut self) -> Vec<TimelineEvent> {
+    let Ok(content) = fs::read_to_string(&self.path) else {
+      return Vec::new();
+    };
+
+    let lines: Vec<&str> = content.lines().filter(|l| !l.trim().is_empty()).collect();
+    self.lines_on_disk = lines.len();
+
+    let skip = lines.len().saturating_sub(self.max_events);
+    lines[skip..]
+      .iter()
+      .filter_map(|line| match serde_json::from_str(line) {
+        Ok(event) => Some(event),
+        Err(e) => {
+          tracing::debug!("Skipping invalid event journal line: {e}");
+          None
+        }
+      })
+      .collect()
+  }
+
+  /// Plan appending events, compacting the file to `current` once it grows past twice the limit
+  pub fn plan_append<'a>(
+    &mut self,
+    new_events: Vec<TimelineEvent>,
+    current: impl Iterator<Item = &'a TimelineEvent>,
+  ) -> JournalWrite {
+    if self.lines_on_disk + new_events.len() > self.max_events * 2 {
+      return self.plan_rewrite(current);
+    }
+    self.lines_on_disk += new_events.len();
+    JournalWrite::Append(new_events)
+  }
+
+  /// Plan replacing the file contents with the given events
+  pub fn plan_rewrite<'a>(&mut self, events: impl Iterator<Item = &'a TimelineEvent>) -> JournalWrite {
+    let events: Vec<TimelineEvent> = events.cloned().collect();
+    self.lines_on_disk = events.len();
+    JournalWrite::Rewrite(events)
+  }
+
+  /// Apply a planned write to the journal file at `path`
+  pub fn apply(path: &Path, write: &JournalWrite) -> Result<()> {
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent)?;
+    }
+
+    match write {
+      JournalWrite::Append(events) => {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        for event in events {
+          writeln!(file, "{}", serde_json::to_string(event)?)?;
+        }
+      }
+      JournalWrite::Rewrite(events) => {
+        let mut content = String::new();
+        for event in events {
+          content.push_str(&serde_json::to_string(event)?);
+          content.push('\n');
+        }
+        fs::write(path, content)?;
+      }
+    }
+    Ok(())
+  }
+}
+
+/// Events emitted when the timeline changes
+#[derive(Debug, Clone)]
+pub enum EventsChanged {
+  Appended,
+  Cleared,
+}
+
+/// Global events timeline, newest entries last
+pub struct EventsState {
+  pub events: VecDeque<TimelineEvent>,
+  seen: HashSet<String>,
+  journal: EventJournal,
+  /// Background writer; without one (tests) journal writes are applied inline
+  journal_tx: Option<UnboundedSender<JournalWrite>>,
+}
+
+impl EventsState {
+  pub fn new(mut journal: EventJournal) -> Self {
+    let events: VecDeque<TimelineEvent> = journal.load().into();
+    let seen = events.iter().map(|e| e.id.clone()).collect();
+    Self {
+      events,
+      seen,
+      journal,
+      journal_tx: None,
+    }
+  }
+
+  /// Send journal writes to a background task instead of writing on the caller's thread
+  #[must_use]
+  pub fn with_writer(mut self, journal_tx: UnboundedSender<JournalWrite>) -> Self {
+    self.journal_tx = Some(journal_tx);
+    self
+  }
+
+  fn write_journal(&self, write: JournalWrite) {
+    if let Some(ref tx) = self.journal_tx {
+      let _ = tx.unbounded_send(write);
+    } else if let Err(e) = EventJournal::apply(self.journal.path(), &write) {
+      tracing::warn!("Failed to write event journal: {e}");
+    }
+  }
+
+  /// Add events to the timeline, ignoring ones already recorded. Returns the events that were new.
+  pub fn push_events(&mut self, incoming: Vec<TimelineEvent>) -> Vec<TimelineEvent> {
+    let mut added = Vec::new();
+    for event in incoming {
+      if self.seen.insert(event.id.clone()) {
+        added.push(event);
+      }
+    }
+    if added.is_empty() {
+      return added;
+    }
+
+    self.events.extend(added.iter().cloned());
+    while self.events.len() > MAX_TIMELINE_EVENTS {
+      if let Some(old) = self.events.pop_front() {
+        self.seen.remove(&old.id);
+      }
+    }
+
+    let write = self.journal.plan_append(added.clone(), self.events.iter());
+    self.write_journal(write);
+    added
+  }
+
+  /// Latest occurrence of each event about `object`, newest first
+  pub fn object_events(&self, object: &InvolvedObject, limit: usize) -> Vec<TimelineEvent> {
+    let mut series = HashSet::new();
+    self
+      .events
+      .iter()
+      .rev()
+      .filter(|e| object.matches(e))
+      .filter(|e| series.insert(e.series_id().to_string()))
+      .take(limit)
+      .cloned()
+      .collect()
+  }
+
+  pub fn clear(&mut self) {
+    self.events.clear();
+    self.seen.clear();
+    let write = self.journal.plan_rewrite(std::iter::empty());
+    self.write_journal(write);
+  }
+}
+
+impl EventEmitter<EventsChanged> for EventsState {}
+
+/// Global wrapper for events state
+struct GlobalEventsState(Entity<EventsState>);
+
+impl Global for GlobalEventsState {}
+
+pub fn init_events_state(cx: &mut App) {
+  let journal = EventJournal::new(EventJournal::default_path(), MAX_TIMELINE_EVENTS);
+  let path = journal.path().to_path_buf();
+
+  // A single consumer keeps appends and compactions in order
+  let (journal_tx, mut journal_rx) = unbounded::<JournalWrite>();
+  cx.background_executor()
+    .spawn(async move {
+      while let Some(write) = journal_rx.next().await {
+        if let Err(e) = EventJournal::apply(&path, &write) {
+          tracing::warn!("Failed to write event journal: {e}");
+        }
+      }
+    })
+    .detach();
+
+  let state = cx.new(|_cx| EventsState::new(journal).with_writer(journal_tx));
+  cx.set_global(GlobalEventsState(state));
+}
+
+pub fn events_state(cx: &App) -> Entity<EventsState> {
+  cx.global::<GlobalEventsState>().0.clone()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn event(id: &str, action: &str) -> TimelineEvent {
+    TimelineEvent {
+      id: id.to_string(),
+      timestamp: Utc::now(),
+      source: EventSource::Docker,
+      kind: "container".to_string(),
+      action: action.to_string(),
+      resource_id: "abc123".to_string(),
+      resource_name: "web".to_string(),
+      namespace: None,
+      attributes: BTreeMap::from([("image".to_string(), "nginx:latest".to_string())]),
+      message: None,
+      is_warning: false,
+    }
+  }
+
+  fn k8s_event(uid: &str, count: u32, kind: &str, name: &str, reason: &str) -> TimelineEvent {
+    let mut attributes = BTreeMap::new();
+    if count > 1 {
+      attributes.insert("count".to_string(), count.to_string());
+    }
+    TimelineEvent {
+      id: format!("k8s-{uid}-{count}"),
+      timestamp: Utc::now(),
+      source: EventSource::Kubernetes,
+      kind: kind.to_string(),
+      action: reason.to_string(),
+      resource_id: String::new(),
+      resource_name: name.to_string(),
+      namespace: Some("default".to_string()),
+      attributes,
+      message: None,
+      is_warning: reason == "BackOff",
+    }
+  }
+
+  fn temp_journal(name: &str, max: usize) -> EventJournal {
+    let path = std::env::temp_dir().join(format!("dockside-test-{name}-{}.jsonl", std::process::id()));
+    let _ = fs::remove_file(&path);
+    EventJournal::new(path, max)
+  }
+
+  #[test]
+  fn test_timeline_event_matches_query() {
+    let e = event("1", "start");
+    assert!(e.matches_query(""));
+    assert!(e.matches_query("START"));
+    assert!(e.matches_query("web"));
+    assert!(e.matches_query("nginx"));
+    assert!(!e.matches_query("postgres"));
+  }
+
+  #[test]
+  fn test_timeline_event_display_attributes() {
+    let mut e = event("1", "start");
+    e.attributes.insert("exitCode".to_string(), "0".to_string());
+    assert_eq!(e.display_attributes(), "exitCode=0 image=nginx:latest");
+  }
+
+  #[test]
+  fn test_event_journal_round_trip() {
+    let mut journal = temp_journal("round-trip", 10);
+    let events = vec![event("1", "create"), event("2", "start")];
+    let write = journal.plan_append(events.clone(), events.iter());
+    EventJournal::apply(journal.path(), &write).expect("append");
+
+    let mut reopened = EventJournal::new(journal.path.clone(), 10);
+    assert_eq!(reopened.load(), events);
+    let _ = fs::remove_file(&journal.path);
+  }
+
+  #[test]
+  fn test_event_journal_load_keeps_newest() {
+    let mut journal = temp_journal("newest", 100);
+    let events: Vec<_> = (0..5).map(|i| event(&i.to_string(), "start")).collect();
+    let write = journal.plan_append(events.clone(), events.iter());
+    EventJournal::apply(journal.path(), &write).expect("append");
+
+    let mut bounded = EventJournal::new(journal.path.clone(), 2);
+    let loaded = bounded.load();
+    assert_eq!(loaded.len(), 2);
+    assert_eq!(loaded[0].id, "3");
+    assert_eq!(loaded[1].id, "4");
+    let _ = fs::remove_file(&journal.path);
+  }
+
+  #[test]
+  fn test_event_journal_plans_compaction() {
+    let mut journal = temp_journal("compaction", 2);
+    let current: Vec<_> = (0..2).map(|i| event(&i.to_string(), "start")).collect();
+
+    let first = journal.plan_append(current.clone(), current.iter());
+    assert_eq!(first, JournalWrite::Append(current.clone()));
+    let second = journal.plan_append(current.clone(), current.iter());
+    assert!(matches!(second, JournalWrite::Append(_)));
+
+    // Past twice the limit the file is rewritten with only the current events
+    let third = journal.plan_append(vec![event("2", "stop")], current.iter());
+    assert_eq!(third, JournalWrite::Rewrite(current.clone()));
+    assert_eq!(journal.lines_on_disk, 2);
+  }
+
+  #[test]
+  fn test_events_state_dedupes_and_bounds() {
+    let journal = temp_journal("state", MAX_TIMELINE_EVENTS);
+    let path = journal.path.clone();
+    let mut state = EventsState::new(journal);
+
+    assert_eq!(
+      state.push_events(vec![event("a", "start"), event("a", "start")]).len(),
+      1
+    );
+    let added = state.push_events(vec![event("a", "start"), event("b", "stop")]);
+    assert_eq!(added.len(), 1);
+    assert_eq!(added[0].id, "b");
+    assert_eq!(state.events.len(), 2);
+
+    let many: Vec<_> = (0..MAX_TIMELINE_EVENTS)
+      .map(|i| event(&format!("n{i}"), "start"))
+      .collect();
+    state.push_events(many);
+    assert_eq!(state.events.len(), MAX_TIMELINE_EVENTS);
+    assert_eq!(state.events.front().map(|e| e.id.as_str()), Some("n0"));
+
+    state.clear();
+    assert!(state.events.is_empty());
+    let _ = fs::remove_file(&path);
+  }
+
+  #[test]
+  fn test_timeline_event_series() {
+    let e = k8s_event("1f2e-3d4c", 7, "Pod", "web", "BackOff");
+    assert_eq!(e.series_id(), "k8s-1f2e-3d4c");
+    assert_eq!(e.count(), 7);
+
+    let d = event("abc-1", "start");
+    assert_eq!(d.series_id(), "abc-1");
+    assert_eq!(d.count(), 1);
+  }
+
+  #[test]
+  fn test_involved_object_matches() {
+    use k8s_openapi::api::core::v1::Pod;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    let pod = |name: &str, hash: Option<&str>| {
+      PodInfo::from_pod(&Pod {
+        metadata: ObjectMeta {
+          name: Some(name.to_string()),
+          namespace: Some("default".to_string()),
+          labels: hash.map(|h| BTreeMap::from([(POD_TEMPLATE_HASH_LABEL.to_string(), h.to_string())])),
+          ..Default::default()
+        },
+        ..Default::default()
+      })
+    };
+    let pods = vec![
+      pod("web-7d9f8b-x2k4p", Some("7d9f8b")),
+      // StatefulSet and Job pods share the prefix but have no template hash
+      pod("web-db-0", None),
+      pod("web-migrate-abcde", None),
+      // A pod of Deployment `web-api`
+      pod("web-api-5c6d7e-q8r9s", Some("5c6d7e")),
+    ];
+
+    let deployment = InvolvedObject::deployment("web", "default", &pods);
+    assert_eq!(deployment.replica_sets, vec!["web-7d9f8b".to_string()]);
+    assert!(deployment.matches(&k8s_event("a", 1, "Deployment", "web", "ScalingReplicaSet")));
+    assert!(deployment.matches(&k8s_event("b", 1, "ReplicaSet", "web-7d9f8b", "SuccessfulCreate")));
+    assert!(deployment.matches(&k8s_event("c", 1, "Pod", "web-7d9f8b-x2k4p", "BackOff")));
+    // A pod of the same ReplicaSet that is already gone
+    assert!(deployment.matches(&k8s_event("c2", 1, "Pod", "web-7d9f8b-m3n4q", "Killing")));
+    assert!(!deployment.matches(&k8s_event("d", 1, "Pod", "web-db-0", "BackOff")));
+    assert!(!deployment.matches(&k8s_event("d2", 1, "Pod", "web-migrate-abcde", "BackOff")));
+    assert!(!deployment.matches(&k8s_event("d3", 1, "Pod", "web-api-5c6d7e-q8r9s", "BackOff")));
+    assert!(!deployment.matches(&k8s_event("d4", 1, "ReplicaSet", "web-5c6d7e", "SuccessfulCreate")));
+    assert!(!deployment.matches(&k8s_event("e", 1, "Deployment", "web-api", "ScalingReplicaSet")));
+    assert!(!deployment.matches(&event("f", "start")));
+
+    let mut other_ns = k8s_event("g", 1, "Deployment", "web", "ScalingReplicaSet");
+    other_ns.namespace = Some("staging".to_string());
+    assert!(!deployment.matches(&other_ns));
+
+    // Without pods to resolve ownership, only the Deployment's own events match
+    let bare = InvolvedObject::new("Deployment", "web", "default");
+    assert!(bare.matches(&k8s_event("a", 1, "Deployment", "web", "ScalingReplicaSet")));
+    assert!(!bare.matches(&k8s_event("c", 1, "Pod", "web-7d9f8b-x2k4p", "BackOff")));
+
+    let pod = InvolvedObject::new("Pod", "web-7d9f8b-x2k4p", "default");
+    assert!(pod.matches(&k8s_event("c", 1, "Pod", "web-7d9f8b-x2k4p", "BackOff")));
+    assert!(!pod.matches(&k8s_event("b", 1, "ReplicaSet", "web-7d9f8b", "SuccessfulCreate")));
+    assert_eq!(pod.to_string(), "Pod/web-7d9f8b-x2k4p (default)");
+  }
+
+  #[test]
+  fn test_events_state_object_events() {
+    let journal = temp_journal("object-events", MAX_TIMELINE_EVENTS);
+    let path = journal.path.clone();
+    let mut state = EventsState::new(journal);
+
+    state.push_events(vec![
+      k8s_event("a", 1, "Pod", "web", "Scheduled"),
+      k8s_event("b", 1, "Pod", "web", "BackOff"),
+      k8s_event("b", 2, "Pod", "web", "BackOff"),
+      k8s_event("c", 1, "Pod", "db", "Pulled"),
+    ]);
+
+    let object = InvolvedObject::new("Pod", "web", "default");
+    let events = state.object_events(&object, 10);
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].id, "k8s-b-2");
+    assert_eq!(events[1].id, "k8s-a-1");
+    assert_eq!(state.object_events(&object, 1).len(), 1);
+
+    state.clear();
+    let _ = fs::remove_file(&path);
+  }
+}
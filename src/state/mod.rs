@@ -1,7 +1,9 @@
 mod app_state;
 mod docker_state;
+mod events;
 mod settings;
 
 pub use app_state::*;
 pub use docker_state::*;
+pub use events::*;
 pub use settings::*;
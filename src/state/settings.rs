@@ -1,8 +1,13 @@
 use anyhow::Result;
+use chrono::{DateTime, TimeDelta, Utc};
 use gpui::{App, AppContext, Entity, EventEmitter, Global};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::docker::{HelperConfig, PinnedResource, Protection, PruneKind};
+use crate::kubernetes::KubeconfigSelection;
 
 /// Available themes (matching themes in themes/ directory JSON files)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -215,6 +220,298 @@ impl ExternalEditor {
   }
 }
 
+/// A compose file or folder registered by the user, with per-project command options
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComposeProjectConfig {
+  /// Compose file or directory containing one
+  pub path: String,
+  /// Project name override (empty derives it from the file or directory)
+  #[serde(default)]
+  pub project_name: String,
+  /// Env file passed with `--env-file` (empty for the default `.env`)
+  #[serde(default)]
+  pub env_file: String,
+  /// Profiles enabled with `--profile`
+  #[serde(default)]
+  pub profiles: Vec<String>,
+}
+
+/// A saved command that can be run inside containers of a given image
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedCommand {
+  pub name: String,
+  /// Image repository the command belongs to (tag and digest are ignored)
+  pub image: String,
+  pub command: String,
+  #[serde(default)]
+  pub user: String,
+  #[serde(default)]
+  pub working_dir: String,
+  /// Environment variables, one `KEY=VALUE` per line
+  #[serde(default)]
+  pub env: String,
+}
+
+impl SavedCommand {
+  /// Strip the tag and digest from an image reference, keeping registry ports intact
+  pub fn image_repository(image: &str) -> &str {
+    let image = image.split('@').next().unwrap_or(image);
+    match image.rfind(':') {
+      Some(idx) if !image[idx + 1..].contains('/') => &image[..idx],
+      _ => image,
+    }
+  }
+
+  pub fn matches_image(&self, image: &str) -> bool {
+    Self::image_repository(&self.image) == Self::image_repository(image)
+  }
+}
+
+/// Crash and restart-loop notification settings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationSettings {
+  /// Send desktop notifications for crashes
+  pub enabled: bool,
+  /// Number of container exits that counts as a restart loop
+  pub restart_loop_threshold: usize,
+  /// Window (in minutes) in which exits are counted
+  pub restart_loop_window_minutes: u64,
+  /// Resource names that never notify; `*` matches any characters
+  pub muted_resources: Vec<String>,
+}
+
+impl Default for NotificationSettings {
+  fn default() -> Self {
+    Self {
+      enabled: true,
+      restart_loop_threshold: 3,
+      restart_loop_window_minutes: 5,
+      muted_resources: Vec::new(),
+    }
+  }
+}
+
+impl NotificationSettings {
+  /// Whether a resource is muted. Rules match the bare name or `namespace/name`.
+  pub fn is_muted(&self, name: &str, namespace: Option<&str>) -> bool {
+    let qualified = namespace.map(|ns| format!("{ns}/{name}"));
+    self.muted_resources.iter().any(|rule| {
+      let rule = rule.trim();
+      !rule.is_empty() && (glob_match(rule, name) || qualified.as_deref().is_some_and(|q| glob_match(rule, q)))
+    })
+  }
+}
+
+/// Volume backup location and schedule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackupSettings {
+  /// Directory backups are written to (empty for `~/dockside-backups`)
+  pub directory: String,
+  /// Volumes backed up automatically
+  pub scheduled_volumes: Vec<String>,
+  /// Hours between scheduled backups of a volume
+  pub interval_hours: u64,
+  /// Scheduled backups kept per volume; older ones are deleted
+  pub keep_last: usize,
+}
+
+impl Default for BackupSettings {
+  fn default() -> Self {
+    Self {
+      directory: String::new(),
+      scheduled_volumes: Vec::new(),
+      interval_hours: 24,
+      keep_last: 7,
+    }
+  }
+}
+
+impl BackupSettings {
+  pub fn backup_dir(&self) -> PathBuf {
+    let directory = self.directory.trim();
+    let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+    if directory.is_empty() {
+      home.join("dockside-backups")
+    } else if let Some(rest) = directory.strip_prefix("~/") {
+      home.join(rest)
+    } else {
+      PathBuf::from(directory)
+    }
+  }
+
+  pub fn is_scheduled(&self, volume: &str) -> bool {
+    self.scheduled_volumes.iter().any(|v| v == volume)
+  }
+}
+
+/// Helper containers used to browse and edit volumes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VolumeHelperSettings {
+  /// Image for helper containers (empty to use a local alpine/busybox or pull alpine)
+  pub image: String,
+  /// Minutes a helper may sit unused before it is removed
+  pub idle_minutes: u64,
+}
+
+impl Default for VolumeHelperSettings {
+  fn default() -> Self {
+    Self {
+      image: String::new(),
+      idle_minutes: 5,
+    }
+  }
+}
+
+impl VolumeHelperSettings {
+  pub fn helper_config(&self) -> HelperConfig {
+    HelperConfig {
+      image: self.image.trim().to_string(),
+      idle_timeout: Duration::from_secs(self.idle_minutes.max(1) * 60),
+    }
+  }
+}
+
+/// Kubeconfig files and the context used in-app
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KubernetesSettings {
+  /// Kubeconfig files merged after the ones in `KUBECONFIG`
+  pub kubeconfig_files: Vec<String>,
+  /// Context used in-app (empty follows the kubeconfig's `current-context`)
+  pub context: String,
+}
+
+impl KubernetesSettings {
+  pub fn selection(&self) -> KubeconfigSelection {
+    let context = self.context.trim();
+    KubeconfigSelection {
+      extra_files: self
+        .kubeconfig_files
+        .iter()
+        .map(|f| f.trim())
+        .filter(|f| !f.is_empty())
+        .map(PathBuf::from)
+        .collect(),
+      context: (!context.is_empty()).then(|| context.to_string()),
+    }
+  }
+}
+
+/// What sets a cleanup rule off
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CleanupTrigger {
+  /// On a fixed schedule
+  Every { hours: u64 },
+  /// When the Colima VM disk is at least this full
+  DiskAbove { percent: u8 },
+  /// When what the rule prunes takes more than this many gigabytes
+  SizeAbove { gigabytes: u64 },
+}
+
+impl CleanupTrigger {
+  pub fn describe(self) -> String {
+    match self {
+      CleanupTrigger::Every { hours: 24 } => "daily".to_string(),
+      CleanupTrigger::Every { hours } if hours % 24 == 0 => format!("every {} days", hours / 24),
+      CleanupTrigger::Every { hours } => format!("every {hours}h"),
+      CleanupTrigger::DiskAbove { percent } => format!("when the VM disk is over {percent}% full"),
+      CleanupTrigger::SizeAbove { gigabytes } => format!("when over {gigabytes} GB"),
+    }
+  }
+
+  /// Threshold in bytes for `SizeAbove`
+  pub fn size_limit(self) -> Option<u64> {
+    match self {
+      CleanupTrigger::SizeAbove { gigabytes } => Some(gigabytes.saturating_mul(1_000_000_000)),
+      _ => None,
+    }
+  }
+}
+
+/// A background prune of one kind of resource
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CleanupRule {
+  /// Unique among rules
+  pub name: String,
+  pub kind: PruneKind,
+  /// Prune filters, e.g. `until=72h label!=keep=true`. Docker doesn't record when an image was
+  /// last used, so for images `until` is their build time and "not used in N days" can't be expressed.
+  #[serde(default)]
+  pub filters: String,
+  /// For images: only remove untagged ones
+  #[serde(default)]
+  pub dangling_only: bool,
+  pub trigger: CleanupTrigger,
+  #[serde(default)]
+  pub paused: bool,
+  #[serde(default)]
+  pub last_run: Option<DateTime<Utc>>,
+}
+
+impl CleanupRule {
+  /// Threshold rules wait this long between runs, so a prune that can't get under the
+  /// threshold doesn't run on every check
+  const THRESHOLD_COOLDOWN_HOURS: u64 = 6;
+
+  /// Whether enough time has passed since the last run. Threshold rules also need their
+  /// measurement to be over the threshold.
+  pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+    if self.paused {
+      return false;
+    }
+    let hours = match self.trigger {
+      CleanupTrigger::Every { hours } => hours.max(1),
+      _ => Self::THRESHOLD_COOLDOWN_HOURS,
+    };
+    // Hand-edited settings can hold any hours; past chrono's range means never again
+    let wait = i64::try_from(hours)
+      .ok()
+      .and_then(TimeDelta::try_hours)
+      .unwrap_or(TimeDelta::MAX);
+    self.last_run.is_none_or(|last| now - last >= wait)
+  }
+
+  /// One line summary, e.g. `Containers (until=72h label!=keep=true) daily`
+  pub fn describe(&self) -> String {
+    let mut what = self.kind.label().to_string();
+    if self.kind == PruneKind::Images && self.dangling_only {
+      what = "Dangling images".to_string();
+    }
+    let filters = self.filters.trim();
+    if filters.is_empty() {
+      format!("{what} {}", self.trigger.describe())
+    } else {
+      format!("{what} ({filters}) {}", self.trigger.describe())
+    }
+  }
+}
+
+/// Minimal glob matching where `*` matches any run of characters
+fn glob_match(pattern: &str, text: &str) -> bool {
+  let parts: Vec<&str> = pattern.split('*').collect();
+  if parts.len() == 1 {
+    return pattern == text;
+  }
+
+  let first = parts[0];
+  let last = parts[parts.len() - 1];
+  if !text.starts_with(first) || text.len() < first.len() + last.len() || !text.ends_with(last) {
+    return false;
+  }
+
+  let mut rest = &text[first.len()..text.len() - last.len()];
+  for part in &parts[1..parts.len() - 1] {
+    match rest.find(part) {
+      Some(idx) => rest = &rest[idx + part.len()..],
+      None => return false,
+    }
+  }
+  true
+}
+
 /// Application settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppSettings {
@@ -242,6 +539,30 @@ pub struct AppSettings {
   pub terminal_scrollback_lines: usize,
   /// External editor for opening files
   pub external_editor: ExternalEditor,
+  /// Saved exec commands, keyed by image
+  #[serde(default)]
+  pub saved_commands: Vec<SavedCommand>,
+  /// Crash, OOM and restart-loop notifications
+  #[serde(default)]
+  pub notifications: NotificationSettings,
+  /// Registered compose files and folders
+  #[serde(default)]
+  pub compose_projects: Vec<ComposeProjectConfig>,
+  /// Volume backup location and schedule
+  #[serde(default)]
+  pub backups: BackupSettings,
+  /// Helper containers for volume file operations
+  #[serde(default)]
+  pub volume_helper: VolumeHelperSettings,
+  /// Scheduled and threshold-triggered prunes
+  #[serde(default)]
+  pub cleanup_rules: Vec<CleanupRule>,
+  /// Resources kept out of prunes and behind typed confirmation, on top of labelled ones
+  #[serde(default)]
+  pub pinned_resources: Vec<PinnedResource>,
+  /// Kubeconfig files and in-app context
+  #[serde(default)]
+  pub kubernetes: KubernetesSettings,
 }
 
 impl Default for AppSettings {
@@ -259,6 +580,14 @@ impl Default for AppSettings {
       terminal_cursor_blink: true,
       terminal_scrollback_lines: 10000,
       external_editor: ExternalEditor::default(),
+      saved_commands: Vec::new(),
+      notifications: NotificationSettings::default(),
+      compose_projects: Vec::new(),
+      backups: BackupSettings::default(),
+      volume_helper: VolumeHelperSettings::default(),
+      cleanup_rules: Vec::new(),
+      pinned_resources: Vec::new(),
+      kubernetes: KubernetesSettings::default(),
     }
   }
 }
@@ -283,6 +612,40 @@ impl AppSettings {
     Self::default()
   }
 
+  /// Saved commands applicable to containers of the given image
+  pub fn saved_commands_for_image(&self, image: &str) -> Vec<SavedCommand> {
+    self
+      .saved_commands
+      .iter()
+      .filter(|c| c.matches_image(image))
+      .cloned()
+      .collect()
+  }
+
+  pub fn protection(&self) -> Protection {
+    Protection::new(self.pinned_resources.clone())
+  }
+
+  pub fn is_pinned(&self, kind: PruneKind, name: &str) -> bool {
+    self.pinned_resources.iter().any(|p| p.kind == kind && p.name == name)
+  }
+
+  /// Pin or unpin a resource, returning whether anything changed
+  pub fn set_pinned(&mut self, kind: PruneKind, name: &str, pinned: bool) -> bool {
+    if pinned == self.is_pinned(kind, name) {
+      return false;
+    }
+    if pinned {
+      self.pinned_resources.push(PinnedResource {
+        kind,
+        name: name.to_string(),
+      });
+    } else {
+      self.pinned_resources.retain(|p| p.kind != kind || p.name != name);
+    }
+    true
+  }
+
   pub fn save(&self) -> Result<()> {
     let path = Self::config_path();
     if let Some(parent) = path.parent() {
@@ -431,6 +794,39 @@ mod tests {
       terminal_cursor_blink: false,
       terminal_scrollback_lines: 5000,
       external_editor: ExternalEditor::Cursor,
+      saved_commands: vec![],
+      notifications: NotificationSettings {
+        enabled: false,
+        ..NotificationSettings::default()
+      },
+      compose_projects: vec![ComposeProjectConfig {
+        path: "~/src/shop".to_string(),
+        project_name: String::new(),
+        env_file: ".env.local".to_string(),
+        profiles: vec!["debug".to_string()],
+      }],
+      backups: BackupSettings {
+        scheduled_volumes: vec!["pg-data".to_string()],
+        ..BackupSettings::default()
+      },
+      volume_helper: VolumeHelperSettings::default(),
+      cleanup_rules: vec![CleanupRule {
+        name: "Old containers".to_string(),
+        kind: PruneKind::Containers,
+        filters: "until=72h".to_string(),
+        dangling_only: false,
+        trigger: CleanupTrigger::Every { hours: 24 },
+        paused: false,
+        last_run: None,
+      }],
+      pinned_resources: vec![PinnedResource {
+        kind: PruneKind::Volumes,
+        name: "pg-data".to_string(),
+      }],
+      kubernetes: KubernetesSettings {
+        kubeconfig_files: vec!["~/.kube/staging.yaml".to_string()],
+        context: "staging".to_string(),
+      },
     };
 
     assert_eq!(settings.theme, ThemeName::GruvboxDark);
@@ -438,6 +834,239 @@ mod tests {
     assert_eq!(settings.default_colima_profile, "dev");
     assert_eq!(settings.container_refresh_interval, 10);
     assert_eq!(settings.external_editor, ExternalEditor::Cursor);
+    assert!(!settings.notifications.enabled);
+    assert_eq!(settings.compose_projects[0].profiles, vec!["debug"]);
+    assert!(settings.backups.is_scheduled("pg-data"));
+    assert_eq!(settings.cleanup_rules[0].kind, PruneKind::Containers);
+    assert!(settings.is_pinned(PruneKind::Volumes, "pg-data"));
+  }
+
+  #[test]
+  fn test_set_pinned() {
+    let mut settings = AppSettings::default();
+    assert!(settings.set_pinned(PruneKind::Containers, "db", true));
+    assert!(!settings.set_pinned(PruneKind::Containers, "db", true));
+    assert!(settings.is_pinned(PruneKind::Containers, "db"));
+    assert!(!settings.is_pinned(PruneKind::Volumes, "db"));
+    assert!(settings.protection().is_pinned(PruneKind::Containers, "f00", &["db"]));
+
+    assert!(settings.set_pinned(PruneKind::Containers, "db", false));
+    assert!(settings.pinned_resources.is_empty());
+  }
+
+  fn cleanup_rule(trigger: CleanupTrigger) -> CleanupRule {
+    CleanupRule {
+      name: "rule".to_string(),
+      kind: PruneKind::Images,
+      filters: String::new(),
+      dangling_only: false,
+      trigger,
+      paused: false,
+      last_run: None,
+    }
+  }
+
+  #[test]
+  fn test_cleanup_rule_defaults_missing_fields() {
+    let rule: CleanupRule =
+      serde_json::from_str(r#"{"name":"cache","kind":"BuildCache","trigger":{"SizeAbove":{"gigabytes":10}}}"#)
+        .expect("Failed to deserialize");
+    assert!(rule.filters.is_empty());
+    assert!(!rule.paused);
+    assert!(rule.last_run.is_none());
+    assert_eq!(rule.trigger.size_limit(), Some(10_000_000_000));
+
+    let settings: AppSettings = serde_json::from_str("{}").expect("Failed to deserialize");
+    assert!(settings.cleanup_rules.is_empty());
+  }
+
+  #[test]
+  fn test_cleanup_rule_is_due() {
+    let now = Utc::now();
+    let mut rule = cleanup_rule(CleanupTrigger::Every { hours: 24 });
+    assert!(rule.is_due(now));
+
+    rule.last_run = Some(now - chrono::Duration::hours(23));
+    assert!(!rule.is_due(now));
+    rule.last_run = Some(now - chrono::Duration::hours(24));
+    assert!(rule.is_due(now));
+
+    rule.paused = true;
+    assert!(!rule.is_due(now));
+
+    // Threshold rules use a cooldown instead of a schedule
+    let mut rule = cleanup_rule(CleanupTrigger::DiskAbove { percent: 80 });
+    rule.last_run = Some(now - chrono::Duration::hours(1));
+    assert!(!rule.is_due(now));
+    rule.last_run = Some(now - chrono::Duration::hours(6));
+    assert!(rule.is_due(now));
+
+    // Out of chrono's range waits forever instead of panicking
+    let mut rule = cleanup_rule(CleanupTrigger::Every { hours: u64::MAX / 2 });
+    rule.last_run = Some(now - chrono::Duration::days(365));
+    assert!(!rule.is_due(now));
+  }
+
+  #[test]
+  fn test_cleanup_rule_describe() {
+    let mut rule = cleanup_rule(CleanupTrigger::Every { hours: 24 });
+    rule.filters = "until=720h label!=keep=true".to_string();
+    assert_eq!(rule.describe(), "Images (until=720h label!=keep=true) daily");
+
+    rule.dangling_only = true;
+    rule.filters.clear();
+    rule.trigger = CleanupTrigger::Every { hours: 72 };
+    assert_eq!(rule.describe(), "Dangling images every 3 days");
+
+    assert_eq!(CleanupTrigger::Every { hours: 6 }.describe(), "every 6h");
+    assert_eq!(
+      CleanupTrigger::SizeAbove { gigabytes: 10 }.describe(),
+      "when over 10 GB"
+    );
+  }
+
+  #[test]
+  fn test_app_settings_missing_saved_commands_defaults_to_empty() {
+    let mut value = serde_json::to_value(AppSettings::default()).expect("Failed to serialize");
+    value.as_object_mut().expect("object").remove("saved_commands");
+    let settings: AppSettings = serde_json::from_value(value).expect("Failed to deserialize");
+    assert!(settings.saved_commands.is_empty());
+  }
+
+  #[test]
+  fn test_notification_settings_default() {
+    let notifications = NotificationSettings::default();
+    assert!(notifications.enabled);
+    assert_eq!(notifications.restart_loop_threshold, 3);
+    assert_eq!(notifications.restart_loop_window_minutes, 5);
+    assert!(notifications.muted_resources.is_empty());
+
+    let partial: NotificationSettings = serde_json::from_str(r#"{"enabled": false}"#).expect("Failed to deserialize");
+    assert!(!partial.enabled);
+    assert_eq!(partial.restart_loop_threshold, 3);
+  }
+
+  #[test]
+  fn test_notification_settings_is_muted() {
+    let notifications = NotificationSettings {
+      muted_resources: vec![
+        "flaky-worker".to_string(),
+        "test-*".to_string(),
+        "kube-system/*".to_string(),
+      ],
+      ..NotificationSettings::default()
+    };
+
+    assert!(notifications.is_muted("flaky-worker", None));
+    assert!(notifications.is_muted("test-db", None));
+    assert!(notifications.is_muted("coredns-abc", Some("kube-system")));
+    assert!(!notifications.is_muted("web", None));
+    assert!(!notifications.is_muted("coredns-abc", Some("default")));
+    assert!(!notifications.is_muted("my-test-db", None));
+  }
+
+  #[test]
+  fn test_backup_settings_backup_dir() {
+    let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
+    let defaults = BackupSettings::default();
+    assert_eq!(defaults.backup_dir(), home.join("dockside-backups"));
+    assert_eq!(defaults.keep_last, 7);
+
+    let custom = BackupSettings {
+      directory: "~/backups/docker".to_string(),
+      ..BackupSettings::default()
+    };
+    assert_eq!(custom.backup_dir(), home.join("backups/docker"));
+
+    let absolute = BackupSettings {
+      directory: "/mnt/backups".to_string(),
+      ..BackupSettings::default()
+    };
+    assert_eq!(absolute.backup_dir(), PathBuf::from("/mnt/backups"));
+  }
+
+  #[test]
+  fn test_volume_helper_settings_config() {
+    let config = VolumeHelperSettings::default().helper_config();
+    assert!(config.image.is_empty());
+    assert_eq!(config.idle_timeout, Duration::from_secs(300));
+
+    let custom = VolumeHelperSettings {
+      image: " busybox:1.36 ".to_string(),
+      idle_minutes: 0,
+    };
+    let config = custom.helper_config();
+    assert_eq!(config.image, "busybox:1.36");
+    assert_eq!(config.idle_timeout, Duration::from_secs(60));
+  }
+
+  #[test]
+  fn test_kubernetes_settings_selection() {
+    assert!(KubernetesSettings::default().selection().is_default());
+
+    let settings = KubernetesSettings {
+      kubeconfig_files: vec![" ~/.kube/work.yaml ".to_string(), String::new()],
+      context: " work ".to_string(),
+    };
+    let selection = settings.selection();
+    assert_eq!(selection.extra_files, vec![PathBuf::from("~/.kube/work.yaml")]);
+    assert_eq!(selection.context.as_deref(), Some("work"));
+  }
+
+  #[test]
+  fn test_glob_match() {
+    assert!(glob_match("web", "web"));
+    assert!(!glob_match("web", "web-1"));
+    assert!(glob_match("web-*", "web-1"));
+    assert!(glob_match("*-worker", "queue-worker"));
+    assert!(glob_match("a*b*c", "a-x-b-y-c"));
+    assert!(!glob_match("a*b*c", "a-x-c"));
+    assert!(glob_match("*", "anything"));
+  }
+
+  #[test]
+  fn test_saved_command_image_repository() {
+    assert_eq!(SavedCommand::image_repository("nginx"), "nginx");
+    assert_eq!(SavedCommand::image_repository("nginx:1.27"), "nginx");
+    assert_eq!(
+      SavedCommand::image_repository("localhost:5000/app"),
+      "localhost:5000/app"
+    );
+    assert_eq!(
+      SavedCommand::image_repository("localhost:5000/app:dev"),
+      "localhost:5000/app"
+    );
+    assert_eq!(SavedCommand::image_repository("redis@sha256:abc"), "redis");
+  }
+
+  #[test]
+  fn test_saved_commands_for_image() {
+    let migrate = SavedCommand {
+      name: "Migrate".to_string(),
+      image: "myapp:latest".to_string(),
+      command: "php artisan migrate".to_string(),
+      user: "www-data".to_string(),
+      working_dir: "/var/www".to_string(),
+      env: String::new(),
+    };
+    let settings = AppSettings {
+      saved_commands: vec![
+        migrate.clone(),
+        SavedCommand {
+          name: "Flush".to_string(),
+          image: "redis".to_string(),
+          command: "redis-cli flushall".to_string(),
+          user: String::new(),
+          working_dir: String::new(),
+          env: String::new(),
+        },
+      ],
+      ..AppSettings::default()
+    };
+
+    assert_eq!(settings.saved_commands_for_image("myapp:v2"), vec![migrate]);
+    assert_eq!(settings.saved_commands_for_image("redis:7").len(), 1);
+    assert!(settings.saved_commands_for_image("postgres").is_empty());
   }
 
   #[test]
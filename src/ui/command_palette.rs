@@ -180,6 +180,62 @@ impl CommandPalette {
         icon: IconName::Copy,
         action: PaletteAction::Navigate(CurrentView::Deployments),
       },
+      PaletteCommand {
+        id: "nav-statefulsets",
+        label: "Go to StatefulSets",
+        shortcut: None,
+        category: "Navigation",
+        icon: IconName::Copy,
+        action: PaletteAction::Navigate(CurrentView::StatefulSets),
+      },
+      PaletteCommand {
+        id: "nav-daemonsets",
+        label: "Go to DaemonSets",
+        shortcut: None,
+        category: "Navigation",
+        icon: IconName::Copy,
+        action: PaletteAction::Navigate(CurrentView::DaemonSets),
+      },
+      PaletteCommand {
+        id: "nav-jobs",
+        label: "Go to Jobs",
+        shortcut: None,
+        category: "Navigation",
+        icon: IconName::CircleCheck,
+        action: PaletteAction::Navigate(CurrentView::Jobs),
+      },
+      PaletteCommand {
+        id: "nav-cronjobs",
+        label: "Go to CronJobs",
+        shortcut: None,
+        category: "Navigation",
+        icon: IconName::Calendar,
+        action: PaletteAction::Navigate(CurrentView::CronJobs),
+      },
+      PaletteCommand {
+        id: "nav-configmaps",
+        label: "Go to ConfigMaps",
+        shortcut: None,
+        category: "Navigation",
+        icon: IconName::Settings,
+        action: PaletteAction::Navigate(CurrentView::ConfigMaps),
+      },
+      PaletteCommand {
+        id: "nav-secrets",
+        label: "Go to Secrets",
+        shortcut: None,
+        category: "Navigation",
+        icon: IconName::EyeOff,
+        action: PaletteAction::Navigate(CurrentView::Secrets),
+      },
+      PaletteCommand {
+        id: "nav-storage",
+        label: "Go to Storage",
+        shortcut: None,
+        category: "Navigation",
+        icon: IconName::Inbox,
+        action: PaletteAction::Navigate(CurrentView::Storage),
+      },
       PaletteCommand {
         id: "nav-services",
         label: "Go to Services",
@@ -188,6 +244,30 @@ impl CommandPalette {
         icon: IconName::Globe,
         action: PaletteAction::Navigate(CurrentView::Services),
       },
+      PaletteCommand {
+        id: "nav-ingresses",
+        label: "Go to Ingresses",
+        shortcut: None,
+        category: "Navigation",
+        icon: IconName::ExternalLink,
+        action: PaletteAction::Navigate(CurrentView::Ingresses),
+      },
+      PaletteCommand {
+        id: "nav-nodes",
+        label: "Go to Nodes",
+        shortcut: None,
+        category: "Navigation",
+        icon: IconName::GalleryVerticalEnd,
+        action: PaletteAction::Navigate(CurrentView::Nodes),
+      },
+      PaletteCommand {
+        id: "nav-contexts",
+        label: "Go to Kubernetes Contexts",
+        shortcut: None,
+        category: "Navigation",
+        icon: IconName::Settings2,
+        action: PaletteAction::Navigate(CurrentView::Contexts),
+      },
       PaletteCommand {
         id: "nav-machines",
         label: "Go to Machines",
@@ -204,6 +284,22 @@ impl CommandPalette {
         icon: IconName::ChartPie,
         action: PaletteAction::Navigate(CurrentView::ActivityMonitor),
       },
+      PaletteCommand {
+        id: "nav-events",
+        label: "Go to Events",
+        shortcut: Some("Cmd+Shift+E"),
+        category: "Navigation",
+        icon: IconName::GalleryVerticalEnd,
+        action: PaletteAction::Navigate(CurrentView::Events),
+      },
+      PaletteCommand {
+        id: "nav-disk-usage",
+        label: "Go to Disk Usage",
+        shortcut: None,
+        category: "Navigation",
+        icon: IconName::Inbox,
+        action: PaletteAction::Navigate(CurrentView::DiskUsage),
+      },
       PaletteCommand {
         id: "nav-settings",
         label: "Go to Settings",
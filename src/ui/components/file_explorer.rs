@@ -11,6 +11,8 @@ use gpui_component::{
 };
 use std::rc::Rc;
 
+use crate::assets::AppIcon;
+
 /// Trait for file entry types that can be displayed in the file explorer.
 /// All file entry types (`ContainerFileEntry`, `VolumeFileEntry`, `VmFileEntry`) implement this.
 pub trait FileEntry: Clone {
@@ -107,6 +109,36 @@ type CloseViewerCallback = Rc<dyn Fn(&(), &mut Window, &mut App) + 'static>;
 type SymlinkClickCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
 /// Callback for opening a path in external editor (path, `is_directory`)
 type OpenInEditorCallback = Rc<dyn Fn(&(String, bool), &mut Window, &mut App) + 'static>;
+type FileActionCallback = Rc<dyn Fn(&FileAction, &mut Window, &mut App) + 'static>;
+
+/// Write operations requested from a writable file explorer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileAction {
+  /// Upload from the host into a directory
+  Upload {
+    dir: String,
+  },
+  NewFolder {
+    dir: String,
+  },
+  NewFile {
+    dir: String,
+  },
+  /// Download a file or directory to the host
+  Download {
+    path: String,
+  },
+  Rename {
+    path: String,
+  },
+  Delete {
+    path: String,
+  },
+  /// Save the contents of the file viewer
+  Save {
+    path: String,
+  },
+}
 
 /// State for the file explorer
 #[derive(Debug, Clone, Default)]
@@ -183,6 +215,7 @@ pub struct FileExplorer<F: FileEntry + 'static> {
   on_close_viewer: Option<CloseViewerCallback>,
   on_symlink_click: Option<SymlinkClickCallback>,
   on_open_in_editor: Option<OpenInEditorCallback>,
+  on_file_action: Option<FileActionCallback>,
 }
 
 impl<F: FileEntry + 'static> FileExplorer<F> {
@@ -197,6 +230,7 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
       on_close_viewer: None,
       on_symlink_click: None,
       on_open_in_editor: None,
+      on_file_action: None,
     }
   }
 
@@ -263,6 +297,16 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
     self
   }
 
+  /// Set callback for write operations. Upload, create, download, rename, delete
+  /// and save controls are only shown when this is set.
+  pub fn on_file_action<C>(mut self, callback: C) -> Self
+  where
+    C: Fn(&FileAction, &mut Window, &mut App) + 'static,
+  {
+    self.on_file_action = Some(Rc::new(callback));
+    self
+  }
+
   /// Render the file explorer
   pub fn render(self, _window: &mut Window, cx: &App) -> gpui::AnyElement {
     let colors = &cx.theme().colors;
@@ -291,6 +335,7 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
     let on_file_select = self.on_file_select.clone();
     let on_symlink_click = self.on_symlink_click.clone();
     let on_open_in_editor = self.on_open_in_editor.clone();
+    let on_file_action = self.on_file_action.clone();
 
     // Calculate parent path
     let parent_path = calculate_parent_path(current_path);
@@ -309,9 +354,10 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
       let select_cb = on_file_select.clone();
       let symlink_cb = on_symlink_click.clone();
       let editor_cb = on_open_in_editor.clone();
+      let action_cb = on_file_action.clone();
 
       // Build context menu for this file
-      let menu = Self::build_file_context_menu(&file_path_menu, is_dir, editor_cb, cx);
+      let menu = Self::build_file_context_menu(&file_path_menu, is_dir, editor_cb, action_cb, cx);
 
       file_list = file_list.child(
         h_flex()
@@ -384,6 +430,35 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
               .text_color(colors.secondary_foreground)
               .child(current_path.clone()),
           )
+          .when_some(on_file_action, |el, cb| {
+            let dir = current_path.clone();
+            let actions = [
+              (
+                "upload",
+                IconName::ArrowUp,
+                "Upload from host",
+                FileAction::Upload { dir: dir.clone() },
+              ),
+              (
+                "new-folder",
+                IconName::Folder,
+                "New folder",
+                FileAction::NewFolder { dir: dir.clone() },
+              ),
+              ("new-file", IconName::Plus, "New file", FileAction::NewFile { dir }),
+            ];
+            el.children(actions.into_iter().map(|(id, icon, tooltip, action)| {
+              let cb = cb.clone();
+              Button::new(id)
+                .icon(icon)
+                .ghost()
+                .compact()
+                .tooltip(tooltip)
+                .on_click(move |_ev, window, cx| {
+                  cb(&action, window, cx);
+                })
+            }))
+          })
           .when_some(on_open_in_editor, move |el, cb| {
             el.child(
               Button::new("open-in-editor")
@@ -494,7 +569,9 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
     let has_error = self.state.file_content_error.is_some();
     let on_close = self.on_close_viewer.clone();
     let on_open_in_editor = self.on_open_in_editor.clone();
+    let on_file_action = self.on_file_action.clone();
     let file_path_for_editor = file_path.to_string();
+    let file_path_for_save = file_path.to_string();
 
     // Extract file name from path
     let file_name = file_path.rsplit('/').next().unwrap_or(file_path).to_string();
@@ -541,6 +618,26 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
               .text_ellipsis()
               .child(file_path.to_string()),
           )
+          // Save button, only once the content loaded into the editor
+          .when(!is_loading && !has_error && self.file_content_editor.is_some(), |el| {
+            el.when_some(on_file_action, move |el, cb| {
+              el.child(
+                Button::new("save-file")
+                  .label("Save")
+                  .primary()
+                  .compact()
+                  .on_click(move |_ev, window, cx| {
+                    cb(
+                      &FileAction::Save {
+                        path: file_path_for_save.clone(),
+                      },
+                      window,
+                      cx,
+                    );
+                  }),
+              )
+            })
+          })
           // Open in Editor button
           .when_some(on_open_in_editor, move |el, cb| {
             el.child(
@@ -635,6 +732,7 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
     path: &str,
     is_dir: bool,
     on_open_in_editor: Option<OpenInEditorCallback>,
+    on_file_action: Option<FileActionCallback>,
     _cx: &App,
   ) -> impl IntoElement {
     let path_owned = path.to_string();
@@ -694,6 +792,31 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
             }),
         );
 
+        // Write actions (only if callback is provided)
+        if let Some(ref cb) = on_file_action {
+          let path = path_owned.clone();
+          let actions = [
+            (
+              "Download",
+              Icon::new(IconName::ArrowDown),
+              FileAction::Download { path: path.clone() },
+            ),
+            (
+              "Rename",
+              Icon::new(AppIcon::Edit),
+              FileAction::Rename { path: path.clone() },
+            ),
+            ("Delete", Icon::new(AppIcon::Trash), FileAction::Delete { path }),
+          ];
+          menu = menu.separator();
+          for (label, icon, action) in actions {
+            let cb = cb.clone();
+            menu = menu.item(PopupMenuItem::new(label).icon(icon).on_click(move |_, window, cx| {
+              cb(&action, window, cx);
+            }));
+          }
+        }
+
         menu
       })
   }
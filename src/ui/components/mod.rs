@@ -1,9 +1,11 @@
 mod file_explorer;
 mod loading;
+mod object_events;
 mod process_view;
 mod spinning_icon;
 
-pub use file_explorer::{FileExplorer, FileExplorerConfig, FileExplorerState, detect_language_from_path};
+pub use file_explorer::{FileAction, FileExplorer, FileExplorerConfig, FileExplorerState, detect_language_from_path};
 pub use loading::{render_error, render_loading};
+pub use object_events::render_object_events;
 pub use process_view::ProcessView;
 pub use spinning_icon::{spinning_loader, spinning_loader_circle};
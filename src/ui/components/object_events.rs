@@ -0,0 +1,198 @@
+//! Events section for Kubernetes detail views
+//!
+//! Lists the timeline events about one object, newest first, like
+//! `kubectl events --for kind/name`.
+
+use chrono::{DateTime, Utc};
+use gpui::{App, Div, SharedString, Styled, div, prelude::*, px};
+use gpui_component::{
+  Icon, IconName, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  scroll::ScrollableElement,
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::services;
+use crate::state::{InvolvedObject, TimelineEvent, events_state};
+
+/// Number of event series shown in a detail view
+const MAX_OBJECT_EVENTS: usize = 100;
+
+/// Compact age like `kubectl get events` (`45s`, `12m`, `3h`, `2d`)
+fn format_event_age(timestamp: DateTime<Utc>, now: DateTime<Utc>) -> String {
+  let seconds = (now - timestamp).num_seconds().max(0);
+  match seconds {
+    s if s < 60 => format!("{s}s"),
+    s if s < 3600 => format!("{}m", s / 60),
+    s if s < 86400 => format!("{}h", s / 3600),
+    s => format!("{}d", s / 86400),
+  }
+}
+
+/// Render the events tab for a Kubernetes object
+pub fn render_object_events(object: &InvolvedObject, cx: &App) -> Div {
+  let colors = &cx.theme().colors;
+  let events = events_state(cx).read(cx).object_events(object, MAX_OBJECT_EVENTS);
+  let warnings = events.iter().filter(|e| e.is_warning).count();
+  let now = Utc::now();
+
+  let summary = if events.is_empty() {
+    "No events".to_string()
+  } else if warnings > 0 {
+    format!("{} events, {warnings} warnings", events.len())
+  } else {
+    format!("{} events", events.len())
+  };
+
+  let open_object = object.clone();
+  let header = h_flex()
+    .w_full()
+    .px(px(16.))
+    .py(px(8.))
+    .items_center()
+    .justify_between()
+    .border_b_1()
+    .border_color(colors.border)
+    .flex_shrink_0()
+    .child(div().text_xs().text_color(colors.muted_foreground).child(summary))
+    .child(
+      Button::new("object-events-open")
+        .label("Open in Events")
+        .icon(IconName::ExternalLink)
+        .ghost()
+        .xsmall()
+        .on_click(move |_ev, _window, cx| {
+          services::open_object_events(open_object.clone(), cx);
+        }),
+    );
+
+  let body = if events.is_empty() {
+    v_flex()
+      .w_full()
+      .py(px(48.))
+      .items_center()
+      .justify_center()
+      .gap(px(12.))
+      .child(
+        Icon::new(IconName::Info)
+          .size(px(32.))
+          .text_color(colors.muted_foreground),
+      )
+      .child(
+        div()
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .child(format!("No events recorded for {}", object.name)),
+      )
+  } else {
+    v_flex()
+      .w_full()
+      .children(events.iter().enumerate().map(|(i, e)| render_event_row(e, i, now, cx)))
+  };
+
+  v_flex().size_full().child(header).child(
+    div()
+      .id("object-events-scroll")
+      .flex_1()
+      .min_h_0()
+      .overflow_y_scrollbar()
+      .child(body),
+  )
+}
+
+fn render_event_row(event: &TimelineEvent, index: usize, now: DateTime<Utc>, cx: &App) -> impl IntoElement {
+  let colors = &cx.theme().colors;
+  let type_color = if event.is_warning {
+    colors.warning
+  } else {
+    colors.success
+  };
+  let count = event.count();
+
+  h_flex()
+    .id(SharedString::from(format!("object-event-{index}")))
+    .w_full()
+    .px(px(16.))
+    .py(px(8.))
+    .gap(px(12.))
+    .items_start()
+    .border_b_1()
+    .border_color(colors.border)
+    .child(
+      div()
+        .mt(px(5.))
+        .size(px(8.))
+        .flex_shrink_0()
+        .rounded_full()
+        .bg(type_color),
+    )
+    .child(
+      v_flex()
+        .flex_1()
+        .min_w_0()
+        .gap(px(2.))
+        .child(
+          h_flex()
+            .gap(px(8.))
+            .items_center()
+            .child(
+              div()
+                .text_sm()
+                .font_weight(gpui::FontWeight::MEDIUM)
+                .text_color(if event.is_warning {
+                  colors.warning
+                } else {
+                  colors.foreground
+                })
+                .child(event.action.clone()),
+            )
+            .child(
+              div()
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .child(format!("{}/{}", event.kind, event.resource_name)),
+            )
+            .when(count > 1, |el| {
+              el.child(
+                div()
+                  .px(px(6.))
+                  .rounded(px(4.))
+                  .bg(colors.secondary)
+                  .text_xs()
+                  .text_color(colors.secondary_foreground)
+                  .child(format!("x{count}")),
+              )
+            }),
+        )
+        .when_some(event.message.clone(), |el, message| {
+          el.child(div().text_xs().text_color(colors.secondary_foreground).child(message))
+        }),
+    )
+    .child(
+      div()
+        .flex_shrink_0()
+        .text_xs()
+        .font_family("monospace")
+        .text_color(colors.muted_foreground)
+        .child(format_event_age(event.timestamp, now)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::Duration;
+
+  #[test]
+  fn test_format_event_age() {
+    let now = Utc::now();
+    assert_eq!(format_event_age(now, now), "0s");
+    assert_eq!(format_event_age(now - Duration::seconds(45), now), "45s");
+    assert_eq!(format_event_age(now - Duration::minutes(12), now), "12m");
+    assert_eq!(format_event_age(now - Duration::hours(3), now), "3h");
+    assert_eq!(format_event_age(now - Duration::days(2), now), "2d");
+    assert_eq!(format_event_age(now + Duration::seconds(5), now), "0s");
+  }
+}
@@ -0,0 +1,525 @@
+use gpui::{App, Context, Entity, Render, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  Disableable, Icon, IconName, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  label::Label,
+  scroll::ScrollableElement,
+  theme::ActiveTheme,
+  v_flex,
+};
+use std::path::Path;
+
+use crate::assets::AppIcon;
+use crate::docker::{
+  ComposeDiagnostic, ComposeServiceDefinition, ComposeTarget, changed_services, dependency_layers, parse_compose_file,
+  validate_compose_file,
+};
+use crate::services::{self, DispatcherEvent, dispatcher};
+
+/// Emitted when the user closes the editor
+pub struct ComposeEditorClosed;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditorTab {
+  Editor,
+  Graph,
+}
+
+/// Editor for one compose file with live validation and a service dependency graph
+pub struct ComposeEditorView {
+  target: ComposeTarget,
+  file_path: String,
+  editor: Entity<InputState>,
+  /// Content as last read from or written to disk
+  saved_content: String,
+  /// Content loaded in the background, applied to the editor on the next render
+  pending_content: Option<String>,
+  load_error: Option<String>,
+  saving: bool,
+  diagnostics: Vec<ComposeDiagnostic>,
+  /// Services of the last version that parsed, used for the graph
+  services: Vec<ComposeServiceDefinition>,
+  /// Services changed by the last save, offered for `up -d`
+  pending_apply: Vec<String>,
+  active_tab: EditorTab,
+}
+
+impl gpui::EventEmitter<ComposeEditorClosed> for ComposeEditorView {}
+
+impl ComposeEditorView {
+  pub fn new(target: ComposeTarget, file_path: String, window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
+    let editor = cx.new(|cx| {
+      InputState::new(window, cx)
+        .multi_line(true)
+        .code_editor("yaml")
+        .line_number(true)
+        .searchable(true)
+        .soft_wrap(false)
+    });
+
+    cx.observe(&editor, |this, _editor, cx| {
+      this.revalidate(cx);
+    })
+    .detach();
+
+    let path = file_path.clone();
+    cx.spawn(async move |this, cx| {
+      let result = cx
+        .background_executor()
+        .spawn(async move { std::fs::read_to_string(&path) })
+        .await;
+
+      let _ = this.update(cx, |this, cx| {
+        match result {
+          Ok(content) => {
+            this.saved_content.clone_from(&content);
+            this.pending_content = Some(content);
+          }
+          Err(e) => this.load_error = Some(format!("Failed to read {}: {e}", this.file_path)),
+        }
+        cx.notify();
+      });
+    })
+    .detach();
+
+    Self {
+      target,
+      file_path,
+      editor,
+      saved_content: String::new(),
+      pending_content: None,
+      load_error: None,
+      saving: false,
+      diagnostics: Vec::new(),
+      services: Vec::new(),
+      pending_apply: Vec::new(),
+      active_tab: EditorTab::Editor,
+    }
+  }
+
+  fn text(&self, cx: &App) -> String {
+    self.editor.read(cx).text().to_string()
+  }
+
+  fn is_dirty(&self, cx: &App) -> bool {
+    self.pending_content.is_none() && self.text(cx) != self.saved_content
+  }
+
+  fn has_syntax_error(&self) -> bool {
+    self.diagnostics.iter().any(|d| d.is_syntax)
+  }
+
+  fn revalidate(&mut self, cx: &mut Context<'_, Self>) {
+    let text = self.text(cx);
+    self.diagnostics = validate_compose_file(&text);
+    if let Ok(file) = parse_compose_file(&text) {
+      self.services = file.services;
+    }
+    cx.notify();
+  }
+
+  fn save(&mut self, cx: &mut Context<'_, Self>) {
+    if self.saving || self.has_syntax_error() {
+      return;
+    }
+    self.saving = true;
+    cx.notify();
+
+    let text = self.text(cx);
+    let path = self.file_path.clone();
+
+    cx.spawn(async move |this, cx| {
+      let content = text.clone();
+      let result = cx
+        .background_executor()
+        .spawn(async move { std::fs::write(&path, content) })
+        .await;
+
+      let _ = this.update(cx, |this, cx| {
+        this.saving = false;
+        match result {
+          Ok(()) => {
+            this.pending_apply = changed_services(&this.saved_content, &text);
+            this.saved_content = text;
+            let message = format!("Saved {}", this.file_path);
+            dispatcher(cx).update(cx, |_, cx| {
+              cx.emit(DispatcherEvent::TaskCompleted { message });
+            });
+          }
+          Err(e) => {
+            let error = format!("Failed to save {}: {e}", this.file_path);
+            dispatcher(cx).update(cx, |_, cx| {
+              cx.emit(DispatcherEvent::TaskFailed { error });
+            });
+          }
+        }
+        cx.notify();
+      });
+    })
+    .detach();
+  }
+
+  fn render_header(&self, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let file_name = Path::new(&self.file_path)
+      .file_name()
+      .map(|n| n.to_string_lossy().to_string())
+      .unwrap_or_else(|| self.file_path.clone());
+    let can_save = self.is_dirty(cx) && !self.has_syntax_error();
+
+    let tab_button = |id: &'static str, label: &'static str, tab: EditorTab, cx: &mut Context<'_, Self>| {
+      let button = Button::new(id)
+        .label(label)
+        .xsmall()
+        .on_click(cx.listener(move |this, _ev, _window, cx| {
+          this.active_tab = tab;
+          cx.notify();
+        }));
+      if self.active_tab == tab {
+        button.primary()
+      } else {
+        button.ghost()
+      }
+    };
+
+    h_flex()
+      .w_full()
+      .h(px(52.))
+      .px(px(16.))
+      .gap(px(8.))
+      .items_center()
+      .border_b_1()
+      .border_color(colors.border)
+      .child(
+        Button::new("compose-editor-back")
+          .icon(IconName::ArrowLeft)
+          .small()
+          .ghost()
+          .on_click(cx.listener(|_this, _ev, _window, cx| {
+            cx.emit(ComposeEditorClosed);
+          })),
+      )
+      .child(
+        Label::new(format!("{} / {file_name}", self.target.project))
+          .text_color(colors.foreground)
+          .font_weight(gpui::FontWeight::SEMIBOLD),
+      )
+      .child(
+        div()
+          .flex_1()
+          .text_xs()
+          .text_color(colors.muted_foreground)
+          .overflow_hidden()
+          .text_ellipsis()
+          .whitespace_nowrap()
+          .child(self.file_path.clone()),
+      )
+      .child(tab_button("compose-editor-tab-editor", "Editor", EditorTab::Editor, cx))
+      .child(tab_button("compose-editor-tab-graph", "Graph", EditorTab::Graph, cx))
+      .child(
+        Button::new("compose-editor-save")
+          .icon(IconName::Check)
+          .label("Save")
+          .small()
+          .primary()
+          .loading(self.saving)
+          .disabled(!can_save)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.save(cx);
+          })),
+      )
+  }
+
+  fn render_apply_banner(&self, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let changed = self.pending_apply.clone();
+    let target = self.target.clone();
+
+    h_flex()
+      .w_full()
+      .px(px(16.))
+      .py(px(8.))
+      .gap(px(8.))
+      .items_center()
+      .bg(colors.primary.opacity(0.1))
+      .border_b_1()
+      .border_color(colors.border)
+      .child(
+        div()
+          .flex_1()
+          .text_sm()
+          .text_color(colors.foreground)
+          .child(format!("Changed services: {}", changed.join(", "))),
+      )
+      .child(
+        Button::new("compose-editor-apply")
+          .icon(AppIcon::Play)
+          .label("Apply with up -d")
+          .xsmall()
+          .primary()
+          .on_click(cx.listener(move |this, _ev, _window, cx| {
+            services::compose_up_services(target.clone(), changed.clone(), cx);
+            this.pending_apply.clear();
+            cx.notify();
+          })),
+      )
+      .child(
+        Button::new("compose-editor-dismiss")
+          .label("Dismiss")
+          .xsmall()
+          .ghost()
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.pending_apply.clear();
+            cx.notify();
+          })),
+      )
+  }
+
+  fn render_diagnostics(&self, cx: &App) -> impl IntoElement {
+    let colors = cx.theme().colors;
+
+    v_flex()
+      .id("compose-editor-diagnostics")
+      .w_full()
+      .max_h(px(140.))
+      .overflow_y_scrollbar()
+      .border_t_1()
+      .border_color(colors.border)
+      .children(self.diagnostics.iter().map(|diagnostic| {
+        let location = diagnostic.line.map(|l| format!("Line {l}")).unwrap_or_default();
+        h_flex()
+          .w_full()
+          .px(px(16.))
+          .py(px(4.))
+          .gap(px(8.))
+          .items_center()
+          .child(
+            Icon::new(if diagnostic.is_syntax {
+              IconName::CircleX
+            } else {
+              IconName::Info
+            })
+            .size(px(14.))
+            .text_color(if diagnostic.is_syntax {
+              colors.danger
+            } else {
+              colors.warning
+            }),
+          )
+          .child(
+            div()
+              .w(px(60.))
+              .flex_shrink_0()
+              .text_xs()
+              .font_family("monospace")
+              .text_color(colors.muted_foreground)
+              .child(location),
+          )
+          .child(
+            div()
+              .flex_1()
+              .text_xs()
+              .text_color(colors.foreground)
+              .child(diagnostic.message.clone()),
+          )
+      }))
+  }
+
+  fn render_editor_tab(&self, cx: &App) -> impl IntoElement {
+    v_flex()
+      .size_full()
+      .child(
+        div()
+          .flex_1()
+          .min_h_0()
+          .child(Input::new(&self.editor).size_full().appearance(false)),
+      )
+      .when(!self.diagnostics.is_empty(), |el| el.child(self.render_diagnostics(cx)))
+  }
+
+  fn render_chips(items: &[String], color: gpui::Hsla) -> impl IntoElement {
+    h_flex().gap(px(4.)).flex_wrap().children(items.iter().map(|item| {
+      div()
+        .px(px(6.))
+        .py(px(1.))
+        .rounded(px(4.))
+        .bg(color.opacity(0.15))
+        .text_xs()
+        .text_color(color)
+        .child(item.clone())
+    }))
+  }
+
+  fn render_graph_tab(&self, cx: &App) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let layers = dependency_layers(&self.services);
+
+    let mut networks: Vec<(String, Vec<String>)> = Vec::new();
+    let mut volumes: Vec<(String, Vec<String>)> = Vec::new();
+    for service in &self.services {
+      for network in &service.networks {
+        add_member(&mut networks, network, &service.name);
+      }
+      for volume in &service.volumes {
+        add_member(&mut volumes, volume, &service.name);
+      }
+    }
+
+    let node = |service: &ComposeServiceDefinition| {
+      let source = match (&service.image, service.has_build) {
+        (Some(image), _) => image.clone(),
+        (None, true) => "build".to_string(),
+        (None, false) => String::new(),
+      };
+
+      v_flex()
+        .w(px(220.))
+        .p(px(10.))
+        .gap(px(6.))
+        .rounded(px(6.))
+        .border_1()
+        .border_color(colors.border)
+        .bg(colors.sidebar)
+        .child(
+          div()
+            .text_sm()
+            .font_weight(gpui::FontWeight::MEDIUM)
+            .text_color(colors.foreground)
+            .child(service.name.clone()),
+        )
+        .child(
+          div()
+            .text_xs()
+            .text_color(colors.muted_foreground)
+            .overflow_hidden()
+            .text_ellipsis()
+            .whitespace_nowrap()
+            .child(source),
+        )
+        .when(!service.depends_on.is_empty(), |el| {
+          el.child(
+            div()
+              .text_xs()
+              .text_color(colors.secondary_foreground)
+              .child(format!("depends on {}", service.depends_on.join(", "))),
+          )
+        })
+        .when(!service.networks.is_empty(), |el| {
+          el.child(Self::render_chips(&service.networks, colors.info))
+        })
+        .when(!service.volumes.is_empty(), |el| {
+          el.child(Self::render_chips(&service.volumes, colors.warning))
+        })
+    };
+
+    let groups = |title: &'static str, groups: Vec<(String, Vec<String>)>| {
+      v_flex()
+        .gap(px(4.))
+        .child(
+          div()
+            .text_xs()
+            .font_weight(gpui::FontWeight::SEMIBOLD)
+            .text_color(colors.muted_foreground)
+            .child(title),
+        )
+        .children(groups.into_iter().map(|(name, members)| {
+          h_flex()
+            .gap(px(8.))
+            .text_xs()
+            .child(div().w(px(140.)).text_color(colors.foreground).child(name))
+            .child(div().text_color(colors.muted_foreground).child(members.join(", ")))
+        }))
+    };
+
+    div()
+      .id("compose-graph-scroll")
+      .size_full()
+      .overflow_y_scrollbar()
+      .child(
+        v_flex()
+        .p(px(16.))
+        .gap(px(24.))
+        .when(self.services.is_empty(), |el| {
+          el.child(
+            div()
+              .text_sm()
+              .text_color(colors.muted_foreground)
+              .child("No services to show"),
+          )
+        })
+        // Dependencies flow left to right: each column only depends on columns before it
+        .child(
+          h_flex()
+            .gap(px(8.))
+            .items_start()
+            .children(layers.iter().enumerate().map(|(idx, layer)| {
+              h_flex()
+                .gap(px(8.))
+                .items_start()
+                .when(idx > 0, |el| {
+                  el.child(
+                    div()
+                      .pt(px(24.))
+                      .text_color(colors.muted_foreground)
+                      .child(Icon::new(IconName::ChevronRight).size(px(16.))),
+                  )
+                })
+                .child(
+                  v_flex().gap(px(8.)).children(
+                    layer
+                      .iter()
+                      .filter_map(|name| self.services.iter().find(|s| &s.name == name))
+                      .map(node),
+                  ),
+                )
+            })),
+        )
+        .when(!networks.is_empty(), |el| el.child(groups("Networks", networks)))
+        .when(!volumes.is_empty(), |el| el.child(groups("Volumes", volumes))),
+      )
+  }
+}
+
+/// Record `member` as a user of the shared resource `name`
+fn add_member(groups: &mut Vec<(String, Vec<String>)>, name: &str, member: &str) {
+  match groups.iter_mut().find(|(n, _)| n == name) {
+    Some((_, members)) => members.push(member.to_string()),
+    None => groups.push((name.to_string(), vec![member.to_string()])),
+  }
+}
+
+impl Render for ComposeEditorView {
+  fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    if let Some(content) = self.pending_content.take() {
+      self.editor.update(cx, |state, cx| state.set_value(content, window, cx));
+      self.revalidate(cx);
+    }
+
+    let colors = cx.theme().colors;
+
+    v_flex()
+      .size_full()
+      .bg(colors.background)
+      .child(self.render_header(cx))
+      .when(!self.pending_apply.is_empty(), |el| {
+        el.child(self.render_apply_banner(cx))
+      })
+      .when_some(self.load_error.clone(), |el, error| {
+        el.child(
+          div()
+            .w_full()
+            .px(px(16.))
+            .py(px(8.))
+            .text_sm()
+            .text_color(colors.danger)
+            .child(error),
+        )
+      })
+      .child(div().flex_1().min_h_0().child(match self.active_tab {
+        EditorTab::Editor => self.render_editor_tab(cx).into_any_element(),
+        EditorTab::Graph => self.render_graph_tab(cx).into_any_element(),
+      }))
+  }
+}
@@ -0,0 +1,374 @@
+use chrono::{Local, Utc};
+use gpui::{Context, Entity, Hsla, Render, ScrollHandle, SharedString, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  Icon, IconName, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  label::Label,
+  theme::ActiveTheme,
+  v_flex,
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+use tokio::task::JoinHandle;
+
+use crate::docker::{ComposeLogLine, extract_compose_projects, insert_log_line};
+use crate::services;
+use crate::state::{DockerState, StateChanged, docker_state};
+
+/// Maximum number of merged lines kept in memory
+const MAX_LOG_LINES: usize = 5000;
+/// Lines rendered at once (newest last)
+const MAX_VISIBLE_LINES: usize = 1000;
+/// Lines fetched from each container when the view opens
+const INITIAL_TAIL: usize = 200;
+
+/// Emitted when the user closes the logs view
+pub struct ComposeLogsClosed;
+
+/// Logs from every service of a compose project, merged by timestamp
+pub struct ComposeLogsView {
+  project_name: String,
+  docker_state: Entity<DockerState>,
+  lines: VecDeque<ComposeLogLine>,
+  /// Service names in order of first appearance, used for colors and toggles
+  services: Vec<String>,
+  hidden_services: HashSet<String>,
+  /// Log followers keyed by container ID
+  followers: HashMap<String, JoinHandle<()>>,
+  tx: UnboundedSender<ComposeLogLine>,
+  /// Unix time the view opened. Containers created afterwards (e.g. recreated services) are read from here.
+  opened_at: i64,
+  initial_sync_done: bool,
+  follow_tail: bool,
+  scroll_handle: ScrollHandle,
+}
+
+impl gpui::EventEmitter<ComposeLogsClosed> for ComposeLogsView {}
+
+impl ComposeLogsView {
+  pub fn new(project_name: String, _window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
+    let docker_state = docker_state(cx);
+    let (tx, rx) = unbounded_channel();
+
+    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| {
+      if let StateChanged::ContainersUpdated = event {
+        this.sync_followers(cx);
+      }
+    })
+    .detach();
+
+    Self::receive_lines(rx, cx);
+
+    let mut view = Self {
+      project_name,
+      docker_state,
+      lines: VecDeque::new(),
+      services: Vec::new(),
+      hidden_services: HashSet::new(),
+      followers: HashMap::new(),
+      tx,
+      opened_at: Utc::now().timestamp(),
+      initial_sync_done: false,
+      follow_tail: true,
+      scroll_handle: ScrollHandle::new(),
+    };
+    view.sync_followers(cx);
+    view
+  }
+
+  fn receive_lines(mut rx: UnboundedReceiver<ComposeLogLine>, cx: &mut Context<'_, Self>) {
+    cx.spawn(async move |this, cx| {
+      while let Some(first) = rx.recv().await {
+        // Batch bursts (e.g. the initial tail of every container) into a single render
+        let mut batch = vec![first];
+        while let Ok(line) = rx.try_recv() {
+          batch.push(line);
+        }
+
+        let updated = this.update(cx, |this, cx| {
+          for line in batch {
+            insert_log_line(&mut this.lines, line, MAX_LOG_LINES);
+          }
+          if this.follow_tail {
+            this.scroll_handle.scroll_to_bottom();
+          }
+          cx.notify();
+        });
+        if updated.is_err() {
+          break;
+        }
+      }
+    })
+    .detach();
+  }
+
+  /// Start following containers of the project that are not followed yet, including recreated ones
+  fn sync_followers(&mut self, cx: &mut Context<'_, Self>) {
+    let containers = self.docker_state.read(cx).containers.clone();
+    let services = extract_compose_projects(&containers)
+      .into_iter()
+      .find(|p| p.name == self.project_name)
+      .map(|p| p.services)
+      .unwrap_or_default();
+
+    // Containers that were removed will not produce more logs
+    self.followers.retain(|id, handle| {
+      let keep = services.iter().any(|s| &s.container_id == id);
+      if !keep {
+        handle.abort();
+      }
+      keep
+    });
+
+    let now = Utc::now().timestamp();
+    for service in services {
+      if !self.services.contains(&service.name) {
+        self.services.push(service.name.clone());
+      }
+
+      // `None` reads the last lines, `Some(t)` reads everything since `t`
+      let since = match self.followers.get(&service.container_id) {
+        None if !self.initial_sync_done => None,
+        None => Some(self.opened_at),
+        // Restarted container whose previous stream ended
+        Some(handle) if handle.is_finished() && service.state.is_running() => Some(now),
+        Some(_) => continue,
+      };
+
+      let handle = Self::follow_container(service.container_id.clone(), service.name, since, self.tx.clone());
+      self.followers.insert(service.container_id, handle);
+    }
+
+    self.initial_sync_done = true;
+    cx.notify();
+  }
+
+  fn follow_container(
+    id: String,
+    service: String,
+    since: Option<i64>,
+    tx: UnboundedSender<ComposeLogLine>,
+  ) -> JoinHandle<()> {
+    let client = services::docker_client();
+
+    services::Tokio::runtime_handle().spawn(async move {
+      let Some(docker) = client.read().await.clone() else {
+        return;
+      };
+
+      let result = docker
+        .follow_logs(&id, INITIAL_TAIL, since, |raw, is_stderr| {
+          let _ = tx.send(ComposeLogLine::from_raw(&service, raw, is_stderr));
+        })
+        .await;
+
+      if let Err(e) = result {
+        tracing::debug!("Stopped following logs for {id}: {e}");
+      }
+    })
+  }
+
+  fn toggle_service(&mut self, service: &str, cx: &mut Context<'_, Self>) {
+    if !self.hidden_services.remove(service) {
+      self.hidden_services.insert(service.to_string());
+    }
+    cx.notify();
+  }
+
+  fn service_color(&self, service: &str, cx: &Context<'_, Self>) -> Hsla {
+    let colors = cx.theme().colors;
+    let palette = [
+      colors.primary,
+      colors.success,
+      colors.warning,
+      colors.info,
+      colors.link,
+      colors.danger,
+      colors.accent_foreground,
+      colors.secondary_foreground,
+    ];
+    let index = self.services.iter().position(|s| s == service).unwrap_or(0);
+    palette[index % palette.len()]
+  }
+
+  fn render_toolbar(&self, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+
+    h_flex()
+      .w_full()
+      .px(px(16.))
+      .py(px(8.))
+      .gap(px(6.))
+      .items_center()
+      .flex_wrap()
+      .border_b_1()
+      .border_color(colors.border)
+      .children(self.services.iter().map(|service| {
+        let color = self.service_color(service, cx);
+        let hidden = self.hidden_services.contains(service);
+        let service_name = service.clone();
+
+        Button::new(SharedString::from(format!("compose-logs-service-{service}")))
+          .label(service.clone())
+          .xsmall()
+          .ghost()
+          .text_color(if hidden { colors.muted_foreground } else { color })
+          .when(hidden, |b| b.opacity(0.5))
+          .on_click(cx.listener(move |this, _ev, _window, cx| {
+            this.toggle_service(&service_name, cx);
+          }))
+      }))
+      .child(div().flex_1())
+      .child({
+        let button = Button::new("compose-logs-follow")
+          .label("Follow")
+          .xsmall()
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.follow_tail = !this.follow_tail;
+            if this.follow_tail {
+              this.scroll_handle.scroll_to_bottom();
+            }
+            cx.notify();
+          }));
+        if self.follow_tail {
+          button.primary()
+        } else {
+          button.ghost()
+        }
+      })
+      .child(
+        Button::new("compose-logs-clear")
+          .label("Clear")
+          .xsmall()
+          .ghost()
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.lines.clear();
+            cx.notify();
+          })),
+      )
+  }
+}
+
+impl Drop for ComposeLogsView {
+  fn drop(&mut self) {
+    for handle in self.followers.values() {
+      handle.abort();
+    }
+  }
+}
+
+impl Render for ComposeLogsView {
+  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let name_width = self.services.iter().map(String::len).max().unwrap_or(0).min(24);
+
+    let visible: Vec<&ComposeLogLine> = self
+      .lines
+      .iter()
+      .rev()
+      .filter(|l| !self.hidden_services.contains(&l.service))
+      .take(MAX_VISIBLE_LINES)
+      .collect();
+
+    let rows: Vec<_> = visible
+      .into_iter()
+      .rev()
+      .map(|line| {
+        let color = self.service_color(&line.service, cx);
+        let time = line
+          .timestamp
+          .map(|t| t.with_timezone(&Local).format("%H:%M:%S%.3f").to_string())
+          .unwrap_or_default();
+
+        h_flex()
+          .w_full()
+          .px(px(16.))
+          .gap(px(8.))
+          .text_xs()
+          .font_family("monospace")
+          .child(div().flex_shrink_0().text_color(colors.muted_foreground).child(time))
+          .child(div().flex_shrink_0().text_color(color).child(format!(
+            "{:<width$} |",
+            line.service,
+            width = name_width
+          )))
+          .child(
+            div()
+              .flex_1()
+              .text_color(if line.is_stderr {
+                colors.danger
+              } else {
+                colors.foreground
+              })
+              .child(line.text.clone()),
+          )
+      })
+      .collect();
+
+    v_flex()
+      .size_full()
+      .bg(colors.background)
+      .child(
+        h_flex()
+          .w_full()
+          .h(px(52.))
+          .px(px(16.))
+          .gap(px(8.))
+          .items_center()
+          .border_b_1()
+          .border_color(colors.border)
+          .child(
+            Button::new("compose-logs-back")
+              .icon(IconName::ArrowLeft)
+              .small()
+              .ghost()
+              .on_click(cx.listener(|_this, _ev, _window, cx| {
+                cx.emit(ComposeLogsClosed);
+              })),
+          )
+          .child(
+            Label::new(format!("{} logs", self.project_name))
+              .text_color(colors.foreground)
+              .font_weight(gpui::FontWeight::SEMIBOLD),
+          )
+          .child(
+            div()
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .child(format!("{} containers", self.followers.len())),
+          ),
+      )
+      .child(self.render_toolbar(cx))
+      .child(
+        div()
+          .id("compose-logs-scroll")
+          .flex_1()
+          .min_h_0()
+          .py(px(4.))
+          .overflow_y_scroll()
+          .track_scroll(&self.scroll_handle)
+          .when(rows.is_empty(), |el| {
+            el.child(
+              v_flex()
+                .size_full()
+                .py(px(48.))
+                .items_center()
+                .gap(px(12.))
+                .child(
+                  Icon::new(IconName::Info)
+                    .size(px(32.))
+                    .text_color(colors.muted_foreground),
+                )
+                .child(
+                  div()
+                    .text_sm()
+                    .text_color(colors.muted_foreground)
+                    .child("Waiting for log output..."),
+                ),
+            )
+          })
+          .children(rows),
+      )
+  }
+}
@@ -1,3 +1,5 @@
+mod editor;
+mod logs;
 mod view;
 
 pub use view::ComposeView;
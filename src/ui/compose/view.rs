@@ -1,43 +1,147 @@
-use gpui::{Context, Entity, Render, SharedString, Styled, Window, div, prelude::*, px};
+use gpui::{App, Context, Entity, Render, SharedString, Styled, Window, div, prelude::*, px};
 use gpui_component::{
-  Icon, Sizable,
+  Icon, IconName, Sizable,
   button::{Button, ButtonVariants},
   h_flex,
+  input::{Input, InputState},
   label::Label,
   scroll::ScrollableElement,
   theme::ActiveTheme,
   v_flex,
 };
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crate::assets::AppIcon;
-use crate::docker::{ComposeProject, ComposeService, extract_compose_projects};
+use crate::docker::{
+  ComposeDefinition, ComposeProject, ComposeService, ComposeServiceAction, ComposeServiceDefinition, ComposeTarget,
+  extract_compose_projects, merge_compose_definitions,
+};
 use crate::services;
-use crate::state::{DockerState, StateChanged, docker_state};
+use crate::state::{ComposeProjectConfig, DockerState, SettingsChanged, StateChanged, docker_state, settings_state};
+
+use super::editor::{ComposeEditorClosed, ComposeEditorView};
+use super::logs::{ComposeLogsClosed, ComposeLogsView};
+
+/// Load compose files for projects found via container labels and for registered paths.
+/// Returns the definitions plus `(path, error)` pairs for registered paths that failed to load.
+fn load_definitions(
+  projects: &[ComposeProject],
+  configs: &[ComposeProjectConfig],
+) -> (Vec<ComposeDefinition>, Vec<(String, String)>) {
+  let mut definitions = Vec::new();
+  let mut errors = Vec::new();
+
+  for project in projects.iter().filter(|p| !p.config_files.is_empty()) {
+    match ComposeDefinition::from_config_files(&project.name, project.working_dir.as_deref(), &project.config_files) {
+      Ok(def) => definitions.push(def),
+      Err(e) => tracing::debug!("Compose files for '{}' unavailable: {e}", project.name),
+    }
+  }
+
+  for config in configs {
+    match ComposeDefinition::from_registered_path(&config.path, &config.project_name) {
+      Ok(def) => definitions.push(def),
+      Err(e) => errors.push((config.path.clone(), format!("{e:#}"))),
+    }
+  }
+
+  (definitions, errors)
+}
 
 /// Docker Compose projects view
 pub struct ComposeView {
   docker_state: Entity<DockerState>,
   /// Set of expanded project names
   expanded_projects: HashSet<String>,
+  /// Compose files read from disk, refreshed whenever containers or settings change
+  definitions: Vec<ComposeDefinition>,
+  /// Registered paths that could not be loaded
+  load_errors: Vec<(String, String)>,
+  path_input: Entity<InputState>,
+  /// Env file inputs keyed by project name
+  env_inputs: HashMap<String, Entity<InputState>>,
+  /// Aggregated logs of one project, shown instead of the project list while open
+  logs_view: Option<Entity<ComposeLogsView>>,
+  /// Compose file editor, shown instead of the project list while open
+  editor_view: Option<Entity<ComposeEditorView>>,
 }
 
 impl ComposeView {
-  pub fn new(_window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
+  pub fn new(window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
     let docker_state = docker_state(cx);
 
     // Subscribe to state changes
-    cx.subscribe(&docker_state, |_this, _state, event: &StateChanged, cx| {
+    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| {
       if let StateChanged::ContainersUpdated = event {
+        this.reload_definitions(cx);
         cx.notify();
       }
     })
     .detach();
 
-    Self {
+    cx.subscribe(&settings_state(cx), |this, _state, event: &SettingsChanged, cx| {
+      if matches!(event, SettingsChanged::SettingsUpdated) {
+        this.reload_definitions(cx);
+      }
+    })
+    .detach();
+
+    let path_input = cx.new(|cx| InputState::new(window, cx).placeholder("Path to compose file or folder..."));
+
+    let mut view = Self {
       docker_state,
       expanded_projects: HashSet::new(),
-    }
+      definitions: Vec::new(),
+      load_errors: Vec::new(),
+      path_input,
+      env_inputs: HashMap::new(),
+      logs_view: None,
+      editor_view: None,
+    };
+    view.reload_definitions(cx);
+    view
+  }
+
+  fn reload_definitions(&mut self, cx: &mut Context<'_, Self>) {
+    let projects = extract_compose_projects(&self.docker_state.read(cx).containers);
+    let configs = settings_state(cx).read(cx).settings.compose_projects.clone();
+
+    cx.spawn(async move |this, cx| {
+      let (definitions, errors) = cx
+        .background_executor()
+        .spawn(async move { load_definitions(&projects, &configs) })
+        .await;
+
+      let _ = this.update(cx, |this, cx| {
+        this.definitions = definitions;
+        this.load_errors = errors;
+        cx.notify();
+      });
+    })
+    .detach();
+  }
+
+  fn open_editor(&mut self, target: ComposeTarget, file: String, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let editor_view = cx.new(|cx| ComposeEditorView::new(target, file, window, cx));
+    cx.subscribe(&editor_view, |this, _view, _event: &ComposeEditorClosed, cx| {
+      this.editor_view = None;
+      this.reload_definitions(cx);
+      cx.notify();
+    })
+    .detach();
+    self.editor_view = Some(editor_view);
+    cx.notify();
+  }
+
+  fn open_logs(&mut self, project_name: String, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let logs_view = cx.new(|cx| ComposeLogsView::new(project_name, window, cx));
+    cx.subscribe(&logs_view, |this, _view, _event: &ComposeLogsClosed, cx| {
+      this.logs_view = None;
+      cx.notify();
+    })
+    .detach();
+    self.logs_view = Some(logs_view);
+    cx.notify();
   }
 
   fn toggle_project(&mut self, project_name: &str, cx: &mut Context<'_, Self>) {
@@ -49,6 +153,101 @@ impl ComposeView {
     cx.notify();
   }
 
+  /// Saved options for a project, matched by registered path or project name
+  fn project_config(project: &ComposeProject, cx: &App) -> Option<ComposeProjectConfig> {
+    settings_state(cx)
+      .read(cx)
+      .settings
+      .compose_projects
+      .iter()
+      .find(|c| Some(&c.path) == project.registered_path.as_ref() || c.project_name == project.name)
+      .cloned()
+  }
+
+  fn project_target(project: &ComposeProject, cx: &App) -> ComposeTarget {
+    match Self::project_config(project, cx) {
+      Some(config) => project.target(Some(config.env_file).filter(|f| !f.is_empty()), config.profiles),
+      None => project.target(None, Vec::new()),
+    }
+  }
+
+  /// Update a project's saved options, registering it first if it was only discovered from labels
+  fn update_project_config(project: &ComposeProject, cx: &mut App, update: impl FnOnce(&mut ComposeProjectConfig)) {
+    let existing = Self::project_config(project, cx).map(|c| c.path);
+    let Some(path) = existing
+      .or_else(|| project.config_files.first().cloned())
+      .or_else(|| project.working_dir.clone())
+    else {
+      return;
+    };
+    let project_name = project.name.clone();
+
+    settings_state(cx).update(cx, |state, cx| {
+      let configs = &mut state.settings.compose_projects;
+      let idx = match configs.iter().position(|c| c.path == path) {
+        Some(idx) => idx,
+        None => {
+          configs.push(ComposeProjectConfig {
+            path,
+            project_name,
+            env_file: String::new(),
+            profiles: Vec::new(),
+          });
+          configs.len() - 1
+        }
+      };
+      update(&mut configs[idx]);
+      let _ = state.settings.save();
+      cx.emit(SettingsChanged::SettingsUpdated);
+    });
+  }
+
+  fn register_path(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let path = self.path_input.read(cx).text().to_string().trim().to_string();
+    if path.is_empty() {
+      return;
+    }
+
+    settings_state(cx).update(cx, |state, cx| {
+      if !state.settings.compose_projects.iter().any(|c| c.path == path) {
+        state.settings.compose_projects.push(ComposeProjectConfig {
+          path,
+          project_name: String::new(),
+          env_file: String::new(),
+          profiles: Vec::new(),
+        });
+        let _ = state.settings.save();
+        cx.emit(SettingsChanged::SettingsUpdated);
+      }
+    });
+    self.path_input.update(cx, |state, cx| state.set_value("", window, cx));
+  }
+
+  fn unregister_path(path: &str, cx: &mut App) {
+    settings_state(cx).update(cx, |state, cx| {
+      state.settings.compose_projects.retain(|c| c.path != path);
+      let _ = state.settings.save();
+      cx.emit(SettingsChanged::SettingsUpdated);
+    });
+  }
+
+  fn env_input(
+    &mut self,
+    project: &ComposeProject,
+    window: &mut Window,
+    cx: &mut Context<'_, Self>,
+  ) -> Entity<InputState> {
+    if let Some(input) = self.env_inputs.get(&project.name) {
+      return input.clone();
+    }
+    let env_file = Self::project_config(project, cx)
+      .map(|c| c.env_file)
+      .unwrap_or_default();
+    let input = cx.new(|cx| InputState::new(window, cx).placeholder(".env").default_value(env_file));
+    self.env_inputs.insert(project.name.clone(), input.clone());
+    input
+  }
+
   fn render_empty(cx: &Context<'_, Self>) -> impl IntoElement {
     let colors = &cx.theme().colors;
 
@@ -70,7 +269,7 @@ impl ComposeView {
           div()
             .text_xs()
             .text_color(colors.muted_foreground)
-            .child("Start a compose project to see it here"),
+            .child("Start a compose project or add a compose file to see it here"),
         ),
     )
   }
@@ -79,9 +278,15 @@ impl ComposeView {
     let colors = cx.theme().colors;
     let project_name = project.name.clone();
     let project_name_for_toggle = project_name.clone();
-    let project_name_for_up = project_name.clone();
-    let project_name_for_down = project_name.clone();
-    let project_name_for_restart = project_name.clone();
+    let project_name_for_logs = project_name.clone();
+    let target = Self::project_target(project, cx);
+    let missing_count = project.missing_services(&target.profiles).count();
+    let target_for_up = target.clone();
+    let target_for_down = target.clone();
+    let target_for_restart = target.clone();
+    let target_for_pull = target.clone();
+    let target_for_build = target;
+    let has_files = !project.config_files.is_empty();
 
     let status_color = if project.is_all_running() {
       colors.success
@@ -132,6 +337,15 @@ impl ComposeView {
                             .text_color(colors.foreground)
                             .child(project_name.clone()),
                     )
+                    // Defined services without containers
+                    .when(missing_count > 0, |el| {
+                        el.child(
+                            div()
+                                .text_xs()
+                                .text_color(colors.muted_foreground)
+                                .child(format!("{missing_count} not created")),
+                        )
+                    })
                     // Status badge
                     .child(
                         div()
@@ -148,53 +362,301 @@ impl ComposeView {
                         h_flex()
                             .gap(px(4.))
                             .child(
-                                Button::new(SharedString::from(format!("up-{}", project_name_for_up.clone())))
+                                Button::new(SharedString::from(format!("up-{project_name}")))
                                     .icon(AppIcon::Play)
                                     .xsmall()
                                     .ghost()
                                     .on_click(cx.listener(move |_this, _ev, _window, cx| {
-                                        services::compose_up(project_name_for_up.clone(), cx);
+                                        services::compose_up(target_for_up.clone(), cx);
                                     })),
                             )
                             .child(
-                                Button::new(SharedString::from(format!("down-{}", project_name_for_down.clone())))
+                                Button::new(SharedString::from(format!("down-{project_name}")))
                                     .icon(AppIcon::Stop)
                                     .xsmall()
                                     .ghost()
                                     .on_click(cx.listener(move |_this, _ev, _window, cx| {
-                                        services::compose_down(project_name_for_down.clone(), cx);
+                                        services::compose_down(target_for_down.clone(), cx);
                                     })),
                             )
                             .child(
-                                Button::new(SharedString::from(format!("restart-{}", project_name_for_restart.clone())))
+                                Button::new(SharedString::from(format!("restart-{project_name}")))
                                     .icon(AppIcon::Restart)
                                     .xsmall()
                                     .ghost()
                                     .on_click(cx.listener(move |_this, _ev, _window, cx| {
-                                        services::compose_restart(project_name_for_restart.clone(), cx);
+                                        services::compose_restart(target_for_restart.clone(), cx);
                                     })),
-                            ),
+                            )
+                            .child(
+                                Button::new(SharedString::from(format!("logs-{project_name}")))
+                                    .icon(AppIcon::Logs)
+                                    .xsmall()
+                                    .ghost()
+                                    .tooltip("Project logs")
+                                    .on_click(cx.listener(move |this, _ev, window, cx| {
+                                        this.open_logs(project_name_for_logs.clone(), window, cx);
+                                    })),
+                            )
+                            .when(has_files, |el| {
+                                el.child(
+                                    Button::new(SharedString::from(format!("pull-{project_name}")))
+                                        .label("Pull")
+                                        .xsmall()
+                                        .ghost()
+                                        .on_click(cx.listener(move |_this, _ev, _window, cx| {
+                                            services::compose_pull(target_for_pull.clone(), cx);
+                                        })),
+                                )
+                                .child(
+                                    Button::new(SharedString::from(format!("build-{project_name}")))
+                                        .label("Build")
+                                        .xsmall()
+                                        .ghost()
+                                        .on_click(cx.listener(move |_this, _ev, _window, cx| {
+                                            services::compose_build(target_for_build.clone(), cx);
+                                        })),
+                                )
+                            }),
                     ),
             )
-            // Services list (when expanded)
-            .when_some(
-                if is_expanded {
-                    Some(
-                        project
-                            .services
-                            .iter()
-                            .map(|service| Self::render_service(service, cx).into_any_element())
-                            .collect::<Vec<_>>(),
-                    )
-                } else {
-                    None
-                },
-                ParentElement::children,
+  }
+
+  /// File, env-file and profile options shown above the services of an expanded project
+  fn render_project_options(
+    &mut self,
+    project: &ComposeProject,
+    window: &mut Window,
+    cx: &mut Context<'_, Self>,
+  ) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let config = Self::project_config(project, cx);
+    let active_profiles = config.as_ref().map(|c| c.profiles.clone()).unwrap_or_default();
+    let env_input = self.env_input(project, window, cx);
+    let files = if project.config_files.is_empty() {
+      "No compose file found for this project".to_string()
+    } else {
+      project.config_files.join(", ")
+    };
+    let project_name = project.name.clone();
+    let project_for_env = project.clone();
+    let env_input_for_apply = env_input.clone();
+    let target = Self::project_target(project, cx);
+
+    v_flex()
+      .w_full()
+      .pl(px(56.))
+      .pr(px(16.))
+      .py(px(8.))
+      .gap(px(8.))
+      .child(
+        h_flex()
+          .gap(px(8.))
+          .items_center()
+          .child(
+            Icon::new(IconName::File)
+              .size(px(14.))
+              .text_color(colors.muted_foreground),
+          )
+          .child(
+            div()
+              .flex_1()
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .overflow_hidden()
+              .text_ellipsis()
+              .whitespace_nowrap()
+              .child(files),
+          )
+          .children(project.config_files.iter().enumerate().map(|(idx, file)| {
+            let target = target.clone();
+            let file = file.clone();
+            let file_name = std::path::Path::new(&file)
+              .file_name()
+              .map(|n| n.to_string_lossy().to_string())
+              .unwrap_or_else(|| file.clone());
+            Button::new(SharedString::from(format!("edit-{project_name}-{idx}")))
+              .icon(Icon::new(AppIcon::Edit))
+              .label(format!("Edit {file_name}"))
+              .xsmall()
+              .ghost()
+              .on_click(cx.listener(move |this, _ev, window, cx| {
+                this.open_editor(target.clone(), file.clone(), window, cx);
+              }))
+          }))
+          .when_some(project.registered_path.clone(), |el, path| {
+            el.child(
+              Button::new(SharedString::from(format!("unregister-{project_name}")))
+                .icon(Icon::new(AppIcon::Trash))
+                .label("Remove")
+                .xsmall()
+                .ghost()
+                .on_click(cx.listener(move |_this, _ev, _window, cx| {
+                  Self::unregister_path(&path, cx);
+                })),
             )
+          }),
+      )
+      .when(!project.config_files.is_empty(), |el| {
+        el.child(
+          h_flex()
+            .gap(px(8.))
+            .items_center()
+            .child(
+              div()
+                .w(px(70.))
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .child("Env file"),
+            )
+            .child(div().w(px(240.)).child(Input::new(&env_input).small().w_full()))
+            .child(
+              Button::new(SharedString::from(format!("apply-env-{project_name}")))
+                .label("Apply")
+                .xsmall()
+                .ghost()
+                .on_click(cx.listener(move |_this, _ev, _window, cx| {
+                  let env_file = env_input_for_apply.read(cx).text().to_string().trim().to_string();
+                  Self::update_project_config(&project_for_env, cx, |config| config.env_file = env_file);
+                })),
+            ),
+        )
+      })
+      .when(!project.available_profiles().is_empty(), |el| {
+        el.child(
+          h_flex()
+            .gap(px(4.))
+            .items_center()
+            .child(
+              div()
+                .w(px(70.))
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .child("Profiles"),
+            )
+            .children(project.available_profiles().into_iter().map(|profile| {
+              let is_active = active_profiles.contains(&profile);
+              let project = project.clone();
+              let button = Button::new(SharedString::from(format!("profile-{project_name}-{profile}")))
+                .label(profile.clone())
+                .xsmall()
+                .on_click(cx.listener(move |_this, _ev, _window, cx| {
+                  let profile = profile.clone();
+                  Self::update_project_config(&project, cx, |config| {
+                    if let Some(idx) = config.profiles.iter().position(|p| *p == profile) {
+                      config.profiles.remove(idx);
+                    } else {
+                      config.profiles.push(profile);
+                    }
+                  });
+                }));
+              if is_active { button.primary() } else { button.ghost() }
+            })),
+        )
+      })
+  }
+
+  /// Per-service buttons. `replicas` is `None` for services without containers.
+  fn render_service_actions(
+    target: &ComposeTarget,
+    service: &str,
+    row_id: &str,
+    is_running: bool,
+    replicas: Option<u32>,
+    has_build: bool,
+    cx: &mut Context<'_, Self>,
+  ) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let action_button = |id: &str, action: ComposeServiceAction, cx: &mut Context<'_, Self>| {
+      let target = target.clone();
+      let service = service.to_string();
+      Button::new(SharedString::from(format!("{id}-{row_id}")))
+        .xsmall()
+        .ghost()
+        .on_click(cx.listener(move |_this, _ev, _window, cx| {
+          services::compose_service_action(target.clone(), service.clone(), action, cx);
+        }))
+    };
+
+    h_flex()
+      .gap(px(2.))
+      .items_center()
+      .child(if is_running {
+        action_button("svc-stop", ComposeServiceAction::Stop, cx)
+          .icon(AppIcon::Stop)
+          .tooltip("Stop service")
+      } else {
+        action_button("svc-start", ComposeServiceAction::Start, cx)
+          .icon(AppIcon::Play)
+          .tooltip("Start service")
+      })
+      .when(replicas.is_some(), |el| {
+        el.child(
+          action_button("svc-restart", ComposeServiceAction::Restart, cx)
+            .icon(AppIcon::Restart)
+            .tooltip("Restart service"),
+        )
+        .child(
+          action_button("svc-recreate", ComposeServiceAction::Recreate, cx)
+            .icon(AppIcon::Refresh)
+            .tooltip("Recreate container"),
+        )
+      })
+      .when(has_build, |el| {
+        el.child(
+          action_button("svc-rebuild", ComposeServiceAction::Rebuild, cx)
+            .label("Build")
+            .tooltip("Rebuild image and recreate"),
+        )
+      })
+      .child(
+        action_button("svc-pull", ComposeServiceAction::Pull, cx)
+          .label("Pull")
+          .tooltip("Pull image"),
+      )
+      .when_some(replicas, |el, replicas| {
+        el.child(
+          action_button(
+            "svc-scale-down",
+            ComposeServiceAction::Scale(replicas.saturating_sub(1)),
+            cx,
+          )
+          .icon(IconName::Minus)
+          .tooltip("Remove a replica"),
+        )
+        .child(
+          div()
+            .min_w(px(16.))
+            .text_xs()
+            .text_center()
+            .text_color(colors.muted_foreground)
+            .child(replicas.to_string()),
+        )
+        .child(
+          action_button("svc-scale-up", ComposeServiceAction::Scale(replicas + 1), cx)
+            .icon(IconName::Plus)
+            .tooltip("Add a replica"),
+        )
+      })
   }
 
-  fn render_service(service: &ComposeService, cx: &mut Context<'_, Self>) -> impl IntoElement {
+  fn render_service(
+    service: &ComposeService,
+    target: &ComposeTarget,
+    replicas: u32,
+    has_build: bool,
+    cx: &mut Context<'_, Self>,
+  ) -> impl IntoElement {
     let colors = cx.theme().colors;
+    let actions = Self::render_service_actions(
+      target,
+      &service.name,
+      &service.container_id,
+      service.state.is_running(),
+      Some(replicas),
+      has_build,
+      cx,
+    );
 
     let status_color = if service.state.is_running() {
       colors.success
@@ -252,16 +714,169 @@ impl ComposeView {
                     .text_color(colors.muted_foreground)
                     .child(service.state.to_string()),
             )
+            .child(actions)
+  }
+
+  /// A service defined in the compose files that has no container yet
+  fn render_missing_service(
+    target: &ComposeTarget,
+    service: &ComposeServiceDefinition,
+    cx: &mut Context<'_, Self>,
+  ) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let project_name = &target.project;
+    let row_id = format!("{project_name}-{}", service.name);
+    let actions = Self::render_service_actions(target, &service.name, &row_id, false, None, service.has_build, cx);
+    let image = match (&service.image, service.has_build) {
+      (Some(image), _) => format!("({image})"),
+      (None, true) => "(build)".to_string(),
+      (None, false) => String::new(),
+    };
+
+    h_flex()
+      .id(SharedString::from(format!("defined-{project_name}-{}", service.name)))
+      .w_full()
+      .h(px(36.))
+      .pl(px(56.))
+      .pr(px(16.))
+      .items_center()
+      .gap(px(8.))
+      .child(
+        div()
+          .w(px(150.))
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .overflow_hidden()
+          .text_ellipsis()
+          .child(service.name.clone()),
+      )
+      .child(
+        div()
+          .flex_1()
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .overflow_hidden()
+          .text_ellipsis()
+          .child(image),
+      )
+      .when(!service.profiles.is_empty(), |el| {
+        el.child(
+          div()
+            .px(px(6.))
+            .py(px(1.))
+            .rounded(px(4.))
+            .bg(colors.secondary)
+            .text_xs()
+            .text_color(colors.secondary_foreground)
+            .child(service.profiles.join(", ")),
+        )
+      })
+      .child(
+        div()
+          .w(px(8.))
+          .h(px(8.))
+          .rounded_full()
+          .border_1()
+          .border_color(colors.muted_foreground),
+      )
+      .child(
+        div()
+          .w(px(80.))
+          .text_xs()
+          .text_color(colors.muted_foreground)
+          .child("Not created"),
+      )
+      .child(actions)
+  }
+
+  fn render_load_errors(&self, cx: &App) -> impl IntoElement {
+    let colors = cx.theme().colors;
+
+    v_flex().w_full().children(self.load_errors.iter().map(|(path, error)| {
+      let path_for_remove = path.clone();
+      h_flex()
+        .w_full()
+        .px(px(16.))
+        .py(px(6.))
+        .gap(px(8.))
+        .items_center()
+        .border_b_1()
+        .border_color(colors.border)
+        .child(Icon::new(IconName::CircleX).size(px(14.)).text_color(colors.danger))
+        .child(
+          div()
+            .flex_1()
+            .text_xs()
+            .text_color(colors.danger)
+            .overflow_hidden()
+            .text_ellipsis()
+            .whitespace_nowrap()
+            .child(error.clone()),
+        )
+        .child(
+          Button::new(SharedString::from(format!("remove-path-{path}")))
+            .icon(Icon::new(AppIcon::Trash))
+            .xsmall()
+            .ghost()
+            .on_click(move |_ev, _window, cx| {
+              Self::unregister_path(&path_for_remove, cx);
+            }),
+        )
+    }))
   }
 }
 
 impl Render for ComposeView {
-  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+  fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
     let colors = cx.theme().colors;
 
-    // Get containers and extract compose projects
+    if let Some(logs_view) = &self.logs_view {
+      return div().size_full().child(logs_view.clone()).into_any_element();
+    }
+    if let Some(editor_view) = &self.editor_view {
+      return div().size_full().child(editor_view.clone()).into_any_element();
+    }
+
+    // Get containers and extract compose projects, then add what the compose files define
     let containers = self.docker_state.read(cx).containers.clone();
-    let projects = extract_compose_projects(&containers);
+    let mut projects = extract_compose_projects(&containers);
+    merge_compose_definitions(&mut projects, self.definitions.clone());
+
+    // Pre-render content to avoid closure escaping issues
+    let content = if projects.is_empty() {
+      Self::render_empty(cx).into_any_element()
+    } else {
+      let mut rows = Vec::new();
+      for project in &projects {
+        let is_expanded = self.expanded_projects.contains(&project.name);
+        rows.push(Self::render_project(project, is_expanded, cx).into_any_element());
+        if is_expanded {
+          rows.push(self.render_project_options(project, window, cx).into_any_element());
+          let target = Self::project_target(project, cx);
+          for service in &project.services {
+            let replicas = project.services.iter().filter(|s| s.name == service.name).count();
+            let has_build = project
+              .definitions
+              .iter()
+              .any(|d| d.name == service.name && d.has_build);
+            rows.push(
+              Self::render_service(
+                service,
+                &target,
+                u32::try_from(replicas).unwrap_or(u32::MAX),
+                has_build,
+                cx,
+              )
+              .into_any_element(),
+            );
+          }
+          for service in project.missing_services(&target.profiles) {
+            rows.push(Self::render_missing_service(&target, service, cx).into_any_element());
+          }
+        }
+      }
+      v_flex().w_full().children(rows).into_any_element()
+    };
 
     div()
             .size_full()
@@ -286,6 +901,17 @@ impl Render for ComposeView {
                     .child(
                         h_flex()
                             .gap(px(8.))
+                            .child(div().w(px(280.)).child(Input::new(&self.path_input).small().w_full()))
+                            .child(
+                                Button::new("add-compose-path")
+                                    .icon(IconName::Plus)
+                                    .label("Add")
+                                    .small()
+                                    .ghost()
+                                    .on_click(cx.listener(|this, _ev, window, cx| {
+                                        this.register_path(window, cx);
+                                    })),
+                            )
                             .child(
                                 Button::new("refresh-compose")
                                     .icon(AppIcon::Restart)
@@ -297,26 +923,15 @@ impl Render for ComposeView {
                             ),
                     ),
             )
+            .child(self.render_load_errors(cx))
             // Content
-            .child({
-                // Pre-render content to avoid closure escaping issues
-                let content = if projects.is_empty() {
-                    Self::render_empty(cx).into_any_element()
-                } else {
-                    v_flex()
-                        .w_full()
-                        .children(projects.iter().map(|project| {
-                            let is_expanded = self.expanded_projects.contains(&project.name);
-                            Self::render_project(project, is_expanded, cx).into_any_element()
-                        }))
-                        .into_any_element()
-                };
-
+            .child(
                 div()
                     .id("compose-scroll")
                     .flex_1()
                     .overflow_y_scrollbar()
-                    .child(content)
-            })
+                    .child(content),
+            )
+            .into_any_element()
   }
 }
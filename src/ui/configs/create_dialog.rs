@@ -0,0 +1,234 @@
+use std::path::PathBuf;
+
+use anyhow::{Result, anyhow, bail};
+use gpui::{App, Context, Entity, FocusHandle, Focusable, Render, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  h_flex,
+  input::{Input, InputState},
+  label::Label,
+  scroll::ScrollableElement,
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::kubernetes::{ConfigKind, parse_file_sources, parse_literal_pairs};
+
+/// What to create, like `kubectl create configmap|secret generic --from-literal --from-file`
+#[derive(Debug, Clone)]
+pub struct CreateConfigOptions {
+  pub name: String,
+  pub namespace: String,
+  pub literals: Vec<(String, String)>,
+  pub files: Vec<(String, PathBuf)>,
+}
+
+/// Dialog for creating a ConfigMap or Secret from literal pairs and files
+pub struct CreateConfigDialog {
+  focus_handle: FocusHandle,
+  kind: ConfigKind,
+  default_namespace: String,
+  name_input: Option<Entity<InputState>>,
+  namespace_input: Option<Entity<InputState>>,
+  literals_input: Option<Entity<InputState>>,
+  files_input: Option<Entity<InputState>>,
+  error: Option<String>,
+}
+
+impl CreateConfigDialog {
+  pub fn new(kind: ConfigKind, default_namespace: String, cx: &mut Context<'_, Self>) -> Self {
+    let focus_handle = cx.focus_handle();
+
+    Self {
+      focus_handle,
+      kind,
+      default_namespace,
+      name_input: None,
+      namespace_input: None,
+      literals_input: None,
+      files_input: None,
+      error: None,
+    }
+  }
+
+  fn ensure_inputs(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    if self.name_input.is_none() {
+      let placeholder = match self.kind {
+        ConfigKind::ConfigMap => "e.g. app-config",
+        ConfigKind::Secret => "e.g. db-credentials",
+      };
+      self.name_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+    }
+    if self.namespace_input.is_none() {
+      let namespace = self.default_namespace.clone();
+      self.namespace_input = Some(cx.new(|cx| {
+        InputState::new(window, cx)
+          .placeholder("default")
+          .default_value(namespace)
+      }));
+    }
+    if self.literals_input.is_none() {
+      self.literals_input = Some(cx.new(|cx| {
+        InputState::new(window, cx)
+          .multi_line(true)
+          .placeholder("LOG_LEVEL=debug\nAPI_URL=http://api:8080")
+      }));
+    }
+    if self.files_input.is_none() {
+      self.files_input = Some(cx.new(|cx| {
+        InputState::new(window, cx)
+          .multi_line(true)
+          .placeholder("~/certs/tls.crt\nconfig.json=./app/config.json")
+      }));
+    }
+  }
+
+  /// Show an error under the form, e.g. when validation fails on submit
+  pub fn set_error(&mut self, error: Option<String>) {
+    self.error = error;
+  }
+
+  pub fn get_options(&self, cx: &App) -> Result<CreateConfigOptions> {
+    let text = |input: Option<&Entity<InputState>>| input.map(|s| s.read(cx).text().to_string()).unwrap_or_default();
+
+    let name = text(self.name_input.as_ref()).trim().to_string();
+    if name.is_empty() {
+      bail!("Name is required");
+    }
+    let namespace = text(self.namespace_input.as_ref()).trim().to_string();
+    let namespace = if namespace.is_empty() {
+      "default".to_string()
+    } else {
+      namespace
+    };
+
+    let literals = parse_literal_pairs(&text(self.literals_input.as_ref())).map_err(|e| anyhow!(e))?;
+    let files = parse_file_sources(&text(self.files_input.as_ref())).map_err(|e| anyhow!(e))?;
+    if let Some((key, _)) = files.iter().find(|(key, _)| literals.iter().any(|(k, _)| k == key)) {
+      bail!("Duplicate key '{key}'");
+    }
+
+    Ok(CreateConfigOptions {
+      name,
+      namespace,
+      literals,
+      files,
+    })
+  }
+
+  fn render_form_row(label: &'static str, content: impl IntoElement, cx: &App) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    h_flex()
+      .w_full()
+      .py(px(12.))
+      .px(px(16.))
+      .justify_between()
+      .items_center()
+      .border_b_1()
+      .border_color(colors.border)
+      .child(Label::new(label).text_color(colors.foreground))
+      .child(content)
+  }
+
+  fn render_source(title: &'static str, description: &'static str, input: &Entity<InputState>, cx: &App) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    v_flex()
+      .w_full()
+      .gap(px(6.))
+      .py(px(12.))
+      .px(px(16.))
+      .border_b_1()
+      .border_color(colors.border)
+      .child(
+        v_flex()
+          .gap(px(2.))
+          .child(Label::new(title).text_color(colors.foreground))
+          .child(div().text_xs().text_color(colors.muted_foreground).child(description)),
+      )
+      .child(div().w_full().h(px(96.)).child(Input::new(input).small().h_full()))
+  }
+}
+
+impl Focusable for CreateConfigDialog {
+  fn focus_handle(&self, _cx: &App) -> FocusHandle {
+    self.focus_handle.clone()
+  }
+}
+
+impl Render for CreateConfigDialog {
+  fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    self.ensure_inputs(window, cx);
+
+    let colors = cx.theme().colors;
+    let name_input = self.name_input.clone().unwrap();
+    let namespace_input = self.namespace_input.clone().unwrap();
+    let literals_input = self.literals_input.clone().unwrap();
+    let files_input = self.files_input.clone().unwrap();
+
+    let files_description = match self.kind {
+      ConfigKind::ConfigMap => "One per line: path, or key=path. Binary files go to binaryData",
+      ConfigKind::Secret => "One per line: path, or key=path. The key defaults to the file name",
+    };
+
+    v_flex()
+      .w_full()
+      .max_h(px(520.))
+      .overflow_y_scrollbar()
+      .child(Self::render_form_row(
+        "Name",
+        div().w(px(250.)).child(Input::new(&name_input).small()),
+        cx,
+      ))
+      .child(Self::render_form_row(
+        "Namespace",
+        div().w(px(250.)).child(Input::new(&namespace_input).small()),
+        cx,
+      ))
+      .child(Self::render_source(
+        "Literal values",
+        "One key=value per line",
+        &literals_input,
+        cx,
+      ))
+      .child(Self::render_source("Files", files_description, &files_input, cx))
+      .when_some(self.error.clone(), |el, error| {
+        el.child(
+          div()
+            .w_full()
+            .px(px(16.))
+            .py(px(8.))
+            .text_sm()
+            .text_color(colors.danger)
+            .child(error),
+        )
+      })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[gpui::test]
+  fn test_create_config_dialog_requires_name(cx: &mut gpui::TestAppContext) {
+    let dialog = cx.new(|cx| CreateConfigDialog::new(ConfigKind::Secret, "default".to_string(), cx));
+
+    dialog.read_with(cx, |dialog, cx| {
+      let err = dialog.get_options(cx).unwrap_err();
+      assert_eq!(err.to_string(), "Name is required");
+    });
+  }
+
+  #[gpui::test]
+  fn test_create_config_dialog_error(cx: &mut gpui::TestAppContext) {
+    let dialog = cx.new(|cx| CreateConfigDialog::new(ConfigKind::ConfigMap, "default".to_string(), cx));
+
+    dialog.update(cx, |dialog, _| {
+      dialog.set_error(Some("Duplicate key 'a'".to_string()));
+    });
+    dialog.read_with(cx, |dialog, _| {
+      assert_eq!(dialog.error.as_deref(), Some("Duplicate key 'a'"));
+    });
+  }
+}
@@ -0,0 +1,836 @@
+use std::collections::HashSet;
+
+use gpui::{Context, Entity, Render, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  Icon, IconName, Selectable, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  menu::DropdownMenu,
+  scroll::ScrollableElement,
+  tab::{Tab, TabBar},
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::assets::AppIcon;
+use crate::kubernetes::{ConfigEntry, ConfigInfo, ConfigKind, PodInfo, is_valid_config_key};
+use crate::services;
+use crate::state::{ConfigDetailTab, DockerState, StateChanged, docker_state};
+
+use super::config_icon;
+use super::list::config_menu;
+
+/// Placeholder shown for Secret values that haven't been revealed
+const MASK: &str = "••••••••";
+
+/// Detail view for a ConfigMap or Secret with tabs
+pub struct ConfigDetail {
+  kind: ConfigKind,
+  docker_state: Entity<DockerState>,
+  config: Option<ConfigInfo>,
+  active_tab: ConfigDetailTab,
+  /// Secret keys whose values are shown in clear text
+  revealed: HashSet<String>,
+  /// Key being edited and its value input
+  editing: Option<(String, Entity<InputState>)>,
+  new_key_input: Option<Entity<InputState>>,
+  new_value_input: Option<Entity<InputState>>,
+  add_error: Option<String>,
+  yaml_content: String,
+  yaml_editor: Option<Entity<InputState>>,
+  last_synced_yaml: String,
+}
+
+impl ConfigDetail {
+  pub fn new(kind: ConfigKind, cx: &mut Context<'_, Self>) -> Self {
+    let docker_state = docker_state(cx);
+
+    cx.subscribe(&docker_state, |this, ds, event: &StateChanged, cx| match event {
+      StateChanged::ConfigYamlLoaded {
+        kind,
+        name,
+        namespace,
+        yaml,
+      } => {
+        if let Some(ref c) = this.config
+          && c.kind == *kind
+          && c.name == *name
+          && c.namespace == *namespace
+        {
+          yaml.clone_into(&mut this.yaml_content);
+          cx.notify();
+        }
+      }
+      StateChanged::ConfigTabRequest {
+        kind,
+        name,
+        namespace,
+        tab,
+      } if *kind == this.kind => {
+        let config = ds.read(cx).get_config(*kind, name, namespace).cloned();
+        if let Some(config) = config {
+          this.reset(config);
+          this.active_tab = *tab;
+          if *tab == ConfigDetailTab::Yaml {
+            services::get_config_yaml(*kind, name.clone(), namespace.clone(), cx);
+          }
+          cx.notify();
+        }
+      }
+      StateChanged::PodsUpdated | StateChanged::DeploymentsUpdated => cx.notify(),
+      _ => {}
+    })
+    .detach();
+
+    Self {
+      kind,
+      docker_state,
+      config: None,
+      active_tab: ConfigDetailTab::Data,
+      revealed: HashSet::new(),
+      editing: None,
+      new_key_input: None,
+      new_value_input: None,
+      add_error: None,
+      yaml_content: String::new(),
+      yaml_editor: None,
+      last_synced_yaml: String::new(),
+    }
+  }
+
+  pub fn config(&self) -> Option<&ConfigInfo> {
+    self.config.as_ref()
+  }
+
+  /// Show another object, hiding everything that was revealed for the previous one
+  fn reset(&mut self, config: ConfigInfo) {
+    self.config = Some(config);
+    self.revealed.clear();
+    self.editing = None;
+    self.add_error = None;
+    self.yaml_content.clear();
+    self.last_synced_yaml.clear();
+  }
+
+  pub fn set_config(&mut self, config: ConfigInfo, cx: &mut Context<'_, Self>) {
+    services::get_config_yaml(config.kind, config.name.clone(), config.namespace.clone(), cx);
+
+    self.reset(config);
+    self.active_tab = ConfigDetailTab::Data;
+    self.yaml_editor = None;
+    cx.notify();
+  }
+
+  /// Update config data without resetting tab or reveal state (for data refresh)
+  pub fn update_config_data(&mut self, config: ConfigInfo, cx: &mut Context<'_, Self>) {
+    // Stop editing a key that no longer exists
+    if let Some((key, _)) = &self.editing
+      && config.entry(key).is_none()
+    {
+      self.editing = None;
+    }
+    self.config = Some(config);
+    cx.notify();
+  }
+
+  fn is_secret(&self) -> bool {
+    self.kind == ConfigKind::Secret
+  }
+
+  fn is_revealed(&self, key: &str) -> bool {
+    !self.is_secret() || self.revealed.contains(key)
+  }
+
+  fn toggle_reveal_all(&mut self, cx: &mut Context<'_, Self>) {
+    let Some(config) = &self.config else {
+      return;
+    };
+    if self.revealed.len() == config.entries.len() {
+      self.revealed.clear();
+    } else {
+      self.revealed = config.entries.iter().map(|e| e.key.clone()).collect();
+    }
+    cx.notify();
+  }
+
+  fn start_edit(&mut self, entry: &ConfigEntry, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let value = entry.value.clone();
+    let input = cx.new(|cx| InputState::new(window, cx).multi_line(true).default_value(value));
+    self.revealed.insert(entry.key.clone());
+    self.editing = Some((entry.key.clone(), input));
+    cx.notify();
+  }
+
+  fn save_edit(&mut self, cx: &mut Context<'_, Self>) {
+    let (Some(config), Some((key, input))) = (&self.config, self.editing.take()) else {
+      return;
+    };
+    let value = input.read(cx).text().to_string();
+    let unchanged = config.entry(&key).is_some_and(|e| e.value == value);
+    if !unchanged {
+      services::set_config_entry(
+        config.kind,
+        config.name.clone(),
+        config.namespace.clone(),
+        key,
+        value,
+        cx,
+      );
+    }
+    cx.notify();
+  }
+
+  fn ensure_add_inputs(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    if self.new_key_input.is_none() {
+      self.new_key_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("key")));
+    }
+    if self.new_value_input.is_none() {
+      self.new_value_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("value")));
+    }
+  }
+
+  fn add_entry(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let (Some(config), Some(key_input), Some(value_input)) = (&self.config, &self.new_key_input, &self.new_value_input)
+    else {
+      return;
+    };
+    let key = key_input.read(cx).text().trim().to_string();
+    let value = value_input.read(cx).text().to_string();
+
+    if !is_valid_config_key(&key) {
+      self.add_error = Some(format!("Invalid key '{key}': use letters, digits, '-', '_' or '.'"));
+    } else if config.entry(&key).is_some() {
+      self.add_error = Some(format!("Key '{key}' already exists"));
+    } else {
+      self.add_error = None;
+      services::set_config_entry(
+        config.kind,
+        config.name.clone(),
+        config.namespace.clone(),
+        key,
+        value,
+        cx,
+      );
+      key_input.update(cx, |input, cx| input.set_value("", window, cx));
+      value_input.update(cx, |input, cx| input.set_value("", window, cx));
+    }
+    cx.notify();
+  }
+
+  fn header_cell(label: &'static str, width: Option<f32>, cx: &Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    let cell = match width {
+      Some(w) => div().w(px(w)).flex_shrink_0(),
+      None => div().flex_1().min_w_0(),
+    };
+    cell
+      .text_xs()
+      .font_weight(gpui::FontWeight::SEMIBOLD)
+      .text_color(colors.muted_foreground)
+      .child(label)
+  }
+
+  fn render_entry(&self, i: usize, entry: &ConfigEntry, config: &ConfigInfo, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = cx.theme().colors;
+    let revealed = self.is_revealed(&entry.key);
+    let editing = self
+      .editing
+      .as_ref()
+      .filter(|(key, _)| *key == entry.key)
+      .map(|(_, input)| input.clone());
+    let can_edit = !config.immutable && !entry.binary;
+
+    let value_cell = if let Some(input) = editing {
+      v_flex()
+        .flex_1()
+        .min_w_0()
+        .gap(px(6.))
+        .child(div().h(px(96.)).child(Input::new(&input).small().h_full()))
+        .child(
+          h_flex()
+            .gap(px(6.))
+            .child(
+              Button::new(("save-entry", i))
+                .label("Save")
+                .primary()
+                .xsmall()
+                .on_click(cx.listener(|this, _ev, _window, cx| {
+                  this.save_edit(cx);
+                })),
+            )
+            .child(
+              Button::new(("cancel-entry", i))
+                .label("Cancel")
+                .ghost()
+                .xsmall()
+                .on_click(cx.listener(|this, _ev, _window, cx| {
+                  this.editing = None;
+                  cx.notify();
+                })),
+            ),
+        )
+    } else {
+      v_flex().flex_1().min_w_0().child(
+        div()
+          .text_xs()
+          .font_family("monospace")
+          .text_color(if revealed {
+            colors.foreground
+          } else {
+            colors.muted_foreground
+          })
+          .child(if revealed {
+            entry.value.clone()
+          } else {
+            MASK.to_string()
+          }),
+      )
+    };
+
+    let key = entry.key.clone();
+    let value = entry.value.clone();
+    let edit_entry = entry.clone();
+    let remove_key = entry.key.clone();
+    let reveal_key = entry.key.clone();
+    let (kind, name, namespace) = (config.kind, config.name.clone(), config.namespace.clone());
+
+    h_flex()
+      .w_full()
+      .py(px(8.))
+      .px(px(12.))
+      .gap(px(8.))
+      .items_start()
+      .rounded(px(6.))
+      .when(i % 2 == 1, |el| el.bg(colors.sidebar.opacity(0.3)))
+      .child(
+        h_flex()
+          .w(px(200.))
+          .flex_shrink_0()
+          .gap(px(6.))
+          .items_center()
+          .child(
+            div()
+              .min_w_0()
+              .text_sm()
+              .font_family("monospace")
+              .text_color(colors.foreground)
+              .text_ellipsis()
+              .overflow_hidden()
+              .whitespace_nowrap()
+              .child(key),
+          )
+          .when(entry.binary, |el| {
+            el.child(
+              div()
+                .flex_shrink_0()
+                .px(px(4.))
+                .rounded(px(4.))
+                .bg(colors.muted_foreground.opacity(0.15))
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .child("binary"),
+            )
+          }),
+      )
+      .child(value_cell)
+      .child(
+        h_flex()
+          .flex_shrink_0()
+          .gap(px(2.))
+          .when(self.is_secret(), |el| {
+            el.child(
+              Button::new(("reveal-entry", i))
+                .icon(if revealed { IconName::EyeOff } else { IconName::Eye })
+                .ghost()
+                .xsmall()
+                .tooltip(if revealed { "Hide value" } else { "Reveal value" })
+                .on_click(cx.listener(move |this, _ev, _window, cx| {
+                  if !this.revealed.remove(&reveal_key) {
+                    this.revealed.insert(reveal_key.clone());
+                  }
+                  cx.notify();
+                })),
+            )
+          })
+          .child(
+            Button::new(("copy-entry", i))
+              .icon(Icon::new(AppIcon::Copy))
+              .ghost()
+              .xsmall()
+              .tooltip("Copy value")
+              .on_click(move |_ev, _window, cx| {
+                cx.write_to_clipboard(gpui::ClipboardItem::new_string(value.clone()));
+              }),
+          )
+          .when(can_edit, |el| {
+            el.child(
+              Button::new(("edit-entry", i))
+                .icon(Icon::new(AppIcon::Edit))
+                .ghost()
+                .xsmall()
+                .tooltip("Edit value")
+                .on_click(cx.listener(move |this, _ev, window, cx| {
+                  this.start_edit(&edit_entry, window, cx);
+                })),
+            )
+          })
+          .when(!config.immutable, |el| {
+            el.child(
+              Button::new(("remove-entry", i))
+                .icon(Icon::new(AppIcon::Trash))
+                .ghost()
+                .xsmall()
+                .tooltip("Remove key")
+                .on_click(move |_ev, _window, cx| {
+                  services::remove_config_entry(kind, name.clone(), namespace.clone(), remove_key.clone(), cx);
+                }),
+            )
+          }),
+      )
+  }
+
+  fn render_data_tab(&mut self, config: &ConfigInfo, window: &mut Window, cx: &mut Context<'_, Self>) -> gpui::Div {
+    if !config.immutable {
+      self.ensure_add_inputs(window, cx);
+    }
+
+    let colors = cx.theme().colors;
+    let all_revealed = self.revealed.len() == config.entries.len();
+
+    let mut summary = format!("{} key(s)", config.entries.len());
+    if let Some(secret_type) = &config.secret_type {
+      summary.push_str(&format!(" · {secret_type}"));
+    }
+    if config.immutable {
+      summary.push_str(" · immutable");
+    }
+
+    let toolbar = h_flex()
+      .w_full()
+      .items_center()
+      .justify_between()
+      .child(div().text_xs().text_color(colors.muted_foreground).child(summary))
+      .when(self.is_secret() && !config.entries.is_empty(), |el| {
+        el.child(
+          Button::new("reveal-all")
+            .label(if all_revealed { "Hide all" } else { "Reveal all" })
+            .icon(if all_revealed { IconName::EyeOff } else { IconName::Eye })
+            .ghost()
+            .xsmall()
+            .on_click(cx.listener(|this, _ev, _window, cx| {
+              this.toggle_reveal_all(cx);
+            })),
+        )
+      });
+
+    let header = h_flex()
+      .w_full()
+      .py(px(8.))
+      .px(px(12.))
+      .gap(px(8.))
+      .bg(colors.sidebar)
+      .rounded_t(px(8.))
+      .child(Self::header_cell("Key", Some(200.), cx))
+      .child(Self::header_cell("Value", None, cx));
+
+    let rows = config
+      .entries
+      .iter()
+      .enumerate()
+      .map(|(i, entry)| self.render_entry(i, entry, config, cx))
+      .collect::<Vec<_>>();
+
+    let mut table = v_flex().w_full().child(header).children(rows);
+    if config.entries.is_empty() {
+      table = table.child(
+        div()
+          .w_full()
+          .py(px(16.))
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .child("No keys"),
+      );
+    }
+
+    let add_row = match (&self.new_key_input, &self.new_value_input) {
+      (Some(key_input), Some(value_input)) if !config.immutable => Some(
+        v_flex()
+          .w_full()
+          .gap(px(4.))
+          .child(
+            h_flex()
+              .w_full()
+              .gap(px(8.))
+              .items_center()
+              .child(div().w(px(200.)).child(Input::new(key_input).small()))
+              .child(div().flex_1().child(Input::new(value_input).small()))
+              .child(
+                Button::new("add-entry")
+                  .label("Add key")
+                  .icon(IconName::Plus)
+                  .small()
+                  .on_click(cx.listener(|this, _ev, window, cx| {
+                    this.add_entry(window, cx);
+                  })),
+              ),
+          )
+          .when_some(self.add_error.clone(), |el, error| {
+            el.child(div().text_xs().text_color(colors.danger).child(error))
+          }),
+      ),
+      _ => None,
+    };
+
+    div().size_full().child(
+      div().w_full().h_full().p(px(16.)).overflow_y_scrollbar().child(
+        v_flex()
+          .w_full()
+          .gap(px(12.))
+          .child(toolbar)
+          .child(table)
+          .children(add_row),
+      ),
+    )
+  }
+
+  fn render_consumers_tab(&self, config: &ConfigInfo, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let state = self.docker_state.read(cx);
+    let pods: Vec<PodInfo> = state
+      .pods
+      .iter()
+      .filter(|pod| config.is_used_by(pod))
+      .cloned()
+      .collect();
+    let deployments: Vec<String> = state
+      .deployments
+      .iter()
+      .filter(|d| d.namespace == config.namespace && !config.usages(&d.config_refs).is_empty())
+      .map(|d| d.name.clone())
+      .collect();
+
+    let colors = cx.theme().colors;
+
+    if pods.is_empty() && deployments.is_empty() {
+      return div().size_full().flex().items_center().justify_center().child(
+        v_flex()
+          .items_center()
+          .gap(px(8.))
+          .child(
+            Icon::new(AppIcon::Pod)
+              .size(px(32.))
+              .text_color(colors.muted_foreground),
+          )
+          .child(
+            div()
+              .text_sm()
+              .text_color(colors.muted_foreground)
+              .child(format!("No pods use this {}", config.kind.label())),
+          ),
+      );
+    }
+
+    let (kind, name, namespace) = (config.kind, config.name.clone(), config.namespace.clone());
+    let restart = h_flex()
+      .w_full()
+      .items_center()
+      .justify_between()
+      .child(
+        div()
+          .text_xs()
+          .text_color(colors.muted_foreground)
+          .child(if deployments.is_empty() {
+            format!("{} pod(s), not managed by a deployment", pods.len())
+          } else {
+            format!("{} pod(s) · deployments: {}", pods.len(), deployments.join(", "))
+          }),
+      )
+      .when(!deployments.is_empty(), |el| {
+        el.child(
+          Button::new("restart-consumers")
+            .label("Restart consumers")
+            .icon(Icon::new(AppIcon::Restart))
+            .small()
+            .tooltip("Rollout restart deployments so they pick up the new values")
+            .on_click(move |_ev, _window, cx| {
+              services::restart_config_consumers(kind, name.clone(), namespace.clone(), cx);
+            }),
+        )
+      });
+
+    let header = h_flex()
+      .w_full()
+      .py(px(8.))
+      .px(px(12.))
+      .gap(px(8.))
+      .bg(colors.sidebar)
+      .rounded_t(px(8.))
+      .child(Self::header_cell("Pod Name", None, cx))
+      .child(Self::header_cell("Used As", Some(100.), cx))
+      .child(Self::header_cell("Status", Some(80.), cx))
+      .child(Self::header_cell("Age", Some(50.), cx))
+      .child(div().w(px(40.)).flex_shrink_0());
+
+    let rows = pods
+      .iter()
+      .enumerate()
+      .map(|(i, pod)| {
+        let status_color = if pod.phase.is_running() {
+          colors.success
+        } else if pod.phase.is_pending() {
+          colors.warning
+        } else {
+          colors.danger
+        };
+        let usages = config
+          .usages(&pod.config_refs)
+          .iter()
+          .map(ToString::to_string)
+          .collect::<Vec<_>>()
+          .join(", ");
+        let pod_name = pod.name.clone();
+        let pod_namespace = pod.namespace.clone();
+
+        h_flex()
+          .w_full()
+          .py(px(8.))
+          .px(px(12.))
+          .gap(px(8.))
+          .rounded(px(6.))
+          .when(i % 2 == 1, |el| el.bg(colors.sidebar.opacity(0.3)))
+          .hover(|el| el.bg(colors.sidebar))
+          .child(
+            div()
+              .flex_1()
+              .min_w_0()
+              .text_sm()
+              .text_color(colors.foreground)
+              .font_family("monospace")
+              .text_ellipsis()
+              .overflow_hidden()
+              .whitespace_nowrap()
+              .child(pod.name.clone()),
+          )
+          .child(
+            div()
+              .w(px(100.))
+              .flex_shrink_0()
+              .text_sm()
+              .text_color(colors.muted_foreground)
+              .child(usages),
+          )
+          .child(
+            div().w(px(80.)).flex_shrink_0().child(
+              div()
+                .px(px(6.))
+                .py(px(2.))
+                .rounded(px(4.))
+                .bg(status_color.opacity(0.15))
+                .text_xs()
+                .text_color(status_color)
+                .child(pod.phase.to_string()),
+            ),
+          )
+          .child(
+            div()
+              .w(px(50.))
+              .flex_shrink_0()
+              .text_sm()
+              .text_color(colors.muted_foreground)
+              .child(pod.age.clone()),
+          )
+          .child(
+            div().w(px(40.)).flex_shrink_0().flex().justify_end().child(
+              Button::new(("view-pod", i))
+                .icon(IconName::Eye)
+                .ghost()
+                .xsmall()
+                .on_click(move |_ev, _window, cx| {
+                  services::open_pod_info(pod_name.clone(), pod_namespace.clone(), cx);
+                }),
+            ),
+          )
+      })
+      .collect::<Vec<_>>();
+
+    div().size_full().p(px(16.)).child(
+      v_flex()
+        .w_full()
+        .gap(px(8.))
+        .child(restart)
+        .child(v_flex().w_full().child(header).children(rows)),
+    )
+  }
+
+  fn render_yaml_tab(&self, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    if self.yaml_content.is_empty() {
+      return v_flex().size_full().p(px(16.)).child(
+        div()
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .child("Loading YAML..."),
+      );
+    }
+
+    if let Some(ref editor) = self.yaml_editor {
+      return div()
+        .size_full()
+        .child(Input::new(editor).size_full().appearance(false).disabled(true));
+    }
+
+    // Fallback to plain text
+    div().size_full().child(
+      div()
+        .size_full()
+        .overflow_y_scrollbar()
+        .bg(colors.sidebar)
+        .p(px(12.))
+        .font_family("monospace")
+        .text_xs()
+        .text_color(colors.foreground)
+        .child(self.yaml_content.clone()),
+    )
+  }
+
+  fn render_empty(&self, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    let label = self.kind.label();
+
+    div().size_full().flex().items_center().justify_center().child(
+      v_flex()
+        .items_center()
+        .gap(px(16.))
+        .child(
+          div()
+            .size(px(64.))
+            .rounded(px(12.))
+            .bg(colors.sidebar)
+            .flex()
+            .items_center()
+            .justify_center()
+            .child(
+              Icon::new(config_icon(self.kind))
+                .size(px(48.))
+                .text_color(colors.muted_foreground),
+            ),
+        )
+        .child(
+          div()
+            .text_lg()
+            .font_weight(gpui::FontWeight::SEMIBOLD)
+            .text_color(colors.secondary_foreground)
+            .child(format!("Select a {label}")),
+        )
+        .child(
+          div()
+            .text_sm()
+            .text_color(colors.muted_foreground)
+            .child(format!("Click on a {label} to view its keys")),
+        ),
+    )
+  }
+}
+
+impl Render for ConfigDetail {
+  fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    // Create yaml editor if needed
+    if self.yaml_editor.is_none() && self.config.is_some() {
+      self.yaml_editor = Some(cx.new(|cx| {
+        InputState::new(window, cx)
+          .multi_line(true)
+          .code_editor("yaml")
+          .line_number(true)
+          .searchable(true)
+          .soft_wrap(false)
+      }));
+    }
+
+    // Sync yaml editor content
+    if let Some(ref editor) = self.yaml_editor
+      && !self.yaml_content.is_empty()
+      && self.last_synced_yaml != self.yaml_content
+    {
+      let yaml_clone = self.yaml_content.clone();
+      editor.update(cx, |state, cx| {
+        state.replace(&yaml_clone, window, cx);
+      });
+      self.last_synced_yaml = self.yaml_content.clone();
+    }
+
+    let colors = cx.theme().colors;
+
+    let Some(config) = self.config.clone() else {
+      return div().size_full().child(self.render_empty(cx));
+    };
+
+    let active_tab = self.active_tab;
+
+    let tab_bar = TabBar::new("config-tabs")
+      .flex_1()
+      .py(px(0.))
+      .child(
+        Tab::new()
+          .label("Data")
+          .selected(active_tab == ConfigDetailTab::Data)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = ConfigDetailTab::Data;
+            cx.notify();
+          })),
+      )
+      .child(
+        Tab::new()
+          .label("Consumers")
+          .selected(active_tab == ConfigDetailTab::Consumers)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = ConfigDetailTab::Consumers;
+            cx.notify();
+          })),
+      )
+      .child(
+        Tab::new()
+          .label("YAML")
+          .selected(active_tab == ConfigDetailTab::Yaml)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = ConfigDetailTab::Yaml;
+            if let Some(ref c) = this.config {
+              services::get_config_yaml(c.kind, c.name.clone(), c.namespace.clone(), cx);
+            }
+          })),
+      );
+
+    let menu_config = config.clone();
+    let actions = Button::new("config-actions")
+      .icon(IconName::Ellipsis)
+      .ghost()
+      .small()
+      .dropdown_menu(move |menu, _window, _cx| config_menu(menu, &menu_config));
+
+    let content = match active_tab {
+      ConfigDetailTab::Data => self.render_data_tab(&config, window, cx),
+      ConfigDetailTab::Consumers => self.render_consumers_tab(&config, cx),
+      ConfigDetailTab::Yaml => self.render_yaml_tab(cx),
+    };
+
+    div()
+      .size_full()
+      .flex()
+      .flex_col()
+      .overflow_hidden()
+      .child(
+        h_flex()
+          .w_full()
+          .px(px(16.))
+          .py(px(8.))
+          .gap(px(12.))
+          .items_center()
+          .border_b_1()
+          .border_color(colors.border)
+          .flex_shrink_0()
+          .child(tab_bar)
+          .child(actions),
+      )
+      .child(div().flex_1().min_h_0().overflow_hidden().child(content))
+  }
+}
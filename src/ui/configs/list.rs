@@ -0,0 +1,543 @@
+use gpui::{App, Context, Entity, Render, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  Icon, IconName, IndexPath, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  label::Label,
+  list::{List, ListDelegate, ListEvent, ListItem, ListState},
+  menu::{DropdownMenu, PopupMenu, PopupMenuItem},
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::assets::AppIcon;
+use crate::kubernetes::{ConfigInfo, ConfigKind};
+use crate::services;
+use crate::state::{ConfigDetailTab, DockerState, LoadState, Selection, StateChanged, docker_state};
+use crate::ui::components::{render_error, render_loading};
+
+use super::config_icon;
+
+/// Config list events emitted to parent
+pub enum ConfigListEvent {
+  Selected(ConfigInfo),
+  NewConfig,
+}
+
+/// Actions for a ConfigMap or Secret, shared by the list row menu and the detail toolbar
+pub(super) fn config_menu(menu: PopupMenu, config: &ConfigInfo) -> PopupMenu {
+  let kind = config.kind;
+  let name = config.name.clone();
+  let ns = config.namespace.clone();
+  let is_system = matches!(ns.as_str(), "kube-system" | "kube-public" | "kube-node-lease");
+
+  let mut menu = menu
+    .item(
+      PopupMenuItem::new("Restart Consumers")
+        .icon(Icon::new(AppIcon::Restart))
+        .on_click({
+          let (name, ns) = (name.clone(), ns.clone());
+          move |_, _, cx| {
+            services::restart_config_consumers(kind, name.clone(), ns.clone(), cx);
+          }
+        }),
+    )
+    .separator()
+    .item(PopupMenuItem::new("View YAML").icon(IconName::File).on_click({
+      let (name, ns) = (name.clone(), ns.clone());
+      move |_, _, cx| {
+        services::open_config_tab(kind, name.clone(), ns.clone(), ConfigDetailTab::Yaml, cx);
+      }
+    }));
+
+  // Only show delete for non-system objects
+  if !is_system {
+    menu =
+      menu.separator().item(
+        PopupMenuItem::new("Delete")
+          .icon(Icon::new(AppIcon::Trash))
+          .on_click(move |_, _, cx| {
+            services::delete_config(kind, name.clone(), ns.clone(), cx);
+          }),
+      );
+  }
+
+  menu
+}
+
+/// Delegate for the ConfigMap or Secret list
+pub struct ConfigListDelegate {
+  kind: ConfigKind,
+  docker_state: Entity<DockerState>,
+  search_query: String,
+}
+
+impl ConfigListDelegate {
+  fn configs(&self, cx: &App) -> Vec<ConfigInfo> {
+    let state = self.docker_state.read(cx);
+    state
+      .configs(self.kind)
+      .iter()
+      .filter(|c| state.selected_namespace == "all" || c.namespace == state.selected_namespace)
+      .cloned()
+      .collect()
+  }
+
+  fn filtered_configs(&self, cx: &App) -> Vec<ConfigInfo> {
+    let configs = self.configs(cx);
+    if self.search_query.is_empty() {
+      return configs;
+    }
+
+    let query = self.search_query.to_lowercase();
+    configs
+      .into_iter()
+      .filter(|c| {
+        c.name.to_lowercase().contains(&query)
+          || c.namespace.to_lowercase().contains(&query)
+          || c.entries.iter().any(|e| e.key.to_lowercase().contains(&query))
+      })
+      .collect()
+  }
+
+  pub fn set_search_query(&mut self, query: String) {
+    self.search_query = query;
+  }
+}
+
+impl ListDelegate for ConfigListDelegate {
+  type Item = ListItem;
+
+  fn items_count(&self, _section: usize, cx: &App) -> usize {
+    self.filtered_configs(cx).len()
+  }
+
+  fn render_item(
+    &mut self,
+    ix: IndexPath,
+    _window: &mut Window,
+    cx: &mut Context<'_, ListState<Self>>,
+  ) -> Option<Self::Item> {
+    let configs = self.filtered_configs(cx);
+    let config = configs.get(ix.row)?.clone();
+    let colors = &cx.theme().colors;
+
+    // Use global selection as single source of truth
+    let global_selection = &self.docker_state.read(cx).selection;
+    let is_selected = matches!(global_selection, Selection::Config { kind, name, namespace } if *kind == config.kind && *name == config.name && *namespace == config.namespace);
+
+    let icon_bg = match config.kind {
+      ConfigKind::ConfigMap => colors.primary,
+      ConfigKind::Secret => colors.warning,
+    };
+    let badge = match &config.secret_type {
+      Some(secret_type) if secret_type != "Opaque" => secret_type.clone(),
+      _ => format!("{} key(s)", config.entries.len()),
+    };
+    let subtitle = if config.immutable {
+      format!("{} - {} - immutable", config.namespace, config.age)
+    } else {
+      format!("{} - {}", config.namespace, config.age)
+    };
+
+    let menu_config = config.clone();
+    let menu_button = Button::new(("menu", ix.row))
+      .icon(IconName::Ellipsis)
+      .ghost()
+      .xsmall()
+      .dropdown_menu(move |menu, _window, _cx| config_menu(menu, &menu_config));
+
+    let item_content = h_flex()
+      .w_full()
+      .items_center()
+      .justify_between()
+      .gap(px(8.))
+      .child(
+        h_flex()
+          .flex_1()
+          .min_w_0()
+          .items_center()
+          .gap(px(10.))
+          .child(
+            div()
+              .size(px(36.))
+              .flex_shrink_0()
+              .rounded(px(8.))
+              .bg(icon_bg)
+              .flex()
+              .items_center()
+              .justify_center()
+              .child(Icon::new(config_icon(config.kind)).text_color(colors.background)),
+          )
+          .child(
+            v_flex()
+              .flex_1()
+              .min_w_0()
+              .gap(px(2.))
+              .child(
+                div()
+                  .text_sm()
+                  .font_weight(gpui::FontWeight::MEDIUM)
+                  .text_ellipsis()
+                  .overflow_hidden()
+                  .whitespace_nowrap()
+                  .child(config.name.clone()),
+              )
+              .child(
+                div()
+                  .text_xs()
+                  .text_color(colors.muted_foreground)
+                  .text_ellipsis()
+                  .overflow_hidden()
+                  .whitespace_nowrap()
+                  .child(subtitle),
+              ),
+          )
+          .child(
+            div()
+              .flex_shrink_0()
+              .px(px(8.))
+              .py(px(2.))
+              .rounded(px(4.))
+              .bg(icon_bg.opacity(0.2))
+              .text_xs()
+              .font_weight(gpui::FontWeight::MEDIUM)
+              .text_color(icon_bg)
+              .child(badge),
+          ),
+      )
+      .child(div().flex_shrink_0().child(menu_button));
+
+    Some(
+      ListItem::new(ix)
+        .py(px(6.))
+        .rounded(px(6.))
+        .overflow_hidden()
+        .selected(is_selected)
+        .child(item_content),
+    )
+  }
+
+  fn set_selected_index(
+    &mut self,
+    _ix: Option<IndexPath>,
+    _window: &mut Window,
+    cx: &mut Context<'_, ListState<Self>>,
+  ) {
+    // Selection is managed globally via DockerState.selection
+    cx.notify();
+  }
+}
+
+/// Self-contained list of ConfigMaps or Secrets
+pub struct ConfigList {
+  kind: ConfigKind,
+  docker_state: Entity<DockerState>,
+  list_state: Entity<ListState<ConfigListDelegate>>,
+  search_input: Option<Entity<InputState>>,
+  search_visible: bool,
+  search_query: String,
+}
+
+impl ConfigList {
+  pub fn new(kind: ConfigKind, window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
+    let docker_state = docker_state(cx);
+
+    let delegate = ConfigListDelegate {
+      kind,
+      docker_state: docker_state.clone(),
+      search_query: String::new(),
+    };
+
+    let list_state = cx.new(|cx| ListState::new(delegate, window, cx));
+
+    cx.subscribe(&list_state, |_this, state, event: &ListEvent, cx| match event {
+      ListEvent::Select(ix) | ListEvent::Confirm(ix) => {
+        let filtered = state.read(cx).delegate().filtered_configs(cx);
+        if let Some(config) = filtered.get(ix.row) {
+          cx.emit(ConfigListEvent::Selected(config.clone()));
+        }
+      }
+      ListEvent::Cancel => {}
+    })
+    .detach();
+
+    cx.subscribe(&docker_state, move |this, _state, event: &StateChanged, cx| {
+      let refresh = match event {
+        StateChanged::ConfigsUpdated { kind: updated } => *updated == kind,
+        StateChanged::NamespacesUpdated | StateChanged::SelectionChanged => true,
+        _ => false,
+      };
+      if refresh {
+        this.list_state.update(cx, |_state, cx| {
+          cx.notify();
+        });
+        cx.notify();
+      }
+    })
+    .detach();
+
+    Self {
+      kind,
+      docker_state,
+      list_state,
+      search_input: None,
+      search_visible: false,
+      search_query: String::new(),
+    }
+  }
+
+  fn ensure_search_input(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    if self.search_input.is_none() {
+      let placeholder = format!("Search {}...", self.kind.plural().to_lowercase());
+      self.search_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder(placeholder)));
+    }
+  }
+
+  fn sync_search_query(&mut self, cx: &mut Context<'_, Self>) {
+    if let Some(input) = &self.search_input {
+      let current_text = input.read(cx).text().to_string();
+      if current_text != self.search_query {
+        current_text.clone_into(&mut self.search_query);
+        self.list_state.update(cx, |state, cx| {
+          state.delegate_mut().set_search_query(current_text);
+          cx.notify();
+        });
+      }
+    }
+  }
+
+  fn toggle_search(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    self.search_visible = !self.search_visible;
+    if self.search_visible {
+      self.ensure_search_input(window, cx);
+    } else {
+      self.search_query.clear();
+      self.search_input = None;
+      self.list_state.update(cx, |state, cx| {
+        state.delegate_mut().set_search_query(String::new());
+        cx.notify();
+      });
+    }
+    cx.notify();
+  }
+
+  fn render_placeholder(&self, title: String, message: String, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    v_flex()
+      .flex_1()
+      .w_full()
+      .items_center()
+      .justify_center()
+      .gap(px(16.))
+      .py(px(48.))
+      .child(
+        div()
+          .size(px(64.))
+          .rounded(px(12.))
+          .bg(colors.sidebar)
+          .flex()
+          .items_center()
+          .justify_center()
+          .child(
+            Icon::new(config_icon(self.kind))
+              .size(px(32.))
+              .text_color(colors.muted_foreground),
+          ),
+      )
+      .child(
+        div()
+          .text_xl()
+          .font_weight(gpui::FontWeight::SEMIBOLD)
+          .text_color(colors.secondary_foreground)
+          .child(title),
+      )
+      .child(div().text_sm().text_color(colors.muted_foreground).child(message))
+  }
+
+  fn render_namespace_selector(&self, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let state = self.docker_state.read(cx);
+    let selected = state.selected_namespace.clone();
+    let namespaces = state.namespaces.clone();
+
+    let display = if selected == "all" { "All".to_string() } else { selected };
+
+    Button::new("namespace-selector")
+      .label(display)
+      .ghost()
+      .compact()
+      .dropdown_menu(move |menu, _window, _cx| {
+        let mut menu = menu.item(PopupMenuItem::new("All Namespaces").on_click(|_, _, cx| {
+          services::set_namespace("all".to_string(), cx);
+        }));
+
+        if !namespaces.is_empty() {
+          menu = menu.separator();
+          for ns in &namespaces {
+            let ns = ns.clone();
+            menu = menu.item(PopupMenuItem::new(ns.clone()).on_click(move |_, _, cx| {
+              services::set_namespace(ns.clone(), cx);
+            }));
+          }
+        }
+
+        menu
+      })
+  }
+}
+
+impl gpui::EventEmitter<ConfigListEvent> for ConfigList {}
+
+impl Render for ConfigList {
+  fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let kind = self.kind;
+    let state = self.docker_state.read(cx);
+    let total_count = state.configs(kind).len();
+    let load_state = state.configs_state(kind);
+    let k8s_available = state.k8s_available;
+
+    let filtered_count = self.list_state.read(cx).delegate().filtered_configs(cx).len();
+    let is_filtering = !self.search_query.is_empty();
+
+    let subtitle = match &load_state {
+      LoadState::NotLoaded | LoadState::Loading => "Loading...".to_string(),
+      LoadState::Error(_) => "Error loading".to_string(),
+      LoadState::Loaded if is_filtering => format!("{filtered_count} of {total_count}"),
+      LoadState::Loaded => format!("{total_count} total"),
+    };
+
+    let colors = cx.theme().colors;
+    let search_visible = self.search_visible;
+
+    if search_visible {
+      self.ensure_search_input(window, cx);
+      self.sync_search_query(cx);
+    }
+
+    let toolbar = h_flex()
+      .h(px(52.))
+      .w_full()
+      .px(px(16.))
+      .border_b_1()
+      .border_color(colors.border)
+      .items_center()
+      .justify_between()
+      .flex_shrink_0()
+      .child(
+        v_flex()
+          .child(Label::new(kind.plural()))
+          .child(div().text_xs().text_color(colors.muted_foreground).child(subtitle)),
+      )
+      .child(
+        h_flex()
+          .items_center()
+          .gap(px(8.))
+          .child(self.render_namespace_selector(cx))
+          .child(
+            Button::new("search")
+              .icon(Icon::new(AppIcon::Search))
+              .when(search_visible, Button::primary)
+              .when(!search_visible, ButtonVariants::ghost)
+              .compact()
+              .on_click(cx.listener(|this, _ev, window, cx| {
+                this.toggle_search(window, cx);
+              })),
+          )
+          .child(
+            Button::new("refresh")
+              .icon(Icon::new(AppIcon::Restart))
+              .ghost()
+              .compact()
+              .on_click(move |_ev, _window, cx| {
+                services::refresh_configs(kind, cx);
+              }),
+          )
+          .child(
+            Button::new("add")
+              .icon(Icon::new(AppIcon::Plus))
+              .ghost()
+              .compact()
+              .on_click(cx.listener(|_this, _ev, _window, cx| {
+                cx.emit(ConfigListEvent::NewConfig);
+              })),
+          ),
+      );
+
+    let search_bar = search_visible.then(|| {
+      h_flex()
+        .w_full()
+        .h(px(40.))
+        .px(px(12.))
+        .gap(px(8.))
+        .items_center()
+        .bg(colors.sidebar)
+        .border_b_1()
+        .border_color(colors.border)
+        .child(
+          Icon::new(AppIcon::Search)
+            .size(px(16.))
+            .text_color(colors.muted_foreground),
+        )
+        .child(div().flex_1().when_some(self.search_input.clone(), |el, input| {
+          el.child(Input::new(&input).small().w_full())
+        }))
+        .when(!self.search_query.is_empty(), |el| {
+          el.child(
+            Button::new("clear-search")
+              .icon(IconName::Close)
+              .ghost()
+              .xsmall()
+              .on_click(cx.listener(|this, _ev, window, cx| {
+                this.toggle_search(window, cx);
+              })),
+          )
+        })
+    });
+
+    let content: gpui::Div = match &load_state {
+      LoadState::NotLoaded | LoadState::Loading => render_loading(&kind.plural().to_lowercase(), cx),
+      LoadState::Error(e) => render_error(
+        &kind.plural().to_lowercase(),
+        e,
+        move |_ev, _window, cx| {
+          services::refresh_configs(kind, cx);
+        },
+        cx,
+      ),
+      LoadState::Loaded if filtered_count == 0 && is_filtering => self.render_placeholder(
+        "No Results".to_string(),
+        format!("No {} match \"{}\"", kind.plural().to_lowercase(), self.search_query),
+        cx,
+      ),
+      LoadState::Loaded if filtered_count == 0 && !k8s_available => self.render_placeholder(
+        "Kubernetes Unavailable".to_string(),
+        "Start a Colima VM with Kubernetes enabled".to_string(),
+        cx,
+      ),
+      LoadState::Loaded if filtered_count == 0 => self.render_placeholder(
+        format!("No {}", kind.plural()),
+        format!("No {} in this namespace", kind.plural().to_lowercase()),
+        cx,
+      ),
+      LoadState::Loaded => div().size_full().p(px(8.)).child(List::new(&self.list_state)),
+    };
+
+    div()
+      .size_full()
+      .flex()
+      .flex_col()
+      .overflow_hidden()
+      .child(toolbar)
+      .children(search_bar)
+      .child(
+        div()
+          .id("config-list-scroll")
+          .flex_1()
+          .min_h_0()
+          .overflow_hidden()
+          .child(content),
+      )
+  }
+}
@@ -0,0 +1,17 @@
+pub mod create_dialog;
+mod detail;
+mod list;
+mod view;
+
+use crate::assets::AppIcon;
+use crate::kubernetes::ConfigKind;
+
+pub use view::ConfigsView;
+
+/// Icon shown for ConfigMaps or Secrets in lists and empty states
+pub fn config_icon(kind: ConfigKind) -> AppIcon {
+  match kind {
+    ConfigKind::ConfigMap => AppIcon::Settings,
+    ConfigKind::Secret => AppIcon::Shield,
+  }
+}
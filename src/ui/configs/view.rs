@@ -0,0 +1,182 @@
+use std::time::Duration;
+
+use gpui::{App, Context, Entity, Render, Styled, Timer, Window, div, prelude::*, px};
+use gpui_component::theme::ActiveTheme;
+
+use super::detail::ConfigDetail;
+use super::list::{ConfigList, ConfigListEvent};
+use crate::kubernetes::{ConfigInfo, ConfigKind};
+use crate::services;
+use crate::state::{DockerState, Selection, StateChanged, docker_state, settings_state};
+use crate::ui::dialogs;
+
+/// ConfigMaps or Secrets view with list and detail panels
+pub struct ConfigsView {
+  kind: ConfigKind,
+  docker_state: Entity<DockerState>,
+  list: Entity<ConfigList>,
+  detail: Entity<ConfigDetail>,
+}
+
+impl ConfigsView {
+  /// Get the currently selected object of this view's kind from global state
+  fn selected_config(&self, cx: &App) -> Option<ConfigInfo> {
+    let state = self.docker_state.read(cx);
+    match &state.selection {
+      Selection::Config { kind, name, namespace } if *kind == self.kind => {
+        state.get_config(*kind, name, namespace).cloned()
+      }
+      _ => None,
+    }
+  }
+
+  fn select(&mut self, config: &ConfigInfo, cx: &mut Context<'_, Self>) {
+    self.docker_state.update(cx, |state, _cx| {
+      state.set_selection(Selection::Config {
+        kind: config.kind,
+        name: config.name.clone(),
+        namespace: config.namespace.clone(),
+      });
+    });
+  }
+
+  pub fn new(kind: ConfigKind, window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
+    let docker_state = docker_state(cx);
+
+    let list = cx.new(|cx| ConfigList::new(kind, window, cx));
+    let detail = cx.new(|cx| ConfigDetail::new(kind, cx));
+
+    cx.subscribe_in(
+      &list,
+      window,
+      move |this, _list, event: &ConfigListEvent, window, cx| match event {
+        ConfigListEvent::Selected(config) => {
+          this.detail.update(cx, |detail, cx| {
+            detail.set_config(config.clone(), cx);
+          });
+          this.select(config, cx);
+          cx.notify();
+        }
+        ConfigListEvent::NewConfig => {
+          dialogs::open_create_config_dialog(kind, window, cx);
+        }
+      },
+    )
+    .detach();
+
+    cx.subscribe(&docker_state, move |this, ds, event: &StateChanged, cx| {
+      match event {
+        StateChanged::ConfigTabRequest {
+          kind: requested,
+          name,
+          namespace,
+          ..
+        } if *requested == kind => {
+          let config = ds.read(cx).get_config(kind, name, namespace).cloned();
+          if let Some(config) = config {
+            this.select(&config, cx);
+            cx.notify();
+          }
+        }
+        StateChanged::SelectionChanged => {
+          // Selections made elsewhere (e.g. global search) still need the detail loaded
+          if let Some(config) = this.selected_config(cx) {
+            let shown = this
+              .detail
+              .read(cx)
+              .config()
+              .map(|c| (c.name.clone(), c.namespace.clone()));
+            if shown != Some((config.name.clone(), config.namespace.clone())) {
+              this.detail.update(cx, |detail, cx| {
+                detail.set_config(config, cx);
+              });
+            }
+          }
+          cx.notify();
+        }
+        StateChanged::ConfigsUpdated { kind: updated } if *updated == kind => {
+          let selected_key = match &this.docker_state.read(cx).selection {
+            Selection::Config {
+              kind: selected,
+              name,
+              namespace,
+            } if *selected == kind => Some((name.clone(), namespace.clone())),
+            _ => None,
+          };
+
+          if let Some((name, namespace)) = selected_key {
+            let config = ds.read(cx).get_config(kind, &name, &namespace).cloned();
+            if let Some(config) = config {
+              // Preserve tab and reveal state during refresh
+              this.detail.update(cx, |detail, cx| {
+                detail.update_config_data(config, cx);
+              });
+            } else {
+              // Object was deleted
+              this.docker_state.update(cx, |s, _| {
+                s.set_selection(Selection::None);
+              });
+            }
+          }
+          cx.notify();
+        }
+        StateChanged::NamespacesUpdated => {
+          services::refresh_configs(kind, cx);
+        }
+        _ => {}
+      }
+    })
+    .detach();
+
+    // Start periodic refresh
+    let refresh_interval = settings_state(cx).read(cx).settings.container_refresh_interval;
+    cx.spawn(async move |_this, cx| {
+      loop {
+        Timer::after(Duration::from_secs(refresh_interval)).await;
+        let _ = cx.update(|cx| {
+          services::refresh_configs(kind, cx);
+        });
+      }
+    })
+    .detach();
+
+    // Trigger initial data load; consumers are matched against pods and deployments
+    services::refresh_configs(kind, cx);
+    services::refresh_pods(cx);
+    services::refresh_deployments(cx);
+
+    Self {
+      kind,
+      docker_state,
+      list,
+      detail,
+    }
+  }
+}
+
+impl Render for ConfigsView {
+  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let has_selection = self.selected_config(cx).is_some();
+
+    div()
+      .size_full()
+      .flex()
+      .overflow_hidden()
+      .child(
+        // Left: list - fixed width when selected, full width when not
+        div()
+          .when(has_selection, |el| {
+            el.w(px(320.)).border_r_1().border_color(colors.border)
+          })
+          .when(!has_selection, gpui::Styled::flex_1)
+          .h_full()
+          .flex_shrink_0()
+          .overflow_hidden()
+          .child(self.list.clone()),
+      )
+      .when(has_selection, |el| {
+        el.child(div().flex_1().h_full().overflow_hidden().child(self.detail.clone()))
+      })
+  }
+}
@@ -15,10 +15,13 @@ use std::rc::Rc;
 pub use crate::state::ContainerDetailTab;
 
 use crate::assets::AppIcon;
-use crate::docker::{ContainerFileEntry, ContainerInfo};
+use crate::docker::{ContainerFileEntry, ContainerInfo, NetworkInfo};
 use crate::terminal::TerminalView;
 use crate::ui::components::{FileExplorer, FileExplorerConfig, FileExplorerState, ProcessView};
 
+use super::diagnostics_panel::DiagnosticsPanel;
+use super::exec_panel::ExecPanel;
+
 type ContainerActionCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
 type TabChangeCallback = Rc<dyn Fn(&ContainerDetailTab, &mut Window, &mut App) + 'static>;
 type RefreshCallback = Rc<dyn Fn(&(), &mut Window, &mut App) + 'static>;
@@ -27,6 +30,8 @@ type FileSelectCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
 type CloseViewerCallback = Rc<dyn Fn(&(), &mut Window, &mut App) + 'static>;
 type SymlinkClickCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
 type OpenInEditorCallback = Rc<dyn Fn(&(String, bool), &mut Window, &mut App) + 'static>;
+/// Called with `(network_id, container_id)`
+type NetworkDisconnectCallback = Rc<dyn Fn(&(String, String), &mut Window, &mut App) + 'static>;
 
 /// State for container detail tabs
 #[derive(Debug, Clone, Default)]
@@ -61,10 +66,14 @@ impl ContainerTabState {
 
 pub struct ContainerDetail {
   container: Option<ContainerInfo>,
+  /// Networks the container is attached to
+  networks: Vec<NetworkInfo>,
   active_tab: ContainerDetailTab,
   container_state: Option<ContainerTabState>,
   terminal_view: Option<Entity<TerminalView>>,
   process_view: Option<Entity<ProcessView>>,
+  exec_panel: Option<Entity<ExecPanel>>,
+  diagnostics_panel: Option<Entity<DiagnosticsPanel>>,
   logs_editor: Option<Entity<InputState>>,
   inspect_editor: Option<Entity<InputState>>,
   file_content_editor: Option<Entity<InputState>>,
@@ -79,16 +88,21 @@ pub struct ContainerDetail {
   on_close_file_viewer: Option<CloseViewerCallback>,
   on_symlink_click: Option<SymlinkClickCallback>,
   on_open_in_editor: Option<OpenInEditorCallback>,
+  on_connect_network: Option<ContainerActionCallback>,
+  on_disconnect_network: Option<NetworkDisconnectCallback>,
 }
 
 impl ContainerDetail {
   pub fn new() -> Self {
     Self {
       container: None,
+      networks: Vec::new(),
       active_tab: ContainerDetailTab::Info,
       container_state: None,
       terminal_view: None,
       process_view: None,
+      exec_panel: None,
+      diagnostics_panel: None,
       logs_editor: None,
       inspect_editor: None,
       file_content_editor: None,
@@ -103,6 +117,8 @@ impl ContainerDetail {
       on_close_file_viewer: None,
       on_symlink_click: None,
       on_open_in_editor: None,
+      on_connect_network: None,
+      on_disconnect_network: None,
     }
   }
 
@@ -111,6 +127,11 @@ impl ContainerDetail {
     self
   }
 
+  pub fn networks(mut self, networks: Vec<NetworkInfo>) -> Self {
+    self.networks = networks;
+    self
+  }
+
   pub fn active_tab(mut self, tab: ContainerDetailTab) -> Self {
     self.active_tab = tab;
     self
@@ -131,6 +152,16 @@ impl ContainerDetail {
     self
   }
 
+  pub fn exec_panel(mut self, panel: Option<Entity<ExecPanel>>) -> Self {
+    self.exec_panel = panel;
+    self
+  }
+
+  pub fn diagnostics_panel(mut self, panel: Option<Entity<DiagnosticsPanel>>) -> Self {
+    self.diagnostics_panel = panel;
+    self
+  }
+
   pub fn logs_editor(mut self, editor: Option<Entity<InputState>>) -> Self {
     self.logs_editor = editor;
     self
@@ -234,6 +265,22 @@ impl ContainerDetail {
     self
   }
 
+  pub fn on_connect_network<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&str, &mut Window, &mut App) + 'static,
+  {
+    self.on_connect_network = Some(Rc::new(callback));
+    self
+  }
+
+  pub fn on_disconnect_network<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&(String, String), &mut Window, &mut App) + 'static,
+  {
+    self.on_disconnect_network = Some(Rc::new(callback));
+    self
+  }
+
   fn render_empty(cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
@@ -260,7 +307,7 @@ impl ContainerDetail {
       )
   }
 
-  fn render_info_tab(container: &ContainerInfo, cx: &App) -> gpui::Div {
+  fn render_info_tab(&self, container: &ContainerInfo, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
     let info_row = |label: &str, value: String| {
@@ -319,6 +366,93 @@ impl ContainerDetail {
             .unwrap_or_default(),
         ))
       })
+      .child(self.render_networks_section(container, cx))
+  }
+
+  fn render_networks_section(&self, container: &ContainerInfo, cx: &App) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    let on_connect = self.on_connect_network.clone();
+    let container_id = container.id.clone();
+
+    let rows = self.networks.iter().enumerate().map(|(i, network)| {
+      let endpoint = network.containers.get(&container.id);
+      let address = endpoint
+        .and_then(|e| e.ipv4_address.clone().or_else(|| e.ipv6_address.clone()))
+        .filter(|a| !a.is_empty())
+        .unwrap_or_else(|| "-".to_string());
+      let on_disconnect = self.on_disconnect_network.clone();
+      let ids = (network.id.clone(), container.id.clone());
+
+      h_flex()
+        .w_full()
+        .py(px(8.))
+        .gap(px(8.))
+        .items_center()
+        .when(i > 0, |el| el.border_t_1().border_color(colors.border))
+        .child(Icon::new(AppIcon::Network).text_color(colors.secondary_foreground))
+        .child(
+          div()
+            .flex_1()
+            .min_w_0()
+            .text_sm()
+            .text_color(colors.foreground)
+            .overflow_hidden()
+            .text_ellipsis()
+            .child(network.name.clone()),
+        )
+        .child(
+          div()
+            .text_sm()
+            .font_family("monospace")
+            .text_color(colors.secondary_foreground)
+            .child(address),
+        )
+        .child(
+          Button::new(("disconnect-network", i))
+            .icon(IconName::Close)
+            .ghost()
+            .xsmall()
+            .tooltip("Disconnect")
+            .on_click(move |_ev, window, cx| {
+              if let Some(ref cb) = on_disconnect {
+                cb(&ids, window, cx);
+              }
+            }),
+        )
+    });
+
+    v_flex()
+      .w_full()
+      .pt(px(12.))
+      .child(
+        h_flex()
+          .w_full()
+          .items_center()
+          .justify_between()
+          .child(div().text_sm().text_color(colors.muted_foreground).child("Networks"))
+          .child(
+            Button::new("connect-network")
+              .icon(Icon::new(AppIcon::Plus))
+              .label("Connect")
+              .ghost()
+              .xsmall()
+              .on_click(move |_ev, window, cx| {
+                if let Some(ref cb) = on_connect {
+                  cb(&container_id, window, cx);
+                }
+              }),
+          ),
+      )
+      .when(self.networks.is_empty(), |el| {
+        el.child(
+          div()
+            .py(px(8.))
+            .text_sm()
+            .text_color(colors.muted_foreground)
+            .child("Not connected to any network"),
+        )
+      })
+      .children(rows)
   }
 
   fn render_logs_tab(&self, cx: &App) -> gpui::Div {
@@ -356,6 +490,78 @@ impl ContainerDetail {
     )
   }
 
+  fn render_run_tab(&self, is_running: bool, cx: &App) -> gpui::AnyElement {
+    let colors = &cx.theme().colors;
+
+    if !is_running {
+      return v_flex()
+        .flex_1()
+        .w_full()
+        .p(px(16.))
+        .items_center()
+        .justify_center()
+        .gap(px(16.))
+        .child(
+          Icon::new(IconName::Info)
+            .size(px(48.))
+            .text_color(colors.muted_foreground),
+        )
+        .child(
+          div()
+            .text_sm()
+            .text_color(colors.muted_foreground)
+            .child("Container must be running to run commands"),
+        )
+        .into_any_element();
+    }
+
+    match &self.exec_panel {
+      Some(panel) => div()
+        .flex_1()
+        .min_h_0()
+        .w_full()
+        .child(panel.clone())
+        .into_any_element(),
+      None => div().flex_1().into_any_element(),
+    }
+  }
+
+  fn render_diagnostics_tab(&self, is_running: bool, cx: &App) -> gpui::AnyElement {
+    let colors = &cx.theme().colors;
+
+    if !is_running {
+      return v_flex()
+        .flex_1()
+        .w_full()
+        .p(px(16.))
+        .items_center()
+        .justify_center()
+        .gap(px(16.))
+        .child(
+          Icon::new(IconName::Info)
+            .size(px(48.))
+            .text_color(colors.muted_foreground),
+        )
+        .child(
+          div()
+            .text_sm()
+            .text_color(colors.muted_foreground)
+            .child("Container must be running to diagnose its network"),
+        )
+        .into_any_element();
+    }
+
+    match &self.diagnostics_panel {
+      Some(panel) => div()
+        .flex_1()
+        .min_h_0()
+        .w_full()
+        .child(panel.clone())
+        .into_any_element(),
+      None => div().flex_1().into_any_element(),
+    }
+  }
+
   fn render_processes_tab(&self, is_running: bool, cx: &App) -> gpui::AnyElement {
     let colors = &cx.theme().colors;
 
@@ -688,13 +894,15 @@ impl ContainerDetail {
           }),
       );
 
-    // Terminal, Logs, Processes, and Files tabs need full height without scroll
+    // Terminal, Logs, Processes, Run, Files and Diagnostics tabs need full height without scroll
     let is_full_height_tab = matches!(
       self.active_tab,
       ContainerDetailTab::Logs
         | ContainerDetailTab::Processes
         | ContainerDetailTab::Terminal
+        | ContainerDetailTab::Run
         | ContainerDetailTab::Files
+        | ContainerDetailTab::Diagnostics
     );
 
     // Content based on active tab
@@ -711,8 +919,10 @@ impl ContainerDetail {
         ContainerDetailTab::Logs => self.render_logs_tab(cx).into_any_element(),
         ContainerDetailTab::Processes => self.render_processes_tab(is_running, cx),
         ContainerDetailTab::Terminal => self.render_terminal_tab(is_running, cx),
+        ContainerDetailTab::Run => self.render_run_tab(is_running, cx),
         ContainerDetailTab::Files => self.render_files_tab(is_running, window, cx),
-        _ => Self::render_info_tab(container, cx).into_any_element(),
+        ContainerDetailTab::Diagnostics => self.render_diagnostics_tab(is_running, cx),
+        _ => self.render_info_tab(container, cx).into_any_element(),
       };
       result = result.child(
         div()
@@ -727,7 +937,7 @@ impl ContainerDetail {
     } else {
       let content = match self.active_tab {
         ContainerDetailTab::Inspect => self.render_inspect_tab(cx),
-        _ => Self::render_info_tab(container, cx),
+        _ => self.render_info_tab(container, cx),
       };
       result = result.child(
         div()
@@ -0,0 +1,374 @@
+//! Network diagnostics from a container's point of view
+//!
+//! Probes a target by name or address with DNS, TCP, HTTP and ping, and shows
+//! what each outcome means next to the raw tool output.
+
+use chrono::{DateTime, Local};
+use gpui::{App, Context, Entity, Render, SharedString, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  Icon, IconName, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  scroll::ScrollableElement,
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::assets::AppIcon;
+use crate::docker::{DiagnosticProbe, DiagnosticResult, DiagnosticTarget};
+use crate::services;
+use crate::state::docker_state;
+
+/// Maximum number of runs kept in the history
+const MAX_HISTORY: usize = 20;
+
+/// One diagnostics run against a target
+#[derive(Debug, Clone)]
+struct DiagnosticRun {
+  target: String,
+  started_at: DateTime<Local>,
+  result: Result<Vec<DiagnosticResult>, String>,
+}
+
+/// Panel for probing the network from inside a container
+pub struct DiagnosticsPanel {
+  container_id: String,
+  target_input: Entity<InputState>,
+  probes: Vec<DiagnosticProbe>,
+  input_error: Option<String>,
+  history: Vec<DiagnosticRun>,
+  is_running: bool,
+}
+
+impl DiagnosticsPanel {
+  pub fn new(container_id: String, window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
+    let target_input = cx.new(|cx| {
+      InputState::new(window, cx).placeholder("Host, host:port or URL (db, db:5432, http://api:8080/health)")
+    });
+
+    Self {
+      container_id,
+      target_input,
+      probes: DiagnosticProbe::ALL.to_vec(),
+      input_error: None,
+      history: Vec::new(),
+      is_running: false,
+    }
+  }
+
+  fn toggle_probe(&mut self, probe: DiagnosticProbe, cx: &mut Context<'_, Self>) {
+    if let Some(i) = self.probes.iter().position(|p| *p == probe) {
+      self.probes.remove(i);
+    } else {
+      self.probes.push(probe);
+      // Keep the run order stable regardless of click order
+      self
+        .probes
+        .sort_by_key(|p| DiagnosticProbe::ALL.iter().position(|a| a == p));
+    }
+    cx.notify();
+  }
+
+  /// Names this container can reach other containers by: names and aliases on shared networks
+  fn peer_names(&self, cx: &App) -> Vec<String> {
+    let state = docker_state(cx);
+    let state = state.read(cx);
+    let mut names: Vec<String> = Vec::new();
+    for network in state
+      .networks
+      .iter()
+      .filter(|n| n.containers.contains_key(&self.container_id))
+    {
+      // The default bridge has no name resolution
+      if network.name == "bridge" {
+        continue;
+      }
+      for (id, endpoint) in &network.containers {
+        if *id == self.container_id {
+          continue;
+        }
+        for name in endpoint.name.iter().chain(&endpoint.aliases) {
+          if !names.contains(name) {
+            names.push(name.clone());
+          }
+        }
+      }
+    }
+    names.sort();
+    names
+  }
+
+  fn run(&mut self, cx: &mut Context<'_, Self>) {
+    if self.is_running || self.probes.is_empty() {
+      return;
+    }
+    let input = self.target_input.read(cx).text().to_string().trim().to_string();
+    let target = match DiagnosticTarget::parse(&input) {
+      Ok(target) => target,
+      Err(e) => {
+        self.input_error = Some(e.to_string());
+        cx.notify();
+        return;
+      }
+    };
+    self.input_error = None;
+    self.is_running = true;
+    cx.notify();
+
+    let container_id = self.container_id.clone();
+    let probes = self.probes.clone();
+    let tokio_handle = services::Tokio::runtime_handle();
+    let client = services::docker_client();
+    let started_at = Local::now();
+
+    cx.spawn(async move |this, cx| {
+      let result = cx
+        .background_executor()
+        .spawn(async move {
+          tokio_handle.block_on(async {
+            let guard = client.read().await;
+            match guard.as_ref() {
+              Some(c) => c.run_diagnostics(&container_id, &target, &probes).await,
+              None => Err(anyhow::anyhow!("Docker client not connected")),
+            }
+          })
+        })
+        .await;
+
+      let _ = this.update(cx, |this, cx| {
+        this.is_running = false;
+        this.history.insert(
+          0,
+          DiagnosticRun {
+            target: input,
+            started_at,
+            result: result.map_err(|e| e.to_string()),
+          },
+        );
+        this.history.truncate(MAX_HISTORY);
+        cx.notify();
+      });
+    })
+    .detach();
+  }
+
+  fn render_form(&self, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let peers = self.peer_names(cx);
+
+    v_flex()
+      .w_full()
+      .p(px(16.))
+      .gap(px(12.))
+      .border_b_1()
+      .border_color(colors.border)
+      .child(
+        h_flex()
+          .w_full()
+          .gap(px(8.))
+          .items_center()
+          .child(div().flex_1().child(Input::new(&self.target_input).small().w_full()))
+          .child(
+            Button::new("diagnostics-run")
+              .label("Run")
+              .icon(Icon::new(AppIcon::Play))
+              .primary()
+              .small()
+              .loading(self.is_running)
+              .on_click(cx.listener(|this, _ev, _window, cx| {
+                this.run(cx);
+              })),
+          ),
+      )
+      .when_some(self.input_error.clone(), |el, error| {
+        el.child(div().text_xs().text_color(colors.danger).child(error))
+      })
+      .child(
+        h_flex()
+          .gap(px(6.))
+          .items_center()
+          .child(div().text_xs().text_color(colors.muted_foreground).child("Probes"))
+          .children(DiagnosticProbe::ALL.iter().map(|&probe| {
+            let enabled = self.probes.contains(&probe);
+            Button::new(SharedString::from(format!("diagnostics-probe-{}", probe.label())))
+              .label(probe.label())
+              .xsmall()
+              .when(enabled, Button::primary)
+              .when(!enabled, ButtonVariants::ghost)
+              .on_click(cx.listener(move |this, _ev, _window, cx| {
+                this.toggle_probe(probe, cx);
+              }))
+          })),
+      )
+      .when(!peers.is_empty(), |el| {
+        el.child(
+          h_flex()
+            .flex_wrap()
+            .gap(px(6.))
+            .items_center()
+            .child(
+              div()
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .child("Reachable by name"),
+            )
+            .children(peers.into_iter().enumerate().map(|(i, name)| {
+              let value = name.clone();
+              div()
+                .id(SharedString::from(format!("diagnostics-peer-{i}")))
+                .px(px(6.))
+                .py(px(2.))
+                .rounded(px(4.))
+                .bg(colors.secondary)
+                .text_xs()
+                .font_family("monospace")
+                .text_color(colors.foreground)
+                .cursor_pointer()
+                .hover(|s| s.bg(colors.list_hover))
+                .on_click(cx.listener(move |this, _ev, window, cx| {
+                  let value = value.clone();
+                  this
+                    .target_input
+                    .update(cx, |state, cx| state.set_value(value, window, cx));
+                }))
+                .child(name)
+            })),
+        )
+      })
+  }
+
+  fn render_result(result: &DiagnosticResult, cx: &App) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let (icon, color) = if result.success {
+      (IconName::CircleCheck, colors.success)
+    } else {
+      (IconName::CircleX, colors.danger)
+    };
+
+    v_flex()
+      .w_full()
+      .gap(px(6.))
+      .child(
+        h_flex()
+          .w_full()
+          .gap(px(8.))
+          .items_center()
+          .child(Icon::new(icon).size(px(14.)).text_color(color))
+          .child(
+            div()
+              .w(px(40.))
+              .flex_shrink_0()
+              .text_xs()
+              .font_weight(gpui::FontWeight::SEMIBOLD)
+              .text_color(colors.muted_foreground)
+              .child(result.probe.label()),
+          )
+          .child(
+            div()
+              .flex_1()
+              .text_sm()
+              .text_color(colors.foreground)
+              .overflow_hidden()
+              .text_ellipsis()
+              .whitespace_nowrap()
+              .child(result.summary.clone()),
+          )
+          .when(result.duration_ms > 0, |el| {
+            el.child(
+              div()
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .child(format!("{}ms", result.duration_ms)),
+            )
+          }),
+      )
+      .child(
+        div()
+          .pl(px(20.))
+          .text_xs()
+          .text_color(colors.secondary_foreground)
+          .child(result.explanation.clone()),
+      )
+      .when(!result.command.is_empty() || !result.output.is_empty(), |el| {
+        el.child(
+          v_flex()
+            .ml(px(20.))
+            .p(px(8.))
+            .rounded(px(4.))
+            .bg(colors.background)
+            .text_xs()
+            .font_family("monospace")
+            .text_color(colors.muted_foreground)
+            .when(!result.command.is_empty(), |el| {
+              el.child(
+                div()
+                  .text_color(colors.foreground)
+                  .child(format!("$ {}", result.command)),
+              )
+            })
+            .children(result.output.lines().map(|line| div().child(line.to_string()))),
+        )
+      })
+  }
+
+  fn render_run(run: &DiagnosticRun, cx: &App) -> impl IntoElement {
+    let colors = cx.theme().colors;
+
+    v_flex()
+      .w_full()
+      .p(px(12.))
+      .gap(px(10.))
+      .border_b_1()
+      .border_color(colors.border)
+      .child(
+        h_flex()
+          .w_full()
+          .gap(px(8.))
+          .items_center()
+          .child(
+            div()
+              .flex_1()
+              .text_sm()
+              .font_family("monospace")
+              .text_color(colors.foreground)
+              .child(run.target.clone()),
+          )
+          .child(
+            div()
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .child(run.started_at.format("%H:%M:%S").to_string()),
+          ),
+      )
+      .map(|el| match &run.result {
+        Ok(results) => el.children(results.iter().map(|result| Self::render_result(result, cx))),
+        Err(e) => el.child(div().text_xs().text_color(colors.danger).child(e.clone())),
+      })
+  }
+}
+
+impl Render for DiagnosticsPanel {
+  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+
+    v_flex()
+      .size_full()
+      .overflow_hidden()
+      .child(self.render_form(cx))
+      .child(
+        div()
+          .id("diagnostics-history")
+          .flex_1()
+          .min_h_0()
+          .overflow_y_scrollbar()
+          .when(self.history.is_empty(), |el| {
+            el.child(div().p(px(16.)).text_sm().text_color(colors.muted_foreground).child(
+              "Probes run in a helper container that shares this container's network, so results match what \
+                   the container itself sees.",
+            ))
+          })
+          .children(self.history.iter().map(|run| Self::render_run(run, cx))),
+      )
+  }
+}
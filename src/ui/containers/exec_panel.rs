@@ -0,0 +1,540 @@
+//! Run one-off commands inside a container
+//!
+//! Unlike the interactive terminal, each run is captured as a structured
+//! result (stdout, stderr, exit code, duration) and kept in a short history.
+//! Commands can be saved as snippets for the container's image.
+
+use chrono::{DateTime, Local};
+use gpui::{App, Context, Entity, Render, SharedString, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  Icon, IconName, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  scroll::ScrollableElement,
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::assets::AppIcon;
+use crate::docker::{ExecOptions, ExecResult, parse_env_pairs};
+use crate::services;
+use crate::state::{SavedCommand, SettingsChanged, settings_state};
+
+/// Maximum number of runs kept in the history
+const MAX_HISTORY: usize = 50;
+
+/// A single command run and its outcome
+#[derive(Debug, Clone)]
+struct ExecRun {
+  command: String,
+  started_at: DateTime<Local>,
+  result: Result<ExecResult, String>,
+}
+
+/// Panel for running commands in a container and managing saved snippets
+pub struct ExecPanel {
+  container_id: String,
+  image: String,
+  command_input: Entity<InputState>,
+  user_input: Entity<InputState>,
+  workdir_input: Entity<InputState>,
+  env_input: Entity<InputState>,
+  stdin_input: Entity<InputState>,
+  snippet_name_input: Entity<InputState>,
+  history: Vec<ExecRun>,
+  is_running: bool,
+}
+
+impl ExecPanel {
+  pub fn new(container_id: String, image: String, window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
+    let command_input = cx.new(|cx| InputState::new(window, cx).placeholder("Command (run with /bin/sh -c)"));
+    let user_input = cx.new(|cx| InputState::new(window, cx).placeholder("User (default)"));
+    let workdir_input = cx.new(|cx| InputState::new(window, cx).placeholder("Working directory (default)"));
+    let env_input = cx.new(|cx| {
+      InputState::new(window, cx)
+        .multi_line(true)
+        .placeholder("One KEY=value per line")
+    });
+    let stdin_input = cx.new(|cx| {
+      InputState::new(window, cx)
+        .multi_line(true)
+        .placeholder("Optional stdin passed to the command")
+    });
+    let snippet_name_input = cx.new(|cx| InputState::new(window, cx).placeholder("Snippet name"));
+
+    // Re-render when saved snippets change elsewhere
+    cx.subscribe(&settings_state(cx), |_this, _state, event: &SettingsChanged, cx| {
+      if matches!(event, SettingsChanged::SettingsUpdated) {
+        cx.notify();
+      }
+    })
+    .detach();
+
+    Self {
+      container_id,
+      image,
+      command_input,
+      user_input,
+      workdir_input,
+      env_input,
+      stdin_input,
+      snippet_name_input,
+      history: Vec::new(),
+      is_running: false,
+    }
+  }
+
+  fn input_text(input: &Entity<InputState>, cx: &App) -> String {
+    input.read(cx).text().to_string().trim().to_string()
+  }
+
+  fn run_from_inputs(&mut self, cx: &mut Context<'_, Self>) {
+    let command = Self::input_text(&self.command_input, cx);
+    if command.is_empty() {
+      return;
+    }
+
+    let mut options = ExecOptions::shell(&command);
+    options.user = Some(Self::input_text(&self.user_input, cx));
+    options.working_dir = Some(Self::input_text(&self.workdir_input, cx));
+    options.env = parse_env_pairs(&Self::input_text(&self.env_input, cx));
+    let stdin = self.stdin_input.read(cx).text().to_string();
+    if !stdin.is_empty() {
+      options.stdin = Some(stdin);
+    }
+
+    self.run(command, options, cx);
+  }
+
+  fn run_snippet(&mut self, snippet: &SavedCommand, cx: &mut Context<'_, Self>) {
+    let mut options = ExecOptions::shell(&snippet.command);
+    options.user = Some(snippet.user.clone());
+    options.working_dir = Some(snippet.working_dir.clone());
+    options.env = parse_env_pairs(&snippet.env);
+
+    self.run(snippet.command.clone(), options, cx);
+  }
+
+  fn run(&mut self, command: String, options: ExecOptions, cx: &mut Context<'_, Self>) {
+    if self.is_running {
+      return;
+    }
+    self.is_running = true;
+    cx.notify();
+
+    let container_id = self.container_id.clone();
+    let tokio_handle = services::Tokio::runtime_handle();
+    let client = services::docker_client();
+    let started_at = Local::now();
+
+    cx.spawn(async move |this, cx| {
+      let result = cx
+        .background_executor()
+        .spawn(async move {
+          tokio_handle.block_on(async {
+            let guard = client.read().await;
+            match guard.as_ref() {
+              Some(c) => c.exec(&container_id, options).await,
+              None => Err(anyhow::anyhow!("Docker client not connected")),
+            }
+          })
+        })
+        .await;
+
+      let _ = this.update(cx, |this, cx| {
+        this.is_running = false;
+        this.history.insert(
+          0,
+          ExecRun {
+            command,
+            started_at,
+            result: result.map_err(|e| e.to_string()),
+          },
+        );
+        this.history.truncate(MAX_HISTORY);
+        cx.notify();
+      });
+    })
+    .detach();
+  }
+
+  fn load_snippet(&mut self, snippet: &SavedCommand, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let fields = [
+      (&self.command_input, &snippet.command),
+      (&self.user_input, &snippet.user),
+      (&self.workdir_input, &snippet.working_dir),
+      (&self.env_input, &snippet.env),
+      (&self.snippet_name_input, &snippet.name),
+    ];
+    for (input, value) in fields {
+      let value = value.clone();
+      input.update(cx, |state, cx| state.set_value(value, window, cx));
+    }
+  }
+
+  fn save_snippet(&mut self, cx: &mut Context<'_, Self>) {
+    let command = Self::input_text(&self.command_input, cx);
+    if command.is_empty() {
+      return;
+    }
+    let name = Some(Self::input_text(&self.snippet_name_input, cx))
+      .filter(|n| !n.is_empty())
+      .unwrap_or_else(|| command.clone());
+
+    let snippet = SavedCommand {
+      name,
+      image: SavedCommand::image_repository(&self.image).to_string(),
+      command,
+      user: Self::input_text(&self.user_input, cx),
+      working_dir: Self::input_text(&self.workdir_input, cx),
+      env: Self::input_text(&self.env_input, cx),
+    };
+
+    settings_state(cx).update(cx, |state, cx| {
+      // Saving under an existing name for the same image replaces it
+      state
+        .settings
+        .saved_commands
+        .retain(|c| !(c.name == snippet.name && c.matches_image(&snippet.image)));
+      state.settings.saved_commands.push(snippet);
+      let _ = state.settings.save();
+      cx.emit(SettingsChanged::SettingsUpdated);
+    });
+  }
+
+  fn delete_snippet(snippet: &SavedCommand, cx: &mut App) {
+    settings_state(cx).update(cx, |state, cx| {
+      state.settings.saved_commands.retain(|c| c != snippet);
+      let _ = state.settings.save();
+      cx.emit(SettingsChanged::SettingsUpdated);
+    });
+  }
+
+  fn render_field(label: &str, input: &Entity<InputState>, cx: &App) -> impl IntoElement {
+    v_flex()
+      .flex_1()
+      .gap(px(4.))
+      .child(
+        div()
+          .text_xs()
+          .text_color(cx.theme().colors.muted_foreground)
+          .child(label.to_string()),
+      )
+      .child(Input::new(input).small().w_full())
+  }
+
+  fn render_form(&self, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+
+    v_flex()
+      .w_full()
+      .p(px(16.))
+      .gap(px(12.))
+      .border_b_1()
+      .border_color(colors.border)
+      .child(
+        h_flex()
+          .w_full()
+          .gap(px(8.))
+          .items_end()
+          .child(Self::render_field("Command", &self.command_input, cx))
+          .child(
+            Button::new("exec-run")
+              .label("Run")
+              .icon(Icon::new(AppIcon::Play))
+              .primary()
+              .small()
+              .loading(self.is_running)
+              .on_click(cx.listener(|this, _ev, _window, cx| {
+                this.run_from_inputs(cx);
+              })),
+          ),
+      )
+      .child(
+        h_flex()
+          .w_full()
+          .gap(px(8.))
+          .child(Self::render_field("User", &self.user_input, cx))
+          .child(Self::render_field("Working directory", &self.workdir_input, cx)),
+      )
+      .child(
+        v_flex()
+          .gap(px(4.))
+          .child(div().text_xs().text_color(colors.muted_foreground).child("Environment"))
+          .child(Input::new(&self.env_input).h(px(64.))),
+      )
+      .child(
+        v_flex()
+          .gap(px(4.))
+          .child(div().text_xs().text_color(colors.muted_foreground).child("Stdin"))
+          .child(Input::new(&self.stdin_input).h(px(64.))),
+      )
+      .child(
+        h_flex()
+          .w_full()
+          .gap(px(8.))
+          .items_end()
+          .child(Self::render_field("Save as snippet", &self.snippet_name_input, cx))
+          .child(
+            Button::new("exec-save-snippet")
+              .label("Save")
+              .icon(IconName::Check)
+              .ghost()
+              .small()
+              .on_click(cx.listener(|this, _ev, _window, cx| {
+                this.save_snippet(cx);
+              })),
+          ),
+      )
+  }
+
+  fn render_snippets(&self, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let snippets = settings_state(cx)
+      .read(cx)
+      .settings
+      .saved_commands_for_image(&self.image);
+
+    v_flex()
+      .w_full()
+      .px(px(16.))
+      .py(px(12.))
+      .gap(px(6.))
+      .border_b_1()
+      .border_color(colors.border)
+      .child(
+        div()
+          .text_xs()
+          .font_weight(gpui::FontWeight::MEDIUM)
+          .text_color(colors.muted_foreground)
+          .child(format!("Snippets for {}", SavedCommand::image_repository(&self.image))),
+      )
+      .when(snippets.is_empty(), |el| {
+        el.child(
+          div()
+            .text_xs()
+            .text_color(colors.muted_foreground)
+            .child("No saved snippets for this image"),
+        )
+      })
+      .children(snippets.into_iter().enumerate().map(|(i, snippet)| {
+        let load = snippet.clone();
+        let run = snippet.clone();
+        let delete = snippet.clone();
+
+        h_flex()
+          .id(SharedString::from(format!("snippet-{i}")))
+          .w_full()
+          .gap(px(8.))
+          .px(px(8.))
+          .py(px(4.))
+          .rounded(px(4.))
+          .items_center()
+          .cursor_pointer()
+          .hover(|s| s.bg(colors.list_hover))
+          .on_click(cx.listener(move |this, _ev, window, cx| {
+            this.load_snippet(&load, window, cx);
+          }))
+          .child(
+            div()
+              .text_sm()
+              .text_color(colors.foreground)
+              .flex_shrink_0()
+              .child(snippet.name.clone()),
+          )
+          .child(
+            div()
+              .flex_1()
+              .text_xs()
+              .font_family("monospace")
+              .text_color(colors.muted_foreground)
+              .overflow_hidden()
+              .text_ellipsis()
+              .whitespace_nowrap()
+              .child(snippet.command.clone()),
+          )
+          .child(
+            Button::new(SharedString::from(format!("snippet-run-{i}")))
+              .icon(Icon::new(AppIcon::Play))
+              .ghost()
+              .xsmall()
+              .on_click(cx.listener(move |this, _ev, _window, cx| {
+                cx.stop_propagation();
+                this.run_snippet(&run, cx);
+              })),
+          )
+          .child(
+            Button::new(SharedString::from(format!("snippet-delete-{i}")))
+              .icon(Icon::new(AppIcon::Trash))
+              .ghost()
+              .xsmall()
+              .on_click(move |_ev, _window, cx| {
+                cx.stop_propagation();
+                Self::delete_snippet(&delete, cx);
+              }),
+          )
+      }))
+  }
+
+  fn render_output(label: &str, text: &str, color: gpui::Hsla, cx: &App) -> impl IntoElement {
+    let colors = cx.theme().colors;
+
+    v_flex()
+      .w_full()
+      .gap(px(2.))
+      .child(
+        div()
+          .text_xs()
+          .text_color(colors.muted_foreground)
+          .child(label.to_string()),
+      )
+      .child(
+        v_flex()
+          .w_full()
+          .p(px(8.))
+          .rounded(px(4.))
+          .bg(colors.background)
+          .text_xs()
+          .font_family("monospace")
+          .text_color(color)
+          .children(text.trim_end().lines().map(|line| div().child(line.to_string()))),
+      )
+  }
+
+  fn render_run(run: &ExecRun, index: usize, cx: &App) -> impl IntoElement {
+    let colors = cx.theme().colors;
+
+    let (badge, badge_color) = match &run.result {
+      Ok(result) if result.is_success() => (format!("exit {}", result.display_exit_code()), colors.success),
+      Ok(result) => (format!("exit {}", result.display_exit_code()), colors.danger),
+      Err(_) => ("error".to_string(), colors.danger),
+    };
+    let command = run.command.clone();
+
+    v_flex()
+      .w_full()
+      .p(px(12.))
+      .gap(px(8.))
+      .border_b_1()
+      .border_color(colors.border)
+      .child(
+        h_flex()
+          .w_full()
+          .gap(px(8.))
+          .items_center()
+          .child(
+            div()
+              .px(px(6.))
+              .py(px(2.))
+              .rounded(px(4.))
+              .bg(badge_color.opacity(0.15))
+              .text_xs()
+              .text_color(badge_color)
+              .child(badge),
+          )
+          .child(
+            div()
+              .flex_1()
+              .text_sm()
+              .font_family("monospace")
+              .text_color(colors.foreground)
+              .overflow_hidden()
+              .text_ellipsis()
+              .whitespace_nowrap()
+              .child(run.command.clone()),
+          )
+          .when_some(run.result.as_ref().ok(), |el, result| {
+            el.child(
+              div()
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .child(result.display_duration()),
+            )
+          })
+          .child(
+            div()
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .child(run.started_at.format("%H:%M:%S").to_string()),
+          )
+          .child(
+            Button::new(SharedString::from(format!("exec-copy-{index}")))
+              .icon(IconName::Copy)
+              .ghost()
+              .xsmall()
+              .on_click(move |_ev, _window, cx| {
+                cx.write_to_clipboard(gpui::ClipboardItem::new_string(command.clone()));
+              }),
+          ),
+      )
+      .map(|el| match &run.result {
+        Ok(result) => el
+          .when(!result.stdout.is_empty(), |el| {
+            el.child(Self::render_output("stdout", &result.stdout, colors.foreground, cx))
+          })
+          .when(!result.stderr.is_empty(), |el| {
+            el.child(Self::render_output("stderr", &result.stderr, colors.danger, cx))
+          })
+          .when(result.stdout.is_empty() && result.stderr.is_empty(), |el| {
+            el.child(div().text_xs().text_color(colors.muted_foreground).child("No output"))
+          }),
+        Err(e) => el.child(div().text_xs().text_color(colors.danger).child(e.clone())),
+      })
+  }
+}
+
+impl Render for ExecPanel {
+  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+
+    v_flex()
+      .size_full()
+      .overflow_hidden()
+      .child(self.render_form(cx))
+      .child(self.render_snippets(cx))
+      .child(
+        h_flex()
+          .w_full()
+          .px(px(16.))
+          .py(px(8.))
+          .items_center()
+          .border_b_1()
+          .border_color(colors.border)
+          .child(
+            div()
+              .flex_1()
+              .text_xs()
+              .font_weight(gpui::FontWeight::MEDIUM)
+              .text_color(colors.muted_foreground)
+              .child(format!("History ({})", self.history.len())),
+          )
+          .when(!self.history.is_empty(), |el| {
+            el.child(
+              Button::new("exec-clear-history")
+                .label("Clear")
+                .ghost()
+                .xsmall()
+                .on_click(cx.listener(|this, _ev, _window, cx| {
+                  this.history.clear();
+                  cx.notify();
+                })),
+            )
+          }),
+      )
+      .child(
+        div()
+          .id("exec-history")
+          .flex_1()
+          .min_h_0()
+          .overflow_y_scrollbar()
+          .children(
+            self
+              .history
+              .iter()
+              .enumerate()
+              .map(|(i, run)| Self::render_run(run, i, cx)),
+          ),
+      )
+  }
+}
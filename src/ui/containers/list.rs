@@ -12,9 +12,9 @@ use gpui_component::{
 };
 
 use crate::assets::AppIcon;
-use crate::docker::ContainerInfo;
+use crate::docker::{ContainerInfo, PruneKind, has_protect_label};
 use crate::services;
-use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state};
+use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state, settings_state};
 use crate::ui::components::{render_error, render_loading};
 
 /// Container list events emitted to parent
@@ -80,6 +80,11 @@ impl ListDelegate for ContainerListDelegate {
     let is_selected = matches!(global_selection, Selection::Container(c) if c.id == container.id);
     let is_running = container.state.is_running();
     let container_id = container.id.clone();
+    let pinned = settings_state(cx)
+      .read(cx)
+      .settings
+      .is_pinned(PruneKind::Containers, &container.name);
+    let protected = pinned || has_protect_label(&container.labels);
 
     let icon_bg = if is_running {
       colors.primary
@@ -249,13 +254,26 @@ impl ListDelegate for ContainerListDelegate {
               services::request_export_container(id.clone(), name.clone(), cx);
             }
           }))
-          .separator()
-          .item(PopupMenuItem::new("Delete").icon(Icon::new(AppIcon::Trash)).on_click({
-            let id = id.clone();
-            move |_, _, cx| {
-              services::delete_container(id.clone(), cx);
-            }
-          }));
+          .separator();
+        // Label protection can only be lifted by changing the label
+        if !protected || pinned {
+          menu = menu.item(
+            PopupMenuItem::new(if pinned { "Unprotect" } else { "Protect" })
+              .icon(Icon::new(AppIcon::Shield))
+              .on_click({
+                let name = name.clone();
+                move |_, _, cx| {
+                  services::set_pinned(PruneKind::Containers, name.clone(), !pinned, cx);
+                }
+              }),
+          );
+        }
+        menu = menu.item(PopupMenuItem::new("Delete").icon(Icon::new(AppIcon::Trash)).on_click({
+          let id = id.clone();
+          move |_, _, cx| {
+            services::delete_container(id.clone(), cx);
+          }
+        }));
 
         menu
       });
@@ -312,6 +330,13 @@ impl ListDelegate for ContainerListDelegate {
               ),
           ),
       )
+      .when(protected, |el| {
+        el.child(
+          Icon::new(AppIcon::Shield)
+            .size(px(12.))
+            .text_color(colors.muted_foreground),
+        )
+      })
       .child(div().flex_shrink_0().child(menu_button));
 
     let item = ListItem::new(("container", ix.row))
@@ -1,5 +1,7 @@
 mod create_dialog;
 mod detail;
+mod diagnostics_panel;
+mod exec_panel;
 mod list;
 mod view;
 
@@ -12,9 +12,13 @@ use crate::services;
 use crate::state::{DockerState, Selection, StateChanged, docker_state, settings_state};
 use crate::terminal::{TerminalSessionType, TerminalView};
 use crate::ui::components::{ProcessView, detect_language_from_path};
+use crate::ui::dialogs;
+use crate::ui::networks::connect_dialog::ConnectTarget;
 
 use super::create_dialog::CreateContainerDialog;
 use super::detail::{ContainerDetail, ContainerDetailTab, ContainerTabState};
+use super::diagnostics_panel::DiagnosticsPanel;
+use super::exec_panel::ExecPanel;
 use super::list::{ContainerList, ContainerListEvent};
 
 /// Self-contained Containers view - handles list, detail, and all state
@@ -25,6 +29,8 @@ pub struct ContainersView {
   active_tab: ContainerDetailTab,
   terminal_view: Option<Entity<TerminalView>>,
   process_view: Option<Entity<ProcessView>>,
+  exec_panel: Option<Entity<ExecPanel>>,
+  diagnostics_panel: Option<Entity<DiagnosticsPanel>>,
   logs_editor: Option<Entity<InputState>>,
   inspect_editor: Option<Entity<InputState>>,
   file_content_editor: Option<Entity<InputState>>,
@@ -100,6 +106,7 @@ impl ContainersView {
             }
             cx.notify();
           }
+          StateChanged::NetworksUpdated => cx.notify(),
           StateChanged::ContainerTabRequest { container_id, tab } => {
             // Find the container and select it with the specified tab
             let container = {
@@ -153,6 +160,8 @@ impl ContainersView {
       active_tab: ContainerDetailTab::Info,
       terminal_view: None,
       process_view: None,
+      exec_panel: None,
+      diagnostics_panel: None,
       logs_editor: None,
       inspect_editor: None,
       file_content_editor: None,
@@ -380,10 +389,15 @@ impl ContainersView {
       state.set_selection(Selection::Container(container.clone()));
     });
 
+    // Network attachments come from the network list
+    services::refresh_networks(cx);
+
     // Reset view-specific state but keep active_tab
     // This allows users to stay on their current tab when switching containers
     self.terminal_view = None;
     self.process_view = None;
+    self.exec_panel = None;
+    self.diagnostics_panel = None;
     self.last_synced_logs.clear();
     self.last_synced_inspect.clear();
     self.last_synced_file_content.clear();
@@ -422,6 +436,14 @@ impl ContainersView {
       self.on_navigate_path("/", cx);
     }
 
+    // If on Run or Diagnostics tab, recreate the panel for the new container
+    if matches!(
+      self.active_tab,
+      ContainerDetailTab::Run | ContainerDetailTab::Diagnostics
+    ) {
+      self.on_tab_change(self.active_tab, window, cx);
+    }
+
     cx.notify();
   }
 
@@ -448,6 +470,27 @@ impl ContainersView {
       self.process_view = Some(cx.new(|cx| ProcessView::for_container(container_id, window, cx)));
     }
 
+    // If switching to run tab, create exec panel
+    if tab == ContainerDetailTab::Run
+      && self.exec_panel.is_none()
+      && let Some(ref container) = self.selected_container(cx)
+      && container.state.is_running()
+    {
+      let container_id = container.id.clone();
+      let image = container.image.clone();
+      self.exec_panel = Some(cx.new(|cx| ExecPanel::new(container_id, image, window, cx)));
+    }
+
+    // If switching to diagnostics tab, create diagnostics panel
+    if tab == ContainerDetailTab::Diagnostics
+      && self.diagnostics_panel.is_none()
+      && let Some(ref container) = self.selected_container(cx)
+      && container.state.is_running()
+    {
+      let container_id = container.id.clone();
+      self.diagnostics_panel = Some(cx.new(|cx| DiagnosticsPanel::new(container_id, window, cx)));
+    }
+
     // If switching to files tab, load files
     if tab == ContainerDetailTab::Files
       && let Some(ref container) = self.selected_container(cx)
@@ -801,18 +844,35 @@ impl Render for ContainersView {
     let container_tab_state = self.container_tab_state.clone();
     let terminal_view = self.terminal_view.clone();
     let process_view = self.process_view.clone();
+    let exec_panel = self.exec_panel.clone();
+    let diagnostics_panel = self.diagnostics_panel.clone();
     let logs_editor = self.logs_editor.clone();
     let inspect_editor = self.inspect_editor.clone();
     let file_content_editor = self.file_content_editor.clone();
     let has_selection = selected_container.is_some();
+    let container_networks = selected_container
+      .as_ref()
+      .map(|c| {
+        let state = self.docker_state.read(cx);
+        state
+          .networks
+          .iter()
+          .filter(|n| n.containers.contains_key(&c.id))
+          .cloned()
+          .collect()
+      })
+      .unwrap_or_default();
 
     // Build detail panel
     let detail = ContainerDetail::new()
       .container(selected_container)
+      .networks(container_networks)
       .active_tab(active_tab)
       .container_state(container_tab_state)
       .terminal_view(terminal_view)
       .process_view(process_view)
+      .exec_panel(exec_panel)
+      .diagnostics_panel(diagnostics_panel)
       .logs_editor(logs_editor)
       .inspect_editor(inspect_editor)
       .file_content_editor(file_content_editor)
@@ -837,6 +897,12 @@ impl Render for ContainersView {
       .on_open_in_editor(cx.listener(|this, data: &(String, bool), window, cx| {
         this.on_open_in_editor(data, window, cx);
       }))
+      .on_connect_network(|id: &str, window, cx| {
+        dialogs::open_connect_network_dialog(ConnectTarget::Container { id: id.to_string() }, window, cx);
+      })
+      .on_disconnect_network(|(network, container): &(String, String), _window, cx| {
+        services::disconnect_network(network.clone(), container.clone(), cx);
+      })
       .on_start(cx.listener(|_this, id: &str, _window, cx| {
         services::start_container(id.to_string(), cx);
       }))
@@ -0,0 +1,398 @@
+use gpui::{Context, Entity, Render, SharedString, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  Icon, IconName, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  scroll::ScrollableElement,
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::assets::AppIcon;
+use crate::kubernetes::{KubeContextInfo, KubeconfigFile, KubeconfigOverview};
+use crate::services;
+use crate::state::{StateChanged, docker_state, settings_state};
+
+/// Merged kubeconfig files, their contexts, clusters and users, and which context the app uses
+pub struct KubeContextsView {
+  file_input: Entity<InputState>,
+}
+
+impl KubeContextsView {
+  pub fn new(window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
+    cx.subscribe(&docker_state(cx), |_this, _state, event: &StateChanged, cx| {
+      if matches!(event, StateChanged::KubeconfigUpdated) {
+        cx.notify();
+      }
+    })
+    .detach();
+
+    services::refresh_kubeconfig(cx);
+
+    Self {
+      file_input: cx.new(|cx| InputState::new(window, cx).placeholder("~/.kube/staging.yaml")),
+    }
+  }
+
+  fn add_file(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let path = self.file_input.read(cx).text().to_string();
+    services::add_kubeconfig_file(path, cx);
+    self.file_input.update(cx, |input, cx| input.set_value("", window, cx));
+  }
+
+  fn render_header(overview: &KubeconfigOverview, overridden: bool, cx: &Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let active = overview.active_context.clone().unwrap_or_else(|| "none".to_string());
+    let subtitle = if overridden {
+      format!("Using '{active}' in-app")
+    } else {
+      format!("Following current-context '{active}'")
+    };
+
+    h_flex()
+      .w_full()
+      .px(px(16.))
+      .py(px(12.))
+      .gap(px(12.))
+      .items_center()
+      .border_b_1()
+      .border_color(colors.border)
+      .bg(colors.sidebar)
+      .child(
+        div()
+          .text_base()
+          .font_weight(gpui::FontWeight::SEMIBOLD)
+          .text_color(colors.foreground)
+          .child("Contexts"),
+      )
+      .child(div().text_sm().text_color(colors.muted_foreground).child(subtitle))
+      .child(div().flex_1())
+      .when(overridden, |el| {
+        el.child(
+          Button::new("contexts-follow")
+            .label("Follow kubeconfig")
+            .ghost()
+            .small()
+            .on_click(|_ev, _window, cx| {
+              services::follow_current_context(cx);
+            }),
+        )
+      })
+      .child(
+        Button::new("contexts-refresh")
+          .icon(Icon::new(AppIcon::Refresh))
+          .ghost()
+          .small()
+          .tooltip("Reload kubeconfig")
+          .on_click(|_ev, _window, cx| {
+            services::refresh_kubeconfig(cx);
+          }),
+      )
+  }
+
+  fn render_section(title: &str, count: usize, cx: &Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    h_flex()
+      .gap(px(8.))
+      .items_center()
+      .child(
+        div()
+          .text_sm()
+          .font_weight(gpui::FontWeight::SEMIBOLD)
+          .text_color(colors.foreground)
+          .child(title.to_string()),
+      )
+      .child(
+        div()
+          .text_xs()
+          .text_color(colors.muted_foreground)
+          .child(count.to_string()),
+      )
+  }
+
+  fn render_file(index: usize, file: &KubeconfigFile, cx: &Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let (status, status_color) = match (&file.error, file.exists) {
+      (Some(error), _) => (error.clone(), colors.danger),
+      (None, false) => ("Not found".to_string(), colors.warning),
+      (None, true) if file.extra => ("Added in settings".to_string(), colors.muted_foreground),
+      (None, true) => ("From KUBECONFIG".to_string(), colors.muted_foreground),
+    };
+    let path = file.path.clone();
+
+    h_flex()
+      .w_full()
+      .px(px(8.))
+      .py(px(4.))
+      .gap(px(8.))
+      .items_center()
+      .child(
+        Icon::new(IconName::File)
+          .size(px(14.))
+          .text_color(colors.muted_foreground),
+      )
+      .child(
+        v_flex()
+          .flex_1()
+          .min_w_0()
+          .child(
+            div()
+              .text_sm()
+              .text_color(colors.foreground)
+              .overflow_hidden()
+              .text_ellipsis()
+              .whitespace_nowrap()
+              .child(file.path.display().to_string()),
+          )
+          .child(div().text_xs().text_color(status_color).child(status)),
+      )
+      .when(file.extra, |el| {
+        el.child(
+          Button::new(SharedString::from(format!("kubeconfig-remove-{index}")))
+            .icon(Icon::new(AppIcon::Trash))
+            .ghost()
+            .xsmall()
+            .tooltip("Stop merging this file")
+            .on_click(move |_ev, _window, cx| {
+              services::remove_kubeconfig_file(path.clone(), cx);
+            }),
+        )
+      })
+  }
+
+  fn render_context(
+    index: usize,
+    context: &KubeContextInfo,
+    overview: &KubeconfigOverview,
+    cx: &Context<'_, Self>,
+  ) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let is_active = overview.active_context.as_deref() == Some(context.name.as_str());
+    let is_current = overview.current_context.as_deref() == Some(context.name.as_str());
+    let detail = format!(
+      "cluster {} · user {} · namespace {} · {}",
+      context.cluster,
+      context.user.as_deref().unwrap_or("--"),
+      context.namespace.as_deref().unwrap_or("default"),
+      context.source.display()
+    );
+    let name = context.name.clone();
+    let name_default = context.name.clone();
+
+    let badge = |label: &'static str, color: gpui::Hsla| {
+      div()
+        .flex_shrink_0()
+        .px(px(6.))
+        .rounded(px(4.))
+        .bg(color.opacity(0.15))
+        .text_xs()
+        .text_color(color)
+        .child(label)
+    };
+
+    h_flex()
+      .w_full()
+      .px(px(8.))
+      .py(px(6.))
+      .gap(px(8.))
+      .items_center()
+      .rounded(px(4.))
+      .when(is_active, |el| el.bg(colors.list_active))
+      .child(Icon::new(AppIcon::Kubernetes).size(px(16.)).text_color(if is_active {
+        colors.primary
+      } else {
+        colors.muted_foreground
+      }))
+      .child(
+        v_flex()
+          .flex_1()
+          .min_w_0()
+          .child(
+            h_flex()
+              .gap(px(6.))
+              .items_center()
+              .child(
+                div()
+                  .text_sm()
+                  .font_weight(gpui::FontWeight::MEDIUM)
+                  .text_color(colors.foreground)
+                  .child(context.name.clone()),
+              )
+              .when(is_active, |el| el.child(badge("active", colors.success)))
+              .when(is_current, |el| el.child(badge("current-context", colors.info))),
+          )
+          .child(
+            div()
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .overflow_hidden()
+              .text_ellipsis()
+              .whitespace_nowrap()
+              .child(detail),
+          ),
+      )
+      .when(!is_active, |el| {
+        el.child(
+          Button::new(SharedString::from(format!("context-use-{index}")))
+            .label("Use in app")
+            .ghost()
+            .xsmall()
+            .tooltip("Switch the app to this context without changing the kubeconfig")
+            .on_click(move |_ev, _window, cx| {
+              services::switch_kube_context(name.clone(), false, cx);
+            }),
+        )
+      })
+      .when(!is_current, |el| {
+        el.child(
+          Button::new(SharedString::from(format!("context-default-{index}")))
+            .label("Set as current")
+            .ghost()
+            .xsmall()
+            .tooltip("Write current-context to the kubeconfig, like kubectl config use-context")
+            .on_click(move |_ev, _window, cx| {
+              services::switch_kube_context(name_default.clone(), true, cx);
+            }),
+        )
+      })
+  }
+
+  fn render_row(title: String, detail: String, cx: &Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    h_flex()
+      .w_full()
+      .px(px(8.))
+      .py(px(4.))
+      .gap(px(12.))
+      .child(
+        div()
+          .w(px(220.))
+          .flex_shrink_0()
+          .text_sm()
+          .text_color(colors.foreground)
+          .overflow_hidden()
+          .text_ellipsis()
+          .whitespace_nowrap()
+          .child(title),
+      )
+      .child(
+        div()
+          .flex_1()
+          .min_w_0()
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .overflow_hidden()
+          .text_ellipsis()
+          .whitespace_nowrap()
+          .child(detail),
+      )
+  }
+
+  fn card(cx: &Context<'_, Self>) -> gpui::Div {
+    let colors = cx.theme().colors;
+    v_flex()
+      .w_full()
+      .p(px(12.))
+      .gap(px(8.))
+      .rounded(px(8.))
+      .border_1()
+      .border_color(colors.border)
+      .bg(colors.sidebar)
+  }
+}
+
+impl Render for KubeContextsView {
+  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let overview = docker_state(cx).read(cx).kubeconfig.clone();
+    let overridden = !settings_state(cx).read(cx).settings.kubernetes.context.is_empty();
+
+    let files = Self::card(cx)
+      .child(Self::render_section("Kubeconfig files", overview.files.len(), cx))
+      .child(
+        v_flex().gap(px(2.)).children(
+          overview
+            .files
+            .iter()
+            .enumerate()
+            .map(|(i, file)| Self::render_file(i, file, cx)),
+        ),
+      )
+      .child(
+        h_flex()
+          .gap(px(8.))
+          .items_center()
+          .child(div().flex_1().child(Input::new(&self.file_input).small()))
+          .child(
+            Button::new("kubeconfig-add")
+              .label("Add file")
+              .icon(IconName::Plus)
+              .small()
+              .on_click(cx.listener(|this, _ev, window, cx| {
+                this.add_file(window, cx);
+              })),
+          ),
+      );
+
+    let contexts = Self::card(cx)
+      .child(Self::render_section("Contexts", overview.contexts.len(), cx))
+      .when(overview.contexts.is_empty(), |el| {
+        el.child(
+          div()
+            .text_xs()
+            .text_color(colors.muted_foreground)
+            .child("No contexts in the merged kubeconfig"),
+        )
+      })
+      .child(
+        v_flex().gap(px(2.)).children(
+          overview
+            .contexts
+            .iter()
+            .enumerate()
+            .map(|(i, context)| Self::render_context(i, context, &overview, cx)),
+        ),
+      );
+
+    let clusters = Self::card(cx)
+      .child(Self::render_section("Clusters", overview.clusters.len(), cx))
+      .children(overview.clusters.iter().map(|cluster| {
+        let mut detail = cluster.server.clone().unwrap_or_else(|| "--".to_string());
+        if cluster.insecure {
+          detail.push_str(" · TLS verification off");
+        }
+        Self::render_row(cluster.name.clone(), detail, cx)
+      }));
+
+    let users = Self::card(cx)
+      .child(Self::render_section("Users", overview.users.len(), cx))
+      .children(
+        overview
+          .users
+          .iter()
+          .map(|user| Self::render_row(user.name.clone(), user.auth.clone(), cx)),
+      );
+
+    v_flex()
+      .size_full()
+      .overflow_hidden()
+      .child(Self::render_header(&overview, overridden, cx))
+      .child(
+        div()
+          .id("contexts-scroll")
+          .flex_1()
+          .min_h_0()
+          .overflow_y_scrollbar()
+          .child(
+            v_flex()
+              .w_full()
+              .p(px(16.))
+              .gap(px(16.))
+              .child(contexts)
+              .child(files)
+              .child(h_flex().gap(px(16.)).items_start().child(clusters).child(users)),
+          ),
+      )
+  }
+}
@@ -13,7 +13,10 @@ use gpui_component::{
 use crate::assets::AppIcon;
 use crate::kubernetes::{DeploymentInfo, PodInfo};
 use crate::services;
-use crate::state::{DeploymentDetailTab, DockerState, StateChanged, docker_state};
+use crate::state::{
+  DeploymentDetailTab, DockerState, EventsChanged, InvolvedObject, StateChanged, docker_state, events_state,
+};
+use crate::ui::components::render_object_events;
 
 /// Detail view for a deployment with tabs
 pub struct DeploymentDetail {
@@ -74,6 +77,15 @@ impl DeploymentDetail {
     })
     .detach();
 
+    // Keep the events tab live as the watcher appends events
+    let events_state = events_state(cx);
+    cx.subscribe(&events_state, |this, _state, _event: &EventsChanged, cx| {
+      if this.active_tab == DeploymentDetailTab::Events {
+        cx.notify();
+      }
+    })
+    .detach();
+
     Self {
       docker_state,
       deployment: None,
@@ -625,6 +637,15 @@ impl Render for DeploymentDetail {
             cx.notify();
           })),
       )
+      .child(
+        Tab::new()
+          .label("Events")
+          .selected(active_tab == DeploymentDetailTab::Events)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = DeploymentDetailTab::Events;
+            cx.notify();
+          })),
+      )
       .child(
         Tab::new()
           .label("YAML")
@@ -641,6 +662,14 @@ impl Render for DeploymentDetail {
     let content = match active_tab {
       DeploymentDetailTab::Info => Self::render_info_tab(&deployment, cx),
       DeploymentDetailTab::Pods => self.render_pods_tab(&deployment, cx),
+      DeploymentDetailTab::Events => render_object_events(
+        &InvolvedObject::deployment(
+          &deployment.name,
+          &deployment.namespace,
+          &self.docker_state.read(cx).pods,
+        ),
+        cx,
+      ),
       DeploymentDetailTab::Yaml => self.render_yaml_tab(&deployment, cx),
     };
 
@@ -4,4 +4,5 @@ mod list;
 mod scale_dialog;
 mod view;
 
+pub use scale_dialog::ScaleDialog;
 pub use view::DeploymentsView;
@@ -9,9 +9,11 @@ use gpui_component::{
   v_flex,
 };
 
-/// Dialog for scaling a deployment's replica count
+/// Dialog for scaling a deployment's (or StatefulSet's) replica count
 pub struct ScaleDialog {
   focus_handle: FocusHandle,
+  /// Lowercase kind shown in the prompt, e.g. "deployment"
+  resource: &'static str,
   deployment_name: String,
   namespace: String,
   current_replicas: i32,
@@ -19,11 +21,18 @@ pub struct ScaleDialog {
 }
 
 impl ScaleDialog {
-  pub fn new(deployment_name: String, namespace: String, current_replicas: i32, cx: &mut Context<'_, Self>) -> Self {
+  pub fn new(
+    resource: &'static str,
+    deployment_name: String,
+    namespace: String,
+    current_replicas: i32,
+    cx: &mut Context<'_, Self>,
+  ) -> Self {
     let focus_handle = cx.focus_handle();
 
     Self {
       focus_handle,
+      resource,
       deployment_name,
       namespace,
       current_replicas,
@@ -74,8 +83,8 @@ impl Render for ScaleDialog {
       .gap(px(16.))
       .p(px(16.))
       .child(div().text_sm().text_color(colors.muted_foreground).child(format!(
-        "Scale deployment '{}' in namespace '{}'",
-        self.deployment_name, self.namespace
+        "Scale {} '{}' in namespace '{}'",
+        self.resource, self.deployment_name, self.namespace
       )))
       .child(
         h_flex()
@@ -170,8 +170,15 @@ impl DeploymentsView {
     window: &mut Window,
     cx: &mut Context<'_, Self>,
   ) {
-    let dialog_entity =
-      cx.new(|cx| ScaleDialog::new(deployment_name.to_string(), namespace.to_string(), current_replicas, cx));
+    let dialog_entity = cx.new(|cx| {
+      ScaleDialog::new(
+        "deployment",
+        deployment_name.to_string(),
+        namespace.to_string(),
+        current_replicas,
+        cx,
+      )
+    });
 
     window.open_dialog(cx, move |dialog, _window, cx| {
       let _colors = cx.theme().colors;
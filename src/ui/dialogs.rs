@@ -4,22 +4,28 @@
 //! and actions pre-configured. Call these functions from anywhere (views, command
 //! palette, menu bar) to open a fully functional dialog.
 
-use gpui::{App, AppContext, IntoElement, ParentElement, Styled, Window, div, px};
+use gpui::{App, AppContext, IntoElement, ParentElement, Styled, Window, div, prelude::FluentBuilder as _, px};
 use gpui_component::{
   WindowExt,
   button::{Button, ButtonVariants},
   h_flex,
+  input::{Input, InputState},
   theme::ActiveTheme,
   v_flex,
 };
 
+use crate::docker::{PROTECT_LABEL, PruneKind};
+use crate::kubernetes::ConfigKind;
 use crate::services;
+use crate::state::docker_state;
+use crate::ui::configs::create_dialog::CreateConfigDialog;
 use crate::ui::containers::CreateContainerDialog;
 use crate::ui::deployments::create_dialog::CreateDeploymentDialog;
 use crate::ui::images::pull_dialog::PullImageDialog;
 use crate::ui::machines::MachineDialog;
+use crate::ui::networks::connect_dialog::{ConnectNetworkDialog, ConnectTarget};
 use crate::ui::networks::create_dialog::CreateNetworkDialog;
-use crate::ui::prune_dialog::PruneDialog;
+use crate::ui::prune_dialog::{PruneDialog, PruneOptions};
 use crate::ui::services::create_dialog::CreateServiceDialog;
 use crate::ui::volumes::create_dialog::CreateVolumeDialog;
 
@@ -171,10 +177,20 @@ pub fn open_create_network_dialog(window: &mut Window, cx: &mut App) {
               .on_click({
                 let dialog = dialog_for_create.clone();
                 move |_ev, window, cx| {
-                  let options = dialog.read(cx).get_options(cx);
-                  if !options.name.is_empty() {
-                    services::create_network(options.name, options.enable_ipv6, options.subnet, cx);
-                    window.close_dialog(cx);
+                  let existing = docker_state(cx).read(cx).networks.clone();
+                  let options = dialog
+                    .read(cx)
+                    .get_options(cx)
+                    .and_then(|options| options.validate(&existing).map(|()| options));
+                  match options {
+                    Ok(options) => {
+                      services::create_network(options, cx);
+                      window.close_dialog(cx);
+                    }
+                    Err(e) => dialog.update(cx, |dialog, cx| {
+                      dialog.set_error(Some(e.to_string()));
+                      cx.notify();
+                    }),
                   }
                 }
               })
@@ -185,6 +201,42 @@ pub fn open_create_network_dialog(window: &mut Window, cx: &mut App) {
   });
 }
 
+/// Opens the Connect Network dialog, picking whichever side `target` leaves open
+pub fn open_connect_network_dialog(target: ConnectTarget, window: &mut Window, cx: &mut App) {
+  let title = match target {
+    ConnectTarget::Network { .. } => "Connect Container",
+    ConnectTarget::Container { .. } => "Connect to Network",
+  };
+  let dialog_entity = cx.new(|cx| ConnectNetworkDialog::new(target, cx));
+
+  window.open_dialog(cx, move |dialog, _window, _cx| {
+    let dialog_clone = dialog_entity.clone();
+
+    dialog
+      .title(title)
+      .min_w(px(500.))
+      .child(dialog_entity.clone())
+      .footer(move |_dialog_state, _, _window, _cx| {
+        let dialog_for_connect = dialog_clone.clone();
+        vec![
+          Button::new("connect")
+            .label("Connect")
+            .primary()
+            .on_click({
+              let dialog = dialog_for_connect.clone();
+              move |_ev, window, cx| {
+                if let Some(request) = dialog.read(cx).get_request(cx) {
+                  services::connect_network(request.network, request.container, request.options, cx);
+                  window.close_dialog(cx);
+                }
+              }
+            })
+            .into_any_element(),
+        ]
+      })
+  });
+}
+
 /// Opens the Create Machine (Colima) dialog with Create button configured
 pub fn open_create_machine_dialog(window: &mut Window, cx: &mut App) {
   let dialog_entity = cx.new(MachineDialog::new_create);
@@ -283,9 +335,63 @@ pub fn open_create_service_dialog(window: &mut Window, cx: &mut App) {
   });
 }
 
+/// Opens the Create ConfigMap or Create Secret dialog in the selected namespace
+pub fn open_create_config_dialog(kind: ConfigKind, window: &mut Window, cx: &mut App) {
+  let selected = docker_state(cx).read(cx).selected_namespace.clone();
+  let namespace = if selected == "all" {
+    "default".to_string()
+  } else {
+    selected
+  };
+  let dialog_entity = cx.new(|cx| CreateConfigDialog::new(kind, namespace, cx));
+
+  window.open_dialog(cx, move |dialog, _window, _cx| {
+    let dialog_clone = dialog_entity.clone();
+
+    dialog
+      .title(format!("Create {}", kind.label()))
+      .min_w(px(550.))
+      .child(dialog_entity.clone())
+      .footer(move |_dialog_state, _, _window, _cx| {
+        let dialog = dialog_clone.clone();
+        vec![
+          Button::new("create")
+            .label("Create")
+            .primary()
+            .on_click(move |_ev, window, cx| {
+              let options = dialog.read(cx).get_options(cx);
+              match options {
+                Ok(options) => {
+                  services::create_config(
+                    kind,
+                    options.name,
+                    options.namespace,
+                    options.literals,
+                    options.files,
+                    cx,
+                  );
+                  window.close_dialog(cx);
+                }
+                Err(e) => dialog.update(cx, |dialog, cx| {
+                  dialog.set_error(Some(e.to_string()));
+                  cx.notify();
+                }),
+              }
+            })
+            .into_any_element(),
+        ]
+      })
+  });
+}
+
 /// Opens the Prune Docker Resources dialog with Prune button configured
 pub fn open_prune_dialog(window: &mut Window, cx: &mut App) {
-  let dialog_entity = cx.new(PruneDialog::new);
+  open_prune_dialog_with(PruneOptions::default(), window, cx);
+}
+
+/// Opens the prune dialog with some options already switched on
+pub fn open_prune_dialog_with(options: PruneOptions, window: &mut Window, cx: &mut App) {
+  let dialog_entity = cx.new(|cx| PruneDialog::with_options(options, cx));
 
   window.open_dialog(cx, move |dialog, _window, _cx| {
     let dialog_clone = dialog_entity.clone();
@@ -297,14 +403,25 @@ pub fn open_prune_dialog(window: &mut Window, cx: &mut App) {
       .footer(move |_dialog_state, _, _window, _cx| {
         let dialog_for_prune = dialog_clone.clone();
         vec![
+          Button::new("prune-preview")
+            .label("Preview")
+            .ghost()
+            .on_click({
+              let dialog = dialog_for_prune.clone();
+              move |_ev, _window, cx| {
+                dialog.update(cx, |dialog, cx| dialog.load_preview(cx));
+              }
+            })
+            .into_any_element(),
           Button::new("prune")
             .label("Prune")
             .primary()
             .on_click({
               let dialog = dialog_for_prune.clone();
               move |_ev, window, cx| {
-                let options = dialog.read(cx).get_options();
-                if !options.is_empty() {
+                if let Some(options) = dialog.read(cx).get_options(cx)
+                  && !options.is_empty()
+                {
                   services::prune_docker(&options, cx);
                   window.close_dialog(cx);
                 }
@@ -316,6 +433,110 @@ pub fn open_prune_dialog(window: &mut Window, cx: &mut App) {
   });
 }
 
+/// Opens the typed confirmation for deleting a protected resource
+pub fn open_protected_delete_dialog(kind: PruneKind, id: String, name: String, window: &mut Window, cx: &mut App) {
+  let input = cx.new(|cx| InputState::new(window, cx).placeholder(name.clone()));
+  let what = match kind {
+    PruneKind::Containers => "container",
+    PruneKind::Images => "image",
+    PruneKind::Volumes => "volume",
+    PruneKind::Networks => "network",
+    PruneKind::BuildCache => "build cache record",
+  };
+
+  window.open_dialog(cx, move |dialog, _window, cx| {
+    let colors = cx.theme().colors;
+    let input_clone = input.clone();
+    let id = id.clone();
+    let name = name.clone();
+
+    dialog
+      .title(format!("Delete protected {what}"))
+      .min_w(px(440.))
+      .child(
+        v_flex()
+          .gap(px(8.))
+          .child(div().text_sm().text_color(colors.foreground).child(format!(
+            "{name} is protected by a {PROTECT_LABEL}=true label or a pin. Type its name to delete it anyway."
+          )))
+          .child(Input::new(&input).w_full()),
+      )
+      .footer(move |_dialog_state, _, _window, _cx| {
+        let input = input_clone.clone();
+        let id = id.clone();
+        let name = name.clone();
+
+        vec![
+          Button::new("delete-protected")
+            .label("Delete")
+            .danger()
+            .on_click(move |_ev, window, cx| {
+              if input.read(cx).text().trim() == name {
+                services::delete_protected(kind, id.clone(), cx);
+                window.close_dialog(cx);
+              }
+            })
+            .into_any_element(),
+        ]
+      })
+  });
+}
+
+/// Opens the confirmation for draining a Kubernetes node. Pods that only go when forced are
+/// listed, and draining them takes the explicit "Drain Anyway".
+pub fn open_drain_node_dialog(name: String, window: &mut Window, cx: &mut App) {
+  let (pods, blockers) = docker_state(cx)
+    .read(cx)
+    .get_node(&name)
+    .map_or((0, Vec::new()), |node| (node.active_pod_count(), node.drain_blockers()));
+
+  window.open_dialog(cx, move |dialog, _window, cx| {
+    let colors = cx.theme().colors;
+    let name = name.clone();
+    let force = !blockers.is_empty();
+
+    dialog
+      .title(format!("Drain {name}"))
+      .min_w(px(440.))
+      .child(
+        v_flex()
+          .gap(px(8.))
+          .child(div().text_sm().text_color(colors.foreground).child(format!(
+            "{name} will be cordoned and its {pods} pod(s) evicted. DaemonSet and static pods stay."
+          )))
+          .when(force, |el| {
+            el.child(div().text_sm().text_color(colors.warning).child(
+              "These pods have no controller to recreate them or keep data in emptyDir volumes. Draining deletes them and their data:",
+            ))
+            .children(blockers.iter().map(|pod| {
+              div()
+                .text_xs()
+                .font_family("monospace")
+                .text_color(colors.foreground)
+                .child(pod.clone())
+            }))
+          })
+          .child(div().text_xs().text_color(colors.muted_foreground).child(
+            "Evictions wait for PodDisruptionBudgets, so this can take a few minutes. Progress shows in the task list.",
+          )),
+      )
+      .footer(move |_dialog_state, _, _window, _cx| {
+        let name = name.clone();
+
+        vec![
+          Button::new("drain-node")
+            .label(if force { "Drain Anyway" } else { "Drain" })
+            .danger()
+            .on_click(move |_ev, window, cx| {
+              services::drain_node(name.clone(), force, cx);
+              window.close_dialog(cx);
+            })
+            .into_any_element(),
+        ]
+      })
+  });
+}
+
 /// Opens the About Dockside dialog
 pub fn open_about_dialog(window: &mut Window, cx: &mut App) {
   use gpui::{ImageSource, Resource, SharedString, img};
@@ -0,0 +1,514 @@
+use anyhow::{Result, bail};
+use chrono::Local;
+use gpui::{Context, Entity, Render, SharedString, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  Icon, IconName, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  label::Label,
+  switch::Switch,
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::assets::AppIcon;
+use crate::docker::{PruneFilters, PruneKind};
+use crate::services;
+use crate::state::{CleanupRule, CleanupTrigger, SettingsChanged, settings_state};
+
+/// Trigger picked in the add form, before its value is parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TriggerChoice {
+  Every,
+  DiskAbove,
+  SizeAbove,
+}
+
+impl TriggerChoice {
+  /// Longest schedule, a year
+  const MAX_HOURS: u64 = 24 * 365;
+
+  const ALL: [TriggerChoice; 3] = [TriggerChoice::Every, TriggerChoice::DiskAbove, TriggerChoice::SizeAbove];
+
+  fn label(self) -> &'static str {
+    match self {
+      TriggerChoice::Every => "Every N hours",
+      TriggerChoice::DiskAbove => "VM disk above %",
+      TriggerChoice::SizeAbove => "Size above GB",
+    }
+  }
+
+  /// Used when the value is left empty
+  fn default_value(self) -> &'static str {
+    match self {
+      TriggerChoice::Every => "24",
+      TriggerChoice::DiskAbove => "85",
+      TriggerChoice::SizeAbove => "10",
+    }
+  }
+
+  fn unit(self) -> &'static str {
+    match self {
+      TriggerChoice::Every => "hours",
+      TriggerChoice::DiskAbove => "percent",
+      TriggerChoice::SizeAbove => "GB",
+    }
+  }
+
+  fn parse(self, value: &str) -> Result<CleanupTrigger> {
+    let value = if value.trim().is_empty() {
+      self.default_value()
+    } else {
+      value.trim()
+    };
+    let Ok(number) = value.parse::<u64>() else {
+      bail!("'{value}' is not a whole number");
+    };
+    if number == 0 {
+      bail!("The value must be greater than zero");
+    }
+    Ok(match self {
+      TriggerChoice::Every if number > Self::MAX_HOURS => {
+        bail!("Schedules can be at most {} hours apart", Self::MAX_HOURS)
+      }
+      TriggerChoice::Every => CleanupTrigger::Every { hours: number },
+      TriggerChoice::DiskAbove => match u8::try_from(number) {
+        Ok(percent) if percent <= 100 => CleanupTrigger::DiskAbove { percent },
+        _ => bail!("Disk usage is a percentage up to 100"),
+      },
+      TriggerChoice::SizeAbove => CleanupTrigger::SizeAbove { gigabytes: number },
+    })
+  }
+}
+
+/// Validate the add form into a rule
+fn new_rule(
+  name: &str,
+  kind: PruneKind,
+  filters: &str,
+  dangling_only: bool,
+  trigger: CleanupTrigger,
+  existing: &[CleanupRule],
+) -> Result<CleanupRule> {
+  let name = name.trim();
+  if name.is_empty() {
+    bail!("Give the rule a name");
+  }
+  if existing.iter().any(|r| r.name == name) {
+    bail!("A rule named '{name}' already exists");
+  }
+  if kind == PruneKind::Networks && matches!(trigger, CleanupTrigger::SizeAbove { .. }) {
+    bail!("Networks take no disk space, use a schedule or the VM disk instead");
+  }
+  PruneFilters::parse(filters)?;
+
+  Ok(CleanupRule {
+    name: name.to_string(),
+    kind,
+    filters: filters.trim().to_string(),
+    dangling_only: kind == PruneKind::Images && dangling_only,
+    trigger,
+    paused: false,
+    last_run: None,
+  })
+}
+
+/// Cleanup rules section of the disk usage view: list, pause, run and add rules
+pub struct CleanupRulesPanel {
+  rules: Vec<CleanupRule>,
+  name_input: Entity<InputState>,
+  filters_input: Entity<InputState>,
+  value_input: Entity<InputState>,
+  kind: PruneKind,
+  dangling_only: bool,
+  trigger: TriggerChoice,
+  error: Option<String>,
+}
+
+impl CleanupRulesPanel {
+  pub fn new(window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
+    // Runs stamp `last_run`, so this also keeps the list current
+    cx.subscribe(&settings_state(cx), |this, state, event: &SettingsChanged, cx| {
+      if let SettingsChanged::SettingsUpdated = event {
+        this.rules = state.read(cx).settings.cleanup_rules.clone();
+        cx.notify();
+      }
+    })
+    .detach();
+
+    let name_input = cx.new(|cx| InputState::new(window, cx).placeholder("Rule name"));
+    let filters_input = cx.new(|cx| InputState::new(window, cx).placeholder("until=72h label!=keep=true"));
+    let value_input = cx.new(|cx| InputState::new(window, cx).placeholder("Default"));
+
+    Self {
+      rules: settings_state(cx).read(cx).settings.cleanup_rules.clone(),
+      name_input,
+      filters_input,
+      value_input,
+      kind: PruneKind::Containers,
+      dangling_only: false,
+      trigger: TriggerChoice::Every,
+      error: None,
+    }
+  }
+
+  fn update_rules(cx: &mut Context<'_, Self>, f: impl FnOnce(&mut Vec<CleanupRule>)) {
+    settings_state(cx).update(cx, |state, cx| {
+      f(&mut state.settings.cleanup_rules);
+      let _ = state.settings.save();
+      cx.emit(SettingsChanged::SettingsUpdated);
+    });
+  }
+
+  fn set_paused(name: &str, paused: bool, cx: &mut Context<'_, Self>) {
+    Self::update_rules(cx, |rules| {
+      if let Some(rule) = rules.iter_mut().find(|r| r.name == name) {
+        rule.paused = paused;
+      }
+    });
+  }
+
+  fn delete_rule(name: &str, cx: &mut Context<'_, Self>) {
+    Self::update_rules(cx, |rules| rules.retain(|r| r.name != name));
+  }
+
+  fn set_trigger(&mut self, trigger: TriggerChoice, window: &mut Window, cx: &mut Context<'_, Self>) {
+    self.trigger = trigger;
+    self.value_input.update(cx, |input, cx| input.set_value("", window, cx));
+    cx.notify();
+  }
+
+  fn add_rule(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let name = self.name_input.read(cx).text().to_string();
+    let filters = self.filters_input.read(cx).text().to_string();
+    let value = self.value_input.read(cx).text().to_string();
+
+    let rule = self
+      .trigger
+      .parse(&value)
+      .and_then(|trigger| new_rule(&name, self.kind, &filters, self.dangling_only, trigger, &self.rules));
+    match rule {
+      Ok(rule) => {
+        self.error = None;
+        Self::update_rules(cx, |rules| rules.push(rule));
+        for input in [&self.name_input, &self.filters_input, &self.value_input] {
+          input.update(cx, |input, cx| input.set_value("", window, cx));
+        }
+      }
+      Err(e) => self.error = Some(e.to_string()),
+    }
+    cx.notify();
+  }
+
+  fn render_rule(index: usize, rule: &CleanupRule, cx: &Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let last_run = rule.last_run.map_or_else(
+      || "Never run".to_string(),
+      |t| format!("Last run {}", t.with_timezone(&Local).format("%Y-%m-%d %H:%M")),
+    );
+    let pause_name = rule.name.clone();
+    let delete_name = rule.name.clone();
+    let run_rule = rule.clone();
+
+    h_flex()
+      .w_full()
+      .px(px(12.))
+      .py(px(10.))
+      .gap(px(12.))
+      .items_center()
+      .when(index > 0, |el| el.border_t_1().border_color(colors.border))
+      .child(
+        v_flex()
+          .flex_1()
+          .min_w_0()
+          .gap(px(2.))
+          .child(
+            div()
+              .text_sm()
+              .text_color(if rule.paused {
+                colors.muted_foreground
+              } else {
+                colors.foreground
+              })
+              .child(rule.name.clone()),
+          )
+          .child(
+            div()
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .overflow_hidden()
+              .text_ellipsis()
+              .child(format!("{} · {last_run}", rule.describe())),
+          ),
+      )
+      .child(
+        Switch::new(("cleanup-rule-active", index))
+          .checked(!rule.paused)
+          .on_click(cx.listener(move |_this, checked: &bool, _window, cx| {
+            Self::set_paused(&pause_name, !*checked, cx);
+          })),
+      )
+      .child(
+        Button::new(("cleanup-rule-run", index))
+          .label("Run now")
+          .icon(Icon::new(AppIcon::Play))
+          .xsmall()
+          .on_click(move |_ev, _window, cx| {
+            services::run_cleanup_rule(run_rule.clone(), cx).detach();
+          }),
+      )
+      .child(
+        Button::new(("cleanup-rule-delete", index))
+          .icon(Icon::new(AppIcon::Trash))
+          .xsmall()
+          .ghost()
+          .tooltip("Delete rule")
+          .on_click(cx.listener(move |_this, _ev, _window, cx| {
+            Self::delete_rule(&delete_name, cx);
+          })),
+      )
+  }
+
+  fn render_form_row(label: &'static str, content: impl IntoElement, cx: &Context<'_, Self>) -> impl IntoElement {
+    h_flex()
+      .gap(px(8.))
+      .items_center()
+      .child(
+        div()
+          .w(px(80.))
+          .flex_shrink_0()
+          .text_xs()
+          .text_color(cx.theme().colors.muted_foreground)
+          .child(label),
+      )
+      .child(content)
+  }
+
+  fn render_form(&self, cx: &Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+
+    let kinds = h_flex()
+      .gap(px(4.))
+      .flex_wrap()
+      .children(PruneKind::ALL.into_iter().map(|kind| {
+        let selected = self.kind == kind;
+        Button::new(SharedString::from(format!("cleanup-kind-{kind:?}")))
+          .label(kind.label())
+          .xsmall()
+          .when(selected, Button::primary)
+          .when(!selected, ButtonVariants::ghost)
+          .on_click(cx.listener(move |this, _ev, _window, cx| {
+            this.kind = kind;
+            cx.notify();
+          }))
+      }));
+
+    let triggers = h_flex()
+      .gap(px(4.))
+      .flex_wrap()
+      .children(TriggerChoice::ALL.into_iter().map(|trigger| {
+        let selected = self.trigger == trigger;
+        Button::new(SharedString::from(format!("cleanup-trigger-{trigger:?}")))
+          .label(trigger.label())
+          .xsmall()
+          .when(selected, Button::primary)
+          .when(!selected, ButtonVariants::ghost)
+          .on_click(cx.listener(move |this, _ev, window, cx| {
+            this.set_trigger(trigger, window, cx);
+          }))
+      }));
+
+    v_flex()
+      .p(px(12.))
+      .gap(px(8.))
+      .rounded(px(8.))
+      .bg(colors.background)
+      .child(Self::render_form_row(
+        "Name",
+        div().flex_1().child(Input::new(&self.name_input).small()),
+        cx,
+      ))
+      .child(Self::render_form_row("Remove", kinds, cx))
+      .when(self.kind == PruneKind::Images, |el| {
+        el.child(Self::render_form_row(
+          "",
+          h_flex()
+            .gap(px(8.))
+            .items_center()
+            .child(
+              Switch::new("cleanup-dangling-only")
+                .checked(self.dangling_only)
+                .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                  this.dangling_only = *checked;
+                  cx.notify();
+                })),
+            )
+            .child(
+              div()
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .child("Only dangling images"),
+            ),
+          cx,
+        ))
+      })
+      .child(Self::render_form_row(
+        "Filters",
+        div().flex_1().child(Input::new(&self.filters_input).small()),
+        cx,
+      ))
+      .when(self.kind == PruneKind::Images, |el| {
+        el.child(Self::render_form_row(
+          "",
+          div()
+            .text_xs()
+            .text_color(colors.muted_foreground)
+            .child("Docker doesn't record when an image was last used: until= is its build time"),
+          cx,
+        ))
+      })
+      .child(Self::render_form_row("Trigger", triggers, cx))
+      .child(Self::render_form_row(
+        "Value",
+        h_flex()
+          .flex_1()
+          .gap(px(8.))
+          .items_center()
+          .child(div().w(px(120.)).child(Input::new(&self.value_input).small()))
+          .child(div().text_xs().text_color(colors.muted_foreground).child(format!(
+            "{} (default {})",
+            self.trigger.unit(),
+            self.trigger.default_value()
+          )))
+          .child(div().flex_1())
+          .child(
+            Button::new("cleanup-rule-add")
+              .label("Add rule")
+              .icon(Icon::new(IconName::Plus))
+              .small()
+              .primary()
+              .on_click(cx.listener(|this, _ev, window, cx| {
+                this.add_rule(window, cx);
+              })),
+          ),
+        cx,
+      ))
+      .when_some(self.error.clone(), |el, error| {
+        el.child(div().text_xs().text_color(colors.danger).child(error))
+      })
+  }
+}
+
+impl Render for CleanupRulesPanel {
+  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+
+    let rules = if self.rules.is_empty() {
+      div()
+        .px(px(12.))
+        .py(px(10.))
+        .text_sm()
+        .text_color(colors.muted_foreground)
+        .child("No cleanup rules yet")
+        .into_any_element()
+    } else {
+      v_flex()
+        .children(
+          self
+            .rules
+            .iter()
+            .enumerate()
+            .map(|(i, rule)| Self::render_rule(i, rule, cx)),
+        )
+        .into_any_element()
+    };
+
+    v_flex()
+      .w_full()
+      .p(px(12.))
+      .gap(px(12.))
+      .rounded(px(8.))
+      .border_1()
+      .border_color(colors.border)
+      .bg(colors.sidebar)
+      .child(
+        v_flex()
+          .gap(px(2.))
+          .child(Label::new("Cleanup Rules").text_color(colors.foreground))
+          .child(div().text_xs().text_color(colors.muted_foreground).child(
+            "Prunes that run in the background on a schedule or when a threshold is crossed. Runs are recorded in Events.",
+          )),
+      )
+      .child(
+        v_flex()
+          .bg(colors.background)
+          .rounded(px(8.))
+          .overflow_hidden()
+          .child(rules),
+      )
+      .child(self.render_form(cx))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_trigger_choice_parse() {
+    assert_eq!(
+      TriggerChoice::Every.parse("").unwrap(),
+      CleanupTrigger::Every { hours: 24 }
+    );
+    assert_eq!(
+      TriggerChoice::DiskAbove.parse(" 90 ").unwrap(),
+      CleanupTrigger::DiskAbove { percent: 90 }
+    );
+    assert_eq!(
+      TriggerChoice::SizeAbove.parse("10").unwrap(),
+      CleanupTrigger::SizeAbove { gigabytes: 10 }
+    );
+    assert!(TriggerChoice::DiskAbove.parse("150").is_err());
+    assert!(TriggerChoice::Every.parse("0").is_err());
+    assert!(TriggerChoice::Every.parse("8760").is_ok());
+    assert!(TriggerChoice::Every.parse("8761").is_err());
+    assert!(TriggerChoice::Every.parse("99999999999999999").is_err());
+    assert!(TriggerChoice::Every.parse("daily").is_err());
+  }
+
+  #[test]
+  fn test_new_rule_validation() {
+    let every = CleanupTrigger::Every { hours: 24 };
+    let rule = new_rule(
+      " Untagged images ",
+      PruneKind::Images,
+      "label!=keep=true",
+      true,
+      every,
+      &[],
+    )
+    .unwrap();
+    assert_eq!(rule.name, "Untagged images");
+    assert!(rule.dangling_only);
+
+    // Dangling only applies to images
+    let rule = new_rule("Stopped", PruneKind::Containers, "", true, every, &[]).unwrap();
+    assert!(!rule.dangling_only);
+
+    assert!(new_rule("", PruneKind::Images, "", false, every, &[]).is_err());
+    assert!(new_rule("Stopped", PruneKind::Containers, "", false, every, &[rule]).is_err());
+    assert!(new_rule("Bad", PruneKind::Images, "until", false, every, &[]).is_err());
+    assert!(
+      new_rule(
+        "Nets",
+        PruneKind::Networks,
+        "",
+        false,
+        CleanupTrigger::SizeAbove { gigabytes: 1 },
+        &[]
+      )
+      .is_err()
+    );
+  }
+}
@@ -0,0 +1,5 @@
+mod cleanup_rules;
+mod view;
+
+pub use cleanup_rules::CleanupRulesPanel;
+pub use view::DiskUsageView;
@@ -0,0 +1,576 @@
+use gpui::{App, Context, Entity, Hsla, Render, SharedString, Styled, Window, div, prelude::*, px, relative};
+use gpui_component::{
+  Icon, IconName, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  scroll::ScrollableElement,
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::assets::AppIcon;
+use crate::colima::{ColimaClient, VmDiskUsage};
+use crate::docker::{DiskUsageCategory, DiskUsageItem, DiskUsageSummary, SystemDiskUsage};
+use crate::services::{self, DispatcherEvent, dispatcher};
+use crate::state::{CurrentView, Selection, docker_state};
+use crate::ui::PruneOptions;
+use crate::ui::dialogs;
+
+use super::CleanupRulesPanel;
+
+/// Items listed per category
+const TOP_ITEMS: usize = 5;
+
+/// Where disk space goes: Docker data by category and the Colima VM disks it lives on
+pub struct DiskUsageView {
+  usage: Option<SystemDiskUsage>,
+  error: Option<String>,
+  is_loading: bool,
+  /// Running Colima machines and their root disk usage
+  vm_disks: Vec<(String, Option<VmDiskUsage>)>,
+  cleanup_rules: Entity<CleanupRulesPanel>,
+}
+
+impl DiskUsageView {
+  pub fn new(window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
+    // Prunes and deletes change the numbers
+    cx.subscribe(&dispatcher(cx), |this, _disp, event: &DispatcherEvent, cx| {
+      if matches!(event, DispatcherEvent::TaskCompleted { .. }) {
+        this.refresh(cx);
+      }
+    })
+    .detach();
+
+    let mut view = Self {
+      usage: None,
+      error: None,
+      is_loading: false,
+      vm_disks: Vec::new(),
+      cleanup_rules: cx.new(|cx| CleanupRulesPanel::new(window, cx)),
+    };
+    view.refresh(cx);
+    view
+  }
+
+  fn refresh(&mut self, cx: &mut Context<'_, Self>) {
+    if self.is_loading {
+      return;
+    }
+    self.is_loading = true;
+    cx.notify();
+
+    let tokio_handle = services::Tokio::runtime_handle();
+    let client = services::docker_client();
+    cx.spawn(async move |this, cx| {
+      let result = cx
+        .background_executor()
+        .spawn(async move {
+          tokio_handle.block_on(async {
+            let guard = client.read().await;
+            match guard.as_ref() {
+              Some(docker) => docker.system_df().await,
+              None => Err(anyhow::anyhow!("Docker client not connected")),
+            }
+          })
+        })
+        .await;
+
+      let _ = this.update(cx, |this, cx| {
+        this.is_loading = false;
+        match result {
+          Ok(usage) => {
+            this.usage = Some(usage);
+            this.error = None;
+          }
+          Err(e) => this.error = Some(e.to_string()),
+        }
+        cx.notify();
+      });
+    })
+    .detach();
+
+    let machines: Vec<String> = docker_state(cx)
+      .read(cx)
+      .colima_vms
+      .iter()
+      .filter(|vm| vm.status.is_running())
+      .map(|vm| vm.name.clone())
+      .collect();
+    cx.spawn(async move |this, cx| {
+      let disks = cx
+        .background_executor()
+        .spawn(async move {
+          machines
+            .into_iter()
+            .map(|name| {
+              let profile = (name != "default").then_some(name.as_str());
+              let usage = ColimaClient::get_disk_usage(profile)
+                .ok()
+                .and_then(|output| VmDiskUsage::parse(&output));
+              (name, usage)
+            })
+            .collect::<Vec<_>>()
+        })
+        .await;
+
+      let _ = this.update(cx, |this, cx| {
+        this.vm_disks = disks;
+        cx.notify();
+      });
+    })
+    .detach();
+  }
+
+  fn category_color(category: DiskUsageCategory, cx: &App) -> Hsla {
+    let colors = &cx.theme().colors;
+    match category {
+      DiskUsageCategory::Images => colors.primary,
+      DiskUsageCategory::Containers => colors.info,
+      DiskUsageCategory::Volumes => colors.success,
+      DiskUsageCategory::BuildCache => colors.warning,
+    }
+  }
+
+  fn category_icon(category: DiskUsageCategory) -> Icon {
+    match category {
+      DiskUsageCategory::Images => Icon::new(AppIcon::Image),
+      DiskUsageCategory::Containers => Icon::new(AppIcon::Container),
+      DiskUsageCategory::Volumes => Icon::new(AppIcon::Volume),
+      DiskUsageCategory::BuildCache => Icon::new(IconName::Inbox),
+    }
+  }
+
+  /// Prune dialog preset for a category
+  fn prune_options(category: DiskUsageCategory) -> PruneOptions {
+    match category {
+      DiskUsageCategory::Images => PruneOptions {
+        prune_images: true,
+        ..Default::default()
+      },
+      DiskUsageCategory::Containers => PruneOptions {
+        prune_containers: true,
+        ..Default::default()
+      },
+      DiskUsageCategory::Volumes => PruneOptions {
+        prune_volumes: true,
+        ..Default::default()
+      },
+      DiskUsageCategory::BuildCache => PruneOptions {
+        prune_build_cache: true,
+        ..Default::default()
+      },
+    }
+  }
+
+  /// Open an item in its own view. Build cache records have no view.
+  fn open_item(category: DiskUsageCategory, id: &str, cx: &mut App) {
+    let state = docker_state(cx);
+    let state = state.read(cx);
+    let target = match category {
+      DiskUsageCategory::Images => state
+        .images
+        .iter()
+        .find(|i| i.id == id)
+        .map(|i| (CurrentView::Images, Selection::Image(i.clone()))),
+      DiskUsageCategory::Containers => state
+        .containers
+        .iter()
+        .find(|c| c.id == id)
+        .map(|c| (CurrentView::Containers, Selection::Container(c.clone()))),
+      DiskUsageCategory::Volumes => Some((CurrentView::Volumes, Selection::Volume(id.to_string()))),
+      DiskUsageCategory::BuildCache => None,
+    };
+    if let Some((view, selection)) = target {
+      services::select_resource(view, selection, cx);
+    }
+  }
+
+  fn render_header(&self, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let summary = self.usage.as_ref().map(|usage| {
+      format!(
+        "{} used · {} reclaimable",
+        bytesize::ByteSize(usage.total()),
+        bytesize::ByteSize(usage.reclaimable())
+      )
+    });
+
+    h_flex()
+      .w_full()
+      .px(px(16.))
+      .py(px(12.))
+      .gap(px(12.))
+      .items_center()
+      .border_b_1()
+      .border_color(colors.border)
+      .bg(colors.sidebar)
+      .child(
+        div()
+          .text_base()
+          .font_weight(gpui::FontWeight::SEMIBOLD)
+          .text_color(colors.foreground)
+          .child("Disk Usage"),
+      )
+      .when_some(summary, |el, summary| {
+        el.child(div().text_sm().text_color(colors.muted_foreground).child(summary))
+      })
+      .child(div().flex_1())
+      .child(
+        Button::new("disk-usage-prune")
+          .label("Prune...")
+          .icon(Icon::new(AppIcon::Trash))
+          .ghost()
+          .small()
+          .on_click(|_ev, window, cx| {
+            dialogs::open_prune_dialog(window, cx);
+          }),
+      )
+      .child(
+        Button::new("disk-usage-refresh")
+          .icon(Icon::new(AppIcon::Refresh))
+          .ghost()
+          .small()
+          .loading(self.is_loading)
+          .tooltip("Refresh")
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.refresh(cx);
+          })),
+      )
+  }
+
+  /// One bar split by category, so the biggest consumer stands out
+  fn render_breakdown(usage: &SystemDiskUsage, cx: &App) -> impl IntoElement {
+    let colors = &cx.theme().colors;
+    let total = usage.total();
+
+    #[allow(clippy::cast_precision_loss)]
+    let fraction = |bytes: u64| if total == 0 { 0.0 } else { bytes as f32 / total as f32 };
+
+    v_flex()
+      .w_full()
+      .gap(px(8.))
+      .child(
+        h_flex()
+          .w_full()
+          .h(px(10.))
+          .rounded(px(5.))
+          .overflow_hidden()
+          .bg(colors.background)
+          .children(usage.categories().into_iter().filter(|c| c.total > 0).map(|c| {
+            div()
+              .h_full()
+              .w(relative(fraction(c.total)))
+              .bg(Self::category_color(c.category, cx))
+          })),
+      )
+      .child(
+        h_flex()
+          .gap(px(16.))
+          .flex_wrap()
+          .children(usage.categories().into_iter().map(|c| {
+            h_flex()
+              .gap(px(6.))
+              .items_center()
+              .child(
+                div()
+                  .size(px(8.))
+                  .rounded_full()
+                  .bg(Self::category_color(c.category, cx)),
+              )
+              .child(div().text_xs().text_color(colors.muted_foreground).child(format!(
+                "{} {}",
+                c.category.label(),
+                c.display_total()
+              )))
+          })),
+      )
+  }
+
+  fn render_vm_disk(name: &str, usage: Option<&VmDiskUsage>, cx: &App) -> impl IntoElement {
+    let colors = &cx.theme().colors;
+    let percent = usage.map_or(0, |u| u.use_percent);
+    let bar_color = if percent >= 90 {
+      colors.danger
+    } else if percent >= 75 {
+      colors.warning
+    } else {
+      colors.success
+    };
+
+    v_flex()
+      .flex_1()
+      .min_w(px(240.))
+      .p(px(12.))
+      .gap(px(8.))
+      .rounded(px(8.))
+      .border_1()
+      .border_color(colors.border)
+      .bg(colors.sidebar)
+      .child(
+        h_flex()
+          .gap(px(8.))
+          .items_center()
+          .child(Icon::new(AppIcon::Machine).size(px(16.)).text_color(colors.primary))
+          .child(
+            div()
+              .flex_1()
+              .text_sm()
+              .font_weight(gpui::FontWeight::SEMIBOLD)
+              .text_color(colors.foreground)
+              .child(format!("Colima VM · {name}")),
+          )
+          .child(
+            div()
+              .text_sm()
+              .text_color(colors.muted_foreground)
+              .child(usage.map_or_else(|| "--".to_string(), |u| format!("{} / {}", u.used, u.size))),
+          ),
+      )
+      .child(
+        div().w_full().h(px(8.)).rounded(px(4.)).bg(colors.background).child(
+          div()
+            .h_full()
+            .rounded(px(4.))
+            .bg(bar_color)
+            .w(relative(f32::from(percent) / 100.0)),
+        ),
+      )
+      .child(
+        div()
+          .text_xs()
+          .text_color(if percent >= 90 {
+            colors.danger
+          } else {
+            colors.muted_foreground
+          })
+          .child(match usage {
+            Some(u) if percent >= 90 => format!(
+              "{percent}% used, {} free. The VM disk is nearly full; prune or grow it with colima start --disk.",
+              u.available
+            ),
+            Some(u) => format!("{percent}% used, {} free", u.available),
+            None => "Disk usage unavailable".to_string(),
+          }),
+      )
+  }
+
+  fn render_item(category: DiskUsageCategory, index: usize, item: &DiskUsageItem, cx: &App) -> impl IntoElement {
+    let colors = &cx.theme().colors;
+    let navigable = category != DiskUsageCategory::BuildCache;
+    let id = item.id.clone();
+
+    h_flex()
+      .id(SharedString::from(format!("disk-usage-{category:?}-{index}")))
+      .w_full()
+      .px(px(8.))
+      .py(px(4.))
+      .gap(px(8.))
+      .items_center()
+      .rounded(px(4.))
+      .when(navigable, |el| {
+        el.cursor_pointer()
+          .hover(|s| s.bg(colors.list_hover))
+          .on_click(move |_ev, _window, cx| {
+            Self::open_item(category, &id, cx);
+          })
+      })
+      .child(
+        v_flex()
+          .flex_1()
+          .min_w_0()
+          .child(
+            div()
+              .text_sm()
+              .text_color(colors.foreground)
+              .overflow_hidden()
+              .text_ellipsis()
+              .whitespace_nowrap()
+              .child(item.name.clone()),
+          )
+          .when(!item.detail.is_empty(), |el| {
+            el.child(
+              div()
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .overflow_hidden()
+                .text_ellipsis()
+                .whitespace_nowrap()
+                .child(item.detail.clone()),
+            )
+          }),
+      )
+      .when(item.reclaimable, |el| {
+        el.child(
+          div()
+            .flex_shrink_0()
+            .px(px(6.))
+            .rounded(px(4.))
+            .bg(colors.warning.opacity(0.15))
+            .text_xs()
+            .text_color(colors.warning)
+            .child("reclaimable"),
+        )
+      })
+      .child(
+        div()
+          .flex_shrink_0()
+          .text_sm()
+          .text_color(colors.secondary_foreground)
+          .child(bytesize::ByteSize(item.size).to_string()),
+      )
+  }
+
+  fn render_category(summary: &DiskUsageSummary, cx: &App) -> impl IntoElement {
+    let colors = &cx.theme().colors;
+    let category = summary.category;
+    let color = Self::category_color(category, cx);
+
+    v_flex()
+      .flex_1()
+      .min_w(px(320.))
+      .p(px(12.))
+      .gap(px(10.))
+      .rounded(px(8.))
+      .border_1()
+      .border_color(colors.border)
+      .bg(colors.sidebar)
+      .child(
+        h_flex()
+          .gap(px(8.))
+          .items_center()
+          .child(Self::category_icon(category).size(px(16.)).text_color(color))
+          .child(
+            div()
+              .flex_1()
+              .text_sm()
+              .font_weight(gpui::FontWeight::SEMIBOLD)
+              .text_color(colors.foreground)
+              .child(category.label()),
+          )
+          .child(
+            div()
+              .text_sm()
+              .font_weight(gpui::FontWeight::SEMIBOLD)
+              .text_color(colors.foreground)
+              .child(summary.display_total()),
+          ),
+      )
+      .child(
+        h_flex()
+          .gap(px(8.))
+          .items_center()
+          .child(
+            div()
+              .flex_1()
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .child(format!(
+                "{} items, {} in use · {} reclaimable ({}%)",
+                summary.items.len(),
+                summary.active,
+                summary.display_reclaimable(),
+                summary.reclaimable_percent()
+              )),
+          )
+          .when(summary.reclaimable > 0, |el| {
+            el.child(
+              Button::new(SharedString::from(format!("disk-usage-clean-{category:?}")))
+                .label("Clean up")
+                .ghost()
+                .xsmall()
+                .on_click(move |_ev, window, cx| {
+                  dialogs::open_prune_dialog_with(Self::prune_options(category), window, cx);
+                }),
+            )
+          }),
+      )
+      .when(summary.items.is_empty(), |el| {
+        el.child(
+          div()
+            .text_xs()
+            .text_color(colors.muted_foreground)
+            .child("Nothing here"),
+        )
+      })
+      .child(
+        v_flex().gap(px(2.)).children(
+          summary
+            .top(TOP_ITEMS)
+            .iter()
+            .enumerate()
+            .map(|(i, item)| Self::render_item(category, i, item, cx)),
+        ),
+      )
+      .when(summary.items.len() > TOP_ITEMS, |el| {
+        el.child(
+          div()
+            .text_xs()
+            .text_color(colors.muted_foreground)
+            .child(format!("and {} more", summary.items.len() - TOP_ITEMS)),
+        )
+      })
+  }
+}
+
+impl Render for DiskUsageView {
+  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+
+    let body = v_flex()
+      .w_full()
+      .p(px(16.))
+      .gap(px(16.))
+      .when_some(self.error.clone(), |el, error| {
+        el.child(
+          div()
+            .w_full()
+            .p(px(12.))
+            .rounded(px(4.))
+            .bg(colors.danger.opacity(0.1))
+            .text_sm()
+            .text_color(colors.danger)
+            .child(format!("Failed to load disk usage: {error}")),
+        )
+      })
+      .when(!self.vm_disks.is_empty(), |el| {
+        el.child(
+          h_flex().gap(px(12.)).flex_wrap().children(
+            self
+              .vm_disks
+              .iter()
+              .map(|(name, usage)| Self::render_vm_disk(name, usage.as_ref(), cx)),
+          ),
+        )
+      })
+      .map(|el| match &self.usage {
+        Some(usage) => el.child(Self::render_breakdown(usage, cx)).child(
+          h_flex()
+            .gap(px(12.))
+            .flex_wrap()
+            .items_start()
+            .children(usage.categories().into_iter().map(|c| Self::render_category(c, cx))),
+        ),
+        None if self.is_loading => el.child(
+          div()
+            .text_sm()
+            .text_color(colors.muted_foreground)
+            .child("Calculating disk usage..."),
+        ),
+        None => el,
+      })
+      .child(self.cleanup_rules.clone());
+
+    v_flex()
+      .size_full()
+      .overflow_hidden()
+      .child(self.render_header(cx))
+      .child(
+        div()
+          .id("disk-usage-scroll")
+          .flex_1()
+          .min_h_0()
+          .overflow_y_scrollbar()
+          .child(body),
+      )
+  }
+}
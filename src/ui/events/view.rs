@@ -0,0 +1,608 @@
+use std::collections::BTreeSet;
+
+use chrono::Local;
+use gpui::{App, Context, Entity, Render, SharedString, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  Icon, IconName, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  label::Label,
+  menu::{DropdownMenu, PopupMenuItem},
+  scroll::ScrollableElement,
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::assets::AppIcon;
+use crate::kubernetes::WorkloadKind;
+use crate::services;
+use crate::state::{
+  CurrentView, DockerState, EventSource, EventsChanged, EventsState, InvolvedObject, Selection, StateChanged,
+  TimelineEvent, docker_state, events_state,
+};
+
+/// Number of matching events rendered at once (newest first)
+const MAX_VISIBLE_EVENTS: usize = 500;
+
+/// Which event sources are shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum SourceFilter {
+  #[default]
+  All,
+  Docker,
+  Kubernetes,
+}
+
+impl SourceFilter {
+  const ALL: [SourceFilter; 3] = [SourceFilter::All, SourceFilter::Docker, SourceFilter::Kubernetes];
+
+  fn label(self) -> &'static str {
+    match self {
+      SourceFilter::All => "All",
+      SourceFilter::Docker => "Docker",
+      SourceFilter::Kubernetes => "Kubernetes",
+    }
+  }
+
+  fn matches(self, source: EventSource) -> bool {
+    match self {
+      SourceFilter::All => true,
+      SourceFilter::Docker => source == EventSource::Docker,
+      SourceFilter::Kubernetes => source == EventSource::Kubernetes,
+    }
+  }
+}
+
+/// Which event types are shown (Kubernetes `type`, or Docker `die`/`oom` as warnings)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum TypeFilter {
+  #[default]
+  All,
+  Warning,
+  Normal,
+}
+
+impl TypeFilter {
+  const ALL: [TypeFilter; 3] = [TypeFilter::All, TypeFilter::Warning, TypeFilter::Normal];
+
+  fn label(self) -> &'static str {
+    match self {
+      TypeFilter::All => "All types",
+      TypeFilter::Warning => "Warning",
+      TypeFilter::Normal => "Normal",
+    }
+  }
+
+  fn matches(self, is_warning: bool) -> bool {
+    match self {
+      TypeFilter::All => true,
+      TypeFilter::Warning => is_warning,
+      TypeFilter::Normal => !is_warning,
+    }
+  }
+}
+
+/// Timeline of Docker and Kubernetes events, persisted across restarts
+pub struct EventsView {
+  docker_state: Entity<DockerState>,
+  events_state: Entity<EventsState>,
+  search_input: Entity<InputState>,
+  source_filter: SourceFilter,
+  type_filter: TypeFilter,
+  reason_filter: Option<String>,
+  object_filter: Option<InvolvedObject>,
+}
+
+impl EventsView {
+  pub fn new(window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
+    let docker_state = docker_state(cx);
+    let events_state = events_state(cx);
+    let search_input = cx.new(|cx| InputState::new(window, cx).placeholder("Filter events..."));
+
+    cx.subscribe(&events_state, |_this, _state, _event: &EventsChanged, cx| {
+      cx.notify();
+    })
+    .detach();
+
+    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| match event {
+      StateChanged::NamespacesUpdated => cx.notify(),
+      StateChanged::EventsFilterRequest { object } => {
+        this.show_object(object.clone());
+        cx.notify();
+      }
+      _ => {}
+    })
+    .detach();
+
+    cx.observe(&search_input, |_this, _input, cx| {
+      cx.notify();
+    })
+    .detach();
+
+    Self {
+      docker_state,
+      events_state,
+      search_input,
+      source_filter: SourceFilter::All,
+      type_filter: TypeFilter::All,
+      reason_filter: None,
+      object_filter: None,
+    }
+  }
+
+  /// Narrow the timeline to one object's events, dropping filters that could hide them
+  fn show_object(&mut self, object: InvolvedObject) {
+    self.source_filter = SourceFilter::All;
+    self.type_filter = TypeFilter::All;
+    self.reason_filter = None;
+    self.object_filter = Some(object);
+  }
+
+  /// Source, namespace and object filters; the base the reason choices are built from
+  fn in_scope(&self, event: &TimelineEvent, namespace: &str) -> bool {
+    if !self.source_filter.matches(event.source) {
+      return false;
+    }
+    if let Some(ref object) = self.object_filter {
+      return object.matches(event);
+    }
+    event.source != EventSource::Kubernetes || namespace == "all" || event.namespace.as_deref() == Some(namespace)
+  }
+
+  /// Distinct reasons (or Docker actions) among the events in scope
+  fn reasons(&self, cx: &App) -> Vec<String> {
+    let namespace = &self.docker_state.read(cx).selected_namespace;
+    let reasons: BTreeSet<&str> = self
+      .events_state
+      .read(cx)
+      .events
+      .iter()
+      .filter(|e| self.in_scope(e, namespace))
+      .map(|e| e.action.as_str())
+      .filter(|a| !a.is_empty())
+      .collect();
+    reasons.into_iter().map(str::to_string).collect()
+  }
+
+  /// Find the view and selection an event refers to, if the resource still exists
+  fn resource_link(event: &TimelineEvent, cx: &App) -> Option<(CurrentView, Selection)> {
+    let state = docker_state(cx).read(cx);
+    let namespace = event.namespace.clone().unwrap_or_default();
+
+    match (event.source, event.kind.as_str()) {
+      (EventSource::Docker, "container") => state
+        .containers
+        .iter()
+        .find(|c| c.id == event.resource_id)
+        .map(|c| (CurrentView::Containers, Selection::Container(c.clone()))),
+      (EventSource::Docker, "image") => state
+        .images
+        .iter()
+        .find(|i| i.id == event.resource_id || i.repo_tags.contains(&event.resource_id))
+        .map(|i| (CurrentView::Images, Selection::Image(i.clone()))),
+      (EventSource::Docker, "volume") => state
+        .volumes
+        .iter()
+        .find(|v| v.name == event.resource_id)
+        .map(|v| (CurrentView::Volumes, Selection::Volume(v.name.clone()))),
+      (EventSource::Docker, "network") => state
+        .networks
+        .iter()
+        .find(|n| n.id == event.resource_id)
+        .map(|n| (CurrentView::Networks, Selection::Network(n.id.clone()))),
+      (EventSource::Kubernetes, "Pod") => state
+        .pods
+        .iter()
+        .find(|p| p.name == event.resource_name && p.namespace == namespace)
+        .map(|p| {
+          (
+            CurrentView::Pods,
+            Selection::Pod {
+              name: p.name.clone(),
+              namespace: p.namespace.clone(),
+            },
+          )
+        }),
+      (EventSource::Kubernetes, "Deployment") => state
+        .deployments
+        .iter()
+        .find(|d| d.name == event.resource_name && d.namespace == namespace)
+        .map(|d| {
+          (
+            CurrentView::Deployments,
+            Selection::Deployment {
+              name: d.name.clone(),
+              namespace: d.namespace.clone(),
+            },
+          )
+        }),
+      (EventSource::Kubernetes, "Service") => state
+        .services
+        .iter()
+        .find(|s| s.name == event.resource_name && s.namespace == namespace)
+        .map(|s| {
+          (
+            CurrentView::Services,
+            Selection::Service {
+              name: s.name.clone(),
+              namespace: s.namespace.clone(),
+            },
+          )
+        }),
+      (EventSource::Kubernetes, kind) => WorkloadKind::from_label(kind).and_then(|kind| {
+        state
+          .workloads(kind)
+          .iter()
+          .find(|w| w.name == event.resource_name && w.namespace == namespace)
+          .map(|w| {
+            (
+              CurrentView::for_workload(kind),
+              Selection::Workload {
+                kind,
+                name: w.name.clone(),
+                namespace: w.namespace.clone(),
+              },
+            )
+          })
+      }),
+      _ => None,
+    }
+  }
+
+  fn filtered_events(&self, cx: &App) -> (Vec<TimelineEvent>, usize) {
+    let query = self.search_input.read(cx).text().to_string();
+    let query = query.trim();
+    let namespace = &self.docker_state.read(cx).selected_namespace;
+    let state = self.events_state.read(cx);
+
+    let matching = state
+      .events
+      .iter()
+      .rev()
+      .filter(|e| self.in_scope(e, namespace))
+      .filter(|e| self.type_filter.matches(e.is_warning))
+      .filter(|e| self.reason_filter.as_ref().is_none_or(|r| *r == e.action))
+      .filter(|e| e.matches_query(query));
+
+    let total = state.events.len();
+    (matching.take(MAX_VISIBLE_EVENTS).cloned().collect(), total)
+  }
+
+  fn render_toolbar(&self, shown: usize, total: usize, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+
+    h_flex()
+      .w_full()
+      .px(px(16.))
+      .py(px(8.))
+      .gap(px(8.))
+      .items_center()
+      .border_b_1()
+      .border_color(colors.border)
+      .child(div().w(px(240.)).child(Input::new(&self.search_input).small().w_full()))
+      .children(SourceFilter::ALL.iter().map(|filter| {
+        let filter = *filter;
+        let button = Button::new(SharedString::from(format!("events-source-{}", filter.label())))
+          .label(filter.label())
+          .xsmall()
+          .on_click(cx.listener(move |this, _ev, _window, cx| {
+            this.source_filter = filter;
+            cx.notify();
+          }));
+        if self.source_filter == filter {
+          button.primary()
+        } else {
+          button.ghost()
+        }
+      }))
+      .child(div().w(px(1.)).h(px(16.)).bg(colors.border))
+      .children(TypeFilter::ALL.iter().map(|filter| {
+        let filter = *filter;
+        let button = Button::new(SharedString::from(format!("events-type-{}", filter.label())))
+          .label(filter.label())
+          .xsmall()
+          .on_click(cx.listener(move |this, _ev, _window, cx| {
+            this.type_filter = filter;
+            cx.notify();
+          }));
+        if self.type_filter == filter {
+          button.primary()
+        } else {
+          button.ghost()
+        }
+      }))
+      .child(self.render_reason_selector(cx))
+      .when(self.object_filter.is_none(), |el| {
+        el.child(self.render_namespace_selector(cx))
+      })
+      .when_some(self.object_filter.clone(), |el, object| {
+        el.child(
+          h_flex()
+            .gap(px(4.))
+            .pl(px(8.))
+            .rounded(px(4.))
+            .bg(colors.secondary)
+            .items_center()
+            .child(
+              div()
+                .text_xs()
+                .text_color(colors.secondary_foreground)
+                .child(object.to_string()),
+            )
+            .child(
+              Button::new("events-object-clear")
+                .icon(IconName::Close)
+                .ghost()
+                .xsmall()
+                .on_click(cx.listener(|this, _ev, _window, cx| {
+                  this.object_filter = None;
+                  this.reason_filter = None;
+                  cx.notify();
+                })),
+            ),
+        )
+      })
+      .child(div().flex_1())
+      .child(
+        div()
+          .text_xs()
+          .text_color(colors.muted_foreground)
+          .child(format!("{shown} of {total} events")),
+      )
+      .child(
+        Button::new("events-clear")
+          .icon(Icon::new(AppIcon::Trash))
+          .ghost()
+          .xsmall()
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.events_state.update(cx, |state, cx| {
+              state.clear();
+              cx.emit(EventsChanged::Cleared);
+            });
+          })),
+      )
+  }
+
+  fn render_reason_selector(&self, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let reasons = self.reasons(cx);
+    let view = cx.entity().downgrade();
+    let label = self.reason_filter.clone().unwrap_or_else(|| "All reasons".to_string());
+
+    let button = Button::new("events-reason-selector").label(label).xsmall().compact();
+    let button = if self.reason_filter.is_some() {
+      button.primary()
+    } else {
+      button.ghost()
+    };
+
+    button.dropdown_menu(move |menu, _window, _cx| {
+      let set_reason = |reason: Option<String>| {
+        let view = view.clone();
+        move |_: &gpui::ClickEvent, _: &mut Window, cx: &mut App| {
+          let _ = view.update(cx, |this, cx| {
+            this.reason_filter = reason.clone();
+            cx.notify();
+          });
+        }
+      };
+
+      let mut menu = menu.item(PopupMenuItem::new("All reasons").on_click(set_reason(None)));
+      if !reasons.is_empty() {
+        menu = menu.separator();
+        for reason in &reasons {
+          menu = menu.item(PopupMenuItem::new(reason.clone()).on_click(set_reason(Some(reason.clone()))));
+        }
+      }
+      menu
+    })
+  }
+
+  fn render_namespace_selector(&self, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let state = self.docker_state.read(cx);
+    let selected = state.selected_namespace.clone();
+    let namespaces = state.namespaces.clone();
+
+    let display = if selected == "all" {
+      "All namespaces".to_string()
+    } else {
+      selected
+    };
+
+    Button::new("events-namespace-selector")
+      .label(display)
+      .ghost()
+      .xsmall()
+      .compact()
+      .dropdown_menu(move |menu, _window, _cx| {
+        let mut menu = menu.item(PopupMenuItem::new("All Namespaces").on_click(|_, _, cx| {
+          services::set_namespace("all".to_string(), cx);
+        }));
+
+        if !namespaces.is_empty() {
+          menu = menu.separator();
+          for ns in &namespaces {
+            let ns = ns.clone();
+            menu = menu.item(PopupMenuItem::new(ns.clone()).on_click(move |_, _, cx| {
+              services::set_namespace(ns.clone(), cx);
+            }));
+          }
+        }
+
+        menu
+      })
+  }
+
+  fn render_event(event: &TimelineEvent, index: usize, cx: &App) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let link = Self::resource_link(event, cx);
+    let has_link = link.is_some();
+
+    let source_icon = match event.source {
+      EventSource::Docker => AppIcon::Container,
+      EventSource::Kubernetes => AppIcon::Kubernetes,
+    };
+    let action_color = if event.is_warning {
+      colors.danger
+    } else {
+      colors.foreground
+    };
+    let resource = match &event.namespace {
+      Some(ns) if !ns.is_empty() => format!("{ns}/{}", event.resource_name),
+      _ => event.resource_name.clone(),
+    };
+    let details = event.message.clone().unwrap_or_else(|| event.display_attributes());
+    let object = (event.source == EventSource::Kubernetes && !event.resource_name.is_empty()).then(|| {
+      let namespace = event.namespace.clone().unwrap_or_default();
+      if event.kind == "Deployment" {
+        InvolvedObject::deployment(&event.resource_name, &namespace, &docker_state(cx).read(cx).pods)
+      } else {
+        InvolvedObject::new(event.kind.clone(), event.resource_name.clone(), namespace)
+      }
+    });
+
+    h_flex()
+      .id(SharedString::from(format!("event-{index}")))
+      .w_full()
+      .px(px(16.))
+      .py(px(6.))
+      .gap(px(12.))
+      .items_center()
+      .border_b_1()
+      .border_color(colors.border)
+      .hover(|s| s.bg(colors.list_hover))
+      .when_some(link, |el, (view, selection)| {
+        el.cursor_pointer().on_click(move |_ev, _window, cx| {
+          services::select_resource(view, selection.clone(), cx);
+        })
+      })
+      .child(
+        div()
+          .w(px(120.))
+          .flex_shrink_0()
+          .text_xs()
+          .font_family("monospace")
+          .text_color(colors.muted_foreground)
+          .child(
+            event
+              .timestamp
+              .with_timezone(&Local)
+              .format("%m-%d %H:%M:%S")
+              .to_string(),
+          ),
+      )
+      .child(Icon::new(source_icon).size(px(14.)).text_color(colors.muted_foreground))
+      .child(
+        div()
+          .w(px(90.))
+          .flex_shrink_0()
+          .text_xs()
+          .text_color(colors.muted_foreground)
+          .child(event.kind.clone()),
+      )
+      .child(
+        div()
+          .w(px(140.))
+          .flex_shrink_0()
+          .text_xs()
+          .font_weight(gpui::FontWeight::MEDIUM)
+          .text_color(action_color)
+          .overflow_hidden()
+          .text_ellipsis()
+          .whitespace_nowrap()
+          .child(event.action.clone()),
+      )
+      .child(
+        div()
+          .w(px(200.))
+          .flex_shrink_0()
+          .text_xs()
+          .text_color(if has_link { colors.link } else { colors.foreground })
+          .overflow_hidden()
+          .text_ellipsis()
+          .whitespace_nowrap()
+          .child(resource),
+      )
+      .child(
+        div()
+          .flex_1()
+          .text_xs()
+          .text_color(colors.secondary_foreground)
+          .overflow_hidden()
+          .text_ellipsis()
+          .whitespace_nowrap()
+          .child(details),
+      )
+      .when_some(object, |el, object| {
+        el.child(
+          Button::new(SharedString::from(format!("event-object-{index}")))
+            .icon(IconName::Search)
+            .ghost()
+            .xsmall()
+            .tooltip("Show events for this object")
+            .on_click(move |_ev, _window, cx| {
+              services::open_object_events(object.clone(), cx);
+            }),
+        )
+      })
+  }
+}
+
+impl Render for EventsView {
+  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let (events, total) = self.filtered_events(cx);
+
+    v_flex()
+      .size_full()
+      .bg(colors.background)
+      .child(
+        h_flex()
+          .w_full()
+          .h(px(52.))
+          .px(px(16.))
+          .items_center()
+          .border_b_1()
+          .border_color(colors.border)
+          .child(
+            Label::new("Events")
+              .text_color(colors.foreground)
+              .font_weight(gpui::FontWeight::SEMIBOLD),
+          ),
+      )
+      .child(self.render_toolbar(events.len(), total, cx))
+      .child(
+        div()
+          .id("events-scroll")
+          .flex_1()
+          .min_h_0()
+          .overflow_y_scrollbar()
+          .when(events.is_empty(), |el| {
+            el.child(
+              v_flex()
+                .size_full()
+                .py(px(48.))
+                .items_center()
+                .justify_center()
+                .gap(px(12.))
+                .child(
+                  Icon::new(IconName::Info)
+                    .size(px(32.))
+                    .text_color(colors.muted_foreground),
+                )
+                .child(
+                  div()
+                    .text_sm()
+                    .text_color(colors.muted_foreground)
+                    .child(if total == 0 {
+                      "No events recorded yet"
+                    } else {
+                      "No events match the current filters"
+                    }),
+                ),
+            )
+          })
+          .children(events.iter().enumerate().map(|(i, e)| Self::render_event(e, i, cx))),
+      )
+  }
+}
@@ -20,6 +20,9 @@ use gpui_component::{
 };
 
 use crate::assets::AppIcon;
+use crate::kubernetes::{ConfigKind, WorkloadKind};
+use crate::ui::configs::config_icon;
+use crate::ui::workloads::workload_icon;
 
 use crate::state::{CurrentView, DockerState, Selection, StateChanged, docker_state};
 use crate::ui::components::spinning_loader_circle;
@@ -48,6 +51,11 @@ pub enum SearchResultType {
   Pod,
   Deployment,
   Service,
+  Workload(WorkloadKind),
+  Config(ConfigKind),
+  Ingress,
+  VolumeClaim,
+  Node,
   Machine,
 }
 
@@ -61,6 +69,11 @@ impl SearchResultType {
       SearchResultType::Pod => AppIcon::Pod,
       SearchResultType::Deployment => AppIcon::Deployment,
       SearchResultType::Service => AppIcon::Service,
+      SearchResultType::Workload(kind) => workload_icon(*kind),
+      SearchResultType::Config(kind) => config_icon(*kind),
+      SearchResultType::Ingress => AppIcon::Network,
+      SearchResultType::VolumeClaim => AppIcon::Volume,
+      SearchResultType::Node => AppIcon::Machine,
       SearchResultType::Machine => AppIcon::Machine,
     }
   }
@@ -74,6 +87,11 @@ impl SearchResultType {
       SearchResultType::Pod => "Pod",
       SearchResultType::Deployment => "Deployment",
       SearchResultType::Service => "Service",
+      SearchResultType::Workload(kind) => kind.label(),
+      SearchResultType::Config(kind) => kind.label(),
+      SearchResultType::Ingress => "Ingress",
+      SearchResultType::VolumeClaim => "PersistentVolumeClaim",
+      SearchResultType::Node => "Node",
       SearchResultType::Machine => "Machine",
     }
   }
@@ -87,6 +105,11 @@ impl SearchResultType {
       SearchResultType::Pod => CurrentView::Pods,
       SearchResultType::Deployment => CurrentView::Deployments,
       SearchResultType::Service => CurrentView::Services,
+      SearchResultType::Workload(kind) => CurrentView::for_workload(*kind),
+      SearchResultType::Config(kind) => CurrentView::for_config(*kind),
+      SearchResultType::Ingress => CurrentView::Ingresses,
+      SearchResultType::VolumeClaim => CurrentView::Storage,
+      SearchResultType::Node => CurrentView::Nodes,
       SearchResultType::Machine => CurrentView::Machines,
     }
   }
@@ -142,6 +165,11 @@ impl GlobalSearch {
         | StateChanged::PodsUpdated
         | StateChanged::DeploymentsUpdated
         | StateChanged::ServicesUpdated
+        | StateChanged::WorkloadsUpdated { .. }
+        | StateChanged::ConfigsUpdated { .. }
+        | StateChanged::IngressesUpdated
+        | StateChanged::StorageUpdated
+        | StateChanged::NodesUpdated
         | StateChanged::MachinesUpdated => {
           this.is_loading = false;
           this.results = this.search_resources(&this.query, cx);
@@ -196,6 +224,11 @@ impl GlobalSearch {
     crate::services::refresh_pods(cx);
     crate::services::refresh_deployments(cx);
     crate::services::refresh_services(cx);
+    crate::services::refresh_all_workloads(cx);
+    crate::services::refresh_all_configs(cx);
+    crate::services::refresh_ingresses(cx);
+    crate::services::refresh_storage(cx);
+    crate::services::refresh_nodes(cx);
   }
 
   fn search_resources(&self, query: &str, cx: &Context<'_, Self>) -> Vec<SearchResult> {
@@ -313,6 +346,100 @@ impl GlobalSearch {
       }
     }
 
+    // Search StatefulSets, DaemonSets, Jobs and CronJobs
+    for kind in WorkloadKind::ALL {
+      for workload in state.workloads(kind) {
+        if query.is_empty()
+          || workload.name.to_lowercase().contains(&query_lower)
+          || workload.namespace.to_lowercase().contains(&query_lower)
+        {
+          results.push(SearchResult {
+            result_type: SearchResultType::Workload(kind),
+            name: workload.name.clone(),
+            subtitle: format!("{} - {}", workload.namespace, workload.status_display()),
+            selection: Selection::Workload {
+              kind,
+              name: workload.name.clone(),
+              namespace: workload.namespace.clone(),
+            },
+          });
+        }
+      }
+    }
+
+    // Search ConfigMaps and Secrets
+    for kind in ConfigKind::ALL {
+      for config in state.configs(kind) {
+        if query.is_empty()
+          || config.name.to_lowercase().contains(&query_lower)
+          || config.namespace.to_lowercase().contains(&query_lower)
+        {
+          results.push(SearchResult {
+            result_type: SearchResultType::Config(kind),
+            name: config.name.clone(),
+            subtitle: format!("{} - {} key(s)", config.namespace, config.entries.len()),
+            selection: Selection::Config {
+              kind,
+              name: config.name.clone(),
+              namespace: config.namespace.clone(),
+            },
+          });
+        }
+      }
+    }
+
+    // Search ingresses, including their hosts
+    for ingress in &state.ingresses {
+      if query.is_empty()
+        || ingress.name.to_lowercase().contains(&query_lower)
+        || ingress.namespace.to_lowercase().contains(&query_lower)
+        || ingress.hosts_display().to_lowercase().contains(&query_lower)
+      {
+        results.push(SearchResult {
+          result_type: SearchResultType::Ingress,
+          name: ingress.name.clone(),
+          subtitle: format!("{} - {}", ingress.namespace, ingress.hosts_display()),
+          selection: Selection::Ingress {
+            name: ingress.name.clone(),
+            namespace: ingress.namespace.clone(),
+          },
+        });
+      }
+    }
+
+    // Search PersistentVolumeClaims
+    for claim in &state.volume_claims {
+      if query.is_empty()
+        || claim.name.to_lowercase().contains(&query_lower)
+        || claim.namespace.to_lowercase().contains(&query_lower)
+      {
+        results.push(SearchResult {
+          result_type: SearchResultType::VolumeClaim,
+          name: claim.name.clone(),
+          subtitle: format!("{} - {} - {}", claim.namespace, claim.phase, claim.size_display()),
+          selection: Selection::VolumeClaim {
+            name: claim.name.clone(),
+            namespace: claim.namespace.clone(),
+          },
+        });
+      }
+    }
+
+    // Search nodes
+    for node in &state.nodes {
+      if query.is_empty()
+        || node.name.to_lowercase().contains(&query_lower)
+        || node.internal_ip.as_ref().is_some_and(|ip| ip.contains(&query_lower))
+      {
+        results.push(SearchResult {
+          result_type: SearchResultType::Node,
+          name: node.name.clone(),
+          subtitle: format!("{} - {}", node.status_display(), node.roles_display()),
+          selection: Selection::Node(node.name.clone()),
+        });
+      }
+    }
+
     // Search machines
     for machine in &state.colima_vms {
       if query.is_empty() || machine.name.to_lowercase().contains(&query_lower) {
@@ -771,6 +898,10 @@ mod tests {
     assert_eq!(SearchResultType::Pod.label(), "Pod");
     assert_eq!(SearchResultType::Deployment.label(), "Deployment");
     assert_eq!(SearchResultType::Service.label(), "Service");
+    assert_eq!(SearchResultType::Workload(WorkloadKind::CronJob).label(), "CronJob");
+    assert_eq!(SearchResultType::Config(ConfigKind::Secret).label(), "Secret");
+    assert_eq!(SearchResultType::Ingress.label(), "Ingress");
+    assert_eq!(SearchResultType::Node.label(), "Node");
     assert_eq!(SearchResultType::Machine.label(), "Machine");
   }
 
@@ -783,6 +914,17 @@ mod tests {
     assert_eq!(SearchResultType::Pod.view(), CurrentView::Pods);
     assert_eq!(SearchResultType::Deployment.view(), CurrentView::Deployments);
     assert_eq!(SearchResultType::Service.view(), CurrentView::Services);
+    assert_eq!(
+      SearchResultType::Workload(WorkloadKind::StatefulSet).view(),
+      CurrentView::StatefulSets
+    );
+    assert_eq!(
+      SearchResultType::Config(ConfigKind::ConfigMap).view(),
+      CurrentView::ConfigMaps
+    );
+    assert_eq!(SearchResultType::Ingress.view(), CurrentView::Ingresses);
+    assert_eq!(SearchResultType::VolumeClaim.view(), CurrentView::Storage);
+    assert_eq!(SearchResultType::Node.view(), CurrentView::Nodes);
     assert_eq!(SearchResultType::Machine.view(), CurrentView::Machines);
   }
 
@@ -796,6 +938,11 @@ mod tests {
     let _ = SearchResultType::Pod.icon();
     let _ = SearchResultType::Deployment.icon();
     let _ = SearchResultType::Service.icon();
+    let _ = SearchResultType::Workload(WorkloadKind::Job).icon();
+    let _ = SearchResultType::Config(ConfigKind::Secret).icon();
+    let _ = SearchResultType::Ingress.icon();
+    let _ = SearchResultType::VolumeClaim.icon();
+    let _ = SearchResultType::Node.icon();
     let _ = SearchResultType::Machine.icon();
   }
 
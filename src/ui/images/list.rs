@@ -11,9 +11,9 @@ use gpui_component::{
 };
 
 use crate::assets::AppIcon;
-use crate::docker::ImageInfo;
+use crate::docker::{ImageInfo, PruneKind, has_protect_label};
 use crate::services;
-use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state};
+use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state, settings_state};
 use crate::ui::components::{render_error, render_loading};
 
 /// Image list events emitted to parent
@@ -169,6 +169,25 @@ impl ListDelegate for ImageListDelegate {
       }
     });
 
+    let pinned = settings_state(cx)
+      .read(cx)
+      .settings
+      .is_pinned(PruneKind::Images, &image.id);
+    let labelled = has_protect_label(&image.labels);
+
+    // Label protection can only be lifted by changing the label
+    let protect_button = (!labelled || pinned).then(|| {
+      let name = image.id.clone();
+      let button = Button::new(SharedString::from(format!("protect-{}-{}", ix.section, ix.row)))
+        .icon(Icon::new(AppIcon::Shield))
+        .xsmall()
+        .tooltip(if pinned { "Unprotect" } else { "Protect" })
+        .on_click(move |_ev, _window, cx| {
+          services::set_pinned(PruneKind::Images, name.clone(), !pinned, cx);
+        });
+      if pinned { button.primary() } else { button.ghost() }
+    });
+
     let item_content = h_flex()
       .w_full()
       .items_center()
@@ -217,7 +236,15 @@ impl ListDelegate for ImageListDelegate {
             .text_color(colors.background)
             .child(plat),
         )
-      });
+      })
+      .when(labelled && !pinned, |el| {
+        el.child(
+          Icon::new(AppIcon::Shield)
+            .size(px(12.))
+            .text_color(colors.muted_foreground),
+        )
+      })
+      .children(protect_button);
 
     let id = image_id.clone();
     let row = ix.row;
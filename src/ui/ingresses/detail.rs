@@ -0,0 +1,491 @@
+use gpui::{Context, Entity, Render, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  Icon, IconName, Selectable, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  menu::DropdownMenu,
+  scroll::ScrollableElement,
+  tab::{Tab, TabBar},
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::assets::AppIcon;
+use crate::kubernetes::{IngressInfo, IngressRoute};
+use crate::services;
+use crate::state::{DockerState, IngressDetailTab, StateChanged, docker_state};
+
+use super::list::ingress_menu;
+
+/// Detail view for an ingress with tabs
+pub struct IngressDetail {
+  docker_state: Entity<DockerState>,
+  ingress: Option<IngressInfo>,
+  active_tab: IngressDetailTab,
+  yaml_content: String,
+  yaml_editor: Option<Entity<InputState>>,
+  last_synced_yaml: String,
+}
+
+impl IngressDetail {
+  pub fn new(cx: &mut Context<'_, Self>) -> Self {
+    let docker_state = docker_state(cx);
+
+    cx.subscribe(&docker_state, |this, ds, event: &StateChanged, cx| match event {
+      StateChanged::IngressYamlLoaded { name, namespace, yaml } => {
+        if let Some(ref i) = this.ingress
+          && i.name == *name
+          && i.namespace == *namespace
+        {
+          yaml.clone_into(&mut this.yaml_content);
+          cx.notify();
+        }
+      }
+      StateChanged::IngressTabRequest { name, namespace, tab } => {
+        let ingress = ds.read(cx).get_ingress(name, namespace).cloned();
+        if let Some(ingress) = ingress {
+          this.ingress = Some(ingress);
+          this.active_tab = *tab;
+          this.yaml_content.clear();
+          this.last_synced_yaml.clear();
+          cx.notify();
+        }
+      }
+      // Backend links depend on which services exist
+      StateChanged::ServicesUpdated => cx.notify(),
+      _ => {}
+    })
+    .detach();
+
+    Self {
+      docker_state,
+      ingress: None,
+      active_tab: IngressDetailTab::Rules,
+      yaml_content: String::new(),
+      yaml_editor: None,
+      last_synced_yaml: String::new(),
+    }
+  }
+
+  pub fn ingress(&self) -> Option<&IngressInfo> {
+    self.ingress.as_ref()
+  }
+
+  pub fn set_ingress(&mut self, ingress: IngressInfo, cx: &mut Context<'_, Self>) {
+    services::get_ingress_yaml(ingress.name.clone(), ingress.namespace.clone(), cx);
+
+    self.ingress = Some(ingress);
+    self.active_tab = IngressDetailTab::Rules;
+    self.yaml_content.clear();
+    self.yaml_editor = None;
+    self.last_synced_yaml.clear();
+    cx.notify();
+  }
+
+  /// Update ingress data without resetting tab state (for data refresh)
+  pub fn update_ingress_data(&mut self, ingress: IngressInfo, cx: &mut Context<'_, Self>) {
+    self.ingress = Some(ingress);
+    cx.notify();
+  }
+
+  fn header_cell(label: &'static str, width: Option<f32>, cx: &Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    let cell = match width {
+      Some(w) => div().w(px(w)).flex_shrink_0(),
+      None => div().flex_1().min_w_0(),
+    };
+    cell
+      .text_xs()
+      .font_weight(gpui::FontWeight::SEMIBOLD)
+      .text_color(colors.muted_foreground)
+      .child(label)
+  }
+
+  fn render_info_row(label: &'static str, value: String, cx: &Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    h_flex()
+      .w_full()
+      .py(px(6.))
+      .gap(px(12.))
+      .child(
+        div()
+          .w(px(120.))
+          .flex_shrink_0()
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .child(label),
+      )
+      .child(
+        div()
+          .flex_1()
+          .min_w_0()
+          .text_sm()
+          .text_color(colors.foreground)
+          .child(value),
+      )
+  }
+
+  /// Backend cell; services that exist link to the services view
+  fn render_backend(
+    &self,
+    i: usize,
+    route: &IngressRoute,
+    namespace: &str,
+    cx: &Context<'_, Self>,
+  ) -> gpui::AnyElement {
+    let colors = cx.theme().colors;
+    let exists = route
+      .service
+      .as_ref()
+      .is_some_and(|svc| self.docker_state.read(cx).get_service(svc, namespace).is_some());
+
+    match &route.service {
+      Some(service) if exists => {
+        let (service, namespace) = (service.clone(), namespace.to_string());
+        Button::new(("backend", i))
+          .label(route.backend.clone())
+          .icon(Icon::new(AppIcon::Service))
+          .ghost()
+          .xsmall()
+          .tooltip("Open service")
+          .on_click(move |_ev, _window, cx| {
+            services::open_service(service.clone(), namespace.clone(), cx);
+          })
+          .into_any_element()
+      }
+      Some(_) => h_flex()
+        .gap(px(6.))
+        .items_center()
+        .child(
+          div()
+            .text_sm()
+            .font_family("monospace")
+            .text_color(colors.foreground)
+            .child(route.backend.clone()),
+        )
+        .child(
+          div()
+            .px(px(6.))
+            .py(px(2.))
+            .rounded(px(4.))
+            .bg(colors.warning.opacity(0.15))
+            .text_xs()
+            .text_color(colors.warning)
+            .child("missing"),
+        )
+        .into_any_element(),
+      None => div()
+        .text_sm()
+        .font_family("monospace")
+        .text_color(colors.foreground)
+        .child(route.backend.clone())
+        .into_any_element(),
+    }
+  }
+
+  fn render_rules_tab(&self, ingress: &IngressInfo, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = cx.theme().colors;
+
+    let addresses = if ingress.addresses.is_empty() {
+      "Pending".to_string()
+    } else {
+      ingress.addresses.join(", ")
+    };
+    let tls = if ingress.tls_hosts.is_empty() {
+      "-".to_string()
+    } else {
+      ingress.tls_hosts.join(", ")
+    };
+
+    let info = v_flex()
+      .w_full()
+      .child(Self::render_info_row(
+        "Class",
+        ingress.class.clone().unwrap_or_else(|| "-".to_string()),
+        cx,
+      ))
+      .child(Self::render_info_row("Addresses", addresses, cx))
+      .child(Self::render_info_row("TLS Hosts", tls, cx))
+      .when_some(ingress.default_backend.clone(), |el, backend| {
+        el.child(Self::render_info_row("Default Backend", backend, cx))
+      })
+      .child(Self::render_info_row("Age", ingress.age.clone(), cx));
+
+    let header = h_flex()
+      .w_full()
+      .py(px(8.))
+      .px(px(12.))
+      .gap(px(8.))
+      .bg(colors.sidebar)
+      .rounded_t(px(8.))
+      .child(Self::header_cell("Host", Some(180.), cx))
+      .child(Self::header_cell("Path", None, cx))
+      .child(Self::header_cell("Backend", Some(180.), cx))
+      .child(div().w(px(40.)).flex_shrink_0());
+
+    let rows = ingress
+      .routes
+      .iter()
+      .enumerate()
+      .map(|(i, route)| {
+        let url = route.url(&ingress.addresses);
+
+        h_flex()
+          .w_full()
+          .py(px(6.))
+          .px(px(12.))
+          .gap(px(8.))
+          .items_center()
+          .rounded(px(6.))
+          .when(i % 2 == 1, |el| el.bg(colors.sidebar.opacity(0.3)))
+          .hover(|el| el.bg(colors.sidebar))
+          .child(
+            h_flex()
+              .w(px(180.))
+              .flex_shrink_0()
+              .gap(px(4.))
+              .items_center()
+              .when(route.tls, |el| {
+                el.child(Icon::new(AppIcon::Shield).size(px(12.)).text_color(colors.success))
+              })
+              .child(
+                div()
+                  .text_sm()
+                  .text_color(colors.foreground)
+                  .text_ellipsis()
+                  .overflow_hidden()
+                  .whitespace_nowrap()
+                  .child(route.host.clone().unwrap_or_else(|| "*".to_string())),
+              ),
+          )
+          .child(
+            div()
+              .flex_1()
+              .min_w_0()
+              .text_sm()
+              .font_family("monospace")
+              .text_color(colors.foreground)
+              .text_ellipsis()
+              .overflow_hidden()
+              .whitespace_nowrap()
+              .child(format!("{} ({})", route.path, route.path_type)),
+          )
+          .child(
+            div()
+              .w(px(180.))
+              .flex_shrink_0()
+              .overflow_hidden()
+              .child(self.render_backend(i, route, &ingress.namespace, cx)),
+          )
+          .child(
+            div()
+              .w(px(40.))
+              .flex_shrink_0()
+              .flex()
+              .justify_end()
+              .when_some(url, |el, url| {
+                el.child(
+                  Button::new(("open-url", i))
+                    .icon(IconName::ExternalLink)
+                    .ghost()
+                    .xsmall()
+                    .tooltip(url.clone())
+                    .on_click(move |_ev, _window, cx| {
+                      cx.open_url(&url);
+                    }),
+                )
+              }),
+          )
+      })
+      .collect::<Vec<_>>();
+
+    let rules = if rows.is_empty() {
+      div()
+        .w_full()
+        .py(px(16.))
+        .text_sm()
+        .text_color(colors.muted_foreground)
+        .child("No rules; all traffic goes to the default backend")
+    } else {
+      div().w_full().child(v_flex().w_full().child(header).children(rows))
+    };
+
+    div().size_full().overflow_y_scrollbar().p(px(16.)).child(
+      v_flex()
+        .w_full()
+        .gap(px(16.))
+        .child(info)
+        .child(
+          div()
+            .text_sm()
+            .font_weight(gpui::FontWeight::SEMIBOLD)
+            .text_color(colors.foreground)
+            .child("Rules"),
+        )
+        .child(rules),
+    )
+  }
+
+  fn render_yaml_tab(&self, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    if self.yaml_content.is_empty() {
+      return v_flex().size_full().p(px(16.)).child(
+        div()
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .child("Loading YAML..."),
+      );
+    }
+
+    if let Some(ref editor) = self.yaml_editor {
+      return div()
+        .size_full()
+        .child(Input::new(editor).size_full().appearance(false).disabled(true));
+    }
+
+    // Fallback to plain text
+    div().size_full().child(
+      div()
+        .size_full()
+        .overflow_y_scrollbar()
+        .bg(colors.sidebar)
+        .p(px(12.))
+        .font_family("monospace")
+        .text_xs()
+        .text_color(colors.foreground)
+        .child(self.yaml_content.clone()),
+    )
+  }
+
+  fn render_empty(cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    div().size_full().flex().items_center().justify_center().child(
+      v_flex()
+        .items_center()
+        .gap(px(16.))
+        .child(
+          div()
+            .size(px(64.))
+            .rounded(px(12.))
+            .bg(colors.sidebar)
+            .flex()
+            .items_center()
+            .justify_center()
+            .child(
+              Icon::new(AppIcon::Network)
+                .size(px(48.))
+                .text_color(colors.muted_foreground),
+            ),
+        )
+        .child(
+          div()
+            .text_lg()
+            .font_weight(gpui::FontWeight::SEMIBOLD)
+            .text_color(colors.secondary_foreground)
+            .child("Select an Ingress"),
+        )
+        .child(
+          div()
+            .text_sm()
+            .text_color(colors.muted_foreground)
+            .child("Click on an ingress to view its rules"),
+        ),
+    )
+  }
+}
+
+impl Render for IngressDetail {
+  fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    // Create yaml editor if needed
+    if self.yaml_editor.is_none() && self.ingress.is_some() {
+      self.yaml_editor = Some(cx.new(|cx| {
+        InputState::new(window, cx)
+          .multi_line(true)
+          .code_editor("yaml")
+          .line_number(true)
+          .searchable(true)
+          .soft_wrap(false)
+      }));
+    }
+
+    // Sync yaml editor content
+    if let Some(ref editor) = self.yaml_editor
+      && !self.yaml_content.is_empty()
+      && self.last_synced_yaml != self.yaml_content
+    {
+      let yaml_clone = self.yaml_content.clone();
+      editor.update(cx, |state, cx| {
+        state.replace(&yaml_clone, window, cx);
+      });
+      self.last_synced_yaml = self.yaml_content.clone();
+    }
+
+    let colors = cx.theme().colors;
+
+    let Some(ingress) = self.ingress.clone() else {
+      return div().size_full().child(Self::render_empty(cx));
+    };
+
+    let active_tab = self.active_tab;
+
+    let tab_bar = TabBar::new("ingress-tabs")
+      .flex_1()
+      .py(px(0.))
+      .child(
+        Tab::new()
+          .label("Rules")
+          .selected(active_tab == IngressDetailTab::Rules)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = IngressDetailTab::Rules;
+            cx.notify();
+          })),
+      )
+      .child(
+        Tab::new()
+          .label("YAML")
+          .selected(active_tab == IngressDetailTab::Yaml)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = IngressDetailTab::Yaml;
+            if let Some(ref i) = this.ingress {
+              services::get_ingress_yaml(i.name.clone(), i.namespace.clone(), cx);
+            }
+          })),
+      );
+
+    let menu_ingress = ingress.clone();
+    let actions = Button::new("ingress-actions")
+      .icon(IconName::Ellipsis)
+      .ghost()
+      .small()
+      .dropdown_menu(move |menu, _window, _cx| ingress_menu(menu, &menu_ingress));
+
+    let content = match active_tab {
+      IngressDetailTab::Rules => self.render_rules_tab(&ingress, cx),
+      IngressDetailTab::Yaml => self.render_yaml_tab(cx),
+    };
+
+    div()
+      .size_full()
+      .flex()
+      .flex_col()
+      .overflow_hidden()
+      .child(
+        h_flex()
+          .w_full()
+          .px(px(16.))
+          .py(px(8.))
+          .gap(px(12.))
+          .items_center()
+          .border_b_1()
+          .border_color(colors.border)
+          .flex_shrink_0()
+          .child(tab_bar)
+          .child(actions),
+      )
+      .child(div().flex_1().min_h_0().overflow_hidden().child(content))
+  }
+}
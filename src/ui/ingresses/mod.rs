@@ -0,0 +1,5 @@
+mod detail;
+mod list;
+mod view;
+
+pub use view::IngressesView;
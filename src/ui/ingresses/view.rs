@@ -0,0 +1,158 @@
+use std::time::Duration;
+
+use gpui::{App, Context, Entity, Render, Styled, Timer, Window, div, prelude::*, px};
+use gpui_component::theme::ActiveTheme;
+
+use super::detail::IngressDetail;
+use super::list::{IngressList, IngressListEvent};
+use crate::kubernetes::IngressInfo;
+use crate::services;
+use crate::state::{DockerState, Selection, StateChanged, docker_state, settings_state};
+
+/// Ingresses view with list and detail panels
+pub struct IngressesView {
+  docker_state: Entity<DockerState>,
+  list: Entity<IngressList>,
+  detail: Entity<IngressDetail>,
+}
+
+impl IngressesView {
+  /// Get the currently selected ingress from global state
+  fn selected_ingress(&self, cx: &App) -> Option<IngressInfo> {
+    let state = self.docker_state.read(cx);
+    if let Selection::Ingress { name, namespace } = &state.selection {
+      state.get_ingress(name, namespace).cloned()
+    } else {
+      None
+    }
+  }
+
+  fn select(&mut self, ingress: &IngressInfo, cx: &mut Context<'_, Self>) {
+    self.docker_state.update(cx, |state, _cx| {
+      state.set_selection(Selection::Ingress {
+        name: ingress.name.clone(),
+        namespace: ingress.namespace.clone(),
+      });
+    });
+  }
+
+  pub fn new(window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
+    let docker_state = docker_state(cx);
+
+    let list = cx.new(|cx| IngressList::new(window, cx));
+    let detail = cx.new(IngressDetail::new);
+
+    cx.subscribe(&list, |this, _list, event: &IngressListEvent, cx| match event {
+      IngressListEvent::Selected(ingress) => {
+        this.detail.update(cx, |detail, cx| {
+          detail.set_ingress(ingress.clone(), cx);
+        });
+        this.select(ingress, cx);
+        cx.notify();
+      }
+    })
+    .detach();
+
+    cx.subscribe(&docker_state, |this, ds, event: &StateChanged, cx| match event {
+      StateChanged::IngressTabRequest { name, namespace, .. } => {
+        let ingress = ds.read(cx).get_ingress(name, namespace).cloned();
+        if let Some(ingress) = ingress {
+          this.select(&ingress, cx);
+          cx.notify();
+        }
+      }
+      StateChanged::SelectionChanged => {
+        // Selections made elsewhere (e.g. global search) still need the detail loaded
+        if let Some(ingress) = this.selected_ingress(cx) {
+          let shown = this
+            .detail
+            .read(cx)
+            .ingress()
+            .map(|i| (i.name.clone(), i.namespace.clone()));
+          if shown != Some((ingress.name.clone(), ingress.namespace.clone())) {
+            this.detail.update(cx, |detail, cx| {
+              detail.set_ingress(ingress, cx);
+            });
+          }
+        }
+        cx.notify();
+      }
+      StateChanged::IngressesUpdated => {
+        let selected_key = match &this.docker_state.read(cx).selection {
+          Selection::Ingress { name, namespace } => Some((name.clone(), namespace.clone())),
+          _ => None,
+        };
+
+        if let Some((name, namespace)) = selected_key {
+          let ingress = ds.read(cx).get_ingress(&name, &namespace).cloned();
+          if let Some(ingress) = ingress {
+            // Preserve tab state during refresh
+            this.detail.update(cx, |detail, cx| {
+              detail.update_ingress_data(ingress, cx);
+            });
+          } else {
+            // Ingress was deleted
+            this.docker_state.update(cx, |s, _| {
+              s.set_selection(Selection::None);
+            });
+          }
+        }
+        cx.notify();
+      }
+      StateChanged::NamespacesUpdated => {
+        services::refresh_ingresses(cx);
+      }
+      _ => {}
+    })
+    .detach();
+
+    // Start periodic refresh
+    let refresh_interval = settings_state(cx).read(cx).settings.container_refresh_interval;
+    cx.spawn(async move |_this, cx| {
+      loop {
+        Timer::after(Duration::from_secs(refresh_interval)).await;
+        let _ = cx.update(|cx| {
+          services::refresh_ingresses(cx);
+        });
+      }
+    })
+    .detach();
+
+    // Trigger initial data load; backends are linked to services
+    services::refresh_ingresses(cx);
+    services::refresh_services(cx);
+
+    Self {
+      docker_state,
+      list,
+      detail,
+    }
+  }
+}
+
+impl Render for IngressesView {
+  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let has_selection = self.selected_ingress(cx).is_some();
+
+    div()
+      .size_full()
+      .flex()
+      .overflow_hidden()
+      .child(
+        // Left: list - fixed width when selected, full width when not
+        div()
+          .when(has_selection, |el| {
+            el.w(px(320.)).border_r_1().border_color(colors.border)
+          })
+          .when(!has_selection, gpui::Styled::flex_1)
+          .h_full()
+          .flex_shrink_0()
+          .overflow_hidden()
+          .child(self.list.clone()),
+      )
+      .when(has_selection, |el| {
+        el.child(div().flex_1().h_full().overflow_hidden().child(self.detail.clone()))
+      })
+  }
+}
@@ -55,7 +55,7 @@ impl ColimaVersionInfo {
 }
 
 use crate::assets::AppIcon;
-use crate::colima::ColimaVm;
+use crate::colima::{ColimaVm, VmDiskUsage};
 use crate::state::{MachineLogType, MachineTabState};
 use crate::terminal::TerminalView;
 use crate::ui::components::{FileExplorer, FileExplorerConfig, FileExplorerState, ProcessView};
@@ -859,12 +859,9 @@ impl MachineDetail {
   fn render_disk_card(disk_usage: Option<&String>, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
-    // Parse disk info from "df -h /" output
-    // Format: Filesystem  Size  Used  Avail  Use%  Mounted on
-    let (used, total, percent) = if let Some(info) = disk_usage {
-      parse_disk_info(info)
-    } else {
-      ("--".to_string(), "--".to_string(), 0.0)
+    let (used, total, percent) = match disk_usage.and_then(|info| VmDiskUsage::parse(info)) {
+      Some(usage) => (usage.used, usage.size, f64::from(usage.use_percent)),
+      None => ("--".to_string(), "--".to_string(), 0.0),
     };
 
     let bar_color = if percent > 80.0 {
@@ -1379,31 +1376,3 @@ fn parse_memory_value(s: &str) -> f64 {
     _ => value,
   }
 }
-
-/// Parse disk info from "df -h /" output
-/// Returns (used, total, `percent_used`)
-fn parse_disk_info(info: &str) -> (String, String, f64) {
-  // Format:
-  // Filesystem      Size  Used Avail Use% Mounted on
-  // /dev/vda1        59G   10G   46G  19% /
-  for line in info.lines() {
-    let line = line.trim();
-    // Skip header
-    if line.starts_with("Filesystem") {
-      continue;
-    }
-
-    let parts: Vec<&str> = line.split_whitespace().collect();
-    if parts.len() >= 5 {
-      let total = parts[1].to_string();
-      let used = parts[2].to_string();
-      let percent_str = parts[4].trim_end_matches('%');
-
-      let percent: f64 = percent_str.parse().unwrap_or(0.0);
-
-      return (used, total, percent);
-    }
-  }
-
-  ("--".to_string(), "--".to_string(), 0.0)
-}
@@ -2,18 +2,26 @@ pub mod activity;
 pub mod command_palette;
 pub mod components;
 pub mod compose;
+pub mod configs;
 pub mod containers;
+pub mod contexts;
 pub mod deployments;
 pub mod dialogs;
+pub mod disk_usage;
+pub mod events;
 pub mod global_search;
 pub mod images;
+pub mod ingresses;
 pub mod machines;
 pub mod networks;
+pub mod nodes;
 pub mod pods;
 pub mod prune_dialog;
 pub mod services;
 pub mod settings;
 pub mod setup_dialog;
+pub mod storage;
 pub mod volumes;
+pub mod workloads;
 
 pub use prune_dialog::{PruneDialog, PruneOptions};
@@ -0,0 +1,278 @@
+use gpui::{App, Context, Entity, FocusHandle, Focusable, Render, SharedString, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  IndexPath, Sizable, h_flex,
+  input::{Input, InputState},
+  label::Label,
+  select::{Select, SelectItem, SelectState},
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::docker::NetworkConnectOptions;
+use crate::state::docker_state;
+
+/// The side of the connection that is already fixed when the dialog opens
+#[derive(Debug, Clone)]
+pub enum ConnectTarget {
+  /// Pick a container to attach to this network
+  Network { id: String },
+  /// Pick a network to attach this container to
+  Container { id: String },
+}
+
+/// A container or network offered in the select
+#[derive(Debug, Clone)]
+struct ConnectChoice {
+  id: String,
+  label: String,
+}
+
+impl SelectItem for ConnectChoice {
+  type Value = String;
+
+  fn title(&self) -> SharedString {
+    self.label.clone().into()
+  }
+
+  fn value(&self) -> &Self::Value {
+    &self.id
+  }
+}
+
+/// A fully specified connect request
+#[derive(Debug, Clone)]
+pub struct ConnectRequest {
+  pub network: String,
+  pub container: String,
+  pub options: NetworkConnectOptions,
+}
+
+/// Dialog for connecting a container to a network
+pub struct ConnectNetworkDialog {
+  focus_handle: FocusHandle,
+  target: ConnectTarget,
+  choice_select: Option<Entity<SelectState<Vec<ConnectChoice>>>>,
+  aliases_input: Option<Entity<InputState>>,
+  ipv4_input: Option<Entity<InputState>>,
+  ipv6_input: Option<Entity<InputState>>,
+  links_input: Option<Entity<InputState>>,
+}
+
+impl ConnectNetworkDialog {
+  pub fn new(target: ConnectTarget, cx: &mut Context<'_, Self>) -> Self {
+    Self {
+      focus_handle: cx.focus_handle(),
+      target,
+      choice_select: None,
+      aliases_input: None,
+      ipv4_input: None,
+      ipv6_input: None,
+      links_input: None,
+    }
+  }
+
+  /// Containers not yet on the network, or networks the container isn't on yet
+  fn choices(&self, cx: &App) -> Vec<ConnectChoice> {
+    let state = docker_state(cx);
+    let state = state.read(cx);
+    match &self.target {
+      ConnectTarget::Network { id } => {
+        let attached = state.networks.iter().find(|n| n.id == *id).map(|n| &n.containers);
+        state
+          .containers
+          .iter()
+          .filter(|c| attached.is_none_or(|a| !a.contains_key(&c.id)))
+          .map(|c| ConnectChoice {
+            id: c.id.clone(),
+            label: c.name.clone(),
+          })
+          .collect()
+      }
+      ConnectTarget::Container { id } => state
+        .networks
+        .iter()
+        // host and none can't be combined with other networks
+        .filter(|n| !n.containers.contains_key(id) && !matches!(n.name.as_str(), "host" | "none"))
+        .map(|n| ConnectChoice {
+          id: n.id.clone(),
+          label: n.name.clone(),
+        })
+        .collect(),
+    }
+  }
+
+  fn ensure_inputs(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    if self.choice_select.is_none() {
+      let choices = self.choices(cx);
+      let selected = (!choices.is_empty()).then(|| IndexPath::new(0));
+      self.choice_select = Some(cx.new(|cx| SelectState::new(choices, selected, window, cx)));
+    }
+
+    if self.aliases_input.is_none() {
+      self.aliases_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("web, api")));
+    }
+
+    if self.ipv4_input.is_none() {
+      self.ipv4_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("Automatic")));
+    }
+
+    if self.ipv6_input.is_none() {
+      self.ipv6_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("Automatic")));
+    }
+
+    if self.links_input.is_none() {
+      self.links_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("db:database")));
+    }
+  }
+
+  /// The request to send, or `None` when nothing is selected
+  pub fn get_request(&self, cx: &App) -> Option<ConnectRequest> {
+    let choice = self.choice_select.as_ref()?.read(cx).selected_value()?.clone();
+    let text = |input: &Option<Entity<InputState>>| {
+      input
+        .as_ref()
+        .map(|s| s.read(cx).text().to_string())
+        .unwrap_or_default()
+    };
+
+    let options = NetworkConnectOptions {
+      aliases: split_list(&text(&self.aliases_input)),
+      ipv4_address: non_empty(&text(&self.ipv4_input)),
+      ipv6_address: non_empty(&text(&self.ipv6_input)),
+      links: split_list(&text(&self.links_input)),
+    };
+
+    let (network, container) = match &self.target {
+      ConnectTarget::Network { id } => (id.clone(), choice),
+      ConnectTarget::Container { id } => (choice, id.clone()),
+    };
+
+    Some(ConnectRequest {
+      network,
+      container,
+      options,
+    })
+  }
+
+  fn render_form_row(label: &'static str, content: impl IntoElement, cx: &App) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    h_flex()
+      .w_full()
+      .py(px(12.))
+      .px(px(16.))
+      .justify_between()
+      .items_center()
+      .border_b_1()
+      .border_color(colors.border)
+      .child(Label::new(label).text_color(colors.foreground))
+      .child(content)
+  }
+}
+
+/// Split a comma or whitespace separated list, dropping empty entries
+fn split_list(text: &str) -> Vec<String> {
+  text
+    .split(|c: char| c == ',' || c.is_whitespace())
+    .filter(|s| !s.is_empty())
+    .map(ToString::to_string)
+    .collect()
+}
+
+fn non_empty(text: &str) -> Option<String> {
+  let text = text.trim();
+  (!text.is_empty()).then(|| text.to_string())
+}
+
+impl Focusable for ConnectNetworkDialog {
+  fn focus_handle(&self, _cx: &App) -> FocusHandle {
+    self.focus_handle.clone()
+  }
+}
+
+impl Render for ConnectNetworkDialog {
+  fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    self.ensure_inputs(window, cx);
+
+    let colors = &cx.theme().colors;
+    let choice_select = self.choice_select.clone().unwrap();
+    let aliases_input = self.aliases_input.clone().unwrap();
+    let ipv4_input = self.ipv4_input.clone().unwrap();
+    let ipv6_input = self.ipv6_input.clone().unwrap();
+    let links_input = self.links_input.clone().unwrap();
+
+    let (choice_label, empty_text) = match self.target {
+      ConnectTarget::Network { .. } => ("Container", "All containers are already connected"),
+      ConnectTarget::Container { .. } => ("Network", "Already connected to every network"),
+    };
+    let has_choices = choice_select.read(cx).selected_value().is_some();
+
+    v_flex()
+      .w_full()
+      .gap(px(0.))
+      .child(Self::render_form_row(
+        choice_label,
+        div().w(px(220.)).child(Select::new(&choice_select).small()),
+        cx,
+      ))
+      .when(!has_choices, |el| {
+        el.child(
+          div()
+            .px(px(16.))
+            .py(px(8.))
+            .text_xs()
+            .text_color(colors.muted_foreground)
+            .child(empty_text),
+        )
+      })
+      .child(
+        div()
+          .w_full()
+          .px(px(16.))
+          .py(px(12.))
+          .text_sm()
+          .font_weight(gpui::FontWeight::SEMIBOLD)
+          .text_color(colors.foreground)
+          .child("Endpoint"),
+      )
+      .child(Self::render_form_row(
+        "Aliases",
+        div().w(px(220.)).child(Input::new(&aliases_input).small()),
+        cx,
+      ))
+      .child(Self::render_form_row(
+        "IPv4 Address",
+        div().w(px(220.)).child(Input::new(&ipv4_input).small()),
+        cx,
+      ))
+      .child(Self::render_form_row(
+        "IPv6 Address",
+        div().w(px(220.)).child(Input::new(&ipv6_input).small()),
+        cx,
+      ))
+      .child(Self::render_form_row(
+        "Links",
+        div().w(px(220.)).child(Input::new(&links_input).small()),
+        cx,
+      ))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_split_list() {
+    assert!(split_list("").is_empty());
+    assert!(split_list(" , ").is_empty());
+    assert_eq!(split_list("web"), vec!["web"]);
+    assert_eq!(split_list("web, api,db  cache"), vec!["web", "api", "db", "cache"]);
+  }
+
+  #[test]
+  fn test_non_empty() {
+    assert_eq!(non_empty("  "), None);
+    assert_eq!(non_empty(" 172.20.0.5 "), Some("172.20.0.5".to_string()));
+  }
+}
@@ -1,27 +1,86 @@
-use gpui::{App, Context, Entity, FocusHandle, Focusable, Render, Styled, Window, div, prelude::*, px};
+use std::collections::HashMap;
+
+use anyhow::{Result, bail};
+use gpui::{App, Context, Entity, FocusHandle, Focusable, Render, SharedString, Styled, Window, div, prelude::*, px};
 use gpui_component::{
-  Sizable, h_flex,
+  IconName, IndexPath, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
   input::{Input, InputState},
   label::Label,
+  scroll::ScrollableElement,
+  select::{Select, SelectItem, SelectState},
   switch::Switch,
   theme::ActiveTheme,
   v_flex,
 };
 
-/// Options for creating a network
-#[derive(Debug, Clone, Default)]
-pub struct CreateNetworkOptions {
-  pub name: String,
-  pub enable_ipv6: bool,
-  pub subnet: Option<String>,
+use crate::docker::{IpamPool, NetworkCreateOptions};
+
+/// Driver options for network
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NetworkDriver {
+  #[default]
+  Bridge,
+  Macvlan,
+  Ipvlan,
+}
+
+impl NetworkDriver {
+  pub fn label(self) -> &'static str {
+    match self {
+      NetworkDriver::Bridge => "bridge",
+      NetworkDriver::Macvlan => "macvlan",
+      NetworkDriver::Ipvlan => "ipvlan",
+    }
+  }
+
+  pub fn all() -> Vec<NetworkDriver> {
+    vec![NetworkDriver::Bridge, NetworkDriver::Macvlan, NetworkDriver::Ipvlan]
+  }
+
+  /// The driver option set by the driver-specific field
+  fn primary_option(self) -> &'static str {
+    match self {
+      NetworkDriver::Bridge => "com.docker.network.bridge.name",
+      NetworkDriver::Macvlan | NetworkDriver::Ipvlan => "parent",
+    }
+  }
+}
+
+impl SelectItem for NetworkDriver {
+  type Value = NetworkDriver;
+
+  fn title(&self) -> SharedString {
+    self.label().into()
+  }
+
+  fn value(&self) -> &Self::Value {
+    self
+  }
+}
+
+/// Inputs for one IPAM pool row
+struct PoolInputs {
+  subnet: Entity<InputState>,
+  gateway: Entity<InputState>,
+  ip_range: Entity<InputState>,
+  aux_addresses: Entity<InputState>,
 }
 
 /// Dialog for creating a new network
 pub struct CreateNetworkDialog {
   focus_handle: FocusHandle,
   name_input: Option<Entity<InputState>>,
-  subnet_input: Option<Entity<InputState>>,
+  driver_select: Option<Entity<SelectState<Vec<NetworkDriver>>>>,
+  driver_option_input: Option<Entity<InputState>>,
+  extra_options_input: Option<Entity<InputState>>,
+  labels_input: Option<Entity<InputState>>,
+  pools: Vec<PoolInputs>,
   enable_ipv6: bool,
+  internal: bool,
+  attachable: bool,
+  error: Option<String>,
 }
 
 impl CreateNetworkDialog {
@@ -31,45 +90,102 @@ impl CreateNetworkDialog {
     Self {
       focus_handle,
       name_input: None,
-      subnet_input: None,
+      driver_select: None,
+      driver_option_input: None,
+      extra_options_input: None,
+      labels_input: None,
+      pools: Vec::new(),
       enable_ipv6: false,
+      internal: false,
+      attachable: false,
+      error: None,
     }
   }
 
   fn ensure_inputs(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
     if self.name_input.is_none() {
       self.name_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("Name")));
+      // Start with one pool; leaving it blank lets Docker pick a subnet
+      self.add_pool(window, cx);
+    }
+
+    if self.driver_select.is_none() {
+      self.driver_select =
+        Some(cx.new(|cx| SelectState::new(NetworkDriver::all(), Some(IndexPath::new(0)), window, cx)));
     }
 
-    if self.subnet_input.is_none() {
-      self.subnet_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("172.30.30.0/24")));
+    if self.driver_option_input.is_none() {
+      self.driver_option_input = Some(cx.new(|cx| InputState::new(window, cx)));
+    }
+
+    if self.extra_options_input.is_none() {
+      self.extra_options_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("key=value, ...")));
+    }
+
+    if self.labels_input.is_none() {
+      self.labels_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("key=value, ...")));
     }
   }
 
-  pub fn get_options(&self, cx: &App) -> CreateNetworkOptions {
-    let name = self
-      .name_input
-      .as_ref()
-      .map(|s| s.read(cx).text().to_string())
-      .unwrap_or_default();
+  fn add_pool(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    self.pools.push(PoolInputs {
+      subnet: cx.new(|cx| InputState::new(window, cx).placeholder("172.30.30.0/24")),
+      gateway: cx.new(|cx| InputState::new(window, cx).placeholder("Gateway")),
+      ip_range: cx.new(|cx| InputState::new(window, cx).placeholder("IP range")),
+      aux_addresses: cx.new(|cx| InputState::new(window, cx).placeholder("host=address, ...")),
+    });
+  }
 
-    let subnet_text = self
-      .subnet_input
+  fn driver(&self, cx: &App) -> NetworkDriver {
+    self
+      .driver_select
       .as_ref()
-      .map(|s| s.read(cx).text().to_string())
-      .unwrap_or_default();
+      .and_then(|s| s.read(cx).selected_value().copied())
+      .unwrap_or_default()
+  }
 
-    let subnet = if subnet_text.is_empty() {
-      None
-    } else {
-      Some(subnet_text)
-    };
+  /// Show an error under the form, e.g. when validation fails on submit
+  pub fn set_error(&mut self, error: Option<String>) {
+    self.error = error;
+  }
 
-    CreateNetworkOptions {
-      name,
-      enable_ipv6: self.enable_ipv6,
-      subnet,
+  pub fn get_options(&self, cx: &App) -> Result<NetworkCreateOptions> {
+    let text =
+      |input: Option<&Entity<InputState>>| input.map(|s| s.read(cx).text().trim().to_string()).unwrap_or_default();
+
+    let driver = self.driver(cx);
+    let mut driver_options = parse_key_values(&text(self.extra_options_input.as_ref()))?;
+    let primary = text(self.driver_option_input.as_ref());
+    if !primary.is_empty() {
+      driver_options.insert(driver.primary_option().to_string(), primary);
+    }
+
+    let mut pools = Vec::new();
+    for pool in &self.pools {
+      let subnet = text(Some(&pool.subnet));
+      if subnet.is_empty() {
+        continue;
+      }
+      let gateway = text(Some(&pool.gateway));
+      let ip_range = text(Some(&pool.ip_range));
+      pools.push(IpamPool {
+        subnet,
+        gateway: (!gateway.is_empty()).then_some(gateway),
+        ip_range: (!ip_range.is_empty()).then_some(ip_range),
+        aux_addresses: parse_key_values(&text(Some(&pool.aux_addresses)))?,
+      });
     }
+
+    Ok(NetworkCreateOptions {
+      name: text(self.name_input.as_ref()),
+      driver: driver.label().to_string(),
+      driver_options,
+      pools,
+      enable_ipv6: self.enable_ipv6,
+      internal: self.internal,
+      attachable: self.attachable,
+      labels: parse_key_values(&text(self.labels_input.as_ref()))?,
+    })
   }
 
   fn render_form_row(label: &'static str, content: impl IntoElement, cx: &App) -> gpui::Div {
@@ -86,6 +202,76 @@ impl CreateNetworkDialog {
       .child(Label::new(label).text_color(colors.foreground))
       .child(content)
   }
+
+  fn render_section_header(title: &'static str, cx: &App) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    div()
+      .w_full()
+      .px(px(16.))
+      .py(px(12.))
+      .text_sm()
+      .font_weight(gpui::FontWeight::SEMIBOLD)
+      .text_color(colors.foreground)
+      .child(title)
+  }
+
+  fn render_pool(i: usize, pool: &PoolInputs, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = cx.theme().colors;
+
+    v_flex()
+      .w_full()
+      .px(px(16.))
+      .py(px(8.))
+      .gap(px(6.))
+      .border_b_1()
+      .border_color(colors.border)
+      .child(
+        h_flex()
+          .w_full()
+          .gap(px(8.))
+          .items_center()
+          .child(div().flex_1().child(Input::new(&pool.subnet).small()))
+          .child(div().flex_1().child(Input::new(&pool.gateway).small()))
+          .child(
+            Button::new(("remove-pool", i))
+              .icon(IconName::Close)
+              .ghost()
+              .xsmall()
+              .on_click(cx.listener(move |this, _ev, _window, cx| {
+                if i < this.pools.len() {
+                  this.pools.remove(i);
+                  cx.notify();
+                }
+              })),
+          ),
+      )
+      .child(
+        h_flex()
+          .w_full()
+          .gap(px(8.))
+          .child(div().flex_1().child(Input::new(&pool.ip_range).small()))
+          .child(div().flex_1().child(Input::new(&pool.aux_addresses).small()))
+          // Keep columns aligned with the row above
+          .child(div().w(px(24.))),
+      )
+  }
+}
+
+/// Parse a comma or newline separated `key=value` list
+fn parse_key_values(text: &str) -> Result<HashMap<String, String>> {
+  let mut values = HashMap::new();
+  for entry in text.split([',', '\n']).map(str::trim).filter(|e| !e.is_empty()) {
+    let Some((key, value)) = entry.split_once('=') else {
+      bail!("Expected key=value, got \"{entry}\"");
+    };
+    let key = key.trim();
+    if key.is_empty() {
+      bail!("Missing key in \"{entry}\"");
+    }
+    values.insert(key.to_string(), value.trim().to_string());
+  }
+  Ok(values)
 }
 
 impl Focusable for CreateNetworkDialog {
@@ -98,14 +284,33 @@ impl Render for CreateNetworkDialog {
   fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
     self.ensure_inputs(window, cx);
 
-    let colors = &cx.theme().colors;
+    let colors = cx.theme().colors;
     let name_input = self.name_input.clone().unwrap();
-    let subnet_input = self.subnet_input.clone().unwrap();
+    let driver_select = self.driver_select.clone().unwrap();
+    let driver_option_input = self.driver_option_input.clone().unwrap();
+    let extra_options_input = self.extra_options_input.clone().unwrap();
+    let labels_input = self.labels_input.clone().unwrap();
     let enable_ipv6 = self.enable_ipv6;
+    let internal = self.internal;
+    let attachable = self.attachable;
+
+    let driver_option_label = match self.driver(cx) {
+      NetworkDriver::Bridge => "Bridge name",
+      NetworkDriver::Macvlan | NetworkDriver::Ipvlan => "Parent interface",
+    };
+
+    let pools: Vec<gpui::Div> = self
+      .pools
+      .iter()
+      .enumerate()
+      .map(|(i, pool)| Self::render_pool(i, pool, cx))
+      .collect();
 
     v_flex()
             .w_full()
             .gap(px(0.))
+            .max_h(px(520.))
+            .overflow_y_scrollbar()
             // Name input (full width)
             .child(
                 div()
@@ -116,17 +321,44 @@ impl Render for CreateNetworkDialog {
                     .border_color(colors.border)
                     .child(Input::new(&name_input).w_full()),
             )
-            // Advanced section header
+            .child(Self::render_form_row(
+                "Driver",
+                div().w(px(150.)).child(Select::new(&driver_select).small()),
+                cx,
+            ))
+            .child(Self::render_form_row(
+                driver_option_label,
+                div().w(px(200.)).child(Input::new(&driver_option_input).small()),
+                cx,
+            ))
+            .child(Self::render_form_row(
+                "Driver options",
+                div().w(px(200.)).child(Input::new(&extra_options_input).small()),
+                cx,
+            ))
+            // IPAM pools
             .child(
-                div()
+                h_flex()
                     .w_full()
-                    .px(px(16.))
-                    .py(px(12.))
-                    .text_sm()
-                    .font_weight(gpui::FontWeight::SEMIBOLD)
-                    .text_color(colors.foreground)
-                    .child("Advanced"),
+                    .pr(px(16.))
+                    .items_center()
+                    .justify_between()
+                    .child(Self::render_section_header("Address Pools", cx))
+                    .child(
+                        Button::new("add-pool")
+                            .icon(IconName::Plus)
+                            .label("Add")
+                            .ghost()
+                            .xsmall()
+                            .on_click(cx.listener(|this, _ev, window, cx| {
+                                this.add_pool(window, cx);
+                                cx.notify();
+                            })),
+                    ),
             )
+            .children(pools)
+            // Advanced section header
+            .child(Self::render_section_header("Advanced", cx))
             // IPv6 toggle
             .child(
                 Self::render_form_row(
@@ -140,14 +372,46 @@ impl Render for CreateNetworkDialog {
                     cx,
                 ),
             )
-            // Subnet input
             .child(
                 Self::render_form_row(
-                    "Subnet (IPv4)",
-                    div().w(px(150.)).child(Input::new(&subnet_input).small()),
+                    "Internal (no external access)",
+                    Switch::new("internal")
+                        .checked(internal)
+                        .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                            this.internal = *checked;
+                            cx.notify();
+                        })),
+                    cx,
+                ),
+            )
+            .child(
+                Self::render_form_row(
+                    "Attachable",
+                    Switch::new("attachable")
+                        .checked(attachable)
+                        .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                            this.attachable = *checked;
+                            cx.notify();
+                        })),
                     cx,
                 ),
             )
+            .child(Self::render_form_row(
+                "Labels",
+                div().w(px(200.)).child(Input::new(&labels_input).small()),
+                cx,
+            ))
+            .when_some(self.error.clone(), |el, error| {
+                el.child(
+                    div()
+                        .w_full()
+                        .px(px(16.))
+                        .py(px(8.))
+                        .text_sm()
+                        .text_color(colors.danger)
+                        .child(error),
+                )
+            })
   }
 }
 
@@ -156,23 +420,33 @@ mod tests {
   use super::*;
 
   #[test]
-  fn test_create_network_options_default() {
-    let options = CreateNetworkOptions::default();
-    assert!(options.name.is_empty());
-    assert!(!options.enable_ipv6);
-    assert!(options.subnet.is_none());
+  fn test_network_driver_label() {
+    assert_eq!(NetworkDriver::Bridge.label(), "bridge");
+    assert_eq!(NetworkDriver::Macvlan.label(), "macvlan");
+    assert_eq!(NetworkDriver::Ipvlan.label(), "ipvlan");
+    assert_eq!(NetworkDriver::default(), NetworkDriver::Bridge);
+    assert_eq!(NetworkDriver::all().len(), 3);
   }
 
   #[test]
-  fn test_create_network_options_with_values() {
-    let options = CreateNetworkOptions {
-      name: "my-network".to_string(),
-      enable_ipv6: true,
-      subnet: Some("172.30.0.0/16".to_string()),
-    };
-    assert_eq!(options.name, "my-network");
-    assert!(options.enable_ipv6);
-    assert_eq!(options.subnet, Some("172.30.0.0/16".to_string()));
+  fn test_network_driver_primary_option() {
+    assert_eq!(NetworkDriver::Bridge.primary_option(), "com.docker.network.bridge.name");
+    assert_eq!(NetworkDriver::Macvlan.primary_option(), "parent");
+    assert_eq!(NetworkDriver::Ipvlan.primary_option(), "parent");
+  }
+
+  #[test]
+  fn test_parse_key_values() {
+    assert!(parse_key_values("").unwrap().is_empty());
+
+    let values = parse_key_values("env=dev, team = core,\nempty=").unwrap();
+    assert_eq!(values.len(), 3);
+    assert_eq!(values["env"], "dev");
+    assert_eq!(values["team"], "core");
+    assert_eq!(values["empty"], "");
+
+    assert!(parse_key_values("novalue").is_err());
+    assert!(parse_key_values("=value").is_err());
   }
 
   // GPUI Component Tests
@@ -183,8 +457,10 @@ mod tests {
 
     dialog.read_with(cx, |dialog, _| {
       assert!(dialog.name_input.is_none()); // Not initialized until render
-      assert!(dialog.subnet_input.is_none());
+      assert!(dialog.pools.is_empty());
       assert!(!dialog.enable_ipv6);
+      assert!(!dialog.internal);
+      assert!(!dialog.attachable);
     });
   }
 
@@ -223,13 +499,27 @@ mod tests {
     // Set state
     dialog.update(cx, |dialog, _| {
       dialog.enable_ipv6 = true;
+      dialog.attachable = true;
     });
 
     // Read multiple times - state should persist
     for _ in 0..3 {
       dialog.read_with(cx, |dialog, _| {
         assert!(dialog.enable_ipv6);
+        assert!(dialog.attachable);
       });
     }
   }
+
+  #[gpui::test]
+  fn test_create_network_dialog_error(cx: &mut gpui::TestAppContext) {
+    let dialog = cx.new(CreateNetworkDialog::new);
+
+    dialog.update(cx, |dialog, _| {
+      dialog.set_error(Some("Subnet overlaps".to_string()));
+    });
+    dialog.read_with(cx, |dialog, _| {
+      assert_eq!(dialog.error.as_deref(), Some("Subnet overlaps"));
+    });
+  }
 }
@@ -1,6 +1,6 @@
 use gpui::{App, Styled, Window, div, prelude::*, px};
 use gpui_component::{
-  Icon, Selectable, Sizable,
+  Icon, IconName, Selectable, Sizable,
   button::{Button, ButtonVariants},
   h_flex,
   scroll::ScrollableElement,
@@ -15,12 +15,16 @@ use crate::docker::NetworkInfo;
 
 type NetworkActionCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
 type TabChangeCallback = Rc<dyn Fn(&usize, &mut Window, &mut App) + 'static>;
+/// Called with `(network_id, container_id)`
+type DisconnectCallback = Rc<dyn Fn(&(String, String), &mut Window, &mut App) + 'static>;
 
 pub struct NetworkDetail {
   network: Option<NetworkInfo>,
   active_tab: usize,
   on_delete: Option<NetworkActionCallback>,
   on_tab_change: Option<TabChangeCallback>,
+  on_connect: Option<NetworkActionCallback>,
+  on_disconnect: Option<DisconnectCallback>,
 }
 
 impl NetworkDetail {
@@ -30,6 +34,8 @@ impl NetworkDetail {
       active_tab: 0,
       on_delete: None,
       on_tab_change: None,
+      on_connect: None,
+      on_disconnect: None,
     }
   }
 
@@ -59,6 +65,22 @@ impl NetworkDetail {
     self
   }
 
+  pub fn on_connect<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&str, &mut Window, &mut App) + 'static,
+  {
+    self.on_connect = Some(Rc::new(callback));
+    self
+  }
+
+  pub fn on_disconnect<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&(String, String), &mut Window, &mut App) + 'static,
+  {
+    self.on_disconnect = Some(Rc::new(callback));
+    self
+  }
+
   fn render_empty(cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
@@ -85,7 +107,7 @@ impl NetworkDetail {
       )
   }
 
-  fn render_info_tab(network: &NetworkInfo, cx: &App) -> gpui::Div {
+  fn render_info_tab(&self, network: &NetworkInfo, cx: &App) -> gpui::Div {
     let _colors = &cx.theme().colors;
 
     // Basic info rows
@@ -136,9 +158,10 @@ impl NetworkDetail {
       }
     }
 
-    // Connected containers section
-    if !network.containers.is_empty() {
-      content = content.child(Self::render_containers_section(network, cx));
+    // Connected containers section; host and none can't take extra containers
+    let can_connect = !matches!(network.name.as_str(), "host" | "none");
+    if can_connect || !network.containers.is_empty() {
+      content = content.child(self.render_containers_section(network, can_connect, cx));
     }
 
     // Labels section if not empty
@@ -216,18 +239,45 @@ impl NetworkDetail {
     row
   }
 
-  fn render_containers_section(network: &NetworkInfo, cx: &App) -> gpui::Div {
+  fn render_containers_section(&self, network: &NetworkInfo, can_connect: bool, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
+    let mut containers: Vec<_> = network.containers.iter().collect();
+    containers.sort_by(|a, b| a.1.name.cmp(&b.1.name));
+
+    let on_connect = self.on_connect.clone();
+    let on_disconnect = self.on_disconnect.clone();
+    let network_id = network.id.clone();
+
     v_flex()
       .gap(px(1.))
       .child(
-        div()
-          .py(px(8.))
-          .text_sm()
-          .font_weight(gpui::FontWeight::MEDIUM)
-          .text_color(colors.foreground)
-          .child("Connected Containers"),
+        h_flex()
+          .py(px(4.))
+          .items_center()
+          .justify_between()
+          .child(
+            div()
+              .text_sm()
+              .font_weight(gpui::FontWeight::MEDIUM)
+              .text_color(colors.foreground)
+              .child("Connected Containers"),
+          )
+          .when(can_connect, |el| {
+            let id = network_id.clone();
+            el.child(
+              Button::new("connect-container")
+                .icon(Icon::new(AppIcon::Plus))
+                .label("Connect")
+                .ghost()
+                .xsmall()
+                .on_click(move |_ev, window, cx| {
+                  if let Some(ref cb) = on_connect {
+                    cb(&id, window, cx);
+                  }
+                }),
+            )
+          }),
       )
       .child(
         v_flex()
@@ -256,12 +306,15 @@ impl NetworkDetail {
                                     .font_weight(gpui::FontWeight::MEDIUM)
                                     .text_color(colors.muted_foreground)
                                     .child("IPv4 Address"),
-                            ),
+                            )
+                            .child(div().w(px(24.))),
                     )
                     // Container rows
-                    .children(network.containers.iter().enumerate().map(|(i, (id, container))| {
+                    .children(containers.iter().enumerate().map(|(i, (id, container))| {
                         let name = container.name.clone().unwrap_or_else(|| id[..12.min(id.len())].to_string());
                         let ip = container.ipv4_address.clone().unwrap_or_else(|| "-".to_string());
+                        let on_disconnect = on_disconnect.clone();
+                        let ids = (network_id.clone(), (*id).clone());
 
                         let mut row = h_flex()
                             .w_full()
@@ -288,6 +341,18 @@ impl NetworkDetail {
                                     .text_sm()
                                     .text_color(colors.secondary_foreground)
                                     .child(ip),
+                            )
+                            .child(
+                                Button::new(("disconnect", i))
+                                    .icon(IconName::Close)
+                                    .ghost()
+                                    .xsmall()
+                                    .tooltip("Disconnect")
+                                    .on_click(move |_ev, window, cx| {
+                                        if let Some(ref cb) = on_disconnect {
+                                            cb(&ids, window, cx);
+                                        }
+                                    }),
                             );
 
                         if i > 0 {
@@ -512,7 +577,7 @@ impl NetworkDetail {
       });
 
     // Content based on active tab
-    let content = Self::render_info_tab(network, cx);
+    let content = self.render_info_tab(network, cx);
 
     div()
       .size_full()
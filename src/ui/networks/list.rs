@@ -11,15 +11,16 @@ use gpui_component::{
 };
 
 use crate::assets::AppIcon;
-use crate::docker::NetworkInfo;
+use crate::docker::{NetworkInfo, PruneKind, has_protect_label};
 use crate::services;
-use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state};
+use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state, settings_state};
 use crate::ui::components::{render_error, render_loading};
 
 /// Network list events emitted to parent
 pub enum NetworkListEvent {
   Selected(Box<NetworkInfo>),
   CreateNetwork,
+  ShowTopology,
 }
 
 /// Delegate for the network list
@@ -136,6 +137,25 @@ impl ListDelegate for NetworkListDelegate {
     let network_id = network.id.clone();
     let is_system = network.is_system_network();
 
+    let pinned = settings_state(cx)
+      .read(cx)
+      .settings
+      .is_pinned(PruneKind::Networks, &network.name);
+    let labelled = has_protect_label(&network.labels);
+
+    // Label protection can only be lifted by changing the label
+    let protect_button = (!is_system && (!labelled || pinned)).then(|| {
+      let name = network.name.clone();
+      let button = Button::new(SharedString::from(format!("protect-{}-{}", ix.section, ix.row)))
+        .icon(Icon::new(AppIcon::Shield))
+        .xsmall()
+        .tooltip(if pinned { "Unprotect" } else { "Protect" })
+        .on_click(move |_ev, _window, cx| {
+          services::set_pinned(PruneKind::Networks, name.clone(), !pinned, cx);
+        });
+      if pinned { button.primary() } else { button.ghost() }
+    });
+
     // Display info
     let name = network.name.clone();
     let driver = network.driver.clone();
@@ -173,7 +193,15 @@ impl ListDelegate for NetworkListDelegate {
                 el.child(format!("{container_count} containers"))
               }),
           ),
-      );
+      )
+      .when(labelled && !pinned, |el| {
+        el.child(
+          Icon::new(AppIcon::Shield)
+            .size(px(12.))
+            .text_color(colors.muted_foreground),
+        )
+      })
+      .children(protect_button);
 
     let id = network_id.clone();
     let row = ix.row;
@@ -490,6 +518,16 @@ impl Render for NetworkList {
                 this.toggle_search(window, cx);
               })),
           )
+          .child(
+            Button::new("topology")
+              .icon(Icon::new(AppIcon::Network))
+              .ghost()
+              .compact()
+              .tooltip("Topology")
+              .on_click(cx.listener(|_this, _ev, _window, cx| {
+                cx.emit(NetworkListEvent::ShowTopology);
+              })),
+          )
           .child(
             Button::new("create")
               .icon(Icon::new(AppIcon::Plus))
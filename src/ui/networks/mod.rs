@@ -1,6 +1,8 @@
+pub mod connect_dialog;
 pub mod create_dialog;
 mod detail;
 mod list;
+mod topology;
 mod view;
 
 pub use view::NetworksView;
@@ -0,0 +1,307 @@
+use gpui::{App, Hsla, SharedString, Styled, Window, div, prelude::*, px};
+use gpui_component::{Icon, IconName, h_flex, scroll::ScrollableElement, theme::ActiveTheme, v_flex};
+use std::rc::Rc;
+
+use crate::assets::AppIcon;
+use crate::docker::{NetworkTopology, TopologyNode};
+
+type SelectCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
+
+/// Graph of networks as hubs with their containers as nodes
+pub struct NetworkTopologyGraph {
+  topology: NetworkTopology,
+  on_select_network: Option<SelectCallback>,
+  on_select_container: Option<SelectCallback>,
+}
+
+impl NetworkTopologyGraph {
+  pub fn new(topology: NetworkTopology) -> Self {
+    Self {
+      topology,
+      on_select_network: None,
+      on_select_container: None,
+    }
+  }
+
+  pub fn on_select_network<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&str, &mut Window, &mut App) + 'static,
+  {
+    self.on_select_network = Some(Rc::new(callback));
+    self
+  }
+
+  pub fn on_select_container<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&str, &mut Window, &mut App) + 'static,
+  {
+    self.on_select_container = Some(Rc::new(callback));
+    self
+  }
+
+  fn render_hub(
+    &self,
+    id: SharedString,
+    icon: Icon,
+    title: String,
+    subtitle: String,
+    network_id: Option<String>,
+    cx: &App,
+  ) -> impl IntoElement {
+    let colors = &cx.theme().colors;
+    let on_select = self.on_select_network.clone();
+
+    v_flex()
+      .id(id)
+      .w(px(200.))
+      .flex_shrink_0()
+      .p(px(10.))
+      .gap(px(4.))
+      .rounded(px(8.))
+      .border_1()
+      .border_color(colors.primary)
+      .bg(colors.primary.opacity(0.08))
+      .when(network_id.is_some(), |el| el.cursor_pointer())
+      .on_click(move |_ev, window, cx| {
+        if let (Some(cb), Some(id)) = (&on_select, &network_id) {
+          cb(id, window, cx);
+        }
+      })
+      .child(
+        h_flex()
+          .gap(px(6.))
+          .items_center()
+          .child(icon.text_color(colors.primary))
+          .child(
+            div()
+              .text_sm()
+              .font_weight(gpui::FontWeight::SEMIBOLD)
+              .text_color(colors.foreground)
+              .overflow_hidden()
+              .text_ellipsis()
+              .whitespace_nowrap()
+              .child(title),
+          ),
+      )
+      .child(div().text_xs().text_color(colors.muted_foreground).child(subtitle))
+  }
+
+  /// A connector line with a label in the middle, standing in for a graph edge
+  fn render_edge(label: String, detail: Option<String>, color: Hsla, cx: &App) -> impl IntoElement {
+    let colors = &cx.theme().colors;
+    let line = || div().w(px(24.)).h(px(1.)).bg(color);
+
+    h_flex()
+      .items_center()
+      .flex_shrink_0()
+      .child(line())
+      .child(
+        v_flex()
+          .w(px(150.))
+          .px(px(6.))
+          .py(px(2.))
+          .rounded(px(4.))
+          .border_1()
+          .border_color(color.opacity(0.5))
+          .child(
+            div()
+              .text_xs()
+              .font_family("monospace")
+              .text_color(colors.foreground)
+              .overflow_hidden()
+              .text_ellipsis()
+              .whitespace_nowrap()
+              .child(label),
+          )
+          .when_some(detail, |el, detail| {
+            el.child(
+              div()
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .overflow_hidden()
+                .text_ellipsis()
+                .whitespace_nowrap()
+                .child(detail),
+            )
+          }),
+      )
+      .child(line())
+  }
+
+  fn render_node(&self, id: SharedString, node: &TopologyNode, cx: &App) -> impl IntoElement {
+    let colors = &cx.theme().colors;
+    let on_select = self.on_select_container.clone();
+    let container_id = node.container_id.clone();
+    let status_color = if node.running {
+      colors.success
+    } else {
+      colors.muted_foreground
+    };
+
+    h_flex()
+      .id(id)
+      .w(px(220.))
+      .flex_shrink_0()
+      .px(px(10.))
+      .py(px(6.))
+      .gap(px(8.))
+      .items_center()
+      .rounded(px(6.))
+      .border_1()
+      .border_color(colors.border)
+      .bg(colors.background)
+      .cursor_pointer()
+      .hover(|el| el.border_color(colors.primary))
+      .on_click(move |_ev, window, cx| {
+        if let Some(cb) = &on_select {
+          cb(&container_id, window, cx);
+        }
+      })
+      .child(div().size(px(8.)).flex_shrink_0().rounded_full().bg(status_color))
+      .child(Icon::new(AppIcon::Container).text_color(colors.secondary_foreground))
+      .child(
+        div()
+          .flex_1()
+          .min_w_0()
+          .text_sm()
+          .text_color(colors.foreground)
+          .overflow_hidden()
+          .text_ellipsis()
+          .whitespace_nowrap()
+          .child(node.name.clone()),
+      )
+      .when_some(node.project.clone(), |el, project| {
+        el.child(
+          div()
+            .flex_shrink_0()
+            .px(px(6.))
+            .rounded(px(4.))
+            .bg(colors.info.opacity(0.15))
+            .text_xs()
+            .text_color(colors.info)
+            .child(project),
+        )
+      })
+  }
+
+  /// Hub on the left, one edge and node per row on the right
+  fn render_group(hub: impl IntoElement, rows: Vec<gpui::AnyElement>, cx: &App) -> impl IntoElement {
+    let colors = &cx.theme().colors;
+
+    h_flex().items_start().gap(px(0.)).child(hub).child(
+      v_flex()
+        .gap(px(6.))
+        .pl(px(8.))
+        .ml(px(8.))
+        .border_l_1()
+        .border_color(colors.border)
+        .when(rows.is_empty(), |el| {
+          el.child(
+            div()
+              .py(px(6.))
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .child("No containers"),
+          )
+        })
+        .children(rows),
+    )
+  }
+
+  pub fn render(self, _window: &mut Window, cx: &App) -> gpui::AnyElement {
+    let colors = &cx.theme().colors;
+
+    let network_groups: Vec<gpui::AnyElement> = self
+      .topology
+      .hubs
+      .iter()
+      .enumerate()
+      .map(|(h, hub)| {
+        let mut subtitle = hub.driver.clone();
+        if let Some(subnet) = &hub.subnet {
+          subtitle = format!("{subtitle} · {subnet}");
+        }
+        if hub.internal {
+          subtitle.push_str(" · internal");
+        }
+
+        let rows = hub
+          .nodes
+          .iter()
+          .enumerate()
+          .map(|(n, node)| {
+            let label = node.address.clone().unwrap_or_else(|| "no address".to_string());
+            let aliases = (!node.aliases.is_empty()).then(|| node.aliases.join(", "));
+            h_flex()
+              .items_center()
+              .child(Self::render_edge(label, aliases, colors.border, cx))
+              .child(self.render_node(format!("topology-node-{h}-{n}").into(), node, cx))
+              .into_any_element()
+          })
+          .collect();
+
+        let hub_element = self.render_hub(
+          format!("topology-hub-{h}").into(),
+          Icon::new(AppIcon::Network),
+          hub.name.clone(),
+          subtitle,
+          Some(hub.network_id.clone()),
+          cx,
+        );
+        Self::render_group(hub_element, rows, cx).into_any_element()
+      })
+      .collect();
+
+    let published_rows: Vec<gpui::AnyElement> = self
+      .topology
+      .published
+      .iter()
+      .enumerate()
+      .map(|(p, port)| {
+        h_flex()
+          .items_center()
+          .child(Self::render_edge(
+            format!("{} → {}", port.host, port.target),
+            None,
+            colors.warning,
+            cx,
+          ))
+          .child(self.render_node(format!("topology-published-{p}").into(), &port.node, cx))
+          .into_any_element()
+      })
+      .collect();
+
+    let has_published = !published_rows.is_empty();
+    let host_hub = self.render_hub(
+      "topology-host".into(),
+      Icon::new(IconName::Globe),
+      "Host".to_string(),
+      "Published ports".to_string(),
+      None,
+      cx,
+    );
+
+    div()
+      .id("network-topology-scroll")
+      .size_full()
+      .overflow_y_scrollbar()
+      .child(
+        v_flex()
+          .p(px(16.))
+          .gap(px(20.))
+          .when(network_groups.is_empty(), |el| {
+            el.child(
+              div()
+                .text_sm()
+                .text_color(colors.muted_foreground)
+                .child("No networks to show"),
+            )
+          })
+          .children(network_groups)
+          .when(has_published, |el| {
+            el.child(Self::render_group(host_hub, published_rows, cx))
+          }),
+      )
+      .into_any_element()
+  }
+}
@@ -1,12 +1,22 @@
 use gpui::{App, Context, Entity, Render, Styled, Window, div, prelude::*, px};
-use gpui_component::theme::ActiveTheme;
+use gpui_component::{
+  IconName, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  label::Label,
+  theme::ActiveTheme,
+  v_flex,
+};
 
-use crate::docker::NetworkInfo;
-use crate::state::{DockerState, Selection, StateChanged, docker_state};
+use crate::docker::{NetworkInfo, NetworkTopology};
+use crate::services;
+use crate::state::{CurrentView, DockerState, Selection, StateChanged, docker_state};
 use crate::ui::dialogs;
 
+use super::connect_dialog::ConnectTarget;
 use super::detail::NetworkDetail;
 use super::list::{NetworkList, NetworkListEvent};
+use super::topology::NetworkTopologyGraph;
 
 /// Self-contained Networks view - handles list, detail, and all state
 pub struct NetworksView {
@@ -14,6 +24,7 @@ pub struct NetworksView {
   network_list: Entity<NetworkList>,
   // View-specific state (not selection - that's in global DockerState)
   active_tab: usize,
+  show_topology: bool,
 }
 
 impl NetworksView {
@@ -44,12 +55,20 @@ impl NetworksView {
         NetworkListEvent::CreateNetwork => {
           Self::show_create_dialog(window, cx);
         }
+        NetworkListEvent::ShowTopology => {
+          this.set_show_topology(true, cx);
+        }
       },
     )
     .detach();
 
     // Subscribe to state changes
     cx.subscribe(&docker_state, |this, state, event: &StateChanged, cx| {
+      if let StateChanged::ContainersUpdated = event
+        && this.show_topology
+      {
+        cx.notify();
+      }
       if let StateChanged::NetworksUpdated = event {
         // If selected network was deleted, clear selection
         let selected_id = {
@@ -81,7 +100,80 @@ impl NetworksView {
       docker_state,
       network_list,
       active_tab: 0,
+      show_topology: false,
+    }
+  }
+
+  fn set_show_topology(&mut self, show: bool, cx: &mut Context<'_, Self>) {
+    self.show_topology = show;
+    if show {
+      // The graph needs both lists to be current
+      services::refresh_networks(cx);
+      services::refresh_containers(cx);
     }
+    cx.notify();
+  }
+
+  fn render_topology(&self, window: &mut Window, cx: &mut Context<'_, Self>) -> gpui::AnyElement {
+    let colors = cx.theme().colors;
+    let topology = {
+      let state = self.docker_state.read(cx);
+      NetworkTopology::build(&state.networks, &state.containers)
+    };
+
+    let graph = NetworkTopologyGraph::new(topology)
+      .on_select_network(cx.listener(|this, id: &str, _window, cx| {
+        let network = this.docker_state.read(cx).networks.iter().find(|n| n.id == id).cloned();
+        this.show_topology = false;
+        if let Some(network) = network {
+          this.on_select_network(&network, cx);
+        }
+        cx.notify();
+      }))
+      .on_select_container(|id: &str, _window, cx| {
+        let container = docker_state(cx)
+          .read(cx)
+          .containers
+          .iter()
+          .find(|c| c.id == id)
+          .cloned();
+        if let Some(container) = container {
+          services::select_resource(CurrentView::Containers, Selection::Container(container), cx);
+        }
+      });
+
+    v_flex()
+      .size_full()
+      .child(
+        h_flex()
+          .h(px(52.))
+          .w_full()
+          .px(px(16.))
+          .flex_shrink_0()
+          .items_center()
+          .justify_between()
+          .border_b_1()
+          .border_color(colors.border)
+          .child(
+            v_flex().child(Label::new("Network Topology")).child(
+              div()
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .child("Click a network or container to open it"),
+            ),
+          )
+          .child(
+            Button::new("close-topology")
+              .icon(IconName::Close)
+              .ghost()
+              .compact()
+              .on_click(cx.listener(|this, _ev, _window, cx| {
+                this.set_show_topology(false, cx);
+              })),
+          ),
+      )
+      .child(div().flex_1().min_h_0().child(graph.render(window, cx)))
+      .into_any_element()
   }
 
   fn show_create_dialog(window: &mut Window, cx: &mut Context<'_, Self>) {
@@ -108,6 +200,10 @@ impl NetworksView {
 
 impl Render for NetworksView {
   fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    if self.show_topology {
+      return div().size_full().child(self.render_topology(window, cx));
+    }
+
     let colors = cx.theme().colors;
     let selected_network = self.selected_network(cx);
     let active_tab = self.active_tab;
@@ -120,6 +216,12 @@ impl Render for NetworksView {
       .on_tab_change(cx.listener(|this, tab: &usize, _window, cx| {
         this.on_tab_change(*tab, cx);
       }))
+      .on_connect(|id: &str, window, cx| {
+        dialogs::open_connect_network_dialog(ConnectTarget::Network { id: id.to_string() }, window, cx);
+      })
+      .on_disconnect(|(network, container): &(String, String), _window, cx| {
+        services::disconnect_network(network.clone(), container.clone(), cx);
+      })
       .on_delete(cx.listener(|this, _id: &str, _window, cx| {
         this.docker_state.update(cx, |s, _| s.set_selection(Selection::None));
         this.active_tab = 0;
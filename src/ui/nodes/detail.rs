@@ -0,0 +1,690 @@
+use gpui::{Context, Entity, Hsla, Render, Styled, Window, div, prelude::*, px, relative};
+use gpui_component::{
+  Icon, IconName, Selectable, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  menu::DropdownMenu,
+  scroll::ScrollableElement,
+  tab::{Tab, TabBar},
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::assets::AppIcon;
+use crate::kubernetes::{NodeInfo, PodPhase, format_cpu_millis, format_memory};
+use crate::services;
+use crate::state::{NodeDetailTab, StateChanged, docker_state};
+
+use super::list::{node_menu, node_status_color};
+
+/// Share of `total` taken by `used`, in percent
+#[allow(clippy::cast_precision_loss)]
+fn percent(used: u64, total: u64) -> f32 {
+  if total == 0 {
+    0.0
+  } else {
+    used as f32 / total as f32 * 100.0
+  }
+}
+
+/// Detail view for a node with tabs
+pub struct NodeDetail {
+  node: Option<NodeInfo>,
+  active_tab: NodeDetailTab,
+  yaml_content: String,
+  yaml_editor: Option<Entity<InputState>>,
+  last_synced_yaml: String,
+}
+
+impl NodeDetail {
+  pub fn new(cx: &mut Context<'_, Self>) -> Self {
+    let docker_state = docker_state(cx);
+
+    cx.subscribe(&docker_state, |this, ds, event: &StateChanged, cx| match event {
+      StateChanged::NodeYamlLoaded { name, yaml } => {
+        if this.node.as_ref().is_some_and(|n| n.name == *name) {
+          yaml.clone_into(&mut this.yaml_content);
+          cx.notify();
+        }
+      }
+      StateChanged::NodeTabRequest { name, tab } => {
+        let node = ds.read(cx).get_node(name).cloned();
+        if let Some(node) = node {
+          this.node = Some(node);
+          this.active_tab = *tab;
+          this.yaml_content.clear();
+          this.last_synced_yaml.clear();
+          cx.notify();
+        }
+      }
+      _ => {}
+    })
+    .detach();
+
+    Self {
+      node: None,
+      active_tab: NodeDetailTab::Overview,
+      yaml_content: String::new(),
+      yaml_editor: None,
+      last_synced_yaml: String::new(),
+    }
+  }
+
+  pub fn node(&self) -> Option<&NodeInfo> {
+    self.node.as_ref()
+  }
+
+  pub fn set_node(&mut self, node: NodeInfo, cx: &mut Context<'_, Self>) {
+    services::get_node_yaml(node.name.clone(), cx);
+
+    self.node = Some(node);
+    self.active_tab = NodeDetailTab::Overview;
+    self.yaml_content.clear();
+    self.yaml_editor = None;
+    self.last_synced_yaml.clear();
+    cx.notify();
+  }
+
+  /// Update node data without resetting tab state (for data refresh)
+  pub fn update_node_data(&mut self, node: NodeInfo, cx: &mut Context<'_, Self>) {
+    self.node = Some(node);
+    cx.notify();
+  }
+
+  fn section_title(title: &'static str, cx: &Context<'_, Self>) -> gpui::Div {
+    div()
+      .text_sm()
+      .font_weight(gpui::FontWeight::SEMIBOLD)
+      .text_color(cx.theme().colors.foreground)
+      .child(title)
+  }
+
+  fn header_cell(label: &'static str, width: Option<f32>, cx: &Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    let cell = match width {
+      Some(w) => div().w(px(w)).flex_shrink_0(),
+      None => div().flex_1().min_w_0(),
+    };
+    cell
+      .text_xs()
+      .font_weight(gpui::FontWeight::SEMIBOLD)
+      .text_color(colors.muted_foreground)
+      .child(label)
+  }
+
+  fn render_info_row(label: &'static str, value: String, cx: &Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    h_flex()
+      .w_full()
+      .py(px(6.))
+      .gap(px(12.))
+      .child(
+        div()
+          .w(px(140.))
+          .flex_shrink_0()
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .child(label),
+      )
+      .child(
+        div()
+          .flex_1()
+          .min_w_0()
+          .text_sm()
+          .text_color(colors.foreground)
+          .child(value),
+      )
+  }
+
+  fn render_badge(text: String, color: Hsla) -> gpui::Div {
+    div()
+      .px(px(8.))
+      .py(px(2.))
+      .rounded(px(4.))
+      .bg(color.opacity(0.15))
+      .text_xs()
+      .font_family("monospace")
+      .text_color(color)
+      .child(text)
+  }
+
+  /// Capacity, allocatable and what the node's pods request and limit
+  fn render_resource_card(
+    title: &'static str,
+    capacity: String,
+    allocatable: (u64, String),
+    requests: (u64, String),
+    limits: Option<(u64, String)>,
+    cx: &Context<'_, Self>,
+  ) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    let used = percent(requests.0, allocatable.0);
+    let bar_color = if used > 90.0 {
+      colors.danger
+    } else if used > 75.0 {
+      colors.warning
+    } else {
+      colors.primary
+    };
+
+    let row = |label: &'static str, value: String| {
+      h_flex()
+        .w_full()
+        .justify_between()
+        .child(div().text_xs().text_color(colors.muted_foreground).child(label))
+        .child(div().text_xs().text_color(colors.foreground).child(value))
+    };
+
+    v_flex()
+      .flex_1()
+      .min_w(px(180.))
+      .gap(px(8.))
+      .p(px(12.))
+      .rounded(px(8.))
+      .bg(colors.sidebar)
+      .child(
+        h_flex()
+          .justify_between()
+          .child(
+            div()
+              .text_sm()
+              .font_weight(gpui::FontWeight::SEMIBOLD)
+              .text_color(colors.foreground)
+              .child(title),
+          )
+          .child(
+            div()
+              .text_sm()
+              .font_weight(gpui::FontWeight::MEDIUM)
+              .text_color(bar_color)
+              .child(format!("{used:.0}%")),
+          ),
+      )
+      .child(
+        div().w_full().h(px(8.)).bg(colors.background).rounded(px(4.)).child(
+          div()
+            .h_full()
+            .rounded(px(4.))
+            .bg(bar_color)
+            .w(relative(used.min(100.0) / 100.0)),
+        ),
+      )
+      .child(row("Capacity", capacity))
+      .child(row("Allocatable", allocatable.1))
+      .child(row("Requests", requests.1))
+      .when_some(limits, |el, (limit, display)| {
+        el.child(row(
+          "Limits",
+          format!("{display} ({:.0}%)", percent(limit, allocatable.0)),
+        ))
+      })
+  }
+
+  fn render_overview_tab(node: &NodeInfo, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = cx.theme().colors;
+
+    let info = v_flex()
+      .w_full()
+      .child(Self::render_info_row("Status", node.status_display(), cx))
+      .child(Self::render_info_row("Roles", node.roles_display(), cx))
+      .child(Self::render_info_row(
+        "Internal IP",
+        node.internal_ip.clone().unwrap_or_else(|| "-".to_string()),
+        cx,
+      ))
+      .child(Self::render_info_row("Kubelet", node.kubelet_version.clone(), cx))
+      .child(Self::render_info_row("Runtime", node.container_runtime.clone(), cx))
+      .child(Self::render_info_row("OS Image", node.os_image.clone(), cx))
+      .child(Self::render_info_row(
+        "Kernel",
+        format!("{} ({})", node.kernel_version, node.architecture),
+        cx,
+      ))
+      .child(Self::render_info_row("Age", node.age.clone(), cx));
+
+    let conditions = node
+      .conditions
+      .iter()
+      .map(|c| {
+        let color = if c.is_healthy() { colors.success } else { colors.danger };
+        let detail = match (&c.reason, &c.message) {
+          (Some(reason), Some(message)) => format!("{reason}: {message}"),
+          (Some(text), None) | (None, Some(text)) => text.clone(),
+          (None, None) => String::new(),
+        };
+
+        h_flex()
+          .w_full()
+          .py(px(6.))
+          .gap(px(12.))
+          .items_center()
+          .child(
+            h_flex()
+              .w(px(180.))
+              .flex_shrink_0()
+              .gap(px(6.))
+              .items_center()
+              .child(div().size(px(8.)).rounded_full().bg(color))
+              .child(
+                div()
+                  .text_sm()
+                  .text_color(colors.foreground)
+                  .child(format!("{} = {}", c.condition_type, c.status)),
+              ),
+          )
+          .child(
+            div()
+              .flex_1()
+              .min_w_0()
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .text_ellipsis()
+              .overflow_hidden()
+              .whitespace_nowrap()
+              .child(detail),
+          )
+          .child(
+            div()
+              .flex_shrink_0()
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .child(c.since.clone()),
+          )
+      })
+      .collect::<Vec<_>>();
+
+    let resources = h_flex()
+      .w_full()
+      .gap(px(12.))
+      .flex_wrap()
+      .child(Self::render_resource_card(
+        "CPU",
+        format_cpu_millis(node.capacity.cpu_millis),
+        (
+          node.allocatable.cpu_millis,
+          format_cpu_millis(node.allocatable.cpu_millis),
+        ),
+        (
+          node.allocated.cpu_requests,
+          format_cpu_millis(node.allocated.cpu_requests),
+        ),
+        Some((node.allocated.cpu_limits, format_cpu_millis(node.allocated.cpu_limits))),
+        cx,
+      ))
+      .child(Self::render_resource_card(
+        "Memory",
+        format_memory(node.capacity.memory_bytes),
+        (
+          node.allocatable.memory_bytes,
+          format_memory(node.allocatable.memory_bytes),
+        ),
+        (
+          node.allocated.memory_requests,
+          format_memory(node.allocated.memory_requests),
+        ),
+        Some((
+          node.allocated.memory_limits,
+          format_memory(node.allocated.memory_limits),
+        )),
+        cx,
+      ))
+      .child({
+        let pods = node.active_pod_count() as u64;
+        Self::render_resource_card(
+          "Pods",
+          node.capacity.pods.to_string(),
+          (node.allocatable.pods, node.allocatable.pods.to_string()),
+          (pods, pods.to_string()),
+          None,
+          cx,
+        )
+      });
+
+    let taints = if node.taints.is_empty() {
+      div().text_sm().text_color(colors.muted_foreground).child("No taints")
+    } else {
+      h_flex().w_full().flex_wrap().gap(px(6.)).children(
+        node
+          .taints
+          .iter()
+          .map(|t| Self::render_badge(t.clone(), colors.warning)),
+      )
+    };
+
+    let mut labels: Vec<_> = node.labels.iter().collect();
+    labels.sort();
+    let labels = h_flex().w_full().flex_wrap().gap(px(6.)).children(
+      labels
+        .into_iter()
+        .map(|(k, v)| Self::render_badge(format!("{k}={v}"), colors.muted_foreground)),
+    );
+
+    div().size_full().overflow_y_scrollbar().p(px(16.)).child(
+      v_flex()
+        .w_full()
+        .gap(px(16.))
+        .child(info)
+        .child(Self::section_title("Conditions", cx))
+        .child(v_flex().w_full().children(conditions))
+        .child(Self::section_title("Resources", cx))
+        .child(resources)
+        .child(Self::section_title("Taints", cx))
+        .child(taints)
+        .child(Self::section_title("Labels", cx))
+        .child(labels),
+    )
+  }
+
+  fn render_pods_tab(node: &NodeInfo, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = cx.theme().colors;
+
+    if node.pods.is_empty() {
+      return v_flex().size_full().p(px(16.)).child(
+        div()
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .child("No pods are scheduled on this node"),
+      );
+    }
+
+    let header = h_flex()
+      .w_full()
+      .py(px(8.))
+      .px(px(12.))
+      .gap(px(8.))
+      .bg(colors.sidebar)
+      .rounded_t(px(8.))
+      .child(Self::header_cell("Name", None, cx))
+      .child(Self::header_cell("Namespace", Some(140.), cx))
+      .child(Self::header_cell("Status", Some(90.), cx))
+      .child(Self::header_cell("CPU", Some(80.), cx))
+      .child(Self::header_cell("Memory", Some(80.), cx));
+
+    let rows = node
+      .pods
+      .iter()
+      .enumerate()
+      .map(|(i, pod)| {
+        let status_color = match pod.phase {
+          PodPhase::Running => colors.success,
+          PodPhase::Pending => colors.warning,
+          PodPhase::Failed => colors.danger,
+          _ => colors.muted_foreground,
+        };
+        let (name, namespace) = (pod.name.clone(), pod.namespace.clone());
+
+        h_flex()
+          .w_full()
+          .py(px(6.))
+          .px(px(12.))
+          .gap(px(8.))
+          .items_center()
+          .rounded(px(6.))
+          .when(i % 2 == 1, |el| el.bg(colors.sidebar.opacity(0.3)))
+          .hover(|el| el.bg(colors.sidebar))
+          .child(
+            div().flex_1().min_w_0().overflow_hidden().child(
+              Button::new(("pod", i))
+                .label(pod.name.clone())
+                .icon(Icon::new(AppIcon::Pod))
+                .ghost()
+                .xsmall()
+                .tooltip("Open pod")
+                .on_click(move |_ev, _window, cx| {
+                  services::open_pod_info(name.clone(), namespace.clone(), cx);
+                }),
+            ),
+          )
+          .child(
+            div()
+              .w(px(140.))
+              .flex_shrink_0()
+              .text_sm()
+              .text_color(colors.muted_foreground)
+              .text_ellipsis()
+              .overflow_hidden()
+              .whitespace_nowrap()
+              .child(pod.namespace.clone()),
+          )
+          .child(
+            div()
+              .w(px(90.))
+              .flex_shrink_0()
+              .text_sm()
+              .text_color(status_color)
+              .child(pod.phase.to_string()),
+          )
+          .child(
+            div()
+              .w(px(80.))
+              .flex_shrink_0()
+              .text_sm()
+              .text_color(colors.foreground)
+              .child(format_cpu_millis(pod.resources.cpu_requests)),
+          )
+          .child(
+            div()
+              .w(px(80.))
+              .flex_shrink_0()
+              .text_sm()
+              .text_color(colors.foreground)
+              .child(format_memory(pod.resources.memory_requests)),
+          )
+      })
+      .collect::<Vec<_>>();
+
+    div()
+      .size_full()
+      .overflow_y_scrollbar()
+      .p(px(16.))
+      .child(v_flex().w_full().child(header).children(rows))
+  }
+
+  fn render_yaml_tab(&self, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    if self.yaml_content.is_empty() {
+      return v_flex().size_full().p(px(16.)).child(
+        div()
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .child("Loading YAML..."),
+      );
+    }
+
+    if let Some(ref editor) = self.yaml_editor {
+      return div()
+        .size_full()
+        .child(Input::new(editor).size_full().appearance(false).disabled(true));
+    }
+
+    // Fallback to plain text
+    div().size_full().child(
+      div()
+        .size_full()
+        .overflow_y_scrollbar()
+        .bg(colors.sidebar)
+        .p(px(12.))
+        .font_family("monospace")
+        .text_xs()
+        .text_color(colors.foreground)
+        .child(self.yaml_content.clone()),
+    )
+  }
+
+  fn render_empty(cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    div().size_full().flex().items_center().justify_center().child(
+      v_flex()
+        .items_center()
+        .gap(px(16.))
+        .child(
+          div()
+            .size(px(64.))
+            .rounded(px(12.))
+            .bg(colors.sidebar)
+            .flex()
+            .items_center()
+            .justify_center()
+            .child(
+              Icon::new(AppIcon::Machine)
+                .size(px(48.))
+                .text_color(colors.muted_foreground),
+            ),
+        )
+        .child(
+          div()
+            .text_lg()
+            .font_weight(gpui::FontWeight::SEMIBOLD)
+            .text_color(colors.secondary_foreground)
+            .child("Select a Node"),
+        )
+        .child(
+          div()
+            .text_sm()
+            .text_color(colors.muted_foreground)
+            .child("Click on a node to view its capacity and pods"),
+        ),
+    )
+  }
+}
+
+impl Render for NodeDetail {
+  fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    // Create yaml editor if needed
+    if self.yaml_editor.is_none() && self.node.is_some() {
+      self.yaml_editor = Some(cx.new(|cx| {
+        InputState::new(window, cx)
+          .multi_line(true)
+          .code_editor("yaml")
+          .line_number(true)
+          .searchable(true)
+          .soft_wrap(false)
+      }));
+    }
+
+    // Sync yaml editor content
+    if let Some(ref editor) = self.yaml_editor
+      && !self.yaml_content.is_empty()
+      && self.last_synced_yaml != self.yaml_content
+    {
+      let yaml_clone = self.yaml_content.clone();
+      editor.update(cx, |state, cx| {
+        state.replace(&yaml_clone, window, cx);
+      });
+      self.last_synced_yaml = self.yaml_content.clone();
+    }
+
+    let colors = cx.theme().colors;
+
+    let Some(node) = self.node.clone() else {
+      return div().size_full().child(Self::render_empty(cx));
+    };
+
+    let active_tab = self.active_tab;
+    let status_color = node_status_color(&node, cx);
+
+    let tab_bar = TabBar::new("node-tabs")
+      .flex_1()
+      .py(px(0.))
+      .child(
+        Tab::new()
+          .label("Overview")
+          .selected(active_tab == NodeDetailTab::Overview)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = NodeDetailTab::Overview;
+            cx.notify();
+          })),
+      )
+      .child(
+        Tab::new()
+          .label(format!("Pods ({})", node.pods.len()))
+          .selected(active_tab == NodeDetailTab::Pods)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = NodeDetailTab::Pods;
+            cx.notify();
+          })),
+      )
+      .child(
+        Tab::new()
+          .label("YAML")
+          .selected(active_tab == NodeDetailTab::Yaml)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = NodeDetailTab::Yaml;
+            if let Some(ref n) = this.node {
+              services::get_node_yaml(n.name.clone(), cx);
+            }
+          })),
+      );
+
+    let schedule_button = if node.unschedulable {
+      let name = node.name.clone();
+      Button::new("uncordon")
+        .icon(IconName::CircleCheck)
+        .label("Uncordon")
+        .ghost()
+        .small()
+        .on_click(move |_ev, _window, cx| {
+          services::uncordon_node(name.clone(), cx);
+        })
+    } else {
+      let name = node.name.clone();
+      Button::new("cordon")
+        .icon(IconName::CircleX)
+        .label("Cordon")
+        .ghost()
+        .small()
+        .on_click(move |_ev, _window, cx| {
+          services::cordon_node(name.clone(), cx);
+        })
+    };
+
+    let menu_node = node.clone();
+    let actions = Button::new("node-actions")
+      .icon(IconName::Ellipsis)
+      .ghost()
+      .small()
+      .dropdown_menu(move |menu, _window, _cx| node_menu(menu, &menu_node));
+
+    let content = match active_tab {
+      NodeDetailTab::Overview => Self::render_overview_tab(&node, cx),
+      NodeDetailTab::Pods => Self::render_pods_tab(&node, cx),
+      NodeDetailTab::Yaml => self.render_yaml_tab(cx),
+    };
+
+    div()
+      .size_full()
+      .flex()
+      .flex_col()
+      .overflow_hidden()
+      .child(
+        h_flex()
+          .w_full()
+          .px(px(16.))
+          .py(px(8.))
+          .gap(px(12.))
+          .items_center()
+          .border_b_1()
+          .border_color(colors.border)
+          .flex_shrink_0()
+          .child(tab_bar)
+          .child(
+            div()
+              .px(px(8.))
+              .py(px(2.))
+              .rounded(px(4.))
+              .bg(status_color.opacity(0.2))
+              .text_xs()
+              .font_weight(gpui::FontWeight::MEDIUM)
+              .text_color(status_color)
+              .child(node.status_display()),
+          )
+          .child(schedule_button)
+          .child(actions),
+      )
+      .child(div().flex_1().min_h_0().overflow_hidden().child(content))
+  }
+}
@@ -0,0 +1,147 @@
+use std::time::Duration;
+
+use gpui::{App, Context, Entity, Render, Styled, Timer, Window, div, prelude::*, px};
+use gpui_component::theme::ActiveTheme;
+
+use super::detail::NodeDetail;
+use super::list::{NodeList, NodeListEvent};
+use crate::kubernetes::NodeInfo;
+use crate::services;
+use crate::state::{DockerState, Selection, StateChanged, docker_state, settings_state};
+
+/// Nodes view with list and detail panels
+pub struct NodesView {
+  docker_state: Entity<DockerState>,
+  list: Entity<NodeList>,
+  detail: Entity<NodeDetail>,
+}
+
+impl NodesView {
+  /// Get the currently selected node from global state
+  fn selected_node(&self, cx: &App) -> Option<NodeInfo> {
+    let state = self.docker_state.read(cx);
+    if let Selection::Node(name) = &state.selection {
+      state.get_node(name).cloned()
+    } else {
+      None
+    }
+  }
+
+  fn select(&mut self, node: &NodeInfo, cx: &mut Context<'_, Self>) {
+    self.docker_state.update(cx, |state, _cx| {
+      state.set_selection(Selection::Node(node.name.clone()));
+    });
+  }
+
+  pub fn new(window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
+    let docker_state = docker_state(cx);
+
+    let list = cx.new(|cx| NodeList::new(window, cx));
+    let detail = cx.new(NodeDetail::new);
+
+    cx.subscribe(&list, |this, _list, event: &NodeListEvent, cx| match event {
+      NodeListEvent::Selected(node) => {
+        this.detail.update(cx, |detail, cx| {
+          detail.set_node(node.clone(), cx);
+        });
+        this.select(node, cx);
+        cx.notify();
+      }
+    })
+    .detach();
+
+    cx.subscribe(&docker_state, |this, ds, event: &StateChanged, cx| match event {
+      StateChanged::NodeTabRequest { name, .. } => {
+        let node = ds.read(cx).get_node(name).cloned();
+        if let Some(node) = node {
+          this.select(&node, cx);
+          cx.notify();
+        }
+      }
+      StateChanged::SelectionChanged => {
+        // Selections made elsewhere (e.g. global search) still need the detail loaded
+        if let Some(node) = this.selected_node(cx) {
+          let shown = this.detail.read(cx).node().map(|n| n.name.clone());
+          if shown.as_ref() != Some(&node.name) {
+            this.detail.update(cx, |detail, cx| {
+              detail.set_node(node, cx);
+            });
+          }
+        }
+        cx.notify();
+      }
+      StateChanged::NodesUpdated => {
+        let selected = match &this.docker_state.read(cx).selection {
+          Selection::Node(name) => Some(name.clone()),
+          _ => None,
+        };
+
+        if let Some(name) = selected {
+          let node = ds.read(cx).get_node(&name).cloned();
+          if let Some(node) = node {
+            // Preserve tab state during refresh
+            this.detail.update(cx, |detail, cx| {
+              detail.update_node_data(node, cx);
+            });
+          } else {
+            // Node left the cluster
+            this.docker_state.update(cx, |s, _| {
+              s.set_selection(Selection::None);
+            });
+          }
+        }
+        cx.notify();
+      }
+      _ => {}
+    })
+    .detach();
+
+    // Start periodic refresh
+    let refresh_interval = settings_state(cx).read(cx).settings.container_refresh_interval;
+    cx.spawn(async move |_this, cx| {
+      loop {
+        Timer::after(Duration::from_secs(refresh_interval)).await;
+        let _ = cx.update(|cx| {
+          services::refresh_nodes(cx);
+        });
+      }
+    })
+    .detach();
+
+    // Trigger initial data load
+    services::refresh_nodes(cx);
+
+    Self {
+      docker_state,
+      list,
+      detail,
+    }
+  }
+}
+
+impl Render for NodesView {
+  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let has_selection = self.selected_node(cx).is_some();
+
+    div()
+      .size_full()
+      .flex()
+      .overflow_hidden()
+      .child(
+        // Left: list - fixed width when selected, full width when not
+        div()
+          .when(has_selection, |el| {
+            el.w(px(320.)).border_r_1().border_color(colors.border)
+          })
+          .when(!has_selection, gpui::Styled::flex_1)
+          .h_full()
+          .flex_shrink_0()
+          .overflow_hidden()
+          .child(self.list.clone()),
+      )
+      .when(has_selection, |el| {
+        el.child(div().flex_1().h_full().overflow_hidden().child(self.detail.clone()))
+      })
+  }
+}
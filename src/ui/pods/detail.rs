@@ -13,7 +13,9 @@ use std::rc::Rc;
 
 use crate::assets::AppIcon;
 use crate::kubernetes::{PodInfo, PodPhase};
+use crate::state::InvolvedObject;
 use crate::terminal::TerminalView;
+use crate::ui::components::render_object_events;
 
 // Re-export from state module for backwards compatibility
 pub use crate::state::PodDetailTab;
@@ -233,10 +235,33 @@ impl PodDetail {
             .child(info_row("Ready", pod.ready.clone()))
             .child(info_row("Restarts", pod.restarts.to_string()))
             .child(info_row("Age", pod.age.clone()))
-            .child(info_row(
-                "Node",
-                pod.node.clone().unwrap_or_else(|| "N/A".to_string()),
-            ))
+            .child(match pod.node.clone() {
+                // Scheduled pods link to their node
+                Some(node) => h_flex()
+                    .w_full()
+                    .py(px(8.))
+                    .justify_between()
+                    .border_b_1()
+                    .border_color(colors.border)
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(colors.muted_foreground)
+                            .child("Node"),
+                    )
+                    .child(
+                        Button::new("open-node")
+                            .label(node.clone())
+                            .icon(Icon::new(AppIcon::Machine))
+                            .ghost()
+                            .xsmall()
+                            .tooltip("Open node")
+                            .on_click(move |_ev, _window, cx| {
+                                crate::services::open_node(node.clone(), cx);
+                            }),
+                    ),
+                None => info_row("Node", "N/A".to_string()),
+            })
             .child(info_row(
                 "IP",
                 pod.ip.clone().unwrap_or_else(|| "N/A".to_string()),
@@ -515,12 +540,13 @@ impl PodDetail {
       PodDetailTab::Logs => self.render_logs_tab(pod, cx),
       PodDetailTab::Terminal => self.render_terminal_tab(pod, cx),
       PodDetailTab::Describe => self.render_describe_tab(cx),
+      PodDetailTab::Events => render_object_events(&InvolvedObject::new("Pod", &pod.name, &pod.namespace), cx),
       PodDetailTab::Yaml => self.render_yaml_tab(cx),
       PodDetailTab::Info => Self::render_info_tab(pod, cx),
     };
 
-    // Terminal tab needs full height without scroll
-    let is_terminal_tab = self.active_tab == PodDetailTab::Terminal;
+    // Terminal and events tabs need full height without scroll (events scroll on their own)
+    let is_full_height_tab = matches!(self.active_tab, PodDetailTab::Terminal | PodDetailTab::Events);
 
     let mut result = div()
       .size_full()
@@ -530,7 +556,7 @@ impl PodDetail {
       .flex_col()
       .child(toolbar);
 
-    if is_terminal_tab {
+    if is_full_height_tab {
       result = result.child(div().flex_1().min_h_0().w_full().child(content));
     } else {
       result = result.child(
@@ -4,7 +4,7 @@ use std::time::Duration;
 
 use crate::kubernetes::{PodInfo, PodPhase};
 use crate::services;
-use crate::state::{DockerState, Selection, StateChanged, docker_state, settings_state};
+use crate::state::{DockerState, EventsChanged, Selection, StateChanged, docker_state, events_state, settings_state};
 use crate::terminal::{TerminalSessionType, TerminalView};
 
 use super::detail::{PodDetail, PodDetailTab, PodTabState};
@@ -151,6 +151,15 @@ impl PodsView {
     )
     .detach();
 
+    // Keep the events tab live as the watcher appends events
+    let events_state = events_state(cx);
+    cx.subscribe(&events_state, |this, _state, _event: &EventsChanged, cx| {
+      if this.active_tab == PodDetailTab::Events {
+        cx.notify();
+      }
+    })
+    .detach();
+
     // Start periodic pod and machine refresh
     let refresh_interval = settings_state(cx).read(cx).settings.container_refresh_interval;
     cx.spawn(async move |_this, cx| {
@@ -1,7 +1,22 @@
-use gpui::{App, Context, FocusHandle, Focusable, Hsla, ParentElement, Render, Styled, Window, div, prelude::*, px};
-use gpui_component::{h_flex, label::Label, scroll::ScrollableElement, switch::Switch, theme::ActiveTheme, v_flex};
+use std::collections::HashSet;
 
-use crate::docker::PruneResult;
+use gpui::{
+  App, Context, Entity, FocusHandle, Focusable, Hsla, ParentElement, Render, SharedString, Styled, Window, div,
+  prelude::*, px,
+};
+use gpui_component::{
+  Icon, IconName, Sizable, h_flex,
+  input::{Input, InputState},
+  label::Label,
+  scroll::ScrollableElement,
+  switch::Switch,
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::docker::{PROTECT_LABEL, PruneCandidate, PruneFilters, PruneKind, PruneResult};
+use crate::services;
+use crate::state::settings_state;
 
 /// Options for prune operation
 #[derive(Debug, Clone, Default)]
@@ -11,7 +26,11 @@ pub struct PruneOptions {
   pub prune_images: bool,
   pub prune_volumes: bool,
   pub prune_networks: bool,
+  pub prune_build_cache: bool,
   pub images_dangling_only: bool,
+  pub filters: PruneFilters,
+  /// Items picked from a preview; when set, only these Docker items are removed
+  pub selected: Option<Vec<PruneCandidate>>,
   // Kubernetes options
   pub prune_k8s_pods: bool,
   pub prune_k8s_pods_all: bool, // If true, delete all pods; if false, only completed/failed
@@ -21,13 +40,57 @@ pub struct PruneOptions {
 
 impl PruneOptions {
   pub fn is_empty(&self) -> bool {
-    !self.prune_containers
-      && !self.prune_images
-      && !self.prune_volumes
-      && !self.prune_networks
-      && !self.prune_k8s_pods
-      && !self.prune_k8s_deployments
-      && !self.prune_k8s_services
+    let docker_empty = match &self.selected {
+      Some(items) => items.is_empty(),
+      None => self.docker_kinds().is_empty(),
+    };
+    docker_empty && !self.prune_k8s_pods && !self.prune_k8s_deployments && !self.prune_k8s_services
+  }
+
+  /// Docker resources switched on, in prune order
+  pub fn docker_kinds(&self) -> Vec<PruneKind> {
+    [
+      (self.prune_containers, PruneKind::Containers),
+      (self.prune_images, PruneKind::Images),
+      (self.prune_volumes, PruneKind::Volumes),
+      (self.prune_networks, PruneKind::Networks),
+      (self.prune_build_cache, PruneKind::BuildCache),
+    ]
+    .into_iter()
+    .filter_map(|(on, kind)| on.then_some(kind))
+    .collect()
+  }
+}
+
+/// What a prune would remove, as last previewed, with the items left out
+#[derive(Debug, Clone, Default)]
+pub struct PrunePreview {
+  pub candidates: Vec<PruneCandidate>,
+  /// Indexes into `candidates`
+  pub excluded: HashSet<usize>,
+  /// Filter text the preview was made with
+  filters: String,
+}
+
+impl PrunePreview {
+  pub fn selected(&self) -> Vec<PruneCandidate> {
+    self
+      .candidates
+      .iter()
+      .enumerate()
+      .filter(|(i, _)| !self.excluded.contains(i))
+      .map(|(_, c)| c.clone())
+      .collect()
+  }
+
+  pub fn selected_size(&self) -> u64 {
+    self
+      .candidates
+      .iter()
+      .enumerate()
+      .filter(|(i, _)| !self.excluded.contains(i))
+      .map(|(_, c)| c.size)
+      .sum()
   }
 }
 
@@ -43,22 +106,313 @@ pub struct PruneResultDisplay {
 pub struct PruneDialog {
   focus_handle: FocusHandle,
   options: PruneOptions,
+  filters_input: Option<Entity<InputState>>,
+  preview: Option<PrunePreview>,
+  preview_loading: bool,
+  preview_error: Option<String>,
   result_display: PruneResultDisplay,
 }
 
 impl PruneDialog {
   pub fn new(cx: &mut Context<'_, Self>) -> Self {
+    Self::with_options(PruneOptions::default(), cx)
+  }
+
+  /// Dialog with some options already switched on
+  pub fn with_options(options: PruneOptions, cx: &mut Context<'_, Self>) -> Self {
     let focus_handle = cx.focus_handle();
 
     Self {
       focus_handle,
-      options: PruneOptions::default(),
+      options,
+      filters_input: None,
+      preview: None,
+      preview_loading: false,
+      preview_error: None,
       result_display: PruneResultDisplay::default(),
     }
   }
 
-  pub fn get_options(&self) -> PruneOptions {
-    self.options.clone()
+  fn ensure_inputs(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    if self.filters_input.is_none() {
+      self.filters_input =
+        Some(cx.new(|cx| InputState::new(window, cx).placeholder("until=24h label=env=dev label!=keep")));
+    }
+  }
+
+  fn filters_text(&self, cx: &App) -> String {
+    self
+      .filters_input
+      .as_ref()
+      .map(|s| s.read(cx).text().to_string().trim().to_string())
+      .unwrap_or_default()
+  }
+
+  /// Options to prune with, or `None` while the filters don't parse.
+  /// A preview made with the current filters narrows the prune to the items left checked.
+  pub fn get_options(&self, cx: &App) -> Option<PruneOptions> {
+    let text = self.filters_text(cx);
+    let mut options = self.options.clone();
+    options.filters = PruneFilters::parse(&text).ok()?;
+    options.selected = self
+      .preview
+      .as_ref()
+      .filter(|preview| preview.filters == text)
+      .map(PrunePreview::selected);
+    Some(options)
+  }
+
+  /// A previewed item list no longer matches once the options change
+  fn options_changed(&mut self, cx: &mut Context<'_, Self>) {
+    self.preview = None;
+    self.preview_error = None;
+    cx.notify();
+  }
+
+  pub fn load_preview(&mut self, cx: &mut Context<'_, Self>) {
+    if self.preview_loading {
+      return;
+    }
+    let text = self.filters_text(cx);
+    let filters = match PruneFilters::parse(&text) {
+      Ok(filters) => filters,
+      Err(e) => {
+        self.preview_error = Some(e.to_string());
+        cx.notify();
+        return;
+      }
+    };
+    let kinds = self.options.docker_kinds();
+    if kinds.is_empty() {
+      self.preview_error = Some("Switch on at least one Docker resource to preview".to_string());
+      cx.notify();
+      return;
+    }
+    let dangling_only = self.options.images_dangling_only;
+    let protection = settings_state(cx).read(cx).settings.protection();
+
+    self.preview_loading = true;
+    self.preview_error = None;
+    cx.notify();
+
+    let tokio_handle = services::Tokio::runtime_handle();
+    let client = services::docker_client();
+    cx.spawn(async move |this, cx| {
+      let result = cx
+        .background_executor()
+        .spawn(async move {
+          tokio_handle.block_on(async {
+            let guard = client.read().await;
+            match guard.as_ref() {
+              Some(docker) => docker.preview_prune(&kinds, dangling_only, &filters, &protection).await,
+              None => Err(anyhow::anyhow!("Docker client not connected")),
+            }
+          })
+        })
+        .await;
+
+      let _ = this.update(cx, |this, cx| {
+        this.preview_loading = false;
+        match result {
+          Ok(candidates) => {
+            this.preview = Some(PrunePreview {
+              candidates,
+              excluded: HashSet::new(),
+              filters: text,
+            });
+          }
+          Err(e) => this.preview_error = Some(e.to_string()),
+        }
+        cx.notify();
+      });
+    })
+    .detach();
+  }
+
+  fn toggle_candidate(&mut self, index: usize, cx: &mut Context<'_, Self>) {
+    if let Some(preview) = &mut self.preview
+      && !preview.excluded.remove(&index)
+    {
+      preview.excluded.insert(index);
+    }
+    cx.notify();
+  }
+
+  fn render_filters(&self, cx: &App) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let error = PruneFilters::parse(&self.filters_text(cx)).err();
+
+    v_flex()
+      .w_full()
+      .py(px(12.))
+      .px(px(16.))
+      .gap(px(6.))
+      .border_b_1()
+      .border_color(colors.border)
+      .child(Label::new("Filters").text_color(colors.foreground))
+      .child(div().text_xs().text_color(colors.muted_foreground).child(format!(
+        "Only prune items older than until, or with (label) or without (label!) a label. \
+             Pinned items and ones labelled {PROTECT_LABEL}=true are always kept."
+      )))
+      .when_some(self.filters_input.as_ref(), |el, input| {
+        el.child(Input::new(input).small().w_full())
+      })
+      .when_some(error, |el, error| {
+        el.child(div().text_xs().text_color(colors.danger).child(error.to_string()))
+      })
+  }
+
+  fn render_candidate(&self, index: usize, candidate: &PruneCandidate, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let checked = self.preview.as_ref().is_some_and(|p| !p.excluded.contains(&index));
+
+    h_flex()
+      .id(SharedString::from(format!("prune-candidate-{index}")))
+      .w_full()
+      .px(px(16.))
+      .py(px(4.))
+      .gap(px(8.))
+      .items_center()
+      .cursor_pointer()
+      .hover(|s| s.bg(colors.list_hover))
+      .on_click(cx.listener(move |this, _ev, _window, cx| {
+        this.toggle_candidate(index, cx);
+      }))
+      .child(
+        div()
+          .size(px(14.))
+          .flex_shrink_0()
+          .flex()
+          .items_center()
+          .justify_center()
+          .rounded(px(3.))
+          .border_1()
+          .border_color(if checked { colors.primary } else { colors.border })
+          .when(checked, |el| {
+            el.bg(colors.primary).child(
+              Icon::new(IconName::Check)
+                .size(px(10.))
+                .text_color(colors.primary_foreground),
+            )
+          }),
+      )
+      .child(
+        div()
+          .flex_1()
+          .min_w_0()
+          .text_sm()
+          .text_color(if checked {
+            colors.foreground
+          } else {
+            colors.muted_foreground
+          })
+          .overflow_hidden()
+          .text_ellipsis()
+          .whitespace_nowrap()
+          .child(candidate.name.clone()),
+      )
+      .when(!candidate.detail.is_empty(), |el| {
+        el.child(
+          div()
+            .max_w(px(140.))
+            .text_xs()
+            .text_color(colors.muted_foreground)
+            .overflow_hidden()
+            .text_ellipsis()
+            .whitespace_nowrap()
+            .child(candidate.detail.clone()),
+        )
+      })
+      .when(candidate.kind != PruneKind::Networks, |el| {
+        el.child(
+          div()
+            .w(px(72.))
+            .flex_shrink_0()
+            .text_xs()
+            .text_right()
+            .text_color(colors.secondary_foreground)
+            .child(candidate.display_size()),
+        )
+      })
+  }
+
+  fn render_preview(&self, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+
+    let Some(preview) = &self.preview else {
+      return v_flex()
+        .w_full()
+        .when(self.preview_loading, |el| {
+          el.child(
+            div()
+              .p(px(16.))
+              .text_sm()
+              .text_color(colors.link)
+              .child("Finding what a prune would remove..."),
+          )
+        })
+        .when_some(self.preview_error.clone(), |el, error| {
+          el.child(div().p(px(16.)).text_sm().text_color(colors.danger).child(error))
+        });
+    };
+
+    let selected = preview.candidates.len() - preview.excluded.len();
+    let stale = preview.filters != self.filters_text(cx);
+    let mut rows: Vec<gpui::AnyElement> = Vec::new();
+    let mut current_kind = None;
+    for (i, candidate) in preview.candidates.iter().enumerate() {
+      if current_kind != Some(candidate.kind) {
+        current_kind = Some(candidate.kind);
+        let count = preview.candidates.iter().filter(|c| c.kind == candidate.kind).count();
+        rows.push(
+          div()
+            .w_full()
+            .px(px(16.))
+            .pt(px(8.))
+            .pb(px(2.))
+            .text_xs()
+            .font_weight(gpui::FontWeight::SEMIBOLD)
+            .text_color(colors.muted_foreground)
+            .child(format!("{} ({count})", candidate.kind.label()))
+            .into_any_element(),
+        );
+      }
+      rows.push(self.render_candidate(i, candidate, cx).into_any_element());
+    }
+
+    v_flex()
+      .w_full()
+      .pb(px(8.))
+      .border_b_1()
+      .border_color(colors.border)
+      .child(
+        h_flex().w_full().px(px(16.)).py(px(8.)).bg(colors.sidebar).child(
+          div()
+            .text_sm()
+            .font_weight(gpui::FontWeight::SEMIBOLD)
+            .text_color(colors.foreground)
+            .child(if preview.candidates.is_empty() {
+              "Preview: nothing would be removed".to_string()
+            } else {
+              format!(
+                "Preview: {selected} of {} items selected, up to {}",
+                preview.candidates.len(),
+                bytesize::ByteSize(preview.selected_size())
+              )
+            }),
+        ),
+      )
+      .when(stale, |el| {
+        el.child(
+          div()
+            .px(px(16.))
+            .py(px(6.))
+            .text_xs()
+            .text_color(colors.warning)
+            .child("Filters changed since this preview. Prune will use the new filters; preview again to pick items."),
+        )
+      })
+      .children(rows)
   }
 
   pub fn set_result(&mut self, result: PruneResult) {
@@ -88,13 +442,15 @@ impl Focusable for PruneDialog {
 }
 
 impl Render for PruneDialog {
-  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+  fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    self.ensure_inputs(window, cx);
     let colors = cx.theme().colors;
     // Docker options
     let containers_checked = self.options.prune_containers;
     let images_checked = self.options.prune_images;
     let volumes_checked = self.options.prune_volumes;
     let networks_checked = self.options.prune_networks;
+    let build_cache_checked = self.options.prune_build_cache;
     let dangling_only = self.options.images_dangling_only;
     // Kubernetes options
     let k8s_pods_checked = self.options.prune_k8s_pods;
@@ -193,6 +549,12 @@ impl Render for PruneDialog {
                 .child(format!("Networks removed: {}", result.networks_deleted.len())),
             )
           })
+          .when(!result.build_cache_deleted.is_empty(), |this| {
+            this.child(div().text_sm().text_color(colors.secondary_foreground).child(format!(
+              "Build cache records removed: {}",
+              result.build_cache_deleted.len()
+            )))
+          })
           .when(!result.pods_deleted.is_empty(), |this| {
             this.child(
               div()
@@ -258,7 +620,7 @@ impl Render for PruneDialog {
                     .checked(containers_checked)
                     .on_click(cx.listener(|this, checked: &bool, _window, cx| {
                         this.options.prune_containers = *checked;
-                        cx.notify();
+                        this.options_changed(cx);
                     }))
                     .into_any_element(),
                 colors.border,
@@ -273,7 +635,7 @@ impl Render for PruneDialog {
                     .checked(images_checked)
                     .on_click(cx.listener(|this, checked: &bool, _window, cx| {
                         this.options.prune_images = *checked;
-                        cx.notify();
+                        this.options_changed(cx);
                     }))
                     .into_any_element(),
                 colors.border,
@@ -303,7 +665,7 @@ impl Render for PruneDialog {
                                 .checked(dangling_only)
                                 .on_click(cx.listener(|this, checked: &bool, _window, cx| {
                                     this.options.images_dangling_only = *checked;
-                                    cx.notify();
+                                    this.options_changed(cx);
                                 })),
                         ),
                 )
@@ -311,12 +673,12 @@ impl Render for PruneDialog {
             // Volumes
             .child(render_form_row(
                 "Volumes",
-                "Remove unused volumes (warning: data loss)",
+                "Remove unused anonymous volumes (warning: data loss)",
                 Switch::new("volumes")
                     .checked(volumes_checked)
                     .on_click(cx.listener(|this, checked: &bool, _window, cx| {
                         this.options.prune_volumes = *checked;
-                        cx.notify();
+                        this.options_changed(cx);
                     }))
                     .into_any_element(),
                 colors.border,
@@ -347,13 +709,30 @@ impl Render for PruneDialog {
                     .checked(networks_checked)
                     .on_click(cx.listener(|this, checked: &bool, _window, cx| {
                         this.options.prune_networks = *checked;
-                        cx.notify();
+                        this.options_changed(cx);
+                    }))
+                    .into_any_element(),
+                colors.border,
+                colors.foreground,
+                colors.muted_foreground,
+            ))
+            // Build cache
+            .child(render_form_row(
+                "Build Cache",
+                "Remove build cache not used by a running build",
+                Switch::new("build-cache")
+                    .checked(build_cache_checked)
+                    .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                        this.options.prune_build_cache = *checked;
+                        this.options_changed(cx);
                     }))
                     .into_any_element(),
                 colors.border,
                 colors.foreground,
                 colors.muted_foreground,
             ))
+            .child(self.render_filters(cx))
+            .child(self.render_preview(cx))
             // Kubernetes section header
             .child(
                 div()
@@ -422,7 +801,10 @@ impl Render for PruneDialog {
                             div()
                                 .text_xs()
                                 .text_color(colors.danger)
-                                .child("Warning: This will delete ALL pods including running workloads!"),
+                                .child(format!(
+                                    "Warning: This will delete ALL pods including running workloads! \
+                                     Only pods annotated {PROTECT_LABEL}=true are kept."
+                                )),
                         ),
                 )
             })
@@ -488,7 +870,10 @@ mod tests {
     assert!(!options.prune_images);
     assert!(!options.prune_volumes);
     assert!(!options.prune_networks);
+    assert!(!options.prune_build_cache);
     assert!(!options.images_dangling_only);
+    assert!(options.filters.is_empty());
+    assert!(options.selected.is_none());
     assert!(!options.prune_k8s_pods);
     assert!(!options.prune_k8s_pods_all);
     assert!(!options.prune_k8s_deployments);
@@ -524,6 +909,12 @@ mod tests {
     };
     assert!(!with_networks.is_empty());
 
+    let with_build_cache = PruneOptions {
+      prune_build_cache: true,
+      ..Default::default()
+    };
+    assert!(!with_build_cache.is_empty());
+
     let with_k8s_pods = PruneOptions {
       prune_k8s_pods: true,
       ..Default::default()
@@ -560,6 +951,65 @@ mod tests {
     assert!(!options.prune_volumes);
   }
 
+  #[test]
+  fn test_prune_options_docker_kinds() {
+    let options = PruneOptions {
+      prune_volumes: true,
+      prune_containers: true,
+      prune_build_cache: true,
+      prune_k8s_pods: true,
+      ..Default::default()
+    };
+    assert_eq!(
+      options.docker_kinds(),
+      vec![PruneKind::Containers, PruneKind::Volumes, PruneKind::BuildCache]
+    );
+  }
+
+  #[test]
+  fn test_prune_options_is_empty_with_selection() {
+    let candidate = PruneCandidate {
+      kind: PruneKind::Images,
+      id: "sha256:abc".to_string(),
+      name: "old:1".to_string(),
+      size: 10,
+      detail: String::new(),
+    };
+
+    // Everything unticked in the preview leaves nothing to do
+    let none_picked = PruneOptions {
+      prune_images: true,
+      selected: Some(Vec::new()),
+      ..Default::default()
+    };
+    assert!(none_picked.is_empty());
+
+    let picked = PruneOptions {
+      selected: Some(vec![candidate]),
+      ..Default::default()
+    };
+    assert!(!picked.is_empty());
+  }
+
+  #[test]
+  fn test_prune_preview_selected() {
+    let candidate = |id: &str, size: u64| PruneCandidate {
+      kind: PruneKind::Containers,
+      id: id.to_string(),
+      name: id.to_string(),
+      size,
+      detail: String::new(),
+    };
+    let preview = PrunePreview {
+      candidates: vec![candidate("a", 10), candidate("b", 20), candidate("c", 30)],
+      excluded: HashSet::from([1]),
+      filters: String::new(),
+    };
+    let ids: Vec<String> = preview.selected().into_iter().map(|c| c.id).collect();
+    assert_eq!(ids, vec!["a", "c"]);
+    assert_eq!(preview.selected_size(), 40);
+  }
+
   #[test]
   fn test_prune_result_display_default() {
     let display = PruneResultDisplay::default();
@@ -575,8 +1025,8 @@ mod tests {
     let dialog = cx.new(PruneDialog::new);
 
     // Verify initial state
-    dialog.read_with(cx, |dialog, _| {
-      let options = dialog.get_options();
+    dialog.read_with(cx, |dialog, cx| {
+      let options = dialog.get_options(cx).unwrap();
       assert!(options.is_empty());
       assert!(!dialog.result_display.is_loading);
       assert!(dialog.result_display.result.is_none());
@@ -667,8 +1117,8 @@ mod tests {
       dialog.options.images_dangling_only = true;
     });
 
-    dialog.read_with(cx, |dialog, _| {
-      let options = dialog.get_options();
+    dialog.read_with(cx, |dialog, cx| {
+      let options = dialog.get_options(cx).unwrap();
       assert!(!options.is_empty());
       assert!(options.prune_containers);
       assert!(options.prune_images);
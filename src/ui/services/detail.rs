@@ -13,7 +13,10 @@ use gpui_component::{
 use crate::assets::AppIcon;
 use crate::kubernetes::{PodInfo, ServiceInfo};
 use crate::services;
-use crate::state::{DockerState, ServiceDetailTab, StateChanged, docker_state};
+use crate::state::{
+  DockerState, EventsChanged, InvolvedObject, ServiceDetailTab, StateChanged, docker_state, events_state,
+};
+use crate::ui::components::render_object_events;
 
 /// Detail view for a service with tabs
 pub struct ServiceDetail {
@@ -74,6 +77,15 @@ impl ServiceDetail {
     })
     .detach();
 
+    // Keep the events tab live as the watcher appends events
+    let events_state = events_state(cx);
+    cx.subscribe(&events_state, |this, _state, _event: &EventsChanged, cx| {
+      if this.active_tab == ServiceDetailTab::Events {
+        cx.notify();
+      }
+    })
+    .detach();
+
     Self {
       docker_state,
       service: None,
@@ -668,6 +680,15 @@ impl Render for ServiceDetail {
             cx.notify();
           })),
       )
+      .child(
+        Tab::new()
+          .label("Events")
+          .selected(active_tab == ServiceDetailTab::Events)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = ServiceDetailTab::Events;
+            cx.notify();
+          })),
+      )
       .child(
         Tab::new()
           .label("YAML")
@@ -685,6 +706,9 @@ impl Render for ServiceDetail {
       ServiceDetailTab::Info => Self::render_info_tab(&service, cx),
       ServiceDetailTab::Ports => Self::render_ports_tab(&service, cx),
       ServiceDetailTab::Endpoints => self.render_endpoints_tab(&service, cx),
+      ServiceDetailTab::Events => {
+        render_object_events(&InvolvedObject::new("Service", &service.name, &service.namespace), cx)
+      }
       ServiceDetailTab::Yaml => self.render_yaml_tab(&service, cx),
     };
 
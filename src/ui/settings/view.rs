@@ -7,6 +7,7 @@ use gpui_component::{
   label::Label,
   scroll::ScrollableElement,
   select::{Select, SelectItem, SelectState},
+  switch::Switch,
   theme::{ActiveTheme, Theme, ThemeRegistry},
   v_flex,
 };
@@ -87,6 +88,15 @@ pub struct SettingsView {
   stats_refresh_input: Option<Entity<InputState>>,
   log_lines_input: Option<Entity<InputState>>,
   font_size_input: Option<Entity<InputState>>,
+  notifications_enabled: bool,
+  restart_threshold_input: Option<Entity<InputState>>,
+  restart_window_input: Option<Entity<InputState>>,
+  muted_resources_input: Option<Entity<InputState>>,
+  backup_dir_input: Option<Entity<InputState>>,
+  backup_interval_input: Option<Entity<InputState>>,
+  backup_keep_input: Option<Entity<InputState>>,
+  helper_image_input: Option<Entity<InputState>>,
+  helper_idle_input: Option<Entity<InputState>>,
   initialized: bool,
   last_theme_index: Option<usize>,
   // Colima cache state
@@ -111,6 +121,15 @@ impl SettingsView {
       stats_refresh_input: None,
       log_lines_input: None,
       font_size_input: None,
+      notifications_enabled: true,
+      restart_threshold_input: None,
+      restart_window_input: None,
+      muted_resources_input: None,
+      backup_dir_input: None,
+      backup_interval_input: None,
+      backup_keep_input: None,
+      helper_image_input: None,
+      helper_idle_input: None,
       initialized: false,
       last_theme_index: None,
       cache_size,
@@ -186,6 +205,45 @@ impl SettingsView {
     self.font_size_input =
       Some(cx.new(|cx| InputState::new(window, cx).default_value(settings.terminal_font_size.to_string())));
 
+    let notifications = &settings.notifications;
+    self.notifications_enabled = notifications.enabled;
+
+    self.restart_threshold_input =
+      Some(cx.new(|cx| InputState::new(window, cx).default_value(notifications.restart_loop_threshold.to_string())));
+
+    self.restart_window_input = Some(
+      cx.new(|cx| InputState::new(window, cx).default_value(notifications.restart_loop_window_minutes.to_string())),
+    );
+
+    self.muted_resources_input = Some(cx.new(|cx| {
+      InputState::new(window, cx)
+        .placeholder("e.g. flaky-worker, test-*, kube-system/*")
+        .default_value(notifications.muted_resources.join(", "))
+    }));
+
+    let backups = &settings.backups;
+    self.backup_dir_input = Some(cx.new(|cx| {
+      InputState::new(window, cx)
+        .placeholder("~/dockside-backups")
+        .default_value(backups.directory.clone())
+    }));
+
+    self.backup_interval_input =
+      Some(cx.new(|cx| InputState::new(window, cx).default_value(backups.interval_hours.to_string())));
+
+    self.backup_keep_input =
+      Some(cx.new(|cx| InputState::new(window, cx).default_value(backups.keep_last.to_string())));
+
+    let helper = &settings.volume_helper;
+    self.helper_image_input = Some(cx.new(|cx| {
+      InputState::new(window, cx)
+        .placeholder("Automatic (local alpine/busybox, else pull alpine:latest)")
+        .default_value(helper.image.clone())
+    }));
+
+    self.helper_idle_input =
+      Some(cx.new(|cx| InputState::new(window, cx).default_value(helper.idle_minutes.to_string())));
+
     self.initialized = true;
   }
 
@@ -267,6 +325,64 @@ impl SettingsView {
       .parse::<f32>()
       .unwrap_or(14.0);
 
+    let defaults = crate::state::NotificationSettings::default();
+    let restart_threshold = self
+      .restart_threshold_input
+      .as_ref()
+      .and_then(|input| input.read(cx).text().to_string().trim().parse::<usize>().ok())
+      .unwrap_or(defaults.restart_loop_threshold);
+    let restart_window = self
+      .restart_window_input
+      .as_ref()
+      .and_then(|input| input.read(cx).text().to_string().trim().parse::<u64>().ok())
+      .unwrap_or(defaults.restart_loop_window_minutes);
+    let muted_resources: Vec<String> = self
+      .muted_resources_input
+      .as_ref()
+      .map(|input| {
+        input
+          .read(cx)
+          .text()
+          .to_string()
+          .split(',')
+          .map(|s| s.trim().to_string())
+          .filter(|s| !s.is_empty())
+          .collect()
+      })
+      .unwrap_or_default();
+    let notifications_enabled = self.notifications_enabled;
+
+    let backup_defaults = crate::state::BackupSettings::default();
+    let backup_dir = self
+      .backup_dir_input
+      .as_ref()
+      .map(|input| input.read(cx).text().to_string().trim().to_string())
+      .unwrap_or_default();
+    let backup_interval = self
+      .backup_interval_input
+      .as_ref()
+      .and_then(|input| input.read(cx).text().to_string().trim().parse::<u64>().ok())
+      .filter(|hours| *hours > 0)
+      .unwrap_or(backup_defaults.interval_hours);
+    let backup_keep = self
+      .backup_keep_input
+      .as_ref()
+      .and_then(|input| input.read(cx).text().to_string().trim().parse::<usize>().ok())
+      .filter(|keep| *keep > 0)
+      .unwrap_or(backup_defaults.keep_last);
+
+    let helper_image = self
+      .helper_image_input
+      .as_ref()
+      .map(|input| input.read(cx).text().to_string().trim().to_string())
+      .unwrap_or_default();
+    let helper_idle = self
+      .helper_idle_input
+      .as_ref()
+      .and_then(|input| input.read(cx).text().to_string().trim().parse::<u64>().ok())
+      .filter(|minutes| *minutes > 0)
+      .unwrap_or_else(|| crate::state::VolumeHelperSettings::default().idle_minutes);
+
     // Get selected theme
     let theme = theme_select
       .read(cx)
@@ -298,6 +414,15 @@ impl SettingsView {
       state.settings.terminal_font_size = font_size;
       state.settings.theme = theme.clone();
       state.settings.external_editor = external_editor;
+      state.settings.notifications.enabled = notifications_enabled;
+      state.settings.notifications.restart_loop_threshold = restart_threshold;
+      state.settings.notifications.restart_loop_window_minutes = restart_window;
+      state.settings.notifications.muted_resources = muted_resources;
+      state.settings.backups.directory = backup_dir;
+      state.settings.backups.interval_hours = backup_interval;
+      state.settings.backups.keep_last = backup_keep;
+      state.settings.volume_helper.image = helper_image;
+      state.settings.volume_helper.idle_minutes = helper_idle;
       let _ = state.settings.save();
 
       if old_theme != theme {
@@ -605,6 +730,85 @@ impl Render for SettingsView {
                     Input::new(font_size_input).small().w_full(),
                     cx,
                 ))
+                // Notifications section
+                .child(Self::render_section_header("Notifications", cx))
+                .child(Self::render_form_row(
+                    "Crash Notifications",
+                    "Notify when containers crash, run out of memory or restart in a loop",
+                    Switch::new("notifications-enabled")
+                        .checked(self.notifications_enabled)
+                        .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                            this.notifications_enabled = *checked;
+                            cx.notify();
+                        })),
+                    cx,
+                ))
+                .when_some(self.restart_threshold_input.as_ref(), |el, input| {
+                    el.child(Self::render_form_row(
+                        "Restart Loop Threshold",
+                        "Number of exits that counts as a restart loop",
+                        Input::new(input).small().w_full(),
+                        cx,
+                    ))
+                })
+                .when_some(self.restart_window_input.as_ref(), |el, input| {
+                    el.child(Self::render_form_row(
+                        "Restart Loop Window",
+                        "Time window for counting exits (minutes)",
+                        Input::new(input).small().w_full(),
+                        cx,
+                    ))
+                })
+                .when_some(self.muted_resources_input.as_ref(), |el, input| {
+                    el.child(Self::render_form_row(
+                        "Muted Resources",
+                        "Comma-separated names that never notify (* wildcard, namespace/name for pods)",
+                        Input::new(input).small().w_full(),
+                        cx,
+                    ))
+                })
+                // Volume backups and helper section
+                .child(Self::render_section_header("Volumes", cx))
+                .when_some(self.backup_dir_input.as_ref(), |el, input| {
+                    el.child(Self::render_form_row(
+                        "Backup Directory",
+                        "Where volume backups are written (empty for ~/dockside-backups)",
+                        Input::new(input).small().w_full(),
+                        cx,
+                    ))
+                })
+                .when_some(self.backup_interval_input.as_ref(), |el, input| {
+                    el.child(Self::render_form_row(
+                        "Backup Interval",
+                        "Hours between scheduled backups of a volume",
+                        Input::new(input).small().w_full(),
+                        cx,
+                    ))
+                })
+                .when_some(self.backup_keep_input.as_ref(), |el, input| {
+                    el.child(Self::render_form_row(
+                        "Backups to Keep",
+                        "Scheduled backups kept per volume; older ones are deleted",
+                        Input::new(input).small().w_full(),
+                        cx,
+                    ))
+                })
+                .when_some(self.helper_image_input.as_ref(), |el, input| {
+                    el.child(Self::render_form_row(
+                        "Helper Image",
+                        "Image for the containers that browse and edit volumes",
+                        Input::new(input).small().w_full(),
+                        cx,
+                    ))
+                })
+                .when_some(self.helper_idle_input.as_ref(), |el, input| {
+                    el.child(Self::render_form_row(
+                        "Helper Idle Timeout",
+                        "Minutes before an unused volume helper container is removed",
+                        Input::new(input).small().w_full(),
+                        cx,
+                    ))
+                })
                 // Colima section
                 .child(Self::render_section_header("Colima", cx))
                 .child(self.render_colima_section(cx))
@@ -482,7 +482,7 @@ impl SetupDialog {
                 .on_click({
                   let ctx = expected.clone();
                   move |_ev, _window, cx| {
-                    crate::services::switch_kubectl_context(ctx.clone(), cx);
+                    crate::services::switch_kube_context(ctx.clone(), true, cx);
                   }
                 }),
             ),
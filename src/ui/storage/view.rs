@@ -0,0 +1,685 @@
+use std::time::Duration;
+
+use gpui::{App, Context, Entity, Hsla, Render, Styled, Timer, Window, div, prelude::*, px};
+use gpui_component::{
+  Icon, IconName, Selectable, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  label::Label,
+  menu::{DropdownMenu, PopupMenuItem},
+  scroll::ScrollableElement,
+  tab::{Tab, TabBar},
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::assets::AppIcon;
+use crate::kubernetes::{PersistentVolumeInfo, PodInfo, StorageClassInfo, VolumeClaimInfo, access_modes_display};
+use crate::services;
+use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state, settings_state};
+use crate::ui::components::{render_error, render_loading};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StorageTab {
+  Claims,
+  Volumes,
+  Classes,
+}
+
+/// PersistentVolumeClaims, PersistentVolumes and StorageClasses
+pub struct StorageView {
+  docker_state: Entity<DockerState>,
+  active_tab: StorageTab,
+}
+
+impl StorageView {
+  pub fn new(_window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
+    let docker_state = docker_state(cx);
+
+    cx.subscribe(&docker_state, |this, ds, event: &StateChanged, cx| match event {
+      StateChanged::VolumeClaimRequest { name, namespace } => {
+        if ds.read(cx).get_volume_claim(name, namespace).is_some() {
+          let selection = Selection::VolumeClaim {
+            name: name.clone(),
+            namespace: namespace.clone(),
+          };
+          this.docker_state.update(cx, |state, _cx| {
+            state.set_selection(selection);
+          });
+          this.active_tab = StorageTab::Claims;
+        }
+        cx.notify();
+      }
+      StateChanged::StorageUpdated => {
+        // Drop the selection if the claim was deleted
+        let deleted = match &ds.read(cx).selection {
+          Selection::VolumeClaim { name, namespace } => ds.read(cx).get_volume_claim(name, namespace).is_none(),
+          _ => false,
+        };
+        if deleted {
+          this.docker_state.update(cx, |s, _| {
+            s.set_selection(Selection::None);
+          });
+        }
+        cx.notify();
+      }
+      StateChanged::NamespacesUpdated => {
+        services::refresh_storage(cx);
+      }
+      StateChanged::SelectionChanged => {
+        // Claims picked elsewhere (e.g. global search) are shown expanded
+        if matches!(ds.read(cx).selection, Selection::VolumeClaim { .. }) {
+          this.active_tab = StorageTab::Claims;
+        }
+        cx.notify();
+      }
+      StateChanged::PodsUpdated => cx.notify(),
+      _ => {}
+    })
+    .detach();
+
+    // Start periodic refresh
+    let refresh_interval = settings_state(cx).read(cx).settings.container_refresh_interval;
+    cx.spawn(async move |_this, cx| {
+      loop {
+        Timer::after(Duration::from_secs(refresh_interval)).await;
+        let _ = cx.update(|cx| {
+          services::refresh_storage(cx);
+        });
+      }
+    })
+    .detach();
+
+    // Trigger initial data load; consuming pods are matched against the pod list
+    services::refresh_storage(cx);
+    services::refresh_pods(cx);
+
+    Self {
+      docker_state,
+      active_tab: StorageTab::Claims,
+    }
+  }
+
+  fn selected_claim(&self, cx: &App) -> Option<(String, String)> {
+    match &self.docker_state.read(cx).selection {
+      Selection::VolumeClaim { name, namespace } => Some((name.clone(), namespace.clone())),
+      _ => None,
+    }
+  }
+
+  fn toggle_claim(&mut self, claim: &VolumeClaimInfo, cx: &mut Context<'_, Self>) {
+    let key = (claim.name.clone(), claim.namespace.clone());
+    let selection = if self.selected_claim(cx) == Some(key) {
+      Selection::None
+    } else {
+      Selection::VolumeClaim {
+        name: claim.name.clone(),
+        namespace: claim.namespace.clone(),
+      }
+    };
+    self.docker_state.update(cx, |state, _cx| {
+      state.set_selection(selection);
+    });
+    cx.notify();
+  }
+
+  fn header_cell(label: &'static str, width: Option<f32>, cx: &App) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    let cell = match width {
+      Some(w) => div().w(px(w)).flex_shrink_0(),
+      None => div().flex_1().min_w_0(),
+    };
+    cell
+      .text_xs()
+      .font_weight(gpui::FontWeight::SEMIBOLD)
+      .text_color(colors.muted_foreground)
+      .child(label)
+  }
+
+  fn text_cell(text: String, width: Option<f32>, cx: &App) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    let cell = match width {
+      Some(w) => div().w(px(w)).flex_shrink_0(),
+      None => div().flex_1().min_w_0(),
+    };
+    cell
+      .text_sm()
+      .text_color(colors.foreground)
+      .text_ellipsis()
+      .overflow_hidden()
+      .whitespace_nowrap()
+      .child(text)
+  }
+
+  fn badge(text: String, color: Hsla) -> gpui::Div {
+    div()
+      .px(px(6.))
+      .py(px(2.))
+      .rounded(px(4.))
+      .bg(color.opacity(0.15))
+      .text_xs()
+      .text_color(color)
+      .child(text)
+  }
+
+  fn phase_color(phase: &str, cx: &App) -> Hsla {
+    let colors = &cx.theme().colors;
+    match phase {
+      "Bound" => colors.success,
+      "Available" => colors.primary,
+      "Pending" | "Released" => colors.warning,
+      _ => colors.danger,
+    }
+  }
+
+  fn table_header(cells: Vec<gpui::Div>, cx: &App) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    h_flex()
+      .w_full()
+      .py(px(8.))
+      .px(px(12.))
+      .gap(px(8.))
+      .bg(colors.sidebar)
+      .rounded_t(px(8.))
+      .children(cells)
+  }
+
+  fn render_claim_usage(&self, claim: &VolumeClaimInfo, cx: &App) -> gpui::Div {
+    let colors = cx.theme().colors;
+    let state = self.docker_state.read(cx);
+    let pods: Vec<PodInfo> = state.pods.iter().filter(|p| claim.is_used_by(p)).cloned().collect();
+    let volume = claim
+      .volume
+      .as_ref()
+      .and_then(|v| state.persistent_volumes.iter().find(|pv| pv.name == *v))
+      .cloned();
+
+    let volume_info = match (&claim.volume, volume) {
+      (Some(_), Some(pv)) => format!(
+        "Volume {} · {} · reclaim {} · {}",
+        pv.name,
+        pv.capacity.clone().unwrap_or_else(|| "-".to_string()),
+        pv.reclaim_policy,
+        pv.source
+      ),
+      (Some(name), None) => format!("Volume {name}"),
+      (None, _) => "Not bound to a volume yet".to_string(),
+    };
+
+    let pod_rows = pods.iter().enumerate().map(|(i, pod)| {
+      let (name, namespace) = (pod.name.clone(), pod.namespace.clone());
+      h_flex()
+        .w_full()
+        .gap(px(8.))
+        .items_center()
+        .child(
+          Icon::new(AppIcon::Pod)
+            .size(px(14.))
+            .text_color(colors.muted_foreground),
+        )
+        .child(
+          div()
+            .flex_1()
+            .min_w_0()
+            .text_sm()
+            .font_family("monospace")
+            .text_color(colors.foreground)
+            .child(pod.name.clone()),
+        )
+        .child(
+          div()
+            .text_xs()
+            .text_color(colors.muted_foreground)
+            .child(pod.phase.to_string()),
+        )
+        .child(
+          Button::new(("claim-pod", i))
+            .icon(IconName::Eye)
+            .ghost()
+            .xsmall()
+            .on_click(move |_ev, _window, cx| {
+              services::open_pod_info(name.clone(), namespace.clone(), cx);
+            }),
+        )
+    });
+
+    v_flex()
+      .w_full()
+      .px(px(24.))
+      .py(px(8.))
+      .gap(px(6.))
+      .bg(colors.sidebar.opacity(0.5))
+      .child(div().text_xs().text_color(colors.muted_foreground).child(volume_info))
+      .child(
+        div()
+          .text_xs()
+          .font_weight(gpui::FontWeight::SEMIBOLD)
+          .text_color(colors.muted_foreground)
+          .child(format!("Used by {} pod(s)", pods.len())),
+      )
+      .children(pod_rows)
+  }
+
+  fn render_claims(&self, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = cx.theme().colors;
+    let state = self.docker_state.read(cx);
+    let claims: Vec<VolumeClaimInfo> = state
+      .volume_claims
+      .iter()
+      .filter(|c| state.selected_namespace == "all" || c.namespace == state.selected_namespace)
+      .cloned()
+      .collect();
+    let selected = self.selected_claim(cx);
+
+    if claims.is_empty() {
+      return Self::render_placeholder("No volume claims in this namespace", cx);
+    }
+
+    let header = Self::table_header(
+      vec![
+        Self::header_cell("Name", None, cx),
+        Self::header_cell("Status", Some(80.), cx),
+        Self::header_cell("Volume", Some(160.), cx),
+        Self::header_cell("Size", Some(70.), cx),
+        Self::header_cell("Access", Some(70.), cx),
+        Self::header_cell("Class", Some(100.), cx),
+        Self::header_cell("Age", Some(50.), cx),
+        div().w(px(32.)).flex_shrink_0(),
+      ],
+      cx,
+    );
+
+    let mut rows = Vec::new();
+    for (i, claim) in claims.into_iter().enumerate() {
+      let is_selected = selected
+        .as_ref()
+        .is_some_and(|(name, ns)| *name == claim.name && *ns == claim.namespace);
+      let is_system = matches!(
+        claim.namespace.as_str(),
+        "kube-system" | "kube-public" | "kube-node-lease"
+      );
+      let (name, namespace) = (claim.name.clone(), claim.namespace.clone());
+      let row_claim = claim.clone();
+
+      let menu = Button::new(("claim-menu", i))
+        .icon(IconName::Ellipsis)
+        .ghost()
+        .xsmall()
+        .dropdown_menu(move |menu, _window, _cx| {
+          let (name, namespace) = (name.clone(), namespace.clone());
+          if is_system {
+            return menu;
+          }
+          menu.item(
+            PopupMenuItem::new("Delete")
+              .icon(Icon::new(AppIcon::Trash))
+              .on_click(move |_, _, cx| {
+                services::delete_volume_claim(name.clone(), namespace.clone(), cx);
+              }),
+          )
+        });
+
+      rows.push(
+        h_flex()
+          .id(("claim", i))
+          .w_full()
+          .py(px(8.))
+          .px(px(12.))
+          .gap(px(8.))
+          .items_center()
+          .rounded(px(6.))
+          .cursor_pointer()
+          .when(i % 2 == 1, |el| el.bg(colors.sidebar.opacity(0.3)))
+          .when(is_selected, |el| el.bg(colors.list_active))
+          .hover(|el| el.bg(colors.sidebar))
+          .on_click(cx.listener(move |this, _ev, _window, cx| {
+            this.toggle_claim(&row_claim, cx);
+          }))
+          .child(
+            v_flex()
+              .flex_1()
+              .min_w_0()
+              .child(Self::text_cell(claim.name.clone(), None, cx).font_family("monospace"))
+              .child(
+                div()
+                  .text_xs()
+                  .text_color(colors.muted_foreground)
+                  .child(claim.namespace.clone()),
+              ),
+          )
+          .child(
+            div()
+              .w(px(80.))
+              .flex_shrink_0()
+              .child(Self::badge(claim.phase.clone(), Self::phase_color(&claim.phase, cx))),
+          )
+          .child(Self::text_cell(
+            claim.volume.clone().unwrap_or_else(|| "-".to_string()),
+            Some(160.),
+            cx,
+          ))
+          .child(Self::text_cell(claim.size_display(), Some(70.), cx))
+          .child(Self::text_cell(
+            access_modes_display(&claim.access_modes),
+            Some(70.),
+            cx,
+          ))
+          .child(Self::text_cell(
+            claim.storage_class.clone().unwrap_or_else(|| "-".to_string()),
+            Some(100.),
+            cx,
+          ))
+          .child(Self::text_cell(claim.age.clone(), Some(50.), cx))
+          .child(div().w(px(32.)).flex_shrink_0().flex().justify_end().child(menu))
+          .into_any_element(),
+      );
+
+      if is_selected {
+        rows.push(self.render_claim_usage(&claim, cx).into_any_element());
+      }
+    }
+
+    v_flex().w_full().child(header).children(rows)
+  }
+
+  fn render_volumes(&self, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = cx.theme().colors;
+    let volumes: Vec<PersistentVolumeInfo> = self.docker_state.read(cx).persistent_volumes.clone();
+
+    if volumes.is_empty() {
+      return Self::render_placeholder("No persistent volumes", cx);
+    }
+
+    let header = Self::table_header(
+      vec![
+        Self::header_cell("Name", None, cx),
+        Self::header_cell("Status", Some(80.), cx),
+        Self::header_cell("Claim", Some(180.), cx),
+        Self::header_cell("Capacity", Some(70.), cx),
+        Self::header_cell("Access", Some(70.), cx),
+        Self::header_cell("Reclaim", Some(70.), cx),
+        Self::header_cell("Class", Some(100.), cx),
+        Self::header_cell("Age", Some(50.), cx),
+      ],
+      cx,
+    );
+
+    let rows = volumes.iter().enumerate().map(|(i, pv)| {
+      let claim = match pv.claim.clone() {
+        Some((namespace, name)) => Button::new(("pv-claim", i))
+          .label(pv.claim_display())
+          .ghost()
+          .xsmall()
+          .tooltip("Show claim")
+          .on_click(move |_ev, _window, cx| {
+            services::open_volume_claim(name.clone(), namespace.clone(), cx);
+          })
+          .into_any_element(),
+        None => Self::text_cell("-".to_string(), None, cx).into_any_element(),
+      };
+
+      h_flex()
+        .w_full()
+        .py(px(8.))
+        .px(px(12.))
+        .gap(px(8.))
+        .items_center()
+        .rounded(px(6.))
+        .when(i % 2 == 1, |el| el.bg(colors.sidebar.opacity(0.3)))
+        .hover(|el| el.bg(colors.sidebar))
+        .child(
+          v_flex()
+            .flex_1()
+            .min_w_0()
+            .child(Self::text_cell(pv.name.clone(), None, cx).font_family("monospace"))
+            .child(
+              div()
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .child(pv.source.clone()),
+            ),
+        )
+        .child(
+          div()
+            .w(px(80.))
+            .flex_shrink_0()
+            .child(Self::badge(pv.phase.clone(), Self::phase_color(&pv.phase, cx))),
+        )
+        .child(div().w(px(180.)).flex_shrink_0().overflow_hidden().child(claim))
+        .child(Self::text_cell(
+          pv.capacity.clone().unwrap_or_else(|| "-".to_string()),
+          Some(70.),
+          cx,
+        ))
+        .child(Self::text_cell(access_modes_display(&pv.access_modes), Some(70.), cx))
+        .child(Self::text_cell(pv.reclaim_policy.clone(), Some(70.), cx))
+        .child(Self::text_cell(
+          pv.storage_class.clone().unwrap_or_else(|| "-".to_string()),
+          Some(100.),
+          cx,
+        ))
+        .child(Self::text_cell(pv.age.clone(), Some(50.), cx))
+    });
+
+    v_flex().w_full().child(header).children(rows)
+  }
+
+  fn render_classes(&self, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = cx.theme().colors;
+    let classes: Vec<StorageClassInfo> = self.docker_state.read(cx).storage_classes.clone();
+
+    if classes.is_empty() {
+      return Self::render_placeholder("No storage classes", cx);
+    }
+
+    let header = Self::table_header(
+      vec![
+        Self::header_cell("Name", None, cx),
+        Self::header_cell("Provisioner", Some(200.), cx),
+        Self::header_cell("Reclaim", Some(70.), cx),
+        Self::header_cell("Binding", Some(140.), cx),
+        Self::header_cell("Expansion", Some(70.), cx),
+        Self::header_cell("Age", Some(50.), cx),
+      ],
+      cx,
+    );
+
+    let rows = classes.iter().enumerate().map(|(i, sc)| {
+      h_flex()
+        .w_full()
+        .py(px(8.))
+        .px(px(12.))
+        .gap(px(8.))
+        .items_center()
+        .rounded(px(6.))
+        .when(i % 2 == 1, |el| el.bg(colors.sidebar.opacity(0.3)))
+        .hover(|el| el.bg(colors.sidebar))
+        .child(
+          h_flex()
+            .flex_1()
+            .min_w_0()
+            .gap(px(6.))
+            .items_center()
+            .child(Self::text_cell(sc.name.clone(), None, cx).font_family("monospace"))
+            .when(sc.is_default, |el| {
+              el.child(Self::badge("default".to_string(), colors.primary))
+            }),
+        )
+        .child(Self::text_cell(sc.provisioner.clone(), Some(200.), cx))
+        .child(Self::text_cell(sc.reclaim_policy.clone(), Some(70.), cx))
+        .child(Self::text_cell(sc.binding_mode.clone(), Some(140.), cx))
+        .child(Self::text_cell(
+          if sc.allow_expansion { "Yes" } else { "No" }.to_string(),
+          Some(70.),
+          cx,
+        ))
+        .child(Self::text_cell(sc.age.clone(), Some(50.), cx))
+    });
+
+    v_flex().w_full().child(header).children(rows)
+  }
+
+  fn render_placeholder(message: &'static str, cx: &App) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    v_flex()
+      .w_full()
+      .items_center()
+      .justify_center()
+      .gap(px(16.))
+      .py(px(48.))
+      .child(
+        div()
+          .size(px(64.))
+          .rounded(px(12.))
+          .bg(colors.sidebar)
+          .flex()
+          .items_center()
+          .justify_center()
+          .child(
+            Icon::new(AppIcon::Volume)
+              .size(px(32.))
+              .text_color(colors.muted_foreground),
+          ),
+      )
+      .child(div().text_sm().text_color(colors.muted_foreground).child(message))
+  }
+
+  fn render_namespace_selector(&self, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let state = self.docker_state.read(cx);
+    let selected = state.selected_namespace.clone();
+    let namespaces = state.namespaces.clone();
+
+    let display = if selected == "all" { "All".to_string() } else { selected };
+
+    Button::new("namespace-selector")
+      .label(display)
+      .ghost()
+      .compact()
+      .dropdown_menu(move |menu, _window, _cx| {
+        let mut menu = menu.item(PopupMenuItem::new("All Namespaces").on_click(|_, _, cx| {
+          services::set_namespace("all".to_string(), cx);
+        }));
+
+        if !namespaces.is_empty() {
+          menu = menu.separator();
+          for ns in &namespaces {
+            let ns = ns.clone();
+            menu = menu.item(PopupMenuItem::new(ns.clone()).on_click(move |_, _, cx| {
+              services::set_namespace(ns.clone(), cx);
+            }));
+          }
+        }
+
+        menu
+      })
+  }
+}
+
+impl Render for StorageView {
+  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let state = self.docker_state.read(cx);
+    let load_state = state.storage_state.clone();
+    let subtitle = match &load_state {
+      LoadState::NotLoaded | LoadState::Loading => "Loading...".to_string(),
+      LoadState::Error(_) => "Error loading".to_string(),
+      LoadState::Loaded => format!(
+        "{} claim(s) · {} volume(s) · {} class(es)",
+        state.volume_claims.len(),
+        state.persistent_volumes.len(),
+        state.storage_classes.len()
+      ),
+    };
+    let active_tab = self.active_tab;
+
+    let toolbar = h_flex()
+      .h(px(52.))
+      .w_full()
+      .px(px(16.))
+      .border_b_1()
+      .border_color(colors.border)
+      .items_center()
+      .justify_between()
+      .flex_shrink_0()
+      .child(
+        v_flex()
+          .child(Label::new("Storage"))
+          .child(div().text_xs().text_color(colors.muted_foreground).child(subtitle)),
+      )
+      .child(
+        h_flex()
+          .items_center()
+          .gap(px(8.))
+          .child(self.render_namespace_selector(cx))
+          .child(
+            Button::new("refresh")
+              .icon(Icon::new(AppIcon::Restart))
+              .ghost()
+              .compact()
+              .on_click(|_ev, _window, cx| {
+                services::refresh_storage(cx);
+              }),
+          ),
+      );
+
+    let tab_bar = TabBar::new("storage-tabs")
+      .px(px(16.))
+      .child(
+        Tab::new()
+          .label("Claims")
+          .selected(active_tab == StorageTab::Claims)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = StorageTab::Claims;
+            cx.notify();
+          })),
+      )
+      .child(
+        Tab::new()
+          .label("Volumes")
+          .selected(active_tab == StorageTab::Volumes)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = StorageTab::Volumes;
+            cx.notify();
+          })),
+      )
+      .child(
+        Tab::new()
+          .label("Classes")
+          .selected(active_tab == StorageTab::Classes)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = StorageTab::Classes;
+            cx.notify();
+          })),
+      );
+
+    let content: gpui::Div = match &load_state {
+      LoadState::NotLoaded | LoadState::Loading => render_loading("storage", cx),
+      LoadState::Error(e) => render_error(
+        "storage",
+        e,
+        |_ev, _window, cx| {
+          services::refresh_storage(cx);
+        },
+        cx,
+      ),
+      LoadState::Loaded => {
+        let table = match active_tab {
+          StorageTab::Claims => self.render_claims(cx),
+          StorageTab::Volumes => self.render_volumes(cx),
+          StorageTab::Classes => self.render_classes(cx),
+        };
+        div().size_full().overflow_y_scrollbar().p(px(16.)).child(table)
+      }
+    };
+
+    div()
+      .size_full()
+      .flex()
+      .flex_col()
+      .overflow_hidden()
+      .child(toolbar)
+      .child(tab_bar)
+      .child(div().flex_1().min_h_0().overflow_hidden().child(content))
+  }
+}
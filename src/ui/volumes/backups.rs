@@ -0,0 +1,417 @@
+use chrono::Local;
+use gpui::{Context, Entity, Render, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  Icon, IconName, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  label::Label,
+  switch::Switch,
+  theme::ActiveTheme,
+  v_flex,
+};
+use std::path::PathBuf;
+
+use crate::assets::AppIcon;
+use crate::docker::{VolumeBackupFile, checksum_path, list_volume_backups};
+use crate::services;
+use crate::state::{BackupSettings, SettingsChanged, StateChanged, docker_state, settings_state};
+
+/// Backups tab of the volume detail: back up, restore, clone and schedule a single volume
+pub struct VolumeBackupsPanel {
+  volume_name: String,
+  settings: BackupSettings,
+  backups: Vec<VolumeBackupFile>,
+  loading: bool,
+  /// Volume backups are restored into (empty for this volume)
+  restore_target_input: Entity<InputState>,
+  /// Archive outside the backup directory to restore from
+  archive_input: Entity<InputState>,
+  clone_input: Entity<InputState>,
+}
+
+impl VolumeBackupsPanel {
+  pub fn new(volume_name: String, window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
+    cx.subscribe(&docker_state(cx), |this, _state, event: &StateChanged, cx| {
+      if let StateChanged::VolumeBackupsChanged { volume_name } = event
+        && *volume_name == this.volume_name
+      {
+        this.load_backups(cx);
+      }
+    })
+    .detach();
+
+    cx.subscribe(&settings_state(cx), |this, state, event: &SettingsChanged, cx| {
+      if let SettingsChanged::SettingsUpdated = event {
+        let settings = state.read(cx).settings.backups.clone();
+        let dir_changed = settings.backup_dir() != this.settings.backup_dir();
+        this.settings = settings;
+        if dir_changed {
+          this.load_backups(cx);
+        }
+        cx.notify();
+      }
+    })
+    .detach();
+
+    let restore_target_input =
+      cx.new(|cx| InputState::new(window, cx).placeholder(format!("{volume_name} (a new name creates the volume)")));
+    let archive_input = cx.new(|cx| InputState::new(window, cx).placeholder("/path/to/backup.tar.gz"));
+    let clone_input = cx.new(|cx| InputState::new(window, cx).placeholder(format!("{volume_name}-copy")));
+
+    let mut panel = Self {
+      volume_name,
+      settings: settings_state(cx).read(cx).settings.backups.clone(),
+      backups: Vec::new(),
+      loading: true,
+      restore_target_input,
+      archive_input,
+      clone_input,
+    };
+    panel.load_backups(cx);
+    panel
+  }
+
+  pub fn volume_name(&self) -> &str {
+    &self.volume_name
+  }
+
+  fn load_backups(&mut self, cx: &mut Context<'_, Self>) {
+    let dir = self.settings.backup_dir();
+    let volume = self.volume_name.clone();
+
+    cx.spawn(async move |this, cx| {
+      let backups = cx
+        .background_executor()
+        .spawn(async move { list_volume_backups(&dir, &volume) })
+        .await;
+
+      let _ = this.update(cx, |this, cx| {
+        this.backups = backups;
+        this.loading = false;
+        cx.notify();
+      });
+    })
+    .detach();
+  }
+
+  fn restore_target(&self, cx: &Context<'_, Self>) -> String {
+    let target = self.restore_target_input.read(cx).text().trim().to_string();
+    if target.is_empty() {
+      self.volume_name.clone()
+    } else {
+      target
+    }
+  }
+
+  fn restore(&mut self, archive: PathBuf, cx: &mut Context<'_, Self>) {
+    let target = self.restore_target(cx);
+    services::restore_volume(archive, target, cx);
+  }
+
+  fn restore_from_file(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let path = self.archive_input.read(cx).text().trim().to_string();
+    if path.is_empty() {
+      return;
+    }
+    let archive = match path.strip_prefix("~/") {
+      Some(rest) => dirs::home_dir().unwrap_or_default().join(rest),
+      None => PathBuf::from(path),
+    };
+    self.restore(archive, cx);
+    self
+      .archive_input
+      .update(cx, |input, cx| input.set_value("", window, cx));
+  }
+
+  fn clone_volume(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let target = self.clone_input.read(cx).text().trim().to_string();
+    if target.is_empty() {
+      return;
+    }
+    services::clone_volume(self.volume_name.clone(), target, cx);
+    self.clone_input.update(cx, |input, cx| input.set_value("", window, cx));
+  }
+
+  fn delete_backup(&mut self, backup: &VolumeBackupFile, cx: &mut Context<'_, Self>) {
+    let path = backup.path.clone();
+    cx.spawn(async move |this, cx| {
+      let result = cx
+        .background_executor()
+        .spawn(async move {
+          let _ = std::fs::remove_file(checksum_path(&path));
+          std::fs::remove_file(&path)
+        })
+        .await;
+      if let Err(e) = result {
+        tracing::warn!("Failed to delete backup: {e}");
+      }
+
+      let _ = this.update(cx, |this, cx| this.load_backups(cx));
+    })
+    .detach();
+  }
+
+  fn set_scheduled(&mut self, scheduled: bool, cx: &mut Context<'_, Self>) {
+    let volume = self.volume_name.clone();
+    settings_state(cx).update(cx, |state, cx| {
+      let volumes = &mut state.settings.backups.scheduled_volumes;
+      volumes.retain(|v| *v != volume);
+      if scheduled {
+        volumes.push(volume);
+      }
+      let _ = state.settings.save();
+      cx.emit(SettingsChanged::SettingsUpdated);
+    });
+  }
+
+  fn render_section_title(title: &str, cx: &Context<'_, Self>) -> impl IntoElement {
+    div()
+      .pt(px(8.))
+      .text_sm()
+      .font_weight(gpui::FontWeight::MEDIUM)
+      .text_color(cx.theme().colors.foreground)
+      .child(title.to_string())
+  }
+
+  fn render_backup_row(&self, index: usize, backup: &VolumeBackupFile, cx: &Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let restore_path = backup.path.clone();
+    let delete_backup = backup.clone();
+
+    h_flex()
+      .w_full()
+      .px(px(16.))
+      .py(px(10.))
+      .gap(px(12.))
+      .items_center()
+      .when(index > 0, |el| el.border_t_1().border_color(colors.border))
+      .child(
+        v_flex()
+          .flex_1()
+          .min_w_0()
+          .gap(px(2.))
+          .child(
+            div().text_sm().text_color(colors.foreground).child(
+              backup
+                .created
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string(),
+            ),
+          )
+          .child(
+            div()
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .overflow_hidden()
+              .text_ellipsis()
+              .child(format!("{} · {}", backup.display_size(), backup.file_name())),
+          ),
+      )
+      .child(
+        div()
+          .text_xs()
+          .text_color(if backup.has_checksum {
+            colors.success
+          } else {
+            colors.warning
+          })
+          .child(if backup.has_checksum { "sha256" } else { "no checksum" }),
+      )
+      .child(
+        Button::new(("restore-backup", index))
+          .label("Restore")
+          .xsmall()
+          .on_click(cx.listener(move |this, _ev, _window, cx| {
+            this.restore(restore_path.clone(), cx);
+          })),
+      )
+      .child(
+        Button::new(("delete-backup", index))
+          .icon(Icon::new(AppIcon::Trash))
+          .xsmall()
+          .ghost()
+          .tooltip("Delete backup")
+          .on_click(cx.listener(move |this, _ev, _window, cx| {
+            this.delete_backup(&delete_backup, cx);
+          })),
+      )
+  }
+}
+
+impl Render for VolumeBackupsPanel {
+  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let scheduled = self.settings.is_scheduled(&self.volume_name);
+    let volume_name = self.volume_name.clone();
+
+    let backups = if self.loading {
+      div()
+        .px(px(16.))
+        .py(px(12.))
+        .text_sm()
+        .text_color(colors.muted_foreground)
+        .child("Loading backups...")
+        .into_any_element()
+    } else if self.backups.is_empty() {
+      h_flex()
+        .px(px(16.))
+        .py(px(12.))
+        .gap(px(8.))
+        .items_center()
+        .child(Icon::new(IconName::Info).text_color(colors.muted_foreground))
+        .child(
+          div()
+            .text_sm()
+            .text_color(colors.muted_foreground)
+            .child("No backups of this volume yet"),
+        )
+        .into_any_element()
+    } else {
+      v_flex()
+        .children(
+          self
+            .backups
+            .iter()
+            .enumerate()
+            .map(|(i, backup)| self.render_backup_row(i, backup, cx)),
+        )
+        .into_any_element()
+    };
+
+    v_flex()
+      .w_full()
+      .p(px(16.))
+      .gap(px(12.))
+      .child(
+        h_flex()
+          .w_full()
+          .gap(px(12.))
+          .items_center()
+          .child(
+            v_flex()
+              .flex_1()
+              .min_w_0()
+              .gap(px(2.))
+              .child(Label::new("Backups").text_color(colors.foreground))
+              .child(
+                div()
+                  .text_xs()
+                  .text_color(colors.muted_foreground)
+                  .overflow_hidden()
+                  .text_ellipsis()
+                  .child(self.settings.backup_dir().display().to_string()),
+              ),
+          )
+          .child(
+            Button::new("backup-now")
+              .label("Back up now")
+              .small()
+              .primary()
+              .on_click(move |_ev, _window, cx| {
+                services::backup_volume(volume_name.clone(), cx);
+              }),
+          ),
+      )
+      .child(
+        h_flex()
+          .w_full()
+          .px(px(16.))
+          .py(px(12.))
+          .gap(px(12.))
+          .items_center()
+          .bg(colors.background)
+          .rounded(px(8.))
+          .child(
+            v_flex()
+              .flex_1()
+              .gap(px(2.))
+              .child(div().text_sm().text_color(colors.foreground).child("Scheduled backups"))
+              .child(div().text_xs().text_color(colors.muted_foreground).child(format!(
+                "Every {} hours, keeping the last {} (change in Settings)",
+                self.settings.interval_hours, self.settings.keep_last
+              ))),
+          )
+          .child(
+            Switch::new("backup-scheduled")
+              .checked(scheduled)
+              .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                this.set_scheduled(*checked, cx);
+              })),
+          ),
+      )
+      .child(
+        v_flex()
+          .bg(colors.background)
+          .rounded(px(8.))
+          .overflow_hidden()
+          .child(backups),
+      )
+      .child(Self::render_section_title("Restore", cx))
+      .child(
+        v_flex()
+          .gap(px(8.))
+          .child(
+            h_flex()
+              .gap(px(8.))
+              .items_center()
+              .child(
+                div()
+                  .w(px(80.))
+                  .text_xs()
+                  .text_color(colors.muted_foreground)
+                  .child("Into volume"),
+              )
+              .child(div().flex_1().child(Input::new(&self.restore_target_input).small())),
+          )
+          .child(
+            h_flex()
+              .gap(px(8.))
+              .items_center()
+              .child(
+                div()
+                  .w(px(80.))
+                  .text_xs()
+                  .text_color(colors.muted_foreground)
+                  .child("From file"),
+              )
+              .child(div().flex_1().child(Input::new(&self.archive_input).small()))
+              .child(
+                Button::new("restore-from-file")
+                  .label("Restore")
+                  .small()
+                  .on_click(cx.listener(|this, _ev, window, cx| {
+                    this.restore_from_file(window, cx);
+                  })),
+              ),
+          )
+          .child(
+            div()
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .child("Files in the backup replace existing files with the same path. Archives are checked against their .sha256 file first."),
+          ),
+      )
+      .child(Self::render_section_title("Clone", cx))
+      .child(
+        h_flex()
+          .gap(px(8.))
+          .items_center()
+          .child(div().flex_1().child(Input::new(&self.clone_input).small()))
+          .child(
+            Button::new("clone-volume")
+              .label("Clone")
+              .icon(Icon::new(AppIcon::Copy))
+              .small()
+              .on_click(cx.listener(|this, _ev, window, cx| {
+                this.clone_volume(window, cx);
+              })),
+          ),
+      )
+      .child(div().text_xs().text_color(colors.muted_foreground).child(format!(
+        "Copies the contents of {} into a new volume with the same driver and labels.",
+        self.volume_name
+      )))
+  }
+}
@@ -13,7 +13,9 @@ use std::rc::Rc;
 
 use crate::assets::AppIcon;
 use crate::docker::{VolumeFileEntry, VolumeInfo};
-use crate::ui::components::{FileExplorer, FileExplorerConfig, FileExplorerState};
+use crate::ui::components::{FileAction, FileExplorer, FileExplorerConfig, FileExplorerState};
+
+use super::backups::VolumeBackupsPanel;
 
 type VolumeActionCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
 type TabChangeCallback = Rc<dyn Fn(&usize, &mut Window, &mut App) + 'static>;
@@ -21,6 +23,7 @@ type FileNavigateCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
 type FileSelectCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
 type CloseViewerCallback = Rc<dyn Fn(&(), &mut Window, &mut App) + 'static>;
 type SymlinkClickCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
+type FileActionCallback = Rc<dyn Fn(&FileAction, &mut Window, &mut App) + 'static>;
 
 /// State for volume detail tabs
 #[derive(Debug, Clone, Default)]
@@ -50,12 +53,14 @@ pub struct VolumeDetail {
   active_tab: usize,
   volume_state: Option<VolumeTabState>,
   file_content_editor: Option<Entity<InputState>>,
+  backups_panel: Option<Entity<VolumeBackupsPanel>>,
   on_delete: Option<VolumeActionCallback>,
   on_tab_change: Option<TabChangeCallback>,
   on_navigate_path: Option<FileNavigateCallback>,
   on_file_select: Option<FileSelectCallback>,
   on_close_file_viewer: Option<CloseViewerCallback>,
   on_symlink_click: Option<SymlinkClickCallback>,
+  on_file_action: Option<FileActionCallback>,
 }
 
 impl VolumeDetail {
@@ -65,12 +70,14 @@ impl VolumeDetail {
       active_tab: 0,
       volume_state: None,
       file_content_editor: None,
+      backups_panel: None,
       on_delete: None,
       on_tab_change: None,
       on_navigate_path: None,
       on_file_select: None,
       on_close_file_viewer: None,
       on_symlink_click: None,
+      on_file_action: None,
     }
   }
 
@@ -94,6 +101,11 @@ impl VolumeDetail {
     self
   }
 
+  pub fn backups_panel(mut self, panel: Option<Entity<VolumeBackupsPanel>>) -> Self {
+    self.backups_panel = panel;
+    self
+  }
+
   pub fn on_delete<F>(mut self, callback: F) -> Self
   where
     F: Fn(&str, &mut Window, &mut App) + 'static,
@@ -142,6 +154,14 @@ impl VolumeDetail {
     self
   }
 
+  pub fn on_file_action<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&FileAction, &mut Window, &mut App) + 'static,
+  {
+    self.on_file_action = Some(Rc::new(callback));
+    self
+  }
+
   fn render_empty(cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
@@ -173,6 +193,9 @@ impl VolumeDetail {
 
     // Basic info rows
     let mut basic_info = vec![("Name", volume.name.clone()), ("Size", volume.display_size())];
+    if let Some(usage) = &volume.usage_data {
+      basic_info.push(("References", usage.ref_count.to_string()));
+    }
 
     if let Some(created) = volume.created {
       basic_info.insert(1, ("Created", created.format("%Y-%m-%d %H:%M:%S").to_string()));
@@ -188,6 +211,7 @@ impl VolumeDetail {
             .when(!volume.labels.is_empty(), |el| {
                 el.child(Self::render_labels_section(volume, cx))
             })
+            .child(Self::render_containers_section(volume, cx))
             // Additional info
             .child(Self::render_section(
                 Some("Details"),
@@ -340,6 +364,97 @@ impl VolumeDetail {
       )
   }
 
+  fn render_containers_section(volume: &VolumeInfo, cx: &App) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    let rows: Vec<gpui::Div> = if volume.containers.is_empty() {
+      vec![
+        div()
+          .px(px(16.))
+          .py(px(12.))
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .child("Not mounted by any container"),
+      ]
+    } else {
+      volume
+        .containers
+        .iter()
+        .enumerate()
+        .map(|(i, mount)| {
+          let status_color = if mount.running {
+            colors.success
+          } else {
+            colors.muted_foreground
+          };
+          let name = if mount.container_name.is_empty() {
+            mount.container_id.chars().take(12).collect()
+          } else {
+            mount.container_name.clone()
+          };
+
+          h_flex()
+            .w_full()
+            .px(px(16.))
+            .py(px(10.))
+            .gap(px(10.))
+            .items_center()
+            .when(i > 0, |el| el.border_t_1().border_color(colors.border))
+            .child(div().size(px(8.)).flex_shrink_0().rounded_full().bg(status_color))
+            .child(
+              div()
+                .flex_1()
+                .min_w_0()
+                .text_sm()
+                .text_color(colors.foreground)
+                .overflow_hidden()
+                .text_ellipsis()
+                .child(name),
+            )
+            .child(
+              div()
+                .flex_1()
+                .min_w_0()
+                .text_sm()
+                .font_family("monospace")
+                .text_color(colors.secondary_foreground)
+                .overflow_hidden()
+                .text_ellipsis()
+                .child(mount.destination.clone()),
+            )
+            .child(
+              div()
+                .flex_shrink_0()
+                .px(px(6.))
+                .rounded(px(4.))
+                .bg(colors.sidebar)
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .child(mount.display_mode()),
+            )
+        })
+        .collect()
+    };
+
+    v_flex()
+      .gap(px(1.))
+      .child(
+        div()
+          .py(px(8.))
+          .text_sm()
+          .font_weight(gpui::FontWeight::MEDIUM)
+          .text_color(colors.foreground)
+          .child("Used By"),
+      )
+      .child(
+        v_flex()
+          .bg(colors.background)
+          .rounded(px(8.))
+          .overflow_hidden()
+          .children(rows),
+      )
+  }
+
   fn render_files_tab(&self, window: &mut Window, cx: &App) -> gpui::AnyElement {
     let state = self.volume_state.as_ref();
 
@@ -389,6 +504,13 @@ impl VolumeDetail {
       });
     }
 
+    if let Some(ref cb) = self.on_file_action {
+      let cb = cb.clone();
+      explorer = explorer.on_file_action(move |action, window, cx| {
+        cb(action, window, cx);
+      });
+    }
+
     explorer.render(window, cx)
   }
 
@@ -405,7 +527,7 @@ impl VolumeDetail {
     let on_delete = self.on_delete.clone();
     let on_tab_change = self.on_tab_change.clone();
 
-    let tabs = ["Info", "Files"];
+    let tabs = ["Info", "Files", "Backups"];
 
     // Toolbar with tabs and actions
     let toolbar = h_flex()
@@ -460,6 +582,15 @@ impl VolumeDetail {
           .overflow_hidden()
           .child(self.render_files_tab(window, cx)),
       );
+    } else if self.active_tab == 2 {
+      result = result.child(
+        div()
+          .id("volume-backups-scroll")
+          .flex_1()
+          .overflow_y_scrollbar()
+          .children(self.backups_panel.clone())
+          .child(div().h(px(100.))),
+      );
     } else {
       // Info tab with scroll container
       let content = Self::render_info_tab(volume, cx);
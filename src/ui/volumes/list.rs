@@ -11,9 +11,9 @@ use gpui_component::{
 };
 
 use crate::assets::AppIcon;
-use crate::docker::VolumeInfo;
+use crate::docker::{PruneKind, VolumeInfo, has_protect_label};
 use crate::services;
-use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state};
+use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state, settings_state};
 use crate::ui::components::{render_error, render_loading};
 
 /// Volume list events emitted to parent
@@ -22,10 +22,20 @@ pub enum VolumeListEvent {
   NewVolume,
 }
 
+/// Volume list ordering
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VolumeSort {
+  #[default]
+  Name,
+  /// Largest first, volumes without a reported size last
+  Size,
+}
+
 /// Delegate for the volume list
 pub struct VolumeListDelegate {
   docker_state: Entity<DockerState>,
   search_query: String,
+  sort: VolumeSort,
 }
 
 impl VolumeListDelegate {
@@ -34,26 +44,33 @@ impl VolumeListDelegate {
   }
 
   fn filtered_volumes(&self, cx: &App) -> Vec<VolumeInfo> {
-    let volumes = self.volumes(cx);
-    if self.search_query.is_empty() {
-      return volumes.clone();
-    }
-
     let query = self.search_query.to_lowercase();
-    volumes
+    let mut volumes: Vec<VolumeInfo> = self
+      .volumes(cx)
       .iter()
       .filter(|v| {
-        v.name.to_lowercase().contains(&query)
+        query.is_empty()
+          || v.name.to_lowercase().contains(&query)
           || v.driver.to_lowercase().contains(&query)
           || v.mountpoint.to_lowercase().contains(&query)
       })
       .cloned()
-      .collect()
+      .collect();
+
+    // Volumes arrive sorted by name
+    if self.sort == VolumeSort::Size {
+      volumes.sort_by(|a, b| b.size().cmp(&a.size()).then_with(|| a.name.cmp(&b.name)));
+    }
+    volumes
   }
 
   pub fn set_search_query(&mut self, query: String) {
     self.search_query = query;
   }
+
+  pub fn set_sort(&mut self, sort: VolumeSort) {
+    self.sort = sort;
+  }
 }
 
 impl ListDelegate for VolumeListDelegate {
@@ -91,6 +108,25 @@ impl ListDelegate for VolumeListDelegate {
     let name = volume_name.clone();
     let row = ix.row;
 
+    let pinned = settings_state(cx)
+      .read(cx)
+      .settings
+      .is_pinned(PruneKind::Volumes, &volume.name);
+    let labelled = has_protect_label(&volume.labels);
+
+    // Label protection can only be lifted by changing the label
+    let protect_button = (!labelled || pinned).then(|| {
+      let name = volume_name.clone();
+      let button = Button::new(("protect", row))
+        .icon(Icon::new(AppIcon::Shield))
+        .xsmall()
+        .tooltip(if pinned { "Unprotect" } else { "Protect" })
+        .on_click(move |_ev, _window, cx| {
+          services::set_pinned(PruneKind::Volumes, name.clone(), !pinned, cx);
+        });
+      if pinned { button.primary() } else { button.ghost() }
+    });
+
     let delete_button = Button::new(("delete", row))
       .icon(Icon::new(AppIcon::Trash))
       .ghost()
@@ -139,6 +175,14 @@ impl ListDelegate for VolumeListDelegate {
               .child(size_text),
           ),
       )
+      .when(labelled && !pinned, |el| {
+        el.child(
+          Icon::new(AppIcon::Shield)
+            .size(px(12.))
+            .text_color(colors.muted_foreground),
+        )
+      })
+      .children(protect_button)
       .child(div().flex_shrink_0().child(delete_button));
 
     let item = ListItem::new(ix)
@@ -169,6 +213,7 @@ pub struct VolumeList {
   search_input: Option<Entity<InputState>>,
   search_visible: bool,
   search_query: String,
+  sort: VolumeSort,
 }
 
 impl VolumeList {
@@ -178,6 +223,7 @@ impl VolumeList {
     let delegate = VolumeListDelegate {
       docker_state: docker_state.clone(),
       search_query: String::new(),
+      sort: VolumeSort::default(),
     };
 
     let list_state = cx.new(|cx| ListState::new(delegate, window, cx));
@@ -212,9 +258,23 @@ impl VolumeList {
       search_input: None,
       search_visible: false,
       search_query: String::new(),
+      sort: VolumeSort::default(),
     }
   }
 
+  fn toggle_sort(&mut self, cx: &mut Context<'_, Self>) {
+    self.sort = match self.sort {
+      VolumeSort::Name => VolumeSort::Size,
+      VolumeSort::Size => VolumeSort::Name,
+    };
+    let sort = self.sort;
+    self.list_state.update(cx, |state, cx| {
+      state.delegate_mut().set_sort(sort);
+      cx.notify();
+    });
+    cx.notify();
+  }
+
   fn ensure_search_input(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
     if self.search_input.is_none() {
       let input_state = cx.new(|cx| InputState::new(window, cx).placeholder("Search volumes..."));
@@ -326,12 +386,8 @@ impl VolumeList {
 
   fn calculate_total_size(&self, cx: &App) -> String {
     let state = self.docker_state.read(cx);
-    let total: i64 = state
-      .volumes
-      .iter()
-      .filter_map(|v| v.usage_data.as_ref().map(|u| u.size))
-      .sum();
-    bytesize::ByteSize(u64::try_from(total).unwrap_or(0)).to_string()
+    let total: u64 = state.volumes.iter().filter_map(VolumeInfo::size).sum();
+    bytesize::ByteSize(total).to_string()
   }
 
   fn render_no_results(&self, cx: &mut Context<'_, Self>) -> gpui::Div {
@@ -386,6 +442,7 @@ impl Render for VolumeList {
     let volumes_empty = filtered_count == 0;
 
     let search_visible = self.search_visible;
+    let sort_by_size = self.sort == VolumeSort::Size;
 
     // Ensure search input exists if visible and sync query
     if search_visible {
@@ -434,6 +491,17 @@ impl Render for VolumeList {
                 this.toggle_search(window, cx);
               })),
           )
+          .child(
+            Button::new("sort")
+              .icon(IconName::ArrowDown)
+              .when(sort_by_size, Button::primary)
+              .when(!sort_by_size, ButtonVariants::ghost)
+              .compact()
+              .tooltip(if sort_by_size { "Sort by name" } else { "Sort by size" })
+              .on_click(cx.listener(|this, _ev, _window, cx| {
+                this.toggle_sort(cx);
+              })),
+          )
           .child(
             Button::new("add")
               .icon(Icon::new(AppIcon::Plus))
@@ -1,3 +1,4 @@
+mod backups;
 pub mod create_dialog;
 mod detail;
 mod list;
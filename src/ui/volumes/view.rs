@@ -1,12 +1,20 @@
 use gpui::{App, Context, Entity, Render, Styled, Window, div, prelude::*, px};
-use gpui_component::{input::InputState, theme::ActiveTheme};
+use gpui_component::{
+  WindowExt,
+  button::{Button, ButtonVariants},
+  input::{Input, InputState},
+  theme::ActiveTheme,
+  v_flex,
+};
+use std::path::PathBuf;
 
 use crate::docker::VolumeInfo;
 use crate::services;
 use crate::state::{DockerState, Selection, StateChanged, docker_state};
-use crate::ui::components::detect_language_from_path;
+use crate::ui::components::{FileAction, detect_language_from_path};
 use crate::ui::dialogs;
 
+use super::backups::VolumeBackupsPanel;
 use super::detail::{VolumeDetail, VolumeTabState};
 use super::list::{VolumeList, VolumeListEvent};
 
@@ -19,6 +27,8 @@ pub struct VolumesView {
   volume_tab_state: VolumeTabState,
   file_content_editor: Option<Entity<InputState>>,
   last_synced_file_content: String,
+  /// Backups tab of the selected volume, created when the tab is first shown
+  backups_panel: Option<Entity<VolumeBackupsPanel>>,
 }
 
 impl VolumesView {
@@ -116,6 +126,7 @@ impl VolumesView {
       volume_tab_state: VolumeTabState::new(),
       file_content_editor: None,
       last_synced_file_content: String::new(),
+      backups_panel: None,
     }
   }
 
@@ -272,6 +283,172 @@ impl VolumesView {
     }
   }
 
+  fn on_file_action(&mut self, action: &FileAction, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let Some(volume) = self.selected_volume(cx).map(|v| v.name) else {
+      return;
+    };
+
+    match action.clone() {
+      FileAction::Upload { dir } => {
+        Self::show_path_prompt(
+          "Upload to Volume",
+          format!("Host file or folder to copy into {dir}"),
+          cx.new(|cx| InputState::new(window, cx).placeholder("/path/on/host")),
+          "Upload",
+          window,
+          cx,
+          move |source, cx| {
+            let source = match source.strip_prefix("~/") {
+              Some(rest) => dirs::home_dir().unwrap_or_default().join(rest),
+              None => PathBuf::from(source),
+            };
+            services::upload_to_volume(volume.clone(), source, dir.clone(), cx);
+          },
+        );
+      }
+      FileAction::NewFolder { dir } => {
+        Self::show_path_prompt(
+          "New Folder",
+          format!("Create a folder in {dir}"),
+          cx.new(|cx| InputState::new(window, cx).placeholder("folder name")),
+          "Create",
+          window,
+          cx,
+          move |name, cx| {
+            services::create_volume_directory(volume.clone(), join_path(&dir, &name), cx);
+          },
+        );
+      }
+      FileAction::NewFile { dir } => {
+        Self::show_path_prompt(
+          "New File",
+          format!("Create an empty file in {dir}"),
+          cx.new(|cx| InputState::new(window, cx).placeholder("file name")),
+          "Create",
+          window,
+          cx,
+          move |name, cx| {
+            services::create_volume_file(volume.clone(), join_path(&dir, &name), cx);
+          },
+        );
+      }
+      FileAction::Download { path } => services::download_from_volume(volume, path, cx),
+      FileAction::Rename { path } => {
+        let (dir, name) = path.rsplit_once('/').unwrap_or(("", &path));
+        let (dir, name) = (dir.to_string(), name.to_string());
+        Self::show_path_prompt(
+          "Rename",
+          format!("New name for {path} (a path starting with / moves it)"),
+          cx.new(|cx| InputState::new(window, cx).default_value(name)),
+          "Rename",
+          window,
+          cx,
+          move |name, cx| {
+            let target = if name.starts_with('/') {
+              name
+            } else {
+              join_path(&dir, &name)
+            };
+            services::rename_volume_entry(volume.clone(), path.clone(), target, cx);
+          },
+        );
+      }
+      FileAction::Delete { path } => Self::show_delete_dialog(volume, path, window, cx),
+      FileAction::Save { path } => {
+        let Some(editor) = &self.file_content_editor else {
+          return;
+        };
+        let content = editor.read(cx).text().to_string();
+        // Keep the render sync from replacing the saved text
+        self.volume_tab_state.file_content.clone_from(&content);
+        self.last_synced_file_content.clone_from(&content);
+        services::save_volume_file(volume, path, content, cx);
+      }
+    }
+  }
+
+  /// Ask for a name or path, calling `on_submit` with the trimmed non-empty value
+  fn show_path_prompt(
+    title: &'static str,
+    description: String,
+    input: Entity<InputState>,
+    confirm_label: &'static str,
+    window: &mut Window,
+    cx: &mut App,
+    on_submit: impl Fn(String, &mut App) + 'static,
+  ) {
+    let on_submit = std::rc::Rc::new(on_submit);
+
+    window.open_dialog(cx, move |dialog, _window, cx| {
+      let colors = cx.theme().colors;
+      let input_clone = input.clone();
+      let on_submit = on_submit.clone();
+
+      dialog
+        .title(title)
+        .min_w(px(420.))
+        .child(
+          v_flex()
+            .gap(px(8.))
+            .child(
+              div()
+                .text_sm()
+                .text_color(colors.muted_foreground)
+                .child(description.clone()),
+            )
+            .child(Input::new(&input).w_full()),
+        )
+        .footer(move |_dialog_state, _, _window, _cx| {
+          let input = input_clone.clone();
+          let on_submit = on_submit.clone();
+
+          vec![
+            Button::new("confirm")
+              .label(confirm_label)
+              .primary()
+              .on_click(move |_ev, window, cx| {
+                let value = input.read(cx).text().trim().to_string();
+                if !value.is_empty() {
+                  on_submit(value, cx);
+                  window.close_dialog(cx);
+                }
+              })
+              .into_any_element(),
+          ]
+        })
+    });
+  }
+
+  fn show_delete_dialog(volume: String, path: String, window: &mut Window, cx: &mut Context<'_, Self>) {
+    window.open_dialog(cx, move |dialog, _window, cx| {
+      let colors = cx.theme().colors;
+      let volume = volume.clone();
+      let path_for_delete = path.clone();
+
+      dialog
+        .title("Delete")
+        .min_w(px(420.))
+        .child(div().text_sm().text_color(colors.foreground).child(format!(
+          "Delete {path} from {volume}? Folders are deleted with their contents."
+        )))
+        .footer(move |_dialog_state, _, _window, _cx| {
+          let volume = volume.clone();
+          let path = path_for_delete.clone();
+
+          vec![
+            Button::new("delete")
+              .label("Delete")
+              .danger()
+              .on_click(move |_ev, window, cx| {
+                services::delete_volume_entry(volume.clone(), path.clone(), cx);
+                window.close_dialog(cx);
+              })
+              .into_any_element(),
+          ]
+        })
+    });
+  }
+
   fn load_volume_file_content(volume_name: &str, path: &str, cx: &mut Context<'_, Self>) {
     let name = volume_name.to_string();
     let path = path.to_string();
@@ -323,12 +500,25 @@ impl Render for VolumesView {
     let file_content_editor = self.file_content_editor.clone();
     let has_selection = selected_volume.is_some();
 
+    // Recreate the backups panel when another volume is selected
+    if active_tab == 2
+      && let Some(volume) = &selected_volume
+      && self
+        .backups_panel
+        .as_ref()
+        .is_none_or(|panel| panel.read(cx).volume_name() != volume.name)
+    {
+      let name = volume.name.clone();
+      self.backups_panel = Some(cx.new(|cx| VolumeBackupsPanel::new(name, window, cx)));
+    }
+
     // Build detail panel
     let detail = VolumeDetail::new()
       .volume(selected_volume)
       .active_tab(active_tab)
       .volume_state(self.volume_tab_state.clone())
       .file_content_editor(file_content_editor)
+      .backups_panel(self.backups_panel.clone())
       .on_tab_change(cx.listener(|this, tab: &usize, _window, cx| {
         this.on_tab_change(*tab, cx);
       }))
@@ -344,6 +534,9 @@ impl Render for VolumesView {
       .on_symlink_click(cx.listener(|this, path: &str, window, cx| {
         this.on_symlink_follow(path, window, cx);
       }))
+      .on_file_action(cx.listener(|this, action: &FileAction, window, cx| {
+        this.on_file_action(action, window, cx);
+      }))
       .on_delete(cx.listener(|this, _name: &str, _window, cx| {
         this.docker_state.update(cx, |s, _| s.set_selection(Selection::None));
         this.active_tab = 0;
@@ -378,3 +571,8 @@ impl Render for VolumesView {
       })
   }
 }
+
+/// Join a directory and an entry name from the file explorer
+fn join_path(dir: &str, name: &str) -> String {
+  format!("{}/{}", dir.trim_end_matches('/'), name.trim_matches('/'))
+}
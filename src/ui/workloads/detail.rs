@@ -0,0 +1,847 @@
+use gpui::{Context, Entity, Hsla, Render, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  Icon, IconName, Selectable, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  menu::DropdownMenu,
+  scroll::ScrollableElement,
+  tab::{Tab, TabBar},
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::assets::AppIcon;
+use crate::kubernetes::{JobState, PodInfo, WorkloadInfo, WorkloadKind, WorkloadStatus};
+use crate::services;
+use crate::state::{DockerState, StateChanged, WorkloadDetailTab, docker_state};
+
+use super::list::{health_color, workload_menu};
+use super::workload_icon;
+
+/// Detail view for a workload with tabs
+pub struct WorkloadDetail {
+  kind: WorkloadKind,
+  docker_state: Entity<DockerState>,
+  workload: Option<WorkloadInfo>,
+  active_tab: WorkloadDetailTab,
+  yaml_content: String,
+  yaml_editor: Option<Entity<InputState>>,
+  last_synced_yaml: String,
+}
+
+impl WorkloadDetail {
+  pub fn new(kind: WorkloadKind, cx: &mut Context<'_, Self>) -> Self {
+    let docker_state = docker_state(cx);
+
+    cx.subscribe(&docker_state, |this, ds, event: &StateChanged, cx| match event {
+      StateChanged::WorkloadYamlLoaded {
+        kind,
+        name,
+        namespace,
+        yaml,
+      } => {
+        if let Some(ref w) = this.workload
+          && w.kind == *kind
+          && w.name == *name
+          && w.namespace == *namespace
+        {
+          yaml.clone_into(&mut this.yaml_content);
+          cx.notify();
+        }
+      }
+      StateChanged::WorkloadTabRequest {
+        kind,
+        name,
+        namespace,
+        tab,
+      } if *kind == this.kind => {
+        let workload = ds.read(cx).get_workload(*kind, name, namespace).cloned();
+        if let Some(workload) = workload {
+          this.workload = Some(workload);
+          this.active_tab = *tab;
+          this.yaml_content.clear();
+          if *tab == WorkloadDetailTab::Yaml {
+            services::get_workload_yaml(*kind, name.clone(), namespace.clone(), cx);
+          }
+          cx.notify();
+        }
+      }
+      StateChanged::PodsUpdated => cx.notify(),
+      _ => {}
+    })
+    .detach();
+
+    Self {
+      kind,
+      docker_state,
+      workload: None,
+      active_tab: WorkloadDetailTab::Info,
+      yaml_content: String::new(),
+      yaml_editor: None,
+      last_synced_yaml: String::new(),
+    }
+  }
+
+  pub fn workload(&self) -> Option<&WorkloadInfo> {
+    self.workload.as_ref()
+  }
+
+  pub fn set_workload(&mut self, workload: WorkloadInfo, cx: &mut Context<'_, Self>) {
+    services::get_workload_yaml(workload.kind, workload.name.clone(), workload.namespace.clone(), cx);
+
+    self.workload = Some(workload);
+    self.active_tab = WorkloadDetailTab::Info;
+    self.yaml_content.clear();
+    self.yaml_editor = None;
+    self.last_synced_yaml.clear();
+    cx.notify();
+  }
+
+  /// Update workload data without resetting tab state (for data refresh)
+  pub fn update_workload_data(&mut self, workload: WorkloadInfo, cx: &mut Context<'_, Self>) {
+    self.workload = Some(workload);
+    cx.notify();
+  }
+
+  fn stat_card(value: String, label: &'static str, color: Hsla, cx: &Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    v_flex()
+      .items_center()
+      .min_w(px(80.))
+      .p(px(16.))
+      .rounded(px(8.))
+      .bg(colors.sidebar)
+      .child(
+        div()
+          .text_2xl()
+          .font_weight(gpui::FontWeight::BOLD)
+          .text_color(color)
+          .child(value),
+      )
+      .child(div().text_xs().text_color(colors.muted_foreground).child(label))
+  }
+
+  fn section(title: &'static str, cx: &Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    v_flex().w_full().mt(px(16.)).gap(px(8.)).child(
+      div()
+        .text_sm()
+        .font_weight(gpui::FontWeight::SEMIBOLD)
+        .text_color(colors.foreground)
+        .child(title),
+    )
+  }
+
+  fn render_status_cards(workload: &WorkloadInfo, cx: &Context<'_, Self>) -> (&'static str, Vec<gpui::Div>) {
+    let colors = cx.theme().colors;
+    let health = health_color(workload.health(), &colors);
+
+    match &workload.status {
+      WorkloadStatus::StatefulSet {
+        replicas,
+        ready,
+        current,
+        updated,
+      } => (
+        "Replicas",
+        vec![
+          Self::stat_card(ready.to_string(), "Ready", health, cx),
+          Self::stat_card(replicas.to_string(), "Desired", colors.foreground, cx),
+          Self::stat_card(current.to_string(), "Current", colors.foreground, cx),
+          Self::stat_card(updated.to_string(), "Updated", colors.primary, cx),
+        ],
+      ),
+      WorkloadStatus::DaemonSet {
+        desired,
+        current,
+        ready,
+        updated,
+        available,
+      } => (
+        "Scheduled Pods",
+        vec![
+          Self::stat_card(ready.to_string(), "Ready", health, cx),
+          Self::stat_card(desired.to_string(), "Desired", colors.foreground, cx),
+          Self::stat_card(current.to_string(), "Current", colors.foreground, cx),
+          Self::stat_card(updated.to_string(), "Updated", colors.primary, cx),
+          Self::stat_card(available.to_string(), "Available", colors.success, cx),
+        ],
+      ),
+      WorkloadStatus::Job {
+        completions,
+        succeeded,
+        failed,
+        active,
+        ..
+      } => (
+        "Completions",
+        vec![
+          Self::stat_card(format!("{succeeded}/{completions}"), "Succeeded", health, cx),
+          Self::stat_card(active.to_string(), "Active", colors.primary, cx),
+          Self::stat_card(
+            failed.to_string(),
+            "Failed",
+            if *failed > 0 { colors.danger } else { colors.foreground },
+            cx,
+          ),
+        ],
+      ),
+      WorkloadStatus::CronJob { suspended, active, .. } => (
+        "Schedule",
+        vec![
+          Self::stat_card(active.to_string(), "Active Jobs", colors.primary, cx),
+          Self::stat_card(
+            if *suspended { "Yes" } else { "No" }.to_string(),
+            "Suspended",
+            if *suspended { colors.warning } else { colors.foreground },
+            cx,
+          ),
+        ],
+      ),
+    }
+  }
+
+  fn render_info_tab(workload: &WorkloadInfo, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    let info_row = |label: &str, value: String| {
+      h_flex()
+        .w_full()
+        .py(px(8.))
+        .gap(px(16.))
+        .child(
+          div()
+            .w(px(140.))
+            .flex_shrink_0()
+            .text_sm()
+            .font_weight(gpui::FontWeight::MEDIUM)
+            .text_color(colors.muted_foreground)
+            .child(label.to_string()),
+        )
+        .child(div().flex_1().text_sm().text_color(colors.foreground).child(value))
+    };
+
+    let mut content = v_flex()
+      .w_full()
+      .gap(px(4.))
+      .child(info_row("Name", workload.name.clone()))
+      .child(info_row("Namespace", workload.namespace.clone()))
+      .child(info_row("Kind", workload.kind.label().to_string()))
+      .child(info_row("Age", workload.age.clone()))
+      .child(info_row("Status", workload.status_display()));
+
+    match &workload.status {
+      WorkloadStatus::Job { state, duration, .. } => {
+        let state_color = match state {
+          JobState::Complete => colors.success,
+          JobState::Failed => colors.danger,
+          JobState::Running => colors.primary,
+          JobState::Suspended => colors.muted_foreground,
+        };
+        content = content
+          .child(
+            h_flex()
+              .w_full()
+              .py(px(8.))
+              .gap(px(16.))
+              .child(
+                div()
+                  .w(px(140.))
+                  .flex_shrink_0()
+                  .text_sm()
+                  .font_weight(gpui::FontWeight::MEDIUM)
+                  .text_color(colors.muted_foreground)
+                  .child("Completion"),
+              )
+              .child(div().text_sm().text_color(state_color).child(state.to_string())),
+          )
+          .child(info_row(
+            "Duration",
+            duration.clone().unwrap_or_else(|| "-".to_string()),
+          ));
+      }
+      WorkloadStatus::CronJob {
+        schedule,
+        last_schedule,
+        last_successful,
+        ..
+      } => {
+        content = content
+          .child(info_row("Schedule", schedule.clone()))
+          .child(info_row(
+            "Last Schedule",
+            last_schedule.clone().unwrap_or_else(|| "Never".to_string()),
+          ))
+          .child(info_row(
+            "Last Successful",
+            last_successful.clone().unwrap_or_else(|| "Never".to_string()),
+          ));
+      }
+      _ => {}
+    }
+
+    // Jobs created by a CronJob link back to it
+    if let Some(owner) = workload.owner.clone() {
+      let namespace = workload.namespace.clone();
+      content = content.child(
+        h_flex()
+          .w_full()
+          .py(px(4.))
+          .gap(px(16.))
+          .items_center()
+          .child(
+            div()
+              .w(px(140.))
+              .flex_shrink_0()
+              .text_sm()
+              .font_weight(gpui::FontWeight::MEDIUM)
+              .text_color(colors.muted_foreground)
+              .child("Created By"),
+          )
+          .child(
+            Button::new("open-owner")
+              .label(format!("CronJob {owner}"))
+              .ghost()
+              .xsmall()
+              .on_click(move |_ev, _window, cx| {
+                services::open_workload(WorkloadKind::CronJob, owner.clone(), namespace.clone(), cx);
+              }),
+          ),
+      );
+    }
+
+    let (status_title, cards) = Self::render_status_cards(workload, cx);
+    content =
+      content.child(Self::section(status_title, cx).child(h_flex().w_full().flex_wrap().gap(px(16.)).children(cards)));
+
+    let colors = &cx.theme().colors;
+
+    // Images section
+    if !workload.images.is_empty() {
+      content = content.child(
+        Self::section("Images", cx).child(
+          div().w_full().p(px(12.)).rounded(px(8.)).bg(colors.sidebar).child(
+            v_flex().gap(px(4.)).children(
+              workload
+                .images
+                .iter()
+                .map(|img| {
+                  div()
+                    .text_xs()
+                    .font_family("monospace")
+                    .text_color(colors.foreground)
+                    .child(img.clone())
+                })
+                .collect::<Vec<_>>(),
+            ),
+          ),
+        ),
+      );
+    }
+
+    // Labels section
+    if !workload.labels.is_empty() {
+      let mut labels: Vec<_> = workload.labels.iter().collect();
+      labels.sort();
+      content = content.child(
+        Self::section("Labels", cx).child(
+          div().w_full().p(px(12.)).rounded(px(8.)).bg(colors.sidebar).child(
+            v_flex().gap(px(4.)).children(
+              labels
+                .into_iter()
+                .map(|(k, v)| {
+                  div()
+                    .text_xs()
+                    .font_family("monospace")
+                    .text_color(colors.muted_foreground)
+                    .child(format!("{k}={v}"))
+                })
+                .collect::<Vec<_>>(),
+            ),
+          ),
+        ),
+      );
+    }
+
+    div()
+      .size_full()
+      .child(div().w_full().h_full().p(px(16.)).overflow_y_scrollbar().child(content))
+  }
+
+  fn render_no_children(label: &'static str, cx: &Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    div().size_full().flex().items_center().justify_center().child(
+      v_flex()
+        .items_center()
+        .gap(px(8.))
+        .child(
+          Icon::new(AppIcon::Pod)
+            .size(px(32.))
+            .text_color(colors.muted_foreground),
+        )
+        .child(div().text_sm().text_color(colors.muted_foreground).child(label)),
+    )
+  }
+
+  fn header_cell(label: &'static str, width: Option<f32>, cx: &Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    let cell = match width {
+      Some(w) => div().w(px(w)).flex_shrink_0(),
+      None => div().flex_1().min_w_0(),
+    };
+    cell
+      .text_xs()
+      .font_weight(gpui::FontWeight::SEMIBOLD)
+      .text_color(colors.muted_foreground)
+      .child(label)
+  }
+
+  fn render_pods_tab(&self, workload: &WorkloadInfo, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let state = self.docker_state.read(cx);
+    let matching_pods: Vec<PodInfo> = state.pods.iter().filter(|pod| workload.selects(pod)).cloned().collect();
+
+    if matching_pods.is_empty() {
+      return Self::render_no_children("No pods found", cx);
+    }
+
+    let colors = &cx.theme().colors;
+    let header = h_flex()
+      .w_full()
+      .py(px(8.))
+      .px(px(12.))
+      .gap(px(8.))
+      .bg(colors.sidebar)
+      .rounded_t(px(8.))
+      .child(Self::header_cell("Pod Name", None, cx))
+      .child(Self::header_cell("Status", Some(80.), cx))
+      .child(Self::header_cell("Node", Some(100.), cx))
+      .child(Self::header_cell("Ready", Some(50.), cx))
+      .child(Self::header_cell("Restarts", Some(60.), cx))
+      .child(Self::header_cell("Age", Some(50.), cx))
+      .child(div().w(px(40.)).flex_shrink_0());
+
+    let colors = &cx.theme().colors;
+    let rows = matching_pods
+      .iter()
+      .enumerate()
+      .map(|(i, pod)| {
+        let status_color = if pod.phase.is_running() {
+          colors.success
+        } else if pod.phase.is_pending() {
+          colors.warning
+        } else {
+          colors.danger
+        };
+
+        let pod_name = pod.name.clone();
+        let pod_namespace = pod.namespace.clone();
+
+        h_flex()
+          .w_full()
+          .py(px(8.))
+          .px(px(12.))
+          .gap(px(8.))
+          .rounded(px(6.))
+          .when(i % 2 == 1, |el| el.bg(colors.sidebar.opacity(0.3)))
+          .hover(|el| el.bg(colors.sidebar))
+          .child(
+            div()
+              .flex_1()
+              .min_w_0()
+              .text_sm()
+              .text_color(colors.foreground)
+              .font_family("monospace")
+              .text_ellipsis()
+              .overflow_hidden()
+              .whitespace_nowrap()
+              .child(pod.name.clone()),
+          )
+          .child(
+            div().w(px(80.)).flex_shrink_0().child(
+              div()
+                .px(px(6.))
+                .py(px(2.))
+                .rounded(px(4.))
+                .bg(status_color.opacity(0.15))
+                .text_xs()
+                .text_color(status_color)
+                .child(pod.phase.to_string()),
+            ),
+          )
+          .child(
+            div()
+              .w(px(100.))
+              .flex_shrink_0()
+              .overflow_hidden()
+              .child(match pod.node.clone() {
+                Some(node) => Button::new(("node", i))
+                  .label(node.clone())
+                  .ghost()
+                  .xsmall()
+                  .tooltip("Open node")
+                  .on_click(move |_ev, _window, cx| {
+                    services::open_node(node.clone(), cx);
+                  })
+                  .into_any_element(),
+                None => div()
+                  .text_sm()
+                  .text_color(colors.muted_foreground)
+                  .child("-")
+                  .into_any_element(),
+              }),
+          )
+          .child(
+            div()
+              .w(px(50.))
+              .flex_shrink_0()
+              .text_sm()
+              .text_color(colors.foreground)
+              .child(pod.ready.clone()),
+          )
+          .child(
+            div()
+              .w(px(60.))
+              .flex_shrink_0()
+              .text_sm()
+              .text_color(if pod.restarts > 0 {
+                colors.warning
+              } else {
+                colors.muted_foreground
+              })
+              .child(pod.restarts.to_string()),
+          )
+          .child(
+            div()
+              .w(px(50.))
+              .flex_shrink_0()
+              .text_sm()
+              .text_color(colors.muted_foreground)
+              .child(pod.age.clone()),
+          )
+          .child(
+            div().w(px(40.)).flex_shrink_0().flex().justify_end().child(
+              Button::new(("view-pod", i))
+                .icon(IconName::Eye)
+                .ghost()
+                .xsmall()
+                .on_click(move |_ev, _window, cx| {
+                  services::open_pod_info(pod_name.clone(), pod_namespace.clone(), cx);
+                }),
+            ),
+          )
+      })
+      .collect::<Vec<_>>();
+
+    div().size_full().p(px(16.)).child(
+      v_flex()
+        .w_full()
+        .gap(px(8.))
+        .child(
+          div()
+            .text_xs()
+            .text_color(colors.muted_foreground)
+            .child(format!("{} pod(s)", matching_pods.len())),
+        )
+        .child(v_flex().w_full().child(header).children(rows)),
+    )
+  }
+
+  /// Jobs a CronJob has created, newest first
+  fn render_jobs_tab(&self, cron_job: &WorkloadInfo, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let state = self.docker_state.read(cx);
+    let jobs: Vec<WorkloadInfo> = state
+      .workloads(WorkloadKind::Job)
+      .iter()
+      .filter(|job| job.namespace == cron_job.namespace && job.owner.as_deref() == Some(cron_job.name.as_str()))
+      .cloned()
+      .collect();
+
+    if jobs.is_empty() {
+      return Self::render_no_children("No jobs created yet", cx);
+    }
+
+    let colors = &cx.theme().colors;
+    let header = h_flex()
+      .w_full()
+      .py(px(8.))
+      .px(px(12.))
+      .gap(px(8.))
+      .bg(colors.sidebar)
+      .rounded_t(px(8.))
+      .child(Self::header_cell("Job Name", None, cx))
+      .child(Self::header_cell("Status", Some(100.), cx))
+      .child(Self::header_cell("Duration", Some(70.), cx))
+      .child(Self::header_cell("Age", Some(50.), cx))
+      .child(div().w(px(40.)).flex_shrink_0());
+
+    let colors = cx.theme().colors;
+    let rows = jobs
+      .iter()
+      .enumerate()
+      .map(|(i, job)| {
+        let status_color = health_color(job.health(), &colors);
+        let duration = match &job.status {
+          WorkloadStatus::Job { duration, .. } => duration.clone(),
+          _ => None,
+        };
+        let job_name = job.name.clone();
+        let job_namespace = job.namespace.clone();
+
+        h_flex()
+          .w_full()
+          .py(px(8.))
+          .px(px(12.))
+          .gap(px(8.))
+          .rounded(px(6.))
+          .when(i % 2 == 1, |el| el.bg(colors.sidebar.opacity(0.3)))
+          .hover(|el| el.bg(colors.sidebar))
+          .child(
+            div()
+              .flex_1()
+              .min_w_0()
+              .text_sm()
+              .text_color(colors.foreground)
+              .font_family("monospace")
+              .text_ellipsis()
+              .overflow_hidden()
+              .whitespace_nowrap()
+              .child(job.name.clone()),
+          )
+          .child(
+            div().w(px(100.)).flex_shrink_0().child(
+              div()
+                .px(px(6.))
+                .py(px(2.))
+                .rounded(px(4.))
+                .bg(status_color.opacity(0.15))
+                .text_xs()
+                .text_color(status_color)
+                .child(job.status_display()),
+            ),
+          )
+          .child(
+            div()
+              .w(px(70.))
+              .flex_shrink_0()
+              .text_sm()
+              .text_color(colors.muted_foreground)
+              .child(duration.unwrap_or_else(|| "-".to_string())),
+          )
+          .child(
+            div()
+              .w(px(50.))
+              .flex_shrink_0()
+              .text_sm()
+              .text_color(colors.muted_foreground)
+              .child(job.age.clone()),
+          )
+          .child(
+            div().w(px(40.)).flex_shrink_0().flex().justify_end().child(
+              Button::new(("view-job", i))
+                .icon(IconName::Eye)
+                .ghost()
+                .xsmall()
+                .on_click(move |_ev, _window, cx| {
+                  services::open_workload(WorkloadKind::Job, job_name.clone(), job_namespace.clone(), cx);
+                }),
+            ),
+          )
+      })
+      .collect::<Vec<_>>();
+
+    div().size_full().p(px(16.)).child(
+      v_flex()
+        .w_full()
+        .gap(px(8.))
+        .child(
+          div()
+            .text_xs()
+            .text_color(colors.muted_foreground)
+            .child(format!("{} job(s)", jobs.len())),
+        )
+        .child(v_flex().w_full().child(header).children(rows)),
+    )
+  }
+
+  fn render_yaml_tab(&self, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    if self.yaml_content.is_empty() {
+      return v_flex().size_full().p(px(16.)).child(
+        div()
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .child("Loading YAML..."),
+      );
+    }
+
+    if let Some(ref editor) = self.yaml_editor {
+      return div()
+        .size_full()
+        .child(Input::new(editor).size_full().appearance(false).disabled(true));
+    }
+
+    // Fallback to plain text
+    div().size_full().child(
+      div()
+        .size_full()
+        .overflow_y_scrollbar()
+        .bg(colors.sidebar)
+        .p(px(12.))
+        .font_family("monospace")
+        .text_xs()
+        .text_color(colors.foreground)
+        .child(self.yaml_content.clone()),
+    )
+  }
+
+  fn render_empty(&self, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    let label = self.kind.label();
+
+    div().size_full().flex().items_center().justify_center().child(
+      v_flex()
+        .items_center()
+        .gap(px(16.))
+        .child(
+          div()
+            .size(px(64.))
+            .rounded(px(12.))
+            .bg(colors.sidebar)
+            .flex()
+            .items_center()
+            .justify_center()
+            .child(
+              Icon::new(workload_icon(self.kind))
+                .size(px(48.))
+                .text_color(colors.muted_foreground),
+            ),
+        )
+        .child(
+          div()
+            .text_lg()
+            .font_weight(gpui::FontWeight::SEMIBOLD)
+            .text_color(colors.secondary_foreground)
+            .child(format!("Select a {label}")),
+        )
+        .child(
+          div()
+            .text_sm()
+            .text_color(colors.muted_foreground)
+            .child(format!("Click on a {} to view details", label.to_lowercase())),
+        ),
+    )
+  }
+}
+
+impl Render for WorkloadDetail {
+  fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    // Create yaml editor if needed
+    if self.yaml_editor.is_none() && self.workload.is_some() {
+      self.yaml_editor = Some(cx.new(|cx| {
+        InputState::new(window, cx)
+          .multi_line(true)
+          .code_editor("yaml")
+          .line_number(true)
+          .searchable(true)
+          .soft_wrap(false)
+      }));
+    }
+
+    // Sync yaml editor content
+    if let Some(ref editor) = self.yaml_editor
+      && !self.yaml_content.is_empty()
+      && self.last_synced_yaml != self.yaml_content
+    {
+      let yaml_clone = self.yaml_content.clone();
+      editor.update(cx, |state, cx| {
+        state.replace(&yaml_clone, window, cx);
+      });
+      self.last_synced_yaml = self.yaml_content.clone();
+    }
+
+    let colors = cx.theme().colors;
+
+    let Some(workload) = self.workload.clone() else {
+      return div().size_full().child(self.render_empty(cx));
+    };
+
+    let active_tab = self.active_tab;
+    let children_label = if workload.kind == WorkloadKind::CronJob {
+      "Jobs"
+    } else {
+      "Pods"
+    };
+
+    let tab_bar = TabBar::new("workload-tabs")
+      .flex_1()
+      .py(px(0.))
+      .child(
+        Tab::new()
+          .label("Info")
+          .selected(active_tab == WorkloadDetailTab::Info)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = WorkloadDetailTab::Info;
+            cx.notify();
+          })),
+      )
+      .child(
+        Tab::new()
+          .label(children_label)
+          .selected(active_tab == WorkloadDetailTab::Pods)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = WorkloadDetailTab::Pods;
+            cx.notify();
+          })),
+      )
+      .child(
+        Tab::new()
+          .label("YAML")
+          .selected(active_tab == WorkloadDetailTab::Yaml)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = WorkloadDetailTab::Yaml;
+            if let Some(ref w) = this.workload {
+              services::get_workload_yaml(w.kind, w.name.clone(), w.namespace.clone(), cx);
+            }
+          })),
+      );
+
+    let menu_workload = workload.clone();
+    let actions = Button::new("workload-actions")
+      .icon(IconName::Ellipsis)
+      .ghost()
+      .small()
+      .dropdown_menu(move |menu, _window, _cx| workload_menu(menu, &menu_workload));
+
+    let content = match active_tab {
+      WorkloadDetailTab::Info => Self::render_info_tab(&workload, cx),
+      WorkloadDetailTab::Pods if workload.kind == WorkloadKind::CronJob => self.render_jobs_tab(&workload, cx),
+      WorkloadDetailTab::Pods => self.render_pods_tab(&workload, cx),
+      WorkloadDetailTab::Yaml => self.render_yaml_tab(cx),
+    };
+
+    div()
+      .size_full()
+      .flex()
+      .flex_col()
+      .overflow_hidden()
+      .child(
+        h_flex()
+          .w_full()
+          .px(px(16.))
+          .py(px(8.))
+          .gap(px(12.))
+          .items_center()
+          .border_b_1()
+          .border_color(colors.border)
+          .flex_shrink_0()
+          .child(tab_bar)
+          .child(actions),
+      )
+      .child(div().flex_1().min_h_0().overflow_hidden().child(content))
+  }
+}
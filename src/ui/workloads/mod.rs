@@ -0,0 +1,17 @@
+mod detail;
+mod list;
+mod view;
+
+use crate::assets::AppIcon;
+use crate::kubernetes::WorkloadKind;
+
+pub use view::WorkloadsView;
+
+/// Icon shown for a workload kind in lists and empty states
+pub fn workload_icon(kind: WorkloadKind) -> AppIcon {
+  match kind {
+    WorkloadKind::StatefulSet | WorkloadKind::DaemonSet => AppIcon::Deployment,
+    WorkloadKind::Job => AppIcon::Activity,
+    WorkloadKind::CronJob => AppIcon::Events,
+  }
+}
@@ -0,0 +1,236 @@
+use std::time::Duration;
+
+use gpui::{App, Context, Entity, Render, Styled, Timer, Window, div, prelude::*, px};
+use gpui_component::{
+  WindowExt,
+  button::{Button, ButtonVariants},
+  theme::ActiveTheme,
+};
+
+use super::detail::WorkloadDetail;
+use super::list::{WorkloadList, WorkloadListEvent};
+use crate::kubernetes::{WorkloadInfo, WorkloadKind};
+use crate::services;
+use crate::state::{DockerState, Selection, StateChanged, docker_state, settings_state};
+use crate::ui::deployments::ScaleDialog;
+
+/// StatefulSets, DaemonSets, Jobs or CronJobs view with list and detail panels
+pub struct WorkloadsView {
+  kind: WorkloadKind,
+  docker_state: Entity<DockerState>,
+  list: Entity<WorkloadList>,
+  detail: Entity<WorkloadDetail>,
+}
+
+impl WorkloadsView {
+  /// Get the currently selected workload of this view's kind from global state
+  fn selected_workload(&self, cx: &App) -> Option<WorkloadInfo> {
+    let state = self.docker_state.read(cx);
+    match &state.selection {
+      Selection::Workload { kind, name, namespace } if *kind == self.kind => {
+        state.get_workload(*kind, name, namespace).cloned()
+      }
+      _ => None,
+    }
+  }
+
+  fn select(&mut self, workload: &WorkloadInfo, cx: &mut Context<'_, Self>) {
+    self.docker_state.update(cx, |state, _cx| {
+      state.set_selection(Selection::Workload {
+        kind: workload.kind,
+        name: workload.name.clone(),
+        namespace: workload.namespace.clone(),
+      });
+    });
+  }
+
+  pub fn new(kind: WorkloadKind, window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
+    let docker_state = docker_state(cx);
+
+    let list = cx.new(|cx| WorkloadList::new(kind, window, cx));
+    let detail = cx.new(|cx| WorkloadDetail::new(kind, cx));
+
+    cx.subscribe(&list, |this, _list, event: &WorkloadListEvent, cx| match event {
+      WorkloadListEvent::Selected(workload) => {
+        this.detail.update(cx, |detail, cx| {
+          detail.set_workload(workload.clone(), cx);
+        });
+        this.select(workload, cx);
+        cx.notify();
+      }
+    })
+    .detach();
+
+    cx.subscribe_in(
+      &docker_state,
+      window,
+      move |this, ds, event: &StateChanged, window, cx| {
+        match event {
+          StateChanged::WorkloadTabRequest {
+            kind: requested,
+            name,
+            namespace,
+            ..
+          } if *requested == kind => {
+            let workload = ds.read(cx).get_workload(kind, name, namespace).cloned();
+            if let Some(workload) = workload {
+              this.select(&workload, cx);
+              cx.notify();
+            }
+          }
+          StateChanged::SelectionChanged => {
+            // Selections made elsewhere (e.g. global search) still need the detail loaded
+            if let Some(workload) = this.selected_workload(cx) {
+              let shown = this
+                .detail
+                .read(cx)
+                .workload()
+                .map(|w| (w.name.clone(), w.namespace.clone()));
+              if shown != Some((workload.name.clone(), workload.namespace.clone())) {
+                this.detail.update(cx, |detail, cx| {
+                  detail.set_workload(workload, cx);
+                });
+              }
+            }
+            cx.notify();
+          }
+          StateChanged::WorkloadsUpdated { kind: updated } if *updated == kind => {
+            let selected_key = match &this.docker_state.read(cx).selection {
+              Selection::Workload {
+                kind: selected,
+                name,
+                namespace,
+              } if *selected == kind => Some((name.clone(), namespace.clone())),
+              _ => None,
+            };
+
+            if let Some((name, namespace)) = selected_key {
+              let workload = ds.read(cx).get_workload(kind, &name, &namespace).cloned();
+              if let Some(workload) = workload {
+                // Preserve tab state during refresh
+                this.detail.update(cx, |detail, cx| {
+                  detail.update_workload_data(workload, cx);
+                });
+              } else {
+                // Workload was deleted
+                this.docker_state.update(cx, |s, _| {
+                  s.set_selection(Selection::None);
+                });
+              }
+            }
+            cx.notify();
+          }
+          StateChanged::NamespacesUpdated => {
+            services::refresh_workloads(kind, cx);
+          }
+          StateChanged::WorkloadScaleRequest {
+            kind: requested,
+            name,
+            namespace,
+            current_replicas,
+          } if *requested == kind => {
+            Self::show_scale_dialog(kind, name, namespace, *current_replicas, window, cx);
+          }
+          _ => {}
+        }
+      },
+    )
+    .detach();
+
+    // Start periodic refresh
+    let refresh_interval = settings_state(cx).read(cx).settings.container_refresh_interval;
+    cx.spawn(async move |_this, cx| {
+      loop {
+        Timer::after(Duration::from_secs(refresh_interval)).await;
+        let _ = cx.update(|cx| {
+          services::refresh_workloads(kind, cx);
+        });
+      }
+    })
+    .detach();
+
+    // Trigger initial data load; CronJob details list their jobs
+    services::refresh_workloads(kind, cx);
+    if kind == WorkloadKind::CronJob {
+      services::refresh_workloads(WorkloadKind::Job, cx);
+    }
+
+    Self {
+      kind,
+      docker_state,
+      list,
+      detail,
+    }
+  }
+
+  fn show_scale_dialog(
+    kind: WorkloadKind,
+    name: &str,
+    namespace: &str,
+    current_replicas: i32,
+    window: &mut Window,
+    cx: &mut Context<'_, Self>,
+  ) {
+    let resource = match kind {
+      WorkloadKind::StatefulSet => "statefulset",
+      WorkloadKind::DaemonSet => "daemonset",
+      WorkloadKind::Job => "job",
+      WorkloadKind::CronJob => "cronjob",
+    };
+    let dialog_entity =
+      cx.new(|cx| ScaleDialog::new(resource, name.to_string(), namespace.to_string(), current_replicas, cx));
+
+    window.open_dialog(cx, move |dialog, _window, _cx| {
+      let dialog_clone = dialog_entity.clone();
+
+      dialog
+        .title(format!("Scale {}", kind.label()))
+        .min_w(px(350.))
+        .child(dialog_entity.clone())
+        .footer(move |_dialog_state, _, _window, _cx| {
+          let dialog = dialog_clone.clone();
+
+          vec![
+            Button::new("scale")
+              .label("Scale")
+              .primary()
+              .on_click(move |_ev, window, cx| {
+                let replicas = dialog.read(cx).get_replicas(cx);
+                let name = dialog.read(cx).deployment_name().to_string();
+                let ns = dialog.read(cx).namespace().to_string();
+                services::scale_workload(kind, name, ns, replicas, cx);
+                window.close_dialog(cx);
+              })
+              .into_any_element(),
+          ]
+        })
+    });
+  }
+}
+
+impl Render for WorkloadsView {
+  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let has_selection = self.selected_workload(cx).is_some();
+
+    div()
+      .size_full()
+      .flex()
+      .overflow_hidden()
+      .child(
+        // Left: list - fixed width when selected, full width when not
+        div()
+          .when(has_selection, |el| {
+            el.w(px(320.)).border_r_1().border_color(colors.border)
+          })
+          .when(!has_selection, gpui::Styled::flex_1)
+          .h_full()
+          .flex_shrink_0()
+          .overflow_hidden()
+          .child(self.list.clone()),
+      )
+      .when(has_selection, |el| {
+        el.child(div().flex_1().h_full().overflow_hidden().child(self.detail.clone()))
+      })
+  }
+}